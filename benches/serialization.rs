@@ -0,0 +1,56 @@
+//! Serialization Benchmarks
+//!
+//! Throughput of the JSON bodies the flower endpoints write and read most:
+//! list pages of various sizes and create requests.
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+
+use rust_api::application::dtos::{ApiResponse, CreateFlowerRequest, FlowerResponse};
+use rust_api::domain::shared::{PaginatedResponse, Pagination};
+use rust_api::test_support::FlowerBuilder;
+
+fn page(size: usize) -> ApiResponse<PaginatedResponse<FlowerResponse>> {
+    let flowers = (0..size)
+        .map(|_| {
+            let flower = FlowerBuilder::new()
+                .with_description("<p>Fresh from the <em>highlands</em></p>")
+                .with_sku("BENCH-1")
+                .build();
+            FlowerResponse::from(flower)
+        })
+        .collect();
+    let pagination = Pagination::try_new(Some(1), Some(size.max(1) as i64)).unwrap();
+    ApiResponse::success(PaginatedResponse::new(flowers, 1_000, &pagination))
+}
+
+fn serialize_pages(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialize_page");
+    for size in [1, 10, 100] {
+        let page = page(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &page, |b, page| {
+            b.iter(|| serde_json::to_vec(black_box(page)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn deserialize_create_requests(c: &mut Criterion) {
+    let body = serde_json::to_vec(
+        &FlowerBuilder::new()
+            .with_description("A beautiful red rose")
+            .with_sku("ROSE-RED-01")
+            .create_request(),
+    )
+    .unwrap();
+
+    let mut group = c.benchmark_group("deserialize_create_request");
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    group.bench_function("json", |b| {
+        b.iter(|| serde_json::from_slice::<CreateFlowerRequest>(black_box(&body)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, serialize_pages, deserialize_create_requests);
+criterion_main!(benches);
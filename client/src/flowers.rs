@@ -0,0 +1,112 @@
+//! Flower Endpoints
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use crate::{
+    Client, CreateFlowerRequest, Error, FlowerColor, FlowerResponse, ListFlowersQuery,
+    PaginatedFlowerResponse, PriceAdjustmentRequest, PriceAdjustmentResponse,
+    StockAdjustmentRequest, StockMovementResponse, TrendingFlowerResponse, TrendingQuery,
+    UpdateFlowerRequest,
+};
+
+/// Operations on the flower catalog, see `Client::flowers`
+pub struct Flowers<'a> {
+    client: &'a Client,
+}
+
+impl<'a> Flowers<'a> {
+    pub(crate) fn new(client: &'a Client) -> Self {
+        Self { client }
+    }
+
+    /// One page of flowers, optionally searched by name or filtered by color
+    pub async fn list(&self, query: &ListFlowersQuery) -> Result<PaginatedFlowerResponse, Error> {
+        let request = self
+            .client
+            .request(Method::GET, "api/flowers")?
+            .query(query);
+        self.client.call(request).await
+    }
+
+    pub async fn get(&self, id: Uuid) -> Result<FlowerResponse, Error> {
+        let request = self
+            .client
+            .request(Method::GET, &format!("api/flowers/{}", id))?;
+        self.client.call(request).await
+    }
+
+    pub async fn create(&self, flower: &CreateFlowerRequest) -> Result<FlowerResponse, Error> {
+        let request = self
+            .client
+            .request(Method::POST, "api/flowers")?
+            .json(flower);
+        self.client.call(request).await
+    }
+
+    /// Change the given fields, leaving the others as they are
+    pub async fn update(
+        &self,
+        id: Uuid,
+        changes: &UpdateFlowerRequest,
+    ) -> Result<FlowerResponse, Error> {
+        let request = self
+            .client
+            .request(Method::PUT, &format!("api/flowers/{}", id))?
+            .json(changes);
+        self.client.call(request).await
+    }
+
+    pub async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let request = self
+            .client
+            .request(Method::DELETE, &format!("api/flowers/{}", id))?;
+        self.client.call_empty(request).await
+    }
+
+    /// Colors a flower may have
+    pub async fn colors(&self) -> Result<Vec<FlowerColor>, Error> {
+        let request = self.client.request(Method::GET, "api/flowers/colors")?;
+        self.client.call(request).await
+    }
+
+    /// Most viewed flowers, most viewed first
+    pub async fn trending(
+        &self,
+        query: &TrendingQuery,
+    ) -> Result<Vec<TrendingFlowerResponse>, Error> {
+        let request = self
+            .client
+            .request(Method::GET, "api/flowers/trending")?
+            .query(query);
+        self.client.call(request).await
+    }
+
+    /// Adjust the prices of matching flowers, or preview it with `dry_run`
+    pub async fn adjust_prices(
+        &self,
+        adjustment: &PriceAdjustmentRequest,
+    ) -> Result<PriceAdjustmentResponse, Error> {
+        let request = self
+            .client
+            .request(Method::POST, "api/flowers/price-adjustments")?
+            .json(adjustment);
+        self.client.call(request).await
+    }
+
+    /// Change a flower's stock and record the movement in the ledger
+    pub async fn adjust_stock(
+        &self,
+        id: Uuid,
+        adjustment: &StockAdjustmentRequest,
+    ) -> Result<StockMovementResponse, Error> {
+        let request = self
+            .client
+            .request(
+                Method::POST,
+                &format!("api/flowers/{}/stock-adjustments", id),
+            )?
+            .json(adjustment);
+        self.client.call(request).await
+    }
+}
@@ -0,0 +1,164 @@
+//! Flower Shop API Client
+//!
+//! Typed access to the flower endpoints for Rust services, using the
+//! server's own request and response types:
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), rust_api_client::Error> {
+//! use rust_api_client::{Client, ListFlowersQuery};
+//!
+//! let client = Client::new("https://flowers.example.com")?.with_api_key("key")?;
+//! let page = client.flowers().list(&ListFlowersQuery::default()).await?;
+//! println!("{} flowers", page.total);
+//! # Ok(())
+//! # }
+//! ```
+
+mod flowers;
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Method, RequestBuilder, Response, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+pub use flowers::Flowers;
+use rust_api::application::dtos::ApiResponse;
+pub use rust_api::application::dtos::{
+    CreateFlowerRequest, ErrorResponse, FieldErrorResponse, FlowerResponse, ListFlowersQuery,
+    PaginatedFlowerResponse, PriceAdjustmentFilter, PriceAdjustmentRequest,
+    PriceAdjustmentResponse, PriceChangeResponse, StockAdjustmentRequest, StockMovementResponse,
+    TrendingFlowerResponse, TrendingQuery, UpdateFlowerRequest,
+};
+pub use rust_api::domain::flower::FlowerColor;
+
+/// Why a call failed
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The server answered with an error envelope
+    #[error("{status}: {} ({})", .body.error, .body.code)]
+    Api {
+        status: StatusCode,
+        body: ErrorResponse,
+    },
+
+    /// The base URL or a header value is invalid
+    #[error("invalid client configuration: {0}")]
+    Config(String),
+
+    /// The server could not be reached or answered with something other
+    /// than the API's JSON
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+}
+
+impl Error {
+    /// Machine-readable code of an API error, such as `flower.not_found`
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Error::Api { body, .. } => Some(&body.code),
+            _ => None,
+        }
+    }
+}
+
+/// Client for one API server; cheap to clone
+#[derive(Debug, Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    base_url: Url,
+    headers: HeaderMap,
+}
+
+impl Client {
+    /// Client for the server at `base_url`, such as `http://localhost:3000`
+    pub fn new(base_url: &str) -> Result<Self, Error> {
+        Self::with_http_client(base_url, reqwest::Client::new())
+    }
+
+    /// Client sending its requests through `http`, for custom timeouts,
+    /// proxies or TLS settings
+    pub fn with_http_client(base_url: &str, http: reqwest::Client) -> Result<Self, Error> {
+        let mut base_url =
+            Url::parse(base_url).map_err(|e| Error::Config(format!("{}: {}", base_url, e)))?;
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+
+        Ok(Self {
+            http,
+            base_url,
+            headers: HeaderMap::new(),
+        })
+    }
+
+    /// Authenticate as a tenant with a key from `TENANT_API_KEYS`
+    pub fn with_api_key(self, api_key: &str) -> Result<Self, Error> {
+        self.with_header("x-api-key", api_key, true)
+    }
+
+    /// Authenticate as the admin
+    pub fn with_admin_token(self, token: &str) -> Result<Self, Error> {
+        self.with_header("authorization", &format!("Bearer {}", token), true)
+    }
+
+    /// Act on the flowers of `tenant` when the server honours `X-Tenant-Id`
+    pub fn with_tenant(self, tenant: &str) -> Result<Self, Error> {
+        self.with_header("x-tenant-id", tenant, false)
+    }
+
+    /// Ask for error messages in `language`, such as `id`
+    pub fn with_language(self, language: &str) -> Result<Self, Error> {
+        self.with_header("accept-language", language, false)
+    }
+
+    /// Flower catalog endpoints
+    pub fn flowers(&self) -> Flowers<'_> {
+        Flowers::new(self)
+    }
+
+    fn with_header(
+        mut self,
+        name: &'static str,
+        value: &str,
+        sensitive: bool,
+    ) -> Result<Self, Error> {
+        let mut value = HeaderValue::from_str(value)
+            .map_err(|_| Error::Config(format!("{} is not a valid header value", name)))?;
+        value.set_sensitive(sensitive);
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    fn request(&self, method: Method, path: &str) -> Result<RequestBuilder, Error> {
+        let url = self
+            .base_url
+            .join(path.trim_start_matches('/'))
+            .map_err(|e| Error::Config(format!("{}: {}", path, e)))?;
+        Ok(self.http.request(method, url).headers(self.headers.clone()))
+    }
+
+    /// Send a request and unwrap the `data` of the response envelope
+    async fn call<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, Error> {
+        let response = Self::check(request.send().await?).await?;
+        let envelope: ApiResponse<T> = response.json().await?;
+        Ok(envelope.data)
+    }
+
+    /// Send a request answered without a body
+    async fn call_empty(&self, request: RequestBuilder) -> Result<(), Error> {
+        Self::check(request.send().await?).await?;
+        Ok(())
+    }
+
+    async fn check(response: Response) -> Result<Response, Error> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        match response.json::<ErrorResponse>().await {
+            Ok(body) => Err(Error::Api { status, body }),
+            Err(e) => Err(Error::Http(e)),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+-- Drop flowers table along with its indexes and sample data
+DROP TABLE IF EXISTS flowers;
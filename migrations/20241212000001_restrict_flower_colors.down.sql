@@ -0,0 +1,3 @@
+-- Allow any color again; colors are kept lower case and trimmed as the up
+-- migration left them
+ALTER TABLE flowers DROP CONSTRAINT IF EXISTS flowers_color_check;
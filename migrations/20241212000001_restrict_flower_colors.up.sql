@@ -0,0 +1,25 @@
+-- Refuse to guess for colors outside the canonical set: list them so
+-- operators can map each one deliberately before migrating again
+DO $$
+DECLARE
+    unknown TEXT;
+BEGIN
+    SELECT string_agg(format('%s (%L)', id, color), ', ' ORDER BY id)
+    INTO unknown
+    FROM flowers
+    WHERE LOWER(TRIM(color)) NOT IN ('red', 'white', 'pink', 'yellow', 'orange', 'purple', 'blue', 'peach', 'mixed');
+
+    IF unknown IS NOT NULL THEN
+        RAISE EXCEPTION 'Flowers with unsupported colors: %', unknown
+            USING HINT = 'Set each to one of red, white, pink, yellow, orange, purple, blue, peach or mixed';
+    END IF;
+END
+$$;
+
+-- Normalize existing colors to the canonical set
+UPDATE flowers SET color = LOWER(TRIM(color));
+
+-- Only allow canonical colors (keep in sync with FlowerColor)
+ALTER TABLE flowers
+    ADD CONSTRAINT flowers_color_check
+    CHECK (color IN ('red', 'white', 'pink', 'yellow', 'orange', 'purple', 'blue', 'peach', 'mixed'));
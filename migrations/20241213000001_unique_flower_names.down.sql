@@ -0,0 +1 @@
+DROP INDEX IF EXISTS flowers_name_lower_unique;
@@ -0,0 +1,23 @@
+-- Refuse to pick a winner among names that differ only in case: list them
+-- so operators can merge or rename each deliberately before migrating again
+DO $$
+DECLARE
+    duplicates TEXT;
+BEGIN
+    SELECT string_agg(format('%s (%L)', id, name), ', ' ORDER BY LOWER(name), id)
+    INTO duplicates
+    FROM (
+        SELECT id, name, COUNT(*) OVER (PARTITION BY LOWER(name)) AS named_alike
+        FROM flowers
+    ) flowers
+    WHERE named_alike > 1;
+
+    IF duplicates IS NOT NULL THEN
+        RAISE EXCEPTION 'Flowers with duplicate names: %', duplicates
+            USING HINT = 'Merge or rename them so no two names differ only in case';
+    END IF;
+END
+$$;
+
+-- Flower names are unique regardless of case
+CREATE UNIQUE INDEX IF NOT EXISTS flowers_name_lower_unique ON flowers (LOWER(name));
@@ -0,0 +1 @@
+COMMENT ON COLUMN flowers.id IS NULL;
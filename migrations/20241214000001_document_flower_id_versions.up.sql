@@ -0,0 +1,5 @@
+-- New flowers get UUIDv7 identifiers (time-ordered) unless ID_VERSION=v4.
+-- Existing rows keep their UUIDv4 values: both fit the UUID column, lookups
+-- are unaffected and no backfill is needed. Only rows created after the
+-- switch sort by creation time when ordered by id.
+COMMENT ON COLUMN flowers.id IS 'UUIDv7 for rows created after the switch to time-ordered IDs; older rows keep UUIDv4';
@@ -0,0 +1,6 @@
+-- Fails when two tenants share a flower name, as names become globally unique again
+DROP INDEX IF EXISTS idx_flowers_tenant_created_at;
+DROP INDEX IF EXISTS flowers_tenant_name_lower_unique;
+CREATE UNIQUE INDEX IF NOT EXISTS flowers_name_lower_unique ON flowers (LOWER(name));
+
+ALTER TABLE flowers DROP COLUMN IF EXISTS tenant_id;
@@ -0,0 +1,9 @@
+-- Flowers belong to a tenant (shop); existing rows move to the default tenant
+ALTER TABLE flowers ADD COLUMN IF NOT EXISTS tenant_id VARCHAR(64) NOT NULL DEFAULT 'default';
+
+-- Names only need to be unique within a shop
+DROP INDEX IF EXISTS flowers_name_lower_unique;
+CREATE UNIQUE INDEX IF NOT EXISTS flowers_tenant_name_lower_unique ON flowers (tenant_id, LOWER(name));
+
+-- Listings are always filtered by tenant and ordered by creation time
+CREATE INDEX IF NOT EXISTS idx_flowers_tenant_created_at ON flowers (tenant_id, created_at DESC);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS dead_letter_tasks;
+DROP TABLE IF EXISTS tasks;
@@ -0,0 +1,28 @@
+-- Deferred work picked up by task workers; rows are deleted once completed
+CREATE TABLE IF NOT EXISTS tasks (
+    id UUID PRIMARY KEY,
+    kind VARCHAR(64) NOT NULL,
+    payload JSONB NOT NULL DEFAULT '{}'::jsonb,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL,
+    run_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_until TIMESTAMPTZ,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Workers claim the oldest due task first
+CREATE INDEX IF NOT EXISTS idx_tasks_run_at ON tasks (run_at);
+
+-- Tasks that exhausted their attempts, kept for inspection
+CREATE TABLE IF NOT EXISTS dead_letter_tasks (
+    id UUID PRIMARY KEY,
+    kind VARCHAR(64) NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    failed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_dead_letter_tasks_failed_at ON dead_letter_tasks (failed_at DESC);
@@ -0,0 +1,11 @@
+-- Dead-lettered tasks past their retention period, when RETENTION_ARCHIVE is on
+CREATE TABLE IF NOT EXISTS dead_letter_tasks_archive (
+    id UUID PRIMARY KEY,
+    kind VARCHAR(64) NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    failed_at TIMESTAMPTZ NOT NULL,
+    archived_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
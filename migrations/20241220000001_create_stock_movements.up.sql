@@ -0,0 +1,13 @@
+-- Inventory ledger: every stock adjustment and why it was made. Entries are
+-- kept when their flower is deleted, so there is no foreign key
+CREATE TABLE IF NOT EXISTS stock_movements (
+    id UUID PRIMARY KEY,
+    tenant_id VARCHAR(64) NOT NULL,
+    flower_id UUID NOT NULL,
+    delta INTEGER NOT NULL CHECK (delta <> 0),
+    reason VARCHAR(200) NOT NULL,
+    stock_after INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_stock_movements_flower ON stock_movements (tenant_id, flower_id, created_at DESC);
@@ -0,0 +1,11 @@
+-- Daily view totals per flower, flushed from each instance's buffer
+CREATE TABLE IF NOT EXISTS flower_views (
+    tenant_id VARCHAR(64) NOT NULL,
+    flower_id UUID NOT NULL,
+    day DATE NOT NULL,
+    views BIGINT NOT NULL,
+    PRIMARY KEY (tenant_id, flower_id, day)
+);
+
+-- Trending flowers sum the views of a tenant's recent days
+CREATE INDEX IF NOT EXISTS idx_flower_views_tenant_day ON flower_views (tenant_id, day);
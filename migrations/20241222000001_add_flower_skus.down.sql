@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS flowers_tenant_sku_unique;
+
+ALTER TABLE flowers DROP COLUMN IF EXISTS sku;
@@ -0,0 +1,5 @@
+-- pg_trgm stays installed; other schemas may rely on it
+CREATE INDEX IF NOT EXISTS idx_flowers_tenant_created_at ON flowers (tenant_id, created_at DESC);
+DROP INDEX IF EXISTS idx_flowers_tenant_created_at_id;
+DROP INDEX IF EXISTS idx_flowers_tenant_color_created_at;
+DROP INDEX IF EXISTS idx_flowers_name_lower_trgm;
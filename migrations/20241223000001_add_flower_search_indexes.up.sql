@@ -0,0 +1,12 @@
+-- Name searches match anywhere in the name (LIKE '%rose%'), which a btree
+-- cannot serve; trigrams can, for patterns of three characters or more
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+CREATE INDEX IF NOT EXISTS idx_flowers_name_lower_trgm ON flowers USING GIN (LOWER(name) gin_trgm_ops);
+
+-- Color filters, already in listing order
+CREATE INDEX IF NOT EXISTS idx_flowers_tenant_color_created_at ON flowers (tenant_id, color, created_at DESC, id DESC);
+
+-- Listings order by creation time, then ID so that flowers created at the
+-- same instant keep their order; also the key for keyset pagination
+CREATE INDEX IF NOT EXISTS idx_flowers_tenant_created_at_id ON flowers (tenant_id, created_at DESC, id DESC);
+DROP INDEX IF EXISTS idx_flowers_tenant_created_at;
@@ -0,0 +1,14 @@
+-- Flower history: every field changed by an update, with its old and new
+-- value and who changed it. Kept when the flower is deleted, like the ledger
+CREATE TABLE IF NOT EXISTS flower_changes (
+    id UUID PRIMARY KEY,
+    tenant_id VARCHAR(64) NOT NULL,
+    flower_id UUID NOT NULL,
+    field VARCHAR(32) NOT NULL,
+    old_value JSONB NOT NULL,
+    new_value JSONB NOT NULL,
+    actor VARCHAR(128) NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_flower_changes_flower ON flower_changes (tenant_id, flower_id, changed_at);
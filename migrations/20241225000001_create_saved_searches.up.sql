@@ -0,0 +1,20 @@
+-- Searches callers saved, each owned by the caller that saved it. Those with
+-- an alert address are checked for new matching flowers on a schedule;
+-- checked_at marks the newest flower already alerted about
+CREATE TABLE IF NOT EXISTS saved_searches (
+    id UUID PRIMARY KEY,
+    tenant_id VARCHAR(64) NOT NULL,
+    owner VARCHAR(128) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    query VARCHAR(100),
+    color VARCHAR(50),
+    min_price DOUBLE PRECISION,
+    max_price DOUBLE PRECISION,
+    alert_email VARCHAR(254),
+    checked_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_searches_owner ON saved_searches (tenant_id, owner, created_at);
+
+CREATE INDEX IF NOT EXISTS idx_saved_searches_alerting ON saved_searches (tenant_id) WHERE alert_email IS NOT NULL;
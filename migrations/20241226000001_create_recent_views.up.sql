@@ -0,0 +1,11 @@
+-- Latest view of each flower by each authenticated caller, flushed from each
+-- instance's buffer; only the newest views of a caller are kept
+CREATE TABLE IF NOT EXISTS recent_views (
+    tenant_id VARCHAR(64) NOT NULL,
+    viewer VARCHAR(128) NOT NULL,
+    flower_id UUID NOT NULL,
+    viewed_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, viewer, flower_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_recent_views_viewer ON recent_views (tenant_id, viewer, viewed_at DESC);
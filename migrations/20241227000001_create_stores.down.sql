@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS store_stock;
+DROP TABLE IF EXISTS stores;
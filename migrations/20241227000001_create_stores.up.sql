@@ -0,0 +1,24 @@
+-- Physical stores of a tenant, where customers pick up flowers
+CREATE TABLE IF NOT EXISTS stores (
+    id UUID PRIMARY KEY,
+    tenant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    address VARCHAR(500) NOT NULL,
+    latitude DOUBLE PRECISION NOT NULL CHECK (latitude BETWEEN -90 AND 90),
+    longitude DOUBLE PRECISION NOT NULL CHECK (longitude BETWEEN -180 AND 180),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_stores_tenant_name ON stores (tenant_id, name);
+
+-- Part of a flower's stock held by a store; flowers a store does not hold
+-- have no row
+CREATE TABLE IF NOT EXISTS store_stock (
+    store_id UUID NOT NULL REFERENCES stores (id) ON DELETE CASCADE,
+    tenant_id VARCHAR(64) NOT NULL,
+    flower_id UUID NOT NULL,
+    quantity INTEGER NOT NULL CHECK (quantity > 0),
+    PRIMARY KEY (store_id, flower_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_store_stock_flower ON store_stock (tenant_id, flower_id);
@@ -0,0 +1,12 @@
+-- Areas a tenant delivers to; the boundary is a JSON array of
+-- [latitude, longitude] corners
+CREATE TABLE IF NOT EXISTS delivery_zones (
+    id UUID PRIMARY KEY,
+    tenant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    boundary JSONB NOT NULL,
+    fee DOUBLE PRECISION NOT NULL CHECK (fee >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_delivery_zones_tenant_name ON delivery_zones (tenant_id, name);
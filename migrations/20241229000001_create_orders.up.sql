@@ -0,0 +1,35 @@
+-- Orders and the history of their status. Lines are a JSON array of
+-- {flower_id, name, unit_price, quantity}, priced when the order was placed;
+-- each status has the time the order reached it
+CREATE TABLE IF NOT EXISTS orders (
+    id UUID PRIMARY KEY,
+    tenant_id VARCHAR(64) NOT NULL,
+    lines JSONB NOT NULL,
+    shipping_carrier VARCHAR(100),
+    shipping_service VARCHAR(100),
+    shipping_fee DOUBLE PRECISION CHECK (shipping_fee >= 0),
+    shipping_estimated_days INTEGER,
+    status VARCHAR(16) NOT NULL
+        CHECK (status IN ('pending', 'paid', 'packed', 'shipped', 'delivered', 'cancelled')),
+    placed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    paid_at TIMESTAMPTZ,
+    packed_at TIMESTAMPTZ,
+    shipped_at TIMESTAMPTZ,
+    delivered_at TIMESTAMPTZ,
+    cancelled_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_orders_tenant_placed ON orders (tenant_id, placed_at DESC);
+
+CREATE TABLE IF NOT EXISTS order_events (
+    id UUID PRIMARY KEY,
+    order_id UUID NOT NULL REFERENCES orders (id) ON DELETE CASCADE,
+    tenant_id VARCHAR(64) NOT NULL,
+    from_status VARCHAR(16),
+    to_status VARCHAR(16) NOT NULL,
+    actor VARCHAR(128) NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_order_events_order ON order_events (order_id, occurred_at);
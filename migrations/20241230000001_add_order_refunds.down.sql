@@ -0,0 +1,3 @@
+ALTER TABLE orders DROP COLUMN IF EXISTS refund_reference;
+ALTER TABLE orders DROP COLUMN IF EXISTS restocking_fee;
+ALTER TABLE orders DROP COLUMN IF EXISTS refund_amount;
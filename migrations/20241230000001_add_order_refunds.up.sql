@@ -0,0 +1,5 @@
+-- Refund of a paid order that was cancelled: what went back to the customer,
+-- the restocking fee kept, and the payment gateway's reference
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS refund_amount DOUBLE PRECISION CHECK (refund_amount >= 0);
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS restocking_fee DOUBLE PRECISION CHECK (restocking_fee >= 0);
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS refund_reference VARCHAR(128);
@@ -0,0 +1,20 @@
+-- Rules adjusting flower prices while their conditions hold: at most
+-- max_stock left, and between starts_at and ends_at. The adjustment is a
+-- percent of the base price, a markup when positive (keep the bounds in sync
+-- with PricingRule)
+CREATE TABLE IF NOT EXISTS pricing_rules (
+    id UUID PRIMARY KEY,
+    tenant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    adjustment_percent DOUBLE PRECISION NOT NULL
+        CHECK (adjustment_percent BETWEEN -90 AND 500),
+    max_stock INTEGER CHECK (max_stock >= 0),
+    starts_at TIMESTAMPTZ,
+    ends_at TIMESTAMPTZ,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pricing_rules_tenant ON pricing_rules (tenant_id, name);
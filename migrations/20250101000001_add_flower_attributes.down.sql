@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_flowers_attributes;
+
+ALTER TABLE flowers DROP COLUMN IF EXISTS attributes;
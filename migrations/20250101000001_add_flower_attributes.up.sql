@@ -0,0 +1,6 @@
+-- Attributes only some flowers have (fragrance, stem length, vase life,
+-- care instructions), validated by the application
+ALTER TABLE flowers ADD COLUMN IF NOT EXISTS attributes JSONB NOT NULL DEFAULT '{}';
+
+-- Attribute filters are containment queries (attributes @> '{"fragrance":"high"}')
+CREATE INDEX IF NOT EXISTS idx_flowers_attributes ON flowers USING GIN (attributes jsonb_path_ops);
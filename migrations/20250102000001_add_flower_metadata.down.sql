@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_flowers_metadata;
+
+ALTER TABLE flowers DROP COLUMN IF EXISTS metadata;
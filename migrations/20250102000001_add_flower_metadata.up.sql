@@ -0,0 +1,5 @@
+-- Key-value pairs integrators keep on a flower, such as ERP references
+ALTER TABLE flowers ADD COLUMN IF NOT EXISTS metadata JSONB NOT NULL DEFAULT '{}';
+
+-- Metadata filters are containment queries (metadata @> '{"erp.item_id":"ITM-1"}')
+CREATE INDEX IF NOT EXISTS idx_flowers_metadata ON flowers USING GIN (metadata jsonb_path_ops);
@@ -0,0 +1,7 @@
+-- What a flower is sold by. Stock stays in stems, prices are per unit, and a
+-- unit holds stems_per_unit stems: 1 for a stem, 12 for a dozen, any number
+-- from 2 for a bundle
+ALTER TABLE flowers ADD COLUMN IF NOT EXISTS unit VARCHAR(8) NOT NULL DEFAULT 'stem'
+    CHECK (unit IN ('stem', 'dozen', 'bundle'));
+ALTER TABLE flowers ADD COLUMN IF NOT EXISTS stems_per_unit INTEGER NOT NULL DEFAULT 1
+    CHECK (stems_per_unit > 0);
@@ -0,0 +1,15 @@
+-- Hourly API usage per tenant and API key, flushed from each instance's
+-- buffer; api_key holds the last characters of the key, '' without one
+CREATE TABLE IF NOT EXISTS api_usage (
+    tenant_id VARCHAR(64) NOT NULL,
+    api_key VARCHAR(16) NOT NULL,
+    hour TIMESTAMPTZ NOT NULL,
+    requests BIGINT NOT NULL,
+    errors BIGINT NOT NULL,
+    request_bytes BIGINT NOT NULL,
+    response_bytes BIGINT NOT NULL,
+    PRIMARY KEY (tenant_id, api_key, hour)
+);
+
+-- Usage reports cover a range of hours across tenants
+CREATE INDEX IF NOT EXISTS idx_api_usage_hour ON api_usage (hour);
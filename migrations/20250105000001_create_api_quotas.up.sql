@@ -0,0 +1,9 @@
+-- Monthly request quotas set by admins for single API keys, overriding
+-- API_KEY_MONTHLY_QUOTA; api_key holds the last characters of the key
+CREATE TABLE IF NOT EXISTS api_quotas (
+    tenant_id VARCHAR(64) NOT NULL,
+    api_key VARCHAR(16) NOT NULL,
+    monthly_requests BIGINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, api_key)
+);
@@ -0,0 +1,14 @@
+-- Receivers a tenant has its events delivered to. Deliveries are signed
+-- with `secret`, and also with `previous_secret` until it expires while the
+-- secret is rotated
+CREATE TABLE IF NOT EXISTS webhook_endpoints (
+    id UUID PRIMARY KEY,
+    tenant_id VARCHAR(64) NOT NULL,
+    url VARCHAR(2048) NOT NULL,
+    secret VARCHAR(128) NOT NULL,
+    previous_secret VARCHAR(128),
+    previous_secret_expires_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_endpoints_tenant ON webhook_endpoints (tenant_id, created_at);
@@ -0,0 +1,36 @@
+-- Keys with the same last characters are counted as one again
+UPDATE api_usage kept
+SET requests = merged.requests,
+    errors = merged.errors,
+    request_bytes = merged.request_bytes,
+    response_bytes = merged.response_bytes
+FROM (
+    SELECT tenant_id, api_key, hour, MIN(key_id) AS key_id,
+           SUM(requests)::bigint AS requests, SUM(errors)::bigint AS errors,
+           SUM(request_bytes)::bigint AS request_bytes,
+           SUM(response_bytes)::bigint AS response_bytes
+    FROM api_usage
+    GROUP BY tenant_id, api_key, hour
+) merged
+WHERE kept.tenant_id = merged.tenant_id
+  AND kept.api_key = merged.api_key
+  AND kept.hour = merged.hour
+  AND kept.key_id = merged.key_id;
+DELETE FROM api_usage duplicate
+USING api_usage kept
+WHERE duplicate.tenant_id = kept.tenant_id
+  AND duplicate.api_key = kept.api_key
+  AND duplicate.hour = kept.hour
+  AND duplicate.key_id > kept.key_id;
+ALTER TABLE api_usage DROP CONSTRAINT IF EXISTS api_usage_pkey;
+ALTER TABLE api_usage DROP COLUMN IF EXISTS key_id;
+ALTER TABLE api_usage ADD PRIMARY KEY (tenant_id, api_key, hour);
+
+DELETE FROM api_quotas duplicate
+USING api_quotas kept
+WHERE duplicate.tenant_id = kept.tenant_id
+  AND duplicate.api_key = kept.api_key
+  AND duplicate.key_id > kept.key_id;
+ALTER TABLE api_quotas DROP CONSTRAINT IF EXISTS api_quotas_pkey;
+ALTER TABLE api_quotas DROP COLUMN IF EXISTS key_id;
+ALTER TABLE api_quotas ADD PRIMARY KEY (tenant_id, api_key);
@@ -0,0 +1,16 @@
+-- Usage and quotas are keyed on a fingerprint of the full API key rather
+-- than its last characters, which keys may share; api_key keeps the last
+-- characters for display. Rows from before keep those characters as their
+-- fingerprint: their usage is reported apart from the key's new usage, and
+-- quotas set before have to be set again.
+ALTER TABLE api_usage ADD COLUMN IF NOT EXISTS key_id VARCHAR(64) NOT NULL DEFAULT '';
+UPDATE api_usage SET key_id = api_key;
+ALTER TABLE api_usage ALTER COLUMN key_id DROP DEFAULT;
+ALTER TABLE api_usage DROP CONSTRAINT IF EXISTS api_usage_pkey;
+ALTER TABLE api_usage ADD PRIMARY KEY (tenant_id, key_id, hour);
+
+ALTER TABLE api_quotas ADD COLUMN IF NOT EXISTS key_id VARCHAR(64) NOT NULL DEFAULT '';
+UPDATE api_quotas SET key_id = api_key;
+ALTER TABLE api_quotas ALTER COLUMN key_id DROP DEFAULT;
+ALTER TABLE api_quotas DROP CONSTRAINT IF EXISTS api_quotas_pkey;
+ALTER TABLE api_quotas ADD PRIMARY KEY (tenant_id, key_id);
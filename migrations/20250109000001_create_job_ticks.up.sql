@@ -0,0 +1,8 @@
+-- Latest tick each exclusive job ran for, so a replica that takes the job's
+-- lock after another one finished the tick skips it rather than running it
+-- again
+CREATE TABLE IF NOT EXISTS job_ticks (
+    job VARCHAR(100) PRIMARY KEY,
+    tick TIMESTAMPTZ NOT NULL,
+    completed_at TIMESTAMPTZ NOT NULL
+);
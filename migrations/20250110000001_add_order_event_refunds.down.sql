@@ -0,0 +1,2 @@
+DELETE FROM order_events WHERE refunded IS NOT NULL;
+ALTER TABLE order_events DROP COLUMN IF EXISTS refunded;
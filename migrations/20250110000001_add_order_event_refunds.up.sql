@@ -0,0 +1,3 @@
+-- Refunds are recorded among an order's events: the amount given back to
+-- the customer, set only on events recording a refund
+ALTER TABLE order_events ADD COLUMN IF NOT EXISTS refunded DOUBLE PRECISION CHECK (refunded >= 0);
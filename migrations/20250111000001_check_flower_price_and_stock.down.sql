@@ -0,0 +1 @@
+ALTER TABLE flowers DROP CONSTRAINT IF EXISTS flowers_price_stock_check;
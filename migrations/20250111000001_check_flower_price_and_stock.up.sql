@@ -0,0 +1,23 @@
+-- Refuse to guess prices and stock that flowers cannot have: list them so
+-- operators can correct each one deliberately before migrating again
+DO $$
+DECLARE
+    invalid TEXT;
+BEGIN
+    SELECT string_agg(format('%s (price %s, stock %s)', id, price, stock), ', ' ORDER BY id)
+    INTO invalid
+    FROM flowers
+    WHERE NOT (price BETWEEN 0 AND 1000000000 AND stock BETWEEN 0 AND 1000000);
+
+    IF invalid IS NOT NULL THEN
+        RAISE EXCEPTION 'Flowers with invalid prices or stock: %', invalid
+            USING HINT = 'Set each price between 0 and 1000000000 and each stock between 0 and 1000000';
+    END IF;
+END
+$$;
+
+-- Prices and stock stay within what a flower may hold (keep in sync with
+-- Price::MAX and StockQuantity)
+ALTER TABLE flowers
+    ADD CONSTRAINT flowers_price_stock_check
+    CHECK (price BETWEEN 0 AND 1000000000 AND stock BETWEEN 0 AND 1000000);
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS dead_letter_tasks;
+DROP TABLE IF EXISTS tasks;
+DROP TABLE IF EXISTS feature_flags;
+DROP TABLE IF EXISTS flowers;
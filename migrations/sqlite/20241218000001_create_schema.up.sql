@@ -0,0 +1,69 @@
+-- SQLite schema, equivalent to the PostgreSQL migrations up to the task queue.
+-- IDs are stored as hyphenated UUID text, timestamps as RFC 3339 text and
+-- JSON payloads as text. Keep in sync with the PostgreSQL migrations.
+CREATE TABLE IF NOT EXISTS flowers (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL DEFAULT 'default',
+    name TEXT NOT NULL,
+    color TEXT NOT NULL
+        CHECK (color IN ('red', 'white', 'pink', 'yellow', 'orange', 'purple', 'blue', 'peach', 'mixed')),
+    description TEXT,
+    price REAL NOT NULL DEFAULT 0,
+    stock INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- Names are unique per tenant regardless of (ASCII) case
+CREATE UNIQUE INDEX IF NOT EXISTS flowers_tenant_name_lower_unique ON flowers (tenant_id, LOWER(name));
+
+CREATE INDEX IF NOT EXISTS idx_flowers_tenant_created_at ON flowers (tenant_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_flowers_color ON flowers (color);
+
+CREATE TABLE IF NOT EXISTS feature_flags (
+    key TEXT PRIMARY KEY,
+    enabled INTEGER NOT NULL DEFAULT 0,
+    description TEXT,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS tasks (
+    id TEXT PRIMARY KEY,
+    kind TEXT NOT NULL,
+    payload TEXT NOT NULL DEFAULT '{}',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL,
+    run_at TEXT NOT NULL,
+    locked_until TEXT,
+    last_error TEXT,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_tasks_run_at ON tasks (run_at);
+
+CREATE TABLE IF NOT EXISTS dead_letter_tasks (
+    id TEXT PRIMARY KEY,
+    kind TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    failed_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_dead_letter_tasks_failed_at ON dead_letter_tasks (failed_at DESC);
+
+-- Same sample data as the PostgreSQL schema
+INSERT INTO flowers (id, name, color, description, price, stock, created_at, updated_at)
+VALUES
+    ('550e8400-e29b-41d4-a716-446655440001', 'Rose', 'red', 'A beautiful red rose, symbol of love and passion', 25000, 100, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00'),
+    ('550e8400-e29b-41d4-a716-446655440002', 'Tulip', 'yellow', 'Bright yellow tulip from Holland', 20000, 75, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00'),
+    ('550e8400-e29b-41d4-a716-446655440003', 'Sunflower', 'yellow', 'Large sunflower that follows the sun', 15000, 50, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00'),
+    ('550e8400-e29b-41d4-a716-446655440004', 'Orchid', 'purple', 'Elegant purple orchid for special occasions', 75000, 30, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00'),
+    ('550e8400-e29b-41d4-a716-446655440005', 'Lily', 'white', 'Pure white lily with a sweet fragrance', 35000, 60, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00'),
+    ('550e8400-e29b-41d4-a716-446655440006', 'Jasmine', 'white', 'Small white jasmine flowers with intense aroma', 18000, 90, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00'),
+    ('550e8400-e29b-41d4-a716-446655440007', 'Lavender', 'purple', 'Calming lavender from Provence', 22000, 80, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00'),
+    ('550e8400-e29b-41d4-a716-446655440008', 'Daisy', 'white', 'Simple and cheerful white daisy', 12000, 120, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00'),
+    ('550e8400-e29b-41d4-a716-446655440009', 'Carnation', 'pink', 'Pink carnation for mothers day', 18000, 95, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00'),
+    ('550e8400-e29b-41d4-a716-446655440010', 'Hydrangea', 'blue', 'Beautiful blue hydrangea cluster', 45000, 40, '2024-12-11T00:00:00+00:00', '2024-12-11T00:00:00+00:00');
@@ -0,0 +1,11 @@
+-- Dead-lettered tasks past their retention period, when RETENTION_ARCHIVE is on
+CREATE TABLE IF NOT EXISTS dead_letter_tasks_archive (
+    id TEXT PRIMARY KEY,
+    kind TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    failed_at TEXT NOT NULL,
+    archived_at TEXT NOT NULL
+);
@@ -0,0 +1,11 @@
+-- Daily view totals per flower, flushed from each instance's buffer
+CREATE TABLE IF NOT EXISTS flower_views (
+    tenant_id TEXT NOT NULL,
+    flower_id TEXT NOT NULL,
+    day TEXT NOT NULL,
+    views INTEGER NOT NULL,
+    PRIMARY KEY (tenant_id, flower_id, day)
+);
+
+-- Trending flowers sum the views of a tenant's recent days
+CREATE INDEX IF NOT EXISTS idx_flower_views_tenant_day ON flower_views (tenant_id, day);
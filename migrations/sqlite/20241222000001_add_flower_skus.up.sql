@@ -0,0 +1,5 @@
+-- Stock keeping units, printed as barcodes on shelf labels; optional
+ALTER TABLE flowers ADD COLUMN sku TEXT;
+
+-- Unique within a shop; flowers without a SKU never conflict
+CREATE UNIQUE INDEX IF NOT EXISTS flowers_tenant_sku_unique ON flowers (tenant_id, sku);
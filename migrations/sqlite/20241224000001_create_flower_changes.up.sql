@@ -0,0 +1,15 @@
+-- Flower history: every field changed by an update, with its old and new
+-- value (as JSON) and who changed it. Kept when the flower is deleted, like
+-- the ledger
+CREATE TABLE IF NOT EXISTS flower_changes (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    flower_id TEXT NOT NULL,
+    field TEXT NOT NULL,
+    old_value TEXT NOT NULL,
+    new_value TEXT NOT NULL,
+    actor TEXT NOT NULL,
+    changed_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_flower_changes_flower ON flower_changes (tenant_id, flower_id, changed_at);
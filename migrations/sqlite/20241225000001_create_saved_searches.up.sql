@@ -0,0 +1,18 @@
+-- Searches callers saved, each owned by the caller that saved it. Those with
+-- an alert address are checked for new matching flowers on a schedule;
+-- checked_at marks the newest flower already alerted about
+CREATE TABLE IF NOT EXISTS saved_searches (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    name TEXT NOT NULL,
+    query TEXT,
+    color TEXT,
+    min_price REAL,
+    max_price REAL,
+    alert_email TEXT,
+    checked_at TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_searches_owner ON saved_searches (tenant_id, owner, created_at);
@@ -0,0 +1,11 @@
+-- Latest view of each flower by each authenticated caller, flushed from each
+-- instance's buffer; only the newest views of a caller are kept
+CREATE TABLE IF NOT EXISTS recent_views (
+    tenant_id TEXT NOT NULL,
+    viewer TEXT NOT NULL,
+    flower_id TEXT NOT NULL,
+    viewed_at TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, viewer, flower_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_recent_views_viewer ON recent_views (tenant_id, viewer, viewed_at DESC);
@@ -0,0 +1,12 @@
+-- Areas a tenant delivers to; the boundary is a JSON array of
+-- [latitude, longitude] corners
+CREATE TABLE IF NOT EXISTS delivery_zones (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    boundary TEXT NOT NULL,
+    fee REAL NOT NULL CHECK (fee >= 0),
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_delivery_zones_tenant_name ON delivery_zones (tenant_id, name);
@@ -0,0 +1,35 @@
+-- Orders and the history of their status. Lines are a JSON array of
+-- {flower_id, name, unit_price, quantity}, priced when the order was placed;
+-- each status has the time the order reached it
+CREATE TABLE IF NOT EXISTS orders (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    lines TEXT NOT NULL,
+    shipping_carrier TEXT,
+    shipping_service TEXT,
+    shipping_fee REAL CHECK (shipping_fee >= 0),
+    shipping_estimated_days INTEGER,
+    status TEXT NOT NULL
+        CHECK (status IN ('pending', 'paid', 'packed', 'shipped', 'delivered', 'cancelled')),
+    placed_at TEXT NOT NULL,
+    paid_at TEXT,
+    packed_at TEXT,
+    shipped_at TEXT,
+    delivered_at TEXT,
+    cancelled_at TEXT,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_orders_tenant_placed ON orders (tenant_id, placed_at DESC);
+
+CREATE TABLE IF NOT EXISTS order_events (
+    id TEXT PRIMARY KEY,
+    order_id TEXT NOT NULL REFERENCES orders (id) ON DELETE CASCADE,
+    tenant_id TEXT NOT NULL,
+    from_status TEXT,
+    to_status TEXT NOT NULL,
+    actor TEXT NOT NULL,
+    occurred_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_order_events_order ON order_events (order_id, occurred_at);
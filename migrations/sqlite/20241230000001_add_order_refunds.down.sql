@@ -0,0 +1,3 @@
+ALTER TABLE orders DROP COLUMN refund_reference;
+ALTER TABLE orders DROP COLUMN restocking_fee;
+ALTER TABLE orders DROP COLUMN refund_amount;
@@ -0,0 +1,5 @@
+-- Refund of a paid order that was cancelled: what went back to the customer,
+-- the restocking fee kept, and the payment gateway's reference
+ALTER TABLE orders ADD COLUMN refund_amount REAL CHECK (refund_amount >= 0);
+ALTER TABLE orders ADD COLUMN restocking_fee REAL CHECK (restocking_fee >= 0);
+ALTER TABLE orders ADD COLUMN refund_reference TEXT;
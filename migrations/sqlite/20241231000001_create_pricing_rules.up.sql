@@ -0,0 +1,20 @@
+-- Rules adjusting flower prices while their conditions hold: at most
+-- max_stock left, and between starts_at and ends_at. The adjustment is a
+-- percent of the base price, a markup when positive (keep the bounds in sync
+-- with PricingRule)
+CREATE TABLE IF NOT EXISTS pricing_rules (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    adjustment_percent REAL NOT NULL
+        CHECK (adjustment_percent BETWEEN -90 AND 500),
+    max_stock INTEGER CHECK (max_stock >= 0),
+    starts_at TEXT,
+    ends_at TEXT,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pricing_rules_tenant ON pricing_rules (tenant_id, name);
@@ -0,0 +1,3 @@
+-- Attributes only some flowers have (fragrance, stem length, vase life,
+-- care instructions) as a JSON object, validated by the application
+ALTER TABLE flowers ADD COLUMN attributes TEXT NOT NULL DEFAULT '{}';
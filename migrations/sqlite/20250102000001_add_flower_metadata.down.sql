@@ -0,0 +1 @@
+ALTER TABLE flowers DROP COLUMN metadata;
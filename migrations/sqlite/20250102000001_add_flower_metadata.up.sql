@@ -0,0 +1,3 @@
+-- Key-value pairs integrators keep on a flower, such as ERP references, as
+-- a JSON object
+ALTER TABLE flowers ADD COLUMN metadata TEXT NOT NULL DEFAULT '{}';
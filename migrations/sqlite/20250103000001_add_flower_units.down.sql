@@ -0,0 +1,3 @@
+ALTER TABLE flowers DROP COLUMN stems_per_unit;
+
+ALTER TABLE flowers DROP COLUMN unit;
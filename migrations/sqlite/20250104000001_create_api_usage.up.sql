@@ -0,0 +1,15 @@
+-- Hourly API usage per tenant and API key, flushed from each instance's
+-- buffer; api_key holds the last characters of the key, '' without one
+CREATE TABLE IF NOT EXISTS api_usage (
+    tenant_id TEXT NOT NULL,
+    api_key TEXT NOT NULL,
+    hour TEXT NOT NULL,
+    requests INTEGER NOT NULL,
+    errors INTEGER NOT NULL,
+    request_bytes INTEGER NOT NULL,
+    response_bytes INTEGER NOT NULL,
+    PRIMARY KEY (tenant_id, api_key, hour)
+);
+
+-- Usage reports cover a range of hours across tenants
+CREATE INDEX IF NOT EXISTS idx_api_usage_hour ON api_usage (hour);
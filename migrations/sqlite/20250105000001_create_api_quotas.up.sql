@@ -0,0 +1,9 @@
+-- Monthly request quotas set by admins for single API keys, overriding
+-- API_KEY_MONTHLY_QUOTA; api_key holds the last characters of the key
+CREATE TABLE IF NOT EXISTS api_quotas (
+    tenant_id TEXT NOT NULL,
+    api_key TEXT NOT NULL,
+    monthly_requests INTEGER NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, api_key)
+);
@@ -0,0 +1,10 @@
+-- Nonces (delivery id and timestamp) of signed inbound webhooks, kept until
+-- their signature is too old to be accepted, so each is accepted once
+CREATE TABLE IF NOT EXISTS webhook_nonces (
+    scope TEXT NOT NULL,
+    nonce TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    PRIMARY KEY (scope, nonce)
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_nonces_expires_at ON webhook_nonces (expires_at);
@@ -0,0 +1,33 @@
+-- Keys with the same last characters are counted as one again
+CREATE TABLE api_usage_by_hint (
+    tenant_id TEXT NOT NULL,
+    api_key TEXT NOT NULL,
+    hour TEXT NOT NULL,
+    requests INTEGER NOT NULL,
+    errors INTEGER NOT NULL,
+    request_bytes INTEGER NOT NULL,
+    response_bytes INTEGER NOT NULL,
+    PRIMARY KEY (tenant_id, api_key, hour)
+);
+INSERT INTO api_usage_by_hint
+SELECT tenant_id, api_key, hour,
+       SUM(requests), SUM(errors), SUM(request_bytes), SUM(response_bytes)
+FROM api_usage
+GROUP BY tenant_id, api_key, hour;
+DROP TABLE api_usage;
+ALTER TABLE api_usage_by_hint RENAME TO api_usage;
+CREATE INDEX IF NOT EXISTS idx_api_usage_hour ON api_usage (hour);
+
+CREATE TABLE api_quotas_by_hint (
+    tenant_id TEXT NOT NULL,
+    api_key TEXT NOT NULL,
+    monthly_requests INTEGER NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, api_key)
+);
+INSERT OR IGNORE INTO api_quotas_by_hint
+SELECT tenant_id, api_key, monthly_requests, updated_at
+FROM api_quotas
+ORDER BY key_id;
+DROP TABLE api_quotas;
+ALTER TABLE api_quotas_by_hint RENAME TO api_quotas;
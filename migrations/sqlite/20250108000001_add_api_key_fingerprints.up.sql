@@ -0,0 +1,35 @@
+-- Usage and quotas are keyed on a fingerprint of the full API key rather
+-- than its last characters, which keys may share; api_key keeps the last
+-- characters for display. Rows from before keep those characters as their
+-- fingerprint. SQLite cannot change a primary key, so the tables are rebuilt
+CREATE TABLE api_usage_by_key_id (
+    tenant_id TEXT NOT NULL,
+    key_id TEXT NOT NULL,
+    api_key TEXT NOT NULL,
+    hour TEXT NOT NULL,
+    requests INTEGER NOT NULL,
+    errors INTEGER NOT NULL,
+    request_bytes INTEGER NOT NULL,
+    response_bytes INTEGER NOT NULL,
+    PRIMARY KEY (tenant_id, key_id, hour)
+);
+INSERT INTO api_usage_by_key_id
+SELECT tenant_id, api_key, api_key, hour, requests, errors, request_bytes, response_bytes
+FROM api_usage;
+DROP TABLE api_usage;
+ALTER TABLE api_usage_by_key_id RENAME TO api_usage;
+CREATE INDEX IF NOT EXISTS idx_api_usage_hour ON api_usage (hour);
+
+CREATE TABLE api_quotas_by_key_id (
+    tenant_id TEXT NOT NULL,
+    key_id TEXT NOT NULL,
+    api_key TEXT NOT NULL,
+    monthly_requests INTEGER NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, key_id)
+);
+INSERT INTO api_quotas_by_key_id
+SELECT tenant_id, api_key, api_key, monthly_requests, updated_at
+FROM api_quotas;
+DROP TABLE api_quotas;
+ALTER TABLE api_quotas_by_key_id RENAME TO api_quotas;
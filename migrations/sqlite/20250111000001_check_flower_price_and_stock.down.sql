@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS flowers_price_stock_update_check;
+DROP TRIGGER IF EXISTS flowers_price_stock_insert_check;
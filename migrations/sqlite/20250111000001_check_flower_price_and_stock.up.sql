@@ -0,0 +1,28 @@
+-- Refuse to guess prices and stock that flowers cannot have, so operators
+-- correct each one deliberately before migrating again. SQLite only raises
+-- errors from triggers, with a fixed message, hence the temporary one
+CREATE TEMP TABLE invalid_flowers (id TEXT);
+CREATE TEMP TRIGGER invalid_flowers_refused BEFORE INSERT ON invalid_flowers
+BEGIN
+    SELECT RAISE(ABORT, 'Flowers with invalid prices or stock, list them with: SELECT id, price, stock FROM flowers WHERE NOT (price BETWEEN 0 AND 1000000000 AND stock BETWEEN 0 AND 1000000)');
+END;
+INSERT INTO invalid_flowers
+SELECT id FROM flowers
+WHERE NOT (price BETWEEN 0 AND 1000000000 AND stock BETWEEN 0 AND 1000000)
+LIMIT 1;
+DROP TABLE invalid_flowers;
+
+-- Prices and stock stay within what a flower may hold (keep in sync with
+-- Price::MAX and StockQuantity). SQLite cannot add a CHECK constraint to an
+-- existing table, so triggers stand in for it
+CREATE TRIGGER flowers_price_stock_insert_check BEFORE INSERT ON flowers
+WHEN NOT (NEW.price BETWEEN 0 AND 1000000000 AND NEW.stock BETWEEN 0 AND 1000000)
+BEGIN
+    SELECT RAISE(ABORT, 'CHECK constraint failed: flowers_price_stock_check');
+END;
+
+CREATE TRIGGER flowers_price_stock_update_check BEFORE UPDATE OF price, stock ON flowers
+WHEN NOT (NEW.price BETWEEN 0 AND 1000000000 AND NEW.stock BETWEEN 0 AND 1000000)
+BEGIN
+    SELECT RAISE(ABORT, 'CHECK constraint failed: flowers_price_stock_check');
+END;
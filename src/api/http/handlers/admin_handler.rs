@@ -0,0 +1,331 @@
+//! Admin HTTP Handlers
+//!
+//! Operations across tenants that no tenant key may perform: auditing the
+//! inventory ledger, purging flowers, purging the cache, reporting API usage,
+//! adjusting API key quotas and inspecting scheduled jobs and the connection
+//! pool.
+
+use axum::{
+    Json,
+    extract::{OriginalUri, Path, Query, State},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::http::pagination::Paginated;
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseCachePurge, ApiResponseFlowerPurge, ApiResponseJobs,
+    ApiResponsePaginatedLedgerEntry, ApiResponseQuota, ApiResponseQuotas, ApiResponseUsageReport,
+    CachePurgeRequest, CachePurgeResponse, ErrorResponse, FlowerPurgeResponse, JobResponse,
+    LedgerEntryResponse, LedgerQueryParams, QuotaResponse, SetQuotaRequest, UsageQueryParams,
+    UsageReportResponse,
+};
+use crate::application::ports::{CachePurge, LedgerQuery};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::{Pagination, TenantId};
+use crate::i18n::{Message, t};
+use crate::infrastructure::persistance::{AcquireLatency, PoolStats};
+
+/// Usage of the database connection pool of the answering instance
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "size": 10,
+    "active": 9,
+    "idle": 1,
+    "max_connections": 10,
+    "waits": 42,
+    "acquire_latency": {
+        "p50_ms": 0.2,
+        "p95_ms": 35.0,
+        "p99_ms": 210.5,
+        "max_ms": 480.0,
+        "samples": 256
+    }
+}))]
+pub struct PoolStatsResponse {
+    /// Open connections
+    pub size: u32,
+    /// Connections running a query or transaction
+    pub active: u32,
+    pub idle: usize,
+    pub max_connections: u32,
+    /// Queries that found every connection busy, since startup
+    pub waits: u64,
+    /// Over the latest probes; absent before the first one or with
+    /// DB_POOL_PROBE_INTERVAL_SECS=0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquire_latency: Option<AcquireLatencyResponse>,
+}
+
+/// Time taken to acquire a connection, in milliseconds
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AcquireLatencyResponse {
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+    /// Probes the percentiles are drawn from
+    pub samples: usize,
+}
+
+/// API Response for the connection pool usage
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiResponsePoolStats {
+    pub success: bool,
+    pub data: PoolStatsResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl From<PoolStats> for PoolStatsResponse {
+    fn from(stats: PoolStats) -> Self {
+        Self {
+            size: stats.size,
+            active: stats.active(),
+            idle: stats.idle,
+            max_connections: stats.max_connections,
+            waits: stats.waits,
+            acquire_latency: stats.acquire_latency.map(AcquireLatencyResponse::from),
+        }
+    }
+}
+
+impl From<AcquireLatency> for AcquireLatencyResponse {
+    fn from(latency: AcquireLatency) -> Self {
+        let millis = |duration: std::time::Duration| duration.as_secs_f64() * 1000.0;
+        Self {
+            p50_ms: millis(latency.p50),
+            p95_ms: millis(latency.p95),
+            p99_ms: millis(latency.p99),
+            max_ms: millis(latency.max),
+            samples: latency.samples,
+        }
+    }
+}
+
+/// Audit the inventory ledger of every tenant, most recent movements first
+#[utoipa::path(
+    get,
+    path = "/api/admin/audit/stock-movements",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    params(LedgerQueryParams),
+    responses(
+        (status = 200, description = "Ledger entries", body = ApiResponsePaginatedLedgerEntry, headers(
+            ("Link" = String, description = "Links to the first, previous, next and last page"),
+            ("X-Total-Count" = i64, description = "Number of entries across all pages")
+        )),
+        (status = 400, description = "Invalid tenant", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 422, description = "Invalid pagination parameters", body = ErrorResponse)
+    )
+)]
+pub async fn list_stock_movements(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Query(params): Query<LedgerQueryParams>,
+) -> DomainResult<Paginated<LedgerEntryResponse>> {
+    let pagination = Pagination::try_new(params.page, params.per_page)?;
+    let query = LedgerQuery {
+        tenant: params.tenant.map(TenantId::new).transpose()?,
+        flower_id: params.flower_id,
+    };
+    let result = state
+        .administration
+        .stock_movements(query, pagination)
+        .await?;
+    Ok(Paginated::new(uri, result))
+}
+
+/// Delete a flower together with its ledger entries and view history
+///
+/// Unlike `DELETE /api/flowers/{id}`, which keeps the ledger, nothing about
+/// the flower remains. History left by a flower deleted earlier is purged too.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/tenants/{tenant}/flowers/{id}",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    params(
+        ("tenant" = String, Path, description = "Tenant of the flower"),
+        ("id" = Uuid, Path, description = "Flower unique identifier")
+    ),
+    responses(
+        (status = 200, description = "What was purged", body = ApiResponseFlowerPurge),
+        (status = 400, description = "Invalid tenant", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 404, description = "Neither the flower nor any of its history exists", body = ErrorResponse)
+    )
+)]
+pub async fn purge_flower(
+    State(state): State<AppState>,
+    Path((tenant, id)): Path<(String, Uuid)>,
+) -> DomainResult<Json<ApiResponse<FlowerPurgeResponse>>> {
+    let tenant = TenantId::new(tenant)?;
+    let purged = state.administration.purge_flower(&tenant, id).await?;
+    Ok(Json(ApiResponse::success(purged)))
+}
+
+/// Drop entries from the flower cache so stale data stops being served
+///
+/// Purging a flower also drops the cached listings of its tenant. With the
+/// in-process cache only the answering instance is purged.
+#[utoipa::path(
+    post,
+    path = "/api/admin/cache/purge",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    request_body = CachePurgeRequest,
+    responses(
+        (status = 200, description = "Cache purged", body = ApiResponseCachePurge),
+        (status = 400, description = "Invalid tenant or empty prefix", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse)
+    )
+)]
+pub async fn purge_cache(
+    State(state): State<AppState>,
+    Json(request): Json<CachePurgeRequest>,
+) -> DomainResult<Json<ApiResponse<CachePurgeResponse>>> {
+    let target = match request {
+        CachePurgeRequest::Flower { tenant, flower_id } => CachePurge::Flower {
+            tenant: TenantId::new(tenant)?,
+            id: flower_id,
+        },
+        CachePurgeRequest::Prefix { prefix } => CachePurge::Prefix(prefix),
+        CachePurgeRequest::All => CachePurge::All,
+    };
+    let purged = state.administration.purge_cache(target).await?;
+    Ok(Json(ApiResponse::with_message(purged, t("cache.purged"))))
+}
+
+/// Scheduled jobs of the answering instance and how their runs went
+#[utoipa::path(
+    get,
+    path = "/api/admin/jobs",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, description = "Scheduled jobs, by name; empty with JOBS_ENABLED off", body = ApiResponseJobs),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse)
+    )
+)]
+pub async fn list_jobs(State(state): State<AppState>) -> Json<ApiResponse<Vec<JobResponse>>> {
+    let jobs = state
+        .jobs
+        .statuses()
+        .into_iter()
+        .map(JobResponse::from)
+        .collect();
+    Json(ApiResponse::success(jobs))
+}
+
+/// Connection pool usage of the answering instance, to debug exhaustion
+///
+/// Acquire latency comes from a probe acquiring a connection every
+/// DB_POOL_PROBE_INTERVAL_SECS; the same figures are exported as the
+/// `db_pool_*` metrics.
+#[utoipa::path(
+    get,
+    path = "/api/admin/db/pool",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, description = "Connection pool usage", body = ApiResponsePoolStats),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 503, description = "In-memory storage, without a database", body = ErrorResponse)
+    )
+)]
+pub async fn pool_stats(
+    State(state): State<AppState>,
+) -> DomainResult<Json<ApiResponse<PoolStatsResponse>>> {
+    let db = state
+        .db
+        .as_ref()
+        .ok_or_else(|| AppError::service_unavailable(Message::new("database.unavailable")))?;
+    Ok(Json(ApiResponse::success(db.stats().into())))
+}
+
+/// API requests and data volume per tenant and API key, by the hour, to bill
+/// integrators and spot abusive clients
+///
+/// Every instance buffers the usage it meters and writes it every
+/// USAGE_FLUSH_INTERVAL_SECS, so the current hour is incomplete until then.
+/// The same requests are counted live by the `api_usage_*` metrics.
+#[utoipa::path(
+    get,
+    path = "/api/admin/usage",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    params(UsageQueryParams),
+    responses(
+        (status = 200, description = "Usage per hour, with totals over the range", body = ApiResponseUsageReport),
+        (status = 400, description = "Invalid tenant", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 422, description = "Range ending before it starts or longer than 31 days", body = ErrorResponse)
+    )
+)]
+pub async fn usage_report(
+    State(state): State<AppState>,
+    Query(params): Query<UsageQueryParams>,
+) -> DomainResult<Json<ApiResponse<UsageReportResponse>>> {
+    let tenant = params.tenant.map(TenantId::new).transpose()?;
+    let report = state.usage.report(tenant, params.from, params.to).await?;
+    Ok(Json(ApiResponse::success(report)))
+}
+
+/// Monthly request quotas of the configured API keys and how much of them
+/// is used
+///
+/// Keys without a quota of their own fall back to API_KEY_MONTHLY_QUOTA.
+/// Usage other instances have not flushed yet is not counted.
+#[utoipa::path(
+    get,
+    path = "/api/admin/quotas",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, description = "Quota of every API key", body = ApiResponseQuotas),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse)
+    )
+)]
+pub async fn list_quotas(
+    State(state): State<AppState>,
+) -> DomainResult<Json<ApiResponse<Vec<QuotaResponse>>>> {
+    Ok(Json(ApiResponse::success(state.usage.quotas())))
+}
+
+/// Set the monthly request quota of one API key, or drop it back to the
+/// default with a null `monthly_requests`
+///
+/// Takes effect at once on the answering instance and on the others after
+/// their next usage flush.
+#[utoipa::path(
+    put,
+    path = "/api/admin/quotas",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    request_body = SetQuotaRequest,
+    responses(
+        (status = 200, description = "Quota updated", body = ApiResponseQuota),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 404, description = "API key not configured", body = ErrorResponse),
+        (status = 422, description = "Quota not positive", body = ErrorResponse)
+    )
+)]
+pub async fn set_quota(
+    State(state): State<AppState>,
+    Json(request): Json<SetQuotaRequest>,
+) -> DomainResult<Json<ApiResponse<QuotaResponse>>> {
+    let quota = state.usage.set_quota(request).await?;
+    Ok(Json(ApiResponse::with_message(quota, t("quota.updated"))))
+}
@@ -0,0 +1,71 @@
+//! Backup HTTP Handlers
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseBackup, ApiResponseRestore, BackupResponse, ErrorResponse,
+    RestoreBackupRequest, RestoreResponse,
+};
+use crate::domain::errors::DomainResult;
+use crate::i18n::t;
+
+/// Export the database to the object store
+#[utoipa::path(
+    post,
+    path = "/api/admin/backup",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    responses(
+        (status = 201, description = "Backup written; keep the restore token", body = ApiResponseBackup),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 503, description = "Backups are not configured", body = ErrorResponse)
+    )
+)]
+pub async fn create_backup(
+    State(state): State<AppState>,
+) -> DomainResult<(StatusCode, Json<ApiResponse<BackupResponse>>)> {
+    let backup = state.backups.create().await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::with_message(backup, t("backup.created"))),
+    ))
+}
+
+/// Replace the database contents with a backup
+#[utoipa::path(
+    post,
+    path = "/api/admin/backup/{id}/restore",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Backup ID")
+    ),
+    request_body = RestoreBackupRequest,
+    responses(
+        (status = 200, description = "Backup restored", body = ApiResponseRestore),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token, or confirmation does not match the restore token", body = ErrorResponse),
+        (status = 404, description = "Backup not found", body = ErrorResponse),
+        (status = 503, description = "Backups are not configured", body = ErrorResponse)
+    )
+)]
+pub async fn restore_backup(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<RestoreBackupRequest>,
+) -> DomainResult<Json<ApiResponse<RestoreResponse>>> {
+    let restored = state.backups.restore(id, &request.confirm).await?;
+    // Flag overrides are cached per instance; pick up the restored ones now
+    state.feature_flags.refresh().await?;
+    Ok(Json(ApiResponse::with_message(
+        restored,
+        t("backup.restored"),
+    )))
+}
@@ -0,0 +1,111 @@
+//! Catalog Export HTTP Handlers
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseCatalogExport, CatalogExportRequest, CatalogExportResponse,
+    CatalogExportStatus, ErrorResponse,
+};
+use crate::domain::errors::DomainResult;
+use crate::i18n::t;
+
+/// Queue a printable catalog of a tenant's flowers
+#[utoipa::path(
+    post,
+    path = "/api/admin/catalog-exports",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    request_body = CatalogExportRequest,
+    responses(
+        (status = 202, description = "Export queued; poll it until it is ready", body = ApiResponseCatalogExport),
+        (status = 400, description = "Invalid tenant or color", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 503, description = "Catalog exports are not configured", body = ErrorResponse)
+    )
+)]
+pub async fn create_catalog_export(
+    State(state): State<AppState>,
+    Json(request): Json<CatalogExportRequest>,
+) -> DomainResult<(StatusCode, Json<ApiResponse<CatalogExportResponse>>)> {
+    let export = state.catalog_exports.request(request).await?;
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ApiResponse::with_message(
+            with_download(export),
+            t("catalog_export.requested"),
+        )),
+    ))
+}
+
+/// Get the status of a catalog export
+#[utoipa::path(
+    get,
+    path = "/api/admin/catalog-exports/{id}",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Catalog export ID")
+    ),
+    responses(
+        (status = 200, description = "Export status; `download` is set once ready", body = ApiResponseCatalogExport),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 404, description = "Catalog export not found", body = ErrorResponse),
+        (status = 503, description = "Catalog exports are not configured", body = ErrorResponse)
+    )
+)]
+pub async fn get_catalog_export(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<Json<ApiResponse<CatalogExportResponse>>> {
+    let export = state.catalog_exports.find(id).await?;
+    Ok(Json(ApiResponse::success(with_download(export))))
+}
+
+/// Download a rendered catalog as printable HTML
+#[utoipa::path(
+    get,
+    path = "/api/admin/catalog-exports/{id}/catalog.html",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Catalog export ID")
+    ),
+    responses(
+        (status = 200, description = "Catalog laid out for A4 printing", content_type = "text/html", body = String),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 404, description = "Catalog export not found", body = ErrorResponse),
+        (status = 409, description = "Catalog export is not ready yet", body = ErrorResponse),
+        (status = 503, description = "Catalog exports are not configured", body = ErrorResponse)
+    )
+)]
+pub async fn download_catalog_export(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<impl IntoResponse> {
+    let catalog = state.catalog_exports.download(id).await?;
+    Ok((
+        [(header::CONTENT_TYPE, "text/html; charset=utf-8")],
+        catalog,
+    ))
+}
+
+/// Point a ready export at its download route
+fn with_download(mut export: CatalogExportResponse) -> CatalogExportResponse {
+    if export.status == CatalogExportStatus::Ready {
+        export.download = Some(format!(
+            "/api/admin/catalog-exports/{}/catalog.html",
+            export.id
+        ));
+    }
+    export
+}
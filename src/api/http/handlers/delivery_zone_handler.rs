@@ -0,0 +1,120 @@
+//! Delivery Zone HTTP Handlers
+
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseDeliveryCheck, ApiResponseDeliveryZone, ApiResponseDeliveryZones,
+    CreateDeliveryZoneRequest, DeliveryCheckQuery, DeliveryCheckResponse, DeliveryZoneResponse,
+    ErrorResponse, TenantHeaders,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::i18n::t;
+
+/// List the delivery zones of a tenant
+#[utoipa::path(
+    get,
+    path = "/api/delivery-zones",
+    tag = "Delivery",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    responses(
+        (status = 200, description = "Delivery zones, by name", body = ApiResponseDeliveryZones),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn list_delivery_zones(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+) -> DomainResult<Json<ApiResponse<Vec<DeliveryZoneResponse>>>> {
+    let zones = state.delivery_zones.list(&tenant).await?;
+    Ok(Json(ApiResponse::success(zones)))
+}
+
+/// Define a delivery zone and its fee
+#[utoipa::path(
+    post,
+    path = "/api/delivery-zones",
+    tag = "Delivery",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    request_body = CreateDeliveryZoneRequest,
+    responses(
+        (status = 201, description = "Delivery zone created", body = ApiResponseDeliveryZone),
+        (status = 400, description = "Invalid name, boundary or fee", body = ErrorResponse),
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn create_delivery_zone(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Json(request): Json<CreateDeliveryZoneRequest>,
+) -> DomainResult<(StatusCode, Json<ApiResponse<DeliveryZoneResponse>>)> {
+    let zone = state.delivery_zones.create(&tenant, request).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::with_message(zone, t("delivery_zone.created"))),
+    ))
+}
+
+/// Stop delivering to a zone
+#[utoipa::path(
+    delete,
+    path = "/api/delivery-zones/{id}",
+    tag = "Delivery",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Delivery zone identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 204, description = "Delivery zone deleted"),
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Delivery zone not found", body = ErrorResponse)
+    )
+)]
+pub async fn delete_delivery_zone(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<StatusCode> {
+    state.delivery_zones.delete(&tenant, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Check whether an address is delivered to, and at what fee
+///
+/// Where zones overlap, the cheapest covering the address applies.
+#[utoipa::path(
+    get,
+    path = "/api/delivery-zones/check",
+    tag = "Delivery",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(DeliveryCheckQuery, TenantHeaders),
+    responses(
+        (status = 200, description = "Whether the address is served, and the fee", body = ApiResponseDeliveryCheck),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 422, description = "Missing or out of range coordinates", body = ErrorResponse)
+    )
+)]
+pub async fn check_delivery(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Query(query): Query<DeliveryCheckQuery>,
+) -> DomainResult<Json<ApiResponse<DeliveryCheckResponse>>> {
+    let check = state
+        .delivery_zones
+        .check(&tenant, query.lat, query.lng)
+        .await?;
+    Ok(Json(ApiResponse::success(check)))
+}
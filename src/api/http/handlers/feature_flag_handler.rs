@@ -0,0 +1,61 @@
+//! Feature Flag HTTP Handlers
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseFeatureFlag, ApiResponseFeatureFlags, ErrorResponse,
+    FeatureFlagResponse, UpdateFeatureFlagRequest,
+};
+use crate::domain::errors::DomainResult;
+use crate::i18n::t;
+
+/// List all feature flags with their effective values
+#[utoipa::path(
+    get,
+    path = "/api/admin/feature-flags",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, description = "Feature flags", body = ApiResponseFeatureFlags),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse)
+    )
+)]
+pub async fn list_feature_flags(
+    State(state): State<AppState>,
+) -> Json<ApiResponse<Vec<FeatureFlagResponse>>> {
+    Json(ApiResponse::success(state.feature_flags.list()))
+}
+
+/// Enable or disable a feature flag at runtime
+#[utoipa::path(
+    put,
+    path = "/api/admin/feature-flags/{key}",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    params(
+        ("key" = String, Path, description = "Feature flag key")
+    ),
+    request_body = UpdateFeatureFlagRequest,
+    responses(
+        (status = 200, description = "Feature flag updated", body = ApiResponseFeatureFlag),
+        (status = 400, description = "Invalid feature flag key", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse)
+    )
+)]
+pub async fn update_feature_flag(
+    State(state): State<AppState>,
+    Path(key): Path<String>,
+    Json(request): Json<UpdateFeatureFlagRequest>,
+) -> DomainResult<Json<ApiResponse<FeatureFlagResponse>>> {
+    let flag = state.feature_flags.set(&key, request).await?;
+    Ok(Json(ApiResponse::with_message(
+        flag,
+        t("feature_flag.updated"),
+    )))
+}
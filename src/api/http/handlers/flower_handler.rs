@@ -1,40 +1,128 @@
 //! Flower HTTP Handlers
 
+use std::hash::{DefaultHasher, Hash, Hasher};
+
 use axum::{
-    Json,
-    extract::{Path, Query, State},
-    http::StatusCode,
+    Extension, Json,
+    extract::{OriginalUri, Path, Query, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
 };
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationErrors};
 
+use crate::api::http::pagination::Paginated;
 use crate::api::http::state::AppState;
+use crate::application::authorization::Subject;
 use crate::application::dtos::{
-    ApiResponse, ApiResponseFlower, ApiResponsePaginatedFlower, CreateFlowerRequest, ErrorResponse,
-    FlowerResponse, ListFlowersQuery, UpdateFlowerRequest,
+    ApiResponse, ApiResponseColors, ApiResponseFlower, ApiResponseFlowerFilters,
+    ApiResponsePaginatedFlower, ApiResponsePaginatedFlowerChange, ApiResponsePriceAdjustment,
+    ApiResponseStockMovement, ApiResponseTrendingFlowers, CreateFlowerRequest, ErrorResponse,
+    FlowerChangeResponse, FlowerFiltersResponse, FlowerResponse, ListFlowersQuery, PaginationQuery,
+    PriceAdjustmentRequest, PriceAdjustmentResponse, StockAdjustmentRequest, StockMovementResponse,
+    TenantHeaders, TrendingFlowerResponse, TrendingQuery, UpdateFlowerRequest,
 };
-use crate::domain::errors::{DomainResult, AppError};
-use crate::domain::shared::Pagination;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::FlowerColor;
+use crate::domain::shared::{Pagination, TenantId};
+use crate::i18n::{Message, t};
+
+/// Flatten validator field errors into a single validation error
+fn validation_error(errors: ValidationErrors) -> AppError {
+    let mut fields: Vec<&str> = errors.field_errors().keys().copied().collect();
+    fields.sort_unstable();
+
+    AppError::validation(Message::new("validation.invalid_fields").arg("fields", fields.join(", ")))
+}
+
+/// Strong ETag of a flower as served
+///
+/// Derived from the response rather than `updated_at`: pricing rules change
+/// the price without touching the flower, including when a rule is deleted
+/// or its date range opens or closes.
+fn flower_etag(flower: &FlowerResponse) -> String {
+    let body = serde_json::to_vec(flower).expect("flower serializes to JSON");
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    format!("\"{:016x}\"", hasher.finish())
+}
+
+/// Whether the client's copy, per `If-None-Match`, is still current
+fn none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|candidate| candidate.trim() == "*" || candidate.trim() == etag)
+}
 
 /// Get a flower by ID
 #[utoipa::path(
     get,
     path = "/api/flowers/{id}",
     tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
     params(
-        ("id" = Uuid, Path, description = "Flower unique identifier")
+        ("id" = Uuid, Path, description = "Flower unique identifier"),
+        TenantHeaders
     ),
     responses(
         (status = 200, description = "Flower found", body = ApiResponseFlower),
-        (status = 404, description = "Flower not found", body = ErrorResponse)
+        (status = 304, description = "Unchanged since the ETag in If-None-Match"),
+        (status = 404, description = "Flower not found", body = ErrorResponse),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
     )
 )]
 pub async fn get_flower(
     State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Extension(subject): Extension<Subject>,
     Path(id): Path<Uuid>,
-) -> DomainResult<Json<ApiResponse<FlowerResponse>>> {
-    let flower = state.flower_usecase.get_flower(id).await?;
-    Ok(Json(ApiResponse::success(flower)))
+    headers: HeaderMap,
+) -> DomainResult<Response> {
+    let flower = state.flower_usecase.get_flower(&tenant, id).await?;
+    state.views.record(&tenant, &subject, id);
+
+    let etag = flower_etag(&flower);
+    let etag_header = [(
+        header::ETAG,
+        HeaderValue::from_str(&etag).expect("ETag is hex"),
+    )];
+    if none_match(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, etag_header).into_response());
+    }
+    Ok((etag_header, Json(ApiResponse::success(flower))).into_response())
+}
+
+/// List the most viewed flowers
+///
+/// Views are counted when a flower is fetched by ID and become visible here
+/// once flushed, every `VIEW_FLUSH_INTERVAL_SECS`.
+#[utoipa::path(
+    get,
+    path = "/api/flowers/trending",
+    tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(TrendingQuery, TenantHeaders),
+    responses(
+        (status = 200, description = "Flowers ranked by views, most viewed first", body = ApiResponseTrendingFlowers),
+        (status = 422, description = "Invalid window or limit", body = ErrorResponse),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn trending_flowers(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Query(query): Query<TrendingQuery>,
+) -> DomainResult<Json<ApiResponse<Vec<TrendingFlowerResponse>>>> {
+    let trending = state
+        .views
+        .trending(&tenant, query.window.as_deref(), query.limit)
+        .await?;
+    Ok(Json(ApiResponse::success(trending)))
 }
 
 /// List all flowers with pagination and optional filters
@@ -42,30 +130,90 @@ pub async fn get_flower(
     get,
     path = "/api/flowers",
     tag = "Flowers",
-    params(ListFlowersQuery),
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(ListFlowersQuery, TenantHeaders),
     responses(
-        (status = 200, description = "List of flowers", body = ApiResponsePaginatedFlower)
+        (status = 200, description = "List of flowers", body = ApiResponsePaginatedFlower, headers(
+            ("Link" = String, description = "Links to the first, previous, next and last page"),
+            ("X-Total-Count" = i64, description = "Number of flowers across all pages")
+        )),
+        (status = 400, description = "Unknown color, attribute or metadata filter", body = ErrorResponse),
+        (status = 422, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
     )
 )]
 pub async fn list_flowers(
     State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    OriginalUri(uri): OriginalUri,
     Query(query): Query<ListFlowersQuery>,
-) -> DomainResult<Json<ApiResponse<crate::domain::shared::PaginatedResponse<FlowerResponse>>>> {
-    let pagination = Pagination {
-        page: query.page.unwrap_or(1),
-        per_page: query.per_page.unwrap_or(10),
-    };
+) -> DomainResult<Paginated<FlowerResponse>> {
+    let pagination = Pagination::try_new(query.page, query.per_page)?;
+    let estimate = query.estimate.unwrap_or(false);
+    let attributes = query.attributes()?;
+    let metadata = query.metadata()?;
 
-    let result = if query.search.is_some() || query.color.is_some() {
+    let result = if query.search.is_some()
+        || query.color.is_some()
+        || attributes.is_some()
+        || metadata.is_some()
+    {
         state
             .flower_usecase
-            .search_flowers(query.search, query.color, pagination)
+            .search_flowers(
+                &tenant,
+                query.search,
+                query.color,
+                attributes,
+                metadata,
+                pagination,
+                estimate,
+            )
             .await?
     } else {
-        state.flower_usecase.list_flowers(pagination).await?
+        state
+            .flower_usecase
+            .list_flowers(&tenant, pagination, estimate)
+            .await?
     };
 
-    Ok(Json(ApiResponse::success(result)))
+    Ok(Paginated::new(uri, result))
+}
+
+/// List the supported flower colors
+#[utoipa::path(
+    get,
+    path = "/api/flowers/colors",
+    tag = "Flowers",
+    responses(
+        (status = 200, description = "Supported flower colors", body = ApiResponseColors),
+        (status = 401, description = "Unknown API key", body = ErrorResponse)
+    )
+)]
+pub async fn list_colors(State(state): State<AppState>) -> Json<ApiResponse<Vec<FlowerColor>>> {
+    Json(ApiResponse::success(state.flower_usecase.list_colors()))
+}
+
+/// Colors and price range of the catalog, for building filter controls
+#[utoipa::path(
+    get,
+    path = "/api/flowers/filters",
+    tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    responses(
+        (status = 200, description = "Colors in the catalog and its price range", body = ApiResponseFlowerFilters),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn flower_filters(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+) -> DomainResult<Json<ApiResponse<FlowerFiltersResponse>>> {
+    let filters = state.flower_usecase.filters(&tenant).await?;
+    Ok(Json(ApiResponse::success(filters)))
 }
 
 /// Create a new flower
@@ -73,38 +221,29 @@ pub async fn list_flowers(
     post,
     path = "/api/flowers",
     tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
     request_body = CreateFlowerRequest,
     responses(
         (status = 201, description = "Flower created successfully", body = ApiResponseFlower),
-        (status = 400, description = "Invalid request data", body = ErrorResponse)
+        (status = 400, description = "Invalid request data", body = ErrorResponse),
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 409, description = "A flower with the same name already exists", body = ErrorResponse)
     )
 )]
 pub async fn create_flower(
     State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
     Json(request): Json<CreateFlowerRequest>,
 ) -> DomainResult<(StatusCode, Json<ApiResponse<FlowerResponse>>)> {
     // Validate the request first
-    request.validate().map_err(|e| AppError::validation(
-        e.field_errors()
-            .iter()
-            .map(|(field, errors)| {
-                errors
-                    .iter()
-                    .map(|error| format!("{}: {}", field, error.message.clone().unwrap_or_else(|| "Invalid input".into())))
-                    .collect::<Vec<String>>()
-            })
-            .flatten()
-            .collect::<Vec<String>>()
-            .join(", ")
-    ))?;
-
-    let flower = state.flower_usecase.create_flower(request).await?;
+    request.validate().map_err(validation_error)?;
+
+    let flower = state.flower_usecase.create_flower(&tenant, request).await?;
     Ok((
         StatusCode::CREATED,
-        Json(ApiResponse::with_message(
-            flower,
-            "Flower created successfully",
-        )),
+        Json(ApiResponse::with_message(flower, t("flower.created"))),
     ))
 }
 
@@ -113,41 +252,134 @@ pub async fn create_flower(
     put,
     path = "/api/flowers/{id}",
     tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
     params(
-        ("id" = Uuid, Path, description = "Flower unique identifier")
+        ("id" = Uuid, Path, description = "Flower unique identifier"),
+        TenantHeaders
     ),
     request_body = UpdateFlowerRequest,
     responses(
         (status = 200, description = "Flower updated successfully", body = ApiResponseFlower),
         (status = 404, description = "Flower not found", body = ErrorResponse),
-        (status = 400, description = "Invalid request data", body = ErrorResponse)
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 400, description = "Invalid request data", body = ErrorResponse),
+        (status = 409, description = "A flower with the same name already exists", body = ErrorResponse)
     )
 )]
 pub async fn update_flower(
     State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateFlowerRequest>,
 ) -> DomainResult<Json<ApiResponse<FlowerResponse>>> {
     // Validate the request first
-    request.validate().map_err(|e| AppError::validation(
-        e.field_errors()
-            .iter()
-            .map(|(field, errors)| {
-                errors
-                    .iter()
-                    .map(|error| format!("{}: {}", field, error.message.clone().unwrap_or_else(|| "Invalid input".into())))
-                    .collect::<Vec<String>>()
-            })
-            .flatten()
-            .collect::<Vec<String>>()
-            .join(", ")
-    ))?;
-
-    let flower = state.flower_usecase.update_flower(id, request).await?;
-    Ok(Json(ApiResponse::with_message(
-        flower,
-        "Flower updated successfully",
-    )))
+    request.validate().map_err(validation_error)?;
+
+    let flower = state
+        .flower_usecase
+        .update_flower(&tenant, id, request)
+        .await?;
+    Ok(Json(ApiResponse::with_message(flower, t("flower.updated"))))
+}
+
+/// Adjust the prices of several flowers at once
+#[utoipa::path(
+    post,
+    path = "/api/flowers/price-adjustments",
+    tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    request_body = PriceAdjustmentRequest,
+    responses(
+        (status = 200, description = "Prices adjusted, or the preview of a dry run", body = ApiResponsePriceAdjustment),
+        (status = 400, description = "Invalid filter or adjustment, or a resulting price out of range", body = ErrorResponse),
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn adjust_prices(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Json(request): Json<PriceAdjustmentRequest>,
+) -> DomainResult<Json<ApiResponse<PriceAdjustmentResponse>>> {
+    let result = state.flower_usecase.adjust_prices(&tenant, request).await?;
+    let message = if result.dry_run {
+        t("flower.prices.preview")
+    } else {
+        t("flower.prices.adjusted")
+    };
+    Ok(Json(ApiResponse::with_message(result, message)))
+}
+
+/// Adjust a flower's stock
+#[utoipa::path(
+    post,
+    path = "/api/flowers/{id}/stock-adjustments",
+    tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Flower unique identifier"),
+        TenantHeaders
+    ),
+    request_body = StockAdjustmentRequest,
+    responses(
+        (status = 201, description = "Stock adjusted and movement recorded", body = ApiResponseStockMovement),
+        (status = 400, description = "Invalid delta or reason", body = ErrorResponse),
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Flower not found", body = ErrorResponse),
+        (status = 422, description = "Not enough stock to remove", body = ErrorResponse)
+    )
+)]
+pub async fn adjust_stock(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<StockAdjustmentRequest>,
+) -> DomainResult<(StatusCode, Json<ApiResponse<StockMovementResponse>>)> {
+    let movement = state
+        .flower_usecase
+        .adjust_stock(&tenant, id, request)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::with_message(movement, t("inventory.adjusted"))),
+    ))
+}
+
+/// List the changes made to a flower, oldest first
+#[utoipa::path(
+    get,
+    path = "/api/flowers/{id}/history",
+    tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Flower unique identifier"),
+        PaginationQuery,
+        TenantHeaders
+    ),
+    responses(
+        (status = 200, description = "Fields changed by updates of the flower", body = ApiResponsePaginatedFlowerChange, headers(
+            ("Link" = String, description = "Links to the first, previous, next and last page"),
+            ("X-Total-Count" = i64, description = "Number of changes across all pages")
+        )),
+        (status = 404, description = "Flower not found and without history", body = ErrorResponse),
+        (status = 422, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn flower_history(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<PaginationQuery>,
+) -> DomainResult<Paginated<FlowerChangeResponse>> {
+    let pagination = Pagination::try_new(query.page, query.per_page)?;
+    let result = state.changes.list(&tenant, id, pagination).await?;
+    Ok(Paginated::new(uri, result))
 }
 
 /// Delete a flower
@@ -155,18 +387,23 @@ pub async fn update_flower(
     delete,
     path = "/api/flowers/{id}",
     tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
     params(
-        ("id" = Uuid, Path, description = "Flower unique identifier")
+        ("id" = Uuid, Path, description = "Flower unique identifier"),
+        TenantHeaders
     ),
     responses(
         (status = 204, description = "Flower deleted successfully"),
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
         (status = 404, description = "Flower not found", body = ErrorResponse)
     )
 )]
 pub async fn delete_flower(
     State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
     Path(id): Path<Uuid>,
 ) -> DomainResult<StatusCode> {
-    state.flower_usecase.delete_flower(id).await?;
+    state.flower_usecase.delete_flower(&tenant, id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
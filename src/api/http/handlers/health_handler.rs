@@ -1,16 +1,55 @@
 //! Health Check HTTP Handlers
 
-use axum::Json;
+use axum::{Json, extract::State, http::StatusCode};
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::api::http::state::AppState;
+use crate::application::health::Readiness;
+use crate::application::ports::HealthStatus;
+
 /// Health check response
 #[derive(Debug, Serialize, ToSchema)]
 pub struct HealthResponse {
     pub status: String,
 }
 
-/// Health check endpoint
+/// Health of a single dependency
+///
+/// Why a component is not up is logged rather than returned, as the probe is
+/// public and errors may tell about the infrastructure behind it.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ComponentHealth {
+    /// Component name
+    pub name: String,
+    /// "up", "degraded" or "down"
+    pub status: String,
+    /// Time taken by the check
+    pub latency_ms: u64,
+    /// Component specific figures, such as pool usage or the number of
+    /// pending migrations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+/// Readiness check response
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "status": "ready",
+    "components": [
+        { "name": "database", "status": "up", "latency_ms": 1 },
+        { "name": "migrations", "status": "up", "latency_ms": 2, "details": { "pending": 0 } },
+        { "name": "pool", "status": "up", "latency_ms": 0, "details": { "size": 3, "idle": 2, "max_connections": 10 } },
+        { "name": "cache", "status": "up", "latency_ms": 1 }
+    ]
+}))]
+pub struct ReadinessResponse {
+    /// "ready", "degraded" or "unavailable"
+    pub status: String,
+    pub components: Vec<ComponentHealth>,
+}
+
+/// Health check endpoint (alias of the liveness probe)
 #[utoipa::path(
     get,
     path = "/health",
@@ -24,3 +63,71 @@ pub async fn health_check() -> Json<HealthResponse> {
         status: "OK".to_string(),
     })
 }
+
+/// Liveness probe: the process is up and serving requests
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "Health",
+    responses(
+        (status = 200, description = "Process is alive", body = HealthResponse)
+    )
+)]
+pub async fn liveness() -> Json<HealthResponse> {
+    health_check().await
+}
+
+/// Readiness probe: the components the service depends on work
+///
+/// Every component registered with the health registry is checked. The
+/// service is unavailable while a critical one is down and degraded, yet
+/// still taking traffic, while any other is not up.
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "Health",
+    responses(
+        (status = 200, description = "Service can take traffic, possibly degraded", body = ReadinessResponse),
+        (status = 503, description = "A critical component is down", body = ReadinessResponse)
+    )
+)]
+pub async fn readiness(State(state): State<AppState>) -> (StatusCode, Json<ReadinessResponse>) {
+    let report = state.health.check().await;
+    let readiness = report.readiness();
+    let status = match readiness {
+        Readiness::Ready | Readiness::Degraded => StatusCode::OK,
+        Readiness::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    let components = report
+        .components
+        .into_iter()
+        .map(|component| {
+            let status = component.health.status;
+            let latency_ms = component.latency.as_millis() as u64;
+            if status != HealthStatus::Up {
+                tracing::warn!(
+                    component = component.name,
+                    status = status.as_str(),
+                    latency_ms,
+                    error = component.health.error.as_deref().unwrap_or_default(),
+                    "Health check did not pass"
+                );
+            }
+            ComponentHealth {
+                name: component.name.to_string(),
+                status: status.as_str().to_string(),
+                latency_ms,
+                details: component.health.details,
+            }
+        })
+        .collect();
+
+    (
+        status,
+        Json(ReadinessResponse {
+            status: readiness.as_str().to_string(),
+            components,
+        }),
+    )
+}
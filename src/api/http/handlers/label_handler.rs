@@ -0,0 +1,112 @@
+//! Flower Label HTTP Handlers
+
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{ErrorResponse, TenantHeaders};
+use crate::application::usecases::Label;
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Labels only change with a flower's SKU or the product URL; clients
+/// revalidate with the ETag once this expires
+const LABEL_CACHE_CONTROL: &str = "private, max-age=3600";
+
+/// QR code linking to the product page of a flower
+#[utoipa::path(
+    get,
+    path = "/api/flowers/{id}/qr.png",
+    tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Flower unique identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 200, description = "QR code image", content_type = "image/png", body = Vec<u8>),
+        (status = 304, description = "Unchanged since the ETag in If-None-Match"),
+        (status = 404, description = "Flower not found", body = ErrorResponse),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn flower_qr_code(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> DomainResult<Response> {
+    let label = state.labels.qr_code(&tenant, id).await?;
+    respond_with_label(&state, &label, &headers)
+}
+
+/// Code 128 barcode of a flower's SKU, for shelf labels
+#[utoipa::path(
+    get,
+    path = "/api/flowers/{id}/barcode.png",
+    tag = "Flowers",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Flower unique identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 200, description = "Barcode image", content_type = "image/png", body = Vec<u8>),
+        (status = 304, description = "Unchanged since the ETag in If-None-Match"),
+        (status = 404, description = "Flower not found, or it has no SKU", body = ErrorResponse),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn flower_barcode(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> DomainResult<Response> {
+    let label = state.labels.barcode(&tenant, id).await?;
+    respond_with_label(&state, &label, &headers)
+}
+
+/// The rendered label, or 304 when the client already has it
+fn respond_with_label(
+    state: &AppState,
+    label: &Label,
+    headers: &HeaderMap,
+) -> DomainResult<Response> {
+    let etag = label.etag();
+    let cache_headers = [
+        (
+            header::ETAG,
+            HeaderValue::from_str(&etag).expect("ETag is hex"),
+        ),
+        (
+            header::CACHE_CONTROL,
+            HeaderValue::from_static(LABEL_CACHE_CONTROL),
+        ),
+    ];
+
+    let cached = headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|candidate| candidate.trim() == "*" || candidate.trim() == etag);
+    if cached {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+
+    let image = state.labels.render(label)?;
+    Ok((
+        cache_headers,
+        [(header::CONTENT_TYPE, HeaderValue::from_static("image/png"))],
+        image,
+    )
+        .into_response())
+}
@@ -0,0 +1,38 @@
+//! Caller HTTP Handlers
+//!
+//! What the service remembers about the caller making the request.
+
+use axum::{Extension, Json, extract::State};
+
+use crate::api::http::state::AppState;
+use crate::application::authorization::Subject;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseRecentlyViewedFlowers, ErrorResponse, RecentlyViewedFlowerResponse,
+    TenantHeaders,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Flowers the caller viewed last
+///
+/// Views show up once the instance that served them flushes its buffer.
+#[utoipa::path(
+    get,
+    path = "/api/me/recently-viewed",
+    tag = "Me",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    responses(
+        (status = 200, description = "Flowers the caller viewed, newest first", body = ApiResponseRecentlyViewedFlowers),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn recently_viewed(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Extension(subject): Extension<Subject>,
+) -> DomainResult<Json<ApiResponse<Vec<RecentlyViewedFlowerResponse>>>> {
+    let flowers = state.views.recently_viewed(&tenant, &subject).await?;
+    Ok(Json(ApiResponse::success(flowers)))
+}
@@ -0,0 +1,13 @@
+//! Prometheus Metrics HTTP Handler
+
+use axum::{extract::State, http::header, response::IntoResponse};
+
+use crate::api::http::state::AppState;
+
+/// Prometheus scrape endpoint
+pub async fn metrics(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(),
+    )
+}
@@ -1,5 +1,43 @@
+pub mod admin_handler;
+pub mod backup_handler;
+pub mod catalog_export_handler;
+pub mod delivery_zone_handler;
+pub mod feature_flag_handler;
 pub mod flower_handler;
 pub mod health_handler;
+pub mod label_handler;
+pub mod me_handler;
+pub mod metrics_handler;
+pub mod openapi_handler;
+pub mod order_handler;
+pub mod pricing_rule_handler;
+pub mod saved_search_handler;
+pub mod shipping_handler;
+pub mod store_handler;
+pub mod supplier_handler;
+pub mod task_handler;
+pub mod version_handler;
+pub mod webhook_endpoint_handler;
+pub mod webhook_handler;
 
+pub use admin_handler::*;
+pub use backup_handler::*;
+pub use catalog_export_handler::*;
+pub use delivery_zone_handler::*;
+pub use feature_flag_handler::*;
 pub use flower_handler::*;
 pub use health_handler::*;
+pub use label_handler::*;
+pub use me_handler::*;
+pub use metrics_handler::*;
+pub use openapi_handler::*;
+pub use order_handler::*;
+pub use pricing_rule_handler::*;
+pub use saved_search_handler::*;
+pub use shipping_handler::*;
+pub use store_handler::*;
+pub use supplier_handler::*;
+pub use task_handler::*;
+pub use version_handler::*;
+pub use webhook_endpoint_handler::*;
+pub use webhook_handler::*;
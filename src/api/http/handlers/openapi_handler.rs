@@ -0,0 +1,42 @@
+//! OpenAPI Document HTTP Handlers
+
+#[cfg(feature = "redoc")]
+use axum::response::Html;
+use axum::{Json, http::header, response::IntoResponse};
+
+use crate::api::http::openapi::ApiDoc;
+use crate::domain::errors::{AppError, DomainResult};
+
+/// The OpenAPI document as JSON
+pub async fn openapi_json() -> impl IntoResponse {
+    Json(ApiDoc::document())
+}
+
+/// The OpenAPI document as YAML
+pub async fn openapi_yaml() -> DomainResult<impl IntoResponse> {
+    let yaml = ApiDoc::document_yaml()
+        .map_err(|e| AppError::internal(format!("Failed to render OpenAPI YAML: {}", e)))?;
+    Ok(([(header::CONTENT_TYPE, "application/yaml")], yaml))
+}
+
+/// Redoc page rendering `/openapi.json`; the viewer itself is loaded from
+/// its CDN
+#[cfg(feature = "redoc")]
+pub async fn redoc() -> Html<&'static str> {
+    Html(REDOC_PAGE)
+}
+
+#[cfg(feature = "redoc")]
+const REDOC_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>Flower API</title>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+  </head>
+  <body>
+    <redoc spec-url="/openapi.json"></redoc>
+    <script src="https://cdn.redoc.ly/redoc/latest/bundles/redoc.standalone.js"></script>
+  </body>
+</html>
+"#;
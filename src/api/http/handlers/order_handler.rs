@@ -0,0 +1,170 @@
+//! Order HTTP Handlers
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseOrder, ApiResponseOrderEvents, CreateOrderRequest, ErrorResponse,
+    OrderEventResponse, OrderResponse, TenantHeaders, UpdateOrderStatusRequest,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::i18n::t;
+
+/// Place an order, reserving the stock of its flowers
+///
+/// A delivery must name one of the rates `GET /api/shipping/rates` quotes
+/// for the destination; it is quoted again, and the order records the fee
+/// of that quote.
+#[utoipa::path(
+    post,
+    path = "/api/orders",
+    tag = "Orders",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    request_body = CreateOrderRequest,
+    responses(
+        (status = 201, description = "Order placed and pending payment", body = ApiResponseOrder),
+        (status = 400, description = "No lines, too many, a repeated flower, an invalid quantity or a carrier service not quoted", body = ErrorResponse),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Flower not found", body = ErrorResponse),
+        (status = 422, description = "Not enough stock", body = ErrorResponse),
+        (status = 503, description = "No shipping rate provider could quote the delivery", body = ErrorResponse)
+    )
+)]
+pub async fn create_order(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Json(request): Json<CreateOrderRequest>,
+) -> DomainResult<(StatusCode, Json<ApiResponse<OrderResponse>>)> {
+    let order = state.orders.place(&tenant, request).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::with_message(order, t("order.placed"))),
+    ))
+}
+
+/// Get an order
+#[utoipa::path(
+    get,
+    path = "/api/orders/{id}",
+    tag = "Orders",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Order identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 200, description = "Order found", body = ApiResponseOrder),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Order not found", body = ErrorResponse)
+    )
+)]
+pub async fn get_order(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<Json<ApiResponse<OrderResponse>>> {
+    let order = state.orders.get(&tenant, id).await?;
+    Ok(Json(ApiResponse::success(order)))
+}
+
+/// Status history of an order
+#[utoipa::path(
+    get,
+    path = "/api/orders/{id}/events",
+    tag = "Orders",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Order identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 200, description = "Status changes, oldest first", body = ApiResponseOrderEvents),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Order not found", body = ErrorResponse)
+    )
+)]
+pub async fn order_events(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<Json<ApiResponse<Vec<OrderEventResponse>>>> {
+    let events = state.orders.events(&tenant, id).await?;
+    Ok(Json(ApiResponse::success(events)))
+}
+
+/// Move an order along fulfilment
+///
+/// Orders go pending → paid → packed → shipped → delivered, one step at a
+/// time; `POST /api/orders/{id}/cancel` cancels them.
+#[utoipa::path(
+    put,
+    path = "/api/orders/{id}/status",
+    tag = "Orders",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Order identifier"),
+        TenantHeaders
+    ),
+    request_body = UpdateOrderStatusRequest,
+    responses(
+        (status = 200, description = "Status changed", body = ApiResponseOrder),
+        (status = 400, description = "Unknown status, or one that cannot be set directly", body = ErrorResponse),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Order not found", body = ErrorResponse),
+        (status = 409, description = "The order cannot reach that status from its current one", body = ErrorResponse)
+    )
+)]
+pub async fn update_order_status(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateOrderStatusRequest>,
+) -> DomainResult<Json<ApiResponse<OrderResponse>>> {
+    let order = state.orders.update_status(&tenant, id, request).await?;
+    Ok(Json(ApiResponse::with_message(
+        order,
+        t("order.status.updated"),
+    )))
+}
+
+/// Cancel an order that has not shipped
+///
+/// Its flowers go back in stock. A paid order is refunded through the
+/// payment gateway, less the restocking fee (`RESTOCKING_FEE_PERCENT` of the
+/// flowers' price).
+#[utoipa::path(
+    post,
+    path = "/api/orders/{id}/cancel",
+    tag = "Orders",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Order identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 200, description = "Order cancelled, with its refund when it was paid", body = ApiResponseOrder),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Order not found", body = ErrorResponse),
+        (status = 409, description = "The order already shipped, or was cancelled", body = ErrorResponse)
+    )
+)]
+pub async fn cancel_order(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<Json<ApiResponse<OrderResponse>>> {
+    let order = state.orders.cancel(&tenant, id).await?;
+    Ok(Json(ApiResponse::with_message(order, t("order.cancelled"))))
+}
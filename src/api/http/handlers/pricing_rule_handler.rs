@@ -0,0 +1,158 @@
+//! Pricing Rule HTTP Handlers
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponsePricingRule, ApiResponsePricingRules, ErrorResponse,
+    PricingRuleRequest, PricingRuleResponse,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::i18n::t;
+
+/// List the pricing rules of a tenant
+#[utoipa::path(
+    get,
+    path = "/api/admin/tenants/{tenant}/pricing-rules",
+    tag = "Pricing",
+    security(("admin_token" = [])),
+    params(("tenant" = String, Path, description = "Tenant the rules price flowers of")),
+    responses(
+        (status = 200, description = "Pricing rules, by name", body = ApiResponsePricingRules),
+        (status = 400, description = "Invalid tenant", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse)
+    )
+)]
+pub async fn list_pricing_rules(
+    State(state): State<AppState>,
+    Path(tenant): Path<String>,
+) -> DomainResult<Json<ApiResponse<Vec<PricingRuleResponse>>>> {
+    let tenant = TenantId::new(tenant)?;
+    let rules = state.pricing.list(&tenant).await?;
+    Ok(Json(ApiResponse::success(rules)))
+}
+
+/// Define a pricing rule
+///
+/// While its conditions hold, the rule adjusts the price flowers are served
+/// and ordered at by a percent of their base price. The adjustments of every
+/// rule applying to a flower add up; the base price itself is never changed.
+#[utoipa::path(
+    post,
+    path = "/api/admin/tenants/{tenant}/pricing-rules",
+    tag = "Pricing",
+    security(("admin_token" = [])),
+    params(("tenant" = String, Path, description = "Tenant the rule prices flowers of")),
+    request_body = PricingRuleRequest,
+    responses(
+        (status = 201, description = "Pricing rule created", body = ApiResponsePricingRule),
+        (status = 400, description = "Invalid tenant, name, adjustment or conditions", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse)
+    )
+)]
+pub async fn create_pricing_rule(
+    State(state): State<AppState>,
+    Path(tenant): Path<String>,
+    Json(request): Json<PricingRuleRequest>,
+) -> DomainResult<(StatusCode, Json<ApiResponse<PricingRuleResponse>>)> {
+    let tenant = TenantId::new(tenant)?;
+    let rule = state.pricing.create(&tenant, request).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::with_message(rule, t("pricing_rule.created"))),
+    ))
+}
+
+/// Get a pricing rule
+#[utoipa::path(
+    get,
+    path = "/api/admin/tenants/{tenant}/pricing-rules/{id}",
+    tag = "Pricing",
+    security(("admin_token" = [])),
+    params(
+        ("tenant" = String, Path, description = "Tenant of the rule"),
+        ("id" = Uuid, Path, description = "Pricing rule identifier")
+    ),
+    responses(
+        (status = 200, description = "Pricing rule found", body = ApiResponsePricingRule),
+        (status = 400, description = "Invalid tenant", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 404, description = "Pricing rule not found", body = ErrorResponse)
+    )
+)]
+pub async fn get_pricing_rule(
+    State(state): State<AppState>,
+    Path((tenant, id)): Path<(String, Uuid)>,
+) -> DomainResult<Json<ApiResponse<PricingRuleResponse>>> {
+    let tenant = TenantId::new(tenant)?;
+    let rule = state.pricing.get(&tenant, id).await?;
+    Ok(Json(ApiResponse::success(rule)))
+}
+
+/// Replace a pricing rule
+#[utoipa::path(
+    put,
+    path = "/api/admin/tenants/{tenant}/pricing-rules/{id}",
+    tag = "Pricing",
+    security(("admin_token" = [])),
+    params(
+        ("tenant" = String, Path, description = "Tenant of the rule"),
+        ("id" = Uuid, Path, description = "Pricing rule identifier")
+    ),
+    request_body = PricingRuleRequest,
+    responses(
+        (status = 200, description = "Pricing rule updated", body = ApiResponsePricingRule),
+        (status = 400, description = "Invalid tenant, name, adjustment or conditions", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 404, description = "Pricing rule not found", body = ErrorResponse)
+    )
+)]
+pub async fn update_pricing_rule(
+    State(state): State<AppState>,
+    Path((tenant, id)): Path<(String, Uuid)>,
+    Json(request): Json<PricingRuleRequest>,
+) -> DomainResult<Json<ApiResponse<PricingRuleResponse>>> {
+    let tenant = TenantId::new(tenant)?;
+    let rule = state.pricing.update(&tenant, id, request).await?;
+    Ok(Json(ApiResponse::with_message(
+        rule,
+        t("pricing_rule.updated"),
+    )))
+}
+
+/// Delete a pricing rule; prices it adjusted go back to the base price
+#[utoipa::path(
+    delete,
+    path = "/api/admin/tenants/{tenant}/pricing-rules/{id}",
+    tag = "Pricing",
+    security(("admin_token" = [])),
+    params(
+        ("tenant" = String, Path, description = "Tenant of the rule"),
+        ("id" = Uuid, Path, description = "Pricing rule identifier")
+    ),
+    responses(
+        (status = 204, description = "Pricing rule deleted"),
+        (status = 400, description = "Invalid tenant", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 404, description = "Pricing rule not found", body = ErrorResponse)
+    )
+)]
+pub async fn delete_pricing_rule(
+    State(state): State<AppState>,
+    Path((tenant, id)): Path<(String, Uuid)>,
+) -> DomainResult<StatusCode> {
+    let tenant = TenantId::new(tenant)?;
+    state.pricing.delete(&tenant, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,98 @@
+//! Saved Search HTTP Handlers
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::api::http::state::AppState;
+use crate::application::authorization::Subject;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseSavedSearch, ApiResponseSavedSearches, ErrorResponse,
+    SaveSearchRequest, SavedSearchResponse, TenantHeaders,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::i18n::t;
+
+/// Saved searches of the caller
+#[utoipa::path(
+    get,
+    path = "/api/saved-searches",
+    tag = "Saved Searches",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    responses(
+        (status = 200, description = "Searches the caller saved, oldest first", body = ApiResponseSavedSearches),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn list_saved_searches(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Extension(subject): Extension<Subject>,
+) -> DomainResult<Json<ApiResponse<Vec<SavedSearchResponse>>>> {
+    let searches = state.saved_searches.list(&tenant, &subject).await?;
+    Ok(Json(ApiResponse::success(searches)))
+}
+
+/// Save a search, optionally alerting an address about new matching flowers
+#[utoipa::path(
+    post,
+    path = "/api/saved-searches",
+    tag = "Saved Searches",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    request_body = SaveSearchRequest,
+    responses(
+        (status = 201, description = "Search saved", body = ApiResponseSavedSearch),
+        (status = 400, description = "Invalid criteria, name or alert address", body = ErrorResponse),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn create_saved_search(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Extension(subject): Extension<Subject>,
+    Json(request): Json<SaveSearchRequest>,
+) -> DomainResult<(StatusCode, Json<ApiResponse<SavedSearchResponse>>)> {
+    let search = state
+        .saved_searches
+        .create(&tenant, &subject, request)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::with_message(search, t("saved_search.created"))),
+    ))
+}
+
+/// Delete a saved search, and with it its alerts
+#[utoipa::path(
+    delete,
+    path = "/api/saved-searches/{id}",
+    tag = "Saved Searches",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Saved search identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 204, description = "Saved search deleted"),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "The caller has no saved search with this ID", body = ErrorResponse)
+    )
+)]
+pub async fn delete_saved_search(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Extension(subject): Extension<Subject>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<StatusCode> {
+    state.saved_searches.delete(&tenant, &subject, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
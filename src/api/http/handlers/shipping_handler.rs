@@ -0,0 +1,44 @@
+//! Shipping HTTP Handlers
+
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+};
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseShippingRates, ErrorResponse, ShippingQuoteQuery, ShippingRateResponse,
+    TenantHeaders,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Quote delivering a parcel to an address
+///
+/// Rates of every configured carrier, cheapest first; carriers that cannot
+/// be reached are left out.
+#[utoipa::path(
+    get,
+    path = "/api/shipping/rates",
+    tag = "Delivery",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(ShippingQuoteQuery, TenantHeaders),
+    responses(
+        (status = 200, description = "Rates able to deliver the parcel, cheapest first", body = ApiResponseShippingRates),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 422, description = "Missing or out of range coordinates or weight", body = ErrorResponse),
+        (status = 503, description = "No carrier could be reached", body = ErrorResponse)
+    )
+)]
+pub async fn shipping_rates(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Query(query): Query<ShippingQuoteQuery>,
+) -> DomainResult<Json<ApiResponse<Vec<ShippingRateResponse>>>> {
+    let rates = state
+        .shipping
+        .quote(&tenant, query.lat, query.lng, query.weight_grams)
+        .await?;
+    Ok(Json(ApiResponse::success(rates)))
+}
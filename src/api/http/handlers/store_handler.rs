@@ -0,0 +1,158 @@
+//! Store HTTP Handlers
+
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseStore, ApiResponseStoreAvailability, ApiResponseStoreStock,
+    ApiResponseStores, AvailabilityQuery, CreateStoreRequest, ErrorResponse, SetStoreStockRequest,
+    StoreAvailabilityResponse, StoreResponse, StoreStockResponse, TenantHeaders,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::i18n::t;
+
+/// List the stores of a tenant
+#[utoipa::path(
+    get,
+    path = "/api/stores",
+    tag = "Stores",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    responses(
+        (status = 200, description = "Stores, by name", body = ApiResponseStores),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn list_stores(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+) -> DomainResult<Json<ApiResponse<Vec<StoreResponse>>>> {
+    let stores = state.stores.list(&tenant).await?;
+    Ok(Json(ApiResponse::success(stores)))
+}
+
+/// Open a store
+#[utoipa::path(
+    post,
+    path = "/api/stores",
+    tag = "Stores",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    request_body = CreateStoreRequest,
+    responses(
+        (status = 201, description = "Store created", body = ApiResponseStore),
+        (status = 400, description = "Invalid name, address or coordinates", body = ErrorResponse),
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn create_store(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Json(request): Json<CreateStoreRequest>,
+) -> DomainResult<(StatusCode, Json<ApiResponse<StoreResponse>>)> {
+    let store = state.stores.create(&tenant, request).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::with_message(store, t("store.created"))),
+    ))
+}
+
+/// Close a store, releasing the stock it held
+#[utoipa::path(
+    delete,
+    path = "/api/stores/{id}",
+    tag = "Stores",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Store identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 204, description = "Store deleted"),
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Store not found", body = ErrorResponse)
+    )
+)]
+pub async fn delete_store(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<StatusCode> {
+    state.stores.delete(&tenant, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Set how many of a flower's stock a store holds
+#[utoipa::path(
+    put,
+    path = "/api/stores/{id}/stock/{flower_id}",
+    tag = "Stores",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Store identifier"),
+        ("flower_id" = Uuid, Path, description = "Flower identifier"),
+        TenantHeaders
+    ),
+    request_body = SetStoreStockRequest,
+    responses(
+        (status = 200, description = "Stock assigned to the store", body = ApiResponseStoreStock),
+        (status = 400, description = "Negative quantity, or more than the flower has left for this store", body = ErrorResponse),
+        (status = 401, description = "Anonymous writes are disabled, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Store or flower not found", body = ErrorResponse)
+    )
+)]
+pub async fn set_store_stock(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path((id, flower_id)): Path<(Uuid, Uuid)>,
+    Json(request): Json<SetStoreStockRequest>,
+) -> DomainResult<Json<ApiResponse<StoreStockResponse>>> {
+    let stock = state
+        .stores
+        .set_stock(&tenant, id, flower_id, request)
+        .await?;
+    Ok(Json(ApiResponse::success(stock)))
+}
+
+/// Stores nearest to a location with a flower in stock, for picking up in
+/// store
+#[utoipa::path(
+    get,
+    path = "/api/flowers/{id}/availability",
+    tag = "Stores",
+    security((), ("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Flower unique identifier"),
+        AvailabilityQuery,
+        TenantHeaders
+    ),
+    responses(
+        (status = 200, description = "Stores holding the flower, nearest first", body = ApiResponseStoreAvailability),
+        (status = 404, description = "Flower not found", body = ErrorResponse),
+        (status = 422, description = "Missing or invalid location, or invalid limit", body = ErrorResponse),
+        (status = 401, description = "Unknown API key", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn flower_availability(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<AvailabilityQuery>,
+) -> DomainResult<Json<ApiResponse<Vec<StoreAvailabilityResponse>>>> {
+    let available = state
+        .stores
+        .availability(&tenant, id, query.lat, query.lng, query.limit)
+        .await?;
+    Ok(Json(ApiResponse::success(available)))
+}
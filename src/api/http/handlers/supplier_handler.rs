@@ -0,0 +1,44 @@
+//! Supplier HTTP Handlers
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseSupplierSync, ErrorResponse, SupplierSyncResponse,
+};
+use crate::domain::errors::DomainResult;
+use crate::i18n::t;
+
+/// Synchronize the catalog with a supplier's feed now
+///
+/// Products are upserted by SKU into the supplier's tenant. Entries that
+/// cannot be read or are invalid are skipped and listed in the report.
+#[utoipa::path(
+    post,
+    path = "/api/admin/sync/suppliers/{id}",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    params(
+        ("id" = String, Path, description = "Supplier ID, as configured in SUPPLIER_FEEDS")
+    ),
+    responses(
+        (status = 200, description = "Feed synchronized", body = ApiResponseSupplierSync),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 404, description = "Supplier not configured", body = ErrorResponse),
+        (status = 503, description = "Supplier feed could not be read", body = ErrorResponse)
+    )
+)]
+pub async fn sync_supplier(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> DomainResult<Json<ApiResponse<SupplierSyncResponse>>> {
+    let report = state.suppliers.sync(&id).await?;
+    Ok(Json(ApiResponse::with_message(
+        report,
+        t("supplier.synced"),
+    )))
+}
@@ -0,0 +1,38 @@
+//! Task Queue HTTP Handlers
+
+use axum::extract::{OriginalUri, Query, State};
+
+use crate::api::http::pagination::Paginated;
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponsePaginatedFailedTask, ErrorResponse, FailedTaskResponse, PaginationQuery,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::Pagination;
+
+/// List tasks that exhausted their attempts
+#[utoipa::path(
+    get,
+    path = "/api/admin/tasks/failed",
+    tag = "Admin",
+    security(("admin_token" = [])),
+    params(PaginationQuery),
+    responses(
+        (status = 200, description = "Dead-lettered tasks", body = ApiResponsePaginatedFailedTask, headers(
+            ("Link" = String, description = "Links to the first, previous, next and last page"),
+            ("X-Total-Count" = i64, description = "Number of tasks across all pages")
+        )),
+        (status = 401, description = "Missing or invalid admin token", body = ErrorResponse),
+        (status = 403, description = "Credentials other than the admin token", body = ErrorResponse),
+        (status = 422, description = "Invalid pagination parameters", body = ErrorResponse)
+    )
+)]
+pub async fn list_failed_tasks(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<PaginationQuery>,
+) -> DomainResult<Paginated<FailedTaskResponse>> {
+    let pagination = Pagination::try_new(query.page, query.per_page)?;
+    let result = state.tasks.list_failed(pagination).await?;
+    Ok(Paginated::new(uri, result))
+}
@@ -0,0 +1,18 @@
+//! Version HTTP Handler
+
+use axum::Json;
+
+use crate::infrastructure::build_info::BuildInfo;
+
+/// Build information of the running service
+#[utoipa::path(
+    get,
+    path = "/version",
+    tag = "Health",
+    responses(
+        (status = 200, description = "Build information", body = BuildInfo)
+    )
+)]
+pub async fn version() -> Json<BuildInfo> {
+    Json(BuildInfo::current())
+}
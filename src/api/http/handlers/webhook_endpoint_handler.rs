@@ -0,0 +1,167 @@
+//! Webhook Endpoint HTTP Handlers
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::api::http::state::AppState;
+use crate::application::dtos::{
+    ApiResponse, ApiResponseWebhookEndpoint, ApiResponseWebhookEndpoints, ApiResponseWebhookTest,
+    CreateWebhookEndpointRequest, ErrorResponse, TenantHeaders, WebhookEndpointResponse,
+    WebhookTestResponse,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::i18n::t;
+
+/// List the webhook endpoints of a tenant
+///
+/// Secrets are not listed; they are only returned when an endpoint is
+/// registered and when its secret is rotated.
+#[utoipa::path(
+    get,
+    path = "/api/webhook-endpoints",
+    tag = "Webhooks",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    responses(
+        (status = 200, description = "Webhook endpoints, oldest first", body = ApiResponseWebhookEndpoints),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse)
+    )
+)]
+pub async fn list_webhook_endpoints(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+) -> DomainResult<Json<ApiResponse<Vec<WebhookEndpointResponse>>>> {
+    let endpoints = state.webhooks.list(&tenant).await?;
+    Ok(Json(ApiResponse::success(endpoints)))
+}
+
+/// Register an endpoint the tenant's events are POSTed to
+///
+/// Deliveries are signed like the payment provider's callbacks: an
+/// HMAC-SHA256 of `{id}.{timestamp}.{body}` with the endpoint's secret, hex
+/// encoded in `X-Signature`, with the id in `X-Webhook-Id` and the Unix
+/// timestamp in `X-Webhook-Timestamp`. Keep the secret from the response; it
+/// is not shown again.
+#[utoipa::path(
+    post,
+    path = "/api/webhook-endpoints",
+    tag = "Webhooks",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(TenantHeaders),
+    request_body = CreateWebhookEndpointRequest,
+    responses(
+        (status = 201, description = "Endpoint registered, with its secret", body = ApiResponseWebhookEndpoint),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 422, description = "Not an http or https URL, or one leading into a private network", body = ErrorResponse)
+    )
+)]
+pub async fn create_webhook_endpoint(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Json(request): Json<CreateWebhookEndpointRequest>,
+) -> DomainResult<(StatusCode, Json<ApiResponse<WebhookEndpointResponse>>)> {
+    let endpoint = state.webhooks.create(&tenant, request).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::with_message(
+            endpoint,
+            t("webhook_endpoint.created"),
+        )),
+    ))
+}
+
+/// Stop delivering events to an endpoint
+#[utoipa::path(
+    delete,
+    path = "/api/webhook-endpoints/{id}",
+    tag = "Webhooks",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Webhook endpoint identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 204, description = "Endpoint deleted"),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Webhook endpoint not found", body = ErrorResponse)
+    )
+)]
+pub async fn delete_webhook_endpoint(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<StatusCode> {
+    state.webhooks.delete(&tenant, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Replace the secret of an endpoint
+///
+/// Deliveries are signed with both the new and the previous secret, comma
+/// separated in `X-Signature`, until `previous_secret_expires_at`
+/// (WEBHOOK_SECRET_ROTATION_SECS), so the receiver keeps accepting them
+/// while it switches to the new secret.
+#[utoipa::path(
+    post,
+    path = "/api/webhook-endpoints/{id}/rotate-secret",
+    tag = "Webhooks",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Webhook endpoint identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 200, description = "Secret rotated, with the new secret", body = ApiResponseWebhookEndpoint),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Webhook endpoint not found", body = ErrorResponse)
+    )
+)]
+pub async fn rotate_webhook_secret(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<Json<ApiResponse<WebhookEndpointResponse>>> {
+    let endpoint = state.webhooks.rotate_secret(&tenant, id).await?;
+    Ok(Json(ApiResponse::with_message(
+        endpoint,
+        t("webhook_endpoint.secret_rotated"),
+    )))
+}
+
+/// Send a signed `webhook.test` event to an endpoint, to check the receiver
+///
+/// The event is sent right away and not retried; the response only tells
+/// whether the receiver took it.
+#[utoipa::path(
+    post,
+    path = "/api/webhook-endpoints/{id}/test",
+    tag = "Webhooks",
+    security(("api_key" = []), ("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Webhook endpoint identifier"),
+        TenantHeaders
+    ),
+    responses(
+        (status = 200, description = "Test delivery sent, and whether the receiver took it", body = ApiResponseWebhookTest),
+        (status = 401, description = "Missing credentials, or the API key is unknown", body = ErrorResponse),
+        (status = 403, description = "API key belongs to another tenant", body = ErrorResponse),
+        (status = 404, description = "Webhook endpoint not found", body = ErrorResponse)
+    )
+)]
+pub async fn test_webhook_endpoint(
+    State(state): State<AppState>,
+    Extension(tenant): Extension<TenantId>,
+    Path(id): Path<Uuid>,
+) -> DomainResult<Json<ApiResponse<WebhookTestResponse>>> {
+    let report = state.webhooks.send_test(&tenant, id).await?;
+    Ok(Json(ApiResponse::success(report)))
+}
@@ -0,0 +1,73 @@
+//! Webhook HTTP Handlers
+//!
+//! Callbacks of integrations, authenticated by the signature of their body
+//! rather than by credentials, see `SignedWebhook`.
+
+use axum::{Json, extract::State};
+
+use crate::api::http::state::AppState;
+use crate::api::http::webhook::{PaymentProvider, SignedWebhook};
+use crate::application::dtos::{
+    ApiResponse, ApiResponseOrder, ErrorResponse, OrderResponse, PaymentEventRequest,
+    PaymentEventType,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Payment provider callback reporting the payment of an order
+///
+/// The body must be signed with a secret of PAYMENT_WEBHOOK_SECRETS: an
+/// HMAC-SHA256 of `{X-Webhook-Id}.{X-Webhook-Timestamp}.{body}`, hex encoded
+/// in `X-Signature`. Signatures older than WEBHOOK_TOLERANCE_SECS are
+/// refused, and so is a signed request sent twice. A successful payment
+/// marks a pending order paid; reporting it again changes nothing.
+#[utoipa::path(
+    post,
+    path = "/api/webhooks/payments",
+    tag = "Webhooks",
+    params(
+        ("X-Webhook-Id" = String, Header, description = "Delivery id, kept across retries"),
+        ("X-Webhook-Timestamp" = i64, Header, description = "When the delivery was signed, in seconds since the Unix epoch"),
+        ("X-Signature" = String, Header, description = "Hex encoded HMAC-SHA256 signatures, comma separated")
+    ),
+    request_body = PaymentEventRequest,
+    responses(
+        (status = 200, description = "Event handled; the order as it now is", body = ApiResponseOrder),
+        (status = 400, description = "Invalid tenant, delivery id or body", body = ErrorResponse),
+        (status = 401, description = "Missing, invalid or expired signature", body = ErrorResponse),
+        (status = 404, description = "Order not found", body = ErrorResponse),
+        (status = 409, description = "Delivery already accepted, or the order was cancelled", body = ErrorResponse),
+        (status = 503, description = "No PAYMENT_WEBHOOK_SECRETS configured", body = ErrorResponse)
+    )
+)]
+pub async fn payment_webhook(
+    State(state): State<AppState>,
+    webhook: SignedWebhook<PaymentProvider, PaymentEventRequest>,
+) -> DomainResult<Json<ApiResponse<OrderResponse>>> {
+    let event = webhook.payload;
+    let tenant = TenantId::new(event.tenant_id)?;
+    let order = match event.event_type {
+        PaymentEventType::Succeeded => {
+            state
+                .orders
+                .record_payment(
+                    &tenant,
+                    event.order_id,
+                    &event.reference,
+                    "webhook:payments",
+                )
+                .await?
+        }
+        PaymentEventType::Failed => {
+            tracing::warn!(
+                order_id = %event.order_id,
+                reference = %event.reference,
+                delivery = %webhook.id,
+                "Payment failed; the order stays pending"
+            );
+            state.orders.get(&tenant, event.order_id).await?
+        }
+        PaymentEventType::Other => state.orders.get(&tenant, event.order_id).await?,
+    };
+    Ok(Json(ApiResponse::success(order)))
+}
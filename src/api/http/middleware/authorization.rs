@@ -0,0 +1,212 @@
+//! Authentication and authorization
+//!
+//! `authenticate` identifies the caller of every API request; `authorize`,
+//! added to each route with the action and resource it declares, asks the
+//! policy whether that caller may proceed.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::header::AUTHORIZATION,
+    middleware::Next,
+    response::Response,
+};
+
+use super::tenant::API_KEY_HEADER;
+use crate::application::authorization::{
+    Action, DefaultPolicy, Policy, Resource, ResourceKind, Subject,
+};
+use crate::application::context::RequestContext;
+use crate::domain::errors::AppError;
+use crate::domain::shared::TenantId;
+use crate::i18n::Message;
+use crate::infrastructure::config::AppConfig;
+use crate::infrastructure::error_reporting;
+
+/// Bearer token of the admin; without one nobody is admin
+#[derive(Clone)]
+pub struct AdminToken(Option<Arc<str>>);
+
+impl AdminToken {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self(config.admin_token.as_deref().map(Arc::from))
+    }
+
+    fn matches(&self, candidate: &str) -> bool {
+        let Some(expected) = &self.0 else {
+            return false;
+        };
+
+        // Compare in constant time so the token cannot be guessed byte by byte
+        expected.len() == candidate.len()
+            && expected
+                .bytes()
+                .zip(candidate.bytes())
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+/// Credentials a caller can present
+#[derive(Clone)]
+pub struct Authenticator {
+    admin_token: AdminToken,
+    api_keys: Arc<HashMap<String, TenantId>>,
+}
+
+impl Authenticator {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            admin_token: AdminToken::from_config(config),
+            api_keys: Arc::new(config.tenant_api_keys.clone()),
+        }
+    }
+}
+
+/// Identify the caller and hand it to later layers as an `Extension<Subject>`,
+/// and to use cases as part of the current `RequestContext`
+///
+/// `Authorization: Bearer <ADMIN_TOKEN>` makes the admin and a known
+/// `X-Api-Key` its tenant; anyone else is anonymous. An unknown API key is
+/// rejected rather than treated as anonymous.
+pub async fn authenticate(
+    State(authenticator): State<Authenticator>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let headers = request.headers();
+    let bearer = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim);
+    let api_key = headers
+        .get(&API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    let subject = if bearer.is_some_and(|token| authenticator.admin_token.matches(token)) {
+        Subject::Admin
+    } else if let Some(api_key) = api_key {
+        let tenant = authenticator
+            .api_keys
+            .get(api_key)
+            .cloned()
+            .ok_or_else(|| AppError::unauthorized(Message::new("tenant.api_key.invalid")))?;
+        Subject::Tenant(tenant)
+    } else {
+        Subject::Anonymous
+    };
+
+    error_reporting::set_user(&subject.to_string());
+    if let Subject::Tenant(tenant) = &subject {
+        error_reporting::set_tenant(tenant.as_str());
+    }
+
+    let context = RequestContext::new(subject.clone());
+    request.extensions_mut().insert(subject);
+    request.extensions_mut().insert(context.clone());
+    Ok(context.scope(next.run(request)).await)
+}
+
+/// Reject every caller but the admin, whatever the rules of the routes
+///
+/// Layered over the whole admin router, so a route added there with a laxer
+/// rule still stays admin only.
+pub async fn require_admin(request: Request, next: Next) -> Result<Response, AppError> {
+    match request.extensions().get::<Subject>() {
+        Some(Subject::Admin) => Ok(next.run(request).await),
+        Some(Subject::Tenant(_)) => Err(AppError::forbidden(Message::new("error.forbidden"))),
+        _ => Err(AppError::unauthorized(Message::new("error.unauthorized"))),
+    }
+}
+
+/// Policy shared by all routes
+#[derive(Clone)]
+pub struct Access {
+    policy: Arc<dyn Policy>,
+}
+
+impl Access {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            policy: Arc::new(DefaultPolicy {
+                anonymous_writes: config.anonymous_writes,
+            }),
+        }
+    }
+
+    /// Rule for a route performing `action` on resources of `kind`
+    pub fn rule(&self, action: Action, kind: ResourceKind) -> Rule {
+        Rule {
+            policy: self.policy.clone(),
+            action,
+            kind,
+        }
+    }
+}
+
+/// Action and resource declared by a route
+#[derive(Clone)]
+pub struct Rule {
+    policy: Arc<dyn Policy>,
+    action: Action,
+    kind: ResourceKind,
+}
+
+/// Reject callers the policy does not allow: 401 for anonymous ones, who
+/// might succeed with credentials, 403 for the rest
+pub async fn authorize(
+    State(rule): State<Rule>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let subject = request
+        .extensions()
+        .get::<Subject>()
+        .cloned()
+        .unwrap_or(Subject::Anonymous);
+    let resource = match rule.kind {
+        ResourceKind::Flowers => Resource::Flowers(resolved_tenant(&request)?),
+        ResourceKind::SavedSearches => Resource::SavedSearches(resolved_tenant(&request)?),
+        ResourceKind::RecentlyViewed => Resource::RecentlyViewed(resolved_tenant(&request)?),
+        ResourceKind::Stores => Resource::Stores(resolved_tenant(&request)?),
+        ResourceKind::DeliveryZones => Resource::DeliveryZones(resolved_tenant(&request)?),
+        ResourceKind::Orders => Resource::Orders(resolved_tenant(&request)?),
+        ResourceKind::WebhookEndpoints => Resource::WebhookEndpoints(resolved_tenant(&request)?),
+        ResourceKind::FeatureFlags => Resource::FeatureFlags,
+        ResourceKind::Tasks => Resource::Tasks,
+        ResourceKind::Backups => Resource::Backups,
+        ResourceKind::CatalogExports => Resource::CatalogExports,
+        ResourceKind::Suppliers => Resource::Suppliers,
+        ResourceKind::Ledger => Resource::Ledger,
+        ResourceKind::FlowerPurge => Resource::FlowerPurge,
+        ResourceKind::Jobs => Resource::Jobs,
+        ResourceKind::Cache => Resource::Cache,
+        ResourceKind::Database => Resource::Database,
+        ResourceKind::PricingRules => Resource::PricingRules,
+        ResourceKind::Usage => Resource::Usage,
+    };
+
+    if rule.policy.allows(&subject, rule.action, &resource) {
+        return Ok(next.run(request).await);
+    }
+
+    tracing::debug!(?subject, action = ?rule.action, resource = %rule.kind, "Access denied");
+    match subject {
+        Subject::Anonymous => Err(AppError::unauthorized(Message::new("error.unauthorized"))),
+        _ => Err(AppError::forbidden(Message::new("error.forbidden"))),
+    }
+}
+
+/// Tenant of a route scoped to one, resolved by an earlier layer
+fn resolved_tenant(request: &Request) -> Result<TenantId, AppError> {
+    request
+        .extensions()
+        .get::<TenantId>()
+        .cloned()
+        .ok_or_else(|| AppError::internal("Tenant must be resolved before authorizing"))
+}
@@ -0,0 +1,57 @@
+//! Response compression
+
+use std::sync::Arc;
+
+use axum::body::HttpBody;
+use axum::http::{Response, header::CONTENT_TYPE};
+use tower_http::compression::{
+    CompressionLayer, Predicate,
+    predicate::{And, DefaultPredicate, SizeAbove},
+};
+
+use crate::infrastructure::config::AppConfig;
+
+/// Only compress responses whose content type starts with one of the prefixes
+#[derive(Debug, Clone)]
+pub struct ContentTypePrefixes(Arc<[String]>);
+
+impl Predicate for ContentTypePrefixes {
+    fn should_compress<B>(&self, response: &Response<B>) -> bool
+    where
+        B: HttpBody,
+    {
+        let Some(content_type) = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+        else {
+            return false;
+        };
+
+        self.0
+            .iter()
+            .any(|prefix| content_type.starts_with(prefix.as_str()))
+    }
+}
+
+/// Responses `compression_layer` compresses
+pub type CompressionPredicate = And<And<DefaultPredicate, SizeAbove>, ContentTypePrefixes>;
+
+/// gzip, brotli and zstd compression for large text responses, or with
+/// `enabled` off a layer leaving every response as it is
+pub fn compression_layer(
+    config: &AppConfig,
+    enabled: bool,
+) -> CompressionLayer<CompressionPredicate> {
+    let predicate = DefaultPredicate::new()
+        .and(SizeAbove::new(config.compression_min_size))
+        .and(ContentTypePrefixes(
+            config.compression_content_types.clone().into(),
+        ));
+
+    CompressionLayer::new()
+        .gzip(enabled)
+        .br(enabled)
+        .zstd(enabled)
+        .compress_when(predicate)
+}
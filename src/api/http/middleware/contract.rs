@@ -0,0 +1,316 @@
+//! Contract Validation
+//!
+//! Checks traffic against the OpenAPI document while the service runs, so
+//! utoipa annotations that drifted from what handlers actually do show up in
+//! development and staging (`CONTRACT_VALIDATION`). A request body is only
+//! held against its schema when the handler accepted it; every response is
+//! checked for a documented status and, for JSON, against that status'
+//! schema. Mismatches are logged, counted in `contract_violations_total` and
+//! flagged with an `X-Contract-Violations` header; nothing is rejected.
+//!
+//! Routes missing from the document, such as `/metrics`, are not checked.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header::CONTENT_TYPE},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use jsonschema::Validator;
+use serde_json::{Value, json};
+
+/// Header carrying the number of violations found in an exchange
+pub static CONTRACT_VIOLATIONS_HEADER: HeaderName =
+    HeaderName::from_static("x-contract-violations");
+
+/// Statuses any route may answer with, left out of the per-route docs
+const CROSS_CUTTING_STATUSES: [StatusCode; 3] = [
+    StatusCode::PAYLOAD_TOO_LARGE,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::SERVICE_UNAVAILABLE,
+];
+
+/// Compiled schemas of every documented operation; cheap to clone
+#[derive(Clone)]
+pub struct ContractValidator {
+    operations: Arc<Vec<Operation>>,
+}
+
+struct Operation {
+    method: Method,
+    path: String,
+    request_body: Option<Validator>,
+    /// Documented statuses, with the schema of their JSON body if any
+    responses: HashMap<String, Option<Validator>>,
+}
+
+impl ContractValidator {
+    pub fn new(document: &utoipa::openapi::OpenApi) -> Self {
+        let document = serde_json::to_value(document).expect("OpenAPI document serializes");
+        let components = document.get("components").cloned().unwrap_or(Value::Null);
+        let compile = |schema: Option<&Value>, what: &str| {
+            let schema = schema?;
+            // Keep `#/components/...` references resolvable from the schema
+            let root = json!({ "allOf": [schema], "components": components });
+            jsonschema::draft202012::new(&root)
+                .inspect_err(|e| tracing::warn!("Skipping schema of {}: {}", what, e))
+                .ok()
+        };
+
+        let mut operations = Vec::new();
+        let paths = document.get("paths").and_then(Value::as_object);
+        for (path, item) in paths.into_iter().flatten() {
+            let methods = item.as_object().into_iter().flatten();
+            for (method, operation) in methods {
+                let Ok(method) = method.to_uppercase().parse::<Method>() else {
+                    continue;
+                };
+                let what = format!("{} {}", method, path);
+                let request_body = compile(
+                    operation.pointer("/requestBody/content/application~1json/schema"),
+                    &what,
+                );
+                let responses = operation
+                    .get("responses")
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flatten()
+                    .map(|(status, response)| {
+                        let schema = response.pointer("/content/application~1json/schema");
+                        (status.clone(), compile(schema, &what))
+                    })
+                    .collect();
+
+                operations.push(Operation {
+                    method,
+                    path: path.clone(),
+                    request_body,
+                    responses,
+                });
+            }
+        }
+        // Literal segments win over parameters: /flowers/trending before /flowers/{id}
+        operations.sort_by_key(|operation| operation.path.matches('{').count());
+
+        Self {
+            operations: Arc::new(operations),
+        }
+    }
+
+    fn operation(&self, method: &Method, path: &str) -> Option<&Operation> {
+        self.operations.iter().find(|operation| {
+            operation.method == *method && matches_template(&operation.path, path)
+        })
+    }
+}
+
+impl Operation {
+    fn check_request(&self, body: &[u8]) -> Vec<String> {
+        let Some(validator) = &self.request_body else {
+            return Vec::new();
+        };
+        // Malformed JSON is the caller's mistake, answered by the handler
+        let Ok(body) = serde_json::from_slice::<Value>(body) else {
+            return Vec::new();
+        };
+        schema_errors(validator, &body, "request body")
+    }
+
+    fn check_response(&self, status: StatusCode, body: Option<&[u8]>) -> Vec<String> {
+        let documented = self
+            .responses
+            .get(status.as_str())
+            .or_else(|| self.responses.get("default"));
+        let Some(schema) = documented else {
+            if CROSS_CUTTING_STATUSES.contains(&status) {
+                return Vec::new();
+            }
+            return vec![format!("status {} is not documented", status.as_u16())];
+        };
+
+        match (schema, body) {
+            (Some(validator), Some(body)) => match serde_json::from_slice::<Value>(body) {
+                Ok(body) => schema_errors(validator, &body, "response body"),
+                Err(e) => vec![format!("response body is not valid JSON: {}", e)],
+            },
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Check an exchange against the operation documented for its route
+pub async fn validate_contract(
+    State(validator): State<ContractValidator>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(operation) = validator.operation(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+    let (method, route) = (request.method().clone(), operation.path.clone());
+
+    // Hold on to JSON request bodies that have a schema to check them against
+    let (request, request_body) = if operation.request_body.is_some() && is_json(request.headers())
+    {
+        let (parts, body) = request.into_parts();
+        let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+            // The body limit cut the upload short; answered like the extractors do
+            return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+        };
+        (
+            Request::from_parts(parts, Body::from(bytes.clone())),
+            Some(bytes),
+        )
+    } else {
+        (request, None)
+    };
+
+    let response = next.run(request).await;
+    let status = response.status();
+
+    let (response, response_body) = if is_json(response.headers()) {
+        let (parts, body) = response.into_parts();
+        match axum::body::to_bytes(body, usize::MAX).await {
+            Ok(bytes) => (
+                Response::from_parts(parts, Body::from(bytes.clone())),
+                Some(bytes),
+            ),
+            Err(e) => {
+                tracing::error!("Failed to read response body for contract checks: {}", e);
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        }
+    } else {
+        (response, None)
+    };
+
+    let mut violations = Vec::new();
+    if let (true, Some(body)) = (status.is_success(), &request_body) {
+        violations.extend(operation.check_request(body));
+    }
+    violations.extend(operation.check_response(status, response_body.as_ref().map(Bytes::as_ref)));
+    if violations.is_empty() {
+        return response;
+    }
+
+    for violation in &violations {
+        tracing::warn!(%method, route = %route, "Contract violation: {}", violation);
+    }
+    metrics::counter!("contract_violations_total", "route" => format!("{} {}", method, route))
+        .increment(violations.len() as u64);
+
+    let mut response = response;
+    response.headers_mut().insert(
+        &CONTRACT_VIOLATIONS_HEADER,
+        HeaderValue::from(violations.len()),
+    );
+    response
+}
+
+/// Whether `path` fits a documented path such as `/api/flowers/{id}`
+fn matches_template(template: &str, path: &str) -> bool {
+    let mut expected = template.split('/');
+    let mut actual = path.split('/');
+    loop {
+        match (expected.next(), actual.next()) {
+            (None, None) => return true,
+            (Some(segment), Some(value)) if segment.starts_with('{') && segment.ends_with('}') => {
+                if value.is_empty() {
+                    return false;
+                }
+            }
+            (Some(segment), Some(value)) if segment == value => {}
+            _ => return false,
+        }
+    }
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+fn schema_errors(validator: &Validator, instance: &Value, what: &str) -> Vec<String> {
+    validator
+        .iter_errors(instance)
+        .map(|error| format!("{} at '{}': {}", what, error.instance_path, error))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::http::openapi::ApiDoc;
+
+    fn flower() -> Value {
+        json!({
+            "id": "01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a60",
+            "name": "Rose",
+            "color": "red",
+            "description": null,
+            "price": 25000.0,
+            "price_display": {
+                "currency": "IDR",
+                "symbol": "Rp",
+                "decimals": 0,
+                "formatted": "Rp25,000"
+            },
+            "base_price": 25000.0,
+            "base_price_display": {
+                "currency": "IDR",
+                "symbol": "Rp",
+                "decimals": 0,
+                "formatted": "Rp25,000"
+            },
+            "stock": 3,
+            "unit": "stem",
+            "stems_per_unit": 1,
+            "units_in_stock": 3,
+            "sku": null,
+            "attributes": { "fragrance": "high" },
+            "metadata": { "erp.item_id": "ITM-00042" },
+            "created_at": "2024-12-11T00:00:00Z",
+            "updated_at": "2024-12-11T00:00:00Z"
+        })
+    }
+
+    #[test]
+    fn flags_undocumented_statuses_and_mismatched_bodies() {
+        let validator = ContractValidator::new(&ApiDoc::document());
+        let get = validator
+            .operation(&Method::GET, "/api/flowers/0193")
+            .unwrap();
+        assert_eq!(get.path, "/api/flowers/{id}");
+        assert_eq!(
+            validator
+                .operation(&Method::GET, "/api/flowers/trending")
+                .unwrap()
+                .path,
+            "/api/flowers/trending"
+        );
+
+        let body = |value: Value| serde_json::to_vec(&value).unwrap();
+        let ok = body(json!({ "success": true, "data": flower() }));
+        assert_eq!(
+            get.check_response(StatusCode::OK, Some(&ok)),
+            Vec::<String>::new()
+        );
+
+        let mut wrong = flower();
+        wrong["stock"] = json!("three");
+        let wrong = body(json!({ "success": true, "data": wrong }));
+        assert_eq!(get.check_response(StatusCode::OK, Some(&wrong)).len(), 1);
+
+        assert_eq!(get.check_response(StatusCode::CONFLICT, None).len(), 1);
+        assert!(
+            get.check_response(StatusCode::SERVICE_UNAVAILABLE, None)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,15 @@
+//! Error Reporting Middleware
+
+use axum::{extract::Request, middleware::Next, response::Response};
+
+use crate::domain::errors::ServerError;
+use crate::infrastructure::error_reporting;
+
+/// Report the failure behind any 5xx response from the routes within
+pub async fn report_errors(request: Request, next: Next) -> Response {
+    let response = next.run(request).await;
+    if let Some(ServerError(error)) = response.extensions().get::<ServerError>() {
+        error_reporting::capture(error);
+    }
+    response
+}
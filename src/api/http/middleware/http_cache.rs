@@ -0,0 +1,124 @@
+//! HTTP caching policy
+//!
+//! Read routes opt into caching with a freshness lifetime; every other API
+//! response, mutations and errors included, is marked `no-store` so no
+//! browser or CDN keeps it.
+
+use std::time::Duration;
+
+use axum::{
+    extract::{Request, State},
+    http::{
+        HeaderValue, StatusCode,
+        header::{ACCEPT_LANGUAGE, CACHE_CONTROL, VARY},
+    },
+    middleware::Next,
+    response::Response,
+};
+
+use super::TENANT_HEADER;
+use crate::application::authorization::Subject;
+use crate::infrastructure::config::AppConfig;
+
+/// Freshness lifetimes of the cacheable read routes
+#[derive(Debug, Clone, Copy)]
+pub struct CachePolicy {
+    /// Listings, searches and other collections
+    pub list: Freshness,
+    /// Single resources
+    pub detail: Freshness,
+}
+
+impl CachePolicy {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            list: Freshness(config.http_cache_list_ttl),
+            detail: Freshness(config.http_cache_detail_ttl),
+        }
+    }
+}
+
+/// How long a successful response of a route may be reused; zero makes
+/// caches revalidate it every time
+#[derive(Debug, Clone, Copy)]
+pub struct Freshness(pub Duration);
+
+impl Freshness {
+    /// `Cache-Control` for a response to `subject`
+    ///
+    /// Responses to callers with credentials are only for their own browser,
+    /// never for a shared cache.
+    fn cache_control(&self, subject: Option<&Subject>) -> HeaderValue {
+        let scope = match subject {
+            None | Some(Subject::Anonymous) => "public",
+            Some(_) => "private",
+        };
+        let value = match self.0.as_secs() {
+            0 => format!("{}, no-cache", scope),
+            seconds => format!("{}, max-age={}", scope, seconds),
+        };
+        HeaderValue::from_str(&value).expect("Cache-Control is ASCII")
+    }
+}
+
+/// Mark successful and 304 responses of the route as cacheable for its
+/// freshness lifetime, unless the handler chose its own `Cache-Control`
+///
+/// The tenant header selects the catalog and `Accept-Language` how prices
+/// are formatted, so caches keep one copy per tenant and language.
+pub async fn cache_for(
+    State(freshness): State<Freshness>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let cache_control = freshness.cache_control(request.extensions().get::<Subject>());
+    let mut response = next.run(request).await;
+
+    let status = response.status();
+    let cacheable = status.is_success() || status == StatusCode::NOT_MODIFIED;
+    if cacheable && !response.headers().contains_key(CACHE_CONTROL) {
+        let headers = response.headers_mut();
+        headers.insert(CACHE_CONTROL, cache_control);
+        headers.append(VARY, HeaderValue::from_static(TENANT_HEADER.as_str()));
+        headers.append(VARY, HeaderValue::from_static(ACCEPT_LANGUAGE.as_str()));
+    }
+    response
+}
+
+/// Keep caches from storing any response that no route marked cacheable
+pub async fn no_store(request: Request, next: Next) -> Response {
+    let mut response = next.run(request).await;
+    response
+        .headers_mut()
+        .entry(CACHE_CONTROL)
+        .or_insert(HeaderValue::from_static("no-store"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::TenantId;
+
+    #[test]
+    fn shared_caches_only_keep_anonymous_responses() {
+        let minute = Freshness(Duration::from_secs(60));
+        assert_eq!(minute.cache_control(None), "public, max-age=60");
+        assert_eq!(
+            minute.cache_control(Some(&Subject::Anonymous)),
+            "public, max-age=60"
+        );
+        assert_eq!(
+            minute.cache_control(Some(&Subject::Tenant(TenantId::default()))),
+            "private, max-age=60"
+        );
+        assert_eq!(
+            minute.cache_control(Some(&Subject::Admin)),
+            "private, max-age=60"
+        );
+        assert_eq!(
+            Freshness(Duration::ZERO).cache_control(None),
+            "public, no-cache"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! IP Allowlist / Denylist Middleware
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, HeaderName},
+    middleware::Next,
+    response::Response,
+};
+use ipnet::IpNet;
+
+use crate::domain::errors::AppError;
+use crate::i18n::Message;
+use crate::infrastructure::config::AppConfig;
+
+/// Header listing the client and the proxies a request passed through
+pub static FORWARDED_FOR_HEADER: HeaderName = HeaderName::from_static("x-forwarded-for");
+
+/// CIDR based access rules for a group of routes
+#[derive(Clone)]
+pub struct IpFilter {
+    allow: Arc<[IpNet]>,
+    deny: Arc<[IpNet]>,
+    trusted_proxies: Arc<[IpNet]>,
+}
+
+impl IpFilter {
+    /// Rules for the admin routes
+    pub fn admin(config: &AppConfig) -> Self {
+        Self {
+            allow: config.admin_allowed_ips.clone().into(),
+            deny: config.admin_denied_ips.clone().into(),
+            trusted_proxies: config.trusted_proxies.clone().into(),
+        }
+    }
+
+    /// Address of the client, looking through trusted proxies
+    ///
+    /// `X-Forwarded-For` is read right to left, skipping trusted proxies; the
+    /// first other address is the client. Entries left of it could have been
+    /// forged by the client and are ignored.
+    pub fn client_ip(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        if !self.is_trusted_proxy(peer) {
+            return peer;
+        }
+
+        let forwarded = headers
+            .get_all(&FORWARDED_FOR_HEADER)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .collect::<Vec<_>>();
+
+        let mut client = peer;
+        for entry in forwarded.into_iter().rev() {
+            let Ok(ip) = entry.parse::<IpAddr>() else {
+                break;
+            };
+            client = ip;
+            if !self.is_trusted_proxy(ip) {
+                break;
+            }
+        }
+        client
+    }
+
+    /// Denied networks win over allowed ones; an empty allowlist admits everyone
+    pub fn is_allowed(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        if self.deny.iter().any(|network| network.contains(&ip)) {
+            return false;
+        }
+        self.allow.is_empty() || self.allow.iter().any(|network| network.contains(&ip))
+    }
+
+    fn is_trusted_proxy(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        self.trusted_proxies
+            .iter()
+            .any(|network| network.contains(&ip))
+    }
+}
+
+/// Reject requests whose client address is not admitted by the filter
+pub async fn filter_ip(
+    State(filter): State<IpFilter>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    let allowed = match peer {
+        Some(peer) => {
+            let client = filter.client_ip(peer, request.headers());
+            let allowed = filter.is_allowed(client);
+            if !allowed {
+                tracing::warn!("Rejected request to {} from {}", request.uri(), client);
+            }
+            allowed
+        }
+        // Without a known peer only an unrestricted filter can admit the request
+        None => filter.allow.is_empty() && filter.deny.is_empty(),
+    };
+
+    if !allowed {
+        return Err(AppError::forbidden(Message::new("error.ip_denied")));
+    }
+    Ok(next.run(request).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(allow: &[&str], deny: &[&str], trusted: &[&str]) -> IpFilter {
+        let networks =
+            |list: &[&str]| -> Arc<[IpNet]> { list.iter().map(|n| n.parse().unwrap()).collect() };
+        IpFilter {
+            allow: networks(allow),
+            deny: networks(deny),
+            trusted_proxies: networks(trusted),
+        }
+    }
+
+    fn forwarded(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(&FORWARDED_FOR_HEADER, value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn forwarded_for_is_ignored_from_untrusted_peers() {
+        let filter = filter(&[], &[], &["10.0.0.0/8"]);
+        let peer = "203.0.113.7".parse().unwrap();
+
+        assert_eq!(filter.client_ip(peer, &forwarded("192.168.1.1")), peer);
+    }
+
+    #[test]
+    fn client_is_first_untrusted_address_from_the_right() {
+        let filter = filter(&[], &[], &["10.0.0.0/8"]);
+        let headers = forwarded("1.1.1.1, 198.51.100.4, 10.0.0.2");
+
+        assert_eq!(
+            filter.client_ip("10.0.0.1".parse().unwrap(), &headers),
+            "198.51.100.4".parse::<IpAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn deny_wins_over_allow() {
+        let filter = filter(&["10.0.0.0/8"], &["10.0.0.13/32"], &[]);
+
+        assert!(filter.is_allowed("10.1.2.3".parse().unwrap()));
+        assert!(!filter.is_allowed("10.0.0.13".parse().unwrap()));
+        assert!(!filter.is_allowed("192.0.2.1".parse().unwrap()));
+        assert!(filter.is_allowed("::ffff:10.1.2.3".parse().unwrap()));
+    }
+}
@@ -0,0 +1,126 @@
+//! Request timeout, body size and concurrency limits
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    BoxError,
+    body::Body,
+    extract::{MatchedPath, Request, State},
+    http::{
+        HeaderValue, StatusCode,
+        header::{CONTENT_LENGTH, RETRY_AFTER},
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http_body_util::Limited;
+use tower_http::timeout::TimeoutLayer;
+
+use crate::domain::errors::AppError;
+use crate::i18n::Message;
+use crate::infrastructure::config::AppConfig;
+
+/// Per-request time and size limits, and the cap on requests in flight
+#[derive(Debug, Clone)]
+pub struct RequestLimits {
+    pub timeout: Duration,
+    /// Body size limit of routes without one of their own
+    pub max_body_size: usize,
+    /// Body size limits by route, such as `/api/flowers/{id}`
+    pub route_body_sizes: Arc<HashMap<String, usize>>,
+    pub max_concurrent_requests: usize,
+}
+
+impl RequestLimits {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            timeout: config.request_timeout,
+            max_body_size: config.max_body_size,
+            route_body_sizes: Arc::new(config.route_body_sizes.clone()),
+            max_concurrent_requests: config.max_concurrent_requests,
+        }
+    }
+
+    /// Body size limit of `route`, or of requests matching no route
+    pub fn body_size(&self, route: Option<&str>) -> usize {
+        route
+            .and_then(|route| self.route_body_sizes.get(route))
+            .copied()
+            .unwrap_or(self.max_body_size)
+    }
+
+    /// Abort requests that take longer than the timeout
+    pub fn timeout_layer(&self) -> TimeoutLayer {
+        TimeoutLayer::with_status_code(StatusCode::REQUEST_TIMEOUT, self.timeout)
+    }
+}
+
+/// Reject request bodies larger than the limit of their route with 413
+///
+/// Bodies announcing a larger `Content-Length` are refused before they are
+/// read; others are cut off once they pass the limit. Runs outside the
+/// router so that axum's fixed extractor limit has to be disabled.
+pub async fn limit_body(
+    State(limits): State<RequestLimits>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let max = limits.body_size(
+        request
+            .extensions()
+            .get::<MatchedPath>()
+            .map(MatchedPath::as_str),
+    );
+
+    let announced = request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if announced.is_none_or(|length| length <= max) {
+        // Extractors answer 413 when the limit cuts the body short
+        let request = request.map(|body| Body::new(Limited::new(body, max)));
+        let response = next.run(request).await;
+        if response.status() != StatusCode::PAYLOAD_TOO_LARGE {
+            return response;
+        }
+    }
+
+    AppError::payload_too_large(Message::new("error.payload_too_large").arg("max", max))
+        .into_response()
+}
+
+/// Rewrite the bare 408 responses of the timeout layer into the error envelope
+pub async fn limit_error_envelope(
+    State(limits): State<RequestLimits>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let response = next.run(request).await;
+    if response.status() != StatusCode::REQUEST_TIMEOUT {
+        return response;
+    }
+
+    AppError::timeout(
+        Message::new("error.request_timeout").arg("seconds", limits.timeout.as_secs()),
+    )
+    .into_response()
+}
+
+/// Answer requests shed by the load shedder with 503 and a retry hint
+pub async fn shed_load(error: BoxError) -> Response {
+    if !error.is::<tower::load_shed::error::Overloaded>() {
+        return AppError::internal(format!("Unhandled middleware error: {}", error))
+            .into_response();
+    }
+
+    metrics::counter!("http_requests_shed_total").increment(1);
+    let mut response =
+        AppError::service_unavailable(Message::new("error.overloaded")).into_response();
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from_static("1"));
+    response
+}
@@ -0,0 +1,30 @@
+//! Locale Resolution Middleware
+
+use axum::{
+    extract::Request,
+    http::{
+        HeaderValue,
+        header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE},
+    },
+    middleware::Next,
+    response::Response,
+};
+
+use crate::i18n::Locale;
+
+/// Resolve the request locale from `Accept-Language` and make it current
+/// for the rest of the request
+pub async fn resolve_locale(request: Request, next: Next) -> Response {
+    let locale = request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(Locale::from_accept_language)
+        .unwrap_or_default();
+
+    let mut response = locale.scope(next.run(request)).await;
+    response
+        .headers_mut()
+        .insert(CONTENT_LANGUAGE, HeaderValue::from_static(locale.code()));
+    response
+}
@@ -0,0 +1,34 @@
+pub mod authorization;
+pub mod compression;
+#[cfg(feature = "contract-validation")]
+pub mod contract;
+pub mod error_reporting;
+pub mod http_cache;
+pub mod ip_filter;
+pub mod limits;
+pub mod locale;
+pub mod payload_metrics;
+pub mod request_id;
+pub mod stack;
+pub mod tenant;
+pub mod usage;
+
+pub use authorization::{
+    Access, AdminToken, Authenticator, Rule, authenticate, authorize, require_admin,
+};
+pub use compression::{CompressionPredicate, compression_layer};
+#[cfg(feature = "contract-validation")]
+pub use contract::{CONTRACT_VIOLATIONS_HEADER, ContractValidator, validate_contract};
+pub use error_reporting::report_errors;
+pub use http_cache::{CachePolicy, Freshness, cache_for, no_store};
+pub use ip_filter::{FORWARDED_FOR_HEADER, IpFilter, filter_ip};
+pub use limits::{RequestLimits, limit_body, limit_error_envelope, shed_load};
+pub use locale::resolve_locale;
+pub use payload_metrics::record_payload_sizes;
+pub use request_id::{REQUEST_ID_HEADER, propagate_request_id, tag_error_envelope};
+pub use stack::MiddlewareStack;
+pub use tenant::{API_KEY_HEADER, TENANT_HEADER, TenantResolver, resolve_tenant};
+pub use usage::{
+    RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER, RATE_LIMIT_RESET_HEADER, enforce_quota,
+    meter_usage,
+};
@@ -0,0 +1,50 @@
+//! Request and response body sizes per route
+
+use axum::{
+    body::HttpBody,
+    extract::{MatchedPath, Request},
+    http::{HeaderMap, header::CONTENT_LENGTH},
+    middleware::Next,
+    response::Response,
+};
+
+/// Route label of requests that matched no route, keeping scanners from
+/// adding a series per probed path
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Record body sizes as `http_request_size_bytes` and
+/// `http_response_size_bytes`, by method and route
+///
+/// Sizes come from `Content-Length` or bodies of known size; streamed
+/// bodies of unknown length are not recorded. Responses are measured
+/// before compression.
+pub async fn record_payload_sizes(request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or(UNMATCHED_ROUTE, MatchedPath::as_str)
+        .to_string();
+    let request_size = body_size(request.headers(), request.body());
+
+    let response = next.run(request).await;
+
+    if let Some(size) = request_size {
+        metrics::histogram!("http_request_size_bytes", "method" => method.clone(), "route" => route.clone())
+            .record(size as f64);
+    }
+    if let Some(size) = body_size(response.headers(), response.body()) {
+        metrics::histogram!("http_response_size_bytes", "method" => method, "route" => route)
+            .record(size as f64);
+    }
+    response
+}
+
+/// Size of a body from `Content-Length`, else from a body of known size
+pub(super) fn body_size(headers: &HeaderMap, body: &impl HttpBody) -> Option<u64> {
+    headers
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .or_else(|| body.size_hint().exact())
+}
@@ -0,0 +1,92 @@
+//! Request ID Middleware
+
+use axum::{
+    Json,
+    body::{Body, to_bytes},
+    extract::Request,
+    http::{HeaderMap, HeaderName, HeaderValue, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{Map, Value, json};
+use uuid::Uuid;
+
+use crate::application::context::with_request_id;
+use crate::infrastructure::error_reporting;
+
+/// Header carrying the request ID in both directions
+pub static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest incoming request ID we are willing to echo back
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// Honor a well-formed incoming `X-Request-Id` or generate a new one, expose
+/// it to the rest of the request and return it in the response
+pub async fn propagate_request_id(mut request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::now_v7().to_string());
+
+    error_reporting::set_request_id(&request_id);
+
+    let header_value =
+        HeaderValue::from_str(&request_id).expect("request IDs are validated visible ASCII");
+    request
+        .headers_mut()
+        .insert(REQUEST_ID_HEADER.clone(), header_value.clone());
+
+    let mut response = with_request_id(request_id, next.run(request)).await;
+    response
+        .headers_mut()
+        .insert(REQUEST_ID_HEADER.clone(), header_value);
+    response
+}
+
+/// Quote the request ID in error envelopes, so callers reporting a failure
+/// can point at its logs
+///
+/// Runs inside compression, where response bodies are still plain JSON.
+pub async fn tag_error_envelope(request: Request, next: Next) -> Response {
+    let request_id = request.headers().get(&REQUEST_ID_HEADER).cloned();
+    let response = next.run(request).await;
+    let status = response.status();
+    let Some(request_id) = request_id.as_ref().and_then(|value| value.to_str().ok()) else {
+        return response;
+    };
+    if !(status.is_client_error() || status.is_server_error()) || !is_json(response.headers()) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!("Failed to read error response body: {}", e);
+            return status.into_response();
+        }
+    };
+    let mut envelope = match serde_json::from_slice::<Map<String, Value>>(&bytes) {
+        Ok(envelope) if envelope.get("success") == Some(&Value::Bool(false)) => envelope,
+        _ => return Response::from_parts(parts, Body::from(bytes)),
+    };
+    envelope.insert("request_id".to_string(), json!(request_id));
+    parts.headers.remove(header::CONTENT_LENGTH);
+    (parts, Json(envelope)).into_response()
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LENGTH
+        && value.bytes().all(|byte| byte.is_ascii_graphic())
+}
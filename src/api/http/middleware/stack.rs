@@ -0,0 +1,168 @@
+//! Middleware Stacks
+//!
+//! The layers wrapped around every request, declared in one place and in the
+//! order they run, with a preset per deployment profile. Route-specific
+//! layers (tenant resolution, authorization, caching) stay with their routes.
+
+use axum::{
+    Router,
+    error_handling::HandleErrorLayer,
+    extract::{DefaultBodyLimit, Request},
+    http::header,
+    middleware,
+};
+use tower::ServiceBuilder;
+use tower_http::compression::CompressionLayer;
+use tower_http::cors::{Any, CorsLayer};
+use tower_http::trace::TraceLayer;
+
+use super::{
+    Authenticator, CompressionPredicate, RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER,
+    RATE_LIMIT_RESET_HEADER, REQUEST_ID_HEADER, RequestLimits, authenticate, compression_layer,
+    enforce_quota, limit_body, limit_error_envelope, meter_usage, no_store, propagate_request_id,
+    record_payload_sizes, report_errors, resolve_locale, shed_load, tag_error_envelope,
+};
+use crate::api::http::pagination::TOTAL_COUNT_HEADER;
+use crate::api::http::state::AppState;
+use crate::infrastructure::config::{AppConfig, Profile};
+
+/// Layers around the API and around the whole service
+#[derive(Clone)]
+pub struct MiddlewareStack {
+    limits: RequestLimits,
+    authenticator: Authenticator,
+    compression: CompressionLayer<CompressionPredicate>,
+    cors: CorsLayer,
+    #[cfg(feature = "contract-validation")]
+    contract_validation: bool,
+}
+
+impl MiddlewareStack {
+    /// Preset for the configured profile
+    pub fn from_config(config: &AppConfig) -> Self {
+        match config.profile {
+            Profile::Development => Self::development(config),
+            Profile::Staging | Profile::Production => Self::production(config),
+        }
+    }
+
+    /// Every layer, compressing responses as configured
+    pub fn production(config: &AppConfig) -> Self {
+        Self::new(config, true)
+    }
+
+    /// The production stack with responses left uncompressed, so they read
+    /// as they are over local connections that gain nothing from compressing
+    pub fn development(config: &AppConfig) -> Self {
+        Self::new(config, false)
+    }
+
+    fn new(config: &AppConfig, compress: bool) -> Self {
+        Self {
+            limits: RequestLimits::from_config(config),
+            authenticator: Authenticator::from_config(config),
+            compression: compression_layer(config, compress),
+            cors: CorsLayer::new()
+                .allow_origin(Any)
+                .allow_methods(Any)
+                .allow_headers(Any)
+                .expose_headers([
+                    header::LINK,
+                    TOTAL_COUNT_HEADER.clone(),
+                    RATE_LIMIT_LIMIT_HEADER.clone(),
+                    RATE_LIMIT_REMAINING_HEADER.clone(),
+                    RATE_LIMIT_RESET_HEADER.clone(),
+                ]),
+            #[cfg(feature = "contract-validation")]
+            contract_validation: config.contract_validation,
+        }
+    }
+
+    /// Wrap the API routes: shed load beyond the concurrency limit, keep
+    /// responses out of shared caches unless a route says otherwise,
+    /// identify the caller, hold API keys to their quotas and meter usage
+    ///
+    /// Probes and docs stay outside, reachable under load and without
+    /// credentials.
+    pub fn api(&self, routes: Router<AppState>, state: &AppState) -> Router<AppState> {
+        routes.layer(
+            ServiceBuilder::new()
+                .layer(HandleErrorLayer::new(shed_load))
+                .load_shed()
+                .concurrency_limit(self.limits.max_concurrent_requests)
+                .layer(middleware::from_fn(no_store))
+                .layer(middleware::from_fn_with_state(
+                    self.authenticator.clone(),
+                    authenticate,
+                ))
+                .layer(middleware::from_fn_with_state(
+                    state.usage.clone(),
+                    enforce_quota,
+                ))
+                .layer(middleware::from_fn_with_state(
+                    state.usage.clone(),
+                    meter_usage,
+                )),
+        )
+    }
+
+    /// Wrap the whole service, outermost layer first
+    pub fn service(self, router: Router<AppState>, state: AppState) -> Router {
+        // Flag traffic that strays from the OpenAPI document
+        #[cfg(feature = "contract-validation")]
+        let router = if self.contract_validation {
+            router.layer(middleware::from_fn_with_state(
+                super::ContractValidator::new(&crate::api::http::ApiDoc::document()),
+                super::validate_contract,
+            ))
+        } else {
+            router
+        };
+
+        let router = router
+            .layer(
+                ServiceBuilder::new()
+                    .layer(middleware::from_fn(propagate_request_id))
+                    .layer(middleware::from_fn(report_errors))
+                    .layer(TraceLayer::new_for_http().make_span_with(request_span))
+                    .layer(self.compression)
+                    .layer(middleware::from_fn(tag_error_envelope))
+                    .layer(middleware::from_fn(resolve_locale))
+                    .layer(middleware::from_fn_with_state(
+                        self.limits.clone(),
+                        limit_error_envelope,
+                    ))
+                    .layer(self.limits.timeout_layer())
+                    .layer(middleware::from_fn(record_payload_sizes))
+                    .layer(middleware::from_fn_with_state(self.limits, limit_body))
+                    // axum's fixed 2MB extractor limit would cap the per-route limits
+                    .layer(DefaultBodyLimit::disable()),
+            )
+            .with_state(state);
+
+        // Per-request Sentry hub carrying route and request details
+        #[cfg(feature = "sentry")]
+        let router = router
+            .layer(sentry::integrations::tower::SentryHttpLayer::new().enable_transaction())
+            .layer(sentry::integrations::tower::NewSentryLayer::new_from_top());
+
+        router.layer(self.cors)
+    }
+}
+
+/// Tracing span for a request, tagged with its request ID
+fn request_span(request: &Request) -> tracing::Span {
+    let request_id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        request_id = %request_id,
+        tenant = tracing::field::Empty,
+    )
+}
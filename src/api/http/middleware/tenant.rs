@@ -0,0 +1,148 @@
+//! Tenant Resolution Middleware
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, HeaderName, header::HOST},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::application::authorization::Subject;
+use crate::application::context::RequestContext;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::TenantId;
+use crate::i18n::Message;
+use crate::infrastructure::config::{AppConfig, TenantSource};
+use crate::infrastructure::error_reporting;
+
+/// Header naming the tenant explicitly
+pub static TENANT_HEADER: HeaderName = HeaderName::from_static("x-tenant-id");
+
+/// Header carrying a tenant API key
+pub static API_KEY_HEADER: HeaderName = HeaderName::from_static("x-api-key");
+
+/// Resolves the tenant of a request from the configured sources
+#[derive(Clone)]
+pub struct TenantResolver {
+    sources: Arc<[TenantSource]>,
+    default: TenantId,
+    base_domain: Option<Arc<str>>,
+    api_keys: Arc<HashMap<String, TenantId>>,
+}
+
+impl TenantResolver {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            sources: config.tenant_sources.clone().into(),
+            default: config.default_tenant.clone(),
+            base_domain: config.tenant_base_domain.as_deref().map(Arc::from),
+            api_keys: Arc::new(config.tenant_api_keys.clone()),
+        }
+    }
+
+    /// First tenant found by the sources in order, else the default tenant
+    ///
+    /// An `X-Tenant-Id` naming any other tenant is refused rather than
+    /// ignored: unless `header` is a source, it is no credential, and a
+    /// request must not land in a tenant its sender did not mean.
+    pub fn resolve(&self, headers: &HeaderMap) -> DomainResult<TenantId> {
+        let tenant = self.first_match(headers)?;
+        if let Some(named) = header_value(headers, &TENANT_HEADER)
+            && named != tenant.as_str()
+        {
+            return Err(AppError::forbidden(
+                Message::new("tenant.header.untrusted").arg("tenant", named),
+            ));
+        }
+        Ok(tenant)
+    }
+
+    fn first_match(&self, headers: &HeaderMap) -> DomainResult<TenantId> {
+        for source in self.sources.iter() {
+            let tenant = match source {
+                TenantSource::ApiKey => self.tenant_for_api_key(headers)?,
+                TenantSource::Subdomain => self.tenant_for_subdomain(headers)?,
+                TenantSource::Header => header_value(headers, &TENANT_HEADER)
+                    .map(TenantId::new)
+                    .transpose()?,
+            };
+            if let Some(tenant) = tenant {
+                return Ok(tenant);
+            }
+        }
+
+        Ok(self.default.clone())
+    }
+
+    fn tenant_for_api_key(&self, headers: &HeaderMap) -> DomainResult<Option<TenantId>> {
+        let Some(api_key) = header_value(headers, &API_KEY_HEADER) else {
+            return Ok(None);
+        };
+
+        // A key that was sent but is unknown must not fall through to other sources
+        self.api_keys
+            .get(api_key)
+            .cloned()
+            .map(Some)
+            .ok_or_else(|| AppError::unauthorized(Message::new("tenant.api_key.invalid")))
+    }
+
+    fn tenant_for_subdomain(&self, headers: &HeaderMap) -> DomainResult<Option<TenantId>> {
+        let (Some(base_domain), Some(host)) = (&self.base_domain, header_value(headers, &HOST))
+        else {
+            return Ok(None);
+        };
+
+        let host = host.split(':').next().unwrap_or_default().to_lowercase();
+        let Some(subdomain) = host
+            .strip_suffix(base_domain.as_ref())
+            .and_then(|prefix| prefix.strip_suffix('.'))
+        else {
+            return Ok(None);
+        };
+
+        // Only the label directly below the base domain names the tenant
+        match subdomain.rsplit('.').next() {
+            Some(label) if !label.is_empty() && label != "www" => Ok(Some(TenantId::new(label)?)),
+            _ => Ok(None),
+        }
+    }
+}
+
+fn header_value<'a>(headers: &'a HeaderMap, name: &HeaderName) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// Resolve the request tenant and hand it to handlers as an `Extension<TenantId>`,
+/// and to use cases as part of the current `RequestContext`
+///
+/// The response carries the tenant as an extension too, for the layers
+/// around the routes, such as usage metering.
+pub async fn resolve_tenant(
+    State(resolver): State<TenantResolver>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let tenant = resolver.resolve(request.headers())?;
+    tracing::Span::current().record("tenant", tenant.as_str());
+    error_reporting::set_tenant(tenant.as_str());
+
+    let context = request
+        .extensions()
+        .get::<RequestContext>()
+        .cloned()
+        .unwrap_or_else(|| RequestContext::new(Subject::Anonymous))
+        .with_tenant(tenant.clone());
+    request.extensions_mut().insert(tenant.clone());
+    request.extensions_mut().insert(context.clone());
+    let mut response = context.scope(next.run(request)).await;
+    response.extensions_mut().insert(tenant);
+    Ok(response)
+}
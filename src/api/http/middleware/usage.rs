@@ -0,0 +1,143 @@
+//! API Usage Metering and Quota Middleware
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, header::RETRY_AFTER},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+
+use super::payload_metrics::body_size;
+use super::tenant::API_KEY_HEADER;
+use crate::application::authorization::Subject;
+use crate::application::usecases::{QuotaStatus, UsageMetering};
+use crate::domain::errors::AppError;
+use crate::domain::shared::TenantId;
+use crate::i18n::Message;
+
+/// Header carrying the monthly quota of the caller's API key
+pub static RATE_LIMIT_LIMIT_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+
+/// Header carrying the requests left this month
+pub static RATE_LIMIT_REMAINING_HEADER: HeaderName =
+    HeaderName::from_static("x-ratelimit-remaining");
+
+/// Header carrying when the quota resets, in seconds since the Unix epoch
+pub static RATE_LIMIT_RESET_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+/// Reject requests made with an API key whose monthly quota is used up with
+/// 429, and tell callers with a quota how much of it is left
+///
+/// Rejected requests are not metered, so they do not count against the
+/// quota. Callers without an API key, or whose key has no quota, pass
+/// untouched.
+pub async fn enforce_quota(
+    State(usage): State<Arc<UsageMetering>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((tenant, quota)) = caller_api_key(&request)
+        .and_then(|(tenant, api_key)| Some((tenant.clone(), usage.quota(&tenant, &api_key)?)))
+    else {
+        return next.run(request).await;
+    };
+
+    if quota.remaining() == 0 {
+        metrics::counter!("api_quota_rejections_total", "tenant" => tenant.as_str().to_string())
+            .increment(1);
+        let mut response = AppError::too_many_requests(
+            Message::new("quota.exceeded")
+                .arg("limit", quota.limit)
+                .arg("resets_at", quota.resets_at.to_rfc3339()),
+        )
+        .into_response();
+        let retry_after = (quota.resets_at - Utc::now()).num_seconds().max(1);
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+        insert_quota_headers(response.headers_mut(), &quota, 0);
+        return response;
+    }
+
+    let mut response = next.run(request).await;
+    // This request is metered once it completes
+    insert_quota_headers(response.headers_mut(), &quota, quota.remaining() - 1);
+    response
+}
+
+fn insert_quota_headers(headers: &mut HeaderMap, quota: &QuotaStatus, remaining: i64) {
+    headers.insert(
+        RATE_LIMIT_LIMIT_HEADER.clone(),
+        HeaderValue::from(quota.limit),
+    );
+    headers.insert(
+        RATE_LIMIT_REMAINING_HEADER.clone(),
+        HeaderValue::from(remaining),
+    );
+    headers.insert(
+        RATE_LIMIT_RESET_HEADER.clone(),
+        HeaderValue::from(quota.resets_at.timestamp()),
+    );
+}
+
+/// Tenant and API key of a caller authenticated with one
+///
+/// Keys were checked by `authenticate`; one sent along the admin token is
+/// not what the request was made with.
+fn caller_api_key(request: &Request) -> Option<(TenantId, String)> {
+    let Some(Subject::Tenant(tenant)) = request.extensions().get::<Subject>() else {
+        return None;
+    };
+    let api_key = request
+        .headers()
+        .get(&API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())?;
+    Some((tenant.clone(), api_key.trim().to_string()))
+}
+
+/// Meter the request for its tenant and API key, and count it in the
+/// `api_usage_requests_total` and `api_usage_bytes_total` metrics by tenant
+///
+/// The tenant is the one the route resolved, else the one of the caller's
+/// API key; requests with neither, such as admin calls, are not metered.
+/// Bytes are counted as in `record_payload_sizes`: streamed bodies of
+/// unknown length count as empty, and responses before compression.
+pub async fn meter_usage(
+    State(usage): State<Arc<UsageMetering>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (caller_tenant, api_key) = caller_api_key(&request).unzip();
+    let request_bytes = body_size(request.headers(), request.body()).unwrap_or(0);
+
+    let response = next.run(request).await;
+
+    let Some(tenant) = response
+        .extensions()
+        .get::<TenantId>()
+        .cloned()
+        .or(caller_tenant)
+    else {
+        return response;
+    };
+    let response_bytes = body_size(response.headers(), response.body()).unwrap_or(0);
+    let failed = response.status().is_client_error() || response.status().is_server_error();
+    usage.record(
+        &tenant,
+        api_key.as_deref(),
+        request_bytes,
+        response_bytes,
+        failed,
+    );
+
+    let tenant = tenant.as_str().to_string();
+    metrics::counter!("api_usage_requests_total", "tenant" => tenant.clone()).increment(1);
+    metrics::counter!("api_usage_bytes_total", "tenant" => tenant.clone(), "direction" => "received")
+        .increment(request_bytes);
+    metrics::counter!("api_usage_bytes_total", "tenant" => tenant, "direction" => "sent")
+        .increment(response_bytes);
+    response
+}
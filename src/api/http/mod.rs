@@ -1,8 +1,13 @@
 pub mod handlers;
+pub mod middleware;
 pub mod openapi;
+pub mod pagination;
 pub mod routes;
+pub mod server;
 pub mod state;
+pub mod webhook;
 
 pub use openapi::ApiDoc;
 pub use routes::create_router;
+pub use server::serve;
 pub use state::AppState;
@@ -1,12 +1,48 @@
 //! OpenAPI Documentation Configuration
 
-use utoipa::OpenApi;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
 
-use crate::api::http::handlers::{flower_handler, health_handler};
+use crate::api::http::handlers::{
+    admin_handler, backup_handler, catalog_export_handler, delivery_zone_handler,
+    feature_flag_handler, flower_handler, health_handler, label_handler, me_handler, order_handler,
+    pricing_rule_handler, saved_search_handler, shipping_handler, store_handler, supplier_handler,
+    task_handler, version_handler, webhook_endpoint_handler, webhook_handler,
+};
 use crate::application::dtos::{
-    ApiResponseFlower, ApiResponsePaginatedFlower, CreateFlowerRequest, ErrorResponse,
-    FlowerResponse, PaginatedFlowerResponse, UpdateFlowerRequest,
+    ApiResponseBackup, ApiResponseCachePurge, ApiResponseCatalogExport, ApiResponseColors,
+    ApiResponseDeliveryCheck, ApiResponseDeliveryZone, ApiResponseDeliveryZones,
+    ApiResponseFeatureFlag, ApiResponseFeatureFlags, ApiResponseFlower, ApiResponseFlowerFilters,
+    ApiResponseFlowerPurge, ApiResponseJobs, ApiResponseOrder, ApiResponseOrderEvents,
+    ApiResponsePaginatedFailedTask, ApiResponsePaginatedFlower, ApiResponsePaginatedFlowerChange,
+    ApiResponsePaginatedLedgerEntry, ApiResponsePriceAdjustment, ApiResponsePricingRule,
+    ApiResponsePricingRules, ApiResponseQuota, ApiResponseQuotas, ApiResponseRecentlyViewedFlowers,
+    ApiResponseRestore, ApiResponseSavedSearch, ApiResponseSavedSearches, ApiResponseShippingRates,
+    ApiResponseStockMovement, ApiResponseStore, ApiResponseStoreAvailability,
+    ApiResponseStoreStock, ApiResponseStores, ApiResponseSupplierSync, ApiResponseTrendingFlowers,
+    ApiResponseUsageReport, ApiResponseWebhookEndpoint, ApiResponseWebhookEndpoints,
+    ApiResponseWebhookTest, BackupResponse, BackupTableResponse, CachePurgeRequest,
+    CachePurgeResponse, CatalogExportRequest, CatalogExportResponse, CatalogExportStatus,
+    Coordinates, CreateDeliveryZoneRequest, CreateFlowerRequest, CreateOrderRequest,
+    CreateStoreRequest, CreateWebhookEndpointRequest, DeliveryCheckResponse, DeliveryZoneResponse,
+    ErrorResponse, FailedTaskResponse, FeatureFlagResponse, FeatureFlagSource, FieldErrorResponse,
+    FlowerChangeResponse, FlowerFiltersResponse, FlowerPurgeResponse, FlowerResponse, JobResponse,
+    LedgerEntryResponse, OrderEventResponse, OrderLineRequest, OrderLineResponse,
+    OrderRefundResponse, OrderResponse, OrderShippingRequest, PaginatedFailedTaskResponse,
+    PaginatedFlowerChangeResponse, PaginatedFlowerResponse, PaginatedLedgerEntryResponse,
+    PaymentEventRequest, PaymentEventType, PriceAdjustmentFilter, PriceAdjustmentRequest,
+    PriceAdjustmentResponse, PriceChangeResponse, PricingRuleRequest, PricingRuleResponse,
+    QuotaResponse, RecentlyViewedFlowerResponse, RestoreBackupRequest, RestoreResponse,
+    SaveSearchRequest, SavedSearchResponse, SearchHighlight, SetQuotaRequest, SetStoreStockRequest,
+    ShippingRateResponse, StockAdjustmentRequest, StockMovementResponse, StoreAvailabilityResponse,
+    StoreResponse, StoreStockResponse, SupplierSyncResponse, TrendingFlowerResponse,
+    UpdateFeatureFlagRequest, UpdateFlowerRequest, UpdateOrderStatusRequest, UsageHourResponse,
+    UsageReportResponse, UsageTotalResponse, WebhookEndpointResponse, WebhookTestResponse,
 };
+use crate::application::money::MoneyDisplay;
+use crate::domain::flower::{FlowerAttributes, FlowerColor, FlowerMetadata, Fragrance};
+use crate::domain::order::OrderStatus;
+use crate::infrastructure::build_info::BuildInfo;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -28,27 +64,257 @@ use crate::application::dtos::{
     ),
     tags(
         (name = "Health", description = "Health check endpoints"),
-        (name = "Flowers", description = "Flower management endpoints")
+        (name = "Flowers", description = "Flower management endpoints"),
+        (name = "Stores", description = "Physical stores and the flowers customers can pick up there"),
+        (name = "Delivery", description = "Zones a tenant delivers to, and what delivering there costs"),
+        (name = "Orders", description = "Orders and their way from payment to delivery"),
+        (name = "Pricing", description = "Rules adjusting flower prices on demand, such as markups while stock runs low"),
+        (name = "Me", description = "What the service remembers about the caller"),
+        (name = "Saved Searches", description = "Searches callers keep, with optional alerts about new matches"),
+        (name = "Webhooks", description = "Callbacks of integrations, authenticated by the signature of their body, and the endpoints tenants have their events delivered to, signed the same way"),
+        (name = "Admin", description = "Operational endpoints requiring the admin token")
     ),
+    modifiers(&SecuritySchemes),
     paths(
         health_handler::health_check,
+        health_handler::liveness,
+        health_handler::readiness,
+        version_handler::version,
         flower_handler::get_flower,
         flower_handler::list_flowers,
+        flower_handler::list_colors,
+        flower_handler::flower_filters,
+        flower_handler::trending_flowers,
         flower_handler::create_flower,
         flower_handler::update_flower,
+        flower_handler::adjust_prices,
+        flower_handler::adjust_stock,
+        flower_handler::flower_history,
         flower_handler::delete_flower,
+        label_handler::flower_qr_code,
+        label_handler::flower_barcode,
+        store_handler::flower_availability,
+        store_handler::list_stores,
+        store_handler::create_store,
+        store_handler::delete_store,
+        store_handler::set_store_stock,
+        delivery_zone_handler::list_delivery_zones,
+        delivery_zone_handler::create_delivery_zone,
+        delivery_zone_handler::check_delivery,
+        delivery_zone_handler::delete_delivery_zone,
+        shipping_handler::shipping_rates,
+        order_handler::create_order,
+        order_handler::get_order,
+        order_handler::order_events,
+        order_handler::update_order_status,
+        order_handler::cancel_order,
+        pricing_rule_handler::list_pricing_rules,
+        pricing_rule_handler::create_pricing_rule,
+        pricing_rule_handler::get_pricing_rule,
+        pricing_rule_handler::update_pricing_rule,
+        pricing_rule_handler::delete_pricing_rule,
+        me_handler::recently_viewed,
+        saved_search_handler::list_saved_searches,
+        saved_search_handler::create_saved_search,
+        saved_search_handler::delete_saved_search,
+        feature_flag_handler::list_feature_flags,
+        feature_flag_handler::update_feature_flag,
+        task_handler::list_failed_tasks,
+        admin_handler::list_jobs,
+        admin_handler::list_stock_movements,
+        admin_handler::purge_flower,
+        admin_handler::purge_cache,
+        admin_handler::pool_stats,
+        admin_handler::usage_report,
+        admin_handler::list_quotas,
+        admin_handler::set_quota,
+        backup_handler::create_backup,
+        backup_handler::restore_backup,
+        catalog_export_handler::create_catalog_export,
+        catalog_export_handler::get_catalog_export,
+        catalog_export_handler::download_catalog_export,
+        supplier_handler::sync_supplier,
+        webhook_handler::payment_webhook,
+        webhook_endpoint_handler::list_webhook_endpoints,
+        webhook_endpoint_handler::create_webhook_endpoint,
+        webhook_endpoint_handler::delete_webhook_endpoint,
+        webhook_endpoint_handler::rotate_webhook_secret,
+        webhook_endpoint_handler::test_webhook_endpoint,
     ),
     components(
         schemas(
             health_handler::HealthResponse,
+            health_handler::ComponentHealth,
+            health_handler::ReadinessResponse,
+            BuildInfo,
             FlowerResponse,
+            MoneyDisplay,
+            SearchHighlight,
+            FlowerColor,
+            Fragrance,
+            FlowerAttributes,
+            FlowerMetadata,
             CreateFlowerRequest,
             UpdateFlowerRequest,
+            PriceAdjustmentFilter,
+            PriceAdjustmentRequest,
+            PriceChangeResponse,
+            PriceAdjustmentResponse,
+            ApiResponsePriceAdjustment,
+            StockAdjustmentRequest,
+            StockMovementResponse,
+            TrendingFlowerResponse,
+            ApiResponseTrendingFlowers,
+            RecentlyViewedFlowerResponse,
+            ApiResponseRecentlyViewedFlowers,
+            CreateStoreRequest,
+            StoreResponse,
+            SetStoreStockRequest,
+            StoreStockResponse,
+            StoreAvailabilityResponse,
+            ApiResponseStore,
+            ApiResponseStores,
+            ApiResponseStoreStock,
+            ApiResponseStoreAvailability,
+            Coordinates,
+            CreateDeliveryZoneRequest,
+            DeliveryZoneResponse,
+            DeliveryCheckResponse,
+            ApiResponseDeliveryZone,
+            ApiResponseDeliveryZones,
+            ApiResponseDeliveryCheck,
+            ShippingRateResponse,
+            ApiResponseShippingRates,
+            OrderStatus,
+            OrderLineRequest,
+            OrderShippingRequest,
+            CreateOrderRequest,
+            UpdateOrderStatusRequest,
+            PaymentEventType,
+            PaymentEventRequest,
+            CreateWebhookEndpointRequest,
+            WebhookEndpointResponse,
+            WebhookTestResponse,
+            ApiResponseWebhookEndpoint,
+            ApiResponseWebhookEndpoints,
+            ApiResponseWebhookTest,
+            OrderLineResponse,
+            OrderRefundResponse,
+            OrderResponse,
+            OrderEventResponse,
+            ApiResponseOrder,
+            ApiResponseOrderEvents,
+            PricingRuleRequest,
+            PricingRuleResponse,
+            ApiResponsePricingRule,
+            ApiResponsePricingRules,
+            ApiResponseStockMovement,
+            FlowerChangeResponse,
+            PaginatedFlowerChangeResponse,
+            ApiResponsePaginatedFlowerChange,
             ErrorResponse,
+            FieldErrorResponse,
             ApiResponseFlower,
             ApiResponsePaginatedFlower,
             PaginatedFlowerResponse,
+            ApiResponseColors,
+            FlowerFiltersResponse,
+            ApiResponseFlowerFilters,
+            SaveSearchRequest,
+            SavedSearchResponse,
+            ApiResponseSavedSearch,
+            ApiResponseSavedSearches,
+            FeatureFlagResponse,
+            FeatureFlagSource,
+            UpdateFeatureFlagRequest,
+            ApiResponseFeatureFlag,
+            ApiResponseFeatureFlags,
+            FailedTaskResponse,
+            PaginatedFailedTaskResponse,
+            ApiResponsePaginatedFailedTask,
+            JobResponse,
+            ApiResponseJobs,
+            LedgerEntryResponse,
+            PaginatedLedgerEntryResponse,
+            ApiResponsePaginatedLedgerEntry,
+            FlowerPurgeResponse,
+            ApiResponseFlowerPurge,
+            CachePurgeRequest,
+            CachePurgeResponse,
+            ApiResponseCachePurge,
+            admin_handler::PoolStatsResponse,
+            admin_handler::AcquireLatencyResponse,
+            admin_handler::ApiResponsePoolStats,
+            UsageTotalResponse,
+            UsageHourResponse,
+            UsageReportResponse,
+            ApiResponseUsageReport,
+            QuotaResponse,
+            SetQuotaRequest,
+            ApiResponseQuota,
+            ApiResponseQuotas,
+            BackupTableResponse,
+            BackupResponse,
+            RestoreBackupRequest,
+            RestoreResponse,
+            ApiResponseBackup,
+            ApiResponseRestore,
+            CatalogExportRequest,
+            CatalogExportStatus,
+            CatalogExportResponse,
+            ApiResponseCatalogExport,
+            SupplierSyncResponse,
+            ApiResponseSupplierSync,
         )
     )
 )]
 pub struct ApiDoc;
+
+/// Registers the credentials routes accept
+///
+/// Admin routes require `admin_token`. Flower routes list an empty
+/// requirement next to `api_key` and `admin_token`, since anonymous callers
+/// may read flowers and, with `ALLOW_ANONYMOUS_WRITES`, change them.
+struct SecuritySchemes;
+
+impl Modify for SecuritySchemes {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "admin_token",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .description(Some("ADMIN_TOKEN, granting access to everything"))
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::with_description(
+                "X-Api-Key",
+                "Key from TENANT_API_KEYS, granting access to that tenant's flowers. Keys with \
+                 a monthly quota get X-RateLimit-Limit, X-RateLimit-Remaining and \
+                 X-RateLimit-Reset headers, and 429 once the quota is used up",
+            ))),
+        );
+    }
+}
+
+impl ApiDoc {
+    /// OpenAPI document with the running build appended to its description
+    pub fn document() -> utoipa::openapi::OpenApi {
+        let mut doc = Self::openapi();
+        let build = format!("Build: {}", BuildInfo::current().summary());
+        doc.info.description = Some(match doc.info.description.take() {
+            Some(description) => format!("{}\n\n{}", description, build),
+            None => build,
+        });
+        doc
+    }
+
+    /// `document` serialized as YAML
+    pub fn document_yaml() -> Result<String, serde_yaml_ng::Error> {
+        serde_yaml_ng::to_string(&Self::document())
+    }
+}
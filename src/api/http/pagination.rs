@@ -0,0 +1,116 @@
+//! Pagination Headers
+//!
+//! Paginated listings answer with the usual envelope and also describe the
+//! page in headers, for clients that do not read our envelope: `Link`
+//! (RFC 8288, formerly RFC 5988) to the first, previous, next and last page,
+//! and `X-Total-Count`.
+
+use axum::{
+    Json,
+    http::{HeaderName, HeaderValue, Uri, header::LINK},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+use crate::application::dtos::ApiResponse;
+use crate::domain::shared::PaginatedResponse;
+
+/// Total number of items across all pages
+pub static TOTAL_COUNT_HEADER: HeaderName = HeaderName::from_static("x-total-count");
+
+/// A page of a listing, answered in the envelope with pagination headers
+pub struct Paginated<T> {
+    /// Request URI, whose path and other query parameters the links keep
+    uri: Uri,
+    page: PaginatedResponse<T>,
+}
+
+impl<T> Paginated<T> {
+    /// `uri` is the full request URI, as given by `OriginalUri` in nested
+    /// routers
+    pub fn new(uri: Uri, page: PaginatedResponse<T>) -> Self {
+        Self { uri, page }
+    }
+}
+
+impl<T: Serialize> IntoResponse for Paginated<T> {
+    fn into_response(self) -> Response {
+        let link = links(&self.uri, self.page.page, self.page.total_pages);
+        let headers = [
+            (
+                LINK,
+                HeaderValue::from_str(&link).expect("links are built from a valid URI"),
+            ),
+            (
+                TOTAL_COUNT_HEADER.clone(),
+                HeaderValue::from(self.page.total),
+            ),
+        ];
+        (headers, Json(ApiResponse::success(self.page))).into_response()
+    }
+}
+
+/// `Link` header value for `page` of `total_pages`
+///
+/// There is always a first and a last page, even of an empty listing.
+/// Pages past the end link back to the last one.
+fn links(uri: &Uri, page: i64, total_pages: i64) -> String {
+    let last = total_pages.max(1);
+    let mut relations = vec![("first", 1)];
+    if page > 1 {
+        relations.push(("prev", (page - 1).min(last)));
+    }
+    if page < last {
+        relations.push(("next", page + 1));
+    }
+    relations.push(("last", last));
+
+    relations
+        .into_iter()
+        .map(|(relation, page)| format!("<{}>; rel=\"{}\"", page_uri(uri, page), relation))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `uri` with its `page` query parameter set to `page`
+fn page_uri(uri: &Uri, page: i64) -> String {
+    let mut parameters: Vec<String> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|parameter| !parameter.is_empty() && !parameter.starts_with("page="))
+        .map(str::to_string)
+        .collect();
+    parameters.push(format!("page={}", page));
+    format!("{}?{}", uri.path(), parameters.join("&"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_keep_other_parameters_and_stop_at_the_ends() {
+        let uri: Uri = "/api/flowers?color=red&page=2&per_page=5".parse().unwrap();
+        assert_eq!(
+            links(&uri, 2, 3),
+            "</api/flowers?color=red&per_page=5&page=1>; rel=\"first\", \
+             </api/flowers?color=red&per_page=5&page=1>; rel=\"prev\", \
+             </api/flowers?color=red&per_page=5&page=3>; rel=\"next\", \
+             </api/flowers?color=red&per_page=5&page=3>; rel=\"last\""
+        );
+
+        let first: Uri = "/api/flowers".parse().unwrap();
+        assert_eq!(
+            links(&first, 1, 0),
+            "</api/flowers?page=1>; rel=\"first\", </api/flowers?page=1>; rel=\"last\""
+        );
+
+        let beyond: Uri = "/api/flowers?page=9".parse().unwrap();
+        assert_eq!(
+            links(&beyond, 9, 2),
+            "</api/flowers?page=1>; rel=\"first\", </api/flowers?page=2>; rel=\"prev\", \
+             </api/flowers?page=2>; rel=\"last\""
+        );
+    }
+}
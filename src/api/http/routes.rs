@@ -1,42 +1,502 @@
 //! HTTP Routes configuration
 
 use axum::{
-    Router,
-    routing::{delete, get, post, put},
+    Router, middleware,
+    routing::{MethodRouter, delete, get, post, put},
 };
-use utoipa::OpenApi;
 use utoipa_scalar::{Scalar, Servable};
 
 use super::handlers::{
-    create_flower, delete_flower, get_flower, health_check, list_flowers, update_flower,
+    adjust_prices, adjust_stock, cancel_order, check_delivery, create_backup,
+    create_catalog_export, create_delivery_zone, create_flower, create_order, create_pricing_rule,
+    create_saved_search, create_store, create_webhook_endpoint, delete_delivery_zone,
+    delete_flower, delete_pricing_rule, delete_saved_search, delete_store, delete_webhook_endpoint,
+    download_catalog_export, flower_availability, flower_barcode, flower_filters, flower_history,
+    flower_qr_code, get_catalog_export, get_flower, get_order, get_pricing_rule, health_check,
+    list_colors, list_delivery_zones, list_failed_tasks, list_feature_flags, list_flowers,
+    list_jobs, list_pricing_rules, list_quotas, list_saved_searches, list_stock_movements,
+    list_stores, list_webhook_endpoints, liveness, metrics, openapi_json, openapi_yaml,
+    order_events, payment_webhook, pool_stats, purge_cache, purge_flower, readiness,
+    recently_viewed, restore_backup, rotate_webhook_secret, set_quota, set_store_stock,
+    shipping_rates, sync_supplier, test_webhook_endpoint, trending_flowers, update_feature_flag,
+    update_flower, update_order_status, update_pricing_rule, usage_report, version,
+};
+use super::middleware::{
+    Access, CachePolicy, Freshness, IpFilter, MiddlewareStack, TenantResolver, authorize,
+    cache_for, filter_ip, require_admin, resolve_tenant,
 };
 use super::openapi::ApiDoc;
 use super::state::AppState;
+use crate::application::authorization::{Action, ResourceKind};
+use crate::infrastructure::config::AppConfig;
 
 /// Create the main HTTP router
-pub fn create_router(state: AppState) -> Router {
-    Router::new()
-        // OpenAPI Scalar UI
-        .merge(Scalar::with_url("/openapi", ApiDoc::openapi()))
-        // Health check
+pub fn create_router(state: AppState, config: &AppConfig) -> Router {
+    let stack = MiddlewareStack::from_config(config);
+
+    let router = Router::new()
+        // OpenAPI documentation
+        .merge(docs_routes())
+        // Health checks
         .route("/health", get(health_check))
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
+        // Build information
+        .route("/version", get(version))
+        // Prometheus metrics
+        .route("/metrics", get(metrics))
         // API routes
-        .nest("/api", api_routes())
-        .with_state(state)
+        .nest("/api", stack.api(api_routes(config), &state));
+
+    stack.service(router, state)
+}
+
+/// API documentation: the Scalar UI, the raw document and, when built with
+/// their features, Swagger UI and Redoc reading `/openapi.json`
+fn docs_routes() -> Router<AppState> {
+    let router = Router::new()
+        .merge(Scalar::with_url("/openapi", ApiDoc::document()))
+        .route("/openapi.json", get(openapi_json))
+        .route("/openapi.yaml", get(openapi_yaml));
+
+    #[cfg(feature = "swagger-ui")]
+    let router = router.merge(
+        utoipa_swagger_ui::SwaggerUi::new("/docs/swagger")
+            .config(utoipa_swagger_ui::Config::new(["/openapi.json"]).persist_authorization(true)),
+    );
+
+    #[cfg(feature = "redoc")]
+    let router = router.route("/docs/redoc", get(super::handlers::redoc));
+
+    router
 }
 
 /// API routes under /api prefix
-fn api_routes() -> Router<AppState> {
-    Router::new().nest("/flowers", flower_routes())
+///
+/// Every route declares the action it performs and on what, see `guard`.
+/// Callers are identified by the `MiddlewareStack` wrapped around them.
+/// Public routes and admin routes live in separate routers, so a route is
+/// admin only by where it is mounted rather than by its own rule.
+fn api_routes(config: &AppConfig) -> Router<AppState> {
+    let access = Access::from_config(config);
+    let caching = CachePolicy::from_config(config);
+
+    Router::new()
+        .nest(
+            "/flowers",
+            flower_routes(&access, &caching).route_layer(middleware::from_fn_with_state(
+                TenantResolver::from_config(config),
+                resolve_tenant,
+            )),
+        )
+        .nest(
+            "/stores",
+            store_routes(&access).route_layer(middleware::from_fn_with_state(
+                TenantResolver::from_config(config),
+                resolve_tenant,
+            )),
+        )
+        .nest(
+            "/delivery-zones",
+            delivery_zone_routes(&access).route_layer(middleware::from_fn_with_state(
+                TenantResolver::from_config(config),
+                resolve_tenant,
+            )),
+        )
+        .nest(
+            "/shipping",
+            shipping_routes(&access).route_layer(middleware::from_fn_with_state(
+                TenantResolver::from_config(config),
+                resolve_tenant,
+            )),
+        )
+        .nest(
+            "/orders",
+            order_routes(&access).route_layer(middleware::from_fn_with_state(
+                TenantResolver::from_config(config),
+                resolve_tenant,
+            )),
+        )
+        .nest(
+            "/saved-searches",
+            saved_search_routes(&access).route_layer(middleware::from_fn_with_state(
+                TenantResolver::from_config(config),
+                resolve_tenant,
+            )),
+        )
+        .nest(
+            "/webhook-endpoints",
+            webhook_endpoint_routes(&access).route_layer(middleware::from_fn_with_state(
+                TenantResolver::from_config(config),
+                resolve_tenant,
+            )),
+        )
+        .nest(
+            "/me",
+            me_routes(&access).route_layer(middleware::from_fn_with_state(
+                TenantResolver::from_config(config),
+                resolve_tenant,
+            )),
+        )
+        .nest("/admin", admin_routes(config, &access))
+        .nest("/webhooks", webhook_routes())
     // Future: .nest("/other", other_routes())
 }
 
+/// Webhook routes: /api/webhooks, callbacks of integrations
+///
+/// They take no credentials, so they declare no rule: the signature of the
+/// body is checked by the `SignedWebhook` extractor of each handler.
+fn webhook_routes() -> Router<AppState> {
+    Router::new().route("/payments", post(payment_webhook))
+}
+
+/// Admin routes: /api/admin, for the admin token only and behind the IP
+/// filter
+fn admin_routes(config: &AppConfig, access: &Access) -> Router<AppState> {
+    use Action::{Create, Delete, Manage, Read, Update};
+    use ResourceKind::{
+        Backups, Cache, CatalogExports, Database, FeatureFlags, FlowerPurge, Jobs, Ledger,
+        PricingRules, Suppliers, Tasks, Usage,
+    };
+
+    Router::new()
+        .route(
+            "/feature-flags",
+            guard(access, Read, FeatureFlags, get(list_feature_flags)),
+        )
+        .route(
+            "/feature-flags/{key}",
+            guard(access, Manage, FeatureFlags, put(update_feature_flag)),
+        )
+        .route(
+            "/tasks/failed",
+            guard(access, Read, Tasks, get(list_failed_tasks)),
+        )
+        .route("/jobs", guard(access, Read, Jobs, get(list_jobs)))
+        .route(
+            "/audit/stock-movements",
+            guard(access, Read, Ledger, get(list_stock_movements)),
+        )
+        .route(
+            "/tenants/{tenant}/flowers/{id}",
+            guard(access, Delete, FlowerPurge, delete(purge_flower)),
+        )
+        .route(
+            "/tenants/{tenant}/pricing-rules",
+            guard(access, Read, PricingRules, get(list_pricing_rules)),
+        )
+        .route(
+            "/tenants/{tenant}/pricing-rules",
+            guard(access, Create, PricingRules, post(create_pricing_rule)),
+        )
+        .route(
+            "/tenants/{tenant}/pricing-rules/{id}",
+            guard(access, Read, PricingRules, get(get_pricing_rule)),
+        )
+        .route(
+            "/tenants/{tenant}/pricing-rules/{id}",
+            guard(access, Update, PricingRules, put(update_pricing_rule)),
+        )
+        .route(
+            "/tenants/{tenant}/pricing-rules/{id}",
+            guard(access, Delete, PricingRules, delete(delete_pricing_rule)),
+        )
+        .route(
+            "/cache/purge",
+            guard(access, Manage, Cache, post(purge_cache)),
+        )
+        .route("/db/pool", guard(access, Read, Database, get(pool_stats)))
+        .route("/usage", guard(access, Read, Usage, get(usage_report)))
+        .route("/quotas", guard(access, Read, Usage, get(list_quotas)))
+        .route("/quotas", guard(access, Manage, Usage, put(set_quota)))
+        .route(
+            "/backup",
+            guard(access, Manage, Backups, post(create_backup)),
+        )
+        .route(
+            "/backup/{id}/restore",
+            guard(access, Manage, Backups, post(restore_backup)),
+        )
+        .route(
+            "/catalog-exports",
+            guard(access, Create, CatalogExports, post(create_catalog_export)),
+        )
+        .route(
+            "/catalog-exports/{id}",
+            guard(access, Read, CatalogExports, get(get_catalog_export)),
+        )
+        .route(
+            "/catalog-exports/{id}/catalog.html",
+            guard(access, Read, CatalogExports, get(download_catalog_export)),
+        )
+        .route(
+            "/sync/suppliers/{id}",
+            guard(access, Manage, Suppliers, post(sync_supplier)),
+        )
+        .route_layer(middleware::from_fn(require_admin))
+        .route_layer(middleware::from_fn_with_state(
+            IpFilter::admin(config),
+            filter_ip,
+        ))
+}
+
 /// Flower routes: /api/flowers
-fn flower_routes() -> Router<AppState> {
+///
+/// Reads are cacheable, see `cached`; labels set their own caching.
+fn flower_routes(access: &Access, caching: &CachePolicy) -> Router<AppState> {
+    use Action::{Create, Delete, Read, Update};
+    use ResourceKind::Flowers;
+
     Router::new()
-        .route("/", get(list_flowers))
-        .route("/", post(create_flower))
-        .route("/{id}", get(get_flower))
-        .route("/{id}", put(update_flower))
-        .route("/{id}", delete(delete_flower))
+        .route(
+            "/",
+            guard(
+                access,
+                Read,
+                Flowers,
+                cached(caching.list, get(list_flowers)),
+            ),
+        )
+        .route("/", guard(access, Create, Flowers, post(create_flower)))
+        .route(
+            "/colors",
+            guard(
+                access,
+                Read,
+                Flowers,
+                cached(caching.list, get(list_colors)),
+            ),
+        )
+        .route(
+            "/filters",
+            guard(
+                access,
+                Read,
+                Flowers,
+                cached(caching.list, get(flower_filters)),
+            ),
+        )
+        .route(
+            "/trending",
+            guard(
+                access,
+                Read,
+                Flowers,
+                cached(caching.list, get(trending_flowers)),
+            ),
+        )
+        .route(
+            "/price-adjustments",
+            guard(access, Update, Flowers, post(adjust_prices)),
+        )
+        .route(
+            "/{id}",
+            guard(
+                access,
+                Read,
+                Flowers,
+                cached(caching.detail, get(get_flower)),
+            ),
+        )
+        .route("/{id}", guard(access, Update, Flowers, put(update_flower)))
+        .route(
+            "/{id}",
+            guard(access, Delete, Flowers, delete(delete_flower)),
+        )
+        .route(
+            "/{id}/stock-adjustments",
+            guard(access, Update, Flowers, post(adjust_stock)),
+        )
+        .route(
+            "/{id}/history",
+            guard(access, Read, Flowers, get(flower_history)),
+        )
+        .route(
+            "/{id}/qr.png",
+            guard(access, Read, Flowers, get(flower_qr_code)),
+        )
+        .route(
+            "/{id}/barcode.png",
+            guard(access, Read, Flowers, get(flower_barcode)),
+        )
+        .route(
+            "/{id}/availability",
+            guard(access, Read, Flowers, get(flower_availability)),
+        )
+}
+
+/// Store routes: /api/stores
+fn store_routes(access: &Access) -> Router<AppState> {
+    use Action::{Create, Delete, Read, Update};
+    use ResourceKind::Stores;
+
+    Router::new()
+        .route("/", guard(access, Read, Stores, get(list_stores)))
+        .route("/", guard(access, Create, Stores, post(create_store)))
+        .route("/{id}", guard(access, Delete, Stores, delete(delete_store)))
+        .route(
+            "/{id}/stock/{flower_id}",
+            guard(access, Update, Stores, put(set_store_stock)),
+        )
+}
+
+/// Delivery zone routes: /api/delivery-zones
+fn delivery_zone_routes(access: &Access) -> Router<AppState> {
+    use Action::{Create, Delete, Read};
+    use ResourceKind::DeliveryZones;
+
+    Router::new()
+        .route(
+            "/",
+            guard(access, Read, DeliveryZones, get(list_delivery_zones)),
+        )
+        .route(
+            "/",
+            guard(access, Create, DeliveryZones, post(create_delivery_zone)),
+        )
+        .route(
+            "/check",
+            guard(access, Read, DeliveryZones, get(check_delivery)),
+        )
+        .route(
+            "/{id}",
+            guard(access, Delete, DeliveryZones, delete(delete_delivery_zone)),
+        )
+}
+
+/// Shipping routes: /api/shipping, quoting deliveries like delivery zones
+/// are checked
+fn shipping_routes(access: &Access) -> Router<AppState> {
+    Router::new().route(
+        "/rates",
+        guard(
+            access,
+            Action::Read,
+            ResourceKind::DeliveryZones,
+            get(shipping_rates),
+        ),
+    )
+}
+
+/// Order routes: /api/orders, for callers with credentials
+fn order_routes(access: &Access) -> Router<AppState> {
+    use Action::{Create, Read, Update};
+    use ResourceKind::Orders;
+
+    Router::new()
+        .route("/", guard(access, Create, Orders, post(create_order)))
+        .route("/{id}", guard(access, Read, Orders, get(get_order)))
+        .route(
+            "/{id}/events",
+            guard(access, Read, Orders, get(order_events)),
+        )
+        .route(
+            "/{id}/status",
+            guard(access, Update, Orders, put(update_order_status)),
+        )
+        .route(
+            "/{id}/cancel",
+            guard(access, Update, Orders, post(cancel_order)),
+        )
+}
+
+/// Saved search routes: /api/saved-searches, for callers with credentials
+fn saved_search_routes(access: &Access) -> Router<AppState> {
+    use Action::{Create, Delete, Read};
+    use ResourceKind::SavedSearches;
+
+    Router::new()
+        .route(
+            "/",
+            guard(access, Read, SavedSearches, get(list_saved_searches)),
+        )
+        .route(
+            "/",
+            guard(access, Create, SavedSearches, post(create_saved_search)),
+        )
+        .route(
+            "/{id}",
+            guard(access, Delete, SavedSearches, delete(delete_saved_search)),
+        )
+}
+
+/// Webhook endpoint routes: /api/webhook-endpoints, for callers with
+/// credentials
+fn webhook_endpoint_routes(access: &Access) -> Router<AppState> {
+    use Action::{Create, Delete, Read, Update};
+    use ResourceKind::WebhookEndpoints;
+
+    Router::new()
+        .route(
+            "/",
+            guard(access, Read, WebhookEndpoints, get(list_webhook_endpoints)),
+        )
+        .route(
+            "/",
+            guard(
+                access,
+                Create,
+                WebhookEndpoints,
+                post(create_webhook_endpoint),
+            ),
+        )
+        .route(
+            "/{id}",
+            guard(
+                access,
+                Delete,
+                WebhookEndpoints,
+                delete(delete_webhook_endpoint),
+            ),
+        )
+        .route(
+            "/{id}/rotate-secret",
+            guard(
+                access,
+                Update,
+                WebhookEndpoints,
+                post(rotate_webhook_secret),
+            ),
+        )
+        .route(
+            "/{id}/test",
+            guard(
+                access,
+                Update,
+                WebhookEndpoints,
+                post(test_webhook_endpoint),
+            ),
+        )
+}
+
+/// Routes about the caller: /api/me
+fn me_routes(access: &Access) -> Router<AppState> {
+    use Action::Read;
+    use ResourceKind::RecentlyViewed;
+
+    Router::new().route(
+        "/recently-viewed",
+        guard(access, Read, RecentlyViewed, get(recently_viewed)),
+    )
+}
+
+/// Let caches reuse successful responses of `route` for `freshness`;
+/// responses of routes without it are never stored
+fn cached(freshness: Freshness, route: MethodRouter<AppState>) -> MethodRouter<AppState> {
+    route.route_layer(middleware::from_fn_with_state(freshness, cache_for))
+}
+
+/// Let `route` through only for callers the policy allows to perform `action`
+/// on resources of `kind`
+fn guard(
+    access: &Access,
+    action: Action,
+    kind: ResourceKind,
+    route: MethodRouter<AppState>,
+) -> MethodRouter<AppState> {
+    route.route_layer(middleware::from_fn_with_state(
+        access.rule(action, kind),
+        authorize,
+    ))
 }
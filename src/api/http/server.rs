@@ -0,0 +1,184 @@
+//! HTTP(S) Server
+
+use std::io;
+use std::net::SocketAddr;
+
+use axum::Router;
+
+use crate::infrastructure::config::AppConfig;
+
+/// Serve the application over HTTP, or HTTPS when a certificate is configured
+pub async fn serve(app: Router, config: &AppConfig) -> io::Result<()> {
+    match (&config.tls_cert_path, &config.tls_key_path) {
+        (None, None) => serve_plain(app, config).await,
+        (Some(_), Some(_)) => serve_tls(app, config).await,
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "TLS_CERT_PATH and TLS_KEY_PATH must be set together",
+        )),
+    }
+}
+
+async fn serve_plain(app: Router, config: &AppConfig) -> io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(&config.server_addr()).await?;
+    log_started("http", config);
+
+    // Peer addresses are needed by the admin IP filter
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+}
+
+#[cfg(not(feature = "tls"))]
+async fn serve_tls(_app: Router, _config: &AppConfig) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "TLS_CERT_PATH is set but the binary was built without the `tls` feature",
+    ))
+}
+
+#[cfg(feature = "tls")]
+async fn serve_tls(app: Router, config: &AppConfig) -> io::Result<()> {
+    use axum_server::tls_rustls::RustlsConfig;
+
+    let (Some(cert_path), Some(key_path)) = (&config.tls_cert_path, &config.tls_key_path) else {
+        unreachable!("checked by serve");
+    };
+
+    // Ignore the error if another component already installed a provider
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    let tls_config = RustlsConfig::from_pem_file(cert_path, key_path).await?;
+    tls::spawn_reload_on_sighup(tls_config.clone(), cert_path.clone(), key_path.clone());
+
+    if let Some(redirect_port) = config.http_redirect_port {
+        tls::spawn_https_redirect(
+            config.server_host.clone(),
+            redirect_port,
+            config.server_port,
+        );
+    }
+
+    let addr: SocketAddr = config
+        .server_addr()
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    log_started("https", config);
+
+    axum_server::bind_rustls(addr, tls_config)
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
+        .await
+}
+
+fn log_started(scheme: &str, config: &AppConfig) {
+    tracing::info!(
+        "🌸 Flower API is running on {}://{}",
+        scheme,
+        config.server_addr()
+    );
+    tracing::info!(
+        "📚 OpenAPI docs available at {}://{}/openapi",
+        scheme,
+        config.server_addr()
+    );
+}
+
+#[cfg(feature = "tls")]
+mod tls {
+    use std::path::PathBuf;
+
+    use axum::{
+        Router,
+        extract::Request,
+        http::{StatusCode, Uri, header::HOST},
+        response::{IntoResponse, Redirect, Response},
+    };
+    use axum_server::tls_rustls::RustlsConfig;
+
+    /// Reload the certificate and key from disk whenever SIGHUP is received
+    pub fn spawn_reload_on_sighup(config: RustlsConfig, cert_path: PathBuf, key_path: PathBuf) {
+        tokio::spawn(async move {
+            use tokio::signal::unix::{SignalKind, signal};
+
+            let mut hangups = match signal(SignalKind::hangup()) {
+                Ok(hangups) => hangups,
+                Err(e) => {
+                    tracing::warn!(
+                        "Cannot listen for SIGHUP, certificate reload disabled: {}",
+                        e
+                    );
+                    return;
+                }
+            };
+
+            while hangups.recv().await.is_some() {
+                match config.reload_from_pem_file(&cert_path, &key_path).await {
+                    Ok(()) => {
+                        tracing::info!("Reloaded TLS certificate from {}", cert_path.display())
+                    }
+                    Err(e) => tracing::error!(
+                        "Failed to reload TLS certificate, keeping the old one: {}",
+                        e
+                    ),
+                }
+            }
+        });
+    }
+
+    /// Listen on plain HTTP and redirect every request to HTTPS
+    pub fn spawn_https_redirect(host: String, http_port: u16, https_port: u16) {
+        tokio::spawn(async move {
+            let app = Router::new().fallback(move |request: Request| async move {
+                redirect_to_https(&request, https_port)
+            });
+
+            let addr = format!("{}:{}", host, http_port);
+            let listener = match tokio::net::TcpListener::bind(&addr).await {
+                Ok(listener) => listener,
+                Err(e) => {
+                    tracing::error!("Failed to bind HTTP redirect listener on {}: {}", addr, e);
+                    return;
+                }
+            };
+            tracing::info!("↪️  Redirecting http://{} to HTTPS", addr);
+
+            if let Err(e) = axum::serve(listener, app).await {
+                tracing::error!("HTTP redirect listener stopped: {}", e);
+            }
+        });
+    }
+
+    fn redirect_to_https(request: &Request, https_port: u16) -> Response {
+        let Some(host) = request
+            .headers()
+            .get(HOST)
+            .and_then(|host| host.to_str().ok())
+            .and_then(|host| host.parse::<axum::http::uri::Authority>().ok())
+        else {
+            return StatusCode::BAD_REQUEST.into_response();
+        };
+
+        let authority = if https_port == 443 {
+            host.host().to_string()
+        } else {
+            format!("{}:{}", host.host(), https_port)
+        };
+        let path = request
+            .uri()
+            .path_and_query()
+            .map(|path| path.as_str())
+            .unwrap_or("/");
+
+        match Uri::builder()
+            .scheme("https")
+            .authority(authority)
+            .path_and_query(path)
+            .build()
+        {
+            Ok(uri) => Redirect::permanent(&uri.to_string()).into_response(),
+            Err(_) => StatusCode::BAD_REQUEST.into_response(),
+        }
+    }
+}
@@ -2,18 +2,310 @@
 
 use std::sync::Arc;
 
-use crate::application::usecases::FlowerUseCase;
-use crate::infrastructure::persistance::PostgresFlowerRepository;
+use metrics_exporter_prometheus::PrometheusHandle;
+
+use crate::application::health::HealthRegistry;
+use crate::application::jobs::JobMonitor;
+use crate::application::ports::{
+    Cache, FeatureFlagRepository, FlowerRepository, PaymentGateway, TaskQueue, UnitOfWork,
+    WebhookSender,
+};
+use crate::application::usecases::{
+    Administration, Backups, CatalogExports, DeliveryZones, FeatureFlags, FlowerChanges,
+    FlowerLabels, FlowerUseCase, FlowerViews, Orders, Pricing, SavedSearches, Shipping, Stores,
+    SupplierSync, Tasks, UsageMetering, Webhooks,
+};
+use crate::application::webhooks::WebhookVerifier;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::cache::{
+    CacheHealth, CacheStorePurger, CachedFlowerRepository, CachedUnitOfWork,
+};
+use crate::infrastructure::config::AppConfig;
+use crate::infrastructure::labels::PngLabelRenderer;
+use crate::infrastructure::object_store::ObjectStoreHealth;
+use crate::infrastructure::payments::ConsolePaymentGateway;
+use crate::infrastructure::persistance::DatabasePool;
+use crate::infrastructure::storage::Storage;
+use crate::infrastructure::{metrics, object_store, shipping, suppliers, webhooks};
 
 /// Shared application state for HTTP handlers
 #[derive(Clone)]
 pub struct AppState {
-    pub flower_usecase: Arc<FlowerUseCase<PostgresFlowerRepository>>,
+    pub flower_usecase: Arc<FlowerUseCase<dyn FlowerRepository>>,
+    pub views: Arc<FlowerViews<dyn FlowerRepository>>,
+    pub changes: Arc<FlowerChanges<dyn FlowerRepository>>,
+    pub labels: Arc<FlowerLabels<dyn FlowerRepository>>,
+    pub saved_searches: Arc<SavedSearches>,
+    pub stores: Arc<Stores<dyn FlowerRepository>>,
+    pub delivery_zones: Arc<DeliveryZones>,
+    pub shipping: Arc<Shipping>,
+    pub orders: Arc<Orders>,
+    pub pricing: Arc<Pricing>,
+    pub feature_flags: Arc<FeatureFlags<dyn FeatureFlagRepository>>,
+    pub tasks: Arc<Tasks<dyn TaskQueue>>,
+    pub backups: Arc<Backups>,
+    pub catalog_exports: Arc<CatalogExports<dyn FlowerRepository>>,
+    pub suppliers: Arc<SupplierSync<dyn FlowerRepository>>,
+    pub administration: Arc<Administration<dyn FlowerRepository>>,
+    /// Requests metered per tenant and API key
+    pub usage: Arc<UsageMetering>,
+    /// Checks the payment provider's callbacks; `None` when no secret is
+    /// configured
+    pub payment_webhooks: Option<Arc<WebhookVerifier>>,
+    /// Endpoints tenants have their events delivered to
+    pub webhooks: Arc<Webhooks>,
+    /// Scheduled jobs of this instance
+    pub jobs: JobMonitor,
+    /// Components checked by the readiness probe
+    pub health: HealthRegistry,
+    /// Database pool, `None` when running on in-memory storage
+    pub db: Option<DatabasePool>,
+    pub metrics: PrometheusHandle,
     // Future: pub other_usecase: Arc<OtherUseCase<...>>,
 }
 
 impl AppState {
-    pub fn new(flower_usecase: Arc<FlowerUseCase<PostgresFlowerRepository>>) -> Self {
-        Self { flower_usecase }
+    /// Builder wiring the use cases to `storage` as configured
+    pub fn builder<'a>(config: &'a AppConfig, storage: &'a Storage) -> AppStateBuilder<'a> {
+        AppStateBuilder {
+            config,
+            storage,
+            flowers: storage.flowers.clone(),
+            cache: None,
+            payments: Arc::new(ConsolePaymentGateway),
+            webhook_sender: None,
+        }
+    }
+}
+
+/// Composes the object graph behind `AppState`
+///
+/// Every use case gets its repositories from the storage the builder was
+/// created with; the `with_*` methods swap in alternatives or decorators
+/// before anything is wired, so handlers never see the difference.
+pub struct AppStateBuilder<'a> {
+    config: &'a AppConfig,
+    storage: &'a Storage,
+    flowers: Arc<dyn FlowerRepository>,
+    cache: Option<Arc<dyn Cache>>,
+    payments: Arc<dyn PaymentGateway>,
+    webhook_sender: Option<Arc<dyn WebhookSender>>,
+}
+
+impl AppStateBuilder<'_> {
+    /// Serve flowers from `flowers` rather than the storage's repository,
+    /// such as the storage's repository wrapped in a decorator
+    pub fn with_flowers(mut self, flowers: Arc<dyn FlowerRepository>) -> Self {
+        self.flowers = flowers;
+        self
+    }
+
+    /// Put `cache` in front of flower lookups, and let admins purge it
+    pub fn with_cache(mut self, cache: Option<Arc<dyn Cache>>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Settle refunds through `payments`; by default they are settled by hand
+    pub fn with_payments(mut self, payments: Arc<dyn PaymentGateway>) -> Self {
+        self.payments = payments;
+        self
+    }
+
+    /// Deliver tenants' webhooks through `sender` rather than the sender of
+    /// this build
+    pub fn with_webhook_sender(mut self, sender: Arc<dyn WebhookSender>) -> Self {
+        self.webhook_sender = Some(sender);
+        self
+    }
+
+    /// Wire the use cases and load the feature flags they start with
+    pub async fn build(self) -> DomainResult<AppState> {
+        let Self {
+            config,
+            storage,
+            flowers,
+            cache,
+            payments,
+            webhook_sender,
+        } = self;
+
+        // Adjust the prices flowers are served at with the tenants' pricing rules
+        let pricing = Arc::new(Pricing::new(storage.pricing_rules.clone()));
+
+        let (flowers, unit_of_work): (Arc<dyn FlowerRepository>, Arc<dyn UnitOfWork>) = match &cache
+        {
+            Some(store) => (
+                Arc::new(CachedFlowerRepository::new(
+                    flowers,
+                    store.clone(),
+                    config.cache_ttl,
+                    config.cache_list_pages,
+                )),
+                Arc::new(CachedUnitOfWork::new(
+                    storage.unit_of_work.clone(),
+                    store.clone(),
+                )),
+            ),
+            None => (flowers, storage.unit_of_work.clone()),
+        };
+        let flower_usecase =
+            Arc::new(FlowerUseCase::new(flowers, unit_of_work).with_pricing(pricing.clone()));
+
+        // Count flower views for trending flowers
+        let views = Arc::new(
+            FlowerViews::new(flower_usecase.repository(), storage.views.clone())
+                .with_pricing(pricing.clone()),
+        );
+
+        // List the changes made to flowers
+        let changes = Arc::new(FlowerChanges::new(
+            flower_usecase.repository(),
+            storage.history.clone(),
+        ));
+
+        // Render shelf labels
+        let labels = Arc::new(FlowerLabels::new(
+            flower_usecase.repository(),
+            Arc::new(PngLabelRenderer::new()),
+            config.product_url_template.clone(),
+        ));
+
+        // Keep searches of callers
+        let saved_searches = Arc::new(SavedSearches::new(storage.saved_searches.clone()));
+
+        // Find stores to pick flowers up from
+        let stores = Arc::new(Stores::new(
+            storage.stores.clone(),
+            flower_usecase.repository(),
+        ));
+
+        // Check which addresses are delivered to
+        let delivery_zones = Arc::new(DeliveryZones::new(storage.delivery_zones.clone()));
+
+        // Quote deliveries from the rate table and couriers
+        let shipping = Arc::new(Shipping::new(shipping::providers(config)?));
+
+        // Queue deferred work
+        let tasks = Arc::new(Tasks::new(storage.tasks.clone(), config.task_max_attempts));
+
+        // Deliver events to the endpoints tenants registered
+        let webhook_sender = match webhook_sender {
+            Some(sender) => sender,
+            None => webhooks::sender(config)?,
+        };
+        let webhooks = Arc::new(Webhooks::new(
+            storage.webhook_endpoints.clone(),
+            webhook_sender,
+            tasks.clone(),
+            config.webhook_rotation_window,
+        ));
+
+        // Take orders, reserving stock through the flower use case's transactions
+        let orders = Arc::new(
+            Orders::new(
+                storage.orders.clone(),
+                flower_usecase.unit_of_work(),
+                shipping.clone(),
+                pricing.clone(),
+                payments,
+                config.restocking_fee_percent,
+            )
+            .with_webhooks(webhooks.clone()),
+        );
+
+        let feature_flags = Arc::new(FeatureFlags::new(
+            storage.feature_flags.clone(),
+            config.feature_flags.clone(),
+        ));
+        feature_flags.refresh().await?;
+
+        // Export the catalog in the background
+        let objects = object_store::store(config);
+        let catalog_exports = Arc::new(CatalogExports::new(
+            flower_usecase.repository(),
+            tasks.clone(),
+            objects.clone(),
+        ));
+
+        // Synchronize the catalog with supplier feeds
+        let suppliers = Arc::new(SupplierSync::new(
+            flower_usecase.clone(),
+            suppliers::feed(config)?,
+            config.suppliers.clone(),
+        ));
+
+        let backups = Arc::new(Backups::new(storage.dump.clone(), objects.clone()));
+        let administration = Administration::new(
+            flower_usecase.repository(),
+            storage.ledger.clone(),
+            storage.history.clone(),
+            storage.views.clone(),
+        );
+        let administration = Arc::new(match cache.clone() {
+            Some(store) => administration.with_cache(Arc::new(CacheStorePurger::new(store))),
+            None => administration,
+        });
+
+        // Meter API usage for billing and hold API keys to their quotas;
+        // without API keys there is nothing to fingerprint
+        let fingerprint_secret = match &config.api_key_fingerprint_secret {
+            Some(secret) => secret.as_str(),
+            None if config.tenant_api_keys.is_empty() => "",
+            None => {
+                return Err(AppError::internal(
+                    "API_KEY_FINGERPRINT_SECRET is required with TENANT_API_KEYS",
+                ));
+            }
+        };
+        let usage = Arc::new(
+            UsageMetering::new(storage.usage.clone(), fingerprint_secret)
+                .with_quotas(config.api_key_monthly_quota, config.tenant_api_keys.clone()),
+        );
+        usage.refresh().await?;
+
+        let payment_webhooks = (!config.payment_webhook_secrets.is_empty()).then(|| {
+            Arc::new(WebhookVerifier::new(
+                "payments",
+                config.payment_webhook_secrets.clone(),
+                config.webhook_tolerance,
+                storage.nonces.clone(),
+            ))
+        });
+
+        // Check what the service depends on when asked whether it is ready
+        let mut health = storage.register_health(HealthRegistry::new());
+        if let Some(cache) = cache {
+            health = health.register(CacheHealth(cache));
+        }
+        if let Some(objects) = objects {
+            health = health.register(ObjectStoreHealth(objects));
+        }
+
+        Ok(AppState {
+            flower_usecase,
+            views,
+            changes,
+            labels,
+            saved_searches,
+            stores,
+            delivery_zones,
+            shipping,
+            orders,
+            pricing,
+            feature_flags,
+            tasks,
+            backups,
+            catalog_exports,
+            suppliers,
+            administration,
+            usage,
+            payment_webhooks,
+            webhooks,
+            jobs: JobMonitor::new(),
+            health,
+            db: storage.db.clone(),
+            metrics: metrics::install(),
+        })
     }
 }
@@ -0,0 +1,90 @@
+//! Signed Webhooks
+//!
+//! Inbound integrations, such as the payment provider's callbacks, take
+//! their body through [`SignedWebhook`]: it is refused with 401 unless it is
+//! signed by a secret of the integration within its tolerance, and with 409
+//! when the delivery was accepted before. Another integration adopts it with
+//! a [`WebhookSource`] saying where its verifier is. See
+//! `application::webhooks` for how deliveries are signed.
+
+use std::marker::PhantomData;
+
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Request},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+};
+use serde::de::DeserializeOwned;
+
+use crate::api::http::state::AppState;
+use crate::application::ports::{SIGNATURE_HEADER, WEBHOOK_ID_HEADER, WEBHOOK_TIMESTAMP_HEADER};
+use crate::application::webhooks::{SignedDelivery, WebhookVerifier};
+use crate::domain::errors::AppError;
+use crate::i18n::Message;
+
+/// An inbound integration whose deliveries are signed
+pub trait WebhookSource: Send + Sync + 'static {
+    /// Verifier of the integration; `None` when it is not configured, and
+    /// its deliveries are refused
+    fn verifier(state: &AppState) -> Option<&WebhookVerifier>;
+}
+
+/// Callbacks of the payment provider, signed with PAYMENT_WEBHOOK_SECRETS
+pub struct PaymentProvider;
+
+impl WebhookSource for PaymentProvider {
+    fn verifier(state: &AppState) -> Option<&WebhookVerifier> {
+        state.payment_webhooks.as_deref()
+    }
+}
+
+/// JSON body of a delivery from `S`, taken once its signature is verified
+pub struct SignedWebhook<S, T> {
+    /// Id of the delivery
+    pub id: String,
+    pub payload: T,
+    source: PhantomData<fn() -> S>,
+}
+
+impl<S: WebhookSource, T: DeserializeOwned> FromRequest<AppState> for SignedWebhook<S, T> {
+    type Rejection = Response;
+
+    async fn from_request(request: Request, state: &AppState) -> Result<Self, Self::Rejection> {
+        let verifier = S::verifier(state).ok_or_else(|| {
+            AppError::service_unavailable(Message::new("webhook.unconfigured")).into_response()
+        })?;
+        let headers = request.headers().clone();
+        // Too large bodies are answered by `limit_body`
+        let body = Bytes::from_request(request, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        let delivery = SignedDelivery {
+            id: header(&headers, WEBHOOK_ID_HEADER),
+            timestamp: header(&headers, WEBHOOK_TIMESTAMP_HEADER),
+            signatures: header(&headers, SIGNATURE_HEADER),
+        };
+        verifier
+            .verify(delivery, &body)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        let payload = serde_json::from_slice(&body).map_err(|e| {
+            AppError::bad_request(Message::new("webhook.payload.invalid").arg("reason", e))
+                .into_response()
+        })?;
+
+        Ok(Self {
+            id: delivery.id.unwrap_or_default().to_string(),
+            payload,
+            source: PhantomData,
+        })
+    }
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+}
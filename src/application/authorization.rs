@@ -0,0 +1,320 @@
+//! Authorization Policy
+//!
+//! Every route declares the action it performs and the resource it touches;
+//! a single `Policy` decides whether the caller may do that. Rules therefore
+//! live here rather than in handlers.
+
+use std::fmt;
+
+use crate::application::context::RequestContext;
+use crate::domain::shared::TenantId;
+
+/// Who is making a request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Subject {
+    /// Holder of the admin token
+    Admin,
+    /// Caller authenticated with the API key of a tenant
+    Tenant(TenantId),
+    /// Caller without credentials
+    Anonymous,
+}
+
+impl Subject {
+    /// Caller of the request being handled; `None` outside a request
+    pub fn current() -> Option<Self> {
+        RequestContext::current().map(|context| context.subject)
+    }
+
+    /// Who to credit with a change: the current caller, or `system` for
+    /// work done outside a request, such as jobs and seeding
+    pub fn current_actor() -> String {
+        Self::current().map_or_else(|| "system".to_string(), |subject| subject.to_string())
+    }
+
+    /// Run a future with this subject as the current caller, keeping the
+    /// rest of the current request context
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        let context = match RequestContext::current() {
+            Some(context) => RequestContext {
+                subject: self,
+                ..context
+            },
+            None => RequestContext::new(self),
+        };
+        context.scope(future).await
+    }
+}
+
+impl fmt::Display for Subject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Subject::Admin => write!(f, "admin"),
+            Subject::Tenant(tenant) => write!(f, "tenant:{}", tenant),
+            Subject::Anonymous => write!(f, "anonymous"),
+        }
+    }
+}
+
+/// What a request does to its resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Read,
+    Create,
+    Update,
+    Delete,
+    /// Operational changes, such as toggling flags or restoring backups
+    Manage,
+}
+
+impl Action {
+    /// Whether the action leaves the resource unchanged
+    pub fn is_read(self) -> bool {
+        self == Action::Read
+    }
+}
+
+/// What a request touches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resource {
+    /// Flowers of one tenant
+    Flowers(TenantId),
+    /// Searches saved by callers of one tenant
+    SavedSearches(TenantId),
+    /// Flowers of one tenant the caller viewed last
+    RecentlyViewed(TenantId),
+    /// Stores of one tenant and the stock they hold
+    Stores(TenantId),
+    /// Zones one tenant delivers to, and its shipping quotes
+    DeliveryZones(TenantId),
+    /// Orders placed with one tenant
+    Orders(TenantId),
+    /// Receivers one tenant has webhooks delivered to
+    WebhookEndpoints(TenantId),
+    FeatureFlags,
+    Tasks,
+    Backups,
+    CatalogExports,
+    Suppliers,
+    /// Inventory ledger of every tenant
+    Ledger,
+    /// Erasing a flower of any tenant along with its ledger entries and
+    /// history
+    FlowerPurge,
+    /// Scheduled jobs of the instance
+    Jobs,
+    /// Cache in front of flower lookups
+    Cache,
+    /// Database connection pool
+    Database,
+    /// Rules adjusting the prices of every tenant's flowers
+    PricingRules,
+    /// Metered API usage of every tenant
+    Usage,
+}
+
+/// Kind of resource a route touches; the tenant of `Flowers` is only known
+/// once the request has been resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceKind {
+    Flowers,
+    SavedSearches,
+    RecentlyViewed,
+    Stores,
+    DeliveryZones,
+    Orders,
+    WebhookEndpoints,
+    FeatureFlags,
+    Tasks,
+    Backups,
+    CatalogExports,
+    Suppliers,
+    Ledger,
+    FlowerPurge,
+    Jobs,
+    Cache,
+    Database,
+    PricingRules,
+    Usage,
+}
+
+impl fmt::Display for ResourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ResourceKind::Flowers => "flowers",
+            ResourceKind::SavedSearches => "saved_searches",
+            ResourceKind::RecentlyViewed => "recently_viewed",
+            ResourceKind::Stores => "stores",
+            ResourceKind::DeliveryZones => "delivery_zones",
+            ResourceKind::Orders => "orders",
+            ResourceKind::WebhookEndpoints => "webhook_endpoints",
+            ResourceKind::FeatureFlags => "feature_flags",
+            ResourceKind::Tasks => "tasks",
+            ResourceKind::Backups => "backups",
+            ResourceKind::CatalogExports => "catalog_exports",
+            ResourceKind::Suppliers => "suppliers",
+            ResourceKind::Ledger => "ledger",
+            ResourceKind::FlowerPurge => "flower_purge",
+            ResourceKind::Jobs => "jobs",
+            ResourceKind::Cache => "cache",
+            ResourceKind::Database => "database",
+            ResourceKind::PricingRules => "pricing_rules",
+            ResourceKind::Usage => "usage",
+        })
+    }
+}
+
+/// Decides whether a subject may perform an action on a resource
+pub trait Policy: Send + Sync {
+    fn allows(&self, subject: &Subject, action: Action, resource: &Resource) -> bool;
+}
+
+/// Rules of this service
+///
+/// - the admin may do anything;
+/// - a tenant API key grants full access to that tenant's flowers, stores
+///   and delivery zones only;
+/// - anonymous callers may read flowers, stores and delivery zones, and
+///   change them only while `anonymous_writes` is on;
+/// - saved searches and recently viewed flowers need credentials, since they
+///   belong to the caller, and so do orders and webhook endpoints; a tenant
+///   API key reaches its own tenant's only;
+/// - operational resources (flags, tasks, backups, catalog exports,
+///   supplier syncs, the ledger, flower purges, jobs, the cache, the
+///   database pool, pricing rules, usage) are admin only.
+#[derive(Debug, Clone, Copy)]
+pub struct DefaultPolicy {
+    pub anonymous_writes: bool,
+}
+
+impl Policy for DefaultPolicy {
+    fn allows(&self, subject: &Subject, action: Action, resource: &Resource) -> bool {
+        match (subject, resource) {
+            (Subject::Admin, _) => true,
+            (
+                Subject::Tenant(own),
+                Resource::Flowers(tenant)
+                | Resource::Stores(tenant)
+                | Resource::DeliveryZones(tenant)
+                | Resource::SavedSearches(tenant)
+                | Resource::RecentlyViewed(tenant)
+                | Resource::Orders(tenant)
+                | Resource::WebhookEndpoints(tenant),
+            ) => own == tenant && action != Action::Manage,
+            (
+                Subject::Anonymous,
+                Resource::Flowers(_) | Resource::Stores(_) | Resource::DeliveryZones(_),
+            ) => action.is_read() || (self.anonymous_writes && action != Action::Manage),
+            (
+                Subject::Anonymous,
+                Resource::SavedSearches(_)
+                | Resource::RecentlyViewed(_)
+                | Resource::Orders(_)
+                | Resource::WebhookEndpoints(_),
+            ) => false,
+            (
+                _,
+                Resource::FeatureFlags
+                | Resource::Tasks
+                | Resource::Backups
+                | Resource::CatalogExports
+                | Resource::Suppliers
+                | Resource::Ledger
+                | Resource::FlowerPurge
+                | Resource::Jobs
+                | Resource::Cache
+                | Resource::Database
+                | Resource::PricingRules
+                | Resource::Usage,
+            ) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tenant(name: &str) -> TenantId {
+        TenantId::new(name).unwrap()
+    }
+
+    #[test]
+    fn tenant_keys_are_confined_to_their_tenant() {
+        let policy = DefaultPolicy {
+            anonymous_writes: false,
+        };
+        let subject = Subject::Tenant(tenant("kiosk"));
+
+        assert!(policy.allows(
+            &subject,
+            Action::Update,
+            &Resource::Flowers(tenant("kiosk"))
+        ));
+        assert!(!policy.allows(&subject, Action::Read, &Resource::Flowers(tenant("other"))));
+        assert!(!policy.allows(&subject, Action::Read, &Resource::Tasks));
+        assert!(!policy.allows(&subject, Action::Delete, &Resource::FlowerPurge));
+        assert!(policy.allows(
+            &subject,
+            Action::Create,
+            &Resource::SavedSearches(tenant("kiosk"))
+        ));
+        assert!(!policy.allows(
+            &subject,
+            Action::Read,
+            &Resource::SavedSearches(tenant("other"))
+        ));
+    }
+
+    #[test]
+    fn anonymous_writes_follow_the_setting() {
+        let flowers = Resource::Flowers(tenant("kiosk"));
+        let closed = DefaultPolicy {
+            anonymous_writes: false,
+        };
+        let open = DefaultPolicy {
+            anonymous_writes: true,
+        };
+
+        assert!(closed.allows(&Subject::Anonymous, Action::Read, &flowers));
+        assert!(!closed.allows(&Subject::Anonymous, Action::Create, &flowers));
+        let zones = Resource::DeliveryZones(tenant("kiosk"));
+        assert!(closed.allows(&Subject::Anonymous, Action::Read, &zones));
+        assert!(!closed.allows(&Subject::Anonymous, Action::Create, &zones));
+        assert!(open.allows(&Subject::Anonymous, Action::Delete, &flowers));
+        assert!(!open.allows(&Subject::Anonymous, Action::Manage, &Resource::Backups));
+        assert!(!open.allows(&Subject::Anonymous, Action::Delete, &Resource::FlowerPurge));
+        assert!(!open.allows(
+            &Subject::Anonymous,
+            Action::Read,
+            &Resource::SavedSearches(tenant("kiosk"))
+        ));
+        assert!(!open.allows(
+            &Subject::Anonymous,
+            Action::Read,
+            &Resource::RecentlyViewed(tenant("kiosk"))
+        ));
+        assert!(!open.allows(
+            &Subject::Anonymous,
+            Action::Create,
+            &Resource::Orders(tenant("kiosk"))
+        ));
+        assert!(!open.allows(
+            &Subject::Anonymous,
+            Action::Read,
+            &Resource::WebhookEndpoints(tenant("kiosk"))
+        ));
+        assert!(policy_admits_admin_everywhere(&open));
+    }
+
+    fn policy_admits_admin_everywhere(policy: &DefaultPolicy) -> bool {
+        [
+            Resource::FeatureFlags,
+            Resource::Tasks,
+            Resource::Backups,
+            Resource::FlowerPurge,
+        ]
+        .iter()
+        .all(|resource| policy.allows(&Subject::Admin, Action::Manage, resource))
+    }
+}
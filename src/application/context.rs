@@ -0,0 +1,89 @@
+//! Request Context
+//!
+//! Who is calling and on behalf of which tenant. The HTTP layer builds one
+//! per request and makes it current, so use cases and repositories can ask
+//! `RequestContext::current()` instead of each concern keeping its own thread
+//! through every signature. The request ID and language are known before the
+//! caller is, so they stay current on their own: see [`current_request_id`]
+//! and [`Locale::current`](crate::i18n::Locale::current).
+
+use std::future::Future;
+
+use crate::application::authorization::Subject;
+use crate::domain::shared::TenantId;
+
+tokio::task_local! {
+    static CURRENT_CONTEXT: RequestContext;
+    static CURRENT_REQUEST_ID: String;
+}
+
+/// Request ID of the request being handled, if any
+///
+/// Known from the moment the request arrives, before the caller is, so also
+/// outside the scope of the `RequestContext`.
+pub fn current_request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Run a future with `request_id` as the current request ID
+pub async fn with_request_id<F: Future>(request_id: String, future: F) -> F::Output {
+    CURRENT_REQUEST_ID.scope(request_id, future).await
+}
+
+/// What is known about the request being handled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// Authenticated caller
+    pub subject: Subject,
+    /// Tenant the request is scoped to; `None` for routes spanning tenants
+    pub tenant: Option<TenantId>,
+}
+
+impl RequestContext {
+    pub fn new(subject: Subject) -> Self {
+        Self {
+            subject,
+            tenant: None,
+        }
+    }
+
+    pub fn with_tenant(mut self, tenant: TenantId) -> Self {
+        self.tenant = Some(tenant);
+        self
+    }
+
+    /// Context of the request being handled; `None` outside a request, such
+    /// as in jobs and seeding
+    pub fn current() -> Option<Self> {
+        CURRENT_CONTEXT.try_with(Self::clone).ok()
+    }
+
+    /// Run a future with this context as the current one
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT_CONTEXT.scope(self, future).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn is_current_only_within_its_scope() {
+        let tenant = TenantId::new("kiosk").unwrap();
+        let context = RequestContext::new(Subject::Admin).with_tenant(tenant.clone());
+
+        let seen = context
+            .clone()
+            .scope(async { RequestContext::current() })
+            .await;
+
+        assert_eq!(seen, Some(context));
+        assert_eq!(RequestContext::current(), None);
+        assert_eq!(
+            with_request_id("req-2".to_string(), async { current_request_id() }).await,
+            Some("req-2".to_string())
+        );
+        assert_eq!(current_request_id(), None);
+    }
+}
@@ -1,13 +1,30 @@
 //! Data Transfer Objects for API layer
 
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::domain::flower::Flower;
-use crate::domain::shared::Entity;
+use crate::application::html;
+use crate::application::jobs::JobStatus;
+use crate::application::money::MoneyDisplay;
+use crate::application::ports::UsageRecord;
+use crate::domain::delivery::{DeliveryZone, ShippingRate};
+use crate::domain::errors::DomainResult;
+use crate::domain::feature_flag::FeatureFlag;
+use crate::domain::flower::{
+    Flower, FlowerAttributes, FlowerChange, FlowerColor, FlowerError, FlowerMetadata,
+};
+use crate::domain::inventory::StockMovement;
+use crate::domain::order::{Order, OrderEvent, OrderLine, OrderRefund, OrderStatus};
+use crate::domain::pricing::{EffectivePrice, PricingConditions, PricingRule};
+use crate::domain::saved_search::SavedSearch;
+use crate::domain::shared::{Entity, PaginatedResponse};
+use crate::domain::store::Store;
+use crate::domain::task::FailedTask;
 
 /// Response DTO for Flower
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -17,7 +34,16 @@ use crate::domain::shared::Entity;
     "color": "red",
     "description": "A beautiful red rose",
     "price": 25000.0,
+    "price_display": { "currency": "IDR", "symbol": "Rp", "decimals": 0, "formatted": "Rp25,000" },
+    "base_price": 25000.0,
+    "base_price_display": { "currency": "IDR", "symbol": "Rp", "decimals": 0, "formatted": "Rp25,000" },
     "stock": 100,
+    "unit": "stem",
+    "stems_per_unit": 1,
+    "units_in_stock": 100,
+    "sku": "ROSE-RED-01",
+    "attributes": { "fragrance": "high", "stem_length_cm": 60, "vase_life_days": 7 },
+    "metadata": { "erp.item_id": "ITM-00042" },
     "created_at": "2024-12-11T00:00:00Z",
     "updated_at": "2024-12-11T00:00:00Z"
 }))]
@@ -27,17 +53,80 @@ pub struct FlowerResponse {
     /// Flower name
     pub name: String,
     /// Flower color
-    pub color: String,
+    pub color: FlowerColor,
     /// Optional description
     pub description: Option<String>,
-    /// Price in IDR
+    /// Price in IDR per `unit`, after the pricing rules in effect
     pub price: f64,
-    /// Available stock
+    /// How to show `price`
+    pub price_display: MoneyDisplay,
+    /// Price in IDR before pricing rules; equals `price` when none applies
+    pub base_price: f64,
+    /// How to show `base_price`
+    pub base_price_display: MoneyDisplay,
+    /// Pricing rules adjusting `price`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pricing_rules: Vec<Uuid>,
+    /// Available stock, in stems
     pub stock: i32,
+    /// What the flower is sold by: stem, dozen or bundle
+    pub unit: String,
+    /// Stems in one unit
+    pub stems_per_unit: i32,
+    /// Whole units in stock
+    pub units_in_stock: i32,
+    /// Stock keeping unit, if assigned
+    pub sku: Option<String>,
+    /// Attributes only some flowers have, such as fragrance or vase life
+    pub attributes: FlowerAttributes,
+    /// Key-value pairs integrators keep on the flower
+    pub metadata: FlowerMetadata,
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
     /// Last update timestamp
     pub updated_at: DateTime<Utc>,
+    /// Why the flower matched the search; only in search results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<SearchHighlight>,
+}
+
+/// Matches of a search term in a flower, as HTML with each match wrapped in
+/// `<em>` and everything else escaped
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "name": "<em>Rose</em> Garden",
+    "description": "…a classic <em>rose</em> with a soft scent"
+}))]
+pub struct SearchHighlight {
+    /// The name, highlighted
+    pub name: String,
+    /// Part of the description around its first match, if it has any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl FlowerResponse {
+    /// Highlight where `term` occurs in the name and description
+    pub fn highlighted(mut self, term: &str) -> Self {
+        self.highlight = Some(SearchHighlight {
+            name: html::highlight(&self.name, term).unwrap_or_else(|| html::escape(&self.name)),
+            description: self
+                .description
+                .as_deref()
+                .and_then(|description| html::snippet(description, term)),
+        });
+        self
+    }
+
+    /// Serve the price the pricing rules make of the base price
+    pub fn priced(mut self, price: EffectivePrice) -> Self {
+        self.price = price.price();
+        self.price_display = MoneyDisplay::of(self.price);
+        self.base_price = price.base();
+        self.base_price_display = MoneyDisplay::of(self.base_price);
+        self.pricing_rules = price.rules().to_vec();
+        self
+    }
 }
 
 impl From<Flower> for FlowerResponse {
@@ -45,12 +134,23 @@ impl From<Flower> for FlowerResponse {
         Self {
             id: flower.id(),
             name: flower.name().to_string(),
-            color: flower.color().to_string(),
+            color: flower.color(),
             description: flower.description().map(String::from),
             price: flower.price(),
+            price_display: MoneyDisplay::of(flower.price()),
+            base_price: flower.price(),
+            base_price_display: MoneyDisplay::of(flower.price()),
+            pricing_rules: Vec::new(),
             stock: flower.stock(),
+            unit: flower.unit().to_string(),
+            stems_per_unit: flower.unit().stems(),
+            units_in_stock: flower.units_in_stock(),
+            sku: flower.sku().map(String::from),
+            attributes: flower.attributes().clone(),
+            metadata: flower.metadata().clone(),
             created_at: flower.created_at(),
             updated_at: flower.updated_at(),
+            highlight: None,
         }
     }
 }
@@ -62,28 +162,47 @@ impl From<Flower> for FlowerResponse {
     "color": "red",
     "description": "A beautiful red rose",
     "price": 25000.0,
-    "stock": 100
+    "stock": 100,
+    "sku": "ROSE-RED-01",
+    "attributes": { "fragrance": "high", "stem_length_cm": 60 },
+    "metadata": { "erp.item_id": "ITM-00042" }
 }))]
 pub struct CreateFlowerRequest {
-    /// Flower name (max 100 characters)
+    /// Flower name (2-100 characters)
     #[validate(length(min = 2, max = 100))]
     pub name: String,
-    
-    /// Flower color (max 50 characters)
-    #[validate(length(min = 2, max = 50))]
+
+    /// Flower color, one of the values returned by `GET /api/flowers/colors`
     pub color: String,
-    
-    /// Optional description
-    #[validate(length(max = 500))]
+
+    /// Optional description (max 500 characters, counted once escaped, so
+    /// `<` counts as the 4 of `&lt;`; basic formatting tags only)
     pub description: Option<String>,
-    
-    /// Price in IDR
+
+    /// Price in IDR per unit
     #[validate(range(min = 0.0))]
     pub price: f64,
-    
-    /// Initial stock quantity
+
+    /// Initial stock, in stems
     #[validate(range(min = 0))]
     pub stock: i32,
+
+    /// What the flower is sold by: `stem` (default), `dozen` or `bundle`
+    pub unit: Option<String>,
+
+    /// Stems in one unit; required for bundles (2 to 1000)
+    pub stems_per_unit: Option<i32>,
+
+    /// Optional stock keeping unit, unique within the tenant (max 32
+    /// characters: letters, digits, `-`, `_`, `.`, `/`)
+    pub sku: Option<String>,
+
+    /// Optional attributes, such as fragrance or vase life
+    pub attributes: Option<FlowerAttributes>,
+
+    /// Optional key-value pairs for integrators, keyed `namespace.name`
+    /// (max 32 entries, values up to 256 characters)
+    pub metadata: Option<BTreeMap<String, String>>,
 }
 
 /// Request DTO for updating an existing Flower
@@ -97,106 +216,1961 @@ pub struct UpdateFlowerRequest {
     /// New flower name
     #[validate(length(min = 2, max = 100))]
     pub name: Option<String>,
-    
-    /// New flower color
-    #[validate(length(min = 2, max = 50))]
+
+    /// New flower color, one of the values returned by `GET /api/flowers/colors`
     pub color: Option<String>,
-    
-    /// New description
-    #[validate(length(max = 500))]
+
+    /// New description (an empty string clears it)
     pub description: Option<String>,
-    
-    /// New price
+
+    /// New price per unit
     #[validate(range(min = 0.0))]
     pub price: Option<f64>,
-    
-    /// New stock quantity
+
+    /// New stock, in stems; the difference is recorded in the inventory
+    /// ledger. Stock reserved by orders is already taken off, so prefer
+    /// `/stock-adjustments` for deliveries and losses.
     #[validate(range(min = 0))]
     pub stock: Option<i32>,
+
+    /// New unit the flower is sold by: `stem`, `dozen` or `bundle`
+    pub unit: Option<String>,
+
+    /// New number of stems in a bundle
+    pub stems_per_unit: Option<i32>,
+
+    /// New stock keeping unit (an empty string clears it)
+    pub sku: Option<String>,
+
+    /// New attributes, replacing all current ones (`{}` clears them)
+    pub attributes: Option<FlowerAttributes>,
+
+    /// New metadata, replacing all current entries (`{}` clears them)
+    pub metadata: Option<BTreeMap<String, String>>,
 }
 
-/// Query parameters for listing flowers
-#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
-pub struct ListFlowersQuery {
-    /// Page number (default: 1)
-    #[param(minimum = 1, default = 1)]
-    pub page: Option<i64>,
-    /// Items per page (default: 10)
-    #[param(minimum = 1, maximum = 100, default = 10)]
-    pub per_page: Option<i64>,
-    /// Search by flower name
-    pub search: Option<String>,
-    /// Filter by color
+/// What the flowers of the catalog can currently be filtered by
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "colors": ["red", "white", "pink"],
+    "min_price": 15000.0,
+    "max_price": 120000.0
+}))]
+pub struct FlowerFiltersResponse {
+    /// Colors at least one flower has, in the order of `/api/flowers/colors`
+    pub colors: Vec<FlowerColor>,
+    /// Lowest price in the catalog; `null` when it is empty
+    pub min_price: Option<f64>,
+    /// Highest price in the catalog; `null` when it is empty
+    pub max_price: Option<f64>,
+}
+
+/// Flower ranked by its recent views
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TrendingFlowerResponse {
+    #[serde(flatten)]
+    pub flower: FlowerResponse,
+    /// Views within the requested window
+    pub views: i64,
+}
+
+/// Flower the caller viewed, with when they last did
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RecentlyViewedFlowerResponse {
+    #[serde(flatten)]
+    pub flower: FlowerResponse,
+    pub viewed_at: DateTime<Utc>,
+}
+
+/// Query parameters for trending flowers
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+pub struct TrendingQuery {
+    /// Days to rank over, such as `7d` (default: 7d)
+    #[param(example = "7d")]
+    pub window: Option<String>,
+    /// Number of flowers to return (default: 10)
+    #[param(minimum = 1, maximum = 50, default = 10)]
+    pub limit: Option<i64>,
+}
+
+/// Request DTO for adjusting a flower's stock
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "delta": -3,
+    "reason": "Damaged in transit"
+}))]
+pub struct StockAdjustmentRequest {
+    /// Change in stock: positive to add, negative to remove
+    pub delta: i32,
+
+    /// Why the stock changed (max 200 characters)
+    pub reason: String,
+}
+
+/// Response DTO for a stock movement recorded in the inventory ledger
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "id": "01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a60",
+    "flower_id": "550e8400-e29b-41d4-a716-446655440001",
+    "delta": -3,
+    "reason": "Damaged in transit",
+    "stock_after": 97,
+    "created_at": "2024-12-20T00:00:00Z"
+}))]
+pub struct StockMovementResponse {
+    /// Movement identifier
+    pub id: Uuid,
+    /// Flower whose stock changed
+    pub flower_id: Uuid,
+    /// Change in stock
+    pub delta: i32,
+    /// Why the stock changed
+    pub reason: String,
+    /// Stock after the movement
+    pub stock_after: i32,
+    /// When the movement was recorded
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<StockMovement> for StockMovementResponse {
+    fn from(movement: StockMovement) -> Self {
+        Self {
+            id: movement.id(),
+            flower_id: movement.flower_id(),
+            delta: movement.delta(),
+            reason: movement.reason().to_string(),
+            stock_after: movement.stock_after(),
+            created_at: movement.created_at(),
+        }
+    }
+}
+
+/// Response DTO for one field changed by an update of a flower
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "id": "01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a61",
+    "field": "price",
+    "old_value": 25000.0,
+    "new_value": 30000.0,
+    "actor": "tenant:rose-shop",
+    "changed_at": "2024-12-24T00:00:00Z"
+}))]
+pub struct FlowerChangeResponse {
+    /// Change identifier
+    pub id: Uuid,
+    /// Field that changed
+    pub field: String,
+    /// Value before the update; `null` when the field was unset
+    pub old_value: serde_json::Value,
+    /// Value after the update; `null` when the field was cleared
+    pub new_value: serde_json::Value,
+    /// Who made the update: `admin`, `tenant:<id>`, `anonymous`, or
+    /// `system` for background work
+    pub actor: String,
+    /// When the update was made
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<FlowerChange> for FlowerChangeResponse {
+    fn from(change: FlowerChange) -> Self {
+        Self {
+            id: change.id(),
+            field: change.field().to_string(),
+            old_value: change.old_value().clone(),
+            new_value: change.new_value().clone(),
+            actor: change.actor().to_string(),
+            changed_at: change.changed_at(),
+        }
+    }
+}
+
+/// Request DTO for saving a search
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "name": "Affordable red roses",
+    "query": "rose",
+    "color": "red",
+    "max_price": 30000.0,
+    "alert_email": "florist@example.com"
+}))]
+pub struct SaveSearchRequest {
+    /// Name to recognize the search by (max 100 characters)
+    pub name: String,
+    /// Part of the flower name, as in `GET /api/flowers?search=`
+    pub query: Option<String>,
+    /// Flower color, one of the values returned by `GET /api/flowers/colors`
     pub color: Option<String>,
+    /// Lowest price in IDR
+    pub min_price: Option<f64>,
+    /// Highest price in IDR
+    pub max_price: Option<f64>,
+    /// Address emailed when new flowers match; leave out for no alerts
+    pub alert_email: Option<String>,
 }
 
-/// Generic API response wrapper
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ApiResponse<T> {
-    /// Indicates if the request was successful
-    pub success: bool,
-    /// Response data
-    pub data: T,
-    /// Optional message
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+/// Response DTO for a saved search
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "id": "01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a62",
+    "name": "Affordable red roses",
+    "query": "rose",
+    "color": "red",
+    "min_price": null,
+    "max_price": 30000.0,
+    "alert_email": "florist@example.com",
+    "created_at": "2024-12-25T00:00:00Z"
+}))]
+pub struct SavedSearchResponse {
+    /// Saved search identifier
+    pub id: Uuid,
+    pub name: String,
+    pub query: Option<String>,
+    pub color: Option<FlowerColor>,
+    pub min_price: Option<f64>,
+    pub max_price: Option<f64>,
+    /// Address alerted about new matches; `null` when alerts are off
+    pub alert_email: Option<String>,
+    pub created_at: DateTime<Utc>,
 }
 
-impl<T> ApiResponse<T> {
-    pub fn success(data: T) -> Self {
+impl From<SavedSearch> for SavedSearchResponse {
+    fn from(search: SavedSearch) -> Self {
+        let criteria = search.criteria();
         Self {
-            success: true,
-            data,
-            message: None,
+            id: search.id(),
+            name: search.name().to_string(),
+            query: criteria.query.clone(),
+            color: criteria.color,
+            min_price: criteria.min_price.map(|price| price.value()),
+            max_price: criteria.max_price.map(|price| price.value()),
+            alert_email: search.alert_email().map(str::to_string),
+            created_at: search.created_at(),
         }
     }
+}
 
-    pub fn with_message(data: T, message: impl Into<String>) -> Self {
+/// Request DTO for opening a store
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "name": "Menteng",
+    "address": "Jl. Cikini Raya 1, Jakarta Pusat",
+    "latitude": -6.1944,
+    "longitude": 106.8411
+}))]
+pub struct CreateStoreRequest {
+    /// Store name (max 100 characters)
+    pub name: String,
+    /// Street address shown to customers (max 500 characters)
+    pub address: String,
+    /// WGS 84 latitude in degrees
+    pub latitude: f64,
+    /// WGS 84 longitude in degrees
+    pub longitude: f64,
+}
+
+/// Response DTO for a store
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "id": "01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a62",
+    "name": "Menteng",
+    "address": "Jl. Cikini Raya 1, Jakarta Pusat",
+    "latitude": -6.1944,
+    "longitude": 106.8411,
+    "created_at": "2024-12-27T00:00:00Z"
+}))]
+pub struct StoreResponse {
+    /// Store identifier
+    pub id: Uuid,
+    pub name: String,
+    pub address: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Store> for StoreResponse {
+    fn from(store: Store) -> Self {
         Self {
-            success: true,
-            data,
-            message: Some(message.into()),
+            id: store.id(),
+            name: store.name().to_string(),
+            address: store.address().to_string(),
+            latitude: store.location().latitude(),
+            longitude: store.location().longitude(),
+            created_at: store.created_at(),
         }
     }
 }
 
-/// API Response for single flower
+/// Request DTO for setting how many of a flower a store holds
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
-pub struct ApiResponseFlower {
-    pub success: bool,
-    pub data: FlowerResponse,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+#[schema(example = json!({ "quantity": 12 }))]
+pub struct SetStoreStockRequest {
+    /// Units of the flower's stock held by the store; 0 to hold none
+    pub quantity: i32,
 }
 
-/// Paginated flower response for OpenAPI schema
+/// Response DTO for the stock a store holds of a flower
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
-pub struct PaginatedFlowerResponse {
-    pub data: Vec<FlowerResponse>,
-    pub total: i64,
-    pub page: i64,
-    pub per_page: i64,
-    pub total_pages: i64,
+pub struct StoreStockResponse {
+    pub store_id: Uuid,
+    pub flower_id: Uuid,
+    pub quantity: i32,
 }
 
-/// API Response for paginated flowers
+/// Store holding a flower, with its distance from the customer
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
-pub struct ApiResponsePaginatedFlower {
-    pub success: bool,
-    pub data: PaginatedFlowerResponse,
+pub struct StoreAvailabilityResponse {
+    #[serde(flatten)]
+    pub store: StoreResponse,
+    /// Units available for pickup
+    pub quantity: i32,
+    /// Great-circle distance from the given location, in kilometres
+    pub distance_km: f64,
+}
+
+/// Query parameters for flower availability
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+pub struct AvailabilityQuery {
+    /// Latitude of the customer, in degrees
+    #[param(example = -6.2)]
+    pub lat: Option<f64>,
+    /// Longitude of the customer, in degrees
+    #[param(example = 106.8)]
+    pub lng: Option<f64>,
+    /// Number of stores to return (default: 5)
+    #[param(minimum = 1, maximum = 50, default = 5)]
+    pub limit: Option<i64>,
+}
+
+/// Point on the map, in WGS 84 degrees
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Request DTO for defining a delivery zone
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "name": "Central Jakarta",
+    "boundary": [
+        { "latitude": -6.15, "longitude": 106.80 },
+        { "latitude": -6.15, "longitude": 106.87 },
+        { "latitude": -6.22, "longitude": 106.87 },
+        { "latitude": -6.22, "longitude": 106.80 }
+    ],
+    "fee": 15000.0
+}))]
+pub struct CreateDeliveryZoneRequest {
+    /// Zone name (max 100 characters)
+    pub name: String,
+    /// Corners of the zone in order (3 to 1000); repeating the first corner
+    /// at the end is optional
+    pub boundary: Vec<Coordinates>,
+    /// Delivery fee in IDR
+    pub fee: f64,
+}
+
+/// Response DTO for a delivery zone
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DeliveryZoneResponse {
+    /// Delivery zone identifier
+    pub id: Uuid,
+    pub name: String,
+    pub boundary: Vec<Coordinates>,
+    /// Delivery fee in IDR
+    pub fee: f64,
+    /// How to show `fee`
+    pub fee_display: MoneyDisplay,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<DeliveryZone> for DeliveryZoneResponse {
+    fn from(zone: DeliveryZone) -> Self {
+        Self {
+            id: zone.id(),
+            name: zone.name().to_string(),
+            boundary: zone
+                .boundary()
+                .iter()
+                .map(|point| Coordinates {
+                    latitude: point.latitude(),
+                    longitude: point.longitude(),
+                })
+                .collect(),
+            fee: zone.fee(),
+            fee_display: MoneyDisplay::of(zone.fee()),
+            created_at: zone.created_at(),
+        }
+    }
+}
+
+/// Query parameters for checking a delivery address
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+pub struct DeliveryCheckQuery {
+    /// Latitude of the delivery address, in degrees
+    #[param(example = -6.2)]
+    pub lat: Option<f64>,
+    /// Longitude of the delivery address, in degrees
+    #[param(example = 106.83)]
+    pub lng: Option<f64>,
+}
+
+/// Response DTO for a delivery address check
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "serviceable": true,
+    "zone_id": "01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a62",
+    "zone_name": "Central Jakarta",
+    "fee": 15000.0,
+    "fee_display": { "currency": "IDR", "symbol": "Rp", "decimals": 0, "formatted": "Rp15,000" }
+}))]
+pub struct DeliveryCheckResponse {
+    /// Whether any delivery zone covers the address
+    pub serviceable: bool,
+    /// Zone the delivery is charged by: the cheapest covering the address
+    pub zone_id: Option<Uuid>,
+    pub zone_name: Option<String>,
+    /// Delivery fee in IDR
+    pub fee: Option<f64>,
+    /// How to show `fee`
+    pub fee_display: Option<MoneyDisplay>,
+}
+
+/// Query parameters for quoting a delivery
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+pub struct ShippingQuoteQuery {
+    /// Latitude of the delivery address, in degrees
+    #[param(example = -6.9)]
+    pub lat: Option<f64>,
+    /// Longitude of the delivery address, in degrees
+    #[param(example = 107.6)]
+    pub lng: Option<f64>,
+    /// Weight of the parcel in grams (default: 1000)
+    #[param(minimum = 1, maximum = 50000, default = 1000)]
+    pub weight_grams: Option<u32>,
+}
+
+/// Response DTO for a shipping rate
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "carrier": "jne",
+    "service": "REG",
+    "fee": 18000.0,
+    "fee_display": { "currency": "IDR", "symbol": "Rp", "decimals": 0, "formatted": "Rp18,000" },
+    "estimated_days": 2
+}))]
+pub struct ShippingRateResponse {
+    /// Who delivers; `shop` for the shop's own deliveries
+    pub carrier: String,
+    /// Service of the carrier
+    pub service: String,
+    /// Fee in IDR
+    pub fee: f64,
+    /// How to show `fee`
+    pub fee_display: MoneyDisplay,
+    /// Days until delivery, when the carrier says
+    pub estimated_days: Option<u32>,
+}
+
+impl From<ShippingRate> for ShippingRateResponse {
+    fn from(rate: ShippingRate) -> Self {
+        Self {
+            carrier: rate.carrier().to_string(),
+            service: rate.service().to_string(),
+            fee: rate.fee(),
+            fee_display: MoneyDisplay::of(rate.fee()),
+            estimated_days: rate.estimated_days(),
+        }
+    }
+}
+
+/// Flower and quantity to order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderLineRequest {
+    pub flower_id: Uuid,
+    /// Number of units the flower is sold by, such as stems or dozens; at
+    /// least 1
+    pub quantity: i32,
+}
+
+/// Where and how to deliver an order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderShippingRequest {
+    pub destination: Coordinates,
+    /// Carrier of one of the rates quoted by `GET /api/shipping/rates`
+    pub carrier: String,
+    /// Service of that carrier
+    pub service: String,
+    /// Parcel weight in grams the rate was quoted for; defaults to 1000
+    pub weight_grams: Option<u32>,
+}
+
+/// Request DTO for placing an order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "lines": [{ "flower_id": "550e8400-e29b-41d4-a716-446655440001", "quantity": 12 }],
+    "shipping": {
+        "destination": { "latitude": -6.2, "longitude": 106.83 },
+        "carrier": "shop",
+        "service": "standard"
+    }
+}))]
+pub struct CreateOrderRequest {
+    /// Flowers to buy (1 to 50, each flower at most once)
+    pub lines: Vec<OrderLineRequest>,
+    /// Delivery; omit for orders picked up at the shop
+    pub shipping: Option<OrderShippingRequest>,
+}
+
+/// Request DTO for moving an order along fulfilment
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({ "status": "paid" }))]
+pub struct UpdateOrderStatusRequest {
+    /// Next status: paid, packed, shipped or delivered
+    pub status: String,
+}
+
+/// What the payment provider reports about an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum PaymentEventType {
+    #[serde(rename = "payment.succeeded")]
+    Succeeded,
+    #[serde(rename = "payment.failed")]
+    Failed,
+    /// Any other event, acknowledged and ignored
+    #[serde(other)]
+    Other,
+}
+
+/// Callback of the payment provider about the payment of an order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "type": "payment.succeeded",
+    "tenant_id": "kiosk",
+    "order_id": "0190f5a2-6c1e-7b3a-9f4d-2a8e5c7b1d30",
+    "reference": "pay_8f2k1m"
+}))]
+pub struct PaymentEventRequest {
+    #[serde(rename = "type")]
+    pub event_type: PaymentEventType,
+    pub tenant_id: String,
+    pub order_id: Uuid,
+    /// The provider's reference for the payment
+    pub reference: String,
+}
+
+/// Request DTO for registering a webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({ "url": "https://shop.example.com/hooks/flowers" }))]
+pub struct CreateWebhookEndpointRequest {
+    /// Where events are POSTed; http or https (max 2048 characters)
+    pub url: String,
+}
+
+/// Response DTO for a webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    pub id: Uuid,
+    pub url: String,
+    /// Secret deliveries are signed with; only returned when the endpoint is
+    /// registered and when its secret is rotated
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+    pub secret: Option<String>,
+    /// Until when deliveries are signed with the previous secret too;
+    /// absent when there is none
+    pub previous_secret_expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
 }
 
-/// Error response
+/// Response DTO for a test delivery to a webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookTestResponse {
+    /// Id the delivery was sent with, in `X-Webhook-Id`
+    pub delivery_id: String,
+    /// Whether the receiver answered with a 2xx status; why it did not is
+    /// only in the server log
+    pub delivered: bool,
+}
+
+/// Response DTO for a line of an order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderLineResponse {
+    pub flower_id: Uuid,
+    /// Flower name when the order was placed
+    pub name: String,
+    /// Price per unit in IDR when the order was placed
+    pub unit_price: f64,
+    /// How to show `unit_price`
+    pub unit_price_display: MoneyDisplay,
+    pub quantity: i32,
+    /// What the flower was sold by: stem, dozen or bundle
+    pub unit: String,
+    /// Stems the line took from stock
+    pub stems: i32,
+    /// Price of the line in IDR
+    pub amount: f64,
+    /// How to show `amount`
+    pub amount_display: MoneyDisplay,
+}
+
+impl From<&OrderLine> for OrderLineResponse {
+    fn from(line: &OrderLine) -> Self {
+        Self {
+            flower_id: line.flower_id(),
+            name: line.name().to_string(),
+            unit_price: line.unit_price(),
+            unit_price_display: MoneyDisplay::of(line.unit_price()),
+            quantity: line.quantity(),
+            unit: line.unit().to_string(),
+            stems: line.stems(),
+            amount: line.amount(),
+            amount_display: MoneyDisplay::of(line.amount()),
+        }
+    }
+}
+
+/// Response DTO for the refund of a cancelled order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderRefundResponse {
+    /// Given back to the customer in IDR
+    pub amount: f64,
+    /// How to show `amount`
+    pub amount_display: MoneyDisplay,
+    /// Kept for putting the flowers back in stock, in IDR
+    pub restocking_fee: f64,
+    /// How to show `restocking_fee`
+    pub restocking_fee_display: MoneyDisplay,
+    /// Payment gateway's reference for the refund
+    pub reference: String,
+}
+
+impl From<&OrderRefund> for OrderRefundResponse {
+    fn from(refund: &OrderRefund) -> Self {
+        Self {
+            amount: refund.amount(),
+            amount_display: MoneyDisplay::of(refund.amount()),
+            restocking_fee: refund.restocking_fee(),
+            restocking_fee_display: MoneyDisplay::of(refund.restocking_fee()),
+            reference: refund.reference().to_string(),
+        }
+    }
+}
+
+/// Response DTO for an order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderResponse {
+    /// Order identifier
+    pub id: Uuid,
+    pub status: OrderStatus,
+    pub lines: Vec<OrderLineResponse>,
+    /// Delivery chosen at checkout; absent for pickups
+    pub shipping: Option<ShippingRateResponse>,
+    /// Price of the flowers in IDR
+    pub subtotal: f64,
+    /// How to show `subtotal`
+    pub subtotal_display: MoneyDisplay,
+    /// Flowers and shipping in IDR
+    pub total: f64,
+    /// How to show `total`
+    pub total_display: MoneyDisplay,
+    pub placed_at: DateTime<Utc>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub packed_at: Option<DateTime<Utc>>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    /// Present once a paid order was cancelled and refunded
+    pub refund: Option<OrderRefundResponse>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Order> for OrderResponse {
+    fn from(order: Order) -> Self {
+        Self {
+            id: order.id(),
+            status: order.status(),
+            lines: order.lines().iter().map(OrderLineResponse::from).collect(),
+            shipping: order.shipping().cloned().map(ShippingRateResponse::from),
+            subtotal: order.subtotal(),
+            subtotal_display: MoneyDisplay::of(order.subtotal()),
+            total: order.total(),
+            total_display: MoneyDisplay::of(order.total()),
+            placed_at: order.placed_at(),
+            paid_at: order.paid_at(),
+            packed_at: order.packed_at(),
+            shipped_at: order.shipped_at(),
+            delivered_at: order.delivered_at(),
+            cancelled_at: order.cancelled_at(),
+            refund: order.refund().map(OrderRefundResponse::from),
+            updated_at: order.updated_at(),
+        }
+    }
+}
+
+/// Response DTO for a status change of an order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderEventResponse {
+    pub id: Uuid,
+    /// Previous status; absent for the order being placed
+    pub from: Option<OrderStatus>,
+    pub to: OrderStatus,
+    /// Who made the change: `admin`, `tenant:<id>` or `system`
+    pub actor: String,
+    pub occurred_at: DateTime<Utc>,
+    /// Amount refunded to the customer, in IDR, for events recording a
+    /// refund rather than a change of status
+    pub refunded: Option<f64>,
+}
+
+impl From<OrderEvent> for OrderEventResponse {
+    fn from(event: OrderEvent) -> Self {
+        Self {
+            id: event.id(),
+            from: event.from(),
+            to: event.to(),
+            actor: event.actor().to_string(),
+            occurred_at: event.occurred_at(),
+            refunded: event.refunded(),
+        }
+    }
+}
+
+/// Request DTO for defining a pricing rule, or replacing one
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[schema(example = json!({
-    "success": false,
-    "error": "Flower not found with id: 550e8400-e29b-41d4-a716-446655440001"
+    "name": "Valentine's week",
+    "adjustment_percent": 25.0,
+    "starts_at": "2025-02-08T00:00:00+07:00",
+    "ends_at": "2025-02-15T00:00:00+07:00"
 }))]
-pub struct ErrorResponse {
-    /// Always false for errors
-    pub success: bool,
-    /// Error message
-    pub error: String,
+pub struct PricingRuleRequest {
+    /// Rule name (max 100 characters)
+    pub name: String,
+    /// Percent added to the base price, from -90 to 500; negative for a
+    /// discount
+    pub adjustment_percent: f64,
+    /// Only for flowers with at most this much stock left
+    pub max_stock: Option<i32>,
+    /// Only from this moment on
+    pub starts_at: Option<DateTime<Utc>>,
+    /// Only until this moment, excluded
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Whether the rule is in use; defaults to true
+    pub enabled: Option<bool>,
+}
+
+impl PricingRuleRequest {
+    pub fn conditions(&self) -> PricingConditions {
+        PricingConditions {
+            max_stock: self.max_stock,
+            starts_at: self.starts_at,
+            ends_at: self.ends_at,
+        }
+    }
+}
+
+/// Response DTO for a pricing rule
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PricingRuleResponse {
+    /// Pricing rule identifier
+    pub id: Uuid,
+    pub name: String,
+    /// Percent added to the base price; negative for a discount
+    pub adjustment_percent: f64,
+    pub max_stock: Option<i32>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<PricingRule> for PricingRuleResponse {
+    fn from(rule: PricingRule) -> Self {
+        let conditions = rule.conditions();
+        Self {
+            id: rule.id(),
+            name: rule.name().to_string(),
+            adjustment_percent: rule.adjustment_percent(),
+            max_stock: conditions.max_stock,
+            starts_at: conditions.starts_at,
+            ends_at: conditions.ends_at,
+            enabled: rule.enabled(),
+            created_at: rule.created_at(),
+            updated_at: rule.updated_at(),
+        }
+    }
+}
+
+/// Entry of the inventory ledger, with the tenant it belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LedgerEntryResponse {
+    /// Tenant of the flower
+    pub tenant_id: String,
+    #[serde(flatten)]
+    pub movement: StockMovementResponse,
+}
+
+impl From<StockMovement> for LedgerEntryResponse {
+    fn from(movement: StockMovement) -> Self {
+        Self {
+            tenant_id: movement.tenant_id().as_str().to_string(),
+            movement: movement.into(),
+        }
+    }
+}
+
+/// Query parameters for auditing the inventory ledger
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+pub struct LedgerQueryParams {
+    /// Page number (default: 1)
+    #[param(minimum = 1, maximum = 1000000, default = 1)]
+    pub page: Option<i64>,
+    /// Items per page (default: 10)
+    #[param(minimum = 1, maximum = 100, default = 10)]
+    pub per_page: Option<i64>,
+    /// Only movements of this tenant
+    pub tenant: Option<String>,
+    /// Only movements of this flower
+    pub flower_id: Option<Uuid>,
+}
+
+/// What a flower purge removed
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "flower_id": "550e8400-e29b-41d4-a716-446655440001",
+    "flower_deleted": true,
+    "stock_movements": 12,
+    "flower_changes": 4,
+    "view_days": 30
+}))]
+pub struct FlowerPurgeResponse {
+    pub flower_id: Uuid,
+    /// Whether the flower itself still existed
+    pub flower_deleted: bool,
+    /// Ledger entries erased
+    pub stock_movements: u64,
+    /// Entries of the flower's change history erased
+    pub flower_changes: u64,
+    /// Days of view totals erased
+    pub view_days: u64,
+}
+
+/// Request DTO for purging the cache, by the scope of what to drop
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "scope", rename_all = "lowercase")]
+#[schema(example = json!({
+    "scope": "flower",
+    "tenant": "default",
+    "flower_id": "550e8400-e29b-41d4-a716-446655440001"
+}))]
+pub enum CachePurgeRequest {
+    /// A flower's cached copy and every cached listing of its tenant
+    Flower { tenant: String, flower_id: Uuid },
+    /// Every entry whose key starts with `prefix`, such as
+    /// `flowers:default:`
+    Prefix { prefix: String },
+    /// The whole cache
+    All,
+}
+
+/// What a cache purge removed
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "cache_enabled": true,
+    "entries_removed": 3
+}))]
+pub struct CachePurgeResponse {
+    /// Whether a cache is configured at all; without one there is nothing
+    /// to purge
+    pub cache_enabled: bool,
+    pub entries_removed: u64,
+}
+
+/// Response DTO for a scheduled job of the answering instance
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "name": "flush_views",
+    "schedule": "@every 60s",
+    "exclusive": false,
+    "running": false,
+    "next_run_at": "2024-12-17T00:01:00Z",
+    "last_started_at": "2024-12-17T00:00:00Z",
+    "last_duration_ms": 12,
+    "last_error": null,
+    "runs": 42,
+    "failures": 0,
+    "skipped": 0
+}))]
+pub struct JobResponse {
+    pub name: String,
+    /// `@every` interval or cron expression
+    pub schedule: String,
+    /// Whether only one replica runs the job per tick
+    pub exclusive: bool,
+    /// Whether a run is in progress
+    pub running: bool,
+    /// When the next run is due, unless one is in progress
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_started_at: Option<DateTime<Utc>>,
+    pub last_duration_ms: Option<u64>,
+    /// Error of the last run, if it failed
+    pub last_error: Option<String>,
+    /// Completed runs, failed ones included
+    pub runs: u64,
+    pub failures: u64,
+    /// Runs left to another replica holding the job's lock
+    pub skipped: u64,
+}
+
+impl From<JobStatus> for JobResponse {
+    fn from(status: JobStatus) -> Self {
+        Self {
+            name: status.name.to_string(),
+            schedule: status.schedule,
+            exclusive: status.exclusive,
+            running: status.running,
+            next_run_at: status.next_run_at,
+            last_started_at: status.last_started_at,
+            last_duration_ms: status
+                .last_duration
+                .map(|duration| duration.as_millis() as u64),
+            last_error: status.last_error,
+            runs: status.runs,
+            failures: status.failures,
+            skipped: status.skipped,
+        }
+    }
+}
+
+/// Query parameters for API usage
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+pub struct UsageQueryParams {
+    /// Only usage of this tenant
+    pub tenant: Option<String>,
+    /// Start of the range, rounded down to the hour (default: a day before `to`)
+    pub from: Option<DateTime<Utc>>,
+    /// End of the range, exclusive (default: now)
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// API usage over a range of hours
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "from": "2024-12-17T00:00:00Z",
+    "to": "2024-12-18T00:00:00Z",
+    "totals": [{
+        "tenant_id": "kiosk",
+        "api_key": "****3f9a",
+        "requests": 1520,
+        "errors": 12,
+        "request_bytes": 48200,
+        "response_bytes": 9120400
+    }],
+    "hours": [{
+        "hour": "2024-12-17T09:00:00Z",
+        "tenant_id": "kiosk",
+        "api_key": "****3f9a",
+        "requests": 310,
+        "errors": 2,
+        "request_bytes": 9800,
+        "response_bytes": 1860200
+    }]
+}))]
+pub struct UsageReportResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Usage over the whole range, per tenant and API key
+    pub totals: Vec<UsageTotalResponse>,
+    /// Usage per hour, tenant and API key, oldest hour first
+    pub hours: Vec<UsageHourResponse>,
+}
+
+/// API usage of one tenant through one API key over a report's range
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UsageTotalResponse {
+    pub tenant_id: String,
+    /// Fingerprint of the API key, telling apart keys ending alike; null for
+    /// requests without one
+    pub key_id: Option<String>,
+    /// Last characters of the API key; null for requests without one
+    pub api_key: Option<String>,
+    pub requests: i64,
+    /// Requests answered with a 4xx or 5xx status
+    pub errors: i64,
+    /// Request body bytes received
+    pub request_bytes: i64,
+    /// Response body bytes sent, before compression
+    pub response_bytes: i64,
+}
+
+impl UsageTotalResponse {
+    /// Add the usage of `record`, which has the same tenant and key
+    pub fn add(&mut self, record: &UsageRecord) {
+        self.requests += record.requests;
+        self.errors += record.errors;
+        self.request_bytes += record.request_bytes;
+        self.response_bytes += record.response_bytes;
+    }
+}
+
+impl From<&UsageRecord> for UsageTotalResponse {
+    fn from(record: &UsageRecord) -> Self {
+        Self {
+            tenant_id: record.tenant.as_str().to_string(),
+            key_id: record.key_id.clone(),
+            api_key: record.api_key.clone(),
+            requests: record.requests,
+            errors: record.errors,
+            request_bytes: record.request_bytes,
+            response_bytes: record.response_bytes,
+        }
+    }
+}
+
+/// API usage of one tenant through one API key during one hour
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UsageHourResponse {
+    /// Start of the hour
+    pub hour: DateTime<Utc>,
+    pub tenant_id: String,
+    /// Fingerprint of the API key; null for requests without one
+    pub key_id: Option<String>,
+    /// Last characters of the API key; null for requests without one
+    pub api_key: Option<String>,
+    pub requests: i64,
+    /// Requests answered with a 4xx or 5xx status
+    pub errors: i64,
+    pub request_bytes: i64,
+    pub response_bytes: i64,
+}
+
+impl From<UsageRecord> for UsageHourResponse {
+    fn from(record: UsageRecord) -> Self {
+        Self {
+            hour: record.hour,
+            tenant_id: record.tenant.as_str().to_string(),
+            key_id: record.key_id,
+            api_key: record.api_key,
+            requests: record.requests,
+            errors: record.errors,
+            request_bytes: record.request_bytes,
+            response_bytes: record.response_bytes,
+        }
+    }
+}
+
+/// Monthly request quota of an API key and how much of it is used
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "tenant_id": "kiosk",
+    "key_id": "5c1f0e7a9b2d4c8e6f3a1b0d9e8c7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0e",
+    "api_key": "****3f9a",
+    "monthly_requests": 100000,
+    "custom": true,
+    "used": 41250,
+    "remaining": 58750,
+    "resets_at": "2025-01-01T00:00:00Z"
+}))]
+pub struct QuotaResponse {
+    pub tenant_id: String,
+    /// Fingerprint of the API key, telling apart keys ending alike
+    pub key_id: String,
+    /// Last characters of the API key
+    pub api_key: String,
+    /// Requests allowed per calendar month (UTC); null for no limit
+    pub monthly_requests: Option<i64>,
+    /// Whether an admin set this key's quota, rather than it being
+    /// API_KEY_MONTHLY_QUOTA
+    pub custom: bool,
+    /// Requests made this month
+    pub used: i64,
+    /// Requests left this month; null for no limit
+    pub remaining: Option<i64>,
+    /// When the month's count starts over
+    pub resets_at: DateTime<Utc>,
+}
+
+/// Request DTO for setting the monthly quota of an API key
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "api_key": "kiosk-3f9a",
+    "monthly_requests": 100000
+}))]
+pub struct SetQuotaRequest {
+    /// The whole key, as in TENANT_API_KEYS
+    pub api_key: String,
+    /// Requests allowed per calendar month; null to apply
+    /// API_KEY_MONTHLY_QUOTA again
+    pub monthly_requests: Option<i64>,
+}
+
+/// Flowers selected by a batch price adjustment; every given criterion must match
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct PriceAdjustmentFilter {
+    /// Only flowers of this color
+    pub color: Option<String>,
+    /// Only these flowers
+    pub ids: Option<Vec<Uuid>>,
+}
+
+/// Request DTO for adjusting the prices of several flowers at once
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "filter": {"color": "red"},
+    "adjustment": "+10%",
+    "dry_run": true
+}))]
+pub struct PriceAdjustmentRequest {
+    /// Flowers to reprice; at least a color or IDs are required
+    pub filter: PriceAdjustmentFilter,
+
+    /// Percentage (`+10%`, `-15%`) or amount in IDR (`-5000`) to apply
+    pub adjustment: String,
+
+    /// Return the new prices without changing anything
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Price change of a single flower
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PriceChangeResponse {
+    /// Flower identifier
+    pub id: Uuid,
+    /// Flower name
+    pub name: String,
+    /// Price before the adjustment
+    pub old_price: f64,
+    /// How to show `old_price`
+    pub old_price_display: MoneyDisplay,
+    /// Price after the adjustment
+    pub new_price: f64,
+    /// How to show `new_price`
+    pub new_price_display: MoneyDisplay,
+}
+
+/// Response DTO for a batch price adjustment
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "dry_run": true,
+    "items": [{
+        "id": "550e8400-e29b-41d4-a716-446655440001",
+        "name": "Rose",
+        "old_price": 25000.0,
+        "old_price_display": { "currency": "IDR", "symbol": "Rp", "decimals": 0, "formatted": "Rp25,000" },
+        "new_price": 27500.0,
+        "new_price_display": { "currency": "IDR", "symbol": "Rp", "decimals": 0, "formatted": "Rp27,500" }
+    }]
+}))]
+pub struct PriceAdjustmentResponse {
+    /// Whether this was only a preview
+    pub dry_run: bool,
+    /// Affected flowers
+    pub items: Vec<PriceChangeResponse>,
+}
+
+/// Query parameters for listing flowers
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+pub struct ListFlowersQuery {
+    /// Page number (default: 1)
+    #[param(minimum = 1, maximum = 1000000, default = 1)]
+    pub page: Option<i64>,
+    /// Items per page (default: 10)
+    #[param(minimum = 1, maximum = 100, default = 10)]
+    pub per_page: Option<i64>,
+    /// Search by flower name; results then say where they matched in
+    /// `highlight`
+    pub search: Option<String>,
+    /// Filter by color
+    pub color: Option<String>,
+    /// Filter by fragrance: none, low, medium or high
+    #[serde(rename = "attr.fragrance")]
+    pub fragrance: Option<String>,
+    /// Filter by stem length in centimeters
+    #[serde(rename = "attr.stem_length_cm")]
+    pub stem_length_cm: Option<String>,
+    /// Filter by vase life in days
+    #[serde(rename = "attr.vase_life_days")]
+    pub vase_life_days: Option<String>,
+    /// Filter by a metadata entry, given as `namespace.name:value`
+    pub metadata: Option<String>,
+    /// Accept an estimated total on large catalogs, which is much cheaper
+    /// than counting; see `total_estimated` in the response
+    #[param(default = false)]
+    pub estimate: Option<bool>,
+}
+
+impl ListFlowersQuery {
+    /// Attributes a flower needs to have to be listed; `None` without
+    /// `attr.` parameters
+    pub fn attributes(&self) -> DomainResult<Option<FlowerAttributes>> {
+        let number = |name: &str, value: &Option<String>| {
+            value
+                .as_deref()
+                .map(|value| {
+                    value
+                        .trim()
+                        .parse()
+                        .map_err(|_| FlowerError::attribute_invalid(name, value.trim()))
+                })
+                .transpose()
+        };
+        let filter = FlowerAttributes {
+            fragrance: self.fragrance.as_deref().map(str::parse).transpose()?,
+            stem_length_cm: number("stem_length_cm", &self.stem_length_cm)?,
+            vase_life_days: number("vase_life_days", &self.vase_life_days)?,
+            care_instructions: None,
+        };
+        Ok((!filter.is_empty()).then_some(filter))
+    }
+
+    /// Metadata entry a flower needs to have to be listed
+    pub fn metadata(&self) -> DomainResult<Option<FlowerMetadata>> {
+        self.metadata
+            .as_deref()
+            .map(FlowerMetadata::parse_entry)
+            .transpose()
+    }
+}
+
+/// Query parameters for plain paginated listings
+#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+pub struct PaginationQuery {
+    /// Page number (default: 1)
+    #[param(minimum = 1, maximum = 1000000, default = 1)]
+    pub page: Option<i64>,
+    /// Items per page (default: 10)
+    #[param(minimum = 1, maximum = 100, default = 10)]
+    pub per_page: Option<i64>,
+}
+
+/// Where the effective value of a feature flag comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FeatureFlagSource {
+    /// Default from the `FEATURE_FLAGS` setting
+    Config,
+    /// Runtime override stored in the database
+    Database,
+}
+
+/// Response DTO for a feature flag
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "key": "new_search",
+    "enabled": true,
+    "description": "Full-text flower search",
+    "source": "database",
+    "updated_at": "2024-12-15T00:00:00Z"
+}))]
+pub struct FeatureFlagResponse {
+    /// Stable flag key
+    pub key: String,
+    /// Whether the feature is currently on
+    pub enabled: bool,
+    /// Optional description
+    pub description: Option<String>,
+    /// Origin of the current value
+    pub source: FeatureFlagSource,
+    /// Last toggle time (database overrides only)
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<FeatureFlag> for FeatureFlagResponse {
+    fn from(flag: FeatureFlag) -> Self {
+        Self {
+            key: flag.key().to_string(),
+            enabled: flag.enabled(),
+            description: flag.description().map(String::from),
+            source: FeatureFlagSource::Database,
+            updated_at: Some(flag.updated_at()),
+        }
+    }
+}
+
+/// Request DTO for toggling a feature flag
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "enabled": true,
+    "description": "Full-text flower search"
+}))]
+pub struct UpdateFeatureFlagRequest {
+    /// New state of the flag
+    pub enabled: bool,
+    /// Optional description; the previous one is kept when omitted
+    pub description: Option<String>,
+}
+
+/// Response DTO for a dead-lettered task
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "id": "01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a60",
+    "kind": "email.send",
+    "payload": {"to": "owner@example.com"},
+    "attempts": 5,
+    "last_error": "SMTP connection refused",
+    "created_at": "2024-12-17T00:00:00Z",
+    "failed_at": "2024-12-17T01:02:00Z"
+}))]
+pub struct FailedTaskResponse {
+    /// Task identifier
+    pub id: Uuid,
+    /// Task kind, selecting its handler
+    pub kind: String,
+    /// Payload the task was enqueued with
+    pub payload: serde_json::Value,
+    /// Attempts made before giving up
+    pub attempts: i32,
+    /// Error of the final attempt
+    pub last_error: String,
+    /// When the task was enqueued
+    pub created_at: DateTime<Utc>,
+    /// When the task was dead-lettered
+    pub failed_at: DateTime<Utc>,
+}
+
+impl From<FailedTask> for FailedTaskResponse {
+    fn from(task: FailedTask) -> Self {
+        Self {
+            id: task.id(),
+            kind: task.kind().to_string(),
+            payload: task.payload().clone(),
+            attempts: task.attempts(),
+            last_error: task.last_error().to_string(),
+            created_at: task.created_at(),
+            failed_at: task.failed_at(),
+        }
+    }
+}
+
+/// Table written to a backup
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BackupTableResponse {
+    /// Table name
+    pub table: String,
+    /// Number of rows exported
+    pub rows: usize,
+    /// Object store key of the table's NDJSON file
+    pub key: String,
+}
+
+/// Response DTO for a backup; the same document is stored as its manifest
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "id": "01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a60",
+    "created_at": "2024-12-20T03:00:00Z",
+    "tables": [{"table": "flowers", "rows": 10, "key": "backups/01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a60/flowers.ndjson"}],
+    "restore_token": "9f3c2a7d51e84b60a1d2c3e4f5a6b7c8"
+}))]
+pub struct BackupResponse {
+    /// Backup identifier
+    pub id: Uuid,
+    /// When the snapshot was taken
+    pub created_at: DateTime<Utc>,
+    /// Exported tables
+    pub tables: Vec<BackupTableResponse>,
+    /// Token to pass when restoring this backup
+    pub restore_token: String,
+}
+
+/// Request DTO for restoring a backup
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({"confirm": "9f3c2a7d51e84b60a1d2c3e4f5a6b7c8"}))]
+pub struct RestoreBackupRequest {
+    /// Restore token returned when the backup was created
+    pub confirm: String,
+}
+
+/// Response DTO for a restored backup
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RestoreResponse {
+    /// Backup that was restored
+    pub id: Uuid,
+    /// When the restore finished
+    pub restored_at: DateTime<Utc>,
+    /// Tables whose contents were replaced
+    pub tables: Vec<BackupTableResponse>,
+}
+
+/// Request DTO for exporting a printable catalog
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({"tenant": "default", "color": "red"}))]
+pub struct CatalogExportRequest {
+    /// Tenant whose flowers to export (default: the default tenant)
+    pub tenant: Option<String>,
+    /// Only flowers whose name matches
+    pub search: Option<String>,
+    /// Only flowers of this color
+    pub color: Option<String>,
+}
+
+/// Progress of a catalog export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CatalogExportStatus {
+    /// Waiting for a task worker; failed attempts are retried
+    Pending,
+    /// Rendered and ready to download
+    Ready,
+}
+
+/// Response DTO for a catalog export
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "id": "0193f6a2-7c4e-7d10-9b2a-5f0e6c1d2a3b",
+    "status": "ready",
+    "tenant": "default",
+    "search": null,
+    "color": "red",
+    "requested_at": "2024-12-22T09:00:00Z",
+    "completed_at": "2024-12-22T09:00:02Z",
+    "flowers": 14,
+    "pages": 2,
+    "download": "/api/admin/catalog-exports/0193f6a2-7c4e-7d10-9b2a-5f0e6c1d2a3b/catalog.html"
+}))]
+pub struct CatalogExportResponse {
+    /// Export identifier
+    pub id: Uuid,
+    pub status: CatalogExportStatus,
+    /// Tenant whose flowers are exported
+    pub tenant: String,
+    /// Name filter, if any
+    pub search: Option<String>,
+    /// Color filter, if any
+    pub color: Option<FlowerColor>,
+    pub requested_at: DateTime<Utc>,
+    /// When the catalog was rendered, once ready
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Number of flowers in the catalog, once ready
+    pub flowers: Option<usize>,
+    /// Number of printed pages, once ready
+    pub pages: Option<usize>,
+    /// Where to download the catalog, once ready
+    pub download: Option<String>,
+}
+
+/// Outcome of synchronizing a supplier's feed into the catalog
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "supplier": "bloomwholesale",
+    "tenant": "default",
+    "created": 3,
+    "updated": 12,
+    "unchanged": 140,
+    "skipped": 1,
+    "errors": ["entry 18 (RO-99): Invalid flower price: price cannot be negative"]
+}))]
+pub struct SupplierSyncResponse {
+    pub supplier: String,
+    /// Tenant the supplier's products are synchronized into
+    pub tenant: String,
+    /// Flowers created for SKUs seen for the first time
+    pub created: usize,
+    /// Existing flowers changed to match the feed
+    pub updated: usize,
+    /// Existing flowers already matching the feed
+    pub unchanged: usize,
+    /// Feed entries that could not be read or applied
+    pub skipped: usize,
+    /// Why entries were skipped, up to the first 20
+    pub errors: Vec<String>,
+}
+
+/// Headers selecting the tenant (shop) of a tenant-scoped request
+///
+/// Which sources are honoured depends on `TENANT_SOURCES`; besides this
+/// header the tenant can come from the `api_key` security scheme or the
+/// subdomain. Without any, the default tenant is used.
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Header)]
+pub struct TenantHeaders {
+    /// Tenant identifier
+    #[serde(rename = "X-Tenant-Id")]
+    pub tenant_id: Option<String>,
+}
+
+/// Declares the OpenAPI schemas of a paginated listing of `$item`: the page,
+/// mirroring `PaginatedResponse<$item>`, and its `ApiResponse` envelope
+///
+/// utoipa inlines the item schema into generic types, so each listing gets
+/// named schemas that reference `$item` instead. The `From` conversion
+/// fails to compile if the page drifts from `PaginatedResponse`.
+macro_rules! paginated_schemas {
+    (
+        $(#[doc = $page_doc:literal])* $page:ident,
+        $(#[doc = $envelope_doc:literal])* $envelope:ident,
+        $item:ty
+    ) => {
+        $(#[doc = $page_doc])*
+        #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+        pub struct $page {
+            pub data: Vec<$item>,
+            pub total: i64,
+            pub page: i64,
+            pub per_page: i64,
+            pub total_pages: i64,
+            /// Whether `total` is the database's estimate rather than an exact
+            /// count; only ever with `estimate=true`
+            pub total_estimated: bool,
+        }
+
+        impl From<PaginatedResponse<$item>> for $page {
+            fn from(response: PaginatedResponse<$item>) -> Self {
+                let PaginatedResponse {
+                    data,
+                    total,
+                    page,
+                    per_page,
+                    total_pages,
+                    total_estimated,
+                } = response;
+                Self {
+                    data,
+                    total,
+                    page,
+                    per_page,
+                    total_pages,
+                    total_estimated,
+                }
+            }
+        }
+
+        $(#[doc = $envelope_doc])*
+        #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+        pub struct $envelope {
+            pub success: bool,
+            pub data: $page,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub message: Option<String>,
+        }
+    };
+}
+
+/// Generic API response wrapper
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiResponse<T> {
+    /// Indicates if the request was successful
+    pub success: bool,
+    /// Response data
+    pub data: T,
+    /// Optional message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl<T> ApiResponse<T> {
+    pub fn success(data: T) -> Self {
+        Self {
+            success: true,
+            data,
+            message: None,
+        }
+    }
+
+    pub fn with_message(data: T, message: impl Into<String>) -> Self {
+        Self {
+            success: true,
+            data,
+            message: Some(message.into()),
+        }
+    }
+}
+
+/// API Response for single flower
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseFlower {
+    pub success: bool,
+    pub data: FlowerResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a single delivery zone
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseDeliveryZone {
+    pub success: bool,
+    pub data: DeliveryZoneResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a list of delivery zones
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseDeliveryZones {
+    pub success: bool,
+    pub data: Vec<DeliveryZoneResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a delivery address check
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseDeliveryCheck {
+    pub success: bool,
+    pub data: DeliveryCheckResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for shipping rates
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseShippingRates {
+    pub success: bool,
+    pub data: Vec<ShippingRateResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a single order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseOrder {
+    pub success: bool,
+    pub data: OrderResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for the status history of an order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseOrderEvents {
+    pub success: bool,
+    pub data: Vec<OrderEventResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a single pricing rule
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponsePricingRule {
+    pub success: bool,
+    pub data: PricingRuleResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a list of pricing rules
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponsePricingRules {
+    pub success: bool,
+    pub data: Vec<PricingRuleResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+paginated_schemas! {
+    /// Paginated flower response for OpenAPI schema
+    PaginatedFlowerResponse,
+    /// API Response for paginated flowers
+    ApiResponsePaginatedFlower,
+    FlowerResponse
+}
+
+/// API Response for supported colors
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "success": true,
+    "data": ["red", "white", "pink", "yellow", "orange", "purple", "blue", "peach", "mixed"]
+}))]
+pub struct ApiResponseColors {
+    pub success: bool,
+    pub data: Vec<FlowerColor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a stock movement
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseStockMovement {
+    pub success: bool,
+    pub data: StockMovementResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for the catalog's filters
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseFlowerFilters {
+    pub success: bool,
+    pub data: FlowerFiltersResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for trending flowers
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseTrendingFlowers {
+    pub success: bool,
+    pub data: Vec<TrendingFlowerResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for recently viewed flowers
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseRecentlyViewedFlowers {
+    pub success: bool,
+    pub data: Vec<RecentlyViewedFlowerResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a batch price adjustment
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponsePriceAdjustment {
+    pub success: bool,
+    pub data: PriceAdjustmentResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a single feature flag
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseFeatureFlag {
+    pub success: bool,
+    pub data: FeatureFlagResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for all feature flags
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseFeatureFlags {
+    pub success: bool,
+    pub data: Vec<FeatureFlagResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+paginated_schemas! {
+    /// Paginated flower change response for OpenAPI schema
+    PaginatedFlowerChangeResponse,
+    /// API Response for paginated flower changes
+    ApiResponsePaginatedFlowerChange,
+    FlowerChangeResponse
+}
+
+/// API Response for a saved search
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseSavedSearch {
+    pub success: bool,
+    pub data: SavedSearchResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for the saved searches of the caller
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseSavedSearches {
+    pub success: bool,
+    pub data: Vec<SavedSearchResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a single store
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseStore {
+    pub success: bool,
+    pub data: StoreResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a list of stores
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseStores {
+    pub success: bool,
+    pub data: Vec<StoreResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for the stock a store holds of a flower
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseStoreStock {
+    pub success: bool,
+    pub data: StoreStockResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for flower availability
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseStoreAvailability {
+    pub success: bool,
+    pub data: Vec<StoreAvailabilityResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+paginated_schemas! {
+    /// Paginated ledger entry response for OpenAPI schema
+    PaginatedLedgerEntryResponse,
+    /// API Response for paginated ledger entries
+    ApiResponsePaginatedLedgerEntry,
+    LedgerEntryResponse
+}
+
+/// API Response for a flower purge
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseFlowerPurge {
+    pub success: bool,
+    pub data: FlowerPurgeResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a cache purge
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseCachePurge {
+    pub success: bool,
+    pub data: CachePurgeResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for the scheduled jobs
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseJobs {
+    pub success: bool,
+    pub data: Vec<JobResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for the quota of an API key
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseQuota {
+    pub success: bool,
+    pub data: QuotaResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for the quotas of every API key
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseQuotas {
+    pub success: bool,
+    pub data: Vec<QuotaResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a single webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseWebhookEndpoint {
+    pub success: bool,
+    pub data: WebhookEndpointResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a list of webhook endpoints
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseWebhookEndpoints {
+    pub success: bool,
+    pub data: Vec<WebhookEndpointResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a test delivery
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseWebhookTest {
+    pub success: bool,
+    pub data: WebhookTestResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for an API usage report
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseUsageReport {
+    pub success: bool,
+    pub data: UsageReportResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+paginated_schemas! {
+    /// Paginated dead-lettered task response for OpenAPI schema
+    PaginatedFailedTaskResponse,
+    /// API Response for paginated dead-lettered tasks
+    ApiResponsePaginatedFailedTask,
+    FailedTaskResponse
+}
+
+/// Error response
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "success": false,
+    "code": "flower.not_found",
+    "error": "Flower not found with id: 550e8400-e29b-41d4-a716-446655440001",
+    "request_id": "01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a60"
+}))]
+pub struct ErrorResponse {
+    /// Always false for errors
+    pub success: bool,
+    /// Machine-readable error code, identical in every language
+    pub code: String,
+    /// Error message, localized according to `Accept-Language`
+    pub error: String,
+    /// ID of the conflicting resource (409 responses only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_id: Option<Uuid>,
+    /// Per-field errors (422 responses only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<FieldErrorResponse>>,
+    /// ID of the failed request, to quote in support tickets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+/// Error details for a single request field
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FieldErrorResponse {
+    /// Name of the offending field
+    pub field: String,
+    /// Machine-readable error code
+    pub code: String,
+    /// Localized error message
+    pub error: String,
+}
+
+/// API Response for a backup
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseBackup {
+    pub success: bool,
+    pub data: BackupResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a restored backup
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseRestore {
+    pub success: bool,
+    pub data: RestoreResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a catalog export
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseCatalogExport {
+    pub success: bool,
+    pub data: CatalogExportResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// API Response for a supplier synchronization
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseSupplierSync {
+    pub success: bool,
+    pub data: SupplierSyncResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
 }
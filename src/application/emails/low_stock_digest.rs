@@ -0,0 +1,78 @@
+//! Low Stock Digest Email
+
+use askama::Template;
+
+use crate::application::emails::EmailTemplate;
+use crate::domain::flower::Flower;
+use crate::domain::shared::TenantId;
+
+/// Flowers of one tenant running out of stock
+#[derive(Template)]
+#[template(path = "email/low_stock_digest.txt")]
+pub struct LowStockDigest<'a> {
+    pub tenant: &'a TenantId,
+    pub threshold: i32,
+    pub flowers: &'a [Flower],
+}
+
+#[derive(Template)]
+#[template(path = "email/low_stock_digest.html")]
+struct Html<'a> {
+    digest: &'a LowStockDigest<'a>,
+}
+
+impl EmailTemplate for LowStockDigest<'_> {
+    fn subject(&self) -> String {
+        format!(
+            "Low stock at {}: {} flowers",
+            self.tenant,
+            self.flowers.len()
+        )
+    }
+
+    fn text(&self) -> askama::Result<String> {
+        self.render()
+    }
+
+    fn html(&self) -> askama::Result<Option<String>> {
+        Html { digest: self }.render().map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::emails::compose;
+    use crate::test_support::FlowerBuilder;
+
+    fn flower(tenant: &TenantId, name: &str, stock: i32) -> Flower {
+        FlowerBuilder::new()
+            .with_tenant(tenant)
+            .with_name(name)
+            .with_stock(stock)
+            .build()
+    }
+
+    #[test]
+    fn lists_every_flower_in_both_bodies() {
+        let tenant: TenantId = "shop-a".parse().unwrap();
+        let flowers = [flower(&tenant, "Rose", 2), flower(&tenant, "Tulip & Co", 0)];
+        let digest = LowStockDigest {
+            tenant: &tenant,
+            threshold: 5,
+            flowers: &flowers,
+        };
+
+        let message = compose(&["ops@example.com".to_string()], &digest).unwrap();
+        assert_eq!(message.subject, "Low stock at shop-a: 2 flowers");
+        assert_eq!(
+            message.text,
+            "2 flowers at shop-a are at or below 5 in stock:\n\n\
+             - Rose: 2 left\n\
+             - Tulip & Co: 0 left\n\n\
+             Restock them before they sell out."
+        );
+        let html = message.html.unwrap();
+        assert!(html.contains("<td>Tulip &#38; Co</td>"));
+    }
+}
@@ -0,0 +1,40 @@
+//! Email Templates
+//!
+//! Each email is an askama template under `templates/email`, checked at
+//! compile time. Templates are rendered when the email is queued, so the
+//! task queue only ever holds finished messages.
+
+pub mod low_stock_digest;
+pub mod saved_search_alert;
+
+use crate::application::ports::EmailMessage;
+use crate::domain::errors::{AppError, DomainResult};
+
+pub use low_stock_digest::LowStockDigest;
+pub use saved_search_alert::SavedSearchAlert;
+
+/// Content of one kind of email
+pub trait EmailTemplate {
+    fn subject(&self) -> String;
+
+    /// Plain text body
+    fn text(&self) -> askama::Result<String>;
+
+    /// HTML alternative of the text body, if the email has one
+    fn html(&self) -> askama::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// Render a template into a message for `to`
+pub fn compose(to: &[String], template: &impl EmailTemplate) -> DomainResult<EmailMessage> {
+    let render_error =
+        |e: askama::Error| AppError::internal(format!("Failed to render email: {}", e));
+
+    Ok(EmailMessage {
+        to: to.to_vec(),
+        subject: template.subject(),
+        text: template.text().map_err(render_error)?,
+        html: template.html().map_err(render_error)?,
+    })
+}
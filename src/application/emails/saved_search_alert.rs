@@ -0,0 +1,98 @@
+//! Saved Search Alert Email
+
+use askama::Template;
+
+use crate::application::emails::EmailTemplate;
+use crate::domain::flower::Flower;
+use crate::domain::saved_search::SavedSearch;
+
+/// Flowers newly matching a saved search
+#[derive(Template)]
+#[template(path = "email/saved_search_alert.txt")]
+pub struct SavedSearchAlert<'a> {
+    pub search: &'a SavedSearch,
+    pub flowers: &'a [&'a Flower],
+}
+
+#[derive(Template)]
+#[template(path = "email/saved_search_alert.html")]
+struct Html<'a> {
+    alert: &'a SavedSearchAlert<'a>,
+}
+
+impl EmailTemplate for SavedSearchAlert<'_> {
+    fn subject(&self) -> String {
+        match self.flowers {
+            [flower] => format!(
+                "New match for \"{}\": {}",
+                self.search.name(),
+                flower.name()
+            ),
+            flowers => format!(
+                "{} new matches for \"{}\"",
+                flowers.len(),
+                self.search.name()
+            ),
+        }
+    }
+
+    fn text(&self) -> askama::Result<String> {
+        self.render()
+    }
+
+    fn html(&self) -> askama::Result<Option<String>> {
+        Html { alert: self }.render().map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::emails::compose;
+    use crate::domain::saved_search::SearchCriteria;
+    use crate::domain::shared::TenantId;
+    use crate::test_support::FlowerBuilder;
+
+    #[test]
+    fn lists_the_new_flowers_in_both_bodies() {
+        let search = SavedSearch::new(
+            TenantId::default(),
+            "admin",
+            "Roses <3",
+            SearchCriteria::default(),
+            Some("florist@example.com"),
+        )
+        .unwrap();
+        let rose = FlowerBuilder::new()
+            .with_name("Red Rose")
+            .with_price(25_000.0)
+            .build();
+        let tulip = FlowerBuilder::new()
+            .with_name("Tulip & Co")
+            .with_price(18_500.0)
+            .build();
+
+        let alert = SavedSearchAlert {
+            search: &search,
+            flowers: &[&rose, &tulip],
+        };
+        let message = compose(&["florist@example.com".to_string()], &alert).unwrap();
+        assert_eq!(message.subject, "2 new matches for \"Roses <3\"");
+        assert_eq!(
+            message.text,
+            "2 new flowers match your saved search \"Roses <3\":\n\n\
+             - Red Rose: 25000\n\
+             - Tulip & Co: 18500\n\n\
+             Remove the saved search or its alert address to stop these emails."
+        );
+        let html = message.html.unwrap();
+        assert!(html.contains("Roses &#60;3"));
+        assert!(html.contains("<td>Tulip &#38; Co</td>"));
+
+        let single = SavedSearchAlert {
+            search: &search,
+            flowers: &[&rose],
+        };
+        assert_eq!(single.subject(), "New match for \"Roses <3\": Red Rose");
+    }
+}
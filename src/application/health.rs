@@ -0,0 +1,178 @@
+//! Health Registry
+//!
+//! Subsystems register a `HealthIndicator` as they are wired; readiness
+//! checks them all at once and sums them up, so a new adapter shows up in
+//! health output without the probe knowing about it.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::task::JoinSet;
+
+use crate::application::ports::{Health, HealthIndicator, HealthStatus};
+
+/// Upper bound for each check so probes never hang
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Whether the service can take traffic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Readiness {
+    Ready,
+    /// Taking traffic with a component degraded or a non-critical one down
+    Degraded,
+    /// A critical component is down
+    Unavailable,
+}
+
+impl Readiness {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Readiness::Ready => "ready",
+            Readiness::Degraded => "degraded",
+            Readiness::Unavailable => "unavailable",
+        }
+    }
+}
+
+/// Health of one component, and how long checking it took
+#[derive(Debug, Clone)]
+pub struct ComponentReport {
+    pub name: &'static str,
+    pub critical: bool,
+    pub health: Health,
+    pub latency: Duration,
+}
+
+/// Health of every registered component, in registration order
+#[derive(Debug, Clone)]
+pub struct HealthReport {
+    pub components: Vec<ComponentReport>,
+}
+
+impl HealthReport {
+    pub fn readiness(&self) -> Readiness {
+        let mut readiness = Readiness::Ready;
+        for component in &self.components {
+            match component.health.status {
+                HealthStatus::Up => {}
+                HealthStatus::Down if component.critical => return Readiness::Unavailable,
+                HealthStatus::Down | HealthStatus::Degraded => readiness = Readiness::Degraded,
+            }
+        }
+        readiness
+    }
+}
+
+/// Indicators of the components the service depends on
+#[derive(Clone, Default)]
+pub struct HealthRegistry {
+    indicators: Vec<Arc<dyn HealthIndicator>>,
+}
+
+impl HealthRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(mut self, indicator: impl HealthIndicator + 'static) -> Self {
+        self.indicators.push(Arc::new(indicator));
+        self
+    }
+
+    /// Check every component concurrently, each within `CHECK_TIMEOUT`
+    pub async fn check(&self) -> HealthReport {
+        let mut checks = JoinSet::new();
+        for (index, indicator) in self.indicators.iter().cloned().enumerate() {
+            checks.spawn(async move {
+                let started = Instant::now();
+                let health = tokio::time::timeout(CHECK_TIMEOUT, indicator.check())
+                    .await
+                    .unwrap_or_else(|_| Health::down("timed out"));
+                (index, health, started.elapsed())
+            });
+        }
+
+        let mut results = vec![None; self.indicators.len()];
+        while let Some(result) = checks.join_next().await {
+            if let Ok((index, health, latency)) = result {
+                results[index] = Some((health, latency));
+            }
+        }
+
+        let components = self
+            .indicators
+            .iter()
+            .zip(results)
+            .map(|(indicator, result)| {
+                // A check that panicked tells nothing good about its component
+                let (health, latency) =
+                    result.unwrap_or_else(|| (Health::down("check failed"), Duration::ZERO));
+                ComponentReport {
+                    name: indicator.name(),
+                    critical: indicator.critical(),
+                    health,
+                    latency,
+                }
+            })
+            .collect();
+        HealthReport { components }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+
+    struct Fixed {
+        name: &'static str,
+        critical: bool,
+        status: HealthStatus,
+    }
+
+    #[async_trait]
+    impl HealthIndicator for Fixed {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn critical(&self) -> bool {
+            self.critical
+        }
+
+        async fn check(&self) -> Health {
+            Health {
+                status: self.status,
+                details: None,
+                error: None,
+            }
+        }
+    }
+
+    fn fixed(name: &'static str, critical: bool, status: HealthStatus) -> Fixed {
+        Fixed {
+            name,
+            critical,
+            status,
+        }
+    }
+
+    #[tokio::test]
+    async fn only_critical_components_make_the_service_unavailable() {
+        let healthy = HealthRegistry::new().register(fixed("database", true, HealthStatus::Up));
+        let cache_down = healthy
+            .clone()
+            .register(fixed("cache", false, HealthStatus::Down));
+        let database_down = HealthRegistry::new()
+            .register(fixed("cache", false, HealthStatus::Up))
+            .register(fixed("database", true, HealthStatus::Down));
+
+        assert_eq!(healthy.check().await.readiness(), Readiness::Ready);
+        assert_eq!(cache_down.check().await.readiness(), Readiness::Degraded);
+        let report = database_down.check().await;
+        assert_eq!(report.readiness(), Readiness::Unavailable);
+        let names: Vec<_> = report.components.iter().map(|c| c.name).collect();
+        assert_eq!(names, ["cache", "database"]);
+    }
+}
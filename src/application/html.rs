@@ -0,0 +1,150 @@
+//! HTML Fragments
+//!
+//! Escaping for text placed in HTML, and search highlighting: matches of a
+//! search term wrapped in `<em>`, found the way searches match, by
+//! case-insensitive substring, so it works the same on every storage backend.
+
+use std::ops::Range;
+
+/// Characters of context kept on each side of the first match in a snippet
+const SNIPPET_CONTEXT: usize = 40;
+
+/// Escape text for use in HTML content and attribute values
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// `text` escaped, with every match of `term` wrapped in `<em>`; `None` when
+/// nothing matches
+pub fn highlight(text: &str, term: &str) -> Option<String> {
+    let matches = matches(text, term);
+    (!matches.is_empty()).then(|| emphasize(text, &matches))
+}
+
+/// Part of `text` around the first match of `term`, highlighted, with `…`
+/// where text was cut; `None` when nothing matches
+pub fn snippet(text: &str, term: &str) -> Option<String> {
+    let matches = matches(text, term);
+    let first = matches.first()?;
+
+    let start = text[..first.start]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_CONTEXT - 1)
+        .map_or(0, |(index, _)| index);
+    let end = text[first.end..]
+        .char_indices()
+        .nth(SNIPPET_CONTEXT)
+        .map_or(text.len(), |(index, _)| first.end + index);
+
+    let inside: Vec<Range<usize>> = matches
+        .iter()
+        .filter(|found| found.end <= end)
+        .map(|found| found.start - start..found.end - start)
+        .collect();
+    let mut snippet = emphasize(&text[start..end], &inside);
+    if start > 0 {
+        snippet.insert(0, '…');
+    }
+    if end < text.len() {
+        snippet.push('…');
+    }
+    Some(snippet)
+}
+
+/// Byte ranges of the non-overlapping, case-insensitive matches of `term`
+fn matches(text: &str, term: &str) -> Vec<Range<usize>> {
+    let needle: Vec<char> = term.trim().to_lowercase().chars().collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    // Lowercasing may turn one character into several; each keeps the range
+    // of the character it came from, so matches map back onto `text`
+    let folded: Vec<(Range<usize>, char)> = text
+        .char_indices()
+        .flat_map(|(index, c)| {
+            let range = index..index + c.len_utf8();
+            c.to_lowercase().map(move |lower| (range.clone(), lower))
+        })
+        .collect();
+
+    let mut found = Vec::new();
+    let mut position = 0;
+    while position + needle.len() <= folded.len() {
+        let candidate = &folded[position..position + needle.len()];
+        if candidate.iter().map(|(_, c)| *c).eq(needle.iter().copied()) {
+            found.push(candidate[0].0.start..candidate[needle.len() - 1].0.end);
+            position += needle.len();
+        } else {
+            position += 1;
+        }
+    }
+    found
+}
+
+/// `text` escaped, with the sorted, disjoint `ranges` wrapped in `<em>`
+fn emphasize(text: &str, ranges: &[Range<usize>]) -> String {
+    let mut html = String::with_capacity(text.len() + ranges.len() * 9);
+    let mut last = 0;
+    for range in ranges {
+        // Characters folding into several may end a match where the next begins
+        let start = range.start.max(last);
+        html.push_str(&escape(&text[last..start]));
+        html.push_str("<em>");
+        html.push_str(&escape(&text[start..range.end]));
+        html.push_str("</em>");
+        last = range.end;
+    }
+    html.push_str(&escape(&text[last..]));
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_every_match_regardless_of_case() {
+        assert_eq!(
+            highlight("Rose & Rosemary", "ROSE").as_deref(),
+            Some("<em>Rose</em> &amp; <em>Rose</em>mary")
+        );
+        assert_eq!(
+            highlight("<b>Tulip</b>", "tulip").as_deref(),
+            Some("&lt;b&gt;<em>Tulip</em>&lt;/b&gt;")
+        );
+        assert_eq!(highlight("Kembang Sepatu", "rose"), None);
+        assert_eq!(highlight("Rose", "  "), None);
+    }
+
+    #[test]
+    fn snippets_keep_context_around_the_first_match() {
+        let description = format!("{} red petals {}", "a".repeat(60), "b".repeat(60));
+        let snippet = snippet(&description, "RED").unwrap();
+        assert_eq!(
+            snippet,
+            format!(
+                "…{} <em>red</em> petals {}…",
+                "a".repeat(SNIPPET_CONTEXT - 1),
+                "b".repeat(SNIPPET_CONTEXT - 8)
+            )
+        );
+
+        assert_eq!(
+            super::snippet("Ünique bloom", "ünique").as_deref(),
+            Some("<em>Ünique</em> bloom")
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! API Usage Flush Job
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::jobs::Job;
+use crate::application::usecases::UsageMetering;
+use crate::domain::errors::DomainResult;
+
+/// Writes the API usage metered by this instance to the usage store
+pub struct FlushUsageJob {
+    usage: Arc<UsageMetering>,
+}
+
+impl FlushUsageJob {
+    pub fn new(usage: Arc<UsageMetering>) -> Self {
+        Self { usage }
+    }
+}
+
+#[async_trait]
+impl Job for FlushUsageJob {
+    fn name(&self) -> &'static str {
+        "flush_usage"
+    }
+
+    fn exclusive(&self) -> bool {
+        false
+    }
+
+    async fn run(&self) -> DomainResult<()> {
+        let flushed = self.usage.flush().await?;
+        if flushed > 0 {
+            tracing::debug!("Flushed the usage of {} requests", flushed);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+//! Flower View Flush Job
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::jobs::Job;
+use crate::application::ports::FlowerRepository;
+use crate::application::usecases::FlowerViews;
+use crate::domain::errors::DomainResult;
+
+/// Writes the views buffered by this instance to the view store
+pub struct FlushViewsJob<R: FlowerRepository + ?Sized> {
+    views: Arc<FlowerViews<R>>,
+}
+
+impl<R: FlowerRepository + ?Sized> FlushViewsJob<R> {
+    pub fn new(views: Arc<FlowerViews<R>>) -> Self {
+        Self { views }
+    }
+}
+
+#[async_trait]
+impl<R: FlowerRepository + ?Sized> Job for FlushViewsJob<R> {
+    fn name(&self) -> &'static str {
+        "flush_views"
+    }
+
+    fn exclusive(&self) -> bool {
+        false
+    }
+
+    async fn run(&self) -> DomainResult<()> {
+        let flushed = self.views.flush().await?;
+        if flushed > 0 {
+            tracing::debug!("Flushed {} flower views", flushed);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+//! Low Stock Digest Job
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::application::emails::LowStockDigest;
+use crate::application::jobs::Job;
+use crate::application::ports::FlowerRepository;
+use crate::application::usecases::Emails;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::Flower;
+use crate::domain::shared::TenantId;
+
+/// Reports flowers running out of stock, per tenant
+///
+/// The digest is logged as a warning and exported as the
+/// `flowers_low_stock{tenant}` gauge so alerts can be built on it. With
+/// recipients set, each tenant's digest is also emailed to them.
+pub struct LowStockDigestJob<R: FlowerRepository + ?Sized> {
+    repository: Arc<R>,
+    threshold: i32,
+    reported_tenants: Mutex<HashSet<TenantId>>,
+    emails: Option<(Arc<Emails>, Vec<String>)>,
+}
+
+impl<R: FlowerRepository + ?Sized> LowStockDigestJob<R> {
+    pub fn new(repository: Arc<R>, threshold: i32) -> Self {
+        Self {
+            repository,
+            threshold,
+            reported_tenants: Mutex::new(HashSet::new()),
+            emails: None,
+        }
+    }
+
+    /// Email every digest to `recipients`; an empty list sends nothing
+    pub fn with_emails(mut self, emails: Arc<Emails>, recipients: Vec<String>) -> Self {
+        if !recipients.is_empty() {
+            self.emails = Some((emails, recipients));
+        }
+        self
+    }
+}
+
+#[async_trait]
+impl<R: FlowerRepository + ?Sized> Job for LowStockDigestJob<R> {
+    fn name(&self) -> &'static str {
+        "low_stock_digest"
+    }
+
+    async fn run(&self) -> DomainResult<()> {
+        let flowers = self.repository.find_low_stock(self.threshold).await?;
+
+        let mut by_tenant: BTreeMap<TenantId, Vec<Flower>> = BTreeMap::new();
+        for flower in flowers {
+            by_tenant
+                .entry(flower.tenant_id().clone())
+                .or_default()
+                .push(flower);
+        }
+
+        for (tenant, flowers) in &by_tenant {
+            let items = flowers
+                .iter()
+                .map(|flower| format!("{} ({})", flower.name(), flower.stock()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            tracing::warn!(
+                tenant = %tenant,
+                "{} flowers at or below {} in stock: {}",
+                flowers.len(),
+                self.threshold,
+                items
+            );
+            metrics::gauge!("flowers_low_stock", "tenant" => tenant.to_string())
+                .set(flowers.len() as f64);
+
+            if let Some((emails, recipients)) = &self.emails {
+                let digest = LowStockDigest {
+                    tenant,
+                    threshold: self.threshold,
+                    flowers,
+                };
+                if let Err(e) = emails.send(recipients, &digest).await {
+                    tracing::error!(tenant = %tenant, "Failed to queue low stock email: {}", e);
+                }
+            }
+        }
+
+        // Clear the gauge for tenants that have restocked since the last run
+        let mut reported = self.reported_tenants.lock().expect("digest lock poisoned");
+        for tenant in reported.iter().filter(|t| !by_tenant.contains_key(*t)) {
+            metrics::gauge!("flowers_low_stock", "tenant" => tenant.to_string()).set(0.0);
+        }
+        *reported = by_tenant.into_keys().collect();
+
+        Ok(())
+    }
+}
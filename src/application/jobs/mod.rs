@@ -0,0 +1,44 @@
+//! Background Jobs
+//!
+//! Jobs run on a schedule inside the server process; the scheduler in
+//! `infrastructure::scheduler` decides when, this module what.
+
+pub mod flush_usage;
+pub mod flush_views;
+pub mod low_stock_digest;
+pub mod monitor;
+pub mod purge_webhook_nonces;
+pub mod refresh_feature_flags;
+pub mod retention;
+pub mod saved_search_alerts;
+pub mod supplier_sync;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainResult;
+
+pub use flush_usage::FlushUsageJob;
+pub use flush_views::FlushViewsJob;
+pub use low_stock_digest::LowStockDigestJob;
+pub use monitor::{JobMonitor, JobStatus};
+pub use purge_webhook_nonces::PurgeWebhookNoncesJob;
+pub use refresh_feature_flags::RefreshFeatureFlagsJob;
+pub use retention::{RetentionJob, RetentionPolicy};
+pub use saved_search_alerts::SavedSearchAlertsJob;
+pub use supplier_sync::SupplierSyncJob;
+
+/// Unit of background work run by the scheduler
+#[async_trait]
+pub trait Job: Send + Sync {
+    /// Stable name used in logs and metrics
+    fn name(&self) -> &'static str;
+
+    /// Whether only one replica may run the job per tick; jobs that update
+    /// per-instance state (such as in-memory caches) return false
+    fn exclusive(&self) -> bool {
+        true
+    }
+
+    /// Run the job once; on failure it is retried at its next scheduled time
+    async fn run(&self) -> DomainResult<()>;
+}
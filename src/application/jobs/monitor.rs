@@ -0,0 +1,136 @@
+//! Job Monitor
+//!
+//! The scheduler reports every run here so admins can see what the jobs of
+//! this instance are doing without searching the logs.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// Latest state of a scheduled job on this instance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobStatus {
+    pub name: &'static str,
+    /// Schedule as configured, e.g. `@every 60s`
+    pub schedule: String,
+    pub exclusive: bool,
+    /// Whether a run is in progress
+    pub running: bool,
+    /// When the next run is due, while waiting for it
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_started_at: Option<DateTime<Utc>>,
+    pub last_duration: Option<Duration>,
+    /// Error of the last run, if it failed
+    pub last_error: Option<String>,
+    /// Completed runs, failed ones included
+    pub runs: u64,
+    pub failures: u64,
+    /// Ticks left to another replica holding the job's lock
+    pub skipped: u64,
+}
+
+/// Shared record of the scheduled jobs; cheap to clone
+#[derive(Debug, Clone, Default)]
+pub struct JobMonitor {
+    jobs: Arc<Mutex<BTreeMap<&'static str, JobStatus>>>,
+}
+
+impl JobMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start tracking a job
+    pub fn register(&self, name: &'static str, schedule: String, exclusive: bool) {
+        self.jobs().insert(
+            name,
+            JobStatus {
+                name,
+                schedule,
+                exclusive,
+                running: false,
+                next_run_at: None,
+                last_started_at: None,
+                last_duration: None,
+                last_error: None,
+                runs: 0,
+                failures: 0,
+                skipped: 0,
+            },
+        );
+    }
+
+    pub fn scheduled(&self, name: &'static str, at: DateTime<Utc>) {
+        self.update(name, |job| job.next_run_at = Some(at));
+    }
+
+    pub fn skipped(&self, name: &'static str) {
+        self.update(name, |job| job.skipped += 1);
+    }
+
+    pub fn started(&self, name: &'static str) {
+        self.update(name, |job| {
+            job.running = true;
+            job.next_run_at = None;
+            job.last_started_at = Some(Utc::now());
+        });
+    }
+
+    /// Record the end of a run and its error, if it failed
+    pub fn finished(&self, name: &'static str, elapsed: Duration, error: Option<String>) {
+        self.update(name, |job| {
+            job.running = false;
+            job.last_duration = Some(elapsed);
+            job.runs += 1;
+            if error.is_some() {
+                job.failures += 1;
+            }
+            job.last_error = error;
+        });
+    }
+
+    /// Every tracked job, by name
+    pub fn statuses(&self) -> Vec<JobStatus> {
+        self.jobs().values().cloned().collect()
+    }
+
+    fn update(&self, name: &'static str, change: impl FnOnce(&mut JobStatus)) {
+        if let Some(job) = self.jobs().get_mut(name) {
+            change(job);
+        }
+    }
+
+    fn jobs(&self) -> std::sync::MutexGuard<'_, BTreeMap<&'static str, JobStatus>> {
+        self.jobs.lock().expect("job monitor lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_runs_and_their_failures() {
+        let monitor = JobMonitor::new();
+        monitor.register("flush_views", "@every 60s".to_string(), false);
+        monitor.scheduled("flush_views", Utc::now());
+
+        monitor.started("flush_views");
+        let running = &monitor.statuses()[0];
+        assert!(running.running);
+        assert_eq!(running.next_run_at, None);
+
+        monitor.finished("flush_views", Duration::from_millis(5), Some("boom".into()));
+        monitor.started("flush_views");
+        monitor.finished("flush_views", Duration::from_millis(3), None);
+        monitor.skipped("unknown");
+
+        let status = &monitor.statuses()[0];
+        assert!(!status.running);
+        assert_eq!((status.runs, status.failures), (2, 1));
+        assert_eq!(status.last_error, None);
+        assert_eq!(status.last_duration, Some(Duration::from_millis(3)));
+    }
+}
@@ -0,0 +1,36 @@
+//! Webhook Nonce Purge Job
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::application::jobs::Job;
+use crate::application::ports::NonceStore;
+use crate::domain::errors::DomainResult;
+
+/// Forgets the nonces of webhook deliveries too old to be replayed
+pub struct PurgeWebhookNoncesJob {
+    nonces: Arc<dyn NonceStore>,
+}
+
+impl PurgeWebhookNoncesJob {
+    pub fn new(nonces: Arc<dyn NonceStore>) -> Self {
+        Self { nonces }
+    }
+}
+
+#[async_trait]
+impl Job for PurgeWebhookNoncesJob {
+    fn name(&self) -> &'static str {
+        "purge_webhook_nonces"
+    }
+
+    async fn run(&self) -> DomainResult<()> {
+        let purged = self.nonces.purge_expired(Utc::now()).await?;
+        if purged > 0 {
+            tracing::debug!("Purged {} expired webhook nonces", purged);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+//! Feature Flag Refresh Job
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::jobs::Job;
+use crate::application::ports::FeatureFlagRepository;
+use crate::application::usecases::FeatureFlags;
+use crate::domain::errors::DomainResult;
+
+/// Picks up feature flag toggles made through other instances
+pub struct RefreshFeatureFlagsJob<R: FeatureFlagRepository + ?Sized> {
+    feature_flags: Arc<FeatureFlags<R>>,
+}
+
+impl<R: FeatureFlagRepository + ?Sized> RefreshFeatureFlagsJob<R> {
+    pub fn new(feature_flags: Arc<FeatureFlags<R>>) -> Self {
+        Self { feature_flags }
+    }
+}
+
+#[async_trait]
+impl<R: FeatureFlagRepository + ?Sized> Job for RefreshFeatureFlagsJob<R> {
+    fn name(&self) -> &'static str {
+        "refresh_feature_flags"
+    }
+
+    fn exclusive(&self) -> bool {
+        false
+    }
+
+    async fn run(&self) -> DomainResult<()> {
+        self.feature_flags.refresh().await
+    }
+}
@@ -0,0 +1,115 @@
+//! Data Retention Job
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::application::jobs::Job;
+use crate::application::ports::TaskQueue;
+use crate::domain::errors::DomainResult;
+
+/// How long data is kept before the retention job removes it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Age, counted from the final failure, after which dead-lettered tasks go
+    pub dead_letter: Duration,
+    /// Copy expired rows to the archive tables before deleting them
+    pub archive: bool,
+}
+
+/// Removes data past its retention period
+///
+/// Removed rows are counted in the `retention_rows_purged_total{table,mode}`
+/// counter, where `mode` is `archive` or `delete`.
+pub struct RetentionJob<Q: TaskQueue + ?Sized> {
+    tasks: Arc<Q>,
+    policy: RetentionPolicy,
+}
+
+impl<Q: TaskQueue + ?Sized> RetentionJob<Q> {
+    pub fn new(tasks: Arc<Q>, policy: RetentionPolicy) -> Self {
+        Self { tasks, policy }
+    }
+}
+
+#[async_trait]
+impl<Q: TaskQueue + ?Sized> Job for RetentionJob<Q> {
+    fn name(&self) -> &'static str {
+        "retention"
+    }
+
+    async fn run(&self) -> DomainResult<()> {
+        let Some(cutoff) = chrono::Duration::from_std(self.policy.dead_letter)
+            .ok()
+            .and_then(|age| Utc::now().checked_sub_signed(age))
+        else {
+            return Ok(());
+        };
+
+        let purged = self.tasks.purge_failed(cutoff, self.policy.archive).await?;
+        let mode = if self.policy.archive {
+            "archive"
+        } else {
+            "delete"
+        };
+        metrics::counter!(
+            "retention_rows_purged_total",
+            "table" => "dead_letter_tasks",
+            "mode" => mode
+        )
+        .increment(purged);
+        if purged > 0 {
+            tracing::info!(
+                "Retention: {} {} dead-lettered tasks that failed before {}",
+                if self.policy.archive {
+                    "archived"
+                } else {
+                    "deleted"
+                },
+                purged,
+                cutoff
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::task::Task;
+    use crate::infrastructure::memory::InMemoryTaskQueue;
+
+    #[tokio::test]
+    async fn purges_only_dead_letters_past_retention() {
+        let queue = Arc::new(InMemoryTaskQueue::new());
+        let task = Task::new("email", json!({}), Utc::now(), 1);
+        queue.enqueue(&task).await.unwrap();
+        queue.dead_letter(task.id(), "boom").await.unwrap();
+
+        let policy = RetentionPolicy {
+            dead_letter: Duration::from_secs(86_400),
+            archive: true,
+        };
+        RetentionJob::new(queue.clone(), policy)
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(queue.count_failed().await.unwrap(), 1);
+
+        let policy = RetentionPolicy {
+            dead_letter: Duration::ZERO,
+            archive: true,
+        };
+        RetentionJob::new(queue.clone(), policy)
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(queue.count_failed().await.unwrap(), 0);
+    }
+}
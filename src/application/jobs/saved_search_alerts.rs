@@ -0,0 +1,201 @@
+//! Saved Search Alerts Job
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::application::emails::SavedSearchAlert;
+use crate::application::jobs::Job;
+use crate::application::ports::{FlowerRepository, SavedSearchRepository};
+use crate::application::usecases::Emails;
+use crate::domain::errors::DomainResult;
+use crate::domain::saved_search::SavedSearch;
+use crate::domain::shared::{Entity, TenantId};
+
+/// How long a flower is given to be committed after it was created
+///
+/// Flowers are stamped before their transaction commits, so one committing
+/// while the job runs can carry a time the job already looked past. Only
+/// flowers at least this old are alerted about; younger ones wait for the
+/// next run.
+const SETTLE: Duration = Duration::minutes(1);
+
+/// Emails the owners of saved searches about flowers created since the
+/// previous run that match their search
+///
+/// Each search with an alert address is checked once per run; matches are
+/// sent as one email per search, queued like every other email.
+pub struct SavedSearchAlertsJob<R: FlowerRepository + ?Sized> {
+    searches: Arc<dyn SavedSearchRepository>,
+    flowers: Arc<R>,
+    emails: Arc<Emails>,
+}
+
+impl<R: FlowerRepository + ?Sized> SavedSearchAlertsJob<R> {
+    pub fn new(
+        searches: Arc<dyn SavedSearchRepository>,
+        flowers: Arc<R>,
+        emails: Arc<Emails>,
+    ) -> Self {
+        Self {
+            searches,
+            flowers,
+            emails,
+        }
+    }
+
+    /// Alert about the flowers of one tenant created up to `until`
+    async fn alert_tenant(
+        &self,
+        tenant: &TenantId,
+        searches: &[SavedSearch],
+        until: DateTime<Utc>,
+    ) -> DomainResult<()> {
+        let Some(since) = searches.iter().map(SavedSearch::checked_at).min() else {
+            return Ok(());
+        };
+        if since >= until {
+            return Ok(());
+        }
+
+        let mut flowers = self.flowers.find_created_since(tenant, since).await?;
+        flowers.retain(|flower| flower.created_at() <= until);
+
+        for search in searches.iter().filter(|search| search.checked_at() < until) {
+            let matches = search.new_matches(&flowers);
+            if let Some(address) = search.alert_email()
+                && !matches.is_empty()
+            {
+                let alert = SavedSearchAlert {
+                    search,
+                    flowers: &matches,
+                };
+                // Left unchecked on failure, so the next run tries again
+                if let Err(e) = self.emails.send(&[address.to_string()], &alert).await {
+                    tracing::error!(
+                        tenant = %tenant,
+                        search = %search.id(),
+                        "Failed to queue saved search alert: {}",
+                        e
+                    );
+                    continue;
+                }
+                metrics::counter!("saved_search_alerts_total").increment(1);
+            }
+            self.searches.mark_checked(search.id(), until).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: FlowerRepository + ?Sized> Job for SavedSearchAlertsJob<R> {
+    fn name(&self) -> &'static str {
+        "saved_search_alerts"
+    }
+
+    async fn run(&self) -> DomainResult<()> {
+        let until = Utc::now() - SETTLE;
+
+        let mut by_tenant: BTreeMap<TenantId, Vec<SavedSearch>> = BTreeMap::new();
+        for search in self.searches.find_alerting().await? {
+            by_tenant
+                .entry(search.tenant_id().clone())
+                .or_default()
+                .push(search);
+        }
+
+        // One tenant failing does not hold back the alerts of the others
+        let mut failure = None;
+        for (tenant, searches) in &by_tenant {
+            if let Err(e) = self.alert_tenant(tenant, searches, until).await {
+                tracing::error!(tenant = %tenant, "Failed to check saved searches: {}", e);
+                failure = Some(e);
+            }
+        }
+
+        failure.map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::application::usecases::Tasks;
+    use crate::domain::flower::{Flower, FlowerName, Price, StockQuantity};
+    use crate::domain::saved_search::SearchCriteria;
+    use crate::infrastructure::storage::Storage;
+    use crate::test_support::FlowerBuilder;
+
+    /// `flower` as if created `minutes` ago
+    fn created_ago(flower: Flower, minutes: i64) -> Flower {
+        let at = Utc::now() - Duration::minutes(minutes);
+        Flower::from_persistence(
+            flower.id(),
+            flower.tenant_id().clone(),
+            FlowerName::new(flower.name()).unwrap(),
+            flower.color(),
+            None,
+            Price::new(flower.price()).unwrap(),
+            StockQuantity::new(flower.stock()).unwrap(),
+            flower.unit(),
+            None,
+            flower.attributes().clone(),
+            flower.metadata().clone(),
+            at,
+            at,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn alerts_once_about_each_new_match() {
+        let storage = Storage::in_memory();
+        let emails = Arc::new(Emails::new(Arc::new(Tasks::new(storage.tasks.clone(), 3))));
+        let job = SavedSearchAlertsJob::new(
+            storage.saved_searches.clone(),
+            storage.flowers.clone(),
+            emails,
+        );
+
+        let roses = SavedSearch::from_persistence(
+            Uuid::new_v4(),
+            TenantId::default(),
+            "admin".to_string(),
+            "Roses".to_string(),
+            SearchCriteria::new(Some("rose"), None, None, None).unwrap(),
+            Some("florist@example.com".to_string()),
+            Utc::now() - Duration::hours(1),
+            Utc::now() - Duration::hours(1),
+        );
+        storage.saved_searches.create(&roses).await.unwrap();
+
+        for flower in [
+            created_ago(FlowerBuilder::new().with_name("Old Rose").build(), 90),
+            created_ago(FlowerBuilder::new().with_name("Red Rose").build(), 30),
+            created_ago(FlowerBuilder::new().with_name("Tulip").build(), 30),
+            // Too recent to be alerted about yet
+            FlowerBuilder::new().with_name("Fresh Rose").build(),
+        ] {
+            storage.flowers.create(&flower).await.unwrap();
+        }
+
+        job.run().await.unwrap();
+        job.run().await.unwrap();
+
+        let lease = std::time::Duration::from_secs(60);
+        let queued = storage.tasks.claim(lease).await.unwrap().unwrap();
+        let message = queued.payload();
+        assert_eq!(message["to"], serde_json::json!(["florist@example.com"]));
+        assert_eq!(message["subject"], "New match for \"Roses\": Red Rose");
+        assert!(storage.tasks.claim(lease).await.unwrap().is_none());
+
+        let checked = storage.saved_searches.find_alerting().await.unwrap();
+        assert!(checked[0].checked_at() > roses.checked_at());
+    }
+}
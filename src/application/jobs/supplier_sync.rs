@@ -0,0 +1,53 @@
+//! Supplier Sync Job
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::jobs::Job;
+use crate::application::ports::FlowerRepository;
+use crate::application::usecases::SupplierSync;
+use crate::domain::errors::DomainResult;
+
+/// Synchronizes the catalog with every configured supplier's feed
+///
+/// A supplier whose feed cannot be read does not hold back the others; the
+/// run fails with the first such error once all have been attempted.
+pub struct SupplierSyncJob<R: FlowerRepository + ?Sized> {
+    sync: Arc<SupplierSync<R>>,
+}
+
+impl<R: FlowerRepository + ?Sized> SupplierSyncJob<R> {
+    pub fn new(sync: Arc<SupplierSync<R>>) -> Self {
+        Self { sync }
+    }
+}
+
+#[async_trait]
+impl<R: FlowerRepository + ?Sized> Job for SupplierSyncJob<R> {
+    fn name(&self) -> &'static str {
+        "supplier_sync"
+    }
+
+    async fn run(&self) -> DomainResult<()> {
+        let mut first_error = None;
+        for id in self.sync.supplier_ids() {
+            match self.sync.sync(id).await {
+                Ok(report) => tracing::info!(
+                    supplier = id,
+                    "Synchronized supplier feed: {} created, {} updated, {} unchanged, {} skipped",
+                    report.created,
+                    report.updated,
+                    report.unchanged,
+                    report.skipped
+                ),
+                Err(e) => {
+                    tracing::error!(supplier = id, "Failed to synchronize supplier feed: {}", e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+}
@@ -1,3 +1,12 @@
+pub mod authorization;
+pub mod context;
 pub mod dtos;
+pub mod emails;
+pub mod health;
+pub mod html;
+pub mod jobs;
+pub mod money;
 pub mod ports;
+pub mod tasks;
 pub mod usecases;
+pub mod webhooks;
@@ -0,0 +1,100 @@
+//! Money Formatting
+//!
+//! Every price is an amount in Indonesian rupiah. Responses carry amounts as
+//! plain numbers to compute with, and next to each one a [`MoneyDisplay`]
+//! saying how to show it, formatted here for the locale of the request so
+//! every client renders prices the same way.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::i18n::Locale;
+
+/// ISO 4217 code of the currency prices are in
+pub const CURRENCY: &str = "IDR";
+
+/// Symbol written before amounts
+pub const SYMBOL: &str = "Rp";
+
+/// Decimals shown; rupiah have no coins below one rupiah in circulation
+pub const DECIMALS: u32 = 0;
+
+/// How to show an amount of money
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "currency": "IDR",
+    "symbol": "Rp",
+    "decimals": 0,
+    "formatted": "Rp25,000"
+}))]
+pub struct MoneyDisplay {
+    /// ISO 4217 currency code
+    pub currency: String,
+    /// Currency symbol
+    pub symbol: String,
+    /// Decimal places shown
+    pub decimals: u32,
+    /// The amount with symbol, rounded and grouped for the request's locale
+    /// (`Rp25,000` in English, `Rp25.000` in Indonesian)
+    pub formatted: String,
+}
+
+impl MoneyDisplay {
+    /// `amount` as shown in the locale of the current request
+    pub fn of(amount: f64) -> Self {
+        Self::in_locale(amount, Locale::current())
+    }
+
+    pub fn in_locale(amount: f64, locale: Locale) -> Self {
+        Self {
+            currency: CURRENCY.to_string(),
+            symbol: SYMBOL.to_string(),
+            decimals: DECIMALS,
+            formatted: format(amount, locale),
+        }
+    }
+}
+
+/// `amount` with the currency symbol, rounded to whole rupiah and with the
+/// digit grouping of `locale`
+pub fn format(amount: f64, locale: Locale) -> String {
+    let group = match locale {
+        Locale::En => ',',
+        Locale::Id => '.',
+    };
+    let rupiah = amount.abs().round() as u64;
+    let digits = rupiah.to_string();
+
+    let mut formatted = String::new();
+    if amount < 0.0 && rupiah > 0 {
+        formatted.push('-');
+    }
+    formatted.push_str(SYMBOL);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            formatted.push(group);
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_digits_the_way_the_locale_does() {
+        assert_eq!(format(25_000.0, Locale::En), "Rp25,000");
+        assert_eq!(format(1_234_567.0, Locale::Id), "Rp1.234.567");
+        assert_eq!(format(950.0, Locale::Id), "Rp950");
+        assert_eq!(format(0.0, Locale::En), "Rp0");
+    }
+
+    #[test]
+    fn rounds_to_whole_rupiah() {
+        assert_eq!(format(27_499.5, Locale::En), "Rp27,500");
+        assert_eq!(format(-5_000.0, Locale::Id), "-Rp5.000");
+        assert_eq!(format(-0.2, Locale::En), "Rp0");
+    }
+}
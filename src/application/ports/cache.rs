@@ -0,0 +1,48 @@
+//! Port (interface) for Cache
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Key-value cache with per-entry expiry, shared by cached repositories
+#[async_trait]
+pub trait Cache: Send + Sync {
+    /// Fetch a value; `None` when missing or expired
+    async fn get(&self, key: &str) -> DomainResult<Option<String>>;
+
+    /// Store a value for `ttl`
+    async fn set(&self, key: &str, value: &str, ttl: Duration) -> DomainResult<()>;
+
+    /// Remove a value if present
+    async fn delete(&self, key: &str) -> DomainResult<()>;
+
+    /// Remove every entry whose key starts with `prefix`, counters included;
+    /// an empty prefix empties the cache. Returns how many were removed.
+    async fn delete_prefix(&self, prefix: &str) -> DomainResult<u64>;
+
+    /// Atomically increment a counter that never expires, starting from 0
+    async fn increment(&self, key: &str) -> DomainResult<i64>;
+}
+
+/// What to drop from the cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CachePurge {
+    /// A flower's cached copy, along with every cached listing of its tenant
+    Flower { tenant: TenantId, id: Uuid },
+    /// Every entry whose key starts with the prefix
+    Prefix(String),
+    /// Everything
+    All,
+}
+
+/// Drops cached data on request, for when it went stale in a way writes did
+/// not invalidate
+#[async_trait]
+pub trait CachePurger: Send + Sync {
+    /// Returns the number of entries removed
+    async fn purge(&self, target: &CachePurge) -> DomainResult<u64>;
+}
@@ -0,0 +1,23 @@
+//! Port (interface) for Logical Database Dumps
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::domain::errors::DomainResult;
+
+/// Rows of one table, each a JSON object keyed by column name
+#[derive(Debug, Clone)]
+pub struct TableDump {
+    pub table: String,
+    pub rows: Vec<Value>,
+}
+
+/// Table-by-table export and import of the whole database
+#[async_trait]
+pub trait DatabaseDump: Send + Sync {
+    /// Read every table from a single consistent snapshot
+    async fn export(&self) -> DomainResult<Vec<TableDump>>;
+
+    /// Replace the contents of the dumped tables in one transaction
+    async fn restore(&self, tables: &[TableDump]) -> DomainResult<()>;
+}
@@ -0,0 +1,21 @@
+//! Port (interface) for Delivery Zone Repository
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::delivery::DeliveryZone;
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Repository trait for the zones a tenant delivers to
+#[async_trait]
+pub trait DeliveryZoneRepository: Send + Sync {
+    /// Save a new delivery zone
+    async fn create(&self, zone: &DeliveryZone) -> DomainResult<DeliveryZone>;
+
+    /// Delivery zones of a tenant, by name
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<DeliveryZone>>;
+
+    /// Delete a delivery zone; returns whether it existed
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool>;
+}
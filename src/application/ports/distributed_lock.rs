@@ -0,0 +1,19 @@
+//! Distributed Lock Port
+
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainResult;
+
+/// Named mutual exclusion shared by all replicas
+#[async_trait]
+pub trait DistributedLock: Send + Sync {
+    /// Take the lock if it is free, without waiting; `None` when another
+    /// holder has it
+    async fn try_acquire(&self, name: &str) -> DomainResult<Option<Box<dyn LockGuard>>>;
+}
+
+/// A held lock, released by `release` or, failing that, when dropped
+#[async_trait]
+pub trait LockGuard: Send {
+    async fn release(self: Box<Self>) -> DomainResult<()>;
+}
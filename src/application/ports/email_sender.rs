@@ -0,0 +1,23 @@
+//! Port (interface) for Sending Email
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::DomainResult;
+
+/// Rendered email, ready to hand to a transport
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmailMessage {
+    pub to: Vec<String>,
+    pub subject: String,
+    pub text: String,
+    /// HTML alternative of `text`
+    pub html: Option<String>,
+}
+
+/// Delivers email; the sender address is part of the transport's setup
+#[async_trait]
+pub trait EmailSender: Send + Sync {
+    /// Deliver a message to all its recipients
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()>;
+}
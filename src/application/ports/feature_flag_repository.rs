@@ -0,0 +1,16 @@
+//! Port (interface) for Feature Flag Repository
+
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::feature_flag::FeatureFlag;
+
+/// Repository trait for runtime feature flag overrides
+#[async_trait]
+pub trait FeatureFlagRepository: Send + Sync {
+    /// Find all stored flags
+    async fn find_all(&self) -> DomainResult<Vec<FeatureFlag>>;
+
+    /// Create or replace a flag
+    async fn upsert(&self, flag: &FeatureFlag) -> DomainResult<FeatureFlag>;
+}
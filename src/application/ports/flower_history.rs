@@ -0,0 +1,30 @@
+//! Flower History Port
+//!
+//! Changes are appended through a [`Transaction`](super::Transaction)
+//! together with the update they describe; this port reads them back and
+//! erases them when a flower is purged.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::FlowerChange;
+use crate::domain::shared::{Pagination, TenantId};
+
+/// Read and erase access to the history of flowers
+#[async_trait]
+pub trait FlowerHistory: Send + Sync {
+    /// Changes to a flower, oldest first
+    async fn find(
+        &self,
+        tenant: &TenantId,
+        flower_id: Uuid,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<FlowerChange>>;
+
+    /// Count the changes to a flower
+    async fn count(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<i64>;
+
+    /// Erase the history of a flower; returns how many changes
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64>;
+}
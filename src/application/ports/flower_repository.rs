@@ -1,41 +1,105 @@
 //! Port (interface) for Flower Repository
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::domain::errors::DomainResult;
-use crate::domain::flower::Flower;
-use crate::domain::shared::Pagination;
+use crate::domain::flower::{Flower, FlowerAttributes, FlowerColor, FlowerMetadata};
+use crate::domain::shared::{Pagination, TenantId};
+
+/// What the flowers of a tenant can be filtered by
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlowerFacets {
+    /// Colors at least one flower has, in no particular order
+    pub colors: Vec<FlowerColor>,
+    /// Lowest price; `None` without flowers
+    pub min_price: Option<f64>,
+    /// Highest price; `None` without flowers
+    pub max_price: Option<f64>,
+}
 
 /// Repository trait for Flower entity
+///
+/// Every lookup is scoped to a tenant; flowers of other tenants behave as if
+/// they did not exist.
 #[async_trait]
 pub trait FlowerRepository: Send + Sync {
     /// Find a flower by its ID
-    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Flower>>;
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>>;
+
+    /// Find a flower by its SKU, as normalized by `Sku`
+    async fn find_by_sku(&self, tenant: &TenantId, sku: &str) -> DomainResult<Option<Flower>>;
 
     /// Find all flowers with pagination
-    async fn find_all(&self, pagination: &Pagination) -> DomainResult<Vec<Flower>>;
+    async fn find_all(
+        &self,
+        tenant: &TenantId,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>>;
 
     /// Count total flowers
-    async fn count(&self) -> DomainResult<i64>;
+    async fn count(&self, tenant: &TenantId) -> DomainResult<i64>;
 
-    /// Search flowers by name or color
+    /// Search flowers by name, color or attributes; flowers match
+    /// `attributes` when they have every attribute it sets
     async fn search(
         &self,
+        tenant: &TenantId,
         query: Option<&str>,
-        color: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
         pagination: &Pagination,
     ) -> DomainResult<Vec<Flower>>;
 
     /// Count flowers matching search criteria
-    async fn count_search(&self, query: Option<&str>, color: Option<&str>) -> DomainResult<i64>;
+    async fn count_search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<i64>;
 
-    /// Create a new flower
+    /// Cheap estimate of `count_search`, or of `count` without criteria,
+    /// from the database's statistics
+    ///
+    /// `None` when storage has no such estimate; counting there is as cheap
+    /// as it gets.
+    async fn estimate_count(
+        &self,
+        _tenant: &TenantId,
+        _query: Option<&str>,
+        _color: Option<FlowerColor>,
+        _attributes: Option<&FlowerAttributes>,
+        _metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<Option<i64>> {
+        Ok(None)
+    }
+
+    /// Distinct colors and the price range of the tenant's flowers
+    async fn facets(&self, tenant: &TenantId) -> DomainResult<FlowerFacets>;
+
+    /// Create a new flower for its tenant
     async fn create(&self, flower: &Flower) -> DomainResult<Flower>;
 
-    /// Update an existing flower
+    /// Update an existing flower of its tenant
     async fn update(&self, flower: &Flower) -> DomainResult<Flower>;
 
+    /// Flowers at or below a stock level across all tenants, for background jobs
+    async fn find_low_stock(&self, threshold: i32) -> DomainResult<Vec<Flower>>;
+
+    /// Flowers of a tenant created after `since`, oldest first, for
+    /// background jobs
+    async fn find_created_since(
+        &self,
+        tenant: &TenantId,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<Flower>>;
+
     /// Delete a flower by ID
-    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()>;
 }
@@ -0,0 +1,59 @@
+//! Port (interface) for Flower View Statistics
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Views of one flower on one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewCount {
+    pub tenant: TenantId,
+    pub flower_id: Uuid,
+    pub day: NaiveDate,
+    pub views: i64,
+}
+
+/// Latest view of one flower by one caller
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentView {
+    pub tenant: TenantId,
+    /// Caller that viewed the flower (`admin`, `tenant:<id>`)
+    pub viewer: String,
+    pub flower_id: Uuid,
+    pub viewed_at: DateTime<Utc>,
+}
+
+/// Daily view totals per flower, and the flowers each caller viewed last
+#[async_trait]
+pub trait FlowerViewStore: Send + Sync {
+    /// Add counts to the stored totals
+    async fn add(&self, counts: &[ViewCount]) -> DomainResult<()>;
+
+    /// Existing flowers of a tenant with the most views since `since`, with
+    /// their views, most viewed first
+    async fn most_viewed(
+        &self,
+        tenant: &TenantId,
+        since: NaiveDate,
+        limit: i64,
+    ) -> DomainResult<Vec<(Uuid, i64)>>;
+
+    /// Record recent views, keeping only the `keep` newest flowers of each
+    /// viewer; a flower viewed again moves to the front
+    async fn add_recent(&self, views: &[RecentView], keep: i64) -> DomainResult<()>;
+
+    /// Flowers of a tenant `viewer` viewed last, with when, newest first
+    async fn recently_viewed(
+        &self,
+        tenant: &TenantId,
+        viewer: &str,
+        limit: i64,
+    ) -> DomainResult<Vec<(Uuid, DateTime<Utc>)>>;
+
+    /// Forget every view of a flower, recent views included; returns how many
+    /// days of totals
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64>;
+}
@@ -0,0 +1,87 @@
+//! Health Indicator Port
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// Whether a component works
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Up,
+    /// Working, but not as it should, such as a connection pool with no
+    /// connection left to hand out
+    Degraded,
+    Down,
+}
+
+impl HealthStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HealthStatus::Up => "up",
+            HealthStatus::Degraded => "degraded",
+            HealthStatus::Down => "down",
+        }
+    }
+}
+
+/// Outcome of a health check
+///
+/// `details` are shown on the public readiness probe, so they hold figures
+/// such as pool usage only; `error` tells why a component is down and is
+/// only logged, as it may tell about the infrastructure behind it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Health {
+    pub status: HealthStatus,
+    pub details: Option<Value>,
+    pub error: Option<String>,
+}
+
+impl Health {
+    pub fn up() -> Self {
+        Self {
+            status: HealthStatus::Up,
+            details: None,
+            error: None,
+        }
+    }
+
+    pub fn degraded() -> Self {
+        Self {
+            status: HealthStatus::Degraded,
+            details: None,
+            error: None,
+        }
+    }
+
+    /// Down because of `error`
+    pub fn down(error: impl ToString) -> Self {
+        Self {
+            status: HealthStatus::Down,
+            details: None,
+            error: Some(error.to_string()),
+        }
+    }
+
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+}
+
+/// A component the service depends on, reporting whether it works
+///
+/// Adapters register one with the `HealthRegistry` when they are wired, and
+/// show up in `/health/ready` from then on.
+#[async_trait]
+pub trait HealthIndicator: Send + Sync {
+    /// Name the component is reported under
+    fn name(&self) -> &'static str;
+
+    /// Whether the service cannot take traffic while the component is down;
+    /// others only degrade it
+    fn critical(&self) -> bool {
+        true
+    }
+
+    /// Check the component; the registry bounds how long this may take
+    async fn check(&self) -> Health;
+}
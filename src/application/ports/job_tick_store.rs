@@ -0,0 +1,17 @@
+//! Port (interface) for Job Ticks
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::errors::DomainResult;
+
+/// Ticks exclusive jobs ran for, shared by all replicas, so a tick one
+/// replica handled is not run again by another
+#[async_trait]
+pub trait JobTickStore: Send + Sync {
+    /// Latest tick `job` ran for, on any replica
+    async fn last_tick(&self, job: &str) -> DomainResult<Option<DateTime<Utc>>>;
+
+    /// Remember that `job` ran for `tick`; earlier ticks are ignored
+    async fn record(&self, job: &str, tick: DateTime<Utc>) -> DomainResult<()>;
+}
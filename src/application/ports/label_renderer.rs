@@ -0,0 +1,12 @@
+//! Port (interface) for Label Rendering
+
+use crate::domain::errors::DomainResult;
+
+/// Renders machine-readable labels as PNG images
+pub trait LabelRenderer: Send + Sync {
+    /// QR code encoding `data`
+    fn qr_code(&self, data: &str) -> DomainResult<Vec<u8>>;
+
+    /// Barcode encoding `data`, which is printable ASCII
+    fn barcode(&self, data: &str) -> DomainResult<Vec<u8>>;
+}
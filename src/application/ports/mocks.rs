@@ -0,0 +1,430 @@
+//! Test Doubles for the Ports
+//!
+//! Unlike the in-memory adapters, mocks answer from canned data without
+//! applying any query logic and record every call, so use case tests can
+//! check exactly what reached storage and simulate storage failing.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::{FlowerFacets, FlowerRepository, Transaction, UnitOfWork};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::{
+    Flower, FlowerAttributes, FlowerChange, FlowerColor, FlowerError, FlowerMetadata,
+};
+use crate::domain::inventory::StockMovement;
+use crate::domain::order::{Order, OrderError, OrderEvent};
+use crate::domain::shared::{Entity, Pagination, TenantId};
+use crate::domain::task::Task;
+
+/// A call that reached a `MockFlowerRepository` or one of its transactions
+#[derive(Debug, Clone, PartialEq)]
+pub enum FlowerCall {
+    FindById(Uuid),
+    FindBySku(String),
+    FindAll {
+        page: i64,
+        per_page: i64,
+    },
+    Count,
+    Search {
+        query: Option<String>,
+        color: Option<FlowerColor>,
+        page: i64,
+        per_page: i64,
+    },
+    CountSearch {
+        query: Option<String>,
+        color: Option<FlowerColor>,
+    },
+    EstimateCount {
+        query: Option<String>,
+        color: Option<FlowerColor>,
+    },
+    Facets,
+    Create(Uuid),
+    Update(Uuid),
+    FindLowStock(i32),
+    FindCreatedSince(DateTime<Utc>),
+    Delete(Uuid),
+    Begin,
+    LockFlower(Uuid),
+    LockFlowers {
+        color: Option<FlowerColor>,
+        ids: Option<Vec<Uuid>>,
+    },
+    InsertInTransaction(Uuid),
+    UpdateInTransaction(Uuid),
+    RecordStockMovement {
+        flower: Uuid,
+        delta: i32,
+    },
+    /// Names of the fields that changed
+    RecordFlowerChanges(Vec<String>),
+    EnqueueTask,
+    Commit,
+}
+
+/// `FlowerRepository` answering from a fixed set of flowers
+///
+/// Lookups by ID or SKU match within the tenant; listings and searches
+/// return every flower of the tenant, leaving filtering and paging to the
+/// real adapters. Counts report `with_total` if set. It is also the
+/// `UnitOfWork` for use cases under test, staging transactional updates
+/// until commit.
+#[derive(Default)]
+pub struct MockFlowerRepository {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    flowers: Vec<Flower>,
+    total: Option<i64>,
+    estimate: Option<i64>,
+    failing: bool,
+    calls: Vec<FlowerCall>,
+}
+
+impl MockFlowerRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_flowers(self, flowers: impl IntoIterator<Item = Flower>) -> Self {
+        self.state.lock().unwrap().flowers.extend(flowers);
+        self
+    }
+
+    /// Report this many flowers from `count` and `count_search`
+    pub fn with_total(self, total: i64) -> Self {
+        self.state.lock().unwrap().total = Some(total);
+        self
+    }
+
+    /// Estimate this many flowers from `estimate_count`, which otherwise
+    /// has no estimate
+    pub fn with_estimate(self, estimate: i64) -> Self {
+        self.state.lock().unwrap().estimate = Some(estimate);
+        self
+    }
+
+    /// Fail every call as if storage were down; calls are still recorded
+    pub fn failing(self) -> Self {
+        self.state.lock().unwrap().failing = true;
+        self
+    }
+
+    /// Calls received so far, in order
+    pub fn calls(&self) -> Vec<FlowerCall> {
+        self.state.lock().unwrap().calls.clone()
+    }
+
+    /// Flowers as they are now, updates included
+    pub fn flowers(&self) -> Vec<Flower> {
+        self.state.lock().unwrap().flowers.clone()
+    }
+
+    fn record<T>(
+        &self,
+        call: FlowerCall,
+        answer: impl FnOnce(&mut MockState) -> DomainResult<T>,
+    ) -> DomainResult<T> {
+        record(&self.state, call, answer)
+    }
+}
+
+fn record<T>(
+    state: &Mutex<MockState>,
+    call: FlowerCall,
+    answer: impl FnOnce(&mut MockState) -> DomainResult<T>,
+) -> DomainResult<T> {
+    let mut state = state.lock().unwrap();
+    state.calls.push(call);
+    if state.failing {
+        return Err(AppError::internal("Mock storage is failing".to_string()));
+    }
+    answer(&mut state)
+}
+
+impl MockState {
+    fn of_tenant(&self, tenant: &TenantId) -> Vec<Flower> {
+        self.flowers
+            .iter()
+            .filter(|flower| flower.tenant_id() == tenant)
+            .cloned()
+            .collect()
+    }
+
+    fn find(&self, tenant: &TenantId, id: Uuid) -> Option<Flower> {
+        self.of_tenant(tenant)
+            .into_iter()
+            .find(|flower| flower.id() == id)
+    }
+
+    fn total(&self, tenant: &TenantId) -> i64 {
+        self.total
+            .unwrap_or_else(|| self.of_tenant(tenant).len() as i64)
+    }
+
+    fn replace(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        let stored = self
+            .flowers
+            .iter_mut()
+            .find(|stored| stored.id() == flower.id() && stored.tenant_id() == flower.tenant_id())
+            .ok_or_else(|| FlowerError::not_found(flower.id()))?;
+        *stored = flower.clone();
+        Ok(flower.clone())
+    }
+}
+
+#[async_trait]
+impl FlowerRepository for MockFlowerRepository {
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        self.record(FlowerCall::FindById(id), |state| Ok(state.find(tenant, id)))
+    }
+
+    async fn find_by_sku(&self, tenant: &TenantId, sku: &str) -> DomainResult<Option<Flower>> {
+        self.record(FlowerCall::FindBySku(sku.to_string()), |state| {
+            Ok(state
+                .of_tenant(tenant)
+                .into_iter()
+                .find(|flower| flower.sku() == Some(sku)))
+        })
+    }
+
+    async fn find_all(
+        &self,
+        tenant: &TenantId,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>> {
+        let call = FlowerCall::FindAll {
+            page: pagination.page,
+            per_page: pagination.per_page,
+        };
+        self.record(call, |state| Ok(state.of_tenant(tenant)))
+    }
+
+    async fn count(&self, tenant: &TenantId) -> DomainResult<i64> {
+        self.record(FlowerCall::Count, |state| Ok(state.total(tenant)))
+    }
+
+    async fn search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        _attributes: Option<&FlowerAttributes>,
+        _metadata: Option<&FlowerMetadata>,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>> {
+        let call = FlowerCall::Search {
+            query: query.map(str::to_string),
+            color,
+            page: pagination.page,
+            per_page: pagination.per_page,
+        };
+        self.record(call, |state| Ok(state.of_tenant(tenant)))
+    }
+
+    async fn count_search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        _attributes: Option<&FlowerAttributes>,
+        _metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<i64> {
+        let call = FlowerCall::CountSearch {
+            query: query.map(str::to_string),
+            color,
+        };
+        self.record(call, |state| Ok(state.total(tenant)))
+    }
+
+    async fn estimate_count(
+        &self,
+        _tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        _attributes: Option<&FlowerAttributes>,
+        _metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<Option<i64>> {
+        let call = FlowerCall::EstimateCount {
+            query: query.map(str::to_string),
+            color,
+        };
+        self.record(call, |state| Ok(state.estimate))
+    }
+
+    async fn facets(&self, tenant: &TenantId) -> DomainResult<FlowerFacets> {
+        self.record(FlowerCall::Facets, |state| {
+            let flowers = state.of_tenant(tenant);
+            let prices = flowers.iter().map(|flower| flower.price());
+            Ok(FlowerFacets {
+                colors: flowers.iter().map(|flower| flower.color()).collect(),
+                min_price: prices.clone().reduce(f64::min),
+                max_price: prices.reduce(f64::max),
+            })
+        })
+    }
+
+    async fn create(&self, flower: &Flower) -> DomainResult<Flower> {
+        self.record(FlowerCall::Create(flower.id()), |state| {
+            state.flowers.push(flower.clone());
+            Ok(flower.clone())
+        })
+    }
+
+    async fn update(&self, flower: &Flower) -> DomainResult<Flower> {
+        self.record(FlowerCall::Update(flower.id()), |state| {
+            state.replace(flower)
+        })
+    }
+
+    async fn find_low_stock(&self, threshold: i32) -> DomainResult<Vec<Flower>> {
+        self.record(FlowerCall::FindLowStock(threshold), |state| {
+            Ok(state
+                .flowers
+                .iter()
+                .filter(|flower| flower.stock() <= threshold)
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn find_created_since(
+        &self,
+        tenant: &TenantId,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<Flower>> {
+        self.record(FlowerCall::FindCreatedSince(since), |state| {
+            Ok(state
+                .flowers
+                .iter()
+                .filter(|flower| flower.tenant_id() == tenant && flower.created_at() > since)
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
+        self.record(FlowerCall::Delete(id), |state| {
+            state
+                .flowers
+                .retain(|flower| !(flower.id() == id && flower.tenant_id() == tenant));
+            Ok(())
+        })
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for MockFlowerRepository {
+    async fn begin(&self) -> DomainResult<Box<dyn Transaction>> {
+        self.record(FlowerCall::Begin, |_| Ok(()))?;
+        Ok(Box::new(MockTransaction {
+            state: self.state.clone(),
+            inserted: Vec::new(),
+            staged: Vec::new(),
+        }))
+    }
+}
+
+/// Transaction of a `MockFlowerRepository`; movements and tasks are only recorded
+struct MockTransaction {
+    state: Arc<Mutex<MockState>>,
+    inserted: Vec<Flower>,
+    staged: Vec<Flower>,
+}
+
+#[async_trait]
+impl Transaction for MockTransaction {
+    async fn lock_flower(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        record(&self.state, FlowerCall::LockFlower(id), |state| {
+            Ok(state.find(tenant, id))
+        })
+    }
+
+    async fn lock_flowers(
+        &mut self,
+        tenant: &TenantId,
+        color: Option<FlowerColor>,
+        ids: Option<&[Uuid]>,
+    ) -> DomainResult<Vec<Flower>> {
+        let call = FlowerCall::LockFlowers {
+            color,
+            ids: ids.map(<[Uuid]>::to_vec),
+        };
+        record(&self.state, call, |state| Ok(state.of_tenant(tenant)))
+    }
+
+    async fn insert_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        record(
+            &self.state,
+            FlowerCall::InsertInTransaction(flower.id()),
+            |_| Ok(()),
+        )?;
+        self.inserted.push(flower.clone());
+        Ok(flower.clone())
+    }
+
+    async fn update_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        record(
+            &self.state,
+            FlowerCall::UpdateInTransaction(flower.id()),
+            |_| Ok(()),
+        )?;
+        self.staged.push(flower.clone());
+        Ok(flower.clone())
+    }
+
+    async fn record_stock_movement(&mut self, movement: &StockMovement) -> DomainResult<()> {
+        let call = FlowerCall::RecordStockMovement {
+            flower: movement.flower_id(),
+            delta: movement.delta(),
+        };
+        record(&self.state, call, |_| Ok(()))
+    }
+
+    async fn record_flower_changes(&mut self, changes: &[FlowerChange]) -> DomainResult<()> {
+        let fields = changes.iter().map(|change| change.field().to_string());
+        record(
+            &self.state,
+            FlowerCall::RecordFlowerChanges(fields.collect()),
+            |_| Ok(()),
+        )
+    }
+
+    async fn insert_order(&mut self, _order: &Order) -> DomainResult<()> {
+        Ok(())
+    }
+
+    async fn lock_order(&mut self, _tenant: &TenantId, _id: Uuid) -> DomainResult<Option<Order>> {
+        Ok(None)
+    }
+
+    async fn update_order(&mut self, order: &Order) -> DomainResult<()> {
+        Err(OrderError::not_found(order.id()))
+    }
+
+    async fn record_order_events(&mut self, _events: &[OrderEvent]) -> DomainResult<()> {
+        Ok(())
+    }
+
+    async fn enqueue_task(&mut self, _task: &Task) -> DomainResult<()> {
+        record(&self.state, FlowerCall::EnqueueTask, |_| Ok(()))
+    }
+
+    async fn commit(self: Box<Self>) -> DomainResult<()> {
+        record(&self.state, FlowerCall::Commit, |state| {
+            state.flowers.extend(self.inserted.iter().cloned());
+            for flower in &self.staged {
+                state.replace(flower)?;
+            }
+            Ok(())
+        })
+    }
+}
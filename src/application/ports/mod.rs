@@ -1,3 +1,62 @@
+pub mod cache;
+pub mod database_dump;
+pub mod delivery_zone_repository;
+pub mod distributed_lock;
+pub mod email_sender;
+pub mod feature_flag_repository;
+pub mod flower_history;
 pub mod flower_repository;
+pub mod flower_view_store;
+pub mod health_indicator;
+pub mod job_tick_store;
+pub mod label_renderer;
+#[cfg(test)]
+pub mod mocks;
+pub mod nonce_store;
+pub mod object_store;
+pub mod order_repository;
+pub mod payment_gateway;
+pub mod pricing_rule_repository;
+pub mod saved_search_repository;
+pub mod secrets_provider;
+pub mod shipping_rate_provider;
+pub mod stock_ledger;
+pub mod store_repository;
+pub mod supplier_feed;
+pub mod task_queue;
+pub mod unit_of_work;
+pub mod usage_store;
+pub mod webhook_endpoint_repository;
+pub mod webhook_sender;
 
-pub use flower_repository::FlowerRepository;
+pub use cache::{Cache, CachePurge, CachePurger};
+pub use database_dump::{DatabaseDump, TableDump};
+pub use delivery_zone_repository::DeliveryZoneRepository;
+pub use distributed_lock::{DistributedLock, LockGuard};
+pub use email_sender::{EmailMessage, EmailSender};
+pub use feature_flag_repository::FeatureFlagRepository;
+pub use flower_history::FlowerHistory;
+pub use flower_repository::{FlowerFacets, FlowerRepository};
+pub use flower_view_store::{FlowerViewStore, RecentView, ViewCount};
+pub use health_indicator::{Health, HealthIndicator, HealthStatus};
+pub use job_tick_store::JobTickStore;
+pub use label_renderer::LabelRenderer;
+pub use nonce_store::NonceStore;
+pub use object_store::ObjectStore;
+pub use order_repository::OrderRepository;
+pub use payment_gateway::{PaymentGateway, Refund};
+pub use pricing_rule_repository::PricingRuleRepository;
+pub use saved_search_repository::SavedSearchRepository;
+pub use secrets_provider::SecretsProvider;
+pub use shipping_rate_provider::{Shipment, ShippingRateProvider};
+pub use stock_ledger::{LedgerQuery, StockLedger};
+pub use store_repository::StoreRepository;
+pub use supplier_feed::{FeedEntry, SupplierFeed, SupplierProduct};
+pub use task_queue::TaskQueue;
+pub use unit_of_work::{Transaction, UnitOfWork};
+pub use usage_store::{ApiQuota, UsageRecord, UsageStore};
+pub use webhook_endpoint_repository::{WebhookEndpoint, WebhookEndpointRepository};
+pub use webhook_sender::{
+    OutboundWebhook, SIGNATURE_HEADER, WEBHOOK_ID_HEADER, WEBHOOK_TIMESTAMP_HEADER, WebhookReceipt,
+    WebhookSender,
+};
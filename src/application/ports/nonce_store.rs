@@ -0,0 +1,23 @@
+//! Port (interface) for Webhook Nonces
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::errors::DomainResult;
+
+/// Nonces of signed inbound requests, remembered so each request is
+/// accepted once
+#[async_trait]
+pub trait NonceStore: Send + Sync {
+    /// Remember `nonce` of `scope` until `expires_at`; returns false when it
+    /// is remembered already, that is when the request is a replay
+    async fn claim(
+        &self,
+        scope: &str,
+        nonce: &str,
+        expires_at: DateTime<Utc>,
+    ) -> DomainResult<bool>;
+
+    /// Forget the nonces that expired by `now`; returns how many
+    async fn purge_expired(&self, now: DateTime<Utc>) -> DomainResult<u64>;
+}
@@ -0,0 +1,16 @@
+//! Port (interface) for Object Storage
+
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainResult;
+
+/// Blob storage addressed by `/`-separated keys, used for backups and
+/// catalog exports
+#[async_trait]
+pub trait ObjectStore: Send + Sync {
+    /// Store an object, replacing any existing one under `key`
+    async fn put(&self, key: &str, body: Vec<u8>) -> DomainResult<()>;
+
+    /// Fetch an object; `None` when missing
+    async fn get(&self, key: &str) -> DomainResult<Option<Vec<u8>>>;
+}
@@ -0,0 +1,22 @@
+//! Port (interface) for Order Repository
+//!
+//! Orders are written through a [`Transaction`](super::Transaction), together
+//! with the stock they reserve and the events of their transitions; this port
+//! reads them back.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::order::{Order, OrderEvent};
+use crate::domain::shared::TenantId;
+
+/// Read access to orders and their status history
+#[async_trait]
+pub trait OrderRepository: Send + Sync {
+    /// Find an order of a tenant by its ID
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Order>>;
+
+    /// Status changes of an order, oldest first
+    async fn events(&self, tenant: &TenantId, order_id: Uuid) -> DomainResult<Vec<OrderEvent>>;
+}
@@ -0,0 +1,54 @@
+//! Port (interface) for Payment Gateways
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Money to give back to the customer of an order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Refund {
+    pub tenant: TenantId,
+    pub order_id: Uuid,
+    /// In IDR
+    pub amount: f64,
+    /// Refunds asked for again under the same key, such as after a failed
+    /// commit, must not move money twice
+    pub idempotency_key: String,
+}
+
+impl Refund {
+    /// Refund of a paid order that was cancelled, which happens at most once
+    pub fn of_order(tenant: TenantId, order_id: Uuid, amount: f64) -> Self {
+        Self {
+            tenant,
+            order_id,
+            amount,
+            idempotency_key: order_id.to_string(),
+        }
+    }
+
+    /// Refund of a payment, identified by the provider's `reference`, that
+    /// arrived after its order was cancelled
+    pub fn of_payment(tenant: TenantId, order_id: Uuid, amount: f64, reference: &str) -> Self {
+        Self {
+            idempotency_key: format!("{}:{}", order_id, reference),
+            ..Self::of_order(tenant, order_id, amount)
+        }
+    }
+}
+
+/// Moves money through a payment processor
+#[async_trait]
+pub trait PaymentGateway: Send + Sync {
+    /// Name the gateway is logged under
+    fn name(&self) -> &str;
+
+    /// Return `refund.amount` to the customer, giving the gateway's reference
+    /// for the refund
+    ///
+    /// A refund whose `idempotency_key` was refunded before is not made
+    /// again; the reference of the earlier one is given instead.
+    async fn refund(&self, refund: &Refund) -> DomainResult<String>;
+}
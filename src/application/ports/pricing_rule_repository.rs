@@ -0,0 +1,26 @@
+//! Port (interface) for Pricing Rule Repository
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::pricing::PricingRule;
+use crate::domain::shared::TenantId;
+
+/// Repository trait for the rules adjusting a tenant's flower prices
+#[async_trait]
+pub trait PricingRuleRepository: Send + Sync {
+    /// Save a new pricing rule
+    async fn create(&self, rule: &PricingRule) -> DomainResult<PricingRule>;
+
+    /// Pricing rules of a tenant, by name
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<PricingRule>>;
+
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<PricingRule>>;
+
+    /// Save changes to a pricing rule; returns whether it existed
+    async fn update(&self, rule: &PricingRule) -> DomainResult<bool>;
+
+    /// Delete a pricing rule; returns whether it existed
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool>;
+}
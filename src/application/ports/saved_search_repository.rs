@@ -0,0 +1,32 @@
+//! Port (interface) for Saved Search Repository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::saved_search::SavedSearch;
+use crate::domain::shared::TenantId;
+
+/// Repository trait for saved searches
+///
+/// Searches are only ever visible to the owner that saved them, within a
+/// tenant; the alerts job alone reads across owners and tenants.
+#[async_trait]
+pub trait SavedSearchRepository: Send + Sync {
+    /// Save a new search
+    async fn create(&self, search: &SavedSearch) -> DomainResult<SavedSearch>;
+
+    /// Searches of one owner, oldest first
+    async fn find_by_owner(&self, tenant: &TenantId, owner: &str)
+    -> DomainResult<Vec<SavedSearch>>;
+
+    /// Searches with alerts on across all tenants, for background jobs
+    async fn find_alerting(&self) -> DomainResult<Vec<SavedSearch>>;
+
+    /// Remember that flowers created up to `at` have been alerted about
+    async fn mark_checked(&self, id: Uuid, at: DateTime<Utc>) -> DomainResult<()>;
+
+    /// Delete a search of an owner; returns whether it existed
+    async fn delete(&self, tenant: &TenantId, owner: &str, id: Uuid) -> DomainResult<bool>;
+}
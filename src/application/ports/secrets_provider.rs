@@ -0,0 +1,12 @@
+//! Port (interface) for Secrets Provider
+
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainResult;
+
+/// Source of credentials that should not live in plaintext configuration
+#[async_trait]
+pub trait SecretsProvider: Send + Sync {
+    /// Fetch a secret by name, e.g. `DATABASE_URL`; `None` if it is not stored
+    async fn get(&self, name: &str) -> DomainResult<Option<String>>;
+}
@@ -0,0 +1,27 @@
+//! Port (interface) for Shipping Rate Providers
+
+use async_trait::async_trait;
+
+use crate::domain::delivery::ShippingRate;
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::domain::store::GeoPoint;
+
+/// Parcel to be quoted
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shipment {
+    pub tenant: TenantId,
+    pub destination: GeoPoint,
+    pub weight_grams: u32,
+}
+
+/// Quotes what delivering a parcel costs, such as a rate table or a
+/// courier's API
+#[async_trait]
+pub trait ShippingRateProvider: Send + Sync {
+    /// Name the provider is logged under
+    fn name(&self) -> &str;
+
+    /// Rates of the services able to deliver `shipment`; empty when none can
+    async fn quote(&self, shipment: &Shipment) -> DomainResult<Vec<ShippingRate>>;
+}
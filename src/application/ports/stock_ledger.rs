@@ -0,0 +1,45 @@
+//! Inventory Ledger Port
+//!
+//! Movements are appended through a [`Transaction`](super::Transaction)
+//! together with the stock change they explain; this port reads them back
+//! for audits and erases them when a flower is purged.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::inventory::StockMovement;
+use crate::domain::shared::{Pagination, TenantId};
+
+/// Movements to look at; every given criterion must match
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LedgerQuery {
+    pub tenant: Option<TenantId>,
+    pub flower_id: Option<Uuid>,
+}
+
+impl LedgerQuery {
+    pub fn matches(&self, movement: &StockMovement) -> bool {
+        self.tenant
+            .as_ref()
+            .is_none_or(|tenant| movement.tenant_id() == tenant)
+            && self.flower_id.is_none_or(|id| movement.flower_id() == id)
+    }
+}
+
+/// Read and erase access to the inventory ledger
+#[async_trait]
+pub trait StockLedger: Send + Sync {
+    /// Movements matching `query`, most recent first
+    async fn find(
+        &self,
+        query: &LedgerQuery,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<StockMovement>>;
+
+    /// Count movements matching `query`
+    async fn count(&self, query: &LedgerQuery) -> DomainResult<i64>;
+
+    /// Erase the movements of a flower; returns how many
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64>;
+}
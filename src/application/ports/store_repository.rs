@@ -0,0 +1,36 @@
+//! Port (interface) for Store Repository
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::domain::store::Store;
+
+/// Repository trait for stores and the flower stock each one holds
+#[async_trait]
+pub trait StoreRepository: Send + Sync {
+    /// Save a new store
+    async fn create(&self, store: &Store) -> DomainResult<Store>;
+
+    /// Stores of a tenant, by name
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<Store>>;
+
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Store>>;
+
+    /// Delete a store and the stock it holds; returns whether it existed
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool>;
+
+    /// Set how many of a flower a store holds; zero removes the flower
+    async fn set_stock(
+        &self,
+        tenant: &TenantId,
+        store_id: Uuid,
+        flower_id: Uuid,
+        quantity: i32,
+    ) -> DomainResult<()>;
+
+    /// Stores holding a flower, with how many each holds
+    async fn stock_of(&self, tenant: &TenantId, flower_id: Uuid)
+    -> DomainResult<Vec<(Store, i32)>>;
+}
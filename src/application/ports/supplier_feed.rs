@@ -0,0 +1,29 @@
+//! Port (interface) for Supplier Feeds
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::domain::errors::DomainResult;
+
+/// Product as listed in a supplier's feed; fields other than the SKU may be
+/// left out, keeping what the flower already has
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SupplierProduct {
+    pub sku: String,
+    pub name: Option<String>,
+    pub color: Option<String>,
+    pub description: Option<String>,
+    pub price: Option<f64>,
+    pub stock: Option<i32>,
+}
+
+/// One entry of a feed: the product, or why it could not be read
+pub type FeedEntry = Result<SupplierProduct, String>;
+
+/// Reads supplier product feeds
+#[async_trait]
+pub trait SupplierFeed: Send + Sync {
+    /// Fetch and parse the feed at `url`; fails only when the feed as a
+    /// whole cannot be read
+    async fn fetch(&self, url: &str) -> DomainResult<Vec<FeedEntry>>;
+}
@@ -0,0 +1,41 @@
+//! Task Queue Port
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::Pagination;
+use crate::domain::task::{FailedTask, Task};
+
+/// Durable queue of deferred tasks shared by all workers
+#[async_trait]
+pub trait TaskQueue: Send + Sync {
+    /// Persist a task for a worker to pick up once it is due
+    async fn enqueue(&self, task: &Task) -> DomainResult<()>;
+
+    /// Claim the oldest due task, counting an attempt and hiding it from
+    /// other workers for `lease`
+    async fn claim(&self, lease: Duration) -> DomainResult<Option<Task>>;
+
+    /// Remove a task that completed successfully
+    async fn complete(&self, id: Uuid) -> DomainResult<()>;
+
+    /// Release a failed task to be attempted again at `run_at`
+    async fn retry(&self, id: Uuid, error: &str, run_at: DateTime<Utc>) -> DomainResult<()>;
+
+    /// Move a task that will not be attempted again to the dead-letter table
+    async fn dead_letter(&self, id: Uuid, error: &str) -> DomainResult<()>;
+
+    /// Dead-lettered tasks, most recently failed first
+    async fn find_failed(&self, pagination: &Pagination) -> DomainResult<Vec<FailedTask>>;
+
+    /// Count dead-lettered tasks
+    async fn count_failed(&self) -> DomainResult<i64>;
+
+    /// Delete dead-lettered tasks that failed before `failed_before`, first
+    /// copying them to the archive when `archive` is set; returns how many
+    async fn purge_failed(&self, failed_before: DateTime<Utc>, archive: bool) -> DomainResult<u64>;
+}
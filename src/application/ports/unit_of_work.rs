@@ -0,0 +1,67 @@
+//! Unit of Work Port
+//!
+//! Use cases that change several things at once (a flower and the tasks it
+//! triggers, an order and the stock it reserves) do so through a
+//! [`Transaction`], so either every change is applied or none is.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerChange, FlowerColor};
+use crate::domain::inventory::StockMovement;
+use crate::domain::order::{Order, OrderEvent};
+use crate::domain::shared::TenantId;
+use crate::domain::task::Task;
+
+/// Starts transactions on the application's storage
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    async fn begin(&self) -> DomainResult<Box<dyn Transaction>>;
+}
+
+/// Changes applied together on `commit`; dropping without committing
+/// discards them
+#[async_trait]
+pub trait Transaction: Send {
+    /// Load a flower and keep others from changing it until the transaction ends
+    async fn lock_flower(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>>;
+
+    /// Load the flowers of a tenant matching every given criterion and lock
+    /// them like `lock_flower`
+    async fn lock_flowers(
+        &mut self,
+        tenant: &TenantId,
+        color: Option<FlowerColor>,
+        ids: Option<&[Uuid]>,
+    ) -> DomainResult<Vec<Flower>>;
+
+    /// Save a new flower
+    async fn insert_flower(&mut self, flower: &Flower) -> DomainResult<Flower>;
+
+    /// Update an existing flower of its tenant
+    async fn update_flower(&mut self, flower: &Flower) -> DomainResult<Flower>;
+
+    /// Append changes to the history of their flowers
+    async fn record_flower_changes(&mut self, changes: &[FlowerChange]) -> DomainResult<()>;
+
+    /// Append a movement to the inventory ledger
+    async fn record_stock_movement(&mut self, movement: &StockMovement) -> DomainResult<()>;
+
+    /// Save a newly placed order
+    async fn insert_order(&mut self, order: &Order) -> DomainResult<()>;
+
+    /// Load an order and keep others from changing it until the transaction ends
+    async fn lock_order(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Order>>;
+
+    /// Update the status of an existing order of its tenant
+    async fn update_order(&mut self, order: &Order) -> DomainResult<()>;
+
+    /// Append events to the status history of their orders
+    async fn record_order_events(&mut self, events: &[OrderEvent]) -> DomainResult<()>;
+
+    /// Enqueue a task that only becomes visible to workers once committed
+    async fn enqueue_task(&mut self, task: &Task) -> DomainResult<()>;
+
+    async fn commit(self: Box<Self>) -> DomainResult<()>;
+}
@@ -0,0 +1,78 @@
+//! Port (interface) for API Usage Metering
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// API usage of one tenant through one API key during one hour
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageRecord {
+    pub tenant: TenantId,
+    /// Fingerprint of the API key; `None` for requests without one
+    pub key_id: Option<String>,
+    /// Last characters of the API key, for display
+    pub api_key: Option<String>,
+    /// Start of the hour
+    pub hour: DateTime<Utc>,
+    pub requests: i64,
+    /// Requests answered with a 4xx or 5xx status
+    pub errors: i64,
+    pub request_bytes: i64,
+    pub response_bytes: i64,
+}
+
+impl UsageRecord {
+    /// Add the counts of `other`, which covers the same tenant, key and hour
+    pub fn add(&mut self, other: &UsageRecord) {
+        self.requests += other.requests;
+        self.errors += other.errors;
+        self.request_bytes += other.request_bytes;
+        self.response_bytes += other.response_bytes;
+    }
+}
+
+/// Monthly request quota an admin set for one API key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiQuota {
+    pub tenant: TenantId,
+    /// Fingerprint of the API key
+    pub key_id: String,
+    /// Last characters of the API key, for display
+    pub api_key: String,
+    pub monthly_requests: i64,
+}
+
+/// Hourly API usage totals per tenant and API key, and the quotas of keys
+#[async_trait]
+pub trait UsageStore: Send + Sync {
+    /// Add records to the stored totals
+    async fn add(&self, records: &[UsageRecord]) -> DomainResult<()>;
+
+    /// Totals of the hours starting in `from..to`, of one tenant or all,
+    /// oldest hour first
+    async fn list(
+        &self,
+        tenant: Option<&TenantId>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<UsageRecord>>;
+
+    /// Requests made with each API key in the hours from `since` on, by
+    /// tenant and key fingerprint
+    async fn requests_by_key_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<(TenantId, String, i64)>>;
+
+    /// Quotas admins set for single API keys
+    async fn quotas(&self) -> DomainResult<Vec<ApiQuota>>;
+
+    /// Set the quota of an API key, replacing any it had
+    async fn set_quota(&self, quota: &ApiQuota) -> DomainResult<()>;
+
+    /// Drop the quota of the API key with fingerprint `key_id`; returns
+    /// whether it had one
+    async fn remove_quota(&self, tenant: &TenantId, key_id: &str) -> DomainResult<bool>;
+}
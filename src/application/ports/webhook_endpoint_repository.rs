@@ -0,0 +1,66 @@
+//! Port (interface) for Webhook Endpoint Repository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Receiver a tenant has its events delivered to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookEndpoint {
+    pub id: Uuid,
+    pub tenant: TenantId,
+    pub url: String,
+    /// Secret deliveries are signed with
+    pub secret: String,
+    /// Secret being rotated out, which signs deliveries too until
+    /// `previous_secret_expires_at`
+    pub previous_secret: Option<String>,
+    pub previous_secret_expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookEndpoint {
+    /// Secrets a delivery made at `now` is signed with, current first
+    pub fn signing_secrets(&self, now: DateTime<Utc>) -> Vec<&str> {
+        let previous = self
+            .previous_secret
+            .as_deref()
+            .filter(|_| self.previous_secret_expires_at.is_some_and(|at| at > now));
+        std::iter::once(self.secret.as_str())
+            .chain(previous)
+            .collect()
+    }
+}
+
+/// Repository trait for the webhook endpoints of tenants
+#[async_trait]
+pub trait WebhookEndpointRepository: Send + Sync {
+    /// Save a new endpoint
+    async fn create(&self, endpoint: &WebhookEndpoint) -> DomainResult<()>;
+
+    /// Endpoints of a tenant, oldest first
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<WebhookEndpoint>>;
+
+    async fn find_by_id(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<Option<WebhookEndpoint>>;
+
+    /// Sign with `secret` from now on, keeping the current secret as the
+    /// previous one until `previous_expires_at`; `None` when the endpoint
+    /// does not exist
+    async fn rotate_secret(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+        secret: &str,
+        previous_expires_at: DateTime<Utc>,
+    ) -> DomainResult<Option<WebhookEndpoint>>;
+
+    /// Delete an endpoint; returns whether it existed
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool>;
+}
@@ -0,0 +1,59 @@
+//! Port (interface) for Sending Webhooks
+
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainResult;
+
+/// Header with the id of the delivery, unique per event and kept across
+/// retries
+pub const WEBHOOK_ID_HEADER: &str = "x-webhook-id";
+
+/// Header with when the delivery was signed, in seconds since the Unix epoch
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "x-webhook-timestamp";
+
+/// Header with the hex encoded HMAC-SHA256 signatures of the delivery, comma
+/// separated
+pub const SIGNATURE_HEADER: &str = "x-signature";
+
+/// Signed delivery of an event to a tenant's receiver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboundWebhook {
+    pub url: String,
+    /// Id of the delivery, kept across retries
+    pub id: String,
+    /// When the delivery was signed, in seconds since the Unix epoch
+    pub timestamp: i64,
+    /// Hex encoded signatures, one per signing secret
+    pub signatures: Vec<String>,
+    /// JSON body
+    pub body: Vec<u8>,
+}
+
+/// How the receiver answered a delivery
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookReceipt {
+    /// HTTP status of the answer; `None` when the transport does not reach
+    /// receivers, such as the console
+    pub status: Option<u16>,
+}
+
+impl WebhookReceipt {
+    /// Whether the receiver took the delivery
+    pub fn is_success(&self) -> bool {
+        self.status
+            .is_none_or(|status| (200..300).contains(&status))
+    }
+}
+
+/// Delivers webhooks to receivers
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Deliver once, without retrying; fails when the receiver cannot be
+    /// reached, and returns its answer otherwise, whatever the status
+    async fn send(&self, webhook: &OutboundWebhook) -> DomainResult<WebhookReceipt>;
+
+    /// Whether `url` leads into our own network rather than to the public
+    /// internet, such as to a cloud metadata service, or cannot be told;
+    /// deliveries there are refused
+    async fn is_private(&self, url: &str) -> bool;
+}
@@ -0,0 +1,43 @@
+//! Catalog Export Task
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::application::ports::FlowerRepository;
+use crate::application::tasks::TaskHandler;
+use crate::application::usecases::CatalogExports;
+use crate::application::usecases::catalog_exports::CATALOG_EXPORT_TASK;
+use crate::domain::errors::{AppError, DomainResult};
+
+#[derive(Deserialize)]
+struct Payload {
+    id: Uuid,
+}
+
+/// Renders a requested catalog export
+pub struct CatalogExportTask<R: FlowerRepository + ?Sized> {
+    exports: Arc<CatalogExports<R>>,
+}
+
+impl<R: FlowerRepository + ?Sized> CatalogExportTask<R> {
+    pub fn new(exports: Arc<CatalogExports<R>>) -> Self {
+        Self { exports }
+    }
+}
+
+#[async_trait]
+impl<R: FlowerRepository + ?Sized> TaskHandler for CatalogExportTask<R> {
+    fn kind(&self) -> &'static str {
+        CATALOG_EXPORT_TASK
+    }
+
+    async fn handle(&self, payload: &Value) -> DomainResult<()> {
+        let Payload { id } = Payload::deserialize(payload)
+            .map_err(|e| AppError::internal(format!("Invalid catalog export task: {}", e)))?;
+        self.exports.render(id).await
+    }
+}
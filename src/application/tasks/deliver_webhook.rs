@@ -0,0 +1,36 @@
+//! Deliver Webhook Task
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::application::tasks::TaskHandler;
+use crate::application::usecases::Webhooks;
+use crate::application::usecases::webhooks::{DELIVER_WEBHOOK_TASK, WebhookDelivery};
+use crate::domain::errors::{AppError, DomainResult};
+
+/// Delivers a queued event to one of its tenant's webhook endpoints
+pub struct DeliverWebhookTask {
+    webhooks: Arc<Webhooks>,
+}
+
+impl DeliverWebhookTask {
+    pub fn new(webhooks: Arc<Webhooks>) -> Self {
+        Self { webhooks }
+    }
+}
+
+#[async_trait]
+impl TaskHandler for DeliverWebhookTask {
+    fn kind(&self) -> &'static str {
+        DELIVER_WEBHOOK_TASK
+    }
+
+    async fn handle(&self, payload: &Value) -> DomainResult<()> {
+        let delivery = WebhookDelivery::deserialize(payload)
+            .map_err(|e| AppError::internal(format!("Invalid deliver webhook task: {}", e)))?;
+        self.webhooks.deliver(&delivery).await
+    }
+}
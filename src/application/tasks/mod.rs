@@ -0,0 +1,31 @@
+//! Deferred Tasks
+//!
+//! Tasks are enqueued through `usecases::Tasks`, persisted by a `TaskQueue`
+//! and executed by a `TaskWorker` using the handler registered for their
+//! kind. Unlike scheduled jobs, tasks carry a payload and are retried with
+//! backoff until they succeed or run out of attempts.
+
+pub mod catalog_export;
+pub mod deliver_webhook;
+pub mod send_email;
+pub mod worker;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::domain::errors::DomainResult;
+
+pub use catalog_export::CatalogExportTask;
+pub use deliver_webhook::DeliverWebhookTask;
+pub use send_email::SendEmailTask;
+pub use worker::{TaskWorker, TaskWorkerSettings};
+
+/// Executes tasks of one kind
+#[async_trait]
+pub trait TaskHandler: Send + Sync {
+    /// Task kind this handler is registered for
+    fn kind(&self) -> &'static str;
+
+    /// Execute a task; an error schedules a retry or dead-letters the task
+    async fn handle(&self, payload: &Value) -> DomainResult<()>;
+}
@@ -0,0 +1,36 @@
+//! Send Email Task
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::application::ports::{EmailMessage, EmailSender};
+use crate::application::tasks::TaskHandler;
+use crate::application::usecases::emails::SEND_EMAIL_TASK;
+use crate::domain::errors::{AppError, DomainResult};
+
+/// Delivers a queued email through the configured transport
+pub struct SendEmailTask {
+    sender: Arc<dyn EmailSender>,
+}
+
+impl SendEmailTask {
+    pub fn new(sender: Arc<dyn EmailSender>) -> Self {
+        Self { sender }
+    }
+}
+
+#[async_trait]
+impl TaskHandler for SendEmailTask {
+    fn kind(&self) -> &'static str {
+        SEND_EMAIL_TASK
+    }
+
+    async fn handle(&self, payload: &Value) -> DomainResult<()> {
+        let message = EmailMessage::deserialize(payload)
+            .map_err(|e| AppError::internal(format!("Invalid send email task: {}", e)))?;
+        self.sender.send(&message).await
+    }
+}
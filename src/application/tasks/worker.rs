@@ -0,0 +1,151 @@
+//! Task Worker
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinSet;
+use tracing::Instrument;
+
+use crate::application::ports::TaskQueue;
+use crate::application::tasks::TaskHandler;
+use crate::domain::errors::DomainResult;
+use crate::domain::task::Task;
+
+/// Upper bound on the delay between two attempts of a task
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(3600);
+
+/// Polling and retry behaviour of a task worker
+#[derive(Debug, Clone, Copy)]
+pub struct TaskWorkerSettings {
+    /// Delay before polling again when no task is due
+    pub poll_interval: Duration,
+    /// How long a claimed task stays hidden from other workers
+    pub lease: Duration,
+    /// Delay before the first retry, doubled on every further attempt
+    pub retry_backoff: Duration,
+}
+
+/// Claims due tasks and dispatches them to their handlers
+pub struct TaskWorker<Q: TaskQueue + ?Sized> {
+    queue: Arc<Q>,
+    handlers: HashMap<&'static str, Arc<dyn TaskHandler>>,
+    settings: TaskWorkerSettings,
+}
+
+impl<Q: TaskQueue + ?Sized + 'static> TaskWorker<Q> {
+    pub fn new(queue: Arc<Q>, settings: TaskWorkerSettings) -> Self {
+        Self {
+            queue,
+            handlers: HashMap::new(),
+            settings,
+        }
+    }
+
+    /// Register the handler for a task kind
+    pub fn handle(mut self, handler: impl TaskHandler + 'static) -> Self {
+        self.handlers.insert(handler.kind(), Arc::new(handler));
+        self
+    }
+
+    /// Start `concurrency` polling loops; dropping the set stops them
+    pub fn start(self, concurrency: usize) -> JoinSet<()> {
+        let worker = Arc::new(self);
+        let mut loops = JoinSet::new();
+        for _ in 0..concurrency {
+            let worker = worker.clone();
+            loops.spawn(async move { worker.poll().await });
+        }
+        loops
+    }
+
+    async fn poll(&self) {
+        loop {
+            match self.process_next().await {
+                Ok(true) => continue,
+                Ok(false) => {}
+                Err(e) => tracing::error!("Failed to process task queue: {}", e),
+            }
+            tokio::time::sleep(self.settings.poll_interval).await;
+        }
+    }
+
+    /// Run the next due task, if any
+    async fn process_next(&self) -> DomainResult<bool> {
+        let Some(task) = self.queue.claim(self.settings.lease).await? else {
+            return Ok(false);
+        };
+
+        let span = tracing::info_span!(
+            "task",
+            kind = task.kind(),
+            id = %task.id(),
+            attempt = task.attempts()
+        );
+
+        let outcome = match self.execute(&task).instrument(span.clone()).await {
+            Ok(()) => {
+                self.queue.complete(task.id()).await?;
+                "completed"
+            }
+            Err(error) if task.can_retry() && self.handlers.contains_key(task.kind()) => {
+                let delay = retry_delay(self.settings.retry_backoff, task.attempts());
+                tracing::warn!(parent: &span, "Task failed, retrying in {:?}: {}", delay, error);
+                let run_at = chrono::Utc::now()
+                    + chrono::Duration::from_std(delay).unwrap_or(chrono::Duration::MAX);
+                self.queue.retry(task.id(), &error, run_at).await?;
+                "retried"
+            }
+            Err(error) => {
+                tracing::error!(parent: &span, "Task failed permanently: {}", error);
+                self.queue.dead_letter(task.id(), &error).await?;
+                "dead_lettered"
+            }
+        };
+
+        metrics::counter!(
+            "tasks_processed_total",
+            "kind" => task.kind().to_string(),
+            "outcome" => outcome
+        )
+        .increment(1);
+
+        Ok(true)
+    }
+
+    /// Run the task's handler, describing any failure as a message
+    ///
+    /// Tasks without a handler fail and are dead-lettered straight away, as
+    /// retrying them cannot succeed until a release registers one.
+    async fn execute(&self, task: &Task) -> Result<(), String> {
+        let handler = self
+            .handlers
+            .get(task.kind())
+            .ok_or_else(|| format!("No handler registered for task kind '{}'", task.kind()))?;
+
+        match tokio::time::timeout(self.settings.lease, handler.handle(task.payload())).await {
+            Ok(result) => result.map_err(|e| e.to_string()),
+            Err(_) => Err(format!("Timed out after {:?}", self.settings.lease)),
+        }
+    }
+}
+
+/// Exponential backoff after the given (1-based) attempt
+fn retry_delay(base: Duration, attempt: i32) -> Duration {
+    let exponent = attempt.saturating_sub(1).clamp(0, 16) as u32;
+    base.saturating_mul(2u32.pow(exponent))
+        .min(MAX_RETRY_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_cap() {
+        let base = Duration::from_secs(10);
+        assert_eq!(retry_delay(base, 1), Duration::from_secs(10));
+        assert_eq!(retry_delay(base, 3), Duration::from_secs(40));
+        assert_eq!(retry_delay(base, 30), MAX_RETRY_BACKOFF);
+    }
+}
@@ -0,0 +1,201 @@
+//! Administration
+//!
+//! Operations only the admin may perform, across tenants: auditing the
+//! inventory ledger, purging flowers together with their history and
+//! purging stale cache entries.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::dtos::{CachePurgeResponse, FlowerPurgeResponse, LedgerEntryResponse};
+use crate::application::ports::{
+    CachePurge, CachePurger, FlowerHistory, FlowerRepository, FlowerViewStore, LedgerQuery,
+    StockLedger,
+};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::FlowerError;
+use crate::domain::shared::{PaginatedResponse, Pagination, TenantId};
+use crate::i18n::Message;
+
+/// Entry point for the admin-only operations
+pub struct Administration<R: FlowerRepository + ?Sized> {
+    flowers: Arc<R>,
+    ledger: Arc<dyn StockLedger>,
+    history: Arc<dyn FlowerHistory>,
+    views: Arc<dyn FlowerViewStore>,
+    cache: Option<Arc<dyn CachePurger>>,
+}
+
+impl<R: FlowerRepository + ?Sized> Administration<R> {
+    pub fn new(
+        flowers: Arc<R>,
+        ledger: Arc<dyn StockLedger>,
+        history: Arc<dyn FlowerHistory>,
+        views: Arc<dyn FlowerViewStore>,
+    ) -> Self {
+        Self {
+            flowers,
+            ledger,
+            history,
+            views,
+            cache: None,
+        }
+    }
+
+    /// Purge the cache placed in front of flower lookups
+    pub fn with_cache(mut self, cache: Arc<dyn CachePurger>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Ledger entries matching `query`, most recent first
+    pub async fn stock_movements(
+        &self,
+        query: LedgerQuery,
+        pagination: Pagination,
+    ) -> DomainResult<PaginatedResponse<LedgerEntryResponse>> {
+        let movements = self.ledger.find(&query, &pagination).await?;
+        let total = self.ledger.count(&query).await?;
+
+        let entries = movements
+            .into_iter()
+            .map(LedgerEntryResponse::from)
+            .collect();
+
+        Ok(PaginatedResponse::new(entries, total, &pagination))
+    }
+
+    /// Delete a flower along with its ledger entries, change history and view
+    /// totals, which a regular delete keeps
+    ///
+    /// History left behind by a flower deleted earlier is purged as well;
+    /// only when there is nothing at all is the flower not found. Views still
+    /// buffered by an instance may be flushed afterwards, but trending
+    /// flowers skip those of missing flowers.
+    pub async fn purge_flower(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<FlowerPurgeResponse> {
+        let exists = self.flowers.find_by_id(tenant, id).await?.is_some();
+        let stock_movements = self.ledger.delete_flower(tenant, id).await?;
+        let flower_changes = self.history.delete_flower(tenant, id).await?;
+        let view_days = self.views.delete_flower(tenant, id).await?;
+        if exists {
+            self.flowers.delete(tenant, id).await?;
+        } else if stock_movements == 0 && flower_changes == 0 && view_days == 0 {
+            return Err(FlowerError::not_found(id));
+        }
+
+        tracing::info!(
+            tenant = %tenant.as_str(),
+            flower_id = %id,
+            stock_movements,
+            flower_changes,
+            view_days,
+            "Purged flower"
+        );
+        Ok(FlowerPurgeResponse {
+            flower_id: id,
+            flower_deleted: exists,
+            stock_movements,
+            flower_changes,
+            view_days,
+        })
+    }
+
+    /// Drop cached entries, so stale data stops being served before it
+    /// expires
+    pub async fn purge_cache(&self, target: CachePurge) -> DomainResult<CachePurgeResponse> {
+        if matches!(&target, CachePurge::Prefix(prefix) if prefix.is_empty()) {
+            return Err(AppError::validation(Message::new("cache.prefix.empty")));
+        }
+        let Some(cache) = &self.cache else {
+            return Ok(CachePurgeResponse {
+                cache_enabled: false,
+                entries_removed: 0,
+            });
+        };
+
+        Ok(CachePurgeResponse {
+            cache_enabled: true,
+            entries_removed: cache.purge(&target).await?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dtos::StockAdjustmentRequest;
+    use crate::application::ports::ViewCount;
+    use crate::application::usecases::FlowerUseCase;
+    use crate::domain::shared::Entity;
+    use crate::infrastructure::storage::Storage;
+    use crate::test_support::FlowerBuilder;
+
+    #[tokio::test]
+    async fn purges_flowers_with_their_history() {
+        let storage = Storage::in_memory();
+        let flowers = FlowerUseCase::new(storage.flowers.clone(), storage.unit_of_work.clone());
+        let admin = Administration::new(
+            storage.flowers.clone(),
+            storage.ledger.clone(),
+            storage.history.clone(),
+            storage.views.clone(),
+        );
+        let tenant = TenantId::default();
+        let rose = FlowerBuilder::new()
+            .with_stock(5)
+            .persisted(storage.flowers.as_ref())
+            .await;
+        let delivery = StockAdjustmentRequest {
+            delta: 2,
+            reason: "Delivery".to_string(),
+        };
+        flowers
+            .adjust_stock(&tenant, rose.id(), delivery)
+            .await
+            .unwrap();
+        storage
+            .views
+            .add(&[ViewCount {
+                tenant: tenant.clone(),
+                flower_id: rose.id(),
+                day: chrono::Utc::now().date_naive(),
+                views: 3,
+            }])
+            .await
+            .unwrap();
+
+        let audited = admin
+            .stock_movements(LedgerQuery::default(), Pagination::default())
+            .await
+            .unwrap();
+        assert_eq!(audited.total, 1);
+        assert_eq!(audited.data[0].movement.stock_after, 7);
+
+        let purged = admin.purge_flower(&tenant, rose.id()).await.unwrap();
+        assert!(purged.flower_deleted);
+        assert_eq!(
+            (
+                purged.stock_movements,
+                purged.flower_changes,
+                purged.view_days
+            ),
+            (1, 1, 1)
+        );
+        assert!(
+            storage
+                .flowers
+                .find_by_id(&tenant, rose.id())
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let again = admin.purge_flower(&tenant, rose.id()).await.unwrap_err();
+        assert_eq!(again.code(), "flower.not_found");
+    }
+}
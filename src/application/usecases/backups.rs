@@ -0,0 +1,130 @@
+//! Backups Service
+//!
+//! A backup is one consistent export of the database written to the object
+//! store as `backups/{id}/{table}.ndjson` plus a `manifest.json`. Restoring
+//! replaces the contents of every table in the backup, so it must be
+//! confirmed with the restore token issued together with the backup.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::application::dtos::{BackupResponse, BackupTableResponse, RestoreResponse};
+use crate::application::ports::{DatabaseDump, ObjectStore, TableDump};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::new_id;
+use crate::i18n::Message;
+
+/// Creates and restores backups; unavailable without a dumpable database and
+/// an object store
+pub struct Backups {
+    dump: Option<Arc<dyn DatabaseDump>>,
+    store: Option<Arc<dyn ObjectStore>>,
+}
+
+impl Backups {
+    pub fn new(dump: Option<Arc<dyn DatabaseDump>>, store: Option<Arc<dyn ObjectStore>>) -> Self {
+        Self { dump, store }
+    }
+
+    fn ports(&self) -> DomainResult<(&dyn DatabaseDump, &dyn ObjectStore)> {
+        match (&self.dump, &self.store) {
+            (Some(dump), Some(store)) => Ok((dump.as_ref(), store.as_ref())),
+            _ => Err(AppError::service_unavailable(Message::new(
+                "backup.unavailable",
+            ))),
+        }
+    }
+
+    /// Export the database and write it to the object store
+    pub async fn create(&self) -> DomainResult<BackupResponse> {
+        let (dump, store) = self.ports()?;
+        let id = new_id();
+        let created_at = Utc::now();
+        let tables = dump.export().await?;
+
+        let mut written = Vec::with_capacity(tables.len());
+        for table in tables {
+            let key = table_key(id, &table.table);
+            let mut body = Vec::new();
+            for row in &table.rows {
+                serde_json::to_writer(&mut body, row).map_err(encode_error)?;
+                body.push(b'\n');
+            }
+            store.put(&key, body).await?;
+            written.push(BackupTableResponse {
+                table: table.table,
+                rows: table.rows.len(),
+                key,
+            });
+        }
+
+        // The manifest goes last: a backup without one is incomplete
+        let backup = BackupResponse {
+            id,
+            created_at,
+            tables: written,
+            restore_token: format!("{:032x}", rand::random::<u128>()),
+        };
+        let manifest = serde_json::to_vec_pretty(&backup).map_err(encode_error)?;
+        store.put(&manifest_key(id), manifest).await?;
+        tracing::info!(backup = %id, "Backup created");
+
+        Ok(backup)
+    }
+
+    /// Replace the database contents with those of backup `id`
+    pub async fn restore(&self, id: Uuid, confirm: &str) -> DomainResult<RestoreResponse> {
+        let (dump, store) = self.ports()?;
+        let not_found = || AppError::not_found(Message::new("backup.not_found").arg("id", id));
+
+        let manifest = store.get(&manifest_key(id)).await?.ok_or_else(not_found)?;
+        let backup: BackupResponse = serde_json::from_slice(&manifest).map_err(decode_error)?;
+        if confirm != backup.restore_token {
+            return Err(AppError::forbidden(
+                Message::new("backup.confirmation.invalid").arg("id", id),
+            ));
+        }
+
+        let mut tables = Vec::with_capacity(backup.tables.len());
+        for table in &backup.tables {
+            let body = store.get(&table.key).await?.ok_or_else(not_found)?;
+            let rows = body
+                .split(|byte| *byte == b'\n')
+                .filter(|line| !line.is_empty())
+                .map(serde_json::from_slice::<Value>)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(decode_error)?;
+            tables.push(TableDump {
+                table: table.table.clone(),
+                rows,
+            });
+        }
+        dump.restore(&tables).await?;
+        tracing::warn!(backup = %id, "Backup restored");
+
+        Ok(RestoreResponse {
+            id,
+            restored_at: Utc::now(),
+            tables: backup.tables,
+        })
+    }
+}
+
+fn table_key(id: Uuid, table: &str) -> String {
+    format!("backups/{}/{}.ndjson", id, table)
+}
+
+fn manifest_key(id: Uuid) -> String {
+    format!("backups/{}/manifest.json", id)
+}
+
+fn encode_error(error: serde_json::Error) -> AppError {
+    AppError::internal(format!("Failed to encode backup: {}", error))
+}
+
+fn decode_error(error: serde_json::Error) -> AppError {
+    AppError::internal(format!("Corrupt backup: {}", error))
+}
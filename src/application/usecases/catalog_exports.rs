@@ -0,0 +1,354 @@
+//! Catalog Exports
+//!
+//! A catalog export renders the flowers of a tenant, optionally filtered, as
+//! a static HTML page laid out for printing on A4 sheets. Rendering is left
+//! to the task workers: requesting an export writes its record to
+//! `catalog-exports/{id}/export.json` and enqueues a `catalog_export` task,
+//! which writes `catalog.html` next to it and marks the export ready.
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::application::dtos::{CatalogExportRequest, CatalogExportResponse, CatalogExportStatus};
+use crate::application::html::escape;
+use crate::application::ports::{FlowerRepository, ObjectStore, TaskQueue};
+use crate::application::usecases::Tasks;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::{Flower, FlowerColor};
+use crate::domain::shared::{Pagination, TenantId, new_id};
+use crate::i18n::Message;
+
+/// Kind of the task rendering an export
+pub const CATALOG_EXPORT_TASK: &str = "catalog_export";
+
+/// Flowers printed on one sheet, three columns of four
+const FLOWERS_PER_PAGE: usize = 12;
+
+/// Requests, renders and serves printable catalogs; unavailable without an
+/// object store
+pub struct CatalogExports<R: FlowerRepository + ?Sized> {
+    repository: Arc<R>,
+    tasks: Arc<Tasks<dyn TaskQueue>>,
+    store: Option<Arc<dyn ObjectStore>>,
+}
+
+impl<R: FlowerRepository + ?Sized> CatalogExports<R> {
+    pub fn new(
+        repository: Arc<R>,
+        tasks: Arc<Tasks<dyn TaskQueue>>,
+        store: Option<Arc<dyn ObjectStore>>,
+    ) -> Self {
+        Self {
+            repository,
+            tasks,
+            store,
+        }
+    }
+
+    fn store(&self) -> DomainResult<&dyn ObjectStore> {
+        self.store.as_deref().ok_or_else(|| {
+            AppError::service_unavailable(Message::new("catalog_export.unavailable"))
+        })
+    }
+
+    /// Record an export and queue it for rendering
+    pub async fn request(
+        &self,
+        request: CatalogExportRequest,
+    ) -> DomainResult<CatalogExportResponse> {
+        let store = self.store()?;
+        let tenant = request
+            .tenant
+            .map(TenantId::new)
+            .transpose()?
+            .unwrap_or_default();
+        let color = request
+            .color
+            .map(|c| c.parse::<FlowerColor>())
+            .transpose()?;
+        let search = request
+            .search
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+
+        let export = CatalogExportResponse {
+            id: new_id(),
+            status: CatalogExportStatus::Pending,
+            tenant: tenant.to_string(),
+            search,
+            color,
+            requested_at: Utc::now(),
+            completed_at: None,
+            flowers: None,
+            pages: None,
+            download: None,
+        };
+        write_record(store, &export).await?;
+        self.tasks
+            .enqueue(CATALOG_EXPORT_TASK, json!({ "id": export.id }))
+            .await?;
+        tracing::info!(export = %export.id, tenant = %tenant, "Catalog export requested");
+
+        Ok(export)
+    }
+
+    /// Current state of export `id`
+    pub async fn find(&self, id: Uuid) -> DomainResult<CatalogExportResponse> {
+        let record = self
+            .store()?
+            .get(&record_key(id))
+            .await?
+            .ok_or_else(|| not_found(id))?;
+        serde_json::from_slice(&record).map_err(decode_error)
+    }
+
+    /// Rendered catalog of export `id`
+    pub async fn download(&self, id: Uuid) -> DomainResult<Vec<u8>> {
+        let export = self.find(id).await?;
+        if export.status != CatalogExportStatus::Ready {
+            return Err(AppError::conflict(
+                Message::new("catalog_export.pending").arg("id", id),
+                None,
+            ));
+        }
+        self.store()?
+            .get(&catalog_key(id))
+            .await?
+            .ok_or_else(|| not_found(id))
+    }
+
+    /// Render export `id` and mark it ready; exports already rendered are
+    /// left alone, so a retried task does no harm
+    pub async fn render(&self, id: Uuid) -> DomainResult<()> {
+        let store = self.store()?;
+        let mut export = self.find(id).await?;
+        if export.status == CatalogExportStatus::Ready {
+            return Ok(());
+        }
+
+        let tenant = TenantId::new(&export.tenant)?;
+        let flowers = self
+            .matching_flowers(&tenant, export.search.as_deref(), export.color)
+            .await?;
+        let generated_at = Utc::now();
+        let (html, pages) = render_html(&tenant, &flowers, generated_at);
+        store.put(&catalog_key(id), html.into_bytes()).await?;
+
+        export.status = CatalogExportStatus::Ready;
+        export.completed_at = Some(generated_at);
+        export.flowers = Some(flowers.len());
+        export.pages = Some(pages);
+        write_record(store, &export).await?;
+        tracing::info!(export = %id, flowers = flowers.len(), pages, "Catalog export rendered");
+
+        Ok(())
+    }
+
+    /// Every flower matching the filters, by name
+    async fn matching_flowers(
+        &self,
+        tenant: &TenantId,
+        search: Option<&str>,
+        color: Option<FlowerColor>,
+    ) -> DomainResult<Vec<Flower>> {
+        let mut flowers = Vec::new();
+        let mut pagination = Pagination {
+            page: 1,
+            per_page: Pagination::MAX_PER_PAGE,
+        };
+        loop {
+            let batch = self
+                .repository
+                .search(tenant, search, color, None, None, &pagination)
+                .await?;
+            let done = (batch.len() as i64) < pagination.per_page;
+            flowers.extend(batch);
+            if done {
+                break;
+            }
+            pagination.page += 1;
+        }
+
+        flowers.sort_by_cached_key(|flower| flower.name().to_lowercase());
+        Ok(flowers)
+    }
+}
+
+async fn write_record(store: &dyn ObjectStore, export: &CatalogExportResponse) -> DomainResult<()> {
+    let record = serde_json::to_vec_pretty(export)
+        .map_err(|e| AppError::internal(format!("Failed to encode catalog export: {}", e)))?;
+    store.put(&record_key(export.id), record).await
+}
+
+fn record_key(id: Uuid) -> String {
+    format!("catalog-exports/{}/export.json", id)
+}
+
+fn catalog_key(id: Uuid) -> String {
+    format!("catalog-exports/{}/catalog.html", id)
+}
+
+fn not_found(id: Uuid) -> AppError {
+    AppError::not_found(Message::new("catalog_export.not_found").arg("id", id))
+}
+
+fn decode_error(error: serde_json::Error) -> AppError {
+    AppError::internal(format!("Corrupt catalog export: {}", error))
+}
+
+const STYLE: &str = "\
+@page { size: A4; margin: 12mm; }
+body { font-family: Helvetica, Arial, sans-serif; color: #222; margin: 0; }
+.sheet { break-after: page; page-break-after: always; }
+.sheet:last-child { break-after: auto; page-break-after: auto; }
+header, footer { display: flex; justify-content: space-between; font-size: 9pt; color: #777; }
+.grid { display: grid; grid-template-columns: repeat(3, 1fr); gap: 5mm; margin: 5mm 0; }
+.card { border: 1px solid #ddd; border-radius: 2mm; padding: 4mm; break-inside: avoid; }
+.card h2 { font-size: 12pt; margin: 0 0 2mm; }
+.color { font-size: 9pt; color: #555; }
+.swatch { display: inline-block; width: 3mm; height: 3mm; border: 1px solid #999; border-radius: 50%; margin-right: 1mm; }
+.description { font-size: 9pt; }
+.price { font-size: 13pt; font-weight: bold; margin: 2mm 0 0; }
+.empty { margin: 20mm 0; text-align: center; color: #777; }
+";
+
+/// Printable HTML catalog and its number of sheets
+fn render_html(
+    tenant: &TenantId,
+    flowers: &[Flower],
+    generated_at: DateTime<Utc>,
+) -> (String, usize) {
+    let sheets: Vec<&[Flower]> = if flowers.is_empty() {
+        vec![&[]]
+    } else {
+        flowers.chunks(FLOWERS_PER_PAGE).collect()
+    };
+    let tenant = escape(tenant.as_str());
+    let generated_at = generated_at.format("%Y-%m-%d %H:%M UTC");
+
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Flower catalog – {tenant}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n"
+    );
+    for (index, sheet) in sheets.iter().enumerate() {
+        let _ = write!(
+            html,
+            "<section class=\"sheet\">\n<header><span>Flower catalog – {tenant}</span>\
+             <span>{generated_at}</span></header>\n"
+        );
+        if sheet.is_empty() {
+            html.push_str("<p class=\"empty\">No flowers match this catalog.</p>\n");
+        } else {
+            html.push_str("<div class=\"grid\">\n");
+            for flower in *sheet {
+                render_card(&mut html, flower);
+            }
+            html.push_str("</div>\n");
+        }
+        let _ = write!(
+            html,
+            "<footer><span></span><span>Page {} of {}</span></footer>\n</section>\n",
+            index + 1,
+            sheets.len()
+        );
+    }
+    html.push_str("</body>\n</html>\n");
+
+    (html, sheets.len())
+}
+
+fn render_card(html: &mut String, flower: &Flower) {
+    let _ = write!(
+        html,
+        "<article class=\"card\">\n<h2>{}</h2>\n\
+         <div class=\"color\"><span class=\"swatch\" style=\"background: {}\"></span>{}</div>\n",
+        escape(flower.name()),
+        swatch(flower.color()),
+        flower.color()
+    );
+    // Descriptions are sanitized when stored and safe to embed as they are
+    if let Some(description) = flower.description() {
+        let _ = writeln!(html, "<div class=\"description\">{}</div>", description);
+    }
+    let _ = write!(
+        html,
+        "<p class=\"price\">{}</p>\n</article>\n",
+        format_price(flower.price())
+    );
+}
+
+/// CSS background standing in for a photo of the flower
+fn swatch(color: FlowerColor) -> &'static str {
+    match color {
+        FlowerColor::Red => "#c62828",
+        FlowerColor::White => "#ffffff",
+        FlowerColor::Pink => "#f48fb1",
+        FlowerColor::Yellow => "#fdd835",
+        FlowerColor::Orange => "#fb8c00",
+        FlowerColor::Purple => "#8e24aa",
+        FlowerColor::Blue => "#1e88e5",
+        FlowerColor::Peach => "#ffcc80",
+        FlowerColor::Mixed => "conic-gradient(#c62828, #fdd835, #1e88e5, #8e24aa, #c62828)",
+    }
+}
+
+/// Price in Indonesian notation, such as `Rp 25.000` or `Rp 1.250,50`
+fn format_price(price: f64) -> String {
+    let cents = (price * 100.0).round() as i64;
+    let digits = (cents / 100).to_string();
+
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push('.');
+        }
+        grouped.push(digit);
+    }
+
+    match cents % 100 {
+        0 => format!("Rp {}", grouped),
+        fraction => format!("Rp {},{:02}", grouped, fraction),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::FlowerBuilder;
+
+    fn flower(name: &str) -> Flower {
+        FlowerBuilder::new().with_name(name).build()
+    }
+
+    #[test]
+    fn prices_use_indonesian_notation() {
+        assert_eq!(format_price(0.0), "Rp 0");
+        assert_eq!(format_price(25_000.0), "Rp 25.000");
+        assert_eq!(format_price(1_250.5), "Rp 1.250,50");
+        assert_eq!(format_price(1_000_000_000.0), "Rp 1.000.000.000");
+    }
+
+    #[test]
+    fn catalogs_are_split_into_sheets_and_escaped() {
+        let mut flowers: Vec<Flower> = (0..FLOWERS_PER_PAGE)
+            .map(|i| flower(&format!("Rose {}", i)))
+            .collect();
+        flowers.push(flower("<script>Tulip</script>"));
+
+        let (html, pages) = render_html(&TenantId::default(), &flowers, Utc::now());
+        assert_eq!(pages, 2);
+        assert!(html.contains("Page 2 of 2"));
+        assert!(html.contains("&lt;script&gt;Tulip&lt;/script&gt;"));
+        assert!(!html.contains("<script>"));
+
+        let (html, pages) = render_html(&TenantId::default(), &[], Utc::now());
+        assert_eq!(pages, 1);
+        assert!(html.contains("No flowers match"));
+    }
+}
@@ -0,0 +1,194 @@
+//! Delivery Zones
+//!
+//! Areas a tenant delivers to, each with its own fee, and the check telling
+//! whether an address is served. Addresses are tested against the zone
+//! boundaries here rather than in the database, like store distances, so
+//! every storage backend answers alike.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::dtos::{
+    CreateDeliveryZoneRequest, DeliveryCheckResponse, DeliveryZoneResponse,
+};
+use crate::application::money::MoneyDisplay;
+use crate::application::ports::DeliveryZoneRepository;
+use crate::domain::delivery::{DeliveryZone, DeliveryZoneError};
+use crate::domain::errors::{AppError, DomainResult, FieldError};
+use crate::domain::shared::TenantId;
+use crate::domain::store::GeoPoint;
+use crate::i18n::Message;
+
+/// Defines delivery zones and checks addresses against them
+pub struct DeliveryZones {
+    zones: Arc<dyn DeliveryZoneRepository>,
+}
+
+impl DeliveryZones {
+    pub fn new(zones: Arc<dyn DeliveryZoneRepository>) -> Self {
+        Self { zones }
+    }
+
+    pub async fn create(
+        &self,
+        tenant: &TenantId,
+        request: CreateDeliveryZoneRequest,
+    ) -> DomainResult<DeliveryZoneResponse> {
+        let boundary = request
+            .boundary
+            .iter()
+            .map(|corner| GeoPoint::new(corner.latitude, corner.longitude))
+            .collect::<DomainResult<_>>()?;
+        let zone = DeliveryZone::new(tenant.clone(), &request.name, boundary, request.fee)?;
+
+        let created = self.zones.create(&zone).await?;
+        Ok(created.into())
+    }
+
+    /// Delivery zones of a tenant, by name
+    pub async fn list(&self, tenant: &TenantId) -> DomainResult<Vec<DeliveryZoneResponse>> {
+        let zones = self.zones.find_all(tenant).await?;
+        Ok(zones.into_iter().map(DeliveryZoneResponse::from).collect())
+    }
+
+    pub async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
+        if self.zones.delete(tenant, id).await? {
+            Ok(())
+        } else {
+            Err(DeliveryZoneError::not_found(id))
+        }
+    }
+
+    /// Zone delivering to `address` is charged by; `None` when no zone
+    /// covers it
+    ///
+    /// Where zones overlap, the cheapest applies.
+    pub async fn zone_for(
+        &self,
+        tenant: &TenantId,
+        address: &GeoPoint,
+    ) -> DomainResult<Option<DeliveryZone>> {
+        Ok(self
+            .zones
+            .find_all(tenant)
+            .await?
+            .into_iter()
+            .filter(|zone| zone.contains(address))
+            .min_by(|a, b| a.fee().total_cmp(&b.fee())))
+    }
+
+    /// Whether the address at `lat`/`lng` is served, and at what fee
+    pub async fn check(
+        &self,
+        tenant: &TenantId,
+        lat: Option<f64>,
+        lng: Option<f64>,
+    ) -> DomainResult<DeliveryCheckResponse> {
+        let mut fields = Vec::new();
+        for (field, value, range) in [
+            ("lat", lat, GeoPoint::LATITUDE),
+            ("lng", lng, GeoPoint::LONGITUDE),
+        ] {
+            match value {
+                None => fields.push(FieldError::new(
+                    field,
+                    Message::new("delivery_zone.check.location_required"),
+                )),
+                Some(value) if !range.contains(&value) => fields.push(FieldError::new(
+                    field,
+                    Message::new("delivery_zone.check.coordinate_out_of_range")
+                        .arg("min", range.start())
+                        .arg("max", range.end()),
+                )),
+                Some(_) => {}
+            }
+        }
+        let address = match (lat, lng) {
+            (Some(lat), Some(lng)) if fields.is_empty() => GeoPoint::new(lat, lng)?,
+            _ => {
+                return Err(AppError::unprocessable(
+                    Message::new("delivery_zone.check.invalid"),
+                    fields,
+                ));
+            }
+        };
+
+        let zone = self.zone_for(tenant, &address).await?;
+        Ok(DeliveryCheckResponse {
+            serviceable: zone.is_some(),
+            zone_id: zone.as_ref().map(DeliveryZone::id),
+            zone_name: zone.as_ref().map(|zone| zone.name().to_string()),
+            fee: zone.as_ref().map(DeliveryZone::fee),
+            fee_display: zone.as_ref().map(|zone| MoneyDisplay::of(zone.fee())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dtos::Coordinates;
+    use crate::infrastructure::storage::Storage;
+
+    fn square(name: &str, north: f64, west: f64, size: f64, fee: f64) -> CreateDeliveryZoneRequest {
+        let corner = |latitude, longitude| Coordinates {
+            latitude,
+            longitude,
+        };
+        CreateDeliveryZoneRequest {
+            name: name.to_string(),
+            boundary: vec![
+                corner(north, west),
+                corner(north, west + size),
+                corner(north - size, west + size),
+                corner(north - size, west),
+            ],
+            fee,
+        }
+    }
+
+    #[tokio::test]
+    async fn the_cheapest_zone_covering_the_address_applies() {
+        let zones = DeliveryZones::new(Storage::in_memory().delivery_zones);
+        let tenant = TenantId::default();
+        zones
+            .create(
+                &tenant,
+                square("Greater Jakarta", -6.0, 106.6, 0.5, 25_000.0),
+            )
+            .await
+            .unwrap();
+        let central = zones
+            .create(
+                &tenant,
+                square("Central Jakarta", -6.15, 106.8, 0.1, 10_000.0),
+            )
+            .await
+            .unwrap();
+
+        let menteng = zones
+            .check(&tenant, Some(-6.2), Some(106.83))
+            .await
+            .unwrap();
+        assert!(menteng.serviceable);
+        assert_eq!(menteng.zone_id, Some(central.id));
+        assert_eq!(menteng.fee, Some(10_000.0));
+
+        let tangerang = zones
+            .check(&tenant, Some(-6.18), Some(106.63))
+            .await
+            .unwrap();
+        assert_eq!(tangerang.fee, Some(25_000.0));
+
+        let bandung = zones
+            .check(&tenant, Some(-6.91), Some(107.61))
+            .await
+            .unwrap();
+        assert!(!bandung.serviceable);
+        assert_eq!(bandung.fee, None);
+
+        let error = zones.check(&tenant, Some(-91.0), None).await.unwrap_err();
+        assert_eq!(error.code(), "delivery_zone.check.invalid");
+    }
+}
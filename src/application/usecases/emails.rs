@@ -0,0 +1,40 @@
+//! Emails Service
+//!
+//! Emails are rendered up front and delivered by a `send_email` task, so a
+//! mail server outage delays them through the task retries instead of
+//! failing the request or job that sent them.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::emails::{EmailTemplate, compose};
+use crate::application::ports::TaskQueue;
+use crate::application::usecases::Tasks;
+use crate::domain::errors::{AppError, DomainResult};
+
+/// Task kind delivering one rendered email
+pub const SEND_EMAIL_TASK: &str = "send_email";
+
+/// Entry point for sending email
+pub struct Emails {
+    tasks: Arc<Tasks<dyn TaskQueue>>,
+}
+
+impl Emails {
+    pub fn new(tasks: Arc<Tasks<dyn TaskQueue>>) -> Self {
+        Self { tasks }
+    }
+
+    /// Render `template` and queue it for delivery to `to`
+    pub async fn send(
+        &self,
+        to: &[String],
+        template: &(impl EmailTemplate + Sync),
+    ) -> DomainResult<Uuid> {
+        let message = compose(to, template)?;
+        let payload = serde_json::to_value(&message)
+            .map_err(|e| AppError::internal(format!("Failed to queue email: {}", e)))?;
+        self.tasks.enqueue(SEND_EMAIL_TASK, payload).await
+    }
+}
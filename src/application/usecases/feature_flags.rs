@@ -0,0 +1,103 @@
+//! Feature Flags Service
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+
+use crate::application::dtos::{FeatureFlagResponse, FeatureFlagSource, UpdateFeatureFlagRequest};
+use crate::application::ports::FeatureFlagRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::feature_flag::{FeatureFlag, FeatureFlagError};
+
+/// Feature flags with configured defaults and database overrides
+///
+/// Overrides are cached in memory so checking a flag on every request costs
+/// no query; `refresh` reloads them, e.g. to pick up toggles made by other
+/// instances.
+pub struct FeatureFlags<R: FeatureFlagRepository + ?Sized> {
+    repository: Arc<R>,
+    defaults: HashMap<String, bool>,
+    overrides: RwLock<HashMap<String, FeatureFlag>>,
+}
+
+impl<R: FeatureFlagRepository + ?Sized> FeatureFlags<R> {
+    pub fn new(repository: Arc<R>, defaults: HashMap<String, bool>) -> Self {
+        Self {
+            repository,
+            defaults,
+            overrides: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Reload the database overrides
+    pub async fn refresh(&self) -> DomainResult<()> {
+        let flags = self.repository.find_all().await?;
+        let overrides = flags
+            .into_iter()
+            .map(|flag| (flag.key().to_string(), flag))
+            .collect();
+
+        *self.overrides.write().expect("feature flag lock poisoned") = overrides;
+        Ok(())
+    }
+
+    /// Whether a feature is on; unknown flags are off
+    pub fn is_enabled(&self, key: &str) -> bool {
+        let overrides = self.overrides.read().expect("feature flag lock poisoned");
+        match overrides.get(key) {
+            Some(flag) => flag.enabled(),
+            None => self.defaults.get(key).copied().unwrap_or(false),
+        }
+    }
+
+    /// Fail with 404 unless the feature is on, for endpoints shipped dark
+    pub fn ensure_enabled(&self, key: &str) -> DomainResult<()> {
+        if self.is_enabled(key) {
+            Ok(())
+        } else {
+            Err(FeatureFlagError::disabled(key))
+        }
+    }
+
+    /// All known flags with their effective values, sorted by key
+    pub fn list(&self) -> Vec<FeatureFlagResponse> {
+        let mut flags: BTreeMap<String, FeatureFlagResponse> = self
+            .defaults
+            .iter()
+            .map(|(key, enabled)| {
+                let flag = FeatureFlagResponse {
+                    key: key.clone(),
+                    enabled: *enabled,
+                    description: None,
+                    source: FeatureFlagSource::Config,
+                    updated_at: None,
+                };
+                (key.clone(), flag)
+            })
+            .collect();
+
+        let overrides = self.overrides.read().expect("feature flag lock poisoned");
+        for flag in overrides.values() {
+            flags.insert(flag.key().to_string(), flag.clone().into());
+        }
+
+        flags.into_values().collect()
+    }
+
+    /// Toggle a flag at runtime, storing the override in the database
+    pub async fn set(
+        &self,
+        key: &str,
+        request: UpdateFeatureFlagRequest,
+    ) -> DomainResult<FeatureFlagResponse> {
+        let flag = FeatureFlag::new(key, request.enabled, request.description)?;
+        let saved = self.repository.upsert(&flag).await?;
+
+        tracing::info!("Feature flag '{}' set to {}", saved.key(), saved.enabled());
+        self.overrides
+            .write()
+            .expect("feature flag lock poisoned")
+            .insert(saved.key().to_string(), saved.clone());
+
+        Ok(saved.into())
+    }
+}
@@ -0,0 +1,145 @@
+//! Flower Change History
+//!
+//! Updates record which fields they changed, from what to what and by whom,
+//! in the same transaction as the update (see `FlowerUseCase`). This reads
+//! that history back, one flower at a time.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::dtos::FlowerChangeResponse;
+use crate::application::ports::{FlowerHistory, FlowerRepository};
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::FlowerError;
+use crate::domain::shared::{PaginatedResponse, Pagination, TenantId};
+
+/// Lists the changes made to flowers
+pub struct FlowerChanges<R: FlowerRepository + ?Sized> {
+    repository: Arc<R>,
+    history: Arc<dyn FlowerHistory>,
+}
+
+impl<R: FlowerRepository + ?Sized> FlowerChanges<R> {
+    pub fn new(repository: Arc<R>, history: Arc<dyn FlowerHistory>) -> Self {
+        Self {
+            repository,
+            history,
+        }
+    }
+
+    /// Changes to a flower, oldest first
+    ///
+    /// The history outlives the flower, so that of a deleted flower is still
+    /// listed; a flower without history is not found unless it exists.
+    pub async fn list(
+        &self,
+        tenant: &TenantId,
+        flower_id: Uuid,
+        pagination: Pagination,
+    ) -> DomainResult<PaginatedResponse<FlowerChangeResponse>> {
+        let total = self.history.count(tenant, flower_id).await?;
+        if total == 0
+            && self
+                .repository
+                .find_by_id(tenant, flower_id)
+                .await?
+                .is_none()
+        {
+            return Err(FlowerError::not_found(flower_id));
+        }
+
+        let changes = self.history.find(tenant, flower_id, &pagination).await?;
+        let changes = changes
+            .into_iter()
+            .map(FlowerChangeResponse::from)
+            .collect();
+
+        Ok(PaginatedResponse::new(changes, total, &pagination))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::authorization::Subject;
+    use crate::application::dtos::UpdateFlowerRequest;
+    use crate::application::usecases::FlowerUseCase;
+    use crate::domain::shared::Entity;
+    use crate::infrastructure::storage::Storage;
+    use crate::test_support::FlowerBuilder;
+
+    #[tokio::test]
+    async fn lists_changes_with_their_actor() {
+        let storage = Storage::in_memory();
+        let flowers = FlowerUseCase::new(storage.flowers.clone(), storage.unit_of_work.clone());
+        let changes = FlowerChanges::new(storage.flowers.clone(), storage.history.clone());
+        let tenant = TenantId::default();
+        let rose = FlowerBuilder::new()
+            .with_price(25_000.0)
+            .persisted(storage.flowers.as_ref())
+            .await;
+        let reprice = |price: f64| UpdateFlowerRequest {
+            name: None,
+            color: None,
+            description: None,
+            price: Some(price),
+            stock: None,
+            unit: None,
+            stems_per_unit: None,
+            sku: None,
+            attributes: None,
+            metadata: None,
+        };
+
+        let empty = changes
+            .list(&tenant, rose.id(), Pagination::default())
+            .await
+            .unwrap();
+        assert_eq!(empty.total, 0);
+
+        flowers
+            .update_flower(&tenant, rose.id(), reprice(30_000.0))
+            .await
+            .unwrap();
+        Subject::Admin
+            .scope(flowers.update_flower(&tenant, rose.id(), reprice(28_000.0)))
+            .await
+            .unwrap();
+        // Nothing changes, nothing is recorded
+        flowers
+            .update_flower(&tenant, rose.id(), reprice(28_000.0))
+            .await
+            .unwrap();
+
+        let listed = changes
+            .list(&tenant, rose.id(), Pagination::default())
+            .await
+            .unwrap();
+        let entries: Vec<_> = listed
+            .data
+            .iter()
+            .map(|change| {
+                (
+                    change.field.as_str(),
+                    change.old_value.clone(),
+                    change.new_value.clone(),
+                    change.actor.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                ("price", 25_000.0.into(), 30_000.0.into(), "system"),
+                ("price", 30_000.0.into(), 28_000.0.into(), "admin"),
+            ]
+        );
+
+        let missing = changes
+            .list(&tenant, Uuid::new_v4(), Pagination::default())
+            .await
+            .unwrap_err();
+        assert_eq!(missing.code(), "flower.not_found");
+    }
+}
@@ -0,0 +1,103 @@
+//! Flower Labels
+//!
+//! Shelf labels carry a QR code linking to the product page and a barcode of
+//! the flower's SKU. A label is identified by the data it encodes, so its
+//! ETag is known before anything is rendered.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{FlowerRepository, LabelRenderer};
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerError};
+use crate::domain::shared::TenantId;
+
+/// What a label encodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LabelKind {
+    /// Link to the product page
+    QrCode,
+    /// SKU as a Code 128 barcode
+    Barcode,
+}
+
+/// Data of one label, not yet rendered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    pub kind: LabelKind,
+    pub data: String,
+}
+
+impl Label {
+    /// Strong ETag derived from the encoded data
+    pub fn etag(&self) -> String {
+        let mut hasher = DefaultHasher::new();
+        (self.kind, &self.data).hash(&mut hasher);
+        format!("\"{:016x}\"", hasher.finish())
+    }
+}
+
+/// Builds and renders the labels of flowers
+pub struct FlowerLabels<R: FlowerRepository + ?Sized> {
+    repository: Arc<R>,
+    renderer: Arc<dyn LabelRenderer>,
+    product_url_template: String,
+}
+
+impl<R: FlowerRepository + ?Sized> FlowerLabels<R> {
+    /// `product_url_template` is the product page address, with `{id}` and
+    /// `{tenant}` standing for the flower ID and its tenant
+    pub fn new(
+        repository: Arc<R>,
+        renderer: Arc<dyn LabelRenderer>,
+        product_url_template: impl Into<String>,
+    ) -> Self {
+        Self {
+            repository,
+            renderer,
+            product_url_template: product_url_template.into(),
+        }
+    }
+
+    async fn flower(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Flower> {
+        self.repository
+            .find_by_id(tenant, id)
+            .await?
+            .ok_or_else(|| FlowerError::not_found(id))
+    }
+
+    /// QR code label linking to the product page of a flower
+    pub async fn qr_code(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Label> {
+        self.flower(tenant, id).await?;
+        let link = self
+            .product_url_template
+            .replace("{id}", &id.to_string())
+            .replace("{tenant}", tenant.as_str());
+
+        Ok(Label {
+            kind: LabelKind::QrCode,
+            data: link,
+        })
+    }
+
+    /// Barcode label of a flower's SKU
+    pub async fn barcode(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Label> {
+        let flower = self.flower(tenant, id).await?;
+        let sku = flower.sku().ok_or_else(|| FlowerError::sku_missing(id))?;
+
+        Ok(Label {
+            kind: LabelKind::Barcode,
+            data: sku.to_string(),
+        })
+    }
+
+    /// Render a label as a PNG image
+    pub fn render(&self, label: &Label) -> DomainResult<Vec<u8>> {
+        match label.kind {
+            LabelKind::QrCode => self.renderer.qr_code(&label.data),
+            LabelKind::Barcode => self.renderer.barcode(&label.data),
+        }
+    }
+}
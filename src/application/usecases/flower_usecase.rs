@@ -1,129 +1,1136 @@
 //! Flower Use Cases
 
+use std::future::Future;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::dtos::{CreateFlowerRequest, FlowerResponse, UpdateFlowerRequest};
-use crate::application::ports::FlowerRepository;
-use crate::domain::errors::{DomainResult, AppError};
-use crate::domain::flower::{Flower, FlowerError};
-use crate::domain::shared::{PaginatedResponse, Pagination};
+use crate::application::authorization::Subject;
+use crate::application::dtos::{
+    CreateFlowerRequest, FlowerFiltersResponse, FlowerResponse, PriceAdjustmentRequest,
+    PriceAdjustmentResponse, PriceChangeResponse, StockAdjustmentRequest, StockMovementResponse,
+    UpdateFlowerRequest,
+};
+use crate::application::money::MoneyDisplay;
+use crate::application::ports::{FlowerRepository, Transaction, UnitOfWork};
+use crate::application::usecases::Pricing;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{
+    Flower, FlowerAttributes, FlowerChange, FlowerColor, FlowerDescription, FlowerError,
+    FlowerMetadata, FlowerName, Price, PriceAdjustment, Sku, StockQuantity, UnitOfMeasure,
+};
+use crate::domain::inventory::StockMovement;
+use crate::domain::pricing::PricingRule;
+use crate::domain::shared::{Entity, PaginatedResponse, Pagination, TenantId};
 
-/// Use case for flower operations
-pub struct FlowerUseCase<R: FlowerRepository> {
+/// What `upsert_by_sku` did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    Created,
+    Updated,
+    /// The flower already matched the request
+    Unchanged,
+}
+
+/// Ledger reason of stock set through `create_flower` or `update_flower`
+pub const STOCK_UPDATE_REASON: &str = "Stock set by flower update";
+
+/// Ledger reason of stock set through `upsert_by_sku`
+pub const STOCK_SYNC_REASON: &str = "Stock set by SKU sync";
+
+/// Estimated totals below this are counted exactly instead; counting that
+/// few rows is cheap and small estimates are the least accurate
+pub const MIN_ESTIMATED_TOTAL: i64 = 10_000;
+
+/// Use case for flower operations, always scoped to the caller's tenant
+pub struct FlowerUseCase<R: FlowerRepository + ?Sized> {
     repository: Arc<R>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    pricing: Option<Arc<Pricing>>,
 }
 
-impl<R: FlowerRepository> FlowerUseCase<R> {
-    pub fn new(repository: Arc<R>) -> Self {
-        Self { repository }
+impl<R: FlowerRepository + ?Sized> FlowerUseCase<R> {
+    pub fn new(repository: Arc<R>, unit_of_work: Arc<dyn UnitOfWork>) -> Self {
+        Self {
+            repository,
+            unit_of_work,
+            pricing: None,
+        }
+    }
+
+    /// Serve prices adjusted by the pricing rules; without it flowers are
+    /// served at their base price
+    pub fn with_pricing(mut self, pricing: Arc<Pricing>) -> Self {
+        self.pricing = Some(pricing);
+        self
+    }
+
+    /// Repository the use case reads flowers from, cache included
+    pub fn repository(&self) -> Arc<R> {
+        self.repository.clone()
+    }
+
+    /// Transactions the use case changes flowers in, cache invalidation
+    /// included
+    pub fn unit_of_work(&self) -> Arc<dyn UnitOfWork> {
+        self.unit_of_work.clone()
     }
 
     /// Get a flower by ID
-    pub async fn get_flower(&self, id: Uuid) -> DomainResult<FlowerResponse> {
+    pub async fn get_flower(&self, tenant: &TenantId, id: Uuid) -> DomainResult<FlowerResponse> {
         let flower = self
             .repository
-            .find_by_id(id)
+            .find_by_id(tenant, id)
             .await?
             .ok_or_else(|| FlowerError::not_found(id))?;
 
-        Ok(FlowerResponse::from(flower))
+        let rules = self.pricing_rules(tenant).await?;
+        Ok(Pricing::respond(flower, &rules))
     }
 
     /// List all flowers with pagination
+    ///
+    /// The page and the total are queried concurrently. With `estimate` the
+    /// total may be the planner's estimate, see [`Self::total`].
     pub async fn list_flowers(
         &self,
+        tenant: &TenantId,
         pagination: Pagination,
+        estimate: bool,
     ) -> DomainResult<PaginatedResponse<FlowerResponse>> {
-        let flowers = self.repository.find_all(&pagination).await?;
-        let total = self.repository.count().await?;
+        let (flowers, (total, estimated), rules) = tokio::try_join!(
+            self.repository.find_all(tenant, &pagination),
+            self.total(
+                tenant,
+                None,
+                None,
+                None,
+                None,
+                estimate,
+                self.repository.count(tenant)
+            ),
+            self.pricing_rules(tenant),
+        )?;
 
-        let flower_responses: Vec<FlowerResponse> =
-            flowers.into_iter().map(FlowerResponse::from).collect();
+        let flower_responses: Vec<FlowerResponse> = flowers
+            .into_iter()
+            .map(|flower| Pricing::respond(flower, &rules))
+            .collect();
 
-        Ok(PaginatedResponse::new(flower_responses, total, &pagination))
+        let mut page = PaginatedResponse::new(flower_responses, total, &pagination);
+        page.total_estimated = estimated;
+        Ok(page)
     }
 
-    /// Search flowers
+    /// Search flowers, querying the page and the total concurrently
+    #[allow(clippy::too_many_arguments)]
     pub async fn search_flowers(
         &self,
+        tenant: &TenantId,
         query: Option<String>,
         color: Option<String>,
+        attributes: Option<FlowerAttributes>,
+        metadata: Option<FlowerMetadata>,
         pagination: Pagination,
+        estimate: bool,
     ) -> DomainResult<PaginatedResponse<FlowerResponse>> {
-        let flowers = self
-            .repository
-            .search(query.as_deref(), color.as_deref(), &pagination)
-            .await?;
-        let total = self
-            .repository
-            .count_search(query.as_deref(), color.as_deref())
-            .await?;
+        let color = color.map(|c| c.parse::<FlowerColor>()).transpose()?;
+        let query = query.as_deref();
+        let attributes = attributes.as_ref();
+        let metadata = metadata.as_ref();
+
+        let (flowers, (total, estimated), rules) = tokio::try_join!(
+            self.repository
+                .search(tenant, query, color, attributes, metadata, &pagination),
+            self.total(
+                tenant,
+                query,
+                color,
+                attributes,
+                metadata,
+                estimate,
+                self.repository
+                    .count_search(tenant, query, color, attributes, metadata),
+            ),
+            self.pricing_rules(tenant),
+        )?;
 
-        let flower_responses: Vec<FlowerResponse> =
-            flowers.into_iter().map(FlowerResponse::from).collect();
+        let flower_responses: Vec<FlowerResponse> = flowers
+            .into_iter()
+            .map(|flower| match query {
+                Some(term) => Pricing::respond(flower, &rules).highlighted(term),
+                None => Pricing::respond(flower, &rules),
+            })
+            .collect();
 
-        Ok(PaginatedResponse::new(flower_responses, total, &pagination))
+        let mut page = PaginatedResponse::new(flower_responses, total, &pagination);
+        page.total_estimated = estimated;
+        Ok(page)
+    }
+
+    /// Pricing rules the tenant's flowers are served under
+    async fn pricing_rules(&self, tenant: &TenantId) -> DomainResult<Vec<PricingRule>> {
+        match &self.pricing {
+            Some(pricing) => pricing.rules(tenant).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Total of a listing, and whether it is an estimate
+    ///
+    /// Estimates are only used when asked for and when the repository
+    /// estimates at least [`MIN_ESTIMATED_TOTAL`] flowers; otherwise `exact`
+    /// counts them.
+    #[allow(clippy::too_many_arguments)]
+    async fn total(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+        estimate: bool,
+        exact: impl Future<Output = DomainResult<i64>>,
+    ) -> DomainResult<(i64, bool)> {
+        if estimate
+            && let Some(total) = self
+                .repository
+                .estimate_count(tenant, query, color, attributes, metadata)
+                .await?
+            && total >= MIN_ESTIMATED_TOTAL
+        {
+            return Ok((total, true));
+        }
+        Ok((exact.await?, false))
+    }
+
+    /// List the supported flower colors
+    pub fn list_colors(&self) -> Vec<FlowerColor> {
+        FlowerColor::ALL.to_vec()
+    }
+
+    /// Colors and price range of the tenant's flowers, to filter them by
+    pub async fn filters(&self, tenant: &TenantId) -> DomainResult<FlowerFiltersResponse> {
+        let mut facets = self.repository.facets(tenant).await?;
+        facets
+            .colors
+            .sort_by_key(|color| FlowerColor::ALL.iter().position(|known| known == color));
+
+        Ok(FlowerFiltersResponse {
+            colors: facets.colors,
+            min_price: facets.min_price,
+            max_price: facets.max_price,
+        })
     }
 
     /// Create a new flower
+    ///
+    /// Its opening stock is recorded in the inventory ledger in the same
+    /// transaction.
     pub async fn create_flower(
         &self,
+        tenant: &TenantId,
         request: CreateFlowerRequest,
+    ) -> DomainResult<FlowerResponse> {
+        self.create(tenant, request, STOCK_UPDATE_REASON).await
+    }
+
+    /// Create a flower, recording its opening stock under `stock_reason`
+    async fn create(
+        &self,
+        tenant: &TenantId,
+        request: CreateFlowerRequest,
+        stock_reason: &str,
     ) -> DomainResult<FlowerResponse> {
         let flower = Flower::new(
-            request.name, 
-            request.color, 
-            request.description, 
-            request.price, 
-            request.stock
+            tenant.clone(),
+            FlowerName::new(request.name)?,
+            request.color.parse()?,
+            request
+                .description
+                .map(FlowerDescription::new)
+                .transpose()?
+                .flatten(),
+            Price::new(request.price)?,
+            StockQuantity::new(request.stock)?,
+            unit_of_measure(
+                UnitOfMeasure::default(),
+                request.unit,
+                request.stems_per_unit,
+            )?,
+            request.sku.map(Sku::new).transpose()?.flatten(),
+            request.attributes.unwrap_or_default(),
+            FlowerMetadata::new(request.metadata.unwrap_or_default())?,
         )?;
 
-        let created_flower = self.repository.create(&flower).await?;
-        Ok(FlowerResponse::from(created_flower))
+        let mut tx = self.unit_of_work.begin().await?;
+        let created_flower = tx.insert_flower(&flower).await?;
+        if created_flower.stock() != 0 {
+            let movement =
+                StockMovement::new(&created_flower, created_flower.stock(), stock_reason)?;
+            tx.record_stock_movement(&movement).await?;
+        }
+        tx.commit().await?;
+        let rules = self.pricing_rules(tenant).await?;
+        Ok(Pricing::respond(created_flower, &rules))
     }
 
     /// Update an existing flower
+    ///
+    /// The flower stays locked between reading and writing it, so concurrent
+    /// partial updates cannot overwrite each other's fields. Setting the
+    /// stock records the difference in the inventory ledger.
     pub async fn update_flower(
         &self,
+        tenant: &TenantId,
         id: Uuid,
         request: UpdateFlowerRequest,
     ) -> DomainResult<FlowerResponse> {
-        let mut flower = self
-            .repository
-            .find_by_id(id)
+        self.update(tenant, id, request, STOCK_UPDATE_REASON).await
+    }
+
+    /// Update a flower, recording a change of stock under `stock_reason`
+    async fn update(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+        request: UpdateFlowerRequest,
+        stock_reason: &str,
+    ) -> DomainResult<FlowerResponse> {
+        let mut tx = self.unit_of_work.begin().await?;
+        let existing = tx
+            .lock_flower(tenant, id)
             .await?
             .ok_or_else(|| FlowerError::not_found(id))?;
 
-        // Apply updates if provided
-        if let Some(name) = request.name {
-            flower.update_name(name)?;
+        let mut flower = existing.clone();
+        apply_update(&mut flower, request)?;
+
+        let updated_flower = save(tx.as_mut(), &existing, &flower).await?;
+        let delta = updated_flower.stock() - existing.stock();
+        if delta != 0 {
+            let movement = StockMovement::new(&updated_flower, delta, stock_reason)?;
+            tx.record_stock_movement(&movement).await?;
+        }
+        tx.commit().await?;
+        let rules = self.pricing_rules(tenant).await?;
+        Ok(Pricing::respond(updated_flower, &rules))
+    }
+
+    /// Update the flower with the given SKU, or create it if there is none
+    ///
+    /// Fields the request leaves out are kept on update, and a change of
+    /// stock is recorded in the inventory ledger; creating a flower
+    /// requires a name, color and price, and starts it without stock unless
+    /// the request sets some.
+    pub async fn upsert_by_sku(
+        &self,
+        tenant: &TenantId,
+        sku: Sku,
+        request: UpdateFlowerRequest,
+    ) -> DomainResult<UpsertOutcome> {
+        let Some(existing) = self.repository.find_by_sku(tenant, sku.as_str()).await? else {
+            let (Some(name), Some(color), Some(price)) =
+                (request.name, request.color, request.price)
+            else {
+                return Err(FlowerError::sku_incomplete(sku.as_str()));
+            };
+            self.create(
+                tenant,
+                CreateFlowerRequest {
+                    name,
+                    color,
+                    description: request.description,
+                    price,
+                    stock: request.stock.unwrap_or(0),
+                    unit: request.unit,
+                    stems_per_unit: request.stems_per_unit,
+                    sku: Some(sku.as_str().to_string()),
+                    attributes: request.attributes,
+                    metadata: request.metadata,
+                },
+                STOCK_SYNC_REASON,
+            )
+            .await?;
+            return Ok(UpsertOutcome::Created);
+        };
+
+        let mut updated = existing.clone();
+        apply_update(&mut updated, request.clone())?;
+        if content(&updated) == content(&existing) {
+            return Ok(UpsertOutcome::Unchanged);
         }
-        if let Some(color) = request.color {
-            flower.update_color(color)?;
+
+        self.update(tenant, existing.id(), request, STOCK_SYNC_REASON)
+            .await?;
+        Ok(UpsertOutcome::Updated)
+    }
+
+    /// Reprice every flower matching the filter in one transaction
+    ///
+    /// A dry run computes the same changes but rolls them back.
+    pub async fn adjust_prices(
+        &self,
+        tenant: &TenantId,
+        request: PriceAdjustmentRequest,
+    ) -> DomainResult<PriceAdjustmentResponse> {
+        let adjustment: PriceAdjustment = request.adjustment.parse()?;
+        let color = request
+            .filter
+            .color
+            .map(|c| c.parse::<FlowerColor>())
+            .transpose()?;
+        let ids = request.filter.ids;
+        if color.is_none() && ids.is_none() {
+            return Err(FlowerError::price_adjustment_unfiltered());
         }
-        if let Some(description) = request.description {
-            flower.update_description(Some(description));
+
+        let mut tx = self.unit_of_work.begin().await?;
+        let flowers = tx.lock_flowers(tenant, color, ids.as_deref()).await?;
+
+        let mut items = Vec::with_capacity(flowers.len());
+        for existing in flowers {
+            let old_price = existing.price();
+            let mut flower = existing.clone();
+            flower.update_price(adjustment.apply(old_price)?);
+            if !request.dry_run {
+                save(tx.as_mut(), &existing, &flower).await?;
+            }
+            items.push(PriceChangeResponse {
+                id: flower.id(),
+                name: flower.name().to_string(),
+                old_price,
+                old_price_display: MoneyDisplay::of(old_price),
+                new_price: flower.price(),
+                new_price_display: MoneyDisplay::of(flower.price()),
+            });
         }
-        if let Some(price) = request.price {
-            flower.update_price(price);
+
+        if !request.dry_run {
+            tx.commit().await?;
         }
-        if let Some(stock) = request.stock {
-            flower.update_stock(stock);
+        Ok(PriceAdjustmentResponse {
+            dry_run: request.dry_run,
+            items,
+        })
+    }
+
+    /// Add or remove stock, recording the movement in the inventory ledger
+    ///
+    /// The flower is updated and the movement recorded in one transaction.
+    pub async fn adjust_stock(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+        request: StockAdjustmentRequest,
+    ) -> DomainResult<StockMovementResponse> {
+        let mut tx = self.unit_of_work.begin().await?;
+        let existing = tx
+            .lock_flower(tenant, id)
+            .await?
+            .ok_or_else(|| FlowerError::not_found(id))?;
+
+        let mut flower = existing.clone();
+        if request.delta >= 0 {
+            flower.add_stock(request.delta)?;
+        } else {
+            flower.reduce_stock(request.delta.saturating_neg())?;
         }
+        let movement = StockMovement::new(&flower, request.delta, &request.reason)?;
 
-        let updated_flower = self.repository.update(&flower).await?;
-        Ok(FlowerResponse::from(updated_flower))
+        save(tx.as_mut(), &existing, &flower).await?;
+        tx.record_stock_movement(&movement).await?;
+        tx.commit().await?;
+        Ok(StockMovementResponse::from(movement))
     }
 
     /// Delete a flower
-    pub async fn delete_flower(&self, id: Uuid) -> DomainResult<()> {
+    pub async fn delete_flower(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
         // Check if flower exists
         self.repository
-            .find_by_id(id)
+            .find_by_id(tenant, id)
             .await?
             .ok_or_else(|| FlowerError::not_found(id))?;
 
-        self.repository.delete(id).await
+        self.repository.delete(tenant, id).await
+    }
+}
+
+/// Write `after` over `before` in `tx`, recording in the flower's history
+/// which fields changed and who changed them
+async fn save(tx: &mut dyn Transaction, before: &Flower, after: &Flower) -> DomainResult<Flower> {
+    let updated = tx.update_flower(after).await?;
+    let changes = FlowerChange::between(before, &updated, &Subject::current_actor());
+    if !changes.is_empty() {
+        tx.record_flower_changes(&changes).await?;
+    }
+    Ok(updated)
+}
+
+/// Apply the fields a partial update sets
+fn apply_update(flower: &mut Flower, request: UpdateFlowerRequest) -> DomainResult<()> {
+    if let Some(name) = request.name {
+        flower.update_name(FlowerName::new(name)?);
+    }
+    if let Some(color) = request.color {
+        flower.update_color(color.parse()?);
+    }
+    if let Some(description) = request.description {
+        flower.update_description(FlowerDescription::new(description)?);
+    }
+    if let Some(price) = request.price {
+        flower.update_price(Price::new(price)?);
+    }
+    if let Some(stock) = request.stock {
+        flower.update_stock(StockQuantity::new(stock)?);
+    }
+    if request.unit.is_some() || request.stems_per_unit.is_some() {
+        flower.update_unit(unit_of_measure(
+            flower.unit(),
+            request.unit,
+            request.stems_per_unit,
+        )?);
+    }
+    if let Some(sku) = request.sku {
+        flower.update_sku(Sku::new(sku)?);
+    }
+    if let Some(attributes) = request.attributes {
+        flower.update_attributes(attributes)?;
+    }
+    if let Some(metadata) = request.metadata {
+        flower.update_metadata(FlowerMetadata::new(metadata)?);
+    }
+    Ok(())
+}
+
+/// Unit a request asks for; `stems_per_unit` alone resizes the `current`
+/// unit, and neither keeps it
+fn unit_of_measure(
+    current: UnitOfMeasure,
+    unit: Option<String>,
+    stems_per_unit: Option<i32>,
+) -> DomainResult<UnitOfMeasure> {
+    if unit.is_none() && stems_per_unit.is_none() {
+        return Ok(current);
+    }
+    UnitOfMeasure::new(unit.as_deref().unwrap_or(current.as_str()), stems_per_unit)
+}
+
+/// Fields a flower is edited through, to tell whether an update changes anything
+#[allow(clippy::type_complexity)]
+fn content(
+    flower: &Flower,
+) -> (
+    &str,
+    FlowerColor,
+    Option<&str>,
+    f64,
+    i32,
+    UnitOfMeasure,
+    Option<&str>,
+    &FlowerAttributes,
+    &FlowerMetadata,
+) {
+    (
+        flower.name(),
+        flower.color(),
+        flower.description(),
+        flower.price(),
+        flower.stock(),
+        flower.unit(),
+        flower.sku(),
+        flower.attributes(),
+        flower.metadata(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dtos::PriceAdjustmentFilter;
+    use crate::application::ports::LedgerQuery;
+    use crate::application::ports::mocks::{FlowerCall, MockFlowerRepository};
+    use crate::infrastructure::storage::Storage;
+    use crate::test_support::FlowerBuilder;
+
+    fn flower(tenant: &TenantId, name: &str) -> Flower {
+        FlowerBuilder::new()
+            .with_tenant(tenant)
+            .with_name(name)
+            .with_description("Freshly cut")
+            .build()
+    }
+
+    fn mocked(
+        repository: MockFlowerRepository,
+    ) -> (
+        Arc<MockFlowerRepository>,
+        FlowerUseCase<MockFlowerRepository>,
+    ) {
+        let repository = Arc::new(repository);
+        let usecase = FlowerUseCase::new(repository.clone(), repository.clone());
+        (repository, usecase)
+    }
+
+    #[tokio::test]
+    async fn missing_flowers_are_not_found_and_left_alone() {
+        let tenant = TenantId::default();
+        let (repository, usecase) = mocked(MockFlowerRepository::new());
+        let id = Uuid::new_v4();
+
+        let error = usecase.get_flower(&tenant, id).await.unwrap_err();
+        assert_eq!(error.code(), "flower.not_found");
+        let error = usecase.delete_flower(&tenant, id).await.unwrap_err();
+        assert_eq!(error.code(), "flower.not_found");
+        let update = UpdateFlowerRequest {
+            name: None,
+            color: None,
+            description: None,
+            price: Some(1.0),
+            stock: None,
+            unit: None,
+            stems_per_unit: None,
+            sku: None,
+            attributes: None,
+            metadata: None,
+        };
+        let error = usecase
+            .update_flower(&tenant, id, update)
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "flower.not_found");
+
+        assert_eq!(
+            repository.calls(),
+            [
+                FlowerCall::FindById(id),
+                FlowerCall::FindById(id),
+                FlowerCall::Begin,
+                FlowerCall::LockFlower(id),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn flowers_of_other_tenants_are_not_found() {
+        let rose = flower(&TenantId::new("rose-shop").unwrap(), "Rose");
+        let (_, usecase) = mocked(MockFlowerRepository::new().with_flowers([rose.clone()]));
+
+        let error = usecase
+            .get_flower(&TenantId::default(), rose.id())
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "flower.not_found");
+    }
+
+    #[tokio::test]
+    async fn partial_updates_keep_fields_left_out() {
+        let tenant = TenantId::default();
+        let rose = flower(&tenant, "Rose");
+        let (repository, usecase) =
+            mocked(MockFlowerRepository::new().with_flowers([rose.clone()]));
+        let update = UpdateFlowerRequest {
+            name: None,
+            color: None,
+            description: Some(String::new()),
+            price: Some(30_000.0),
+            stock: None,
+            unit: None,
+            stems_per_unit: None,
+            sku: None,
+            attributes: None,
+            metadata: None,
+        };
+
+        let updated = usecase
+            .update_flower(&tenant, rose.id(), update)
+            .await
+            .unwrap();
+        assert_eq!(updated.name, "Rose");
+        assert_eq!(updated.color, FlowerColor::Red);
+        assert_eq!(updated.description, None);
+        assert_eq!(updated.price, 30_000.0);
+        assert_eq!(updated.stock, 5);
+        assert_eq!(
+            repository.calls(),
+            [
+                FlowerCall::Begin,
+                FlowerCall::LockFlower(rose.id()),
+                FlowerCall::UpdateInTransaction(rose.id()),
+                FlowerCall::RecordFlowerChanges(vec![
+                    "description".to_string(),
+                    "price".to_string()
+                ]),
+                FlowerCall::Commit,
+            ]
+        );
+        assert_eq!(repository.flowers()[0].price(), 30_000.0);
+    }
+
+    #[tokio::test]
+    async fn invalid_partial_updates_write_nothing() {
+        let tenant = TenantId::default();
+        let rose = flower(&tenant, "Rose");
+        let (repository, usecase) =
+            mocked(MockFlowerRepository::new().with_flowers([rose.clone()]));
+        let update = UpdateFlowerRequest {
+            name: Some("Rosa".to_string()),
+            color: None,
+            description: None,
+            price: Some(-1.0),
+            stock: None,
+            unit: None,
+            stems_per_unit: None,
+            sku: None,
+            attributes: None,
+            metadata: None,
+        };
+
+        assert!(
+            usecase
+                .update_flower(&tenant, rose.id(), update)
+                .await
+                .is_err()
+        );
+        assert!(!repository.calls().contains(&FlowerCall::Commit));
+        assert_eq!(repository.flowers()[0].name(), "Rose");
+    }
+
+    #[tokio::test]
+    async fn pages_are_counted_from_the_total() {
+        let tenant = TenantId::default();
+        for (total, per_page, pages) in [(0, 10, 0), (20, 10, 2), (23, 10, 3), (23, 100, 1)] {
+            let (repository, usecase) = mocked(MockFlowerRepository::new().with_total(total));
+            let pagination = Pagination::try_new(Some(3), Some(per_page)).unwrap();
+
+            let page = usecase
+                .list_flowers(&tenant, pagination, false)
+                .await
+                .unwrap();
+            assert_eq!(page.total, total);
+            assert_eq!(page.total_pages, pages, "{} flowers by {}", total, per_page);
+            assert_eq!((page.page, page.per_page), (3, per_page));
+            assert_eq!(
+                repository.calls(),
+                [FlowerCall::FindAll { page: 3, per_page }, FlowerCall::Count]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn estimates_replace_counts_only_on_large_catalogs() {
+        let tenant = TenantId::default();
+        let pagination = Pagination::default();
+        let page_call = FlowerCall::FindAll {
+            page: 1,
+            per_page: Pagination::DEFAULT_PER_PAGE,
+        };
+        let estimate_call = FlowerCall::EstimateCount {
+            query: None,
+            color: None,
+        };
+
+        let (repository, usecase) = mocked(
+            MockFlowerRepository::new()
+                .with_total(250_123)
+                .with_estimate(250_000),
+        );
+        let page = usecase
+            .list_flowers(&tenant, pagination.clone(), true)
+            .await
+            .unwrap();
+        assert_eq!((page.total, page.total_estimated), (250_000, true));
+        assert_eq!(
+            repository.calls(),
+            [page_call.clone(), estimate_call.clone()]
+        );
+
+        let page = usecase
+            .list_flowers(&tenant, pagination.clone(), false)
+            .await
+            .unwrap();
+        assert_eq!((page.total, page.total_estimated), (250_123, false));
+
+        let (repository, usecase) = mocked(
+            MockFlowerRepository::new()
+                .with_total(480)
+                .with_estimate(500),
+        );
+        let page = usecase
+            .list_flowers(&tenant, pagination.clone(), true)
+            .await
+            .unwrap();
+        assert_eq!((page.total, page.total_estimated), (480, false));
+        assert_eq!(
+            repository.calls(),
+            [page_call, estimate_call, FlowerCall::Count]
+        );
+
+        let (_, usecase) = mocked(MockFlowerRepository::new().with_total(480));
+        let page = usecase
+            .search_flowers(
+                &tenant,
+                Some("ros".to_string()),
+                None,
+                None,
+                None,
+                pagination,
+                true,
+            )
+            .await
+            .unwrap();
+        assert_eq!((page.total, page.total_estimated), (480, false));
+    }
+
+    #[tokio::test]
+    async fn searches_parse_the_color_before_querying() {
+        let tenant = TenantId::default();
+        let (repository, usecase) = mocked(MockFlowerRepository::new().with_total(1));
+
+        let error = usecase
+            .search_flowers(
+                &tenant,
+                None,
+                Some("green".to_string()),
+                None,
+                None,
+                Pagination::default(),
+                false,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "flower.color.unsupported");
+        assert!(repository.calls().is_empty());
+
+        usecase
+            .search_flowers(
+                &tenant,
+                Some("ros".to_string()),
+                Some("RED".to_string()),
+                None,
+                None,
+                Pagination::default(),
+                false,
+            )
+            .await
+            .unwrap();
+        let query = Some("ros".to_string());
+        let color = Some(FlowerColor::Red);
+        assert_eq!(
+            repository.calls(),
+            [
+                FlowerCall::Search {
+                    query: query.clone(),
+                    color,
+                    page: 1,
+                    per_page: Pagination::DEFAULT_PER_PAGE,
+                },
+                FlowerCall::CountSearch { query, color },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn storage_failures_are_passed_on() {
+        let tenant = TenantId::default();
+        let (repository, usecase) = mocked(MockFlowerRepository::new().failing());
+
+        let error = usecase
+            .list_flowers(&tenant, Pagination::default(), false)
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "internal_error");
+        let error = usecase
+            .adjust_stock(
+                &tenant,
+                Uuid::new_v4(),
+                StockAdjustmentRequest {
+                    delta: 1,
+                    reason: "Delivery".to_string(),
+                },
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "internal_error");
+        assert_eq!(repository.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn stock_adjustments_cannot_overdraw() {
+        let storage = Storage::in_memory();
+        let usecase = FlowerUseCase::new(storage.flowers, storage.unit_of_work);
+        let tenant = TenantId::default();
+        let rose = usecase
+            .create_flower(&tenant, FlowerBuilder::new().with_stock(5).create_request())
+            .await
+            .unwrap();
+        let adjustment = |delta: i32| StockAdjustmentRequest {
+            delta,
+            reason: "Stock count".to_string(),
+        };
+
+        let movement = usecase
+            .adjust_stock(&tenant, rose.id, adjustment(-3))
+            .await
+            .unwrap();
+        assert_eq!(movement.stock_after, 2);
+
+        let error = usecase
+            .adjust_stock(&tenant, rose.id, adjustment(-3))
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "flower.stock.insufficient");
+        let stored = usecase.get_flower(&tenant, rose.id).await.unwrap();
+        assert_eq!(stored.stock, 2);
+
+        let movement = usecase
+            .adjust_stock(&tenant, rose.id, adjustment(10))
+            .await
+            .unwrap();
+        assert_eq!(movement.stock_after, 12);
+    }
+
+    #[tokio::test]
+    async fn stock_set_by_updates_is_recorded_in_the_ledger() {
+        let storage = Storage::in_memory();
+        let ledger = storage.ledger.clone();
+        let usecase = FlowerUseCase::new(storage.flowers, storage.unit_of_work);
+        let tenant = TenantId::default();
+        let rose = usecase
+            .create_flower(
+                &tenant,
+                FlowerBuilder::new()
+                    .with_stock(4)
+                    .with_sku("ROSE-RED")
+                    .create_request(),
+            )
+            .await
+            .unwrap();
+        let stock = |stock: i32| UpdateFlowerRequest {
+            name: None,
+            color: None,
+            description: None,
+            price: None,
+            stock: Some(stock),
+            unit: None,
+            stems_per_unit: None,
+            sku: None,
+            attributes: None,
+            metadata: None,
+        };
+
+        usecase
+            .adjust_stock(
+                &tenant,
+                rose.id,
+                StockAdjustmentRequest {
+                    delta: 5,
+                    reason: "Delivery".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        usecase
+            .update_flower(&tenant, rose.id, stock(12))
+            .await
+            .unwrap();
+        let sku = Sku::new("ROSE-RED").unwrap().unwrap();
+        let outcome = usecase.upsert_by_sku(&tenant, sku, stock(9)).await.unwrap();
+        assert_eq!(outcome, UpsertOutcome::Updated);
+
+        let query = LedgerQuery {
+            tenant: Some(tenant.clone()),
+            flower_id: Some(rose.id),
+        };
+        let movements = ledger.find(&query, &Pagination::default()).await.unwrap();
+        let entries: Vec<_> = movements
+            .iter()
+            .map(|movement| (movement.delta(), movement.reason()))
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                (-3, STOCK_SYNC_REASON),
+                (3, STOCK_UPDATE_REASON),
+                (5, "Delivery"),
+                (4, STOCK_UPDATE_REASON)
+            ]
+        );
+        let stored = usecase.get_flower(&tenant, rose.id).await.unwrap();
+        assert_eq!(
+            movements.iter().map(StockMovement::delta).sum::<i32>(),
+            stored.stock
+        );
+    }
+
+    #[tokio::test]
+    async fn opening_stock_is_recorded_in_the_ledger() {
+        let storage = Storage::in_memory();
+        let ledger = storage.ledger.clone();
+        let usecase = FlowerUseCase::new(storage.flowers, storage.unit_of_work);
+        let tenant = TenantId::default();
+        let rose = usecase
+            .create_flower(&tenant, FlowerBuilder::new().with_stock(6).create_request())
+            .await
+            .unwrap();
+        let sku = Sku::new("TULIP-RED").unwrap().unwrap();
+        let outcome = usecase
+            .upsert_by_sku(
+                &tenant,
+                sku.clone(),
+                UpdateFlowerRequest {
+                    name: Some("Tulip".to_string()),
+                    color: Some("red".to_string()),
+                    description: None,
+                    price: Some(3.0),
+                    stock: Some(8),
+                    unit: None,
+                    stems_per_unit: None,
+                    sku: None,
+                    attributes: None,
+                    metadata: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(outcome, UpsertOutcome::Created);
+        let tulip = usecase
+            .repository
+            .find_by_sku(&tenant, sku.as_str())
+            .await
+            .unwrap()
+            .unwrap();
+
+        for (id, stock, reason) in [
+            (rose.id, 6, STOCK_UPDATE_REASON),
+            (tulip.id(), 8, STOCK_SYNC_REASON),
+        ] {
+            let query = LedgerQuery {
+                tenant: Some(tenant.clone()),
+                flower_id: Some(id),
+            };
+            let movements = ledger.find(&query, &Pagination::default()).await.unwrap();
+            assert!(movements.iter().all(|movement| movement.reason() == reason));
+            assert_eq!(
+                movements.iter().map(StockMovement::delta).sum::<i32>(),
+                stock
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn dry_run_price_adjustments_change_nothing() {
+        let storage = Storage::in_memory();
+        let usecase = FlowerUseCase::new(storage.flowers, storage.unit_of_work);
+        let tenant = TenantId::default();
+        for (name, color) in [("Rose", "red"), ("Tulip", "red"), ("Lily", "white")] {
+            let request = FlowerBuilder::new()
+                .with_name(name)
+                .with_color(color)
+                .with_price(20_000.0)
+                .create_request();
+            usecase.create_flower(&tenant, request).await.unwrap();
+        }
+        let adjustment = |dry_run: bool| PriceAdjustmentRequest {
+            filter: PriceAdjustmentFilter {
+                color: Some("red".to_string()),
+                ids: None,
+            },
+            adjustment: "+10%".to_string(),
+            dry_run,
+        };
+
+        let preview = usecase
+            .adjust_prices(&tenant, adjustment(true))
+            .await
+            .unwrap();
+        assert_eq!(preview.items.len(), 2);
+        assert!(preview.items.iter().all(|item| item.new_price == 22_000.0));
+        let red = usecase
+            .search_flowers(
+                &tenant,
+                None,
+                Some("red".to_string()),
+                None,
+                None,
+                Pagination::default(),
+                false,
+            )
+            .await
+            .unwrap();
+        assert!(red.data.iter().all(|flower| flower.price == 20_000.0));
+
+        usecase
+            .adjust_prices(&tenant, adjustment(false))
+            .await
+            .unwrap();
+        let all = usecase
+            .list_flowers(&tenant, Pagination::default(), false)
+            .await
+            .unwrap();
+        for flower in all.data {
+            let expected = if flower.color == FlowerColor::Red {
+                22_000.0
+            } else {
+                20_000.0
+            };
+            assert_eq!(flower.price, expected, "{}", flower.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn upserts_by_sku_create_then_update_changed_flowers() {
+        let storage = Storage::in_memory();
+        let usecase = FlowerUseCase::new(storage.flowers, storage.unit_of_work);
+        let tenant = TenantId::default();
+        let sku = || Sku::new("RO-12").unwrap().unwrap();
+        let request = |price: f64| UpdateFlowerRequest {
+            name: Some("Rose".to_string()),
+            color: Some("red".to_string()),
+            description: None,
+            price: Some(price),
+            stock: None,
+            unit: None,
+            stems_per_unit: None,
+            sku: None,
+            attributes: None,
+            metadata: None,
+        };
+
+        let outcomes = [
+            usecase
+                .upsert_by_sku(&tenant, sku(), request(20_000.0))
+                .await,
+            usecase
+                .upsert_by_sku(&tenant, sku(), request(20_000.0))
+                .await,
+            usecase
+                .upsert_by_sku(&tenant, sku(), request(24_000.0))
+                .await,
+        ];
+        assert_eq!(
+            outcomes.map(Result::unwrap),
+            [
+                UpsertOutcome::Created,
+                UpsertOutcome::Unchanged,
+                UpsertOutcome::Updated
+            ]
+        );
+        let all = usecase
+            .list_flowers(&tenant, Pagination::default(), false)
+            .await
+            .unwrap();
+        assert_eq!(all.data.len(), 1);
+        assert_eq!(all.data[0].price, 24_000.0);
+        assert_eq!(all.data[0].stock, 0);
+
+        let partial = UpdateFlowerRequest {
+            name: None,
+            ..request(1.0)
+        };
+        let error = usecase
+            .upsert_by_sku(&tenant, Sku::new("TU-1").unwrap().unwrap(), partial)
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "flower.sku.incomplete");
     }
 }
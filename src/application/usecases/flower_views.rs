@@ -0,0 +1,312 @@
+//! Flower View Statistics
+//!
+//! Views are counted in a per-instance buffer and flushed periodically, so a
+//! page view costs a map update instead of a database write. Flushes add to
+//! the stored totals, so every replica flushing its own buffer is correct.
+//! Views buffered when an instance crashes are lost.
+//!
+//! The same flush records which flowers each authenticated caller viewed
+//! last, so a storefront can show them again.
+
+use std::collections::HashMap;
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::application::authorization::Subject;
+use crate::application::dtos::{RecentlyViewedFlowerResponse, TrendingFlowerResponse};
+use crate::application::ports::{FlowerRepository, FlowerViewStore, RecentView, ViewCount};
+use crate::application::usecases::Pricing;
+use crate::domain::errors::{AppError, DomainResult, FieldError};
+use crate::domain::pricing::PricingRule;
+use crate::domain::shared::TenantId;
+use crate::i18n::Message;
+
+type Buffer = HashMap<(TenantId, Uuid, NaiveDate), i64>;
+type RecentBuffer = HashMap<(TenantId, String, Uuid), DateTime<Utc>>;
+
+/// Counts flower views and ranks flowers by them
+pub struct FlowerViews<R: FlowerRepository + ?Sized> {
+    repository: Arc<R>,
+    store: Arc<dyn FlowerViewStore>,
+    buffer: Mutex<Buffer>,
+    recent: Mutex<RecentBuffer>,
+    pricing: Option<Arc<Pricing>>,
+}
+
+impl<R: FlowerRepository + ?Sized> FlowerViews<R> {
+    pub fn new(repository: Arc<R>, store: Arc<dyn FlowerViewStore>) -> Self {
+        Self {
+            repository,
+            store,
+            buffer: Mutex::new(HashMap::new()),
+            recent: Mutex::new(HashMap::new()),
+            pricing: None,
+        }
+    }
+
+    /// Serve the flowers at the prices the pricing rules make
+    pub fn with_pricing(mut self, pricing: Arc<Pricing>) -> Self {
+        self.pricing = Some(pricing);
+        self
+    }
+
+    /// Count a view of a flower by `viewer`; anonymous views are counted but
+    /// not remembered as recently viewed
+    pub fn record(&self, tenant: &TenantId, viewer: &Subject, flower_id: Uuid) {
+        let now = Utc::now();
+        *self
+            .lock()
+            .entry((tenant.clone(), flower_id, now.date_naive()))
+            .or_default() += 1;
+
+        if *viewer != Subject::Anonymous {
+            self.lock_recent()
+                .insert((tenant.clone(), viewer.to_string(), flower_id), now);
+        }
+    }
+
+    /// Add the buffered views to the store, returning how many were flushed
+    ///
+    /// If the store fails, the views go back into the buffer for the next flush.
+    pub async fn flush(&self) -> DomainResult<i64> {
+        let flushed = self.flush_counts().await;
+        self.flush_recent().await?;
+        flushed
+    }
+
+    async fn flush_counts(&self) -> DomainResult<i64> {
+        let buffered = mem::take(&mut *self.lock());
+        if buffered.is_empty() {
+            return Ok(0);
+        }
+
+        let counts: Vec<ViewCount> = buffered
+            .iter()
+            .map(|((tenant, flower_id, day), views)| ViewCount {
+                tenant: tenant.clone(),
+                flower_id: *flower_id,
+                day: *day,
+                views: *views,
+            })
+            .collect();
+        if let Err(e) = self.store.add(&counts).await {
+            let mut buffer = self.lock();
+            for (key, views) in buffered {
+                *buffer.entry(key).or_default() += views;
+            }
+            return Err(e);
+        }
+
+        Ok(counts.iter().map(|count| count.views).sum())
+    }
+
+    async fn flush_recent(&self) -> DomainResult<()> {
+        let buffered = mem::take(&mut *self.lock_recent());
+        if buffered.is_empty() {
+            return Ok(());
+        }
+
+        let views: Vec<RecentView> = buffered
+            .iter()
+            .map(|((tenant, viewer, flower_id), viewed_at)| RecentView {
+                tenant: tenant.clone(),
+                viewer: viewer.clone(),
+                flower_id: *flower_id,
+                viewed_at: *viewed_at,
+            })
+            .collect();
+        if let Err(e) = self.store.add_recent(&views, Self::RECENT_KEPT).await {
+            let mut buffer = self.lock_recent();
+            for (key, viewed_at) in buffered {
+                let latest = buffer.entry(key).or_insert(viewed_at);
+                *latest = (*latest).max(viewed_at);
+            }
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    /// Recently viewed flowers kept per caller
+    pub const RECENT_KEPT: i64 = 20;
+
+    /// Flowers `viewer` viewed last, newest first
+    ///
+    /// Views reach the store when they are flushed, so the latest may take up
+    /// to one flush interval to show.
+    pub async fn recently_viewed(
+        &self,
+        tenant: &TenantId,
+        viewer: &Subject,
+    ) -> DomainResult<Vec<RecentlyViewedFlowerResponse>> {
+        let viewed = self
+            .store
+            .recently_viewed(tenant, &viewer.to_string(), Self::RECENT_KEPT)
+            .await?;
+        let rules = self.pricing_rules(tenant).await?;
+        let mut recent = Vec::with_capacity(viewed.len());
+        for (flower_id, viewed_at) in viewed {
+            // Flowers deleted since they were viewed drop out of the list
+            if let Some(flower) = self.repository.find_by_id(tenant, flower_id).await? {
+                recent.push(RecentlyViewedFlowerResponse {
+                    flower: Pricing::respond(flower, &rules),
+                    viewed_at,
+                });
+            }
+        }
+
+        Ok(recent)
+    }
+
+    /// Longest window trending flowers are ranked over, in days
+    pub const MAX_WINDOW_DAYS: u64 = 90;
+    /// Most trending flowers returned at once
+    pub const MAX_LIMIT: i64 = 50;
+
+    /// Most viewed flowers of a tenant over a window such as `7d` (the last
+    /// seven days, today included)
+    pub async fn trending(
+        &self,
+        tenant: &TenantId,
+        window: Option<&str>,
+        limit: Option<i64>,
+    ) -> DomainResult<Vec<TrendingFlowerResponse>> {
+        let days = window.map_or(Some(7), |window| {
+            window
+                .trim()
+                .strip_suffix('d')
+                .and_then(|days| days.parse::<u64>().ok())
+                .filter(|days| (1..=Self::MAX_WINDOW_DAYS).contains(days))
+        });
+        let limit = limit.unwrap_or(10);
+
+        let mut fields = Vec::new();
+        if days.is_none() {
+            fields.push(FieldError::new(
+                "window",
+                Message::new("trending.window.invalid").arg("max", Self::MAX_WINDOW_DAYS),
+            ));
+        }
+        if !(1..=Self::MAX_LIMIT).contains(&limit) {
+            fields.push(FieldError::new(
+                "limit",
+                Message::new("trending.limit.out_of_range").arg("max", Self::MAX_LIMIT),
+            ));
+        }
+        let Some(days) = days.filter(|_| fields.is_empty()) else {
+            return Err(AppError::unprocessable(
+                Message::new("trending.invalid"),
+                fields,
+            ));
+        };
+
+        let today = Utc::now().date_naive();
+        let since = today
+            .checked_sub_days(Days::new(days - 1))
+            .unwrap_or(NaiveDate::MIN);
+
+        let most_viewed = self.store.most_viewed(tenant, since, limit).await?;
+        let rules = self.pricing_rules(tenant).await?;
+        let mut trending = Vec::with_capacity(most_viewed.len());
+        for (flower_id, views) in most_viewed {
+            // Flowers deleted since they were viewed drop out of the ranking
+            if let Some(flower) = self.repository.find_by_id(tenant, flower_id).await? {
+                trending.push(TrendingFlowerResponse {
+                    flower: Pricing::respond(flower, &rules),
+                    views,
+                });
+            }
+        }
+
+        Ok(trending)
+    }
+
+    /// Pricing rules the tenant's flowers are served under
+    async fn pricing_rules(&self, tenant: &TenantId) -> DomainResult<Vec<PricingRule>> {
+        match &self.pricing {
+            Some(pricing) => pricing.rules(tenant).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Buffer> {
+        self.buffer.lock().expect("view buffer lock poisoned")
+    }
+
+    fn lock_recent(&self) -> std::sync::MutexGuard<'_, RecentBuffer> {
+        self.recent.lock().expect("view buffer lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::Entity;
+    use crate::infrastructure::storage::Storage;
+    use crate::test_support::FlowerBuilder;
+
+    #[tokio::test]
+    async fn flushed_views_rank_flowers() {
+        let storage = Storage::in_memory();
+        let views = FlowerViews::new(storage.flowers.clone(), storage.views.clone());
+        let tenant = TenantId::default();
+        let (rose, tulip) = (Uuid::new_v4(), Uuid::new_v4());
+
+        views.record(&tenant, &Subject::Anonymous, rose);
+        views.record(&tenant, &Subject::Anonymous, tulip);
+        views.record(&tenant, &Subject::Anonymous, tulip);
+        assert_eq!(views.flush().await.unwrap(), 3);
+        assert_eq!(views.flush().await.unwrap(), 0);
+
+        let ranked = storage
+            .views
+            .most_viewed(&tenant, Utc::now().date_naive(), 10)
+            .await
+            .unwrap();
+        assert_eq!(ranked, vec![(tulip, 2), (rose, 1)]);
+    }
+
+    #[tokio::test]
+    async fn remembers_what_each_caller_viewed_last() {
+        let storage = Storage::in_memory();
+        let views = FlowerViews::new(storage.flowers.clone(), storage.views.clone());
+        let tenant = TenantId::default();
+        let kiosk = Subject::Tenant(tenant.clone());
+        let (rose, tulip) = (
+            FlowerBuilder::new().with_name("Rose").build(),
+            FlowerBuilder::new().with_name("Tulip").build(),
+        );
+        for flower in [&rose, &tulip] {
+            storage.flowers.create(flower).await.unwrap();
+        }
+
+        views.record(&tenant, &kiosk, rose.id());
+        views.record(&tenant, &Subject::Anonymous, tulip.id());
+        views.flush().await.unwrap();
+        views.record(&tenant, &kiosk, tulip.id());
+        views.record(&tenant, &Subject::Admin, rose.id());
+        views.flush().await.unwrap();
+        storage.flowers.delete(&tenant, rose.id()).await.unwrap();
+        views.record(&tenant, &kiosk, rose.id());
+        views.flush().await.unwrap();
+
+        let names = |viewed: Vec<RecentlyViewedFlowerResponse>| -> Vec<String> {
+            viewed.into_iter().map(|view| view.flower.name).collect()
+        };
+        let kiosk_viewed = views.recently_viewed(&tenant, &kiosk).await.unwrap();
+        assert_eq!(names(kiosk_viewed), ["Tulip"]);
+        let anonymous = views.recently_viewed(&tenant, &Subject::Anonymous);
+        assert!(anonymous.await.unwrap().is_empty());
+
+        let kept = storage
+            .views
+            .recently_viewed(&tenant, &kiosk.to_string(), 10)
+            .await
+            .unwrap();
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0].0, rose.id());
+    }
+}
@@ -1,3 +1,41 @@
+pub mod administration;
+pub mod backups;
+pub mod catalog_exports;
+pub mod delivery_zones;
+pub mod emails;
+pub mod feature_flags;
+pub mod flower_changes;
+pub mod flower_labels;
 pub mod flower_usecase;
+pub mod flower_views;
+pub mod orders;
+pub mod pricing;
+pub mod saved_searches;
+pub mod seed;
+pub mod shipping;
+pub mod stores;
+pub mod supplier_sync;
+pub mod tasks;
+pub mod usage_metering;
+pub mod webhooks;
 
-pub use flower_usecase::FlowerUseCase;
+pub use administration::Administration;
+pub use backups::Backups;
+pub use catalog_exports::CatalogExports;
+pub use delivery_zones::DeliveryZones;
+pub use emails::Emails;
+pub use feature_flags::FeatureFlags;
+pub use flower_changes::FlowerChanges;
+pub use flower_labels::{FlowerLabels, Label, LabelKind};
+pub use flower_usecase::{FlowerUseCase, UpsertOutcome};
+pub use flower_views::FlowerViews;
+pub use orders::Orders;
+pub use pricing::Pricing;
+pub use saved_searches::SavedSearches;
+pub use seed::{SeedReport, Seeder};
+pub use shipping::Shipping;
+pub use stores::Stores;
+pub use supplier_sync::{Supplier, SupplierSync};
+pub use tasks::Tasks;
+pub use usage_metering::{QuotaStatus, UsageMetering};
+pub use webhooks::Webhooks;
@@ -0,0 +1,583 @@
+//! Orders
+//!
+//! Placing an order reserves its flowers: their stock goes down, with a
+//! ledger movement, in the same transaction that saves the order. Lines
+//! count the units flowers are sold by, and take their stems from stock.
+//! Flowers are charged the price the pricing rules make at that moment. From then
+//! on the order only moves through the transitions of its status, each
+//! recorded as an event, and sent to the tenant's webhook endpoints as an
+//! `order.status_changed` event once committed. Cancelling gives the stock
+//! back the same way, and refunds a paid order through the payment gateway,
+//! as does a payment that arrives after its order was cancelled. Refunds
+//! are recorded as events too, sent as `order.refunded`.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::application::authorization::Subject;
+use crate::application::dtos::{
+    CreateOrderRequest, OrderEventResponse, OrderResponse, OrderShippingRequest,
+    UpdateOrderStatusRequest,
+};
+use crate::application::ports::{
+    OrderRepository, PaymentGateway, Refund, Shipment, Transaction, UnitOfWork,
+};
+use crate::application::usecases::{Pricing, Shipping, Webhooks};
+use crate::domain::delivery::ShippingRate;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerChange, FlowerError};
+use crate::domain::inventory::StockMovement;
+use crate::domain::order::{Order, OrderError, OrderEvent, OrderLine, OrderRefund, OrderStatus};
+use crate::domain::shared::{Entity, TenantId};
+use crate::domain::store::GeoPoint;
+
+/// Event type of order status changes sent to webhook endpoints
+pub const ORDER_STATUS_CHANGED_EVENT: &str = "order.status_changed";
+
+/// Event type of refunds sent to webhook endpoints
+pub const ORDER_REFUNDED_EVENT: &str = "order.refunded";
+
+/// Places orders and moves them through fulfilment
+pub struct Orders {
+    orders: Arc<dyn OrderRepository>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    shipping: Arc<Shipping>,
+    pricing: Arc<Pricing>,
+    payments: Arc<dyn PaymentGateway>,
+    restocking_fee_percent: u8,
+    webhooks: Option<Arc<Webhooks>>,
+}
+
+impl Orders {
+    pub fn new(
+        orders: Arc<dyn OrderRepository>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+        shipping: Arc<Shipping>,
+        pricing: Arc<Pricing>,
+        payments: Arc<dyn PaymentGateway>,
+        restocking_fee_percent: u8,
+    ) -> Self {
+        Self {
+            orders,
+            unit_of_work,
+            shipping,
+            pricing,
+            payments,
+            restocking_fee_percent,
+            webhooks: None,
+        }
+    }
+
+    /// Tell tenants' webhook endpoints when their orders change status
+    pub fn with_webhooks(mut self, webhooks: Arc<Webhooks>) -> Self {
+        self.webhooks = Some(webhooks);
+        self
+    }
+
+    /// Place a pending order, reserving the stock of its flowers
+    pub async fn place(
+        &self,
+        tenant: &TenantId,
+        request: CreateOrderRequest,
+    ) -> DomainResult<OrderResponse> {
+        if request.lines.is_empty() {
+            return Err(OrderError::lines_empty());
+        }
+        if request.lines.len() > Order::MAX_LINES {
+            return Err(OrderError::too_many_lines(Order::MAX_LINES));
+        }
+        let mut seen = HashSet::new();
+        if let Some(line) = request
+            .lines
+            .iter()
+            .find(|line| !seen.insert(line.flower_id))
+        {
+            return Err(OrderError::duplicate_line(line.flower_id));
+        }
+
+        // Quoted before locking anything: couriers may take seconds to answer
+        let shipping = match &request.shipping {
+            Some(choice) => Some(self.shipping_rate(tenant, choice).await?),
+            None => None,
+        };
+        let rules = self.pricing.rules(tenant).await?;
+
+        let actor = Subject::current_actor();
+        let ids: Vec<Uuid> = request.lines.iter().map(|line| line.flower_id).collect();
+        let mut tx = self.unit_of_work.begin().await?;
+        let flowers = tx.lock_flowers(tenant, None, Some(&ids)).await?;
+
+        let mut lines = Vec::with_capacity(request.lines.len());
+        let mut reserved = Vec::with_capacity(request.lines.len());
+        for requested in &request.lines {
+            let existing = flowers
+                .iter()
+                .find(|flower| flower.id() == requested.flower_id)
+                .ok_or_else(|| FlowerError::not_found(requested.flower_id))?;
+            let unit_price = Pricing::price(existing, &rules).price();
+            let line = OrderLine::new(existing, unit_price, requested.quantity)?;
+            let mut flower = existing.clone();
+            flower.reduce_stock(line.stems())?;
+            lines.push(line);
+            reserved.push((existing, flower));
+        }
+
+        let mut order = Order::place(tenant.clone(), lines, shipping, &actor)?;
+        let reason = format!("order {}", order.id());
+        for (before, after) in reserved {
+            save_stock(tx.as_mut(), before, &after, &actor, &reason).await?;
+        }
+        let events = order.take_events();
+        tx.insert_order(&order).await?;
+        tx.record_order_events(&events).await?;
+        tx.commit().await?;
+
+        self.announce(&events).await;
+        Ok(order.into())
+    }
+
+    pub async fn get(&self, tenant: &TenantId, id: Uuid) -> DomainResult<OrderResponse> {
+        let order = self
+            .orders
+            .find_by_id(tenant, id)
+            .await?
+            .ok_or_else(|| OrderError::not_found(id))?;
+        Ok(order.into())
+    }
+
+    /// Status changes of an order, oldest first
+    pub async fn events(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<Vec<OrderEventResponse>> {
+        self.orders
+            .find_by_id(tenant, id)
+            .await?
+            .ok_or_else(|| OrderError::not_found(id))?;
+        let events = self.orders.events(tenant, id).await?;
+        Ok(events.into_iter().map(OrderEventResponse::from).collect())
+    }
+
+    /// Move an order to the next fulfilment status
+    pub async fn update_status(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+        request: UpdateOrderStatusRequest,
+    ) -> DomainResult<OrderResponse> {
+        let status: OrderStatus = request.status.parse()?;
+        let actor = Subject::current_actor();
+
+        let mut tx = self.unit_of_work.begin().await?;
+        let mut order = tx
+            .lock_order(tenant, id)
+            .await?
+            .ok_or_else(|| OrderError::not_found(id))?;
+        match status {
+            OrderStatus::Paid => order.pay(&actor)?,
+            OrderStatus::Packed => order.pack(&actor)?,
+            OrderStatus::Shipped => order.ship(&actor)?,
+            OrderStatus::Delivered => order.deliver(&actor)?,
+            OrderStatus::Pending | OrderStatus::Cancelled => {
+                return Err(OrderError::status_not_settable(status));
+            }
+        }
+        let events = order.take_events();
+        tx.update_order(&order).await?;
+        tx.record_order_events(&events).await?;
+        tx.commit().await?;
+
+        self.announce(&events).await;
+        Ok(order.into())
+    }
+
+    /// Mark a pending order paid, as reported by the payment provider
+    ///
+    /// Providers may report a payment more than once, so an order that is
+    /// already paid, or further along, is left as it is. A payment for an
+    /// order cancelled before it was paid is refunded in full, as its
+    /// flowers are already back in stock; the order stays cancelled. The
+    /// payment `reference` is logged for staff to reconcile.
+    pub async fn record_payment(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+        reference: &str,
+        actor: &str,
+    ) -> DomainResult<OrderResponse> {
+        let mut tx = self.unit_of_work.begin().await?;
+        let mut order = tx
+            .lock_order(tenant, id)
+            .await?
+            .ok_or_else(|| OrderError::not_found(id))?;
+        match order.status() {
+            OrderStatus::Pending => order.pay(actor)?,
+            OrderStatus::Cancelled if order.paid_at().is_none() && order.refund().is_none() => {
+                return self.refund_late_payment(tx, order, reference, actor).await;
+            }
+            _ => return Ok(order.into()),
+        }
+        let events = order.take_events();
+        tx.update_order(&order).await?;
+        tx.record_order_events(&events).await?;
+        tx.commit().await?;
+
+        tracing::info!(order_id = %id, reference, "Order paid");
+        self.announce(&events).await;
+        Ok(order.into())
+    }
+
+    /// Give back a payment that arrived after its order was cancelled
+    ///
+    /// Like `cancel`, the refund is asked for before the transaction
+    /// commits, so a failed refund is asked for again when the provider
+    /// retries. The refund is keyed on the order and the payment, so asking
+    /// again after a refund whose commit failed moves no money twice.
+    async fn refund_late_payment(
+        &self,
+        mut tx: Box<dyn Transaction>,
+        mut order: Order,
+        reference: &str,
+        actor: &str,
+    ) -> DomainResult<OrderResponse> {
+        let refund = Refund::of_payment(
+            order.tenant_id().clone(),
+            order.id(),
+            order.total(),
+            reference,
+        );
+        let refund_reference = self.payments.refund(&refund).await?;
+        tracing::warn!(
+            order_id = %order.id(),
+            gateway = self.payments.name(),
+            amount = refund.amount,
+            reference,
+            refund_reference = %refund_reference,
+            "Payment for a cancelled order refunded"
+        );
+        order.refunded(
+            OrderRefund::new(refund.amount, 0.0, refund_reference),
+            actor,
+        );
+        let events = order.take_events();
+        tx.update_order(&order).await?;
+        tx.record_order_events(&events).await?;
+        tx.commit().await?;
+
+        self.announce(&events).await;
+        Ok(order.into())
+    }
+
+    /// Cancel an order before it ships, putting its flowers back in stock
+    ///
+    /// A paid order is refunded what was paid less the restocking fee. The
+    /// refund is asked for before the transaction commits, so an order whose
+    /// refund failed stays as it was; asking again is safe, as the refund is
+    /// keyed on the order and gateways make each key's refund at most once.
+    pub async fn cancel(&self, tenant: &TenantId, id: Uuid) -> DomainResult<OrderResponse> {
+        let actor = Subject::current_actor();
+
+        let mut tx = self.unit_of_work.begin().await?;
+        let mut order = tx
+            .lock_order(tenant, id)
+            .await?
+            .ok_or_else(|| OrderError::not_found(id))?;
+        order.cancel(&actor)?;
+
+        let ids: Vec<Uuid> = order.lines().iter().map(OrderLine::flower_id).collect();
+        let flowers = tx.lock_flowers(tenant, None, Some(&ids)).await?;
+        let reason = format!("order {} cancelled", order.id());
+        for line in order.lines() {
+            // Flowers deleted since the order was placed have no stock to return
+            let Some(before) = flowers
+                .iter()
+                .find(|flower| flower.id() == line.flower_id())
+            else {
+                continue;
+            };
+            let mut flower = before.clone();
+            flower.add_stock(line.stems())?;
+            save_stock(tx.as_mut(), before, &flower, &actor, &reason).await?;
+        }
+
+        if let Some(amount) = order.refund_due(self.restocking_fee_percent) {
+            let refund = Refund::of_order(tenant.clone(), order.id(), amount);
+            let reference = self.payments.refund(&refund).await?;
+            tracing::info!(
+                order_id = %order.id(),
+                gateway = self.payments.name(),
+                amount,
+                reference = %reference,
+                "Order refunded"
+            );
+            let restocking_fee = order.restocking_fee(self.restocking_fee_percent);
+            order.refunded(OrderRefund::new(amount, restocking_fee, reference), &actor);
+        }
+
+        let events = order.take_events();
+        tx.update_order(&order).await?;
+        tx.record_order_events(&events).await?;
+        tx.commit().await?;
+
+        self.announce(&events).await;
+        Ok(order.into())
+    }
+
+    /// The rate of the carrier service picked at checkout, quoted afresh
+    async fn shipping_rate(
+        &self,
+        tenant: &TenantId,
+        choice: &OrderShippingRequest,
+    ) -> DomainResult<ShippingRate> {
+        let shipment = Shipment {
+            tenant: tenant.clone(),
+            destination: GeoPoint::new(choice.destination.latitude, choice.destination.longitude)?,
+            weight_grams: choice
+                .weight_grams
+                .unwrap_or(Shipping::DEFAULT_WEIGHT_GRAMS),
+        };
+        self.shipping
+            .rates(&shipment)
+            .await?
+            .into_iter()
+            .find(|rate| rate.carrier() == choice.carrier && rate.service() == choice.service)
+            .ok_or_else(|| OrderError::shipping_unavailable(&choice.carrier, &choice.service))
+    }
+
+    /// Log and count committed status changes, and queue them for the
+    /// tenant's webhook endpoints
+    ///
+    /// The changes are committed already, so failing to queue them is
+    /// logged rather than failing the request.
+    async fn announce(&self, events: &[OrderEvent]) {
+        record_transitions(events);
+        let Some(webhooks) = &self.webhooks else {
+            return;
+        };
+        for event in events {
+            let (event_type, data) = match event.refunded() {
+                Some(amount) => (
+                    ORDER_REFUNDED_EVENT,
+                    json!({
+                        "order_id": event.order_id(),
+                        "status": event.to().as_str(),
+                        "amount": amount,
+                        "actor": event.actor(),
+                        "occurred_at": event.occurred_at(),
+                    }),
+                ),
+                None => (
+                    ORDER_STATUS_CHANGED_EVENT,
+                    json!({
+                        "order_id": event.order_id(),
+                        "from": event.from().map(OrderStatus::as_str),
+                        "to": event.to().as_str(),
+                        "actor": event.actor(),
+                        "occurred_at": event.occurred_at(),
+                    }),
+                ),
+            };
+            if let Err(e) = webhooks.publish(event.tenant_id(), event_type, data).await {
+                tracing::error!(
+                    order_id = %event.order_id(),
+                    error = %e,
+                    "Failed to queue order webhooks"
+                );
+            }
+        }
+    }
+}
+
+/// Save a flower's new stock with its change history and ledger movement
+async fn save_stock(
+    tx: &mut dyn Transaction,
+    before: &Flower,
+    after: &Flower,
+    actor: &str,
+    reason: &str,
+) -> DomainResult<()> {
+    let updated = tx.update_flower(after).await?;
+    let changes = FlowerChange::between(before, &updated, actor);
+    if !changes.is_empty() {
+        tx.record_flower_changes(&changes).await?;
+    }
+    let delta = updated.stock() - before.stock();
+    tx.record_stock_movement(&StockMovement::new(&updated, delta, reason)?)
+        .await
+}
+
+/// Log and count committed status changes
+fn record_transitions(events: &[OrderEvent]) {
+    for event in events.iter().filter(|event| event.refunded().is_none()) {
+        metrics::counter!("order_transitions_total", "to" => event.to().as_str()).increment(1);
+        tracing::info!(
+            order_id = %event.order_id(),
+            tenant = %event.tenant_id(),
+            from = event.from().map(OrderStatus::as_str),
+            to = event.to().as_str(),
+            actor = event.actor(),
+            "Order status changed"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dtos::OrderLineRequest;
+    use crate::domain::errors::AppError;
+    use crate::infrastructure::payments::ConsolePaymentGateway;
+    use crate::infrastructure::storage::Storage;
+    use crate::test_support::FlowerBuilder;
+
+    async fn stock_of(storage: &Storage, flower: &Flower) -> i32 {
+        let stored = storage.flowers.find_by_id(flower.tenant_id(), flower.id());
+        stored.await.unwrap().unwrap().stock()
+    }
+
+    fn order_of(flower_id: Uuid, quantity: i32) -> CreateOrderRequest {
+        CreateOrderRequest {
+            lines: vec![OrderLineRequest {
+                flower_id,
+                quantity,
+            }],
+            shipping: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn orders_reserve_stock_and_advance_one_step_at_a_time() {
+        let storage = Storage::in_memory();
+        let orders = Orders::new(
+            storage.orders.clone(),
+            storage.unit_of_work.clone(),
+            Arc::new(Shipping::new(Vec::new())),
+            Arc::new(Pricing::new(storage.pricing_rules.clone())),
+            Arc::new(ConsolePaymentGateway),
+            10,
+        );
+        let rose = FlowerBuilder::new()
+            .with_stock(5)
+            .persisted(storage.flowers.as_ref())
+            .await;
+
+        let order = orders
+            .place(rose.tenant_id(), order_of(rose.id(), 3))
+            .await
+            .unwrap();
+        assert_eq!(order.status, OrderStatus::Pending);
+        assert_eq!(order.total, 75_000.0);
+        assert_eq!(stock_of(&storage, &rose).await, 2);
+
+        let oversold = orders.place(rose.tenant_id(), order_of(rose.id(), 3)).await;
+        assert!(matches!(oversold, Err(AppError::Unprocessable { .. })));
+        assert_eq!(stock_of(&storage, &rose).await, 2);
+
+        let status = |status: &str| UpdateOrderStatusRequest {
+            status: status.to_string(),
+        };
+        let skipped = orders
+            .update_status(rose.tenant_id(), order.id, status("shipped"))
+            .await;
+        assert!(matches!(skipped, Err(AppError::Conflict { .. })));
+        let paid = orders
+            .update_status(rose.tenant_id(), order.id, status("paid"))
+            .await
+            .unwrap();
+        assert!(paid.paid_at.is_some());
+
+        let events = orders.events(rose.tenant_id(), order.id).await.unwrap();
+        let steps: Vec<_> = events.iter().map(|event| (event.from, event.to)).collect();
+        assert_eq!(
+            steps,
+            [
+                (None, OrderStatus::Pending),
+                (Some(OrderStatus::Pending), OrderStatus::Paid)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelling_returns_the_stock_and_refunds_paid_orders() {
+        let storage = Storage::in_memory();
+        let orders = Orders::new(
+            storage.orders.clone(),
+            storage.unit_of_work.clone(),
+            Arc::new(Shipping::new(Vec::new())),
+            Arc::new(Pricing::new(storage.pricing_rules.clone())),
+            Arc::new(ConsolePaymentGateway),
+            10,
+        );
+        let rose = FlowerBuilder::new()
+            .with_stock(5)
+            .persisted(storage.flowers.as_ref())
+            .await;
+        let tenant = rose.tenant_id();
+
+        let unpaid = orders.place(tenant, order_of(rose.id(), 2)).await.unwrap();
+        let cancelled = orders.cancel(tenant, unpaid.id).await.unwrap();
+        assert_eq!(cancelled.status, OrderStatus::Cancelled);
+        assert!(cancelled.refund.is_none());
+        assert_eq!(stock_of(&storage, &rose).await, 5);
+
+        let paid = orders.place(tenant, order_of(rose.id(), 3)).await.unwrap();
+        let status = UpdateOrderStatusRequest {
+            status: "paid".to_string(),
+        };
+        orders.update_status(tenant, paid.id, status).await.unwrap();
+        let cancelled = orders.cancel(tenant, paid.id).await.unwrap();
+        let refund = cancelled.refund.unwrap();
+        assert_eq!(refund.restocking_fee, 7_500.0);
+        assert_eq!(refund.amount, 67_500.0);
+        assert_eq!(stock_of(&storage, &rose).await, 5);
+
+        let again = orders.cancel(tenant, paid.id).await;
+        assert!(matches!(again, Err(AppError::Conflict { .. })));
+        assert_eq!(stock_of(&storage, &rose).await, 5);
+    }
+
+    #[tokio::test]
+    async fn payments_for_cancelled_orders_are_refunded_in_full() {
+        let storage = Storage::in_memory();
+        let orders = Orders::new(
+            storage.orders.clone(),
+            storage.unit_of_work.clone(),
+            Arc::new(Shipping::new(Vec::new())),
+            Arc::new(Pricing::new(storage.pricing_rules.clone())),
+            Arc::new(ConsolePaymentGateway),
+            10,
+        );
+        let rose = FlowerBuilder::new()
+            .with_stock(5)
+            .persisted(storage.flowers.as_ref())
+            .await;
+        let tenant = rose.tenant_id();
+
+        let order = orders.place(tenant, order_of(rose.id(), 2)).await.unwrap();
+        orders.cancel(tenant, order.id).await.unwrap();
+
+        let late = orders
+            .record_payment(tenant, order.id, "pay-1", "payments")
+            .await
+            .unwrap();
+        assert_eq!(late.status, OrderStatus::Cancelled);
+        assert!(late.paid_at.is_none());
+        let refund = late.refund.unwrap();
+        assert_eq!(refund.amount, 50_000.0);
+        assert_eq!(refund.restocking_fee, 0.0);
+        assert_eq!(stock_of(&storage, &rose).await, 5);
+
+        // Retried notifications are not refunded twice
+        let retried = orders
+            .record_payment(tenant, order.id, "pay-1", "payments")
+            .await
+            .unwrap();
+        assert_eq!(retried.refund.unwrap().reference, refund.reference);
+        let events = orders.events(tenant, order.id).await.unwrap();
+        assert_eq!(events.last().unwrap().to, OrderStatus::Cancelled);
+    }
+}
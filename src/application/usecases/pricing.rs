@@ -0,0 +1,158 @@
+//! Pricing
+//!
+//! Rules adjusting flower prices on demand: a markup while stock runs low,
+//! or over a date range such as Valentine's week. Rules are applied when a
+//! price is served and never written to the flower, so its base price is
+//! what the ledger and reports keep seeing.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::application::dtos::{FlowerResponse, PricingRuleRequest, PricingRuleResponse};
+use crate::application::ports::PricingRuleRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::Flower;
+use crate::domain::pricing::{EffectivePrice, PricingRule, PricingRuleError};
+use crate::domain::shared::TenantId;
+
+/// Manages pricing rules and prices flowers with them
+pub struct Pricing {
+    rules: Arc<dyn PricingRuleRepository>,
+}
+
+impl Pricing {
+    pub fn new(rules: Arc<dyn PricingRuleRepository>) -> Self {
+        Self { rules }
+    }
+
+    pub async fn create(
+        &self,
+        tenant: &TenantId,
+        request: PricingRuleRequest,
+    ) -> DomainResult<PricingRuleResponse> {
+        let rule = PricingRule::new(
+            tenant.clone(),
+            &request.name,
+            request.adjustment_percent,
+            request.conditions(),
+            request.enabled.unwrap_or(true),
+        )?;
+
+        let created = self.rules.create(&rule).await?;
+        Ok(created.into())
+    }
+
+    /// Pricing rules of a tenant, by name
+    pub async fn list(&self, tenant: &TenantId) -> DomainResult<Vec<PricingRuleResponse>> {
+        let rules = self.rules.find_all(tenant).await?;
+        Ok(rules.into_iter().map(PricingRuleResponse::from).collect())
+    }
+
+    pub async fn get(&self, tenant: &TenantId, id: Uuid) -> DomainResult<PricingRuleResponse> {
+        let rule = self
+            .rules
+            .find_by_id(tenant, id)
+            .await?
+            .ok_or_else(|| PricingRuleError::not_found(id))?;
+        Ok(rule.into())
+    }
+
+    /// Replace what a rule does and when it applies
+    pub async fn update(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+        request: PricingRuleRequest,
+    ) -> DomainResult<PricingRuleResponse> {
+        let mut rule = self
+            .rules
+            .find_by_id(tenant, id)
+            .await?
+            .ok_or_else(|| PricingRuleError::not_found(id))?;
+        rule.update(
+            &request.name,
+            request.adjustment_percent,
+            request.conditions(),
+            request.enabled.unwrap_or(true),
+        )?;
+
+        if !self.rules.update(&rule).await? {
+            return Err(PricingRuleError::not_found(id));
+        }
+        Ok(rule.into())
+    }
+
+    pub async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
+        if self.rules.delete(tenant, id).await? {
+            Ok(())
+        } else {
+            Err(PricingRuleError::not_found(id))
+        }
+    }
+
+    /// Enabled rules of a tenant, to price its flowers with
+    pub async fn rules(&self, tenant: &TenantId) -> DomainResult<Vec<PricingRule>> {
+        let mut rules = self.rules.find_all(tenant).await?;
+        rules.retain(PricingRule::enabled);
+        Ok(rules)
+    }
+
+    /// Price of `flower` right now under `rules`
+    pub fn price(flower: &Flower, rules: &[PricingRule]) -> EffectivePrice {
+        EffectivePrice::of(flower, rules, Utc::now())
+    }
+
+    /// `flower` as served, priced under `rules`
+    pub fn respond(flower: Flower, rules: &[PricingRule]) -> FlowerResponse {
+        let price = Self::price(&flower, rules);
+        FlowerResponse::from(flower).priced(price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::memory::InMemoryPricingRuleRepository;
+    use crate::test_support::FlowerBuilder;
+
+    fn request(name: &str, adjustment_percent: f64, max_stock: Option<i32>) -> PricingRuleRequest {
+        PricingRuleRequest {
+            name: name.to_string(),
+            adjustment_percent,
+            max_stock,
+            starts_at: None,
+            ends_at: None,
+            enabled: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn disabled_rules_stop_adjusting_prices() {
+        let pricing = Pricing::new(Arc::new(InMemoryPricingRuleRepository::new()));
+        let rose = FlowerBuilder::new()
+            .with_price(10_000.0)
+            .with_stock(2)
+            .build();
+        let tenant = &rose.tenant_id().clone();
+
+        let scarce = pricing
+            .create(tenant, request("Scarce", 50.0, Some(3)))
+            .await
+            .unwrap();
+        let rules = pricing.rules(tenant).await.unwrap();
+        assert_eq!(Pricing::respond(rose.clone(), &rules).price, 15_000.0);
+
+        let mut disabled = request("Scarce", 50.0, Some(3));
+        disabled.enabled = Some(false);
+        pricing.update(tenant, scarce.id, disabled).await.unwrap();
+        let rules = pricing.rules(tenant).await.unwrap();
+        let served = Pricing::respond(rose, &rules);
+        assert_eq!((served.price, served.base_price), (10_000.0, 10_000.0));
+        assert!(served.pricing_rules.is_empty());
+
+        pricing.delete(tenant, scarce.id).await.unwrap();
+        assert!(pricing.get(tenant, scarce.id).await.is_err());
+    }
+}
@@ -0,0 +1,133 @@
+//! Saved Searches
+//!
+//! Callers keep searches to run again, each visible to them alone. Searches
+//! with an alert address are checked for new matching flowers by
+//! `SavedSearchAlertsJob`.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::authorization::Subject;
+use crate::application::dtos::{SaveSearchRequest, SavedSearchResponse};
+use crate::application::ports::SavedSearchRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{FlowerColor, Price};
+use crate::domain::saved_search::{SavedSearch, SavedSearchError, SearchCriteria};
+use crate::domain::shared::TenantId;
+
+/// Saves, lists and deletes the searches of a caller
+pub struct SavedSearches {
+    repository: Arc<dyn SavedSearchRepository>,
+}
+
+impl SavedSearches {
+    pub fn new(repository: Arc<dyn SavedSearchRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Save a search for `owner`; alerts only cover flowers created from now on
+    pub async fn create(
+        &self,
+        tenant: &TenantId,
+        owner: &Subject,
+        request: SaveSearchRequest,
+    ) -> DomainResult<SavedSearchResponse> {
+        let criteria = SearchCriteria::new(
+            request.query.as_deref(),
+            request
+                .color
+                .map(|color| color.parse::<FlowerColor>())
+                .transpose()?,
+            request.min_price.map(Price::new).transpose()?,
+            request.max_price.map(Price::new).transpose()?,
+        )?;
+        let search = SavedSearch::new(
+            tenant.clone(),
+            owner.to_string(),
+            &request.name,
+            criteria,
+            request.alert_email.as_deref(),
+        )?;
+
+        let saved = self.repository.create(&search).await?;
+        Ok(saved.into())
+    }
+
+    /// Searches `owner` saved, oldest first
+    pub async fn list(
+        &self,
+        tenant: &TenantId,
+        owner: &Subject,
+    ) -> DomainResult<Vec<SavedSearchResponse>> {
+        let searches = self
+            .repository
+            .find_by_owner(tenant, &owner.to_string())
+            .await?;
+        Ok(searches
+            .into_iter()
+            .map(SavedSearchResponse::from)
+            .collect())
+    }
+
+    /// Delete a search of `owner`; those of other callers are not found
+    pub async fn delete(&self, tenant: &TenantId, owner: &Subject, id: Uuid) -> DomainResult<()> {
+        if self
+            .repository
+            .delete(tenant, &owner.to_string(), id)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(SavedSearchError::not_found(id))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::storage::Storage;
+
+    fn request(name: &str) -> SaveSearchRequest {
+        SaveSearchRequest {
+            name: name.to_string(),
+            query: Some("rose".to_string()),
+            color: Some("Red".to_string()),
+            min_price: None,
+            max_price: Some(30_000.0),
+            alert_email: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn searches_are_private_to_their_owner() {
+        let searches = SavedSearches::new(Storage::in_memory().saved_searches);
+        let tenant = TenantId::default();
+        let kiosk = Subject::Tenant(tenant.clone());
+
+        let saved = searches
+            .create(&tenant, &kiosk, request("Red roses"))
+            .await
+            .unwrap();
+        assert_eq!(saved.color, Some(FlowerColor::Red));
+        assert_eq!(saved.max_price, Some(30_000.0));
+
+        assert_eq!(searches.list(&tenant, &kiosk).await.unwrap().len(), 1);
+        assert!(
+            searches
+                .list(&tenant, &Subject::Admin)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let elsewhere = searches
+            .delete(&tenant, &Subject::Admin, saved.id)
+            .await
+            .unwrap_err();
+        assert_eq!(elsewhere.code(), "saved_search.not_found");
+
+        searches.delete(&tenant, &kiosk, saved.id).await.unwrap();
+        assert!(searches.list(&tenant, &kiosk).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+//! Development Fixtures
+//!
+//! Curated sample data embedded in the binary and loaded through the regular
+//! use cases, so it passes the same validation as data created over the API.
+//! Seeding is idempotent: fixtures whose name is already taken are skipped.
+
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::application::dtos::CreateFlowerRequest;
+use crate::application::ports::FlowerRepository;
+use crate::application::usecases::FlowerUseCase;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::TenantId;
+
+const FLOWERS: &str = include_str!("../../../fixtures/flowers.json");
+
+/// A fixture flower; without a tenant it goes to the default one
+#[derive(Debug, Deserialize)]
+struct FlowerFixture {
+    tenant: Option<String>,
+    #[serde(flatten)]
+    flower: CreateFlowerRequest,
+}
+
+/// Outcome of a seeding run
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SeedReport {
+    pub created: usize,
+    /// Fixtures that already existed
+    pub skipped: usize,
+}
+
+/// Loads the embedded fixtures
+pub struct Seeder<R: FlowerRepository + ?Sized> {
+    flowers: Arc<FlowerUseCase<R>>,
+}
+
+impl<R: FlowerRepository + ?Sized> Seeder<R> {
+    pub fn new(flowers: Arc<FlowerUseCase<R>>) -> Self {
+        Self { flowers }
+    }
+
+    /// Create every fixture that does not exist yet
+    pub async fn run(&self, default_tenant: &TenantId) -> DomainResult<SeedReport> {
+        let fixtures: Vec<FlowerFixture> = serde_json::from_str(FLOWERS)
+            .map_err(|e| AppError::internal(format!("Invalid flower fixtures: {}", e)))?;
+
+        let mut report = SeedReport::default();
+        for fixture in fixtures {
+            let tenant = match fixture.tenant {
+                Some(tenant) => TenantId::new(tenant)?,
+                None => default_tenant.clone(),
+            };
+            match self.flowers.create_flower(&tenant, fixture.flower).await {
+                Ok(_) => report.created += 1,
+                Err(AppError::Conflict { .. }) => report.skipped += 1,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::memory::{
+        InMemoryFlowerHistory, InMemoryFlowerRepository, InMemoryOrderRepository,
+        InMemoryStockLedger, InMemoryTaskQueue, InMemoryUnitOfWork,
+    };
+
+    #[tokio::test]
+    async fn seeding_twice_skips_existing_fixtures() {
+        let flowers = Arc::new(InMemoryFlowerRepository::new());
+        let unit_of_work = Arc::new(InMemoryUnitOfWork::new(
+            flowers.clone(),
+            Arc::new(InMemoryStockLedger::new()),
+            Arc::new(InMemoryFlowerHistory::new()),
+            Arc::new(InMemoryTaskQueue::new()),
+            Arc::new(InMemoryOrderRepository::new()),
+        ));
+        let seeder = Seeder::new(Arc::new(FlowerUseCase::new(flowers, unit_of_work)));
+
+        let first = seeder.run(&TenantId::default()).await.unwrap();
+        let second = seeder.run(&TenantId::default()).await.unwrap();
+
+        assert!(first.created > 0);
+        assert_eq!(first.skipped, 0);
+        assert_eq!(second.created, 0);
+        assert_eq!(second.skipped, first.created);
+    }
+}
@@ -0,0 +1,175 @@
+//! Shipping
+//!
+//! Delivery quotes from every configured rate provider, such as the shop's
+//! rate table and a courier's API. Checkout picks one of the rates quoted.
+
+use std::sync::Arc;
+
+use crate::application::dtos::ShippingRateResponse;
+use crate::application::ports::{Shipment, ShippingRateProvider};
+use crate::domain::delivery::{ShippingError, ShippingRate};
+use crate::domain::errors::{AppError, DomainResult, FieldError};
+use crate::domain::shared::TenantId;
+use crate::domain::store::GeoPoint;
+use crate::i18n::Message;
+
+/// Quotes deliveries from the configured rate providers
+pub struct Shipping {
+    providers: Vec<Arc<dyn ShippingRateProvider>>,
+}
+
+impl Shipping {
+    /// Weight quoted when none is given: about one bouquet
+    pub const DEFAULT_WEIGHT_GRAMS: u32 = 1000;
+
+    /// Heaviest parcel quoted
+    pub const MAX_WEIGHT_GRAMS: u32 = 50_000;
+
+    pub fn new(providers: Vec<Arc<dyn ShippingRateProvider>>) -> Self {
+        Self { providers }
+    }
+
+    /// Rates of every provider able to deliver `shipment`, cheapest first
+    ///
+    /// A provider failing is logged and left out, so a courier being down
+    /// still leaves the others to choose from; only when all fail is the
+    /// shipment unquotable.
+    pub async fn rates(&self, shipment: &Shipment) -> DomainResult<Vec<ShippingRate>> {
+        let mut rates = Vec::new();
+        let mut failures = 0;
+        for provider in &self.providers {
+            match provider.quote(shipment).await {
+                Ok(quoted) => rates.extend(quoted),
+                Err(e) => {
+                    failures += 1;
+                    metrics::counter!(
+                        "shipping_quote_failures_total",
+                        "provider" => provider.name().to_string()
+                    )
+                    .increment(1);
+                    tracing::warn!(
+                        provider = provider.name(),
+                        "Failed to quote shipping: {}",
+                        e
+                    );
+                }
+            }
+        }
+        if failures > 0 && failures == self.providers.len() {
+            return Err(ShippingError::unavailable());
+        }
+
+        rates.sort_by(|a, b| a.fee().total_cmp(&b.fee()));
+        Ok(rates)
+    }
+
+    /// Rates for a parcel of `weight_grams` to the address at `lat`/`lng`
+    pub async fn quote(
+        &self,
+        tenant: &TenantId,
+        lat: Option<f64>,
+        lng: Option<f64>,
+        weight_grams: Option<u32>,
+    ) -> DomainResult<Vec<ShippingRateResponse>> {
+        let weight_grams = weight_grams.unwrap_or(Self::DEFAULT_WEIGHT_GRAMS);
+        let mut fields = Vec::new();
+        for (field, value, range) in [
+            ("lat", lat, GeoPoint::LATITUDE),
+            ("lng", lng, GeoPoint::LONGITUDE),
+        ] {
+            match value {
+                None => fields.push(FieldError::new(
+                    field,
+                    Message::new("shipping.quote.location_required"),
+                )),
+                Some(value) if !range.contains(&value) => fields.push(FieldError::new(
+                    field,
+                    Message::new("shipping.quote.coordinate_out_of_range")
+                        .arg("min", range.start())
+                        .arg("max", range.end()),
+                )),
+                Some(_) => {}
+            }
+        }
+        if !(1..=Self::MAX_WEIGHT_GRAMS).contains(&weight_grams) {
+            fields.push(FieldError::new(
+                "weight_grams",
+                Message::new("shipping.quote.weight_out_of_range")
+                    .arg("max", Self::MAX_WEIGHT_GRAMS),
+            ));
+        }
+        let destination = match (lat, lng) {
+            (Some(lat), Some(lng)) if fields.is_empty() => GeoPoint::new(lat, lng)?,
+            _ => {
+                return Err(AppError::unprocessable(
+                    Message::new("shipping.quote.invalid"),
+                    fields,
+                ));
+            }
+        };
+
+        let shipment = Shipment {
+            tenant: tenant.clone(),
+            destination,
+            weight_grams,
+        };
+        let rates = self.rates(&shipment).await?;
+        Ok(rates.into_iter().map(ShippingRateResponse::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::infrastructure::shipping::{RateTable, TableRateProvider};
+
+    struct Unreachable;
+
+    #[async_trait]
+    impl ShippingRateProvider for Unreachable {
+        fn name(&self) -> &str {
+            "unreachable"
+        }
+
+        async fn quote(&self, _shipment: &Shipment) -> DomainResult<Vec<ShippingRate>> {
+            Err(AppError::internal("connection refused"))
+        }
+    }
+
+    #[tokio::test]
+    async fn failing_providers_leave_the_others_to_choose_from() {
+        let table = |rates: &str| -> Arc<dyn ShippingRateProvider> {
+            Arc::new(TableRateProvider::new(rates.parse::<RateTable>().unwrap()))
+        };
+        let shipping = Shipping::new(vec![
+            table("1000=20000,*=45000"),
+            Arc::new(Unreachable),
+            table("12000"),
+        ]);
+        let tenant = TenantId::default();
+
+        let fees: Vec<f64> = shipping
+            .quote(&tenant, Some(-6.2), Some(106.8), None)
+            .await
+            .unwrap()
+            .iter()
+            .map(|rate| rate.fee)
+            .collect();
+        assert_eq!(fees, [12_000.0, 20_000.0]);
+
+        let down = Shipping::new(vec![Arc::new(Unreachable)]);
+        let error = down
+            .quote(&tenant, Some(-6.2), Some(106.8), Some(500))
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "shipping.unavailable");
+
+        let error = shipping
+            .quote(&tenant, Some(-6.2), Some(106.8), Some(0))
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "shipping.quote.invalid");
+    }
+}
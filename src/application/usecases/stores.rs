@@ -0,0 +1,254 @@
+//! Stores
+//!
+//! Physical stores of a tenant and the part of each flower's stock they hold,
+//! for picking up in store. Stores are ranked by great-circle distance here
+//! rather than in the database, so every storage backend ranks them alike; a
+//! tenant has few enough stores for that to be cheap.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::dtos::{
+    CreateStoreRequest, SetStoreStockRequest, StoreAvailabilityResponse, StoreResponse,
+    StoreStockResponse,
+};
+use crate::application::ports::{FlowerRepository, StoreRepository};
+use crate::domain::errors::{AppError, DomainResult, FieldError};
+use crate::domain::flower::{FlowerError, StockQuantity};
+use crate::domain::shared::TenantId;
+use crate::domain::store::{GeoPoint, Store, StoreError};
+use crate::i18n::Message;
+
+/// Opens stores, assigns them stock and finds the nearest holding a flower
+pub struct Stores<R: FlowerRepository + ?Sized> {
+    stores: Arc<dyn StoreRepository>,
+    flowers: Arc<R>,
+}
+
+impl<R: FlowerRepository + ?Sized> Stores<R> {
+    pub fn new(stores: Arc<dyn StoreRepository>, flowers: Arc<R>) -> Self {
+        Self { stores, flowers }
+    }
+
+    pub async fn create(
+        &self,
+        tenant: &TenantId,
+        request: CreateStoreRequest,
+    ) -> DomainResult<StoreResponse> {
+        let location = GeoPoint::new(request.latitude, request.longitude)?;
+        let store = Store::new(tenant.clone(), &request.name, &request.address, location)?;
+
+        let created = self.stores.create(&store).await?;
+        Ok(created.into())
+    }
+
+    /// Stores of a tenant, by name
+    pub async fn list(&self, tenant: &TenantId) -> DomainResult<Vec<StoreResponse>> {
+        let stores = self.stores.find_all(tenant).await?;
+        Ok(stores.into_iter().map(StoreResponse::from).collect())
+    }
+
+    /// Close a store; the stock it held goes back to no store
+    pub async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
+        if self.stores.delete(tenant, id).await? {
+            Ok(())
+        } else {
+            Err(StoreError::not_found(id))
+        }
+    }
+
+    /// Set how many of a flower a store holds
+    ///
+    /// Stores share the flower's stock: together they cannot hold more than
+    /// the flower has.
+    pub async fn set_stock(
+        &self,
+        tenant: &TenantId,
+        store_id: Uuid,
+        flower_id: Uuid,
+        request: SetStoreStockRequest,
+    ) -> DomainResult<StoreStockResponse> {
+        let quantity = StockQuantity::new(request.quantity)?.value();
+        if self.stores.find_by_id(tenant, store_id).await?.is_none() {
+            return Err(StoreError::not_found(store_id));
+        }
+        let flower = self
+            .flowers
+            .find_by_id(tenant, flower_id)
+            .await?
+            .ok_or_else(|| FlowerError::not_found(flower_id))?;
+
+        let elsewhere: i32 = self
+            .stores
+            .stock_of(tenant, flower_id)
+            .await?
+            .iter()
+            .filter(|(store, _)| store.id() != store_id)
+            .map(|(_, held)| held)
+            .sum();
+        let available = (flower.stock() - elsewhere).max(0);
+        if quantity > available {
+            return Err(StoreError::stock_exceeds_flower(available));
+        }
+
+        self.stores
+            .set_stock(tenant, store_id, flower_id, quantity)
+            .await?;
+        Ok(StoreStockResponse {
+            store_id,
+            flower_id,
+            quantity,
+        })
+    }
+
+    /// Most stores returned by `availability`
+    pub const MAX_LIMIT: i64 = 50;
+
+    /// Stores holding a flower, nearest to `lat`/`lng` first
+    pub async fn availability(
+        &self,
+        tenant: &TenantId,
+        flower_id: Uuid,
+        lat: Option<f64>,
+        lng: Option<f64>,
+        limit: Option<i64>,
+    ) -> DomainResult<Vec<StoreAvailabilityResponse>> {
+        let limit = limit.unwrap_or(5);
+        let mut fields = Vec::new();
+        for (field, value, range) in [
+            ("lat", lat, GeoPoint::LATITUDE),
+            ("lng", lng, GeoPoint::LONGITUDE),
+        ] {
+            match value {
+                None => fields.push(FieldError::new(
+                    field,
+                    Message::new("store.availability.location_required"),
+                )),
+                Some(value) if !range.contains(&value) => fields.push(FieldError::new(
+                    field,
+                    Message::new("store.availability.coordinate_out_of_range")
+                        .arg("min", range.start())
+                        .arg("max", range.end()),
+                )),
+                Some(_) => {}
+            }
+        }
+        if !(1..=Self::MAX_LIMIT).contains(&limit) {
+            fields.push(FieldError::new(
+                "limit",
+                Message::new("store.availability.limit.out_of_range").arg("max", Self::MAX_LIMIT),
+            ));
+        }
+        let customer = match (lat, lng) {
+            (Some(lat), Some(lng)) if fields.is_empty() => GeoPoint::new(lat, lng)?,
+            _ => {
+                return Err(AppError::unprocessable(
+                    Message::new("store.availability.invalid"),
+                    fields,
+                ));
+            }
+        };
+
+        let flower = self
+            .flowers
+            .find_by_id(tenant, flower_id)
+            .await?
+            .ok_or_else(|| FlowerError::not_found(flower_id))?;
+
+        let mut available: Vec<StoreAvailabilityResponse> = self
+            .stores
+            .stock_of(tenant, flower_id)
+            .await?
+            .into_iter()
+            // Sales since stock was assigned may have used up what a store held
+            .map(|(store, held)| (store, held.min(flower.stock())))
+            .filter(|(_, quantity)| *quantity > 0)
+            .map(|(store, quantity)| StoreAvailabilityResponse {
+                distance_km: round_km(store.location().distance_km(&customer)),
+                quantity,
+                store: store.into(),
+            })
+            .collect();
+        available.sort_by(|a, b| a.distance_km.total_cmp(&b.distance_km));
+        available.truncate(limit as usize);
+
+        Ok(available)
+    }
+}
+
+/// Kilometres to the nearest ten metres
+fn round_km(km: f64) -> f64 {
+    (km * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::Entity;
+    use crate::infrastructure::storage::Storage;
+    use crate::test_support::FlowerBuilder;
+
+    fn store(name: &str, latitude: f64, longitude: f64) -> CreateStoreRequest {
+        CreateStoreRequest {
+            name: name.to_string(),
+            address: format!("{name}, Indonesia"),
+            latitude,
+            longitude,
+        }
+    }
+
+    #[tokio::test]
+    async fn nearest_stores_holding_the_flower_come_first() {
+        let storage = Storage::in_memory();
+        let stores = Stores::new(storage.stores.clone(), storage.flowers.clone());
+        let tenant = TenantId::default();
+        let rose = FlowerBuilder::new().with_stock(10).build();
+        storage.flowers.create(&rose).await.unwrap();
+
+        let bandung = stores
+            .create(&tenant, store("Bandung", -6.9175, 107.6191))
+            .await
+            .unwrap();
+        let bogor = stores
+            .create(&tenant, store("Bogor", -6.5971, 106.8060))
+            .await
+            .unwrap();
+        let surabaya = stores
+            .create(&tenant, store("Surabaya", -7.2575, 112.7521))
+            .await
+            .unwrap();
+        for (held, quantity) in [(&bandung, 4), (&bogor, 3)] {
+            let request = SetStoreStockRequest { quantity };
+            stores
+                .set_stock(&tenant, held.id, rose.id(), request)
+                .await
+                .unwrap();
+        }
+
+        let too_many = SetStoreStockRequest { quantity: 4 };
+        let error = stores
+            .set_stock(&tenant, surabaya.id, rose.id(), too_many)
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "store.stock.exceeds_flower");
+
+        let from_jakarta = stores
+            .availability(&tenant, rose.id(), Some(-6.2), Some(106.8), None)
+            .await
+            .unwrap();
+        let names: Vec<_> = from_jakarta
+            .iter()
+            .map(|store| store.store.name.as_str())
+            .collect();
+        assert_eq!(names, ["Bogor", "Bandung"]);
+        assert_eq!(from_jakarta[0].quantity, 3);
+        assert!((from_jakarta[0].distance_km - 44.1).abs() < 0.5);
+
+        let error = stores
+            .availability(&tenant, rose.id(), None, Some(200.0), None)
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "store.availability.invalid");
+    }
+}
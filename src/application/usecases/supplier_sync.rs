@@ -0,0 +1,193 @@
+//! Supplier Catalog Synchronization
+//!
+//! Suppliers publish their products as a feed; synchronizing one upserts
+//! every product into the supplier's tenant by SKU. Entries that cannot be
+//! read or fail validation are skipped and reported, so one bad row does
+//! not hold back the rest of the feed.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::application::dtos::{SupplierSyncResponse, UpdateFlowerRequest};
+use crate::application::ports::{FlowerRepository, SupplierFeed, SupplierProduct};
+use crate::application::usecases::{FlowerUseCase, UpsertOutcome};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::{FlowerError, Sku};
+use crate::domain::shared::TenantId;
+use crate::i18n::Message;
+
+/// Skip reasons kept in a report; the rest are only counted
+const MAX_REPORTED_ERRORS: usize = 20;
+
+/// Where a supplier's feed is read from, and the tenant it supplies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Supplier {
+    pub feed_url: String,
+    pub tenant: TenantId,
+}
+
+/// Synchronizes the catalog with the configured suppliers' feeds
+pub struct SupplierSync<R: FlowerRepository + ?Sized> {
+    flowers: Arc<FlowerUseCase<R>>,
+    feed: Arc<dyn SupplierFeed>,
+    suppliers: BTreeMap<String, Supplier>,
+}
+
+impl<R: FlowerRepository + ?Sized> SupplierSync<R> {
+    pub fn new(
+        flowers: Arc<FlowerUseCase<R>>,
+        feed: Arc<dyn SupplierFeed>,
+        suppliers: BTreeMap<String, Supplier>,
+    ) -> Self {
+        Self {
+            flowers,
+            feed,
+            suppliers,
+        }
+    }
+
+    /// IDs of the configured suppliers
+    pub fn supplier_ids(&self) -> impl Iterator<Item = &str> {
+        self.suppliers.keys().map(String::as_str)
+    }
+
+    /// Pull a supplier's feed and upsert its products
+    pub async fn sync(&self, id: &str) -> DomainResult<SupplierSyncResponse> {
+        let supplier = self
+            .suppliers
+            .get(id)
+            .ok_or_else(|| AppError::not_found(Message::new("supplier.not_found").arg("id", id)))?;
+        let entries = self.feed.fetch(&supplier.feed_url).await?;
+
+        let mut report = SupplierSyncResponse {
+            supplier: id.to_string(),
+            tenant: supplier.tenant.to_string(),
+            ..Default::default()
+        };
+        for (index, entry) in entries.into_iter().enumerate() {
+            let result = match entry {
+                Ok(product) => {
+                    let sku = product.sku.clone();
+                    match self.upsert(&supplier.tenant, product).await {
+                        Ok(outcome) => Ok(outcome),
+                        Err(e) if caused_by_entry(&e) => {
+                            Err(format!("entry {} ({}): {}", index + 1, sku, e))
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+                Err(reason) => Err(format!("entry {}: {}", index + 1, reason)),
+            };
+
+            match result {
+                Ok(UpsertOutcome::Created) => report.created += 1,
+                Ok(UpsertOutcome::Updated) => report.updated += 1,
+                Ok(UpsertOutcome::Unchanged) => report.unchanged += 1,
+                Err(reason) => {
+                    tracing::warn!(supplier = id, "Skipped supplier feed {}", reason);
+                    report.skipped += 1;
+                    if report.errors.len() < MAX_REPORTED_ERRORS {
+                        report.errors.push(reason);
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn upsert(
+        &self,
+        tenant: &TenantId,
+        product: SupplierProduct,
+    ) -> DomainResult<UpsertOutcome> {
+        let sku = Sku::new(product.sku.as_str())?
+            .ok_or_else(|| FlowerError::sku_invalid(&product.sku, Sku::MAX_LENGTH))?;
+        let request = UpdateFlowerRequest {
+            name: product.name,
+            color: product.color,
+            description: product.description,
+            price: product.price,
+            stock: product.stock,
+            unit: None,
+            stems_per_unit: None,
+            sku: None,
+            attributes: None,
+            metadata: None,
+        };
+
+        self.flowers.upsert_by_sku(tenant, sku, request).await
+    }
+}
+
+/// Whether an error is down to the feed entry, which is then skipped; any
+/// other error aborts the synchronization
+fn caused_by_entry(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::NotFound(_)
+            | AppError::BadRequest(_)
+            | AppError::Validation(_)
+            | AppError::Unprocessable { .. }
+            | AppError::Conflict { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::application::ports::FeedEntry;
+    use crate::infrastructure::storage::Storage;
+
+    struct StaticFeed(Vec<FeedEntry>);
+
+    #[async_trait]
+    impl SupplierFeed for StaticFeed {
+        async fn fetch(&self, _url: &str) -> DomainResult<Vec<FeedEntry>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn product(sku: &str, price: f64) -> FeedEntry {
+        Ok(SupplierProduct {
+            sku: sku.to_string(),
+            name: Some(format!("Flower {}", sku)),
+            color: Some("red".to_string()),
+            description: None,
+            price: Some(price),
+            stock: Some(10),
+        })
+    }
+
+    #[tokio::test]
+    async fn skipped_entries_do_not_stop_the_feed() {
+        let storage = Storage::in_memory();
+        let flowers = Arc::new(FlowerUseCase::new(storage.flowers, storage.unit_of_work));
+        let tenant = TenantId::default();
+        let suppliers = BTreeMap::from([(
+            "bloom".to_string(),
+            Supplier {
+                feed_url: "file:///feed.csv".to_string(),
+                tenant: tenant.clone(),
+            },
+        )]);
+        let feed = StaticFeed(vec![
+            product("a-1", 1_000.0),
+            Err("missing field `sku`".to_string()),
+            product("b-2", -5.0),
+            product("c-3", 3_000.0),
+        ]);
+        let sync = SupplierSync::new(flowers.clone(), Arc::new(feed), suppliers);
+
+        let report = sync.sync("bloom").await.unwrap();
+        assert_eq!((report.created, report.skipped), (2, 2));
+        assert_eq!(report.errors[0], "entry 2: missing field `sku`");
+        assert!(report.errors[1].starts_with("entry 3 (b-2): "));
+
+        let report = sync.sync("bloom").await.unwrap();
+        assert_eq!((report.created, report.unchanged), (0, 2));
+        assert!(sync.sync("other").await.is_err());
+    }
+}
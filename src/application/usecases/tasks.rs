@@ -0,0 +1,58 @@
+//! Tasks Service
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::application::dtos::FailedTaskResponse;
+use crate::application::ports::TaskQueue;
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::{PaginatedResponse, Pagination};
+use crate::domain::task::Task;
+
+/// Entry point for deferring work to the task workers
+pub struct Tasks<Q: TaskQueue + ?Sized> {
+    queue: Arc<Q>,
+    max_attempts: i32,
+}
+
+impl<Q: TaskQueue + ?Sized> Tasks<Q> {
+    pub fn new(queue: Arc<Q>, max_attempts: i32) -> Self {
+        Self {
+            queue,
+            max_attempts,
+        }
+    }
+
+    /// Enqueue a task to run as soon as a worker is free
+    pub async fn enqueue(&self, kind: &str, payload: Value) -> DomainResult<Uuid> {
+        self.enqueue_at(kind, payload, Utc::now()).await
+    }
+
+    /// Enqueue a task to run no earlier than `run_at`
+    pub async fn enqueue_at(
+        &self,
+        kind: &str,
+        payload: Value,
+        run_at: DateTime<Utc>,
+    ) -> DomainResult<Uuid> {
+        let task = Task::new(kind, payload, run_at, self.max_attempts);
+        self.queue.enqueue(&task).await?;
+        Ok(task.id())
+    }
+
+    /// List dead-lettered tasks, most recently failed first
+    pub async fn list_failed(
+        &self,
+        pagination: Pagination,
+    ) -> DomainResult<PaginatedResponse<FailedTaskResponse>> {
+        let tasks = self.queue.find_failed(&pagination).await?;
+        let total = self.queue.count_failed().await?;
+
+        let responses = tasks.into_iter().map(FailedTaskResponse::from).collect();
+
+        Ok(PaginatedResponse::new(responses, total, &pagination))
+    }
+}
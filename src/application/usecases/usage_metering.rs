@@ -0,0 +1,573 @@
+//! API Usage Metering
+//!
+//! Requests are counted per tenant, API key and hour in a per-instance
+//! buffer and flushed periodically, like flower views: metering costs a map
+//! update per request, and every replica adds its own counts to the stored
+//! totals. Usage buffered when an instance crashes is lost.
+//!
+//! API keys are never stored: usage is keyed on a fingerprint of the whole
+//! key, made with a configured secret so it cannot be matched against
+//! guessed keys without it, and shown by the key's last characters, which
+//! several keys may share.
+//!
+//! The same counts enforce monthly quotas per API key. Quotas are soft:
+//! each instance knows the usage of the others as of its last flush, so the
+//! replicas together may let a key exceed its quota by up to a flush
+//! interval's worth of requests.
+
+use std::collections::HashMap;
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Datelike, DurationRound, TimeDelta, TimeZone, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::application::dtos::{
+    QuotaResponse, SetQuotaRequest, UsageHourResponse, UsageReportResponse, UsageTotalResponse,
+};
+use crate::application::ports::{ApiQuota, UsageRecord, UsageStore};
+use crate::domain::errors::{AppError, DomainResult, FieldError};
+use crate::domain::shared::TenantId;
+use crate::i18n::Message;
+
+type Buffer = HashMap<(TenantId, Option<String>, DateTime<Utc>), UsageRecord>;
+
+/// Stored requests per API key this month, and the quotas admins set per
+/// key, as of the last refresh, by tenant and key fingerprint
+#[derive(Default)]
+struct Quotas {
+    /// Start of the month `used` counts
+    month: DateTime<Utc>,
+    used: HashMap<(TenantId, String), i64>,
+    custom: HashMap<(TenantId, String), i64>,
+}
+
+/// Monthly quota of an API key and how much of it is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuotaStatus {
+    pub limit: i64,
+    pub used: i64,
+    /// Start of the next month, when the count starts over
+    pub resets_at: DateTime<Utc>,
+}
+
+impl QuotaStatus {
+    pub fn remaining(&self) -> i64 {
+        (self.limit - self.used).max(0)
+    }
+}
+
+/// Meters API requests, enforces the monthly quotas of API keys and reports
+/// the metered usage
+pub struct UsageMetering {
+    store: Arc<dyn UsageStore>,
+    buffer: Mutex<Buffer>,
+    quotas: Mutex<Quotas>,
+    default_quota: Option<i64>,
+    /// Keys callers may present, with their tenants
+    api_keys: HashMap<String, TenantId>,
+    /// Key of the HMAC fingerprinting API keys; fixed, as fingerprints must
+    /// not change across restarts and replicas
+    fingerprint_secret: Vec<u8>,
+}
+
+impl UsageMetering {
+    pub fn new(store: Arc<dyn UsageStore>, fingerprint_secret: &str) -> Self {
+        Self {
+            store,
+            fingerprint_secret: fingerprint_secret.as_bytes().to_vec(),
+            buffer: Mutex::new(HashMap::new()),
+            quotas: Mutex::new(Quotas::default()),
+            default_quota: None,
+            api_keys: HashMap::new(),
+        }
+    }
+
+    /// Limit every key of `api_keys` to `default_quota` requests a month,
+    /// unless an admin sets a quota of its own
+    pub fn with_quotas(
+        mut self,
+        default_quota: Option<i64>,
+        api_keys: HashMap<String, TenantId>,
+    ) -> Self {
+        self.default_quota = default_quota;
+        self.api_keys = api_keys;
+        self
+    }
+
+    /// Count a request of `tenant` made with `api_key`, if any, and the bytes
+    /// it carried each way
+    pub fn record(
+        &self,
+        tenant: &TenantId,
+        api_key: Option<&str>,
+        request_bytes: u64,
+        response_bytes: u64,
+        failed: bool,
+    ) {
+        let hour = start_of_hour(Utc::now());
+        let key_id = api_key.map(|api_key| self.fingerprint(api_key));
+        let usage = UsageRecord {
+            tenant: tenant.clone(),
+            key_id: key_id.clone(),
+            api_key: api_key.map(key_hint),
+            hour,
+            requests: 1,
+            errors: i64::from(failed),
+            request_bytes: i64::try_from(request_bytes).unwrap_or(i64::MAX),
+            response_bytes: i64::try_from(response_bytes).unwrap_or(i64::MAX),
+        };
+        self.lock()
+            .entry((tenant.clone(), key_id, hour))
+            .and_modify(|total| total.add(&usage))
+            .or_insert(usage);
+    }
+
+    /// Add the buffered usage to the store, returning how many requests were
+    /// flushed
+    ///
+    /// If the store fails, the usage goes back into the buffer for the next
+    /// flush.
+    pub async fn flush(&self) -> DomainResult<i64> {
+        let buffered = mem::take(&mut *self.lock());
+        if buffered.is_empty() {
+            return Ok(0);
+        }
+
+        let records: Vec<UsageRecord> = buffered.values().cloned().collect();
+        if let Err(e) = self.store.add(&records).await {
+            let mut buffer = self.lock();
+            for (key, usage) in buffered {
+                buffer
+                    .entry(key)
+                    .and_modify(|total| total.add(&usage))
+                    .or_insert(usage);
+            }
+            return Err(e);
+        }
+
+        // Keep counting the flushed requests against quotas until the
+        // refresh reads them back with those of the other instances
+        {
+            let mut quotas = self.lock_quotas();
+            for record in &records {
+                if let Some(key_id) = &record.key_id
+                    && record.hour >= quotas.month
+                {
+                    *quotas
+                        .used
+                        .entry((record.tenant.clone(), key_id.clone()))
+                        .or_default() += record.requests;
+                }
+            }
+        }
+        self.refresh().await?;
+
+        Ok(records.iter().map(|record| record.requests).sum())
+    }
+
+    /// Reload the stored usage of this month and the quotas set per key
+    pub async fn refresh(&self) -> DomainResult<()> {
+        let month = start_of_month(Utc::now());
+        let used = self.store.requests_by_key_since(month).await?;
+        let custom = self.store.quotas().await?;
+
+        *self.lock_quotas() = Quotas {
+            month,
+            used: used
+                .into_iter()
+                .map(|(tenant, key_id, requests)| ((tenant, key_id), requests))
+                .collect(),
+            custom: custom
+                .into_iter()
+                .map(|quota| ((quota.tenant, quota.key_id), quota.monthly_requests))
+                .collect(),
+        };
+        Ok(())
+    }
+
+    /// Quota of the API key `api_key` of `tenant` this month, `None` when it
+    /// has none
+    pub fn quota(&self, tenant: &TenantId, api_key: &str) -> Option<QuotaStatus> {
+        self.status(tenant, &self.fingerprint(api_key)).1
+    }
+
+    /// Quota and usage of the key with fingerprint `key_id`, and whether an
+    /// admin set the quota
+    fn status(&self, tenant: &TenantId, key_id: &str) -> (bool, Option<QuotaStatus>) {
+        let now = Utc::now();
+        let month = start_of_month(now);
+        let key = (tenant.clone(), key_id.to_string());
+
+        let (custom, stored) = {
+            let quotas = self.lock_quotas();
+            let stored = if quotas.month == month {
+                quotas.used.get(&key).copied().unwrap_or(0)
+            } else {
+                0
+            };
+            (quotas.custom.get(&key).copied(), stored)
+        };
+        let Some(limit) = custom.or(self.default_quota) else {
+            return (false, None);
+        };
+
+        let buffered: i64 = self
+            .lock()
+            .iter()
+            .filter(|((buffered_tenant, buffered_key, hour), _)| {
+                buffered_tenant == tenant
+                    && buffered_key.as_deref() == Some(key_id)
+                    && *hour >= month
+            })
+            .map(|(_, usage)| usage.requests)
+            .sum();
+
+        let status = QuotaStatus {
+            limit,
+            used: stored + buffered,
+            resets_at: next_month(month),
+        };
+        (custom.is_some(), Some(status))
+    }
+
+    /// Quotas and usage of every API key, by tenant
+    pub fn quotas(&self) -> Vec<QuotaResponse> {
+        let mut quotas: Vec<QuotaResponse> = self
+            .api_keys
+            .iter()
+            .map(|(api_key, tenant)| self.respond(tenant, api_key))
+            .collect();
+        quotas.sort_by(|a, b| (&a.tenant_id, &a.api_key).cmp(&(&b.tenant_id, &b.api_key)));
+        quotas
+    }
+
+    /// Set the monthly quota of an API key, or with none make it fall back to
+    /// the default
+    ///
+    /// Other instances apply the change at their next flush.
+    pub async fn set_quota(&self, request: SetQuotaRequest) -> DomainResult<QuotaResponse> {
+        let tenant = self
+            .api_keys
+            .get(request.api_key.trim())
+            .cloned()
+            .ok_or_else(|| AppError::not_found(Message::new("quota.api_key.unknown")))?;
+        if request
+            .monthly_requests
+            .is_some_and(|monthly_requests| monthly_requests <= 0)
+        {
+            return Err(AppError::unprocessable(
+                Message::new("quota.invalid"),
+                vec![FieldError::new(
+                    "monthly_requests",
+                    Message::new("quota.monthly_requests.not_positive"),
+                )],
+            ));
+        }
+
+        let api_key = request.api_key.trim();
+        let key_id = self.fingerprint(api_key);
+        match request.monthly_requests {
+            Some(monthly_requests) => {
+                self.store
+                    .set_quota(&ApiQuota {
+                        tenant: tenant.clone(),
+                        key_id: key_id.clone(),
+                        api_key: key_hint(api_key),
+                        monthly_requests,
+                    })
+                    .await?;
+                self.lock_quotas()
+                    .custom
+                    .insert((tenant.clone(), key_id), monthly_requests);
+            }
+            None => {
+                self.store.remove_quota(&tenant, &key_id).await?;
+                self.lock_quotas().custom.remove(&(tenant.clone(), key_id));
+            }
+        }
+
+        Ok(self.respond(&tenant, api_key))
+    }
+
+    fn respond(&self, tenant: &TenantId, api_key: &str) -> QuotaResponse {
+        let key_id = self.fingerprint(api_key);
+        let (custom, status) = self.status(tenant, &key_id);
+        QuotaResponse {
+            tenant_id: tenant.as_str().to_string(),
+            monthly_requests: status.map(|status| status.limit),
+            custom,
+            used: status.map_or(0, |status| status.used),
+            remaining: status.map(|status| status.remaining()),
+            resets_at: next_month(start_of_month(Utc::now())),
+            key_id,
+            api_key: key_hint(api_key),
+        }
+    }
+
+    /// Longest range a usage report covers, in days
+    pub const MAX_RANGE_DAYS: i64 = 31;
+
+    /// Hourly usage between `from` (default: a day before `to`) and `to`
+    /// (default: now), of one tenant or all, with totals per tenant and key
+    ///
+    /// Hours are whole: `from` is rounded down to the start of its hour. Usage
+    /// reaches the store when it is flushed, so the latest may take up to one
+    /// flush interval to show.
+    pub async fn report(
+        &self,
+        tenant: Option<TenantId>,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> DomainResult<UsageReportResponse> {
+        let to = to.unwrap_or_else(Utc::now);
+        let from = start_of_hour(from.unwrap_or(to - TimeDelta::days(1)));
+
+        let mut fields = Vec::new();
+        if to <= from {
+            fields.push(FieldError::new("to", Message::new("usage.to.before_from")));
+        } else if to - from > TimeDelta::days(Self::MAX_RANGE_DAYS) {
+            fields.push(FieldError::new(
+                "from",
+                Message::new("usage.range.too_long").arg("max", Self::MAX_RANGE_DAYS),
+            ));
+        }
+        if !fields.is_empty() {
+            return Err(AppError::unprocessable(
+                Message::new("usage.range.invalid"),
+                fields,
+            ));
+        }
+
+        let records = self.store.list(tenant.as_ref(), from, to).await?;
+
+        let mut totals: Vec<UsageTotalResponse> = Vec::new();
+        for record in &records {
+            let position = totals.iter().position(|total| {
+                total.tenant_id == record.tenant.as_str() && total.key_id == record.key_id
+            });
+            match position {
+                Some(position) => totals[position].add(record),
+                None => totals.push(UsageTotalResponse::from(record)),
+            }
+        }
+        totals.sort_by(|a, b| {
+            (&a.tenant_id, &a.api_key, &a.key_id).cmp(&(&b.tenant_id, &b.api_key, &b.key_id))
+        });
+
+        Ok(UsageReportResponse {
+            from,
+            to,
+            totals,
+            hours: records.into_iter().map(UsageHourResponse::from).collect(),
+        })
+    }
+
+    fn fingerprint(&self, api_key: &str) -> String {
+        key_fingerprint(&self.fingerprint_secret, api_key)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Buffer> {
+        self.buffer.lock().expect("usage buffer lock poisoned")
+    }
+
+    fn lock_quotas(&self) -> std::sync::MutexGuard<'_, Quotas> {
+        self.quotas.lock().expect("quota lock poisoned")
+    }
+}
+
+fn start_of_hour(at: DateTime<Utc>) -> DateTime<Utc> {
+    at.duration_trunc(TimeDelta::hours(1)).unwrap_or(at)
+}
+
+fn start_of_month(at: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(at.year(), at.month(), 1, 0, 0, 0)
+        .single()
+        .unwrap_or(at)
+}
+
+fn next_month(month: DateTime<Utc>) -> DateTime<Utc> {
+    let (year, month) = match month.month() {
+        12 => (month.year() + 1, 1),
+        month_of_year => (month.year(), month_of_year + 1),
+    };
+    Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0)
+        .single()
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// Identifies an API key without revealing it: HMAC-SHA256 of the key
+/// under `secret`, hex encoded
+fn key_fingerprint(secret: &[u8], api_key: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts any key length");
+    mac.update(api_key.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// The last characters of an API key, at most a quarter of it, for display
+fn key_hint(api_key: &str) -> String {
+    let shown = (api_key.chars().count() / 4).min(4);
+    let tail: String = api_key
+        .chars()
+        .rev()
+        .take(shown)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    format!("****{}", tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::memory::InMemoryUsageStore;
+
+    const SECRET: &str = "test-fingerprint-secret";
+
+    fn tenant(name: &str) -> TenantId {
+        TenantId::new(name).unwrap()
+    }
+
+    #[tokio::test]
+    async fn usage_is_totalled_per_tenant_and_key() {
+        let metering = UsageMetering::new(Arc::new(InMemoryUsageStore::new()), SECRET);
+        metering.record(&tenant("kiosk"), Some("kiosk-secret-1234"), 10, 100, false);
+        metering.record(&tenant("kiosk"), Some("kiosk-secret-1234"), 20, 200, true);
+        metering.record(&tenant("kiosk"), None, 0, 50, false);
+        metering.record(&tenant("garden"), Some("garden-secret-99"), 5, 5, false);
+
+        assert_eq!(metering.flush().await.unwrap(), 4);
+        assert_eq!(metering.flush().await.unwrap(), 0);
+
+        let report = metering
+            .report(Some(tenant("kiosk")), None, None)
+            .await
+            .unwrap();
+        let totals: Vec<_> = report
+            .totals
+            .iter()
+            .map(|t| (t.api_key.as_deref(), t.requests, t.errors, t.response_bytes))
+            .collect();
+        assert_eq!(totals, [(None, 1, 0, 50), (Some("****1234"), 2, 1, 300)]);
+        assert_eq!(
+            report.totals[1].key_id.as_deref(),
+            Some(key_fingerprint(SECRET.as_bytes(), "kiosk-secret-1234").as_str())
+        );
+        // Without the secret, a guessed key does not match its fingerprint
+        assert_ne!(
+            report.totals[1].key_id,
+            Some(key_fingerprint(b"guess", "kiosk-secret-1234"))
+        );
+    }
+
+    #[tokio::test]
+    async fn keys_ending_alike_are_metered_apart() {
+        let store = Arc::new(InMemoryUsageStore::new());
+        let api_keys = HashMap::from([
+            ("kiosk-front-1234".to_string(), tenant("kiosk")),
+            ("kiosk-backs-1234".to_string(), tenant("kiosk")),
+        ]);
+        let metering = UsageMetering::new(store, SECRET).with_quotas(Some(2), api_keys);
+        metering.record(&tenant("kiosk"), Some("kiosk-front-1234"), 0, 0, false);
+        metering.record(&tenant("kiosk"), Some("kiosk-front-1234"), 0, 0, false);
+        metering.record(&tenant("kiosk"), Some("kiosk-backs-1234"), 0, 0, false);
+        metering.flush().await.unwrap();
+
+        let front = metering
+            .quota(&tenant("kiosk"), "kiosk-front-1234")
+            .unwrap();
+        let back = metering
+            .quota(&tenant("kiosk"), "kiosk-backs-1234")
+            .unwrap();
+        assert_eq!((front.used, back.used), (2, 1));
+        metering
+            .set_quota(SetQuotaRequest {
+                api_key: "kiosk-backs-1234".to_string(),
+                monthly_requests: Some(10),
+            })
+            .await
+            .unwrap();
+        let front = metering
+            .quota(&tenant("kiosk"), "kiosk-front-1234")
+            .unwrap();
+        assert_eq!(front.limit, 2, "a quota set for one key is not the other's");
+
+        let report = metering.report(None, None, None).await.unwrap();
+        let totals: Vec<_> = report
+            .totals
+            .iter()
+            .map(|t| (t.api_key.as_deref(), t.requests))
+            .collect();
+        assert_eq!(totals.len(), 2);
+        assert!(totals.iter().all(|(hint, _)| *hint == Some("****1234")));
+        assert_eq!(totals.iter().map(|(_, requests)| requests).sum::<i64>(), 3);
+    }
+
+    #[tokio::test]
+    async fn keys_are_held_to_their_monthly_quota() {
+        let store = Arc::new(InMemoryUsageStore::new());
+        let api_keys = HashMap::from([
+            ("kiosk-secret-1234".to_string(), tenant("kiosk")),
+            ("garden-secret-99".to_string(), tenant("garden")),
+        ]);
+        let metering =
+            UsageMetering::new(store.clone(), SECRET).with_quotas(Some(2), api_keys.clone());
+        metering.record(&tenant("kiosk"), Some("kiosk-secret-1234"), 0, 0, false);
+        metering.flush().await.unwrap();
+        metering.record(&tenant("kiosk"), Some("kiosk-secret-1234"), 0, 0, false);
+
+        let quota = metering
+            .quota(&tenant("kiosk"), "kiosk-secret-1234")
+            .unwrap();
+        assert_eq!((quota.limit, quota.used, quota.remaining()), (2, 2, 0));
+        assert!(quota.resets_at > Utc::now());
+        let unused = metering
+            .quota(&tenant("garden"), "garden-secret-99")
+            .unwrap();
+        assert_eq!(unused.remaining(), 2);
+
+        // Another instance sees the flushed usage and the custom quota
+        metering
+            .set_quota(SetQuotaRequest {
+                api_key: "kiosk-secret-1234".to_string(),
+                monthly_requests: Some(10),
+            })
+            .await
+            .unwrap();
+        let replica = UsageMetering::new(store, SECRET).with_quotas(Some(2), api_keys);
+        replica.refresh().await.unwrap();
+        let quota = replica
+            .quota(&tenant("kiosk"), "kiosk-secret-1234")
+            .unwrap();
+        assert_eq!((quota.limit, quota.used), (10, 1));
+
+        let unknown = replica
+            .set_quota(SetQuotaRequest {
+                api_key: "guessed".to_string(),
+                monthly_requests: Some(10),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(unknown.code(), "quota.api_key.unknown");
+    }
+
+    #[test]
+    fn months_start_over_at_midnight_utc() {
+        let new_year = Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 0).unwrap();
+        let month = start_of_month(new_year);
+        assert_eq!(month, Utc.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap());
+        assert_eq!(
+            next_month(month),
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn short_keys_reveal_little() {
+        assert_eq!(key_hint("abc"), "****");
+        assert_eq!(key_hint("abcdefgh"), "****gh");
+        assert_eq!(key_hint("kiosk-secret-1234"), "****1234");
+    }
+}
@@ -0,0 +1,607 @@
+//! Webhooks Service
+//!
+//! Tenants register endpoints their events are POSTed to. Each endpoint has
+//! a secret of its own, which signs its deliveries as `application::webhooks`
+//! describes; rotating it keeps the previous secret signing too for the
+//! rotation window, so receivers can switch over without refusing any
+//! delivery. Events are delivered by a `deliver_webhook` task per endpoint,
+//! signed anew on every attempt, and retried like any task until the
+//! receiver answers with a 2xx status.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use url::Url;
+use uuid::Uuid;
+
+use crate::application::dtos::{
+    CreateWebhookEndpointRequest, WebhookEndpointResponse, WebhookTestResponse,
+};
+use crate::application::ports::{
+    OutboundWebhook, TaskQueue, WebhookEndpoint, WebhookEndpointRepository, WebhookReceipt,
+    WebhookSender,
+};
+use crate::application::usecases::Tasks;
+use crate::application::webhooks::sign;
+use crate::domain::errors::{AppError, DomainResult, FieldError};
+use crate::domain::shared::{TenantId, new_id};
+use crate::i18n::Message;
+
+/// Task kind delivering one event to one endpoint
+pub const DELIVER_WEBHOOK_TASK: &str = "deliver_webhook";
+
+/// Type of the events sent by test deliveries
+pub const TEST_EVENT: &str = "webhook.test";
+
+/// Longest endpoint URL accepted
+pub const MAX_URL_LENGTH: usize = 2048;
+
+/// Event as receivers get it, in the body of a delivery
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    pub id: Uuid,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub tenant_id: TenantId,
+    pub created_at: DateTime<Utc>,
+    pub data: Value,
+}
+
+/// Payload of a `deliver_webhook` task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    /// Id of the delivery, kept across retries
+    pub id: String,
+    pub endpoint_id: Uuid,
+    pub event: WebhookEvent,
+}
+
+/// Manages the webhook endpoints of tenants and delivers events to them
+pub struct Webhooks {
+    endpoints: Arc<dyn WebhookEndpointRepository>,
+    sender: Arc<dyn WebhookSender>,
+    tasks: Arc<Tasks<dyn TaskQueue>>,
+    rotation_window: Duration,
+}
+
+impl Webhooks {
+    pub fn new(
+        endpoints: Arc<dyn WebhookEndpointRepository>,
+        sender: Arc<dyn WebhookSender>,
+        tasks: Arc<Tasks<dyn TaskQueue>>,
+        rotation_window: Duration,
+    ) -> Self {
+        Self {
+            endpoints,
+            sender,
+            tasks,
+            rotation_window,
+        }
+    }
+
+    /// Register an endpoint; its secret is in the response, and only there
+    pub async fn create(
+        &self,
+        tenant: &TenantId,
+        request: CreateWebhookEndpointRequest,
+    ) -> DomainResult<WebhookEndpointResponse> {
+        let url = request.url.trim();
+        let problem = if !is_valid_url(url) {
+            Some(Message::new("webhook_endpoint.url.invalid").arg("max", MAX_URL_LENGTH))
+        } else if self.sender.is_private(url).await {
+            Some(Message::new("webhook_endpoint.url.private"))
+        } else {
+            None
+        };
+        if let Some(problem) = problem {
+            return Err(AppError::unprocessable(
+                Message::new("webhook_endpoint.invalid"),
+                vec![FieldError::new("url", problem)],
+            ));
+        }
+
+        let endpoint = WebhookEndpoint {
+            id: new_id(),
+            tenant: tenant.clone(),
+            url: url.to_string(),
+            secret: new_secret(),
+            previous_secret: None,
+            previous_secret_expires_at: None,
+            created_at: Utc::now(),
+        };
+        self.endpoints.create(&endpoint).await?;
+        Ok(response(endpoint, true))
+    }
+
+    /// Endpoints of a tenant, oldest first, without their secrets
+    pub async fn list(&self, tenant: &TenantId) -> DomainResult<Vec<WebhookEndpointResponse>> {
+        let endpoints = self.endpoints.find_all(tenant).await?;
+        Ok(endpoints
+            .into_iter()
+            .map(|endpoint| response(endpoint, false))
+            .collect())
+    }
+
+    pub async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
+        if self.endpoints.delete(tenant, id).await? {
+            Ok(())
+        } else {
+            Err(not_found(id))
+        }
+    }
+
+    /// Sign deliveries to an endpoint with a new secret, returned in the
+    /// response
+    ///
+    /// The current secret keeps signing them too for the rotation window,
+    /// so the receiver accepts them while it is switched over. Rotating
+    /// again within the window drops the secret before the current one.
+    pub async fn rotate_secret(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<WebhookEndpointResponse> {
+        let window =
+            chrono::Duration::from_std(self.rotation_window).unwrap_or(chrono::Duration::MAX);
+        let previous_expires_at = Utc::now()
+            .checked_add_signed(window)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        let endpoint = self
+            .endpoints
+            .rotate_secret(tenant, id, &new_secret(), previous_expires_at)
+            .await?
+            .ok_or_else(|| not_found(id))?;
+
+        tracing::info!(endpoint_id = %id, tenant = %tenant, "Webhook secret rotated");
+        Ok(response(endpoint, true))
+    }
+
+    /// Send a `webhook.test` event to an endpoint right away, for integrators
+    /// to check their receiver, and report whether it took the event
+    ///
+    /// Unlike events, a test delivery is neither queued nor retried. How it
+    /// failed is only logged: the status or error of a receiver would tell
+    /// callers about hosts they cannot reach themselves.
+    pub async fn send_test(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<WebhookTestResponse> {
+        let endpoint = self
+            .endpoints
+            .find_by_id(tenant, id)
+            .await?
+            .ok_or_else(|| not_found(id))?;
+        let event = WebhookEvent {
+            id: new_id(),
+            event_type: TEST_EVENT.to_string(),
+            tenant_id: tenant.clone(),
+            created_at: Utc::now(),
+            data: json!({ "endpoint_id": id }),
+        };
+        let delivery_id = new_id().to_string();
+
+        let delivered = match self.send(&endpoint, &delivery_id, &event).await {
+            Ok(receipt) if receipt.is_success() => true,
+            Ok(receipt) => {
+                tracing::info!(
+                    id = %delivery_id,
+                    endpoint_id = %id,
+                    status = receipt.status.unwrap_or_default(),
+                    "Test webhook refused"
+                );
+                false
+            }
+            Err(e) => {
+                tracing::info!(id = %delivery_id, endpoint_id = %id, "Test webhook failed: {}", e);
+                false
+            }
+        };
+        Ok(WebhookTestResponse {
+            delivery_id,
+            delivered,
+        })
+    }
+
+    /// Queue the delivery of an event to every endpoint of `tenant`
+    pub async fn publish(
+        &self,
+        tenant: &TenantId,
+        event_type: &str,
+        data: Value,
+    ) -> DomainResult<()> {
+        let endpoints = self.endpoints.find_all(tenant).await?;
+        if endpoints.is_empty() {
+            return Ok(());
+        }
+
+        let event = WebhookEvent {
+            id: new_id(),
+            event_type: event_type.to_string(),
+            tenant_id: tenant.clone(),
+            created_at: Utc::now(),
+            data,
+        };
+        for endpoint in endpoints {
+            let delivery = WebhookDelivery {
+                id: new_id().to_string(),
+                endpoint_id: endpoint.id,
+                event: event.clone(),
+            };
+            let payload = serde_json::to_value(&delivery)
+                .map_err(|e| AppError::internal(format!("Failed to queue webhook: {}", e)))?;
+            self.tasks.enqueue(DELIVER_WEBHOOK_TASK, payload).await?;
+        }
+        Ok(())
+    }
+
+    /// Deliver a queued event; fails, so the task is retried, unless the
+    /// receiver answers with a 2xx status
+    ///
+    /// Deliveries to endpoints deleted since the event was queued are
+    /// dropped.
+    pub async fn deliver(&self, delivery: &WebhookDelivery) -> DomainResult<()> {
+        let Some(endpoint) = self
+            .endpoints
+            .find_by_id(&delivery.event.tenant_id, delivery.endpoint_id)
+            .await?
+        else {
+            tracing::info!(
+                id = %delivery.id,
+                endpoint_id = %delivery.endpoint_id,
+                "Webhook dropped, its endpoint was deleted"
+            );
+            return Ok(());
+        };
+
+        let receipt = self.send(&endpoint, &delivery.id, &delivery.event).await?;
+        if receipt.is_success() {
+            Ok(())
+        } else {
+            Err(AppError::internal(format!(
+                "Webhook {} was refused by {} with status {}",
+                delivery.id,
+                endpoint.url,
+                receipt.status.unwrap_or_default()
+            )))
+        }
+    }
+
+    /// Sign `event` with the secrets of `endpoint` as of now and send it,
+    /// unless the endpoint leads into our own network
+    ///
+    /// Endpoints are checked on every send, not only when registered: the
+    /// addresses their names resolve to may have changed since.
+    async fn send(
+        &self,
+        endpoint: &WebhookEndpoint,
+        delivery_id: &str,
+        event: &WebhookEvent,
+    ) -> DomainResult<WebhookReceipt> {
+        if self.sender.is_private(&endpoint.url).await {
+            metrics::counter!(
+                "webhook_sends_total",
+                "event" => event.event_type.clone(),
+                "outcome" => "refused_destination"
+            )
+            .increment(1);
+            return Err(AppError::internal(format!(
+                "Webhook {} not sent, {} is not a public address",
+                delivery_id, endpoint.url
+            )));
+        }
+        let body = serde_json::to_vec(event)
+            .map_err(|e| AppError::internal(format!("Failed to serialize webhook: {}", e)))?;
+        let now = Utc::now();
+        let timestamp = now.timestamp();
+        let webhook = OutboundWebhook {
+            url: endpoint.url.clone(),
+            id: delivery_id.to_string(),
+            timestamp,
+            signatures: endpoint
+                .signing_secrets(now)
+                .into_iter()
+                .map(|secret| sign(secret, delivery_id, timestamp, &body))
+                .collect(),
+            body,
+        };
+
+        let result = self.sender.send(&webhook).await;
+        let outcome = match &result {
+            Ok(receipt) if receipt.is_success() => "delivered",
+            Ok(_) => "refused",
+            Err(_) => "unreachable",
+        };
+        metrics::counter!(
+            "webhook_sends_total",
+            "event" => event.event_type.clone(),
+            "outcome" => outcome
+        )
+        .increment(1);
+        result
+    }
+}
+
+fn not_found(id: Uuid) -> AppError {
+    AppError::not_found(Message::new("webhook_endpoint.not_found").arg("id", id))
+}
+
+/// 256 random bits, hex encoded behind a prefix telling what they are
+fn new_secret() -> String {
+    format!(
+        "whsec_{:032x}{:032x}",
+        rand::random::<u128>(),
+        rand::random::<u128>()
+    )
+}
+
+/// Absolute http(s) URL with a host and no whitespace
+fn is_valid_url(url: &str) -> bool {
+    url.len() <= MAX_URL_LENGTH
+        && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+        && Url::parse(url)
+            .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.host().is_some())
+}
+
+fn response(endpoint: WebhookEndpoint, with_secret: bool) -> WebhookEndpointResponse {
+    WebhookEndpointResponse {
+        id: endpoint.id,
+        url: endpoint.url,
+        secret: with_secret.then_some(endpoint.secret),
+        previous_secret_expires_at: endpoint
+            .previous_secret
+            .and(endpoint.previous_secret_expires_at),
+        created_at: endpoint.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::application::webhooks::{SignedDelivery, WebhookVerifier};
+    use crate::infrastructure::memory::{
+        InMemoryNonceStore, InMemoryTaskQueue, InMemoryWebhookEndpointRepository,
+    };
+    use crate::infrastructure::webhooks::is_private_url;
+
+    /// Records deliveries and answers them with `status`
+    struct RecordingSender {
+        status: u16,
+        sent: Mutex<Vec<OutboundWebhook>>,
+    }
+
+    #[async_trait]
+    impl WebhookSender for RecordingSender {
+        async fn send(&self, webhook: &OutboundWebhook) -> DomainResult<WebhookReceipt> {
+            self.sent.lock().unwrap().push(webhook.clone());
+            Ok(WebhookReceipt {
+                status: Some(self.status),
+            })
+        }
+
+        async fn is_private(&self, url: &str) -> bool {
+            is_private_url(url)
+        }
+    }
+
+    fn webhooks(status: u16) -> (Webhooks, Arc<RecordingSender>, Arc<InMemoryTaskQueue>) {
+        let sender = Arc::new(RecordingSender {
+            status,
+            sent: Mutex::new(Vec::new()),
+        });
+        let queue = Arc::new(InMemoryTaskQueue::new());
+        let tasks: Arc<Tasks<dyn TaskQueue>> = Arc::new(Tasks::new(queue.clone(), 3));
+        let webhooks = Webhooks::new(
+            Arc::new(InMemoryWebhookEndpointRepository::new()),
+            sender.clone(),
+            tasks,
+            Duration::from_secs(3600),
+        );
+        (webhooks, sender, queue)
+    }
+
+    fn tenant() -> TenantId {
+        TenantId::new("kiosk").unwrap()
+    }
+
+    fn register(url: &str) -> CreateWebhookEndpointRequest {
+        CreateWebhookEndpointRequest {
+            url: url.to_string(),
+        }
+    }
+
+    /// Whether a receiver holding only `secret` accepts `webhook`
+    async fn accepts(secret: &str, webhook: &OutboundWebhook) -> bool {
+        let verifier = WebhookVerifier::new(
+            "test",
+            vec![secret.to_string()],
+            Duration::from_secs(300),
+            Arc::new(InMemoryNonceStore::new()),
+        );
+        let timestamp = webhook.timestamp.to_string();
+        let signatures = webhook.signatures.join(",");
+        let delivery = SignedDelivery {
+            id: Some(&webhook.id),
+            timestamp: Some(&timestamp),
+            signatures: Some(&signatures),
+        };
+        verifier.verify(delivery, &webhook.body).await.is_ok()
+    }
+
+    #[tokio::test]
+    async fn test_deliveries_are_signed_with_the_endpoint_secret() {
+        let (webhooks, sender, _) = webhooks(204);
+        let endpoint = webhooks
+            .create(&tenant(), register(" https://shop.example.com/hooks "))
+            .await
+            .unwrap();
+        let secret = endpoint.secret.unwrap();
+        assert!(secret.starts_with("whsec_"));
+        assert_eq!(endpoint.url, "https://shop.example.com/hooks");
+
+        let report = webhooks.send_test(&tenant(), endpoint.id).await.unwrap();
+        assert!(report.delivered);
+
+        let sent = sender.sent.lock().unwrap().clone();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].id, report.delivery_id);
+        assert!(accepts(&secret, &sent[0]).await);
+        assert!(!accepts("whsec_other", &sent[0]).await);
+        let event: WebhookEvent = serde_json::from_slice(&sent[0].body).unwrap();
+        assert_eq!(event.event_type, TEST_EVENT);
+        assert_eq!(event.tenant_id, tenant());
+    }
+
+    #[tokio::test]
+    async fn rotated_secrets_sign_alongside_the_new_one_for_the_window() {
+        let (webhooks, sender, _) = webhooks(200);
+        let endpoint = webhooks
+            .create(&tenant(), register("https://shop.example.com/hooks"))
+            .await
+            .unwrap();
+        let old = endpoint.secret.unwrap();
+        let rotated = webhooks
+            .rotate_secret(&tenant(), endpoint.id)
+            .await
+            .unwrap();
+        let new = rotated.secret.unwrap();
+        assert_ne!(old, new);
+        assert!(rotated.previous_secret_expires_at.unwrap() > Utc::now());
+        assert_eq!(
+            webhooks.list(&tenant()).await.unwrap()[0].secret,
+            None,
+            "secrets are not listed"
+        );
+
+        webhooks.send_test(&tenant(), endpoint.id).await.unwrap();
+        let sent = sender.sent.lock().unwrap().clone();
+        assert_eq!(sent[0].signatures.len(), 2);
+        assert!(accepts(&old, &sent[0]).await);
+        assert!(accepts(&new, &sent[0]).await);
+
+        let expired = WebhookEndpoint {
+            id: endpoint.id,
+            tenant: tenant(),
+            url: endpoint.url,
+            secret: new.clone(),
+            previous_secret: Some(old),
+            previous_secret_expires_at: Some(Utc::now() - chrono::Duration::seconds(1)),
+            created_at: endpoint.created_at,
+        };
+        assert_eq!(expired.signing_secrets(Utc::now()), vec![new.as_str()]);
+    }
+
+    #[tokio::test]
+    async fn events_are_queued_per_endpoint_and_retried_until_taken() {
+        let (webhooks, sender, queue) = webhooks(500);
+        for url in ["https://a.example.com", "http://b.example.com/hooks"] {
+            webhooks.create(&tenant(), register(url)).await.unwrap();
+        }
+        webhooks
+            .publish(&tenant(), "order.status_changed", json!({ "to": "paid" }))
+            .await
+            .unwrap();
+        webhooks
+            .publish(
+                &TenantId::new("other").unwrap(),
+                "order.status_changed",
+                json!({}),
+            )
+            .await
+            .unwrap();
+
+        let lease = Duration::from_secs(60);
+        let task = queue.claim(lease).await.unwrap().unwrap();
+        assert_eq!(task.kind(), DELIVER_WEBHOOK_TASK);
+        assert!(queue.claim(lease).await.unwrap().is_some());
+        assert!(queue.claim(lease).await.unwrap().is_none());
+        let delivery: WebhookDelivery = serde_json::from_value(task.payload().clone()).unwrap();
+        assert!(webhooks.deliver(&delivery).await.is_err(), "5xx is retried");
+        assert_eq!(sender.sent.lock().unwrap()[0].id, delivery.id);
+
+        webhooks
+            .delete(&tenant(), delivery.endpoint_id)
+            .await
+            .unwrap();
+        webhooks.deliver(&delivery).await.unwrap();
+        assert_eq!(sender.sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn only_public_http_urls_are_registered() {
+        let (webhooks, _, _) = webhooks(200);
+        for url in [
+            "ftp://shop.example.com",
+            "https://",
+            "shop.example.com/hooks",
+            "https://shop.example.com/a b",
+            "http://localhost:8080/hooks",
+            "http://127.0.0.1/hooks",
+            "https://shop.example.com@10.0.0.7/hooks",
+            "http://169.254.169.254/latest/meta-data",
+            "http://metadata.google.internal/computeMetadata/v1",
+            "http://[::1]:8080",
+            "http://[::ffff:192.168.1.1]/hooks",
+            "http://100.64.0.1/hooks",
+        ] {
+            let error = webhooks.create(&tenant(), register(url)).await.unwrap_err();
+            assert!(
+                matches!(error, AppError::Unprocessable { .. }),
+                "{}: {:?}",
+                url,
+                error
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn endpoints_leading_into_our_network_are_not_sent_to() {
+        let sender = Arc::new(RecordingSender {
+            status: 200,
+            sent: Mutex::new(Vec::new()),
+        });
+        let endpoints = Arc::new(InMemoryWebhookEndpointRepository::new());
+        let tasks: Arc<Tasks<dyn TaskQueue>> =
+            Arc::new(Tasks::new(Arc::new(InMemoryTaskQueue::new()), 3));
+        let webhooks = Webhooks::new(
+            endpoints.clone(),
+            sender.clone(),
+            tasks,
+            Duration::from_secs(3600),
+        );
+        // Registered before its name resolved into our network, say
+        let endpoint = WebhookEndpoint {
+            id: new_id(),
+            tenant: tenant(),
+            url: "http://169.254.169.254/latest/meta-data".to_string(),
+            secret: new_secret(),
+            previous_secret: None,
+            previous_secret_expires_at: None,
+            created_at: Utc::now(),
+        };
+        endpoints.create(&endpoint).await.unwrap();
+
+        let report = webhooks.send_test(&tenant(), endpoint.id).await.unwrap();
+        assert!(!report.delivered);
+        let delivery = WebhookDelivery {
+            id: new_id().to_string(),
+            endpoint_id: endpoint.id,
+            event: WebhookEvent {
+                id: new_id(),
+                event_type: "order.status_changed".to_string(),
+                tenant_id: tenant(),
+                created_at: Utc::now(),
+                data: json!({}),
+            },
+        };
+        assert!(webhooks.deliver(&delivery).await.is_err());
+        assert!(sender.sent.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,296 @@
+//! Webhook Signatures
+//!
+//! Webhooks are signed the same way in both directions: the sender computes
+//! an HMAC-SHA256 of `{id}.{timestamp}.{body}` with a secret it shares with
+//! the receiver, where `id` is unique to the delivery and `timestamp` is in
+//! seconds since the Unix epoch. Receivers refuse signatures older than
+//! their tolerance and remember the signatures they accepted until then, so
+//! a captured delivery cannot be replayed. Retries are signed anew and keep
+//! their id, so receivers must handle a delivery more than once gracefully.
+//! While a secret is rotated a delivery may carry one signature per secret.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::application::ports::NonceStore;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::i18n::Message;
+
+/// Longest delivery id accepted
+pub const MAX_ID_LENGTH: usize = 128;
+
+/// Hex encoded signature of a delivery
+pub fn sign(secret: &str, id: &str, timestamp: i64, body: &[u8]) -> String {
+    hex::encode(mac(secret, id, timestamp, body).finalize().into_bytes())
+}
+
+fn mac(secret: &str, id: &str, timestamp: i64, body: &[u8]) -> Hmac<Sha256> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(format!("{}.{}.", id, timestamp).as_bytes());
+    mac.update(body);
+    mac
+}
+
+/// Signature headers of an inbound delivery, as received
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SignedDelivery<'a> {
+    pub id: Option<&'a str>,
+    pub timestamp: Option<&'a str>,
+    /// Hex encoded signatures, comma separated
+    pub signatures: Option<&'a str>,
+}
+
+/// Checks the signatures of the deliveries of one inbound integration
+///
+/// Outcomes are counted in `webhook_deliveries_total{source,outcome}`.
+pub struct WebhookVerifier {
+    source: &'static str,
+    /// Any of them may sign; more than one while the sender rotates
+    secrets: Vec<String>,
+    tolerance: Duration,
+    nonces: Arc<dyn NonceStore>,
+}
+
+impl WebhookVerifier {
+    pub fn new(
+        source: &'static str,
+        secrets: Vec<String>,
+        tolerance: Duration,
+        nonces: Arc<dyn NonceStore>,
+    ) -> Self {
+        Self {
+            source,
+            secrets,
+            tolerance,
+            nonces,
+        }
+    }
+
+    /// Name of the integration, which also scopes its nonces
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// Accept `body` if one of its signatures is by a secret of this source,
+    /// made within the tolerance, and was not accepted before
+    ///
+    /// A delivery is remembered by its id and timestamp, which a replay
+    /// cannot change without breaking the signature. Forged deliveries are
+    /// refused before being remembered, so they cannot block genuine ones.
+    pub async fn verify(&self, delivery: SignedDelivery<'_>, body: &[u8]) -> DomainResult<()> {
+        let result = self.check(delivery, body).await;
+        let outcome = match &result {
+            Ok(()) => "accepted",
+            Err(AppError::Conflict { .. }) => "replayed",
+            Err(AppError::Unauthorized(message)) if message.key() == "webhook.timestamp.stale" => {
+                "stale"
+            }
+            Err(_) => "rejected",
+        };
+        metrics::counter!(
+            "webhook_deliveries_total",
+            "source" => self.source,
+            "outcome" => outcome
+        )
+        .increment(1);
+        result
+    }
+
+    async fn check(&self, delivery: SignedDelivery<'_>, body: &[u8]) -> DomainResult<()> {
+        let (Some(id), Some(timestamp), Some(signatures)) =
+            (delivery.id, delivery.timestamp, delivery.signatures)
+        else {
+            return Err(AppError::unauthorized(Message::new(
+                "webhook.signature.missing",
+            )));
+        };
+        if id.is_empty() || id.len() > MAX_ID_LENGTH {
+            return Err(AppError::bad_request(
+                Message::new("webhook.id.invalid").arg("max", MAX_ID_LENGTH),
+            ));
+        }
+        let signed_at = timestamp
+            .parse::<i64>()
+            .ok()
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+            .ok_or_else(|| AppError::unauthorized(Message::new("webhook.timestamp.stale")))?;
+        let tolerance = chrono::Duration::from_std(self.tolerance).unwrap_or(chrono::Duration::MAX);
+        if (Utc::now() - signed_at).abs() > tolerance {
+            return Err(AppError::unauthorized(Message::new(
+                "webhook.timestamp.stale",
+            )));
+        }
+
+        let signed = signatures
+            .split(',')
+            .filter_map(|signature| hex::decode(signature.trim()).ok())
+            .any(|signature| {
+                self.secrets.iter().any(|secret| {
+                    mac(secret, id, signed_at.timestamp(), body)
+                        .verify_slice(&signature)
+                        .is_ok()
+                })
+            });
+        if !signed {
+            return Err(AppError::unauthorized(Message::new(
+                "webhook.signature.invalid",
+            )));
+        }
+
+        // Older deliveries are refused by their timestamp, so the nonce need
+        // not be remembered any longer
+        let nonce = format!("{}.{}", id, signed_at.timestamp());
+        if !self
+            .nonces
+            .claim(self.source, &nonce, signed_at + tolerance)
+            .await?
+        {
+            return Err(AppError::conflict(
+                Message::new("webhook.replayed").arg("id", id),
+                None,
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::memory::InMemoryNonceStore;
+
+    const BODY: &[u8] = br#"{"type":"payment.succeeded"}"#;
+
+    fn verifier(secrets: &[&str]) -> WebhookVerifier {
+        WebhookVerifier::new(
+            "payments",
+            secrets.iter().map(|secret| secret.to_string()).collect(),
+            Duration::from_secs(300),
+            Arc::new(InMemoryNonceStore::new()),
+        )
+    }
+
+    fn delivery<'a>(id: &'a str, timestamp: &'a str, signatures: &'a str) -> SignedDelivery<'a> {
+        SignedDelivery {
+            id: Some(id),
+            timestamp: Some(timestamp),
+            signatures: Some(signatures),
+        }
+    }
+
+    fn code(result: DomainResult<()>) -> &'static str {
+        match result.unwrap_err() {
+            AppError::Unauthorized(message) => message.key(),
+            AppError::Conflict { message, .. } => message.key(),
+            AppError::BadRequest(message) => message.key(),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn accepts_each_signed_delivery_once() {
+        let verifier = verifier(&["whsec_one"]);
+        let now = Utc::now().timestamp();
+        let signature = sign("whsec_one", "evt_1", now, BODY);
+        let timestamp = now.to_string();
+
+        verifier
+            .verify(delivery("evt_1", &timestamp, &signature), BODY)
+            .await
+            .unwrap();
+        assert_eq!(
+            code(
+                verifier
+                    .verify(delivery("evt_1", &timestamp, &signature), BODY)
+                    .await
+            ),
+            "webhook.replayed"
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_retries_signed_anew() {
+        let verifier = verifier(&["whsec_one"]);
+        let now = Utc::now().timestamp();
+
+        for signed_at in [now - 60, now] {
+            let signature = sign("whsec_one", "evt_1", signed_at, BODY);
+            verifier
+                .verify(delivery("evt_1", &signed_at.to_string(), &signature), BODY)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn refuses_forged_tampered_and_stale_deliveries() {
+        let verifier = verifier(&["whsec_one"]);
+        let now = Utc::now().timestamp();
+        let timestamp = now.to_string();
+
+        let forged = sign("whsec_other", "evt_1", now, BODY);
+        assert_eq!(
+            code(
+                verifier
+                    .verify(delivery("evt_1", &timestamp, &forged), BODY)
+                    .await
+            ),
+            "webhook.signature.invalid"
+        );
+        let signature = sign("whsec_one", "evt_1", now, BODY);
+        assert_eq!(
+            code(
+                verifier
+                    .verify(delivery("evt_1", &timestamp, &signature), b"{}")
+                    .await
+            ),
+            "webhook.signature.invalid"
+        );
+        let old = now - 301;
+        let stale = sign("whsec_one", "evt_1", old, BODY);
+        assert_eq!(
+            code(
+                verifier
+                    .verify(delivery("evt_1", &old.to_string(), &stale), BODY)
+                    .await
+            ),
+            "webhook.timestamp.stale"
+        );
+        assert_eq!(
+            code(verifier.verify(SignedDelivery::default(), BODY).await),
+            "webhook.signature.missing"
+        );
+        // None of the refusals was remembered
+        verifier
+            .verify(delivery("evt_1", &timestamp, &signature), BODY)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn accepts_any_secret_while_rotating() {
+        let verifier = verifier(&["whsec_new", "whsec_old"]);
+        let now = Utc::now().timestamp();
+        let timestamp = now.to_string();
+
+        let old = sign("whsec_old", "evt_1", now, BODY);
+        verifier
+            .verify(delivery("evt_1", &timestamp, &old), BODY)
+            .await
+            .unwrap();
+        let both = format!(
+            "{},{}",
+            sign("whsec_retired", "evt_2", now, BODY),
+            sign("whsec_new", "evt_2", now, BODY)
+        );
+        verifier
+            .verify(delivery("evt_2", &timestamp, &both), BODY)
+            .await
+            .unwrap();
+    }
+}
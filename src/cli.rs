@@ -0,0 +1,176 @@
+//! Command Line Interface
+//!
+//! `serve` (the default) runs the API; `migrate` and `db` manage the database
+//! so schema changes can run as a deploy step of their own, `seed` loads
+//! the development fixtures and `openapi` writes the API document for client
+//! generation.
+
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, Subcommand};
+
+use rust_api::api::http::{ApiDoc, AppState};
+
+use rust_api::application::usecases::Seeder;
+use rust_api::infrastructure::cache;
+use rust_api::infrastructure::config::AppConfig;
+use rust_api::infrastructure::persistance::DatabasePool;
+use rust_api::infrastructure::storage::{MEMORY_SCHEME, Storage, connect_database};
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Flower shop API")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the HTTP server (the default)
+    Serve,
+    /// Manage database migrations
+    #[command(subcommand)]
+    Migrate(MigrateCommand),
+    /// Manage the database itself
+    #[command(subcommand)]
+    Db(DbCommand),
+    /// Load the sample flowers in fixtures/, skipping ones that exist
+    Seed,
+    /// Write the OpenAPI document without starting the server or reading
+    /// configuration
+    Openapi {
+        /// File to write; YAML for .yaml and .yml, JSON otherwise. Printed
+        /// to stdout when omitted
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Print YAML instead of JSON to stdout
+        #[arg(long, conflicts_with = "out")]
+        yaml: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MigrateCommand {
+    /// Apply all pending migrations
+    Up,
+    /// Revert the most recently applied migrations
+    Down {
+        /// Number of migrations to revert
+        #[arg(long, default_value_t = 1)]
+        steps: usize,
+    },
+    /// List migrations and whether they have been applied
+    Status,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DbCommand {
+    /// Create the database named by DATABASE_URL unless it exists
+    Create,
+}
+
+/// Run a `migrate` subcommand
+pub async fn migrate(
+    config: &AppConfig,
+    command: MigrateCommand,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let db = connect_database(config).await?;
+
+    match command {
+        MigrateCommand::Up => {
+            let pending = db
+                .migration_status()
+                .await?
+                .iter()
+                .filter(|migration| !migration.applied)
+                .count();
+            db.run_migrations().await?;
+            println!("Applied {} migrations", pending);
+        }
+        MigrateCommand::Down { steps } => {
+            let reverted = db.undo_migrations(steps).await?;
+            for version in &reverted {
+                println!("Reverted {}", version);
+            }
+            println!("Reverted {} migrations", reverted.len());
+        }
+        MigrateCommand::Status => {
+            for migration in db.migration_status().await? {
+                let state = if migration.applied {
+                    "applied"
+                } else {
+                    "pending"
+                };
+                println!(
+                    "{}  {:<7}  {}",
+                    migration.version, state, migration.description
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run a `db` subcommand
+pub async fn db(config: &AppConfig, command: DbCommand) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        DbCommand::Create => {
+            if config.database_url.starts_with(MEMORY_SCHEME) {
+                return Err("In-memory storage has no database to create".into());
+            }
+            if DatabasePool::create_database(&config.database_url).await? {
+                println!("Created database");
+            } else {
+                println!("Database already exists");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the `seed` command
+pub async fn seed(config: &AppConfig) -> Result<(), Box<dyn std::error::Error>> {
+    if config.database_url.starts_with(MEMORY_SCHEME) {
+        return Err("In-memory storage is lost on exit; use SEED_ON_START instead".into());
+    }
+
+    let storage = Storage::connect(config).await?;
+    let state = AppState::builder(config, &storage)
+        .with_cache(cache::store(config).await?)
+        .build()
+        .await?;
+    let report = Seeder::new(state.flower_usecase)
+        .run(&config.default_tenant)
+        .await?;
+    println!(
+        "Seeded {} flowers ({} already present)",
+        report.created, report.skipped
+    );
+
+    Ok(())
+}
+
+/// Run the `openapi` command
+pub fn openapi(out: Option<&Path>, yaml: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let yaml = yaml
+        || out
+            .and_then(Path::extension)
+            .is_some_and(|extension| extension == "yaml" || extension == "yml");
+    let document = if yaml {
+        ApiDoc::document_yaml()?
+    } else {
+        ApiDoc::document().to_pretty_json()? + "\n"
+    };
+
+    match out {
+        Some(path) => {
+            std::fs::write(path, document)?;
+            eprintln!("Wrote OpenAPI document to {}", path.display());
+        }
+        None => print!("{}", document),
+    }
+
+    Ok(())
+}
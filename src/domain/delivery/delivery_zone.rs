@@ -0,0 +1,207 @@
+//! Delivery Zone Entity
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::delivery::errors::DeliveryZoneError;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::Price;
+use crate::domain::shared::{TenantId, new_id};
+use crate::domain::store::GeoPoint;
+
+/// Area a tenant delivers to, and what delivering there costs
+///
+/// The boundary is a simple polygon of WGS 84 points, treated as flat: fine
+/// for city-sized zones, but a zone may not cross the antimeridian.
+#[derive(Debug, Clone)]
+pub struct DeliveryZone {
+    id: Uuid,
+    tenant_id: TenantId,
+    name: String,
+    boundary: Vec<GeoPoint>,
+    fee: f64,
+    created_at: DateTime<Utc>,
+}
+
+impl DeliveryZone {
+    /// Maximum name length in characters, matching the `VARCHAR(100)` column
+    pub const MAX_NAME_LENGTH: usize = 100;
+
+    /// Fewest points enclosing an area
+    pub const MIN_BOUNDARY_POINTS: usize = 3;
+
+    /// Most points of a boundary; checking an address walks all of them
+    pub const MAX_BOUNDARY_POINTS: usize = 1000;
+
+    /// Define a zone; a boundary repeating its first point at the end, as
+    /// GeoJSON rings do, is accepted
+    pub fn new(
+        tenant_id: TenantId,
+        name: impl AsRef<str>,
+        mut boundary: Vec<GeoPoint>,
+        fee: f64,
+    ) -> DomainResult<Self> {
+        let name = name.as_ref().trim();
+        if name.is_empty() {
+            return Err(DeliveryZoneError::name_empty());
+        }
+        if name.chars().count() > Self::MAX_NAME_LENGTH {
+            return Err(DeliveryZoneError::name_too_long(Self::MAX_NAME_LENGTH));
+        }
+
+        if boundary.len() > 1 && boundary.first() == boundary.last() {
+            boundary.pop();
+        }
+        if boundary.len() < Self::MIN_BOUNDARY_POINTS {
+            return Err(DeliveryZoneError::boundary_too_small(
+                Self::MIN_BOUNDARY_POINTS,
+            ));
+        }
+        if boundary.len() > Self::MAX_BOUNDARY_POINTS {
+            return Err(DeliveryZoneError::boundary_too_large(
+                Self::MAX_BOUNDARY_POINTS,
+            ));
+        }
+
+        if !(fee.is_finite() && (0.0..=Price::MAX).contains(&fee)) {
+            return Err(DeliveryZoneError::fee_invalid(Price::MAX));
+        }
+
+        Ok(Self {
+            id: new_id(),
+            tenant_id,
+            name: name.to_string(),
+            boundary,
+            fee,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Reconstruct a delivery zone from persistence layer
+    pub fn from_persistence(
+        id: Uuid,
+        tenant_id: TenantId,
+        name: String,
+        boundary: Vec<GeoPoint>,
+        fee: f64,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            name,
+            boundary,
+            fee,
+            created_at,
+        }
+    }
+
+    /// Whether `point` lies inside the boundary (even-odd rule)
+    ///
+    /// Counts the edges a ray from the point towards the east crosses; an
+    /// odd count means inside.
+    pub fn contains(&self, point: &GeoPoint) -> bool {
+        let (x, y) = (point.longitude(), point.latitude());
+        let mut inside = false;
+        let mut previous = self.boundary[self.boundary.len() - 1];
+        for current in &self.boundary {
+            let (x1, y1) = (current.longitude(), current.latitude());
+            let (x2, y2) = (previous.longitude(), previous.latitude());
+            if (y1 > y) != (y2 > y) && x < x1 + (y - y1) * (x2 - x1) / (y2 - y1) {
+                inside = !inside;
+            }
+            previous = *current;
+        }
+        inside
+    }
+
+    // Getters
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Corners of the zone, without the first repeated at the end
+    pub fn boundary(&self) -> &[GeoPoint] {
+        &self.boundary
+    }
+
+    /// Delivery fee in IDR
+    pub fn fee(&self) -> f64 {
+        self.fee
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(corners: &[(f64, f64)]) -> Vec<GeoPoint> {
+        corners
+            .iter()
+            .map(|(lat, lng)| GeoPoint::new(*lat, *lng).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn contains_points_inside_the_boundary_only() {
+        // An L shape around central Jakarta, closed like a GeoJSON ring
+        let zone = DeliveryZone::new(
+            TenantId::default(),
+            "Central Jakarta",
+            points(&[
+                (-6.10, 106.80),
+                (-6.10, 106.85),
+                (-6.15, 106.85),
+                (-6.15, 106.90),
+                (-6.20, 106.90),
+                (-6.20, 106.80),
+                (-6.10, 106.80),
+            ]),
+            15_000.0,
+        )
+        .unwrap();
+        assert_eq!(zone.boundary().len(), 6);
+
+        let inside = |lat, lng| zone.contains(&GeoPoint::new(lat, lng).unwrap());
+        assert!(inside(-6.12, 106.82));
+        assert!(inside(-6.18, 106.88));
+        // In the notch of the L
+        assert!(!inside(-6.12, 106.88));
+        assert!(!inside(-6.25, 106.82));
+    }
+
+    #[test]
+    fn validates_the_boundary_and_fee() {
+        let triangle = points(&[(-6.1, 106.8), (-6.2, 106.8), (-6.2, 106.9)]);
+        let define = |boundary: Vec<GeoPoint>, fee: f64| {
+            DeliveryZone::new(TenantId::default(), "Zone", boundary, fee)
+        };
+
+        assert!(define(triangle.clone(), 0.0).is_ok());
+        assert_eq!(
+            define(triangle[..2].to_vec(), 0.0).unwrap_err().code(),
+            "delivery_zone.boundary.too_small"
+        );
+        let closed_line = points(&[(-6.1, 106.8), (-6.2, 106.8), (-6.1, 106.8)]);
+        assert_eq!(
+            define(closed_line, 0.0).unwrap_err().code(),
+            "delivery_zone.boundary.too_small"
+        );
+        assert_eq!(
+            define(triangle, -1.0).unwrap_err().code(),
+            "delivery_zone.fee.invalid"
+        );
+    }
+}
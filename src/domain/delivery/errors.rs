@@ -0,0 +1,49 @@
+//! Delivery Domain Specific Errors
+
+use uuid::Uuid;
+
+use crate::domain::errors::AppError;
+use crate::i18n::Message;
+
+/// Delivery zone error constructors
+pub struct DeliveryZoneError;
+
+impl DeliveryZoneError {
+    pub fn not_found(id: Uuid) -> AppError {
+        AppError::not_found(Message::new("delivery_zone.not_found").arg("id", id))
+    }
+
+    pub fn name_empty() -> AppError {
+        AppError::validation(Message::new("delivery_zone.name.empty"))
+    }
+
+    pub fn name_too_long(max: usize) -> AppError {
+        AppError::validation(Message::new("delivery_zone.name.too_long").arg("max", max))
+    }
+
+    pub fn boundary_too_small(min: usize) -> AppError {
+        AppError::validation(Message::new("delivery_zone.boundary.too_small").arg("min", min))
+    }
+
+    pub fn boundary_too_large(max: usize) -> AppError {
+        AppError::validation(Message::new("delivery_zone.boundary.too_large").arg("max", max))
+    }
+
+    pub fn fee_invalid(max: f64) -> AppError {
+        AppError::validation(Message::new("delivery_zone.fee.invalid").arg("max", max))
+    }
+}
+
+/// Shipping error constructors
+pub struct ShippingError;
+
+impl ShippingError {
+    pub fn fee_invalid(max: f64) -> AppError {
+        AppError::validation(Message::new("shipping.fee.invalid").arg("max", max))
+    }
+
+    /// No carrier could quote the shipment
+    pub fn unavailable() -> AppError {
+        AppError::service_unavailable(Message::new("shipping.unavailable"))
+    }
+}
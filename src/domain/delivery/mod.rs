@@ -0,0 +1,9 @@
+//! Delivery Domain Module
+
+pub mod delivery_zone;
+pub mod errors;
+pub mod shipping_rate;
+
+pub use delivery_zone::DeliveryZone;
+pub use errors::{DeliveryZoneError, ShippingError};
+pub use shipping_rate::ShippingRate;
@@ -0,0 +1,54 @@
+//! Shipping Rate Value Object
+
+use crate::domain::delivery::errors::ShippingError;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::Price;
+
+/// Price of sending a parcel with one service of a carrier
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShippingRate {
+    carrier: String,
+    service: String,
+    fee: f64,
+    estimated_days: Option<u32>,
+}
+
+impl ShippingRate {
+    pub fn new(
+        carrier: impl Into<String>,
+        service: impl Into<String>,
+        fee: f64,
+        estimated_days: Option<u32>,
+    ) -> DomainResult<Self> {
+        if !(fee.is_finite() && (0.0..=Price::MAX).contains(&fee)) {
+            return Err(ShippingError::fee_invalid(Price::MAX));
+        }
+
+        Ok(Self {
+            carrier: carrier.into(),
+            service: service.into(),
+            fee,
+            estimated_days,
+        })
+    }
+
+    /// Who delivers, such as `jne` or the shop's own couriers
+    pub fn carrier(&self) -> &str {
+        &self.carrier
+    }
+
+    /// Service of the carrier, such as `REG` or `YES`
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// Fee in IDR
+    pub fn fee(&self) -> f64 {
+        self.fee
+    }
+
+    /// Days until delivery, when the carrier says
+    pub fn estimated_days(&self) -> Option<u32> {
+        self.estimated_days
+    }
+}
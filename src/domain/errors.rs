@@ -1,5 +1,7 @@
 //! Generic Domain Errors
 
+use std::sync::Arc;
+
 use axum::{
     Json,
     http::StatusCode,
@@ -7,67 +9,230 @@ use axum::{
 };
 use serde_json::json;
 use thiserror::Error;
+use uuid::Uuid;
+
+use crate::i18n::{Locale, Message};
+
+/// Validation failure for a single input field
+#[derive(Debug, Clone)]
+pub struct FieldError {
+    pub field: &'static str,
+    pub message: Message,
+}
+
+impl FieldError {
+    pub fn new(field: &'static str, message: Message) -> Self {
+        Self { field, message }
+    }
+}
 
 /// Generic application error types
 #[derive(Debug, Error)]
 pub enum AppError {
     #[error("{0}")]
-    NotFound(String),
+    NotFound(Message),
+
+    #[error("{0}")]
+    BadRequest(Message),
+
+    #[error("{0}")]
+    Validation(Message),
+
+    #[error("{0}")]
+    Unauthorized(Message),
 
     #[error("{0}")]
-    BadRequest(String),
+    Forbidden(Message),
+
+    #[error("{message}")]
+    Unprocessable {
+        message: Message,
+        fields: Vec<FieldError>,
+    },
+
+    #[error("{message}")]
+    Conflict {
+        message: Message,
+        existing_id: Option<Uuid>,
+    },
+
+    #[error("{0}")]
+    Timeout(Message),
 
     #[error("{0}")]
-    Validation(String),
+    PayloadTooLarge(Message),
+
+    #[error("{0}")]
+    TooManyRequests(Message),
+
+    #[error("{0}")]
+    ServiceUnavailable(Message),
 
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 
     #[error("Internal server error: {0}")]
-    Internal(String),
+    Internal(Message),
 }
 
 impl AppError {
-    pub fn not_found(message: impl Into<String>) -> Self {
+    pub fn not_found(message: impl Into<Message>) -> Self {
         Self::NotFound(message.into())
     }
 
-    pub fn bad_request(message: impl Into<String>) -> Self {
+    pub fn bad_request(message: impl Into<Message>) -> Self {
         Self::BadRequest(message.into())
     }
 
-    pub fn validation(message: impl Into<String>) -> Self {
+    pub fn validation(message: impl Into<Message>) -> Self {
         Self::Validation(message.into())
     }
 
-    pub fn internal(message: impl Into<String>) -> Self {
+    pub fn unauthorized(message: impl Into<Message>) -> Self {
+        Self::Unauthorized(message.into())
+    }
+
+    pub fn forbidden(message: impl Into<Message>) -> Self {
+        Self::Forbidden(message.into())
+    }
+
+    pub fn unprocessable(message: impl Into<Message>, fields: Vec<FieldError>) -> Self {
+        Self::Unprocessable {
+            message: message.into(),
+            fields,
+        }
+    }
+
+    pub fn conflict(message: impl Into<Message>, existing_id: Option<Uuid>) -> Self {
+        Self::Conflict {
+            message: message.into(),
+            existing_id,
+        }
+    }
+
+    pub fn timeout(message: impl Into<Message>) -> Self {
+        Self::Timeout(message.into())
+    }
+
+    pub fn payload_too_large(message: impl Into<Message>) -> Self {
+        Self::PayloadTooLarge(message.into())
+    }
+
+    pub fn too_many_requests(message: impl Into<Message>) -> Self {
+        Self::TooManyRequests(message.into())
+    }
+
+    pub fn service_unavailable(message: impl Into<Message>) -> Self {
+        Self::ServiceUnavailable(message.into())
+    }
+
+    pub fn internal(message: impl Into<Message>) -> Self {
         Self::Internal(message.into())
     }
+
+    /// Machine-readable, language-independent error code
+    pub fn code(&self) -> &'static str {
+        let (message, fallback) = match self {
+            AppError::NotFound(message) => (Some(message), "not_found"),
+            AppError::BadRequest(message) => (Some(message), "bad_request"),
+            AppError::Validation(message) => (Some(message), "validation_error"),
+            AppError::Unauthorized(message) => (Some(message), "unauthorized"),
+            AppError::Forbidden(message) => (Some(message), "forbidden"),
+            AppError::Unprocessable { message, .. } => (Some(message), "unprocessable_entity"),
+            AppError::Conflict { message, .. } => (Some(message), "conflict"),
+            AppError::Timeout(message) => (Some(message), "request_timeout"),
+            AppError::PayloadTooLarge(message) => (Some(message), "payload_too_large"),
+            AppError::TooManyRequests(message) => (Some(message), "too_many_requests"),
+            AppError::ServiceUnavailable(message) => (Some(message), "service_unavailable"),
+            AppError::Database(_) => (None, "database_error"),
+            AppError::Internal(_) => (None, "internal_error"),
+        };
+
+        match message {
+            Some(message) if !message.is_raw() => message.key(),
+            _ => fallback,
+        }
+    }
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
+        let locale = Locale::current();
         let (status, error_message) = match &self {
-            AppError::NotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
-            AppError::BadRequest(_) => (StatusCode::BAD_REQUEST, self.to_string()),
-            AppError::Validation(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            AppError::NotFound(message) => (StatusCode::NOT_FOUND, message.localize(locale)),
+            AppError::BadRequest(message) => (StatusCode::BAD_REQUEST, message.localize(locale)),
+            AppError::Validation(message) => (StatusCode::BAD_REQUEST, message.localize(locale)),
+            AppError::Unauthorized(message) => (StatusCode::UNAUTHORIZED, message.localize(locale)),
+            AppError::Forbidden(message) => (StatusCode::FORBIDDEN, message.localize(locale)),
+            AppError::Unprocessable { message, .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, message.localize(locale))
+            }
+            AppError::Conflict { message, .. } => (StatusCode::CONFLICT, message.localize(locale)),
+            AppError::Timeout(message) => (StatusCode::REQUEST_TIMEOUT, message.localize(locale)),
+            AppError::PayloadTooLarge(message) => {
+                (StatusCode::PAYLOAD_TOO_LARGE, message.localize(locale))
+            }
+            AppError::TooManyRequests(message) => {
+                (StatusCode::TOO_MANY_REQUESTS, message.localize(locale))
+            }
+            AppError::ServiceUnavailable(message) => {
+                (StatusCode::SERVICE_UNAVAILABLE, message.localize(locale))
+            }
             AppError::Database(e) => {
                 tracing::error!("Database error: {:?}", e);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
-                    "Internal server error".to_string(),
+                    Message::new("error.internal").localize(locale),
+                )
+            }
+            AppError::Internal(message) => {
+                tracing::error!("Internal error: {}", message);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Message::new("error.internal").localize(locale),
                 )
             }
-            AppError::Internal(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
         };
 
-        let body = Json(json!({
+        let mut body = json!({
             "success": false,
+            "code": self.code(),
             "error": error_message,
-        }));
+        });
+        match &self {
+            AppError::Conflict {
+                existing_id: Some(id),
+                ..
+            } => body["existing_id"] = json!(id),
+            AppError::Unprocessable { fields, .. } => {
+                body["fields"] = fields
+                    .iter()
+                    .map(|field| {
+                        json!({
+                            "field": field.field,
+                            "code": field.message.key(),
+                            "error": field.message.localize(locale),
+                        })
+                    })
+                    .collect();
+            }
+            _ => {}
+        }
+        let body = Json(body);
 
-        (status, body).into_response()
+        let mut response = (status, body).into_response();
+        if status.is_server_error() {
+            response
+                .extensions_mut()
+                .insert(ServerError(Arc::new(self)));
+        }
+        response
     }
 }
 
+/// The failure behind a 5xx response, left on the response for the API
+/// layer to report
+#[derive(Debug, Clone)]
+pub struct ServerError(pub Arc<AppError>);
+
 pub type DomainResult<T> = Result<T, AppError>;
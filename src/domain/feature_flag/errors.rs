@@ -0,0 +1,22 @@
+//! Feature Flag Domain Specific Errors
+
+use crate::domain::errors::AppError;
+use crate::i18n::Message;
+
+/// Feature flag error constructors
+pub struct FeatureFlagError;
+
+impl FeatureFlagError {
+    pub fn invalid_key(key: &str, max: usize) -> AppError {
+        AppError::validation(
+            Message::new("feature_flag.key.invalid")
+                .arg("key", key)
+                .arg("max", max),
+        )
+    }
+
+    /// Disabled features are indistinguishable from missing routes
+    pub fn disabled(key: &str) -> AppError {
+        AppError::not_found(Message::new("feature_flag.disabled").arg("key", key))
+    }
+}
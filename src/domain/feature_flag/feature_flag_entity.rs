@@ -0,0 +1,84 @@
+//! Feature Flag Entity
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::errors::DomainResult;
+use crate::domain::feature_flag::errors::FeatureFlagError;
+
+/// Runtime switch for a feature, identified by a stable key
+#[derive(Debug, Clone)]
+pub struct FeatureFlag {
+    key: String,
+    enabled: bool,
+    description: Option<String>,
+    updated_at: DateTime<Utc>,
+}
+
+impl FeatureFlag {
+    /// Maximum key length, matching the `VARCHAR(64)` column
+    pub const MAX_KEY_LENGTH: usize = 64;
+
+    /// Create a flag, validating its key
+    pub fn new(
+        key: impl Into<String>,
+        enabled: bool,
+        description: Option<String>,
+    ) -> DomainResult<Self> {
+        let key = key.into();
+        Self::validate_key(&key)?;
+
+        Ok(Self {
+            key,
+            enabled,
+            description: description.filter(|d| !d.trim().is_empty()),
+            updated_at: Utc::now(),
+        })
+    }
+
+    /// Reconstruct a flag from persistence layer
+    pub fn from_persistence(
+        key: String,
+        enabled: bool,
+        description: Option<String>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            key,
+            enabled,
+            description,
+            updated_at,
+        }
+    }
+
+    /// Keys are lowercase ASCII letters, digits, `_`, `-` and `.`
+    pub fn validate_key(key: &str) -> DomainResult<()> {
+        let valid = !key.is_empty()
+            && key.len() <= Self::MAX_KEY_LENGTH
+            && key.bytes().all(|b| {
+                b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'_' | b'-' | b'.')
+            });
+
+        if valid {
+            Ok(())
+        } else {
+            Err(FeatureFlagError::invalid_key(key, Self::MAX_KEY_LENGTH))
+        }
+    }
+
+    // Getters
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
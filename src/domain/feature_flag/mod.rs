@@ -0,0 +1,7 @@
+//! Feature Flag Domain Module
+
+pub mod errors;
+pub mod feature_flag_entity;
+
+pub use errors::FeatureFlagError;
+pub use feature_flag_entity::FeatureFlag;
@@ -0,0 +1,207 @@
+//! Flower Attributes
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::errors::FlowerError;
+
+/// How strongly a flower smells
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Fragrance {
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+impl Fragrance {
+    pub const ALL: [Fragrance; 4] = [
+        Fragrance::None,
+        Fragrance::Low,
+        Fragrance::Medium,
+        Fragrance::High,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Fragrance::None => "none",
+            Fragrance::Low => "low",
+            Fragrance::Medium => "medium",
+            Fragrance::High => "high",
+        }
+    }
+}
+
+impl fmt::Display for Fragrance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Fragrance {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let normalized = value.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|fragrance| fragrance.as_str() == normalized)
+            .ok_or_else(|| FlowerError::attribute_invalid("fragrance", value.trim()))
+    }
+}
+
+/// Attributes that only some flowers have, stored as one JSON document
+///
+/// Every attribute is optional: a cut tulip has a vase life, a potted
+/// orchid care instructions instead. Unknown attributes are rejected so
+/// typos do not end up stored and never matched by filters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(example = json!({
+    "fragrance": "high",
+    "stem_length_cm": 60,
+    "vase_life_days": 7,
+    "care_instructions": "Trim stems at an angle and change the water every other day"
+}))]
+pub struct FlowerAttributes {
+    /// How strongly the flower smells
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fragrance: Option<Fragrance>,
+    /// Stem length in centimeters (1-300)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stem_length_cm: Option<u16>,
+    /// Days the flower lasts in a vase (1-60)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vase_life_days: Option<u16>,
+    /// How to look after the flower (max 1000 characters)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub care_instructions: Option<String>,
+}
+
+impl FlowerAttributes {
+    /// Longest stem, in centimeters
+    pub const MAX_STEM_LENGTH_CM: u16 = 300;
+
+    /// Longest vase life, in days
+    pub const MAX_VASE_LIFE_DAYS: u16 = 60;
+
+    /// Maximum care instructions length in characters
+    pub const MAX_CARE_INSTRUCTIONS_LENGTH: usize = 1000;
+
+    /// Check the attributes against their bounds, trimming the care
+    /// instructions and dropping them when blank
+    pub fn validated(mut self) -> DomainResult<Self> {
+        if let Some(length) = self.stem_length_cm
+            && !(1..=Self::MAX_STEM_LENGTH_CM).contains(&length)
+        {
+            return Err(FlowerError::attribute_out_of_range(
+                "stem_length_cm",
+                Self::MAX_STEM_LENGTH_CM,
+            ));
+        }
+        if let Some(days) = self.vase_life_days
+            && !(1..=Self::MAX_VASE_LIFE_DAYS).contains(&days)
+        {
+            return Err(FlowerError::attribute_out_of_range(
+                "vase_life_days",
+                Self::MAX_VASE_LIFE_DAYS,
+            ));
+        }
+        self.care_instructions = self
+            .care_instructions
+            .map(|care| care.trim().to_string())
+            .filter(|care| !care.is_empty());
+        if self
+            .care_instructions
+            .as_ref()
+            .is_some_and(|care| care.chars().count() > Self::MAX_CARE_INSTRUCTIONS_LENGTH)
+        {
+            return Err(FlowerError::care_instructions_too_long(
+                Self::MAX_CARE_INSTRUCTIONS_LENGTH,
+            ));
+        }
+        Ok(self)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether every attribute set in `filter` has the same value here
+    pub fn contains(&self, filter: &FlowerAttributes) -> bool {
+        filter
+            .fragrance
+            .is_none_or(|fragrance| self.fragrance == Some(fragrance))
+            && filter
+                .stem_length_cm
+                .is_none_or(|length| self.stem_length_cm == Some(length))
+            && filter
+                .vase_life_days
+                .is_none_or(|days| self.vase_life_days == Some(days))
+            && filter
+                .care_instructions
+                .as_ref()
+                .is_none_or(|care| self.care_instructions.as_ref() == Some(care))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attributes_are_checked_against_their_bounds() {
+        let tulip = FlowerAttributes {
+            vase_life_days: Some(7),
+            care_instructions: Some("  Keep away from fruit  ".to_string()),
+            ..Default::default()
+        }
+        .validated()
+        .unwrap();
+        assert_eq!(
+            tulip.care_instructions.as_deref(),
+            Some("Keep away from fruit")
+        );
+
+        let blank = FlowerAttributes {
+            care_instructions: Some("   ".to_string()),
+            ..Default::default()
+        };
+        assert!(blank.validated().unwrap().is_empty());
+
+        let giant = FlowerAttributes {
+            stem_length_cm: Some(FlowerAttributes::MAX_STEM_LENGTH_CM + 1),
+            ..Default::default()
+        };
+        assert_eq!(
+            giant.validated().unwrap_err().code(),
+            "flower.attributes.out_of_range"
+        );
+
+        let unknown = serde_json::from_str::<FlowerAttributes>(r#"{"colour": "red"}"#);
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    fn filters_match_attributes_they_set() {
+        let rose = FlowerAttributes {
+            fragrance: Some(Fragrance::High),
+            stem_length_cm: Some(60),
+            ..Default::default()
+        };
+        let fragrant = FlowerAttributes {
+            fragrance: Some("HIGH".parse().unwrap()),
+            ..Default::default()
+        };
+
+        assert!(rose.contains(&fragrant));
+        assert!(rose.contains(&FlowerAttributes::default()));
+        assert!(!FlowerAttributes::default().contains(&fragrant));
+        assert!("strong".parse::<Fragrance>().is_err());
+    }
+}
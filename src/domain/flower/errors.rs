@@ -3,24 +3,180 @@
 use uuid::Uuid;
 
 use crate::domain::errors::AppError;
+use crate::i18n::Message;
 
 /// Flower-specific error constructors
 pub struct FlowerError;
 
 impl FlowerError {
     pub fn not_found(id: Uuid) -> AppError {
-        AppError::not_found(format!("Flower not found with id: {}", id))
+        AppError::not_found(Message::new("flower.not_found").arg("id", id))
     }
 
-    pub fn invalid_name(reason: impl Into<String>) -> AppError {
-        AppError::validation(format!("Invalid flower name: {}", reason.into()))
+    pub fn name_empty() -> AppError {
+        AppError::validation(Message::new("flower.name.empty"))
     }
 
-    pub fn invalid_color(reason: impl Into<String>) -> AppError {
-        AppError::validation(format!("Invalid flower color: {}", reason.into()))
+    pub fn name_too_long(max: usize) -> AppError {
+        AppError::validation(Message::new("flower.name.too_long").arg("max", max))
     }
 
-    pub fn insufficient_stock() -> AppError {
-        AppError::validation("Insufficient stock".to_string())
+    pub fn name_taken(name: &str, existing_id: Option<Uuid>) -> AppError {
+        AppError::conflict(
+            Message::new("flower.name.taken").arg("name", name),
+            existing_id,
+        )
+    }
+
+    pub fn color_empty() -> AppError {
+        AppError::validation(Message::new("flower.color.empty"))
+    }
+
+    pub fn unsupported_color(value: &str, allowed: &str) -> AppError {
+        AppError::validation(
+            Message::new("flower.color.unsupported")
+                .arg("value", value)
+                .arg("allowed", allowed),
+        )
+    }
+
+    pub fn description_empty() -> AppError {
+        AppError::validation(Message::new("flower.description.empty"))
+    }
+
+    pub fn description_too_long(max: usize) -> AppError {
+        AppError::validation(Message::new("flower.description.too_long").arg("max", max))
+    }
+
+    pub fn sku_invalid(value: &str, max: usize) -> AppError {
+        AppError::validation(
+            Message::new("flower.sku.invalid")
+                .arg("value", value)
+                .arg("max", max),
+        )
+    }
+
+    pub fn sku_taken(sku: &str, existing_id: Option<Uuid>) -> AppError {
+        AppError::conflict(
+            Message::new("flower.sku.taken").arg("sku", sku),
+            existing_id,
+        )
+    }
+
+    pub fn sku_missing(id: Uuid) -> AppError {
+        AppError::not_found(Message::new("flower.sku.missing").arg("id", id))
+    }
+
+    pub fn sku_incomplete(sku: &str) -> AppError {
+        AppError::validation(Message::new("flower.sku.incomplete").arg("sku", sku))
+    }
+
+    pub fn attribute_invalid(name: &str, value: &str) -> AppError {
+        AppError::validation(
+            Message::new("flower.attributes.invalid")
+                .arg("name", name)
+                .arg("value", value),
+        )
+    }
+
+    pub fn attribute_out_of_range(name: &str, max: u16) -> AppError {
+        AppError::validation(
+            Message::new("flower.attributes.out_of_range")
+                .arg("name", name)
+                .arg("max", max),
+        )
+    }
+
+    pub fn care_instructions_too_long(max: usize) -> AppError {
+        AppError::validation(
+            Message::new("flower.attributes.care_instructions_too_long").arg("max", max),
+        )
+    }
+
+    pub fn metadata_key_invalid(key: &str, max: usize) -> AppError {
+        AppError::validation(
+            Message::new("flower.metadata.key_invalid")
+                .arg("key", key)
+                .arg("max", max),
+        )
+    }
+
+    pub fn metadata_value_too_long(key: &str, max: usize) -> AppError {
+        AppError::validation(
+            Message::new("flower.metadata.value_too_long")
+                .arg("key", key)
+                .arg("max", max),
+        )
+    }
+
+    pub fn metadata_too_many(max: usize) -> AppError {
+        AppError::validation(Message::new("flower.metadata.too_many").arg("max", max))
+    }
+
+    pub fn metadata_filter_invalid(value: &str) -> AppError {
+        AppError::validation(Message::new("flower.metadata.filter_invalid").arg("value", value))
+    }
+
+    pub fn price_not_finite() -> AppError {
+        AppError::validation(Message::new("flower.price.not_finite"))
+    }
+
+    pub fn price_negative() -> AppError {
+        AppError::validation(Message::new("flower.price.negative"))
+    }
+
+    pub fn price_too_high(max: f64) -> AppError {
+        AppError::validation(Message::new("flower.price.too_high").arg("max", max))
+    }
+
+    pub fn invalid_price_adjustment(value: &str) -> AppError {
+        AppError::validation(Message::new("flower.price_adjustment.invalid").arg("value", value))
+    }
+
+    pub fn price_adjustment_unfiltered() -> AppError {
+        AppError::validation(Message::new("flower.price_adjustment.unfiltered"))
+    }
+
+    pub fn stock_negative() -> AppError {
+        AppError::validation(Message::new("flower.stock.negative"))
+    }
+
+    pub fn stock_too_high(max: i32) -> AppError {
+        AppError::validation(Message::new("flower.stock.too_high").arg("max", max))
+    }
+
+    pub fn stock_overflow() -> AppError {
+        AppError::validation(Message::new("flower.stock.overflow"))
+    }
+
+    pub fn negative_quantity() -> AppError {
+        AppError::validation(Message::new("flower.stock.negative_quantity"))
+    }
+
+    pub fn unit_unsupported(value: &str, allowed: &str) -> AppError {
+        AppError::validation(
+            Message::new("flower.unit.unsupported")
+                .arg("value", value)
+                .arg("allowed", allowed),
+        )
+    }
+
+    pub fn unit_bundle_size(max: i32) -> AppError {
+        AppError::validation(Message::new("flower.unit.bundle_size").arg("max", max))
+    }
+
+    pub fn unit_stems_fixed(unit: &str, stems: i32) -> AppError {
+        AppError::validation(
+            Message::new("flower.unit.stems_fixed")
+                .arg("unit", unit)
+                .arg("stems", stems),
+        )
+    }
+
+    pub fn insufficient_stock(available: i32) -> AppError {
+        AppError::unprocessable(
+            Message::new("flower.stock.insufficient").arg("available", available),
+            Vec::new(),
+        )
     }
 }
@@ -0,0 +1,158 @@
+//! Flower Change Entity
+
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::domain::flower::Flower;
+use crate::domain::shared::{Entity, TenantId, new_id};
+
+/// Entry of a flower's history: one field changed by an update, and who
+/// changed it
+///
+/// Like stock movements, changes are only ever appended and outlive the
+/// flower they refer to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowerChange {
+    id: Uuid,
+    tenant_id: TenantId,
+    flower_id: Uuid,
+    field: String,
+    old_value: Value,
+    new_value: Value,
+    actor: String,
+    changed_at: DateTime<Utc>,
+}
+
+impl FlowerChange {
+    /// One change per field that differs between `before` and `after`, two
+    /// versions of the same flower, made by `actor`
+    pub fn between(before: &Flower, after: &Flower, actor: &str) -> Vec<Self> {
+        let fields = [
+            ("name", json!(before.name()), json!(after.name())),
+            ("color", json!(before.color()), json!(after.color())),
+            (
+                "description",
+                json!(before.description()),
+                json!(after.description()),
+            ),
+            ("price", json!(before.price()), json!(after.price())),
+            ("stock", json!(before.stock()), json!(after.stock())),
+            ("unit", json!(before.unit()), json!(after.unit())),
+            ("sku", json!(before.sku()), json!(after.sku())),
+            (
+                "attributes",
+                json!(before.attributes()),
+                json!(after.attributes()),
+            ),
+            (
+                "metadata",
+                json!(before.metadata()),
+                json!(after.metadata()),
+            ),
+        ];
+
+        let changed_at = Utc::now();
+        fields
+            .into_iter()
+            .filter(|(_, old_value, new_value)| old_value != new_value)
+            .map(|(field, old_value, new_value)| Self {
+                id: new_id(),
+                tenant_id: after.tenant_id().clone(),
+                flower_id: after.id(),
+                field: field.to_string(),
+                old_value,
+                new_value,
+                actor: actor.to_string(),
+                changed_at,
+            })
+            .collect()
+    }
+
+    /// Reconstruct a change from the history
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_persistence(
+        id: Uuid,
+        tenant_id: TenantId,
+        flower_id: Uuid,
+        field: String,
+        old_value: Value,
+        new_value: Value,
+        actor: String,
+        changed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            flower_id,
+            field,
+            old_value,
+            new_value,
+            actor,
+            changed_at,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn flower_id(&self) -> Uuid {
+        self.flower_id
+    }
+
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    pub fn old_value(&self) -> &Value {
+        &self.old_value
+    }
+
+    pub fn new_value(&self) -> &Value {
+        &self.new_value
+    }
+
+    pub fn actor(&self) -> &str {
+        &self.actor
+    }
+
+    pub fn changed_at(&self) -> DateTime<Utc> {
+        self.changed_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::flower::{FlowerDescription, Price};
+    use crate::test_support::FlowerBuilder;
+
+    #[test]
+    fn records_only_the_fields_that_changed() {
+        let before = FlowerBuilder::new().with_price(25_000.0).build();
+        let mut after = before.clone();
+        after.update_price(Price::new(30_000.0).unwrap());
+        after.update_description(FlowerDescription::new("Fresh").unwrap());
+
+        let changes = FlowerChange::between(&before, &after, "admin");
+        let fields: Vec<_> = changes
+            .iter()
+            .map(|change| (change.field(), change.old_value(), change.new_value()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("description", &Value::Null, &json!("Fresh")),
+                ("price", &json!(25_000.0), &json!(30_000.0)),
+            ]
+        );
+        assert!(changes.iter().all(|change| change.actor() == "admin"));
+        assert!(FlowerChange::between(&before, &before, "admin").is_empty());
+    }
+}
@@ -5,139 +5,215 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::domain::errors::DomainResult;
-use crate::domain::shared::Entity;
+use crate::domain::shared::{Entity, TenantId, new_id};
 
+use crate::domain::flower::attributes::FlowerAttributes;
 use crate::domain::flower::errors::FlowerError;
+use crate::domain::flower::metadata::FlowerMetadata;
+use crate::domain::flower::value_objects::{
+    FlowerColor, FlowerDescription, FlowerName, Price, Sku, StockQuantity, UnitOfMeasure,
+};
 
 /// Flower entity representing a flower in the domain
+///
+/// `price` is per unit the flower is sold by, while `stock` counts stems.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Flower {
     id: Uuid,
-    name: String,
-    color: String,
-    description: Option<String>,
-    price: f64,
-    stock: i32,
+    tenant_id: TenantId,
+    name: FlowerName,
+    color: FlowerColor,
+    description: Option<FlowerDescription>,
+    price: Price,
+    stock: StockQuantity,
+    /// Unit, attributes and metadata are defaulted so flowers cached before
+    /// they existed still load
+    #[serde(default)]
+    unit: UnitOfMeasure,
+    sku: Option<Sku>,
+    #[serde(default)]
+    attributes: FlowerAttributes,
+    #[serde(default)]
+    metadata: FlowerMetadata,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
 impl Flower {
     /// Create a new Flower entity
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        name: String,
-        color: String,
-        description: Option<String>,
-        price: f64,
-        stock: i32,
+        tenant_id: TenantId,
+        name: FlowerName,
+        color: FlowerColor,
+        description: Option<FlowerDescription>,
+        price: Price,
+        stock: StockQuantity,
+        unit: UnitOfMeasure,
+        sku: Option<Sku>,
+        attributes: FlowerAttributes,
+        metadata: FlowerMetadata,
     ) -> DomainResult<Self> {
         let now = Utc::now();
         Ok(Self {
-            id: Uuid::new_v4(),
+            id: new_id(),
+            tenant_id,
             name,
             color,
             description,
             price,
             stock,
+            unit,
+            sku,
+            attributes: attributes.validated()?,
+            metadata,
             created_at: now,
             updated_at: now,
         })
     }
 
     /// Reconstruct a Flower from persistence layer
+    #[allow(clippy::too_many_arguments)]
     pub fn from_persistence(
         id: Uuid,
-        name: String,
-        color: String,
-        description: Option<String>,
-        price: f64,
-        stock: i32,
+        tenant_id: TenantId,
+        name: FlowerName,
+        color: FlowerColor,
+        description: Option<FlowerDescription>,
+        price: Price,
+        stock: StockQuantity,
+        unit: UnitOfMeasure,
+        sku: Option<Sku>,
+        attributes: FlowerAttributes,
+        metadata: FlowerMetadata,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> DomainResult<Self> {
         Ok(Self {
             id,
+            tenant_id,
             name,
             color,
             description,
             price,
             stock,
+            unit,
+            sku,
+            attributes,
+            metadata,
             created_at,
             updated_at,
         })
     }
 
     // Getters
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
     pub fn name(&self) -> &str {
-        &self.name
+        self.name.as_str()
     }
 
-    pub fn color(&self) -> &str {
-        &self.color
+    pub fn color(&self) -> FlowerColor {
+        self.color
     }
 
     pub fn description(&self) -> Option<&str> {
-        self.description.as_deref()
+        self.description.as_ref().map(FlowerDescription::as_str)
     }
 
     pub fn price(&self) -> f64 {
-        self.price
+        self.price.value()
     }
 
     pub fn stock(&self) -> i32 {
-        self.stock
+        self.stock.value()
+    }
+
+    pub fn unit(&self) -> UnitOfMeasure {
+        self.unit
+    }
+
+    /// Whole units in stock
+    pub fn units_in_stock(&self) -> i32 {
+        self.unit.units_in(self.stock())
+    }
+
+    pub fn sku(&self) -> Option<&str> {
+        self.sku.as_ref().map(Sku::as_str)
+    }
+
+    pub fn attributes(&self) -> &FlowerAttributes {
+        &self.attributes
+    }
+
+    pub fn metadata(&self) -> &FlowerMetadata {
+        &self.metadata
     }
 
     // Setters with basic validation
-    pub fn update_name(&mut self, name: String) -> DomainResult<()> {
-        if name.trim().is_empty() {
-            return Err(FlowerError::invalid_name("Name cannot be empty"));
-        }
-        if name.len() > 100 {
-            return Err(FlowerError::invalid_name("Name too long"));
-        }
-        self.name = name.trim().to_string();
+    pub fn update_name(&mut self, name: FlowerName) {
+        self.name = name;
         self.updated_at = Utc::now();
-        Ok(())
     }
 
-    pub fn update_color(&mut self, color: String) -> DomainResult<()> {
-        if color.trim().is_empty() {
-            return Err(FlowerError::invalid_color("Color cannot be empty"));
-        }
-        if color.len() > 50 {
-            return Err(FlowerError::invalid_color("Color too long"));
-        }
-        self.color = color.trim().to_lowercase();
+    pub fn update_color(&mut self, color: FlowerColor) {
+        self.color = color;
         self.updated_at = Utc::now();
-        Ok(())
     }
 
-    pub fn update_description(&mut self, description: Option<String>) {
+    pub fn update_description(&mut self, description: Option<FlowerDescription>) {
         self.description = description;
         self.updated_at = Utc::now();
     }
 
-    pub fn update_price(&mut self, price: f64) {
+    pub fn update_price(&mut self, price: Price) {
         self.price = price;
         self.updated_at = Utc::now();
     }
 
-    pub fn update_stock(&mut self, stock: i32) {
+    pub fn update_stock(&mut self, stock: StockQuantity) {
         self.stock = stock;
         self.updated_at = Utc::now();
     }
 
-    pub fn add_stock(&mut self, quantity: i32) {
-        self.stock += quantity;
+    pub fn update_unit(&mut self, unit: UnitOfMeasure) {
+        self.unit = unit;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn update_sku(&mut self, sku: Option<Sku>) {
+        self.sku = sku;
+        self.updated_at = Utc::now();
+    }
+
+    /// Replace all attributes at once
+    pub fn update_attributes(&mut self, attributes: FlowerAttributes) -> DomainResult<()> {
+        self.attributes = attributes.validated()?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    pub fn update_metadata(&mut self, metadata: FlowerMetadata) {
+        self.metadata = metadata;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn add_stock(&mut self, quantity: i32) -> DomainResult<()> {
+        if quantity < 0 {
+            return Err(FlowerError::negative_quantity());
+        }
+        self.stock = self.stock.increase(quantity)?;
         self.updated_at = Utc::now();
+        Ok(())
     }
 
     pub fn reduce_stock(&mut self, quantity: i32) -> DomainResult<()> {
-        if self.stock < quantity {
-            return Err(FlowerError::insufficient_stock());
+        if quantity < 0 {
+            return Err(FlowerError::negative_quantity());
         }
-        self.stock -= quantity;
+        self.stock = self.stock.decrease(quantity)?;
         self.updated_at = Utc::now();
         Ok(())
     }
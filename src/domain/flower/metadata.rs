@@ -0,0 +1,151 @@
+//! Flower Metadata
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::errors::FlowerError;
+
+/// Key-value pairs integrators keep on a flower, such as the item number
+/// in their ERP; the API stores them without interpreting them
+///
+/// Keys are namespaced as `namespace.name` so integrations do not step on
+/// each other's keys.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+#[schema(example = json!({ "erp.item_id": "ITM-00042", "erp.warehouse": "JKT-1" }))]
+pub struct FlowerMetadata(BTreeMap<String, String>);
+
+impl FlowerMetadata {
+    /// Most entries a flower can carry
+    pub const MAX_ENTRIES: usize = 32;
+
+    /// Maximum key length in bytes; keys are ASCII
+    pub const MAX_KEY_LENGTH: usize = 64;
+
+    /// Maximum value length in characters
+    pub const MAX_VALUE_LENGTH: usize = 256;
+
+    /// Check every entry, and that there are not too many
+    pub fn new(entries: BTreeMap<String, String>) -> DomainResult<Self> {
+        if entries.len() > Self::MAX_ENTRIES {
+            return Err(FlowerError::metadata_too_many(Self::MAX_ENTRIES));
+        }
+        for (key, value) in &entries {
+            Self::check_key(key)?;
+            if value.chars().count() > Self::MAX_VALUE_LENGTH {
+                return Err(FlowerError::metadata_value_too_long(
+                    key,
+                    Self::MAX_VALUE_LENGTH,
+                ));
+            }
+        }
+        Ok(Self(entries))
+    }
+
+    /// Metadata with a single entry, such as a filter parsed from
+    /// `namespace.name:value`
+    pub fn parse_entry(entry: &str) -> DomainResult<Self> {
+        let (key, value) = entry
+            .split_once(':')
+            .ok_or_else(|| FlowerError::metadata_filter_invalid(entry))?;
+        Self::new(BTreeMap::from([(
+            key.trim().to_string(),
+            value.to_string(),
+        )]))
+    }
+
+    /// A namespace and a name, each made of lower case letters, digits, `_`
+    /// and `-`, joined by a dot
+    fn check_key(key: &str) -> DomainResult<()> {
+        let part = |part: &str| {
+            !part.is_empty()
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-')
+        };
+        let valid = key.len() <= Self::MAX_KEY_LENGTH
+            && key
+                .split_once('.')
+                .is_some_and(|(namespace, name)| part(namespace) && name.split('.').all(part));
+        if !valid {
+            return Err(FlowerError::metadata_key_invalid(key, Self::MAX_KEY_LENGTH));
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether every entry of `filter` is here with the same value
+    pub fn contains(&self, filter: &FlowerMetadata) -> bool {
+        filter
+            .0
+            .iter()
+            .all(|(key, value)| self.0.get(key) == Some(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(entries: &[(&str, &str)]) -> DomainResult<FlowerMetadata> {
+        FlowerMetadata::new(
+            entries
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn keys_are_namespaced() {
+        assert!(metadata(&[("erp.item_id", "ITM-1"), ("pos.shelf.row", "3")]).is_ok());
+        for key in ["item_id", ".item_id", "erp.", "ERP.item_id", "erp.item id"] {
+            assert_eq!(
+                metadata(&[(key, "x")]).unwrap_err().code(),
+                "flower.metadata.key_invalid",
+                "{key}"
+            );
+        }
+        let long_key = format!("erp.{}", "k".repeat(FlowerMetadata::MAX_KEY_LENGTH));
+        assert!(metadata(&[(&long_key, "x")]).is_err());
+    }
+
+    #[test]
+    fn size_is_limited() {
+        let long_value = "v".repeat(FlowerMetadata::MAX_VALUE_LENGTH + 1);
+        assert_eq!(
+            metadata(&[("erp.note", &long_value)]).unwrap_err().code(),
+            "flower.metadata.value_too_long"
+        );
+
+        let keys: Vec<String> = (0..=FlowerMetadata::MAX_ENTRIES)
+            .map(|i| format!("erp.key_{i}"))
+            .collect();
+        let entries: Vec<(&str, &str)> = keys.iter().map(|key| (key.as_str(), "x")).collect();
+        assert_eq!(
+            metadata(&entries).unwrap_err().code(),
+            "flower.metadata.too_many"
+        );
+    }
+
+    #[test]
+    fn filters_match_exact_entries() {
+        let stored = metadata(&[("erp.item_id", "ITM-1"), ("erp.warehouse", "JKT")]).unwrap();
+
+        let filter = FlowerMetadata::parse_entry("erp.item_id:ITM-1").unwrap();
+        assert!(stored.contains(&filter));
+        assert!(!stored.contains(&FlowerMetadata::parse_entry("erp.item_id:itm-1").unwrap()));
+        assert_eq!(
+            FlowerMetadata::parse_entry("erp.item_id")
+                .unwrap_err()
+                .code(),
+            "flower.metadata.filter_invalid"
+        );
+    }
+}
@@ -1,8 +1,19 @@
 //! Flower Domain Module
 
+pub mod attributes;
 pub mod errors;
+pub mod flower_change;
 pub mod flower_entity;
+pub mod metadata;
+pub mod value_objects;
 
-// Re-export the Flower entity and FlowerError
-pub use flower_entity::Flower;
+// Re-export the Flower entities, FlowerError and value objects
+pub use attributes::{FlowerAttributes, Fragrance};
 pub use errors::FlowerError;
+pub use flower_change::FlowerChange;
+pub use flower_entity::Flower;
+pub use metadata::FlowerMetadata;
+pub use value_objects::{
+    FlowerColor, FlowerDescription, FlowerName, Price, PriceAdjustment, Sku, StockQuantity,
+    UnitOfMeasure,
+};
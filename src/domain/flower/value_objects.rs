@@ -0,0 +1,718 @@
+//! Flower Value Objects
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::errors::AppError;
+use crate::domain::flower::errors::FlowerError;
+
+/// Flower name, trimmed and limited in characters rather than bytes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct FlowerName(String);
+
+impl FlowerName {
+    /// Maximum name length in characters, matching the `VARCHAR(100)` column
+    pub const MAX_LENGTH: usize = 100;
+
+    pub fn new(value: impl AsRef<str>) -> Result<Self, AppError> {
+        let trimmed = value.as_ref().trim();
+        if trimmed.is_empty() {
+            return Err(FlowerError::name_empty());
+        }
+        if trimmed.chars().count() > Self::MAX_LENGTH {
+            return Err(FlowerError::name_too_long(Self::MAX_LENGTH));
+        }
+        Ok(Self(trimmed.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for FlowerName {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<FlowerName> for String {
+    fn from(name: FlowerName) -> Self {
+        name.0
+    }
+}
+
+/// Canonical set of flower colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FlowerColor {
+    Red,
+    White,
+    Pink,
+    Yellow,
+    Orange,
+    Purple,
+    Blue,
+    Peach,
+    Mixed,
+}
+
+impl FlowerColor {
+    /// All supported colors, in display order
+    pub const ALL: [FlowerColor; 9] = [
+        FlowerColor::Red,
+        FlowerColor::White,
+        FlowerColor::Pink,
+        FlowerColor::Yellow,
+        FlowerColor::Orange,
+        FlowerColor::Purple,
+        FlowerColor::Blue,
+        FlowerColor::Peach,
+        FlowerColor::Mixed,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FlowerColor::Red => "red",
+            FlowerColor::White => "white",
+            FlowerColor::Pink => "pink",
+            FlowerColor::Yellow => "yellow",
+            FlowerColor::Orange => "orange",
+            FlowerColor::Purple => "purple",
+            FlowerColor::Blue => "blue",
+            FlowerColor::Peach => "peach",
+            FlowerColor::Mixed => "mixed",
+        }
+    }
+
+    /// Comma separated list of allowed values, used in error messages
+    pub fn allowed_values() -> String {
+        Self::ALL
+            .iter()
+            .map(FlowerColor::as_str)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl fmt::Display for FlowerColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FlowerColor {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let normalized = value.trim().to_lowercase();
+        if normalized.is_empty() {
+            return Err(FlowerError::color_empty());
+        }
+
+        Self::ALL
+            .into_iter()
+            .find(|color| color.as_str() == normalized)
+            .ok_or_else(|| FlowerError::unsupported_color(value.trim(), &Self::allowed_values()))
+    }
+}
+
+/// Flower price in IDR, never negative
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Price(f64);
+
+impl Price {
+    /// Upper bound to catch obvious input mistakes (one billion IDR)
+    pub const MAX: f64 = 1_000_000_000.0;
+
+    pub fn new(value: f64) -> Result<Self, AppError> {
+        if !value.is_finite() {
+            return Err(FlowerError::price_not_finite());
+        }
+        if value < 0.0 {
+            return Err(FlowerError::price_negative());
+        }
+        if value > Self::MAX {
+            return Err(FlowerError::price_too_high(Self::MAX));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for Price {
+    type Error = AppError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Price> for f64 {
+    fn from(price: Price) -> Self {
+        price.0
+    }
+}
+
+/// Relative price change: a percentage such as `+10%` or `-15%`, or an
+/// amount in IDR such as `-5000`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PriceAdjustment {
+    Percent(f64),
+    Amount(f64),
+}
+
+impl PriceAdjustment {
+    /// New price after the adjustment, rounded to two decimals so percentage
+    /// changes do not leave floating point noise behind
+    pub fn apply(self, price: f64) -> Result<Price, AppError> {
+        let adjusted = match self {
+            PriceAdjustment::Percent(percent) => price * (1.0 + percent / 100.0),
+            PriceAdjustment::Amount(amount) => price + amount,
+        };
+        Price::new((adjusted * 100.0).round() / 100.0)
+    }
+}
+
+impl FromStr for PriceAdjustment {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        let (number, percent) = match trimmed.strip_suffix('%') {
+            Some(number) => (number.trim_end(), true),
+            None => (trimmed, false),
+        };
+        let number: f64 = number
+            .parse()
+            .ok()
+            .filter(|number: &f64| number.is_finite())
+            .ok_or_else(|| FlowerError::invalid_price_adjustment(trimmed))?;
+
+        Ok(if percent {
+            PriceAdjustment::Percent(number)
+        } else {
+            PriceAdjustment::Amount(number)
+        })
+    }
+}
+
+/// Quantity of stems in stock, never negative
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "i32", into = "i32")]
+pub struct StockQuantity(i32);
+
+impl StockQuantity {
+    /// Upper bound on the stock a single flower can hold
+    pub const MAX: i32 = 1_000_000;
+
+    pub fn new(value: i32) -> Result<Self, AppError> {
+        if value < 0 {
+            return Err(FlowerError::stock_negative());
+        }
+        if value > Self::MAX {
+            return Err(FlowerError::stock_too_high(Self::MAX));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+
+    /// Add stock, failing if the result would exceed the maximum
+    pub fn increase(self, quantity: i32) -> Result<Self, AppError> {
+        let total = self
+            .0
+            .checked_add(quantity)
+            .ok_or_else(FlowerError::stock_overflow)?;
+        Self::new(total)
+    }
+
+    /// Remove stock, failing if there is not enough available
+    pub fn decrease(self, quantity: i32) -> Result<Self, AppError> {
+        if quantity < 0 {
+            return Err(FlowerError::negative_quantity());
+        }
+        if quantity > self.0 {
+            return Err(FlowerError::insufficient_stock(self.0));
+        }
+        Self::new(self.0 - quantity)
+    }
+}
+
+impl TryFrom<i32> for StockQuantity {
+    type Error = AppError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<StockQuantity> for i32 {
+    fn from(stock: StockQuantity) -> Self {
+        stock.0
+    }
+}
+
+/// What a flower is sold by: single stems, dozens, or bundles of a fixed
+/// number of stems
+///
+/// Stock is always counted in stems; the unit converts the quantities
+/// customers buy into the stems they take from it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    tag = "kind",
+    content = "stems",
+    rename_all = "lowercase",
+    try_from = "RawUnitOfMeasure"
+)]
+pub enum UnitOfMeasure {
+    #[default]
+    Stem,
+    Dozen,
+    Bundle(i32),
+}
+
+impl UnitOfMeasure {
+    /// Most stems a bundle can hold
+    pub const MAX_BUNDLE_STEMS: i32 = 1_000;
+
+    /// Allowed unit names, used in error messages
+    pub const NAMES: [&'static str; 3] = ["stem", "dozen", "bundle"];
+
+    /// Unit named `name`; bundles need their number of stems, which other
+    /// units may only repeat
+    pub fn new(name: &str, stems: Option<i32>) -> Result<Self, AppError> {
+        let unit = match name.trim().to_lowercase().as_str() {
+            "stem" => UnitOfMeasure::Stem,
+            "dozen" => UnitOfMeasure::Dozen,
+            "bundle" => match stems {
+                Some(stems) if (2..=Self::MAX_BUNDLE_STEMS).contains(&stems) => {
+                    UnitOfMeasure::Bundle(stems)
+                }
+                _ => return Err(FlowerError::unit_bundle_size(Self::MAX_BUNDLE_STEMS)),
+            },
+            _ => {
+                return Err(FlowerError::unit_unsupported(
+                    name.trim(),
+                    &Self::NAMES.join(", "),
+                ));
+            }
+        };
+        if stems.is_some_and(|stems| stems != unit.stems()) {
+            return Err(FlowerError::unit_stems_fixed(unit.as_str(), unit.stems()));
+        }
+        Ok(unit)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnitOfMeasure::Stem => "stem",
+            UnitOfMeasure::Dozen => "dozen",
+            UnitOfMeasure::Bundle(_) => "bundle",
+        }
+    }
+
+    /// Stems in one unit
+    pub fn stems(&self) -> i32 {
+        match self {
+            UnitOfMeasure::Stem => 1,
+            UnitOfMeasure::Dozen => 12,
+            UnitOfMeasure::Bundle(stems) => *stems,
+        }
+    }
+
+    /// Stems in `units` units, failing past the most stock a flower can hold
+    pub fn to_stems(&self, units: i32) -> Result<i32, AppError> {
+        units
+            .checked_mul(self.stems())
+            .filter(|stems| *stems <= StockQuantity::MAX)
+            .ok_or_else(|| FlowerError::stock_too_high(StockQuantity::MAX))
+    }
+
+    /// Whole units that can be made of `stems`
+    pub fn units_in(&self, stems: i32) -> i32 {
+        stems / self.stems()
+    }
+}
+
+/// Serialized form of a `UnitOfMeasure`, checked by `UnitOfMeasure::new`
+#[derive(Deserialize)]
+struct RawUnitOfMeasure {
+    kind: String,
+    stems: Option<i32>,
+}
+
+impl TryFrom<RawUnitOfMeasure> for UnitOfMeasure {
+    type Error = AppError;
+
+    fn try_from(raw: RawUnitOfMeasure) -> Result<Self, Self::Error> {
+        Self::new(&raw.kind, raw.stems)
+    }
+}
+
+impl fmt::Display for UnitOfMeasure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Sanitized flower description, safe to render as HTML
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct FlowerDescription(String);
+
+impl FlowerDescription {
+    /// Maximum description length in characters
+    pub const MAX_LENGTH: usize = 500;
+
+    /// Basic formatting tags storefronts may render
+    const ALLOWED_TAGS: [&'static str; 8] = ["b", "strong", "i", "em", "p", "br", "ul", "li"];
+
+    /// Validate and sanitize a description; blank input yields `None`
+    ///
+    /// The limit holds for what is stored as well: escaping can make a
+    /// description several times longer, such as `<` becoming `&lt;`.
+    pub fn new(value: impl AsRef<str>) -> Result<Option<Self>, AppError> {
+        let trimmed = value.as_ref().trim();
+        if trimmed.is_empty() {
+            return Ok(None);
+        }
+        if trimmed.chars().count() > Self::MAX_LENGTH {
+            return Err(FlowerError::description_too_long(Self::MAX_LENGTH));
+        }
+        let sanitized = Self::sanitize(trimmed);
+        if sanitized.chars().count() > Self::MAX_LENGTH {
+            return Err(FlowerError::description_too_long(Self::MAX_LENGTH));
+        }
+        Ok(Some(Self(sanitized)))
+    }
+
+    /// Re-sanitize a stored description without enforcing the length limit
+    pub fn from_persistence(value: impl AsRef<str>) -> Option<Self> {
+        let trimmed = value.as_ref().trim();
+        (!trimmed.is_empty()).then(|| Self(Self::sanitize(trimmed)))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn sanitize(value: &str) -> String {
+        ammonia::Builder::empty()
+            .add_tags(Self::ALLOWED_TAGS)
+            .clean(value)
+            .to_string()
+            .trim()
+            .to_string()
+    }
+}
+
+impl TryFrom<String> for FlowerDescription {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)?.ok_or_else(FlowerError::description_empty)
+    }
+}
+
+impl From<FlowerDescription> for String {
+    fn from(description: FlowerDescription) -> Self {
+        description.0
+    }
+}
+
+/// Stock keeping unit, stored upper case and printable as a Code 128 barcode
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Sku(String);
+
+impl Sku {
+    /// Maximum length, matching the `VARCHAR(32)` column and short enough for
+    /// a shelf label
+    pub const MAX_LENGTH: usize = 32;
+
+    /// Validate a SKU; blank input yields `None`
+    pub fn new(value: impl AsRef<str>) -> Result<Option<Self>, AppError> {
+        let normalized = value.as_ref().trim().to_uppercase();
+        if normalized.is_empty() {
+            return Ok(None);
+        }
+        let valid = normalized.len() <= Self::MAX_LENGTH
+            && normalized
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'/'));
+        if !valid {
+            return Err(FlowerError::sku_invalid(
+                value.as_ref().trim(),
+                Self::MAX_LENGTH,
+            ));
+        }
+        Ok(Some(Self(normalized)))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Sku {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)?.ok_or_else(|| FlowerError::sku_invalid(&value, Self::MAX_LENGTH))
+    }
+}
+
+impl From<Sku> for String {
+    fn from(sku: Sku) -> Self {
+        sku.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn skus_are_normalized_and_restricted() {
+        assert_eq!(Sku::new("  ro-12/a ").unwrap().unwrap().as_str(), "RO-12/A");
+        assert!(Sku::new("   ").unwrap().is_none());
+        assert!(Sku::new("RO 12").is_err());
+        assert!(Sku::new("Å-1").is_err());
+        assert!(Sku::new("X".repeat(Sku::MAX_LENGTH + 1)).is_err());
+
+        let sku: Sku = serde_json::from_str(r#"" ro-12/a""#).unwrap();
+        assert_eq!(sku.as_str(), "RO-12/A");
+        assert!(serde_json::from_str::<Sku>(r#""RO 12""#).is_err());
+        assert!(serde_json::from_str::<Sku>(r#""   ""#).is_err());
+    }
+
+    #[test]
+    fn price_adjustments_parse_percentages_and_amounts() {
+        let raise: PriceAdjustment = "+10%".parse().unwrap();
+        assert_eq!(raise, PriceAdjustment::Percent(10.0));
+        assert_eq!(raise.apply(25_000.0).unwrap().value(), 27_500.0);
+
+        let discount: PriceAdjustment = "-5000".parse().unwrap();
+        assert_eq!(discount.apply(25_000.0).unwrap().value(), 20_000.0);
+        assert!(discount.apply(1_000.0).is_err());
+
+        assert!("ten percent".parse::<PriceAdjustment>().is_err());
+        assert!("%".parse::<PriceAdjustment>().is_err());
+    }
+
+    #[test]
+    fn units_convert_to_stems() {
+        assert_eq!(
+            UnitOfMeasure::new("stem", None).unwrap(),
+            UnitOfMeasure::Stem
+        );
+        let dozen = UnitOfMeasure::new(" Dozen ", Some(12)).unwrap();
+        assert_eq!(dozen.to_stems(3).unwrap(), 36);
+        assert_eq!(dozen.units_in(30), 2);
+
+        let bundle = UnitOfMeasure::new("bundle", Some(10)).unwrap();
+        assert_eq!(bundle, UnitOfMeasure::Bundle(10));
+        assert_eq!(bundle.to_stems(5).unwrap(), 50);
+        assert!(bundle.to_stems(StockQuantity::MAX).is_err());
+
+        assert!(UnitOfMeasure::new("bundle", None).is_err());
+        assert!(UnitOfMeasure::new("bundle", Some(1)).is_err());
+        assert!(UnitOfMeasure::new("dozen", Some(10)).is_err());
+        assert!(UnitOfMeasure::new("box", None).is_err());
+    }
+
+    #[test]
+    fn units_are_checked_when_deserialized() {
+        let bundle = UnitOfMeasure::Bundle(10);
+        let json = serde_json::to_string(&bundle).unwrap();
+        assert_eq!(json, r#"{"kind":"bundle","stems":10}"#);
+        assert_eq!(
+            serde_json::from_str::<UnitOfMeasure>(&json).unwrap(),
+            bundle
+        );
+        assert_eq!(
+            serde_json::from_str::<UnitOfMeasure>(r#"{"kind":"stem"}"#).unwrap(),
+            UnitOfMeasure::Stem
+        );
+
+        for stems in [0, 1] {
+            let json = format!(r#"{{"kind":"bundle","stems":{}}}"#, stems);
+            assert!(serde_json::from_str::<UnitOfMeasure>(&json).is_err());
+        }
+    }
+
+    #[test]
+    fn name_length_counts_characters_not_bytes() {
+        let cjk = "玫".repeat(FlowerName::MAX_LENGTH);
+        assert_eq!(cjk.len(), 300);
+        assert_eq!(FlowerName::new(&cjk).unwrap().as_str(), cjk);
+
+        let too_long = "玫".repeat(FlowerName::MAX_LENGTH + 1);
+        assert!(FlowerName::new(too_long).is_err());
+    }
+
+    #[test]
+    fn name_accepts_emoji_and_combining_marks() {
+        let name = "Rosé 🌹 Ñandú";
+        assert_eq!(
+            FlowerName::new(format!("  {}  ", name)).unwrap().as_str(),
+            name
+        );
+    }
+
+    #[test]
+    fn name_rejects_blank_input() {
+        assert!(FlowerName::new("   ").is_err());
+        assert!(FlowerName::new("\u{3000}").is_err());
+    }
+
+    #[test]
+    fn color_parsing_ignores_case_and_whitespace() {
+        assert_eq!(" Pink ".parse::<FlowerColor>().unwrap(), FlowerColor::Pink);
+        assert!("ピンク".parse::<FlowerColor>().is_err());
+    }
+
+    #[test]
+    fn description_length_counts_characters_not_bytes() {
+        let text = "花".repeat(FlowerDescription::MAX_LENGTH);
+        let description = FlowerDescription::new(&text).unwrap().unwrap();
+        assert_eq!(description.as_str(), text);
+
+        let too_long = "花".repeat(FlowerDescription::MAX_LENGTH + 1);
+        assert!(FlowerDescription::new(too_long).is_err());
+    }
+
+    #[test]
+    fn description_length_holds_once_escaped() {
+        let escaped = FlowerDescription::new("a < b").unwrap().unwrap();
+        assert_eq!(escaped.as_str(), "a &lt; b");
+
+        let brackets = "<".repeat(FlowerDescription::MAX_LENGTH);
+        assert!(FlowerDescription::new(brackets).is_err());
+    }
+
+    #[test]
+    fn description_strips_scripts_but_keeps_text() {
+        let description = FlowerDescription::new("<em>Harum</em><script>x()</script> melati")
+            .unwrap()
+            .unwrap();
+        assert_eq!(description.as_str(), "<em>Harum</em> melati");
+    }
+
+    #[test]
+    fn descriptions_are_sanitized_when_deserialized() {
+        let description: FlowerDescription =
+            serde_json::from_str(r#""<b>Wangi</b><script>x()</script>""#).unwrap();
+        assert_eq!(description.as_str(), "<b>Wangi</b>");
+        assert!(serde_json::from_str::<FlowerDescription>(r#""  ""#).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn names_are_trimmed_and_limited_in_characters(value in any::<String>()) {
+            let trimmed = value.trim();
+            match FlowerName::new(&value) {
+                Ok(name) => {
+                    prop_assert_eq!(name.as_str(), trimmed);
+                    prop_assert!(!trimmed.is_empty());
+                    prop_assert!(trimmed.chars().count() <= FlowerName::MAX_LENGTH);
+                    prop_assert_eq!(FlowerName::new(name.as_str()).unwrap(), name);
+                }
+                Err(_) => prop_assert!(
+                    trimmed.is_empty() || trimmed.chars().count() > FlowerName::MAX_LENGTH
+                ),
+            }
+        }
+
+        #[test]
+        fn names_up_to_the_limit_are_accepted(name in "[^\\s]\\PC{0,98}[^\\s]") {
+            prop_assert!(FlowerName::new(&name).is_ok());
+        }
+
+        #[test]
+        fn colors_parse_regardless_of_case_and_padding(
+            color in prop::sample::select(FlowerColor::ALL.to_vec()),
+            upper in prop::collection::vec(any::<bool>(), 6),
+            padding in "[ \\t]{0,3}",
+        ) {
+            let spelled: String = color
+                .as_str()
+                .chars()
+                .zip(upper.iter().cycle())
+                .map(|(c, &upper)| if upper { c.to_ascii_uppercase() } else { c })
+                .collect();
+            let input = format!("{}{}{}", padding, spelled, padding);
+            prop_assert_eq!(input.parse::<FlowerColor>().unwrap(), color);
+        }
+
+        #[test]
+        fn parsed_colors_spell_their_input(value in any::<String>()) {
+            if let Ok(color) = value.parse::<FlowerColor>() {
+                prop_assert_eq!(color.as_str(), value.trim().to_lowercase());
+            }
+        }
+
+        #[test]
+        fn prices_are_finite_and_within_bounds(value in any::<f64>()) {
+            let in_range = value.is_finite() && (0.0..=Price::MAX).contains(&value);
+            prop_assert_eq!(Price::new(value).is_ok(), in_range);
+        }
+
+        #[test]
+        fn prices_survive_serialization(cents in 0..=(Price::MAX as i64) * 100) {
+            let price = Price::new(cents as f64 / 100.0).unwrap();
+            let json = serde_json::to_string(&price).unwrap();
+            prop_assert_eq!(serde_json::from_str::<Price>(&json).unwrap(), price);
+        }
+
+        #[test]
+        fn price_adjustments_never_yield_invalid_prices(
+            price in 0.0..=Price::MAX,
+            adjustment in prop_oneof![
+                any::<f64>().prop_map(PriceAdjustment::Percent),
+                any::<f64>().prop_map(PriceAdjustment::Amount),
+            ],
+        ) {
+            if let Ok(adjusted) = adjustment.apply(price) {
+                prop_assert!(Price::new(adjusted.value()).is_ok());
+            }
+        }
+
+        #[test]
+        fn stock_stays_within_bounds(value in 0..=StockQuantity::MAX, quantity in any::<i32>()) {
+            let stock = StockQuantity::new(value).unwrap();
+            let expected = i64::from(value) + i64::from(quantity);
+            match stock.increase(quantity) {
+                Ok(increased) => prop_assert_eq!(i64::from(increased.value()), expected),
+                Err(_) => prop_assert!(!(0..=i64::from(StockQuantity::MAX)).contains(&expected)),
+            }
+            match stock.decrease(quantity) {
+                Ok(decreased) => prop_assert_eq!(decreased.value(), value - quantity),
+                Err(_) => prop_assert!(quantity < 0 || quantity > value),
+            }
+        }
+
+        #[test]
+        fn stock_rejects_values_out_of_range(value in any::<i32>()) {
+            prop_assert_eq!(
+                StockQuantity::new(value).is_ok(),
+                (0..=StockQuantity::MAX).contains(&value)
+            );
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Inventory Domain Specific Errors
+
+use crate::domain::errors::AppError;
+use crate::i18n::Message;
+
+/// Inventory-specific error constructors
+pub struct InventoryError;
+
+impl InventoryError {
+    pub fn delta_zero() -> AppError {
+        AppError::validation(Message::new("inventory.delta.zero"))
+    }
+
+    pub fn reason_empty() -> AppError {
+        AppError::validation(Message::new("inventory.reason.empty"))
+    }
+
+    pub fn reason_too_long(max: usize) -> AppError {
+        AppError::validation(Message::new("inventory.reason.too_long").arg("max", max))
+    }
+}
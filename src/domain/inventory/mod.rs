@@ -0,0 +1,7 @@
+//! Inventory Domain Module
+
+pub mod errors;
+pub mod stock_movement_entity;
+
+pub use errors::InventoryError;
+pub use stock_movement_entity::StockMovement;
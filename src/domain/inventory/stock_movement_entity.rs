@@ -0,0 +1,103 @@
+//! Stock Movement Entity
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::Flower;
+use crate::domain::inventory::errors::InventoryError;
+use crate::domain::shared::{Entity, TenantId, new_id};
+
+/// Entry of the inventory ledger: a change to a flower's stock and why it
+/// was made
+///
+/// Movements are only ever appended, so they outlive the flower they refer to.
+#[derive(Debug, Clone)]
+pub struct StockMovement {
+    id: Uuid,
+    tenant_id: TenantId,
+    flower_id: Uuid,
+    delta: i32,
+    reason: String,
+    stock_after: i32,
+    created_at: DateTime<Utc>,
+}
+
+impl StockMovement {
+    /// Maximum reason length in characters, matching the `VARCHAR(200)` column
+    pub const MAX_REASON_LENGTH: usize = 200;
+
+    /// Record `delta` as applied to `flower`, which already holds the new stock
+    pub fn new(flower: &Flower, delta: i32, reason: impl AsRef<str>) -> DomainResult<Self> {
+        if delta == 0 {
+            return Err(InventoryError::delta_zero());
+        }
+        let reason = reason.as_ref().trim();
+        if reason.is_empty() {
+            return Err(InventoryError::reason_empty());
+        }
+        if reason.chars().count() > Self::MAX_REASON_LENGTH {
+            return Err(InventoryError::reason_too_long(Self::MAX_REASON_LENGTH));
+        }
+
+        Ok(Self {
+            id: new_id(),
+            tenant_id: flower.tenant_id().clone(),
+            flower_id: flower.id(),
+            delta,
+            reason: reason.to_string(),
+            stock_after: flower.stock(),
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Reconstruct a movement from the ledger
+    pub fn from_persistence(
+        id: Uuid,
+        tenant_id: TenantId,
+        flower_id: Uuid,
+        delta: i32,
+        reason: String,
+        stock_after: i32,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            flower_id,
+            delta,
+            reason,
+            stock_after,
+            created_at,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn flower_id(&self) -> Uuid {
+        self.flower_id
+    }
+
+    pub fn delta(&self) -> i32 {
+        self.delta
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn stock_after(&self) -> i32 {
+        self.stock_after
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
@@ -1,3 +1,11 @@
+pub mod delivery;
 pub mod errors;
+pub mod feature_flag;
 pub mod flower;
+pub mod inventory;
+pub mod order;
+pub mod pricing;
+pub mod saved_search;
 pub mod shared;
+pub mod store;
+pub mod task;
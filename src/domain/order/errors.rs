@@ -0,0 +1,60 @@
+//! Order Domain Specific Errors
+
+use uuid::Uuid;
+
+use crate::domain::errors::AppError;
+use crate::domain::order::OrderStatus;
+use crate::i18n::Message;
+
+/// Order-specific error constructors
+pub struct OrderError;
+
+impl OrderError {
+    pub fn not_found(id: Uuid) -> AppError {
+        AppError::not_found(Message::new("order.not_found").arg("id", id))
+    }
+
+    pub fn lines_empty() -> AppError {
+        AppError::validation(Message::new("order.lines.empty"))
+    }
+
+    pub fn too_many_lines(max: usize) -> AppError {
+        AppError::validation(Message::new("order.lines.too_many").arg("max", max))
+    }
+
+    pub fn duplicate_line(flower_id: Uuid) -> AppError {
+        AppError::validation(Message::new("order.lines.duplicate").arg("flower_id", flower_id))
+    }
+
+    pub fn quantity_invalid(max: i32) -> AppError {
+        AppError::validation(Message::new("order.line.quantity_invalid").arg("max", max))
+    }
+
+    pub fn status_invalid(value: &str) -> AppError {
+        AppError::validation(Message::new("order.status.invalid").arg("value", value))
+    }
+
+    /// Only fulfilment statuses can be set directly
+    pub fn status_not_settable(status: OrderStatus) -> AppError {
+        AppError::validation(Message::new("order.status.not_settable").arg("status", status))
+    }
+
+    /// The order's status cannot go from `from` to `to`
+    pub fn invalid_transition(id: Uuid, from: OrderStatus, to: OrderStatus) -> AppError {
+        AppError::conflict(
+            Message::new("order.status.invalid_transition")
+                .arg("from", from)
+                .arg("to", to),
+            Some(id),
+        )
+    }
+
+    /// The carrier service chosen at checkout cannot deliver the order
+    pub fn shipping_unavailable(carrier: &str, service: &str) -> AppError {
+        AppError::validation(
+            Message::new("order.shipping.unavailable")
+                .arg("carrier", carrier)
+                .arg("service", service),
+        )
+    }
+}
@@ -0,0 +1,11 @@
+//! Order Domain Module
+
+pub mod errors;
+pub mod order_entity;
+pub mod order_event;
+pub mod status;
+
+pub use errors::OrderError;
+pub use order_entity::{Order, OrderLine, OrderRefund};
+pub use order_event::OrderEvent;
+pub use status::OrderStatus;
@@ -0,0 +1,499 @@
+//! Order Entity
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::delivery::ShippingRate;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, StockQuantity, UnitOfMeasure};
+use crate::domain::order::errors::OrderError;
+use crate::domain::order::{OrderEvent, OrderStatus};
+use crate::domain::shared::{Entity, TenantId, new_id};
+
+/// Flower bought in an order, with its name, price and the unit it was sold
+/// by as they were when the order was placed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderLine {
+    flower_id: Uuid,
+    name: String,
+    unit_price: f64,
+    quantity: i32,
+    /// Defaulted so lines of orders placed before units existed still load
+    #[serde(default)]
+    unit: UnitOfMeasure,
+}
+
+impl OrderLine {
+    /// Buy `quantity` units of `flower` at `unit_price`, what it sells for
+    /// now
+    pub fn new(flower: &Flower, unit_price: f64, quantity: i32) -> DomainResult<Self> {
+        let unit = flower.unit();
+        if quantity < 1 || unit.to_stems(quantity).is_err() {
+            return Err(OrderError::quantity_invalid(
+                unit.units_in(StockQuantity::MAX),
+            ));
+        }
+
+        Ok(Self {
+            flower_id: flower.id(),
+            name: flower.name().to_string(),
+            unit_price,
+            quantity,
+            unit,
+        })
+    }
+
+    /// Reconstruct a line from persistence layer
+    pub fn from_persistence(
+        flower_id: Uuid,
+        name: String,
+        unit_price: f64,
+        quantity: i32,
+        unit: UnitOfMeasure,
+    ) -> Self {
+        Self {
+            flower_id,
+            name,
+            unit_price,
+            quantity,
+            unit,
+        }
+    }
+
+    // Getters
+    pub fn flower_id(&self) -> Uuid {
+        self.flower_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn unit_price(&self) -> f64 {
+        self.unit_price
+    }
+
+    pub fn quantity(&self) -> i32 {
+        self.quantity
+    }
+
+    pub fn unit(&self) -> UnitOfMeasure {
+        self.unit
+    }
+
+    /// Stems the line takes from stock
+    pub fn stems(&self) -> i32 {
+        self.quantity * self.unit.stems()
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.unit_price * f64::from(self.quantity)
+    }
+}
+
+/// Money given back when a paid order was cancelled
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderRefund {
+    amount: f64,
+    restocking_fee: f64,
+    reference: String,
+}
+
+impl OrderRefund {
+    /// `amount` was refunded through the payment gateway as `reference`,
+    /// keeping `restocking_fee`
+    pub fn new(amount: f64, restocking_fee: f64, reference: impl Into<String>) -> Self {
+        Self {
+            amount,
+            restocking_fee,
+            reference: reference.into(),
+        }
+    }
+
+    // Getters
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn restocking_fee(&self) -> f64 {
+        self.restocking_fee
+    }
+
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+}
+
+/// A customer's purchase, moving through fulfilment one status at a time
+///
+/// The status only changes through the transition methods, which refuse
+/// jumps `OrderStatus::can_become` does not allow, stamp when the order
+/// reached its new status and queue an `OrderEvent` for the caller to
+/// record along with the order.
+#[derive(Debug, Clone)]
+pub struct Order {
+    id: Uuid,
+    tenant_id: TenantId,
+    lines: Vec<OrderLine>,
+    shipping: Option<ShippingRate>,
+    status: OrderStatus,
+    placed_at: DateTime<Utc>,
+    paid_at: Option<DateTime<Utc>>,
+    packed_at: Option<DateTime<Utc>>,
+    shipped_at: Option<DateTime<Utc>>,
+    delivered_at: Option<DateTime<Utc>>,
+    cancelled_at: Option<DateTime<Utc>>,
+    refund: Option<OrderRefund>,
+    updated_at: DateTime<Utc>,
+    events: Vec<OrderEvent>,
+}
+
+impl Order {
+    /// Most lines in one order; placing it locks every flower bought
+    pub const MAX_LINES: usize = 50;
+
+    /// Place a pending order for `lines`, delivered with `shipping` unless
+    /// picked up
+    pub fn place(
+        tenant_id: TenantId,
+        lines: Vec<OrderLine>,
+        shipping: Option<ShippingRate>,
+        actor: &str,
+    ) -> DomainResult<Self> {
+        if lines.is_empty() {
+            return Err(OrderError::lines_empty());
+        }
+        if lines.len() > Self::MAX_LINES {
+            return Err(OrderError::too_many_lines(Self::MAX_LINES));
+        }
+
+        let id = new_id();
+        let now = Utc::now();
+        let placed = OrderEvent::new(
+            id,
+            tenant_id.clone(),
+            None,
+            OrderStatus::Pending,
+            actor,
+            now,
+        );
+        Ok(Self {
+            id,
+            tenant_id,
+            lines,
+            shipping,
+            status: OrderStatus::Pending,
+            placed_at: now,
+            paid_at: None,
+            packed_at: None,
+            shipped_at: None,
+            delivered_at: None,
+            cancelled_at: None,
+            refund: None,
+            updated_at: now,
+            events: vec![placed],
+        })
+    }
+
+    /// Reconstruct an order from persistence layer
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_persistence(
+        id: Uuid,
+        tenant_id: TenantId,
+        lines: Vec<OrderLine>,
+        shipping: Option<ShippingRate>,
+        status: OrderStatus,
+        placed_at: DateTime<Utc>,
+        paid_at: Option<DateTime<Utc>>,
+        packed_at: Option<DateTime<Utc>>,
+        shipped_at: Option<DateTime<Utc>>,
+        delivered_at: Option<DateTime<Utc>>,
+        cancelled_at: Option<DateTime<Utc>>,
+        refund: Option<OrderRefund>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            lines,
+            shipping,
+            status,
+            placed_at,
+            paid_at,
+            packed_at,
+            shipped_at,
+            delivered_at,
+            cancelled_at,
+            refund,
+            updated_at,
+            events: Vec::new(),
+        }
+    }
+
+    /// Payment was received
+    pub fn pay(&mut self, actor: &str) -> DomainResult<()> {
+        self.transition(OrderStatus::Paid, actor)
+    }
+
+    /// The flowers were arranged and wrapped
+    pub fn pack(&mut self, actor: &str) -> DomainResult<()> {
+        self.transition(OrderStatus::Packed, actor)
+    }
+
+    /// The parcel left with the carrier
+    pub fn ship(&mut self, actor: &str) -> DomainResult<()> {
+        self.transition(OrderStatus::Shipped, actor)
+    }
+
+    /// The customer received the parcel
+    pub fn deliver(&mut self, actor: &str) -> DomainResult<()> {
+        self.transition(OrderStatus::Delivered, actor)
+    }
+
+    /// The order will not be fulfilled; only possible before it ships
+    pub fn cancel(&mut self, actor: &str) -> DomainResult<()> {
+        self.transition(OrderStatus::Cancelled, actor)
+    }
+
+    /// Part of the flowers' price kept when a paid order is cancelled,
+    /// rounded to whole rupiah
+    pub fn restocking_fee(&self, percent: u8) -> f64 {
+        (self.subtotal() * f64::from(percent) / 100.0).round()
+    }
+
+    /// What the customer gets back for a cancelled order: everything paid
+    /// less the restocking fee. `None` unless the order was paid, then
+    /// cancelled, and not refunded yet
+    pub fn refund_due(&self, restocking_fee_percent: u8) -> Option<f64> {
+        if self.status != OrderStatus::Cancelled || self.paid_at.is_none() || self.refund.is_some()
+        {
+            return None;
+        }
+        Some((self.total() - self.restocking_fee(restocking_fee_percent)).max(0.0))
+    }
+
+    /// The payment gateway gave the customer their money back
+    pub fn refunded(&mut self, refund: OrderRefund, actor: &str) {
+        let now = Utc::now();
+        self.events.push(OrderEvent::refund(
+            self.id,
+            self.tenant_id.clone(),
+            self.status,
+            refund.amount(),
+            actor,
+            now,
+        ));
+        self.refund = Some(refund);
+        self.updated_at = now;
+    }
+
+    fn transition(&mut self, to: OrderStatus, actor: &str) -> DomainResult<()> {
+        let from = self.status;
+        if !from.can_become(to) {
+            return Err(OrderError::invalid_transition(self.id, from, to));
+        }
+
+        let now = Utc::now();
+        let stamp = match to {
+            OrderStatus::Pending => unreachable!("no status leads back to pending"),
+            OrderStatus::Paid => &mut self.paid_at,
+            OrderStatus::Packed => &mut self.packed_at,
+            OrderStatus::Shipped => &mut self.shipped_at,
+            OrderStatus::Delivered => &mut self.delivered_at,
+            OrderStatus::Cancelled => &mut self.cancelled_at,
+        };
+        *stamp = Some(now);
+        self.status = to;
+        self.updated_at = now;
+        self.events.push(OrderEvent::new(
+            self.id,
+            self.tenant_id.clone(),
+            Some(from),
+            to,
+            actor,
+            now,
+        ));
+        Ok(())
+    }
+
+    /// Events raised since the order was placed or loaded, oldest first
+    pub fn take_events(&mut self) -> Vec<OrderEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    // Getters
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn lines(&self) -> &[OrderLine] {
+        &self.lines
+    }
+
+    pub fn shipping(&self) -> Option<&ShippingRate> {
+        self.shipping.as_ref()
+    }
+
+    pub fn status(&self) -> OrderStatus {
+        self.status
+    }
+
+    /// Price of the flowers, in IDR
+    pub fn subtotal(&self) -> f64 {
+        self.lines.iter().map(OrderLine::amount).sum()
+    }
+
+    /// What the customer pays, flowers and shipping, in IDR
+    pub fn total(&self) -> f64 {
+        self.subtotal() + self.shipping.as_ref().map_or(0.0, ShippingRate::fee)
+    }
+
+    pub fn placed_at(&self) -> DateTime<Utc> {
+        self.placed_at
+    }
+
+    pub fn paid_at(&self) -> Option<DateTime<Utc>> {
+        self.paid_at
+    }
+
+    pub fn packed_at(&self) -> Option<DateTime<Utc>> {
+        self.packed_at
+    }
+
+    pub fn shipped_at(&self) -> Option<DateTime<Utc>> {
+        self.shipped_at
+    }
+
+    pub fn delivered_at(&self) -> Option<DateTime<Utc>> {
+        self.delivered_at
+    }
+
+    pub fn cancelled_at(&self) -> Option<DateTime<Utc>> {
+        self.cancelled_at
+    }
+
+    pub fn refund(&self) -> Option<&OrderRefund> {
+        self.refund.as_ref()
+    }
+}
+
+impl Entity for Order {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.placed_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::errors::AppError;
+    use crate::test_support::FlowerBuilder;
+
+    fn order() -> Order {
+        let line = OrderLine::from_persistence(
+            new_id(),
+            "Rose".to_string(),
+            15_000.0,
+            2,
+            UnitOfMeasure::Stem,
+        );
+        let shipping = ShippingRate::new("shop", "standard", 10_000.0, None).unwrap();
+        Order::place(TenantId::default(), vec![line], Some(shipping), "admin").unwrap()
+    }
+
+    #[test]
+    fn lines_take_the_stems_of_their_units() {
+        let roses = FlowerBuilder::new().with_unit("dozen", None).build();
+        let line = OrderLine::new(&roses, 180_000.0, 2).unwrap();
+        assert_eq!((line.stems(), line.amount()), (24, 360_000.0));
+        assert!(OrderLine::new(&roses, 180_000.0, StockQuantity::MAX).is_err());
+
+        let placed_before_units: OrderLine = serde_json::from_value(json!({
+            "flower_id": roses.id(),
+            "name": "Rose",
+            "unit_price": 15_000.0,
+            "quantity": 3
+        }))
+        .unwrap();
+        assert_eq!(placed_before_units.stems(), 3);
+    }
+
+    #[test]
+    fn transitions_stamp_the_order_and_raise_events() {
+        let mut order = order();
+        assert_eq!(order.total(), 40_000.0);
+
+        order.pay("admin").unwrap();
+        order.pack("admin").unwrap();
+        assert_eq!(order.status(), OrderStatus::Packed);
+        assert!(order.paid_at().is_some() && order.packed_at().is_some());
+        assert!(order.shipped_at().is_none());
+
+        let events = order.take_events();
+        let steps: Vec<_> = events.iter().map(|e| (e.from(), e.to())).collect();
+        assert_eq!(
+            steps,
+            [
+                (None, OrderStatus::Pending),
+                (Some(OrderStatus::Pending), OrderStatus::Paid),
+                (Some(OrderStatus::Paid), OrderStatus::Packed),
+            ]
+        );
+        assert!(order.take_events().is_empty());
+    }
+
+    #[test]
+    fn invalid_jumps_leave_the_order_untouched() {
+        let mut order = order();
+        order.take_events();
+
+        let err = order.ship("admin").unwrap_err();
+        assert!(matches!(err, AppError::Conflict { .. }));
+        assert_eq!(order.status(), OrderStatus::Pending);
+        assert!(order.shipped_at().is_none());
+        assert!(order.take_events().is_empty());
+
+        order.cancel("admin").unwrap();
+        assert!(order.pay("admin").is_err());
+        assert!(order.cancelled_at().is_some());
+    }
+
+    #[test]
+    fn paid_orders_are_refunded_less_the_restocking_fee() {
+        let mut unpaid = order();
+        unpaid.cancel("admin").unwrap();
+        assert_eq!(unpaid.refund_due(10), None);
+
+        let mut paid = order();
+        paid.pay("admin").unwrap();
+        assert_eq!(paid.refund_due(10), None);
+        paid.cancel("admin").unwrap();
+        assert_eq!(paid.restocking_fee(10), 3_000.0);
+        assert_eq!(paid.refund_due(10), Some(37_000.0));
+
+        paid.take_events();
+        paid.refunded(OrderRefund::new(37_000.0, 3_000.0, "rf-1"), "admin");
+        assert_eq!(paid.refund_due(10), None);
+        assert_eq!(paid.refund().map(OrderRefund::reference), Some("rf-1"));
+        let events = paid.take_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].refunded(), Some(37_000.0));
+        assert_eq!(events[0].to(), OrderStatus::Cancelled);
+    }
+
+    #[test]
+    fn orders_need_lines() {
+        assert!(Order::place(TenantId::default(), Vec::new(), None, "admin").is_err());
+    }
+}
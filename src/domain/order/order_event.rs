@@ -0,0 +1,126 @@
+//! Order Event
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::order::OrderStatus;
+use crate::domain::shared::{TenantId, new_id};
+
+/// An order changing status; orders emit one per transition, placing
+/// included, and one when the customer is refunded
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderEvent {
+    id: Uuid,
+    order_id: Uuid,
+    tenant_id: TenantId,
+    /// `None` when the order was placed
+    from: Option<OrderStatus>,
+    to: OrderStatus,
+    /// Who made the change, as flower changes credit it
+    actor: String,
+    occurred_at: DateTime<Utc>,
+    /// Amount given back to the customer, in IDR, for events recording a
+    /// refund; their `from` and `to` are the status the order stays in
+    refunded: Option<f64>,
+}
+
+impl OrderEvent {
+    pub(crate) fn new(
+        order_id: Uuid,
+        tenant_id: TenantId,
+        from: Option<OrderStatus>,
+        to: OrderStatus,
+        actor: &str,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: new_id(),
+            order_id,
+            tenant_id,
+            from,
+            to,
+            actor: actor.to_string(),
+            occurred_at,
+            refunded: None,
+        }
+    }
+
+    /// `amount` was refunded to the customer of an order in `status`
+    pub(crate) fn refund(
+        order_id: Uuid,
+        tenant_id: TenantId,
+        status: OrderStatus,
+        amount: f64,
+        actor: &str,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            refunded: Some(amount),
+            ..Self::new(
+                order_id,
+                tenant_id,
+                Some(status),
+                status,
+                actor,
+                occurred_at,
+            )
+        }
+    }
+
+    /// Reconstruct an event from persistence layer
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_persistence(
+        id: Uuid,
+        order_id: Uuid,
+        tenant_id: TenantId,
+        from: Option<OrderStatus>,
+        to: OrderStatus,
+        actor: String,
+        occurred_at: DateTime<Utc>,
+        refunded: Option<f64>,
+    ) -> Self {
+        Self {
+            id,
+            order_id,
+            tenant_id,
+            from,
+            to,
+            actor,
+            occurred_at,
+            refunded,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn order_id(&self) -> Uuid {
+        self.order_id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn from(&self) -> Option<OrderStatus> {
+        self.from
+    }
+
+    pub fn to(&self) -> OrderStatus {
+        self.to
+    }
+
+    pub fn actor(&self) -> &str {
+        &self.actor
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+
+    pub fn refunded(&self) -> Option<f64> {
+        self.refunded
+    }
+}
@@ -0,0 +1,121 @@
+//! Order Status
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::errors::AppError;
+use crate::domain::order::errors::OrderError;
+
+/// Where an order is in its life
+///
+/// Orders move forward only: pending → paid → packed → shipped → delivered.
+/// Until shipped they may be cancelled instead. Delivered and cancelled
+/// orders are final.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OrderStatus {
+    /// Placed, stock reserved, awaiting payment
+    Pending,
+    Paid,
+    Packed,
+    Shipped,
+    Delivered,
+    Cancelled,
+}
+
+impl OrderStatus {
+    pub const ALL: [OrderStatus; 6] = [
+        OrderStatus::Pending,
+        OrderStatus::Paid,
+        OrderStatus::Packed,
+        OrderStatus::Shipped,
+        OrderStatus::Delivered,
+        OrderStatus::Cancelled,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OrderStatus::Pending => "pending",
+            OrderStatus::Paid => "paid",
+            OrderStatus::Packed => "packed",
+            OrderStatus::Shipped => "shipped",
+            OrderStatus::Delivered => "delivered",
+            OrderStatus::Cancelled => "cancelled",
+        }
+    }
+
+    /// Whether an order may go from this status to `next`
+    pub fn can_become(self, next: OrderStatus) -> bool {
+        use OrderStatus::*;
+        matches!(
+            (self, next),
+            (Pending, Paid)
+                | (Paid, Packed)
+                | (Packed, Shipped)
+                | (Shipped, Delivered)
+                | (Pending | Paid | Packed, Cancelled)
+        )
+    }
+
+    /// Whether the order can no longer change
+    pub fn is_final(self) -> bool {
+        matches!(self, OrderStatus::Delivered | OrderStatus::Cancelled)
+    }
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OrderStatus {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == value)
+            .ok_or_else(|| OrderError::status_invalid(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_move_forward_and_cancel_only_before_shipping() {
+        use OrderStatus::*;
+        let allowed: Vec<(OrderStatus, OrderStatus)> = OrderStatus::ALL
+            .into_iter()
+            .flat_map(|from| OrderStatus::ALL.into_iter().map(move |to| (from, to)))
+            .filter(|(from, to)| from.can_become(*to))
+            .collect();
+        assert_eq!(
+            allowed,
+            [
+                (Pending, Paid),
+                (Pending, Cancelled),
+                (Paid, Packed),
+                (Paid, Cancelled),
+                (Packed, Shipped),
+                (Packed, Cancelled),
+                (Shipped, Delivered),
+            ]
+        );
+        assert!(
+            OrderStatus::ALL
+                .into_iter()
+                .filter(|status| status.is_final())
+                .all(|status| OrderStatus::ALL
+                    .into_iter()
+                    .all(|to| !status.can_become(to)))
+        );
+        assert_eq!("shipped".parse::<OrderStatus>().unwrap(), Shipped);
+        assert!("lost".parse::<OrderStatus>().is_err());
+    }
+}
@@ -0,0 +1,40 @@
+//! Pricing Domain Specific Errors
+
+use uuid::Uuid;
+
+use crate::domain::errors::AppError;
+use crate::i18n::Message;
+
+/// Pricing rule error constructors
+pub struct PricingRuleError;
+
+impl PricingRuleError {
+    pub fn not_found(id: Uuid) -> AppError {
+        AppError::not_found(Message::new("pricing_rule.not_found").arg("id", id))
+    }
+
+    pub fn name_empty() -> AppError {
+        AppError::validation(Message::new("pricing_rule.name.empty"))
+    }
+
+    pub fn name_too_long(max: usize) -> AppError {
+        AppError::validation(Message::new("pricing_rule.name.too_long").arg("max", max))
+    }
+
+    pub fn adjustment_invalid(min: f64, max: f64) -> AppError {
+        AppError::validation(
+            Message::new("pricing_rule.adjustment.invalid")
+                .arg("min", min)
+                .arg("max", max),
+        )
+    }
+
+    pub fn max_stock_invalid() -> AppError {
+        AppError::validation(Message::new("pricing_rule.max_stock.invalid"))
+    }
+
+    /// The rule would end before it starts
+    pub fn window_invalid() -> AppError {
+        AppError::validation(Message::new("pricing_rule.window.invalid"))
+    }
+}
@@ -0,0 +1,7 @@
+//! Pricing Domain Module
+
+pub mod errors;
+pub mod pricing_rule;
+
+pub use errors::PricingRuleError;
+pub use pricing_rule::{EffectivePrice, PricingConditions, PricingRule};
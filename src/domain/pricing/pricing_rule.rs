@@ -0,0 +1,324 @@
+//! Pricing Rule Entity
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, Price};
+use crate::domain::pricing::errors::PricingRuleError;
+use crate::domain::shared::{Entity, TenantId, new_id};
+
+/// When a pricing rule applies; conditions left out always hold
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PricingConditions {
+    /// Applies to flowers with at most this much stock left
+    pub max_stock: Option<i32>,
+    /// Applies from this moment on
+    pub starts_at: Option<DateTime<Utc>>,
+    /// Applies until this moment, excluded
+    pub ends_at: Option<DateTime<Utc>>,
+}
+
+impl PricingConditions {
+    fn validate(&self) -> DomainResult<()> {
+        if self.max_stock.is_some_and(|stock| stock < 0) {
+            return Err(PricingRuleError::max_stock_invalid());
+        }
+        if let (Some(starts_at), Some(ends_at)) = (self.starts_at, self.ends_at)
+            && ends_at <= starts_at
+        {
+            return Err(PricingRuleError::window_invalid());
+        }
+        Ok(())
+    }
+
+    /// Whether the conditions hold for `flower` at `at`
+    pub fn hold(&self, flower: &Flower, at: DateTime<Utc>) -> bool {
+        self.max_stock.is_none_or(|max| flower.stock() <= max)
+            && self.starts_at.is_none_or(|starts_at| at >= starts_at)
+            && self.ends_at.is_none_or(|ends_at| at < ends_at)
+    }
+}
+
+/// Adjustment of a tenant's flower prices while its conditions hold, such
+/// as a markup when stock runs low or during Valentine's week
+///
+/// Rules never change the price stored with a flower: they are evaluated
+/// whenever a price is served, so the base price stays what reports see.
+#[derive(Debug, Clone)]
+pub struct PricingRule {
+    id: Uuid,
+    tenant_id: TenantId,
+    name: String,
+    adjustment_percent: f64,
+    conditions: PricingConditions,
+    enabled: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl PricingRule {
+    /// Maximum name length in characters, matching the `VARCHAR(100)` column
+    pub const MAX_NAME_LENGTH: usize = 100;
+
+    /// Largest discount, in percent; prices never drop to nothing
+    pub const MIN_ADJUSTMENT_PERCENT: f64 = -90.0;
+
+    /// Largest markup, in percent
+    pub const MAX_ADJUSTMENT_PERCENT: f64 = 500.0;
+
+    /// Define a rule; `adjustment_percent` is a markup when positive and a
+    /// discount when negative
+    pub fn new(
+        tenant_id: TenantId,
+        name: impl AsRef<str>,
+        adjustment_percent: f64,
+        conditions: PricingConditions,
+        enabled: bool,
+    ) -> DomainResult<Self> {
+        let now = Utc::now();
+        let mut rule = Self {
+            id: new_id(),
+            tenant_id,
+            name: String::new(),
+            adjustment_percent: 0.0,
+            conditions: PricingConditions::default(),
+            enabled,
+            created_at: now,
+            updated_at: now,
+        };
+        rule.update(name, adjustment_percent, conditions, enabled)?;
+        Ok(rule)
+    }
+
+    /// Reconstruct a pricing rule from persistence layer
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_persistence(
+        id: Uuid,
+        tenant_id: TenantId,
+        name: String,
+        adjustment_percent: f64,
+        conditions: PricingConditions,
+        enabled: bool,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            name,
+            adjustment_percent,
+            conditions,
+            enabled,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replace what the rule does and when; the rule is left untouched when
+    /// any of it is invalid
+    pub fn update(
+        &mut self,
+        name: impl AsRef<str>,
+        adjustment_percent: f64,
+        conditions: PricingConditions,
+        enabled: bool,
+    ) -> DomainResult<()> {
+        let name = name.as_ref().trim();
+        if name.is_empty() {
+            return Err(PricingRuleError::name_empty());
+        }
+        if name.chars().count() > Self::MAX_NAME_LENGTH {
+            return Err(PricingRuleError::name_too_long(Self::MAX_NAME_LENGTH));
+        }
+        let range = Self::MIN_ADJUSTMENT_PERCENT..=Self::MAX_ADJUSTMENT_PERCENT;
+        if !(adjustment_percent.is_finite() && range.contains(&adjustment_percent)) {
+            return Err(PricingRuleError::adjustment_invalid(
+                Self::MIN_ADJUSTMENT_PERCENT,
+                Self::MAX_ADJUSTMENT_PERCENT,
+            ));
+        }
+        conditions.validate()?;
+
+        self.name = name.to_string();
+        self.adjustment_percent = adjustment_percent;
+        self.conditions = conditions;
+        self.enabled = enabled;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Whether the rule adjusts the price of `flower` at `at`
+    pub fn applies_to(&self, flower: &Flower, at: DateTime<Utc>) -> bool {
+        self.enabled && self.conditions.hold(flower, at)
+    }
+
+    // Getters
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn adjustment_percent(&self) -> f64 {
+        self.adjustment_percent
+    }
+
+    pub fn conditions(&self) -> &PricingConditions {
+        &self.conditions
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+impl Entity for PricingRule {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+/// Price a flower is sold at, and the base price it was derived from
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectivePrice {
+    base: f64,
+    price: f64,
+    rules: Vec<Uuid>,
+}
+
+impl EffectivePrice {
+    /// Price of `flower` at `at` under `rules`
+    ///
+    /// The adjustments of every rule that applies add up, so a 20% markup
+    /// and a 5% discount make a 15% markup. Adjusted prices are rounded to
+    /// whole rupiah; without any rule applying the base price is kept as is.
+    pub fn of(flower: &Flower, rules: &[PricingRule], at: DateTime<Utc>) -> Self {
+        let base = flower.price();
+        let applied: Vec<&PricingRule> = rules
+            .iter()
+            .filter(|rule| rule.tenant_id() == flower.tenant_id() && rule.applies_to(flower, at))
+            .collect();
+        if applied.is_empty() {
+            return Self {
+                base,
+                price: base,
+                rules: Vec::new(),
+            };
+        }
+
+        let percent: f64 = applied.iter().map(|rule| rule.adjustment_percent()).sum();
+        let price = (base * (1.0 + percent / 100.0))
+            .round()
+            .clamp(0.0, Price::MAX);
+        Self {
+            base,
+            price,
+            rules: applied.iter().map(|rule| rule.id()).collect(),
+        }
+    }
+
+    // Getters
+    /// Price stored with the flower, in IDR
+    pub fn base(&self) -> f64 {
+        self.base
+    }
+
+    /// Price after the pricing rules, in IDR
+    pub fn price(&self) -> f64 {
+        self.price
+    }
+
+    /// Rules that adjusted the price
+    pub fn rules(&self) -> &[Uuid] {
+        &self.rules
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::FlowerBuilder;
+    use chrono::Duration;
+
+    fn rule(percent: f64, conditions: PricingConditions) -> PricingRule {
+        PricingRule::new(TenantId::default(), "Rule", percent, conditions, true).unwrap()
+    }
+
+    #[test]
+    fn applying_rules_add_up_and_the_rest_are_ignored() {
+        let now = Utc::now();
+        let rose = FlowerBuilder::new()
+            .with_price(20_000.0)
+            .with_stock(3)
+            .build();
+        let low_stock = rule(
+            20.0,
+            PricingConditions {
+                max_stock: Some(5),
+                ..Default::default()
+            },
+        );
+        let valentines = rule(
+            -5.0,
+            PricingConditions {
+                starts_at: Some(now - Duration::days(1)),
+                ends_at: Some(now + Duration::days(6)),
+                ..Default::default()
+            },
+        );
+        let ended = rule(
+            100.0,
+            PricingConditions {
+                ends_at: Some(now),
+                ..Default::default()
+            },
+        );
+        let rules = [low_stock.clone(), valentines, ended];
+
+        let priced = EffectivePrice::of(&rose, &rules, now);
+        assert_eq!(priced.base(), 20_000.0);
+        assert_eq!(priced.price(), 23_000.0);
+        assert_eq!(priced.rules().len(), 2);
+
+        let plenty = FlowerBuilder::new()
+            .with_price(20_000.0)
+            .with_stock(50)
+            .build();
+        assert_eq!(
+            EffectivePrice::of(&plenty, &[low_stock], now).price(),
+            20_000.0
+        );
+    }
+
+    #[test]
+    fn invalid_rules_are_refused() {
+        let now = Utc::now();
+        let backwards = PricingConditions {
+            starts_at: Some(now),
+            ends_at: Some(now - Duration::hours(1)),
+            ..Default::default()
+        };
+        let tenant = TenantId::default;
+        assert!(PricingRule::new(tenant(), "Late", 10.0, backwards, true).is_err());
+        assert!(PricingRule::new(tenant(), " ", 10.0, Default::default(), true).is_err());
+        assert!(PricingRule::new(tenant(), "Free", -100.0, Default::default(), true).is_err());
+
+        let mut rule = rule(10.0, Default::default());
+        assert!(
+            rule.update("Rule", f64::NAN, Default::default(), true)
+                .is_err()
+        );
+        assert_eq!(rule.adjustment_percent(), 10.0);
+    }
+}
@@ -0,0 +1,35 @@
+//! Saved Search Domain Specific Errors
+
+use uuid::Uuid;
+
+use crate::domain::errors::AppError;
+use crate::i18n::Message;
+
+/// Saved search error constructors
+pub struct SavedSearchError;
+
+impl SavedSearchError {
+    pub fn not_found(id: Uuid) -> AppError {
+        AppError::not_found(Message::new("saved_search.not_found").arg("id", id))
+    }
+
+    pub fn name_empty() -> AppError {
+        AppError::validation(Message::new("saved_search.name.empty"))
+    }
+
+    pub fn name_too_long(max: usize) -> AppError {
+        AppError::validation(Message::new("saved_search.name.too_long").arg("max", max))
+    }
+
+    pub fn query_too_long(max: usize) -> AppError {
+        AppError::validation(Message::new("saved_search.query.too_long").arg("max", max))
+    }
+
+    pub fn price_range_inverted() -> AppError {
+        AppError::validation(Message::new("saved_search.price_range.inverted"))
+    }
+
+    pub fn alert_email_invalid(value: &str) -> AppError {
+        AppError::validation(Message::new("saved_search.alert_email.invalid").arg("value", value))
+    }
+}
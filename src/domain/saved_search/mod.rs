@@ -0,0 +1,7 @@
+//! Saved Search Domain Module
+
+pub mod errors;
+pub mod saved_search_entity;
+
+pub use errors::SavedSearchError;
+pub use saved_search_entity::{SavedSearch, SearchCriteria};
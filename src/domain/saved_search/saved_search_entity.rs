@@ -0,0 +1,312 @@
+//! Saved Search Entity
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerColor, Price};
+use crate::domain::saved_search::errors::SavedSearchError;
+use crate::domain::shared::{Entity, TenantId, new_id};
+
+/// What a saved search looks for; criteria left out match every flower
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchCriteria {
+    /// Part of the flower name, matched regardless of case
+    pub query: Option<String>,
+    pub color: Option<FlowerColor>,
+    pub min_price: Option<Price>,
+    pub max_price: Option<Price>,
+}
+
+impl SearchCriteria {
+    /// Maximum query length in characters, matching the `VARCHAR(100)` column
+    pub const MAX_QUERY_LENGTH: usize = 100;
+
+    /// Criteria with a trimmed query, blank queries dropped
+    pub fn new(
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        min_price: Option<Price>,
+        max_price: Option<Price>,
+    ) -> DomainResult<Self> {
+        let query = query.map(str::trim).filter(|query| !query.is_empty());
+        if query.is_some_and(|query| query.chars().count() > Self::MAX_QUERY_LENGTH) {
+            return Err(SavedSearchError::query_too_long(Self::MAX_QUERY_LENGTH));
+        }
+        if let (Some(min), Some(max)) = (min_price, max_price)
+            && min.value() > max.value()
+        {
+            return Err(SavedSearchError::price_range_inverted());
+        }
+
+        Ok(Self {
+            query: query.map(str::to_string),
+            color,
+            min_price,
+            max_price,
+        })
+    }
+
+    /// Whether a search with these criteria finds `flower`, the way flower
+    /// searches match names
+    pub fn matches(&self, flower: &Flower) -> bool {
+        let price = flower.price();
+        self.query
+            .as_deref()
+            .is_none_or(|query| flower.name().to_lowercase().contains(&query.to_lowercase()))
+            && self.color.is_none_or(|color| flower.color() == color)
+            && self.min_price.is_none_or(|min| price >= min.value())
+            && self.max_price.is_none_or(|max| price <= max.value())
+    }
+}
+
+/// Search a caller kept to run again, optionally alerting an address when
+/// new flowers match it
+///
+/// Searches belong to the caller that saved them, identified the way flower
+/// changes credit their actor (`admin`, `tenant:<id>`).
+#[derive(Debug, Clone)]
+pub struct SavedSearch {
+    id: Uuid,
+    tenant_id: TenantId,
+    owner: String,
+    name: String,
+    criteria: SearchCriteria,
+    alert_email: Option<String>,
+    checked_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl SavedSearch {
+    /// Maximum name length in characters, matching the `VARCHAR(100)` column
+    pub const MAX_NAME_LENGTH: usize = 100;
+
+    /// Maximum email address length, per RFC 5321
+    pub const MAX_EMAIL_LENGTH: usize = 254;
+
+    /// Save a search; only flowers created from now on are alerted about
+    pub fn new(
+        tenant_id: TenantId,
+        owner: impl Into<String>,
+        name: impl AsRef<str>,
+        criteria: SearchCriteria,
+        alert_email: Option<&str>,
+    ) -> DomainResult<Self> {
+        let name = name.as_ref().trim();
+        if name.is_empty() {
+            return Err(SavedSearchError::name_empty());
+        }
+        if name.chars().count() > Self::MAX_NAME_LENGTH {
+            return Err(SavedSearchError::name_too_long(Self::MAX_NAME_LENGTH));
+        }
+
+        let alert_email = alert_email.map(str::trim).filter(|email| !email.is_empty());
+        if let Some(email) = alert_email
+            && !Self::is_email(email)
+        {
+            return Err(SavedSearchError::alert_email_invalid(email));
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: new_id(),
+            tenant_id,
+            owner: owner.into(),
+            name: name.to_string(),
+            criteria,
+            alert_email: alert_email.map(str::to_string),
+            checked_at: now,
+            created_at: now,
+        })
+    }
+
+    /// Reconstruct a saved search from persistence layer
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_persistence(
+        id: Uuid,
+        tenant_id: TenantId,
+        owner: String,
+        name: String,
+        criteria: SearchCriteria,
+        alert_email: Option<String>,
+        checked_at: DateTime<Utc>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            owner,
+            name,
+            criteria,
+            alert_email,
+            checked_at,
+            created_at,
+        }
+    }
+
+    /// A local part, an `@` and a domain, without spaces; deliverability is
+    /// for the mail server to judge
+    fn is_email(value: &str) -> bool {
+        value.len() <= Self::MAX_EMAIL_LENGTH
+            && !value.contains(char::is_whitespace)
+            && value
+                .split_once('@')
+                .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'))
+    }
+
+    // Getters
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn criteria(&self) -> &SearchCriteria {
+        &self.criteria
+    }
+
+    /// Address alerted about new matches; `None` when alerts are off
+    pub fn alert_email(&self) -> Option<&str> {
+        self.alert_email.as_deref()
+    }
+
+    /// Flowers created after this have not been alerted about yet
+    pub fn checked_at(&self) -> DateTime<Utc> {
+        self.checked_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Remember that flowers created up to `at` have been alerted about
+    pub fn mark_checked(&mut self, at: DateTime<Utc>) {
+        self.checked_at = at;
+    }
+
+    /// Flowers among `flowers` this search has not alerted about yet
+    pub fn new_matches<'a>(&self, flowers: &'a [Flower]) -> Vec<&'a Flower> {
+        flowers
+            .iter()
+            .filter(|flower| {
+                flower.tenant_id() == &self.tenant_id
+                    && flower.created_at() > self.checked_at
+                    && self.criteria.matches(flower)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::FlowerBuilder;
+
+    fn price(value: f64) -> Option<Price> {
+        Some(Price::new(value).unwrap())
+    }
+
+    #[test]
+    fn criteria_match_like_flower_searches() {
+        let rose = FlowerBuilder::new()
+            .with_name("Red Rose")
+            .with_color("red")
+            .with_price(25_000.0)
+            .build();
+
+        let matching = SearchCriteria::new(
+            Some(" ROSE "),
+            Some(FlowerColor::Red),
+            price(20_000.0),
+            None,
+        )
+        .unwrap();
+        assert_eq!(matching.query.as_deref(), Some("ROSE"));
+        assert!(matching.matches(&rose));
+        assert!(SearchCriteria::default().matches(&rose));
+
+        let too_cheap = SearchCriteria::new(None, None, None, price(20_000.0)).unwrap();
+        assert!(!too_cheap.matches(&rose));
+        let other_color = SearchCriteria::new(Some("rose"), Some(FlowerColor::White), None, None);
+        assert!(!other_color.unwrap().matches(&rose));
+
+        let inverted = SearchCriteria::new(None, None, price(30_000.0), price(20_000.0));
+        assert_eq!(
+            inverted.unwrap_err().code(),
+            "saved_search.price_range.inverted"
+        );
+    }
+
+    #[test]
+    fn validates_the_name_and_alert_address() {
+        let save = |name: &str, email: Option<&str>| {
+            SavedSearch::new(
+                TenantId::default(),
+                "admin",
+                name,
+                SearchCriteria::default(),
+                email,
+            )
+        };
+
+        let saved = save("  Red roses ", Some(" florist@example.com ")).unwrap();
+        assert_eq!(saved.name(), "Red roses");
+        assert_eq!(saved.alert_email(), Some("florist@example.com"));
+        assert_eq!(save("Roses", Some("")).unwrap().alert_email(), None);
+
+        assert_eq!(
+            save(" ", None).unwrap_err().code(),
+            "saved_search.name.empty"
+        );
+        for email in [
+            "florist",
+            "@example.com",
+            "florist@localhost",
+            "a b@example.com",
+        ] {
+            assert_eq!(
+                save("Roses", Some(email)).unwrap_err().code(),
+                "saved_search.alert_email.invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn only_flowers_created_since_the_last_check_are_new() {
+        let older = FlowerBuilder::new().with_name("Old Rose").build();
+        let search = SavedSearch::new(
+            TenantId::default(),
+            "admin",
+            "Roses",
+            SearchCriteria::new(Some("rose"), None, None, None).unwrap(),
+            None,
+        )
+        .unwrap();
+        let newer = [
+            FlowerBuilder::new().with_name("New Rose").build(),
+            FlowerBuilder::new().with_name("Tulip").build(),
+            FlowerBuilder::new()
+                .with_tenant("elsewhere")
+                .with_name("Rose")
+                .build(),
+        ];
+
+        let flowers = [vec![older], newer.to_vec()].concat();
+        let names: Vec<_> = search
+            .new_matches(&flowers)
+            .into_iter()
+            .map(|flower| flower.name())
+            .collect();
+        assert_eq!(names, ["New Rose"]);
+    }
+}
@@ -1,8 +1,14 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::errors::{AppError, DomainResult, FieldError};
+use crate::i18n::Message;
+
 /// Base entity trait for all domain entities
 pub trait Entity {
     fn id(&self) -> Uuid;
@@ -10,6 +16,128 @@ pub trait Entity {
     fn updated_at(&self) -> DateTime<Utc>;
 }
 
+/// UUID version used for newly created entities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdVersion {
+    /// Random IDs, kept for backwards compatibility
+    V4,
+    /// Time-ordered IDs with better index locality
+    #[default]
+    V7,
+}
+
+static ID_VERSION: AtomicU8 = AtomicU8::new(7);
+
+impl IdVersion {
+    /// Set the version used by `new_id` for the whole process
+    pub fn set_global(self) {
+        let version = match self {
+            IdVersion::V4 => 4,
+            IdVersion::V7 => 7,
+        };
+        ID_VERSION.store(version, Ordering::Relaxed);
+    }
+
+    pub fn global() -> Self {
+        match ID_VERSION.load(Ordering::Relaxed) {
+            4 => IdVersion::V4,
+            _ => IdVersion::V7,
+        }
+    }
+}
+
+impl FromStr for IdVersion {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "v4" | "4" => Ok(IdVersion::V4),
+            "v7" | "7" => Ok(IdVersion::V7),
+            other => Err(format!(
+                "unsupported id version '{}', expected v4 or v7",
+                other
+            )),
+        }
+    }
+}
+
+/// Generate an identifier for a new entity
+pub fn new_id() -> Uuid {
+    match IdVersion::global() {
+        IdVersion::V4 => Uuid::new_v4(),
+        IdVersion::V7 => Uuid::now_v7(),
+    }
+}
+
+/// Shop (tenant) owning a piece of data; lowercase letters, digits, `-` and `_`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TenantId(String);
+
+impl TenantId {
+    /// Maximum length, matching the `VARCHAR(64)` column
+    pub const MAX_LENGTH: usize = 64;
+    /// Tenant owning data created before multi-tenancy
+    pub const DEFAULT: &'static str = "default";
+
+    /// Validate a tenant identifier; case is ignored, as in host names
+    pub fn new(value: impl AsRef<str>) -> DomainResult<Self> {
+        let normalized = value.as_ref().trim().to_lowercase();
+        let valid = !normalized.is_empty()
+            && normalized.len() <= Self::MAX_LENGTH
+            && normalized
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'-' | b'_'));
+
+        if !valid {
+            return Err(AppError::bad_request(
+                Message::new("tenant.invalid")
+                    .arg("tenant", value.as_ref().trim())
+                    .arg("max", Self::MAX_LENGTH),
+            ));
+        }
+        Ok(Self(normalized))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for TenantId {
+    fn default() -> Self {
+        Self(Self::DEFAULT.to_string())
+    }
+}
+
+impl std::fmt::Display for TenantId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for TenantId {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for TenantId {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<TenantId> for String {
+    fn from(tenant: TenantId) -> Self {
+        tenant.0
+    }
+}
+
 /// Pagination parameters
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Pagination {
@@ -21,12 +149,46 @@ impl Default for Pagination {
     fn default() -> Self {
         Self {
             page: 1,
-            per_page: 10,
+            per_page: Self::DEFAULT_PER_PAGE,
         }
     }
 }
 
 impl Pagination {
+    pub const DEFAULT_PER_PAGE: i64 = 10;
+    pub const MAX_PER_PAGE: i64 = 100;
+    /// Keeps `offset()` far away from overflowing
+    pub const MAX_PAGE: i64 = 1_000_000;
+
+    /// Build pagination from optional query values, rejecting out of range input
+    pub fn try_new(page: Option<i64>, per_page: Option<i64>) -> DomainResult<Self> {
+        let page = page.unwrap_or(1);
+        let per_page = per_page.unwrap_or(Self::DEFAULT_PER_PAGE);
+
+        let mut fields = Vec::new();
+        if !(1..=Self::MAX_PAGE).contains(&page) {
+            fields.push(FieldError::new(
+                "page",
+                Message::new("pagination.page.out_of_range").arg("max", Self::MAX_PAGE),
+            ));
+        }
+        if !(1..=Self::MAX_PER_PAGE).contains(&per_page) {
+            fields.push(FieldError::new(
+                "per_page",
+                Message::new("pagination.per_page.out_of_range").arg("max", Self::MAX_PER_PAGE),
+            ));
+        }
+
+        if !fields.is_empty() {
+            return Err(AppError::unprocessable(
+                Message::new("pagination.invalid"),
+                fields,
+            ));
+        }
+
+        Ok(Self { page, per_page })
+    }
+
     pub fn offset(&self) -> i64 {
         (self.page - 1) * self.per_page
     }
@@ -44,17 +206,77 @@ pub struct PaginatedResponse<T> {
     pub page: i64,
     pub per_page: i64,
     pub total_pages: i64,
+    /// Whether `total`, and so `total_pages`, is an estimate
+    #[serde(default)]
+    pub total_estimated: bool,
 }
 
 impl<T> PaginatedResponse<T> {
     pub fn new(data: Vec<T>, total: i64, pagination: &Pagination) -> Self {
-        let total_pages = (total as f64 / pagination.per_page as f64).ceil() as i64;
+        // Integer math: going through f64 miscounts totals beyond 2^53
+        let per_page = pagination.per_page.max(1);
+        let total = total.max(0);
+        let total_pages = total / per_page + i64::from(total % per_page != 0);
         Self {
             data,
             total,
             page: pagination.page,
             per_page: pagination.per_page,
             total_pages,
+            total_estimated: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn pagination_accepts_only_bounded_values(
+            page in prop_oneof![any::<i64>(), -2..=Pagination::MAX_PAGE + 2],
+            per_page in prop_oneof![any::<i64>(), -2..=Pagination::MAX_PER_PAGE + 2],
+        ) {
+            let valid = (1..=Pagination::MAX_PAGE).contains(&page)
+                && (1..=Pagination::MAX_PER_PAGE).contains(&per_page);
+            match Pagination::try_new(Some(page), Some(per_page)) {
+                Ok(pagination) => {
+                    prop_assert!(valid);
+                    prop_assert_eq!(pagination.limit(), per_page);
+                    prop_assert!(pagination.offset() >= 0);
+                    prop_assert_eq!(pagination.offset() % per_page, 0);
+                }
+                Err(error) => {
+                    prop_assert!(!valid);
+                    prop_assert_eq!(error.code(), "pagination.invalid");
+                }
+            }
         }
+
+        #[test]
+        fn pages_cover_the_total_exactly(
+            total in prop_oneof![0..=10_000i64, any::<i64>()],
+            per_page in 1..=Pagination::MAX_PER_PAGE,
+        ) {
+            let pagination = Pagination::try_new(None, Some(per_page)).unwrap();
+            let pages = PaginatedResponse::<()>::new(Vec::new(), total, &pagination).total_pages;
+            let total = i128::from(total.max(0));
+            let (pages, per_page) = (i128::from(pages), i128::from(per_page));
+            prop_assert!(pages * per_page >= total);
+            prop_assert!((pages - 1) * per_page < total || pages == 0);
+        }
+    }
+
+    #[test]
+    fn last_page_starts_without_overflow() {
+        let last = Pagination::try_new(Some(Pagination::MAX_PAGE), Some(Pagination::MAX_PER_PAGE))
+            .unwrap();
+        assert_eq!(
+            last.offset(),
+            (Pagination::MAX_PAGE - 1) * Pagination::MAX_PER_PAGE
+        );
     }
 }
@@ -0,0 +1,43 @@
+//! Store Domain Specific Errors
+
+use uuid::Uuid;
+
+use crate::domain::errors::AppError;
+use crate::i18n::Message;
+
+/// Store error constructors
+pub struct StoreError;
+
+impl StoreError {
+    pub fn not_found(id: Uuid) -> AppError {
+        AppError::not_found(Message::new("store.not_found").arg("id", id))
+    }
+
+    pub fn name_empty() -> AppError {
+        AppError::validation(Message::new("store.name.empty"))
+    }
+
+    pub fn name_too_long(max: usize) -> AppError {
+        AppError::validation(Message::new("store.name.too_long").arg("max", max))
+    }
+
+    pub fn address_empty() -> AppError {
+        AppError::validation(Message::new("store.address.empty"))
+    }
+
+    pub fn address_too_long(max: usize) -> AppError {
+        AppError::validation(Message::new("store.address.too_long").arg("max", max))
+    }
+
+    pub fn latitude_invalid(value: f64) -> AppError {
+        AppError::validation(Message::new("store.latitude.invalid").arg("value", value))
+    }
+
+    pub fn longitude_invalid(value: f64) -> AppError {
+        AppError::validation(Message::new("store.longitude.invalid").arg("value", value))
+    }
+
+    pub fn stock_exceeds_flower(available: i32) -> AppError {
+        AppError::validation(Message::new("store.stock.exceeds_flower").arg("available", available))
+    }
+}
@@ -0,0 +1,89 @@
+//! Geographic Coordinates
+
+use std::ops::RangeInclusive;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::store::errors::StoreError;
+
+/// Mean radius of the Earth in kilometres
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// Point on the Earth in WGS 84 degrees, as GPS and map services give them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl GeoPoint {
+    pub const LATITUDE: RangeInclusive<f64> = -90.0..=90.0;
+    pub const LONGITUDE: RangeInclusive<f64> = -180.0..=180.0;
+
+    pub fn new(latitude: f64, longitude: f64) -> DomainResult<Self> {
+        if !Self::LATITUDE.contains(&latitude) {
+            return Err(StoreError::latitude_invalid(latitude));
+        }
+        if !Self::LONGITUDE.contains(&longitude) {
+            return Err(StoreError::longitude_invalid(longitude));
+        }
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Great-circle distance to `other` in kilometres (haversine formula)
+    ///
+    /// Treats the Earth as a sphere, which is off by at most about 0.5%;
+    /// plenty to rank stores by how far away they are.
+    pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let half_dlat = (lat2 - lat1) / 2.0;
+        let half_dlng = (other.longitude - self.longitude).to_radians() / 2.0;
+
+        let a = half_dlat.sin().powi(2) + lat1.cos() * lat2.cos() * half_dlng.sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_great_circle_distances() {
+        let monas = GeoPoint::new(-6.1754, 106.8272).unwrap();
+        let gedung_sate = GeoPoint::new(-6.9025, 107.6188).unwrap();
+
+        let distance = monas.distance_km(&gedung_sate);
+        assert!((distance - 119.1).abs() < 0.1, "got {distance}");
+        assert_eq!(monas.distance_km(&monas), 0.0);
+
+        let antipode = GeoPoint::new(6.1754, -73.1728).unwrap();
+        assert!((monas.distance_km(&antipode) - 20_015.1).abs() < 0.1);
+    }
+
+    #[test]
+    fn rejects_coordinates_off_the_globe() {
+        assert_eq!(
+            GeoPoint::new(-91.0, 0.0).unwrap_err().code(),
+            "store.latitude.invalid"
+        );
+        assert_eq!(
+            GeoPoint::new(0.0, 180.5).unwrap_err().code(),
+            "store.longitude.invalid"
+        );
+        assert_eq!(
+            GeoPoint::new(f64::NAN, 0.0).unwrap_err().code(),
+            "store.latitude.invalid"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+//! Store Domain Module
+
+pub mod errors;
+pub mod location;
+pub mod store_entity;
+
+pub use errors::StoreError;
+pub use location::GeoPoint;
+pub use store_entity::Store;
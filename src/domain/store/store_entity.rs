@@ -0,0 +1,133 @@
+//! Store Entity
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::{TenantId, new_id};
+use crate::domain::store::errors::StoreError;
+use crate::domain::store::location::GeoPoint;
+
+/// Physical shop of a tenant where customers can pick up flowers
+///
+/// Stores hold part of the tenant's flower stock; how much of each flower a
+/// store holds is kept by the store repository.
+#[derive(Debug, Clone)]
+pub struct Store {
+    id: Uuid,
+    tenant_id: TenantId,
+    name: String,
+    address: String,
+    location: GeoPoint,
+    created_at: DateTime<Utc>,
+}
+
+impl Store {
+    /// Maximum name length in characters, matching the `VARCHAR(100)` column
+    pub const MAX_NAME_LENGTH: usize = 100;
+
+    /// Maximum address length in characters, matching the `VARCHAR(500)` column
+    pub const MAX_ADDRESS_LENGTH: usize = 500;
+
+    pub fn new(
+        tenant_id: TenantId,
+        name: impl AsRef<str>,
+        address: impl AsRef<str>,
+        location: GeoPoint,
+    ) -> DomainResult<Self> {
+        let name = name.as_ref().trim();
+        if name.is_empty() {
+            return Err(StoreError::name_empty());
+        }
+        if name.chars().count() > Self::MAX_NAME_LENGTH {
+            return Err(StoreError::name_too_long(Self::MAX_NAME_LENGTH));
+        }
+
+        let address = address.as_ref().trim();
+        if address.is_empty() {
+            return Err(StoreError::address_empty());
+        }
+        if address.chars().count() > Self::MAX_ADDRESS_LENGTH {
+            return Err(StoreError::address_too_long(Self::MAX_ADDRESS_LENGTH));
+        }
+
+        Ok(Self {
+            id: new_id(),
+            tenant_id,
+            name: name.to_string(),
+            address: address.to_string(),
+            location,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Reconstruct a store from persistence layer
+    pub fn from_persistence(
+        id: Uuid,
+        tenant_id: TenantId,
+        name: String,
+        address: String,
+        location: GeoPoint,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            name,
+            address,
+            location,
+            created_at,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn location(&self) -> GeoPoint {
+        self.location
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_the_name_and_address() {
+        let jakarta = GeoPoint::new(-6.2, 106.8).unwrap();
+        let open =
+            |name: &str, address: &str| Store::new(TenantId::default(), name, address, jakarta);
+
+        let store = open(" Menteng ", " Jl. Cikini Raya 1, Jakarta ").unwrap();
+        assert_eq!(store.name(), "Menteng");
+        assert_eq!(store.address(), "Jl. Cikini Raya 1, Jakarta");
+
+        assert_eq!(open(" ", "Jakarta").unwrap_err().code(), "store.name.empty");
+        assert_eq!(
+            open("Menteng", "").unwrap_err().code(),
+            "store.address.empty"
+        );
+        assert_eq!(
+            open(&"a".repeat(101), "Jakarta").unwrap_err().code(),
+            "store.name.too_long"
+        );
+    }
+}
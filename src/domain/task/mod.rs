@@ -0,0 +1,5 @@
+//! Task Domain Module
+
+pub mod task_entity;
+
+pub use task_entity::{FailedTask, Task};
@@ -0,0 +1,168 @@
+//! Task Entities
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::domain::shared::new_id;
+
+/// Unit of deferred work, dispatched to a handler by its kind
+#[derive(Debug, Clone)]
+pub struct Task {
+    id: Uuid,
+    kind: String,
+    payload: Value,
+    attempts: i32,
+    max_attempts: i32,
+    run_at: DateTime<Utc>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl Task {
+    /// Create a task due at `run_at`
+    pub fn new(
+        kind: impl Into<String>,
+        payload: Value,
+        run_at: DateTime<Utc>,
+        max_attempts: i32,
+    ) -> Self {
+        Self {
+            id: new_id(),
+            kind: kind.into(),
+            payload,
+            attempts: 0,
+            max_attempts: max_attempts.max(1),
+            run_at,
+            last_error: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Reconstruct a task from persistence layer
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_persistence(
+        id: Uuid,
+        kind: String,
+        payload: Value,
+        attempts: i32,
+        max_attempts: i32,
+        run_at: DateTime<Utc>,
+        last_error: Option<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            kind,
+            payload,
+            attempts,
+            max_attempts,
+            run_at,
+            last_error,
+            created_at,
+        }
+    }
+
+    /// Whether another attempt is allowed after the current one fails
+    pub fn can_retry(&self) -> bool {
+        self.attempts < self.max_attempts
+    }
+
+    // Getters
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn payload(&self) -> &Value {
+        &self.payload
+    }
+
+    /// Attempts made so far, including the one in progress
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    pub fn max_attempts(&self) -> i32 {
+        self.max_attempts
+    }
+
+    pub fn run_at(&self) -> DateTime<Utc> {
+        self.run_at
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+/// Task moved to the dead-letter table after its final failed attempt
+#[derive(Debug, Clone)]
+pub struct FailedTask {
+    id: Uuid,
+    kind: String,
+    payload: Value,
+    attempts: i32,
+    last_error: String,
+    created_at: DateTime<Utc>,
+    failed_at: DateTime<Utc>,
+}
+
+impl FailedTask {
+    /// Reconstruct a failed task from persistence layer
+    pub fn from_persistence(
+        id: Uuid,
+        kind: String,
+        payload: Value,
+        attempts: i32,
+        last_error: String,
+        created_at: DateTime<Utc>,
+        failed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            kind,
+            payload,
+            attempts,
+            last_error,
+            created_at,
+            failed_at,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn payload(&self) -> &Value {
+        &self.payload
+    }
+
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    pub fn last_error(&self) -> &str {
+        &self.last_error
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn failed_at(&self) -> DateTime<Utc> {
+        self.failed_at
+    }
+}
@@ -0,0 +1,266 @@
+//! Internationalization
+//!
+//! Messages are identified by language-independent keys and rendered from
+//! simple `key = value` catalogs, one per supported locale. The locale of
+//! the current request is stored in a task-local by the HTTP layer.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::OnceLock;
+
+const EN_CATALOG: &str = include_str!("locales/en.properties");
+const ID_CATALOG: &str = include_str!("locales/id.properties");
+
+tokio::task_local! {
+    static CURRENT_LOCALE: Locale;
+}
+
+/// Supported locales
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Id,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Id];
+
+    /// BCP 47 language tag
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Id => "id",
+        }
+    }
+
+    /// Match a single language tag such as `id-ID` or `en`
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next()?.trim().to_lowercase();
+        match primary.as_str() {
+            "en" => Some(Locale::En),
+            // "in" is the deprecated ISO 639 code some clients still send
+            "id" | "in" => Some(Locale::Id),
+            _ => None,
+        }
+    }
+
+    /// Pick the preferred supported locale from an `Accept-Language` header
+    pub fn from_accept_language(header: &str) -> Self {
+        let mut candidates: Vec<(f32, Locale)> = header
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';');
+                let locale = Self::from_tag(parts.next()?.trim())?;
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .and_then(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (quality > 0.0).then_some((quality, locale))
+            })
+            .collect();
+
+        // Stable sort keeps header order for equal weights
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+        candidates
+            .first()
+            .map(|(_, locale)| *locale)
+            .unwrap_or_default()
+    }
+
+    /// Locale of the request being handled, or the default outside a request
+    pub fn current() -> Self {
+        CURRENT_LOCALE
+            .try_with(|locale| *locale)
+            .unwrap_or_default()
+    }
+
+    /// Run a future with this locale as the current locale
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT_LOCALE.scope(self, future).await
+    }
+
+    fn catalog(&self) -> &'static HashMap<&'static str, &'static str> {
+        static EN: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+        static ID: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+
+        match self {
+            Locale::En => EN.get_or_init(|| parse_catalog(EN_CATALOG)),
+            Locale::Id => ID.get_or_init(|| parse_catalog(ID_CATALOG)),
+        }
+    }
+}
+
+/// A translatable message: a catalog key plus named arguments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    key: &'static str,
+    args: Vec<(&'static str, String)>,
+}
+
+impl Message {
+    /// Key used for untranslated free-form text
+    pub const RAW_KEY: &'static str = "raw";
+
+    pub fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            args: Vec::new(),
+        }
+    }
+
+    /// Free-form text that is shown as-is in every locale
+    pub fn raw(text: impl Into<String>) -> Self {
+        Self::new(Self::RAW_KEY).arg("text", text.into())
+    }
+
+    pub fn arg(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.args.push((name, value.to_string()));
+        self
+    }
+
+    pub fn key(&self) -> &'static str {
+        self.key
+    }
+
+    pub fn is_raw(&self) -> bool {
+        self.key == Self::RAW_KEY
+    }
+
+    /// Render the message, falling back to English and then to the key
+    ///
+    /// Placeholders are filled in one pass over the template, so arguments,
+    /// which may come from users, are never expanded themselves.
+    pub fn localize(&self, locale: Locale) -> String {
+        let template = locale
+            .catalog()
+            .get(self.key)
+            .or_else(|| Locale::En.catalog().get(self.key))
+            .copied()
+            .unwrap_or(self.key);
+
+        let mut text = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            text.push_str(&rest[..start]);
+            let placeholder = &rest[start..];
+            let value = placeholder.find('}').and_then(|end| {
+                let name = &placeholder[1..end];
+                let (_, value) = self.args.iter().find(|(arg, _)| *arg == name)?;
+                Some((value, end))
+            });
+            match value {
+                Some((value, end)) => {
+                    text.push_str(value);
+                    rest = &placeholder[end + 1..];
+                }
+                None => {
+                    text.push('{');
+                    rest = &placeholder[1..];
+                }
+            }
+        }
+        text.push_str(rest);
+        text
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.localize(Locale::En))
+    }
+}
+
+impl From<String> for Message {
+    fn from(text: String) -> Self {
+        Self::raw(text)
+    }
+}
+
+impl From<&str> for Message {
+    fn from(text: &str) -> Self {
+        Self::raw(text)
+    }
+}
+
+/// Translate a message key without arguments into the current locale
+pub fn t(key: &'static str) -> String {
+    Message::new(key).localize(Locale::current())
+}
+
+fn parse_catalog(source: &'static str) -> HashMap<&'static str, &'static str> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accept_language_prefers_highest_quality_supported_tag() {
+        assert_eq!(
+            Locale::from_accept_language("id-ID,id;q=0.9,en;q=0.8"),
+            Locale::Id
+        );
+        assert_eq!(
+            Locale::from_accept_language("fr-FR,en;q=0.5,id;q=0.4"),
+            Locale::En
+        );
+        assert_eq!(
+            Locale::from_accept_language("en;q=0.2,id;q=0.7"),
+            Locale::Id
+        );
+        assert_eq!(Locale::from_accept_language("fr, de"), Locale::En);
+        assert_eq!(Locale::from_accept_language("id;q=0"), Locale::En);
+    }
+
+    #[test]
+    fn every_key_is_translated_in_every_locale() {
+        let english = Locale::En.catalog();
+        for locale in Locale::ALL {
+            let catalog = locale.catalog();
+            let mut missing: Vec<_> = english
+                .keys()
+                .filter(|key| !catalog.contains_key(*key))
+                .collect();
+            missing.sort();
+            assert!(
+                missing.is_empty(),
+                "{} is missing {:?}",
+                locale.code(),
+                missing
+            );
+        }
+    }
+
+    #[test]
+    fn messages_interpolate_arguments() {
+        let message = Message::new("flower.name.too_long").arg("max", 100);
+        assert_eq!(
+            message.to_string(),
+            "Invalid flower name: name cannot exceed 100 characters"
+        );
+        assert_eq!(
+            message.localize(Locale::Id),
+            "Nama bunga tidak valid: nama tidak boleh melebihi 100 karakter"
+        );
+    }
+
+    #[test]
+    fn arguments_are_not_expanded_themselves() {
+        let message = Message::new("flower.attributes.invalid")
+            .arg("name", "{value}")
+            .arg("value", "{name}");
+        assert_eq!(
+            message.to_string(),
+            "Invalid flower attribute {value}: '{name}'"
+        );
+    }
+}
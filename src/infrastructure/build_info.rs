@@ -0,0 +1,65 @@
+//! Build Information embedded at compile time by `build.rs`
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Metadata identifying the running build
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(example = json!({
+    "name": "rust-api",
+    "version": "0.1.0",
+    "git_sha": "3f37c40b2a1d",
+    "build_timestamp": "2024-12-11T00:00:00Z",
+    "features": ["sentry"]
+}))]
+pub struct BuildInfo {
+    /// Crate name
+    pub name: &'static str,
+    /// Crate version
+    pub version: &'static str,
+    /// Short git commit SHA, or "unknown"
+    pub git_sha: &'static str,
+    /// Time the binary was built
+    pub build_timestamp: DateTime<Utc>,
+    /// Enabled cargo features
+    pub features: Vec<&'static str>,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        let build_timestamp = env!("BUILD_TIMESTAMP")
+            .parse()
+            .ok()
+            .and_then(|secs| DateTime::from_timestamp(secs, 0))
+            .unwrap_or_default();
+
+        Self {
+            name: env!("CARGO_PKG_NAME"),
+            version: env!("CARGO_PKG_VERSION"),
+            git_sha: env!("BUILD_GIT_SHA"),
+            build_timestamp,
+            features: env!("BUILD_FEATURES")
+                .split(',')
+                .filter(|feature| !feature.is_empty())
+                .collect(),
+        }
+    }
+
+    /// One-line summary for logs and docs
+    pub fn summary(&self) -> String {
+        let features = if self.features.is_empty() {
+            "none".to_string()
+        } else {
+            self.features.join(", ")
+        };
+        format!(
+            "{} {} (git {}, built {}, features: {})",
+            self.name,
+            self.version,
+            self.git_sha,
+            self.build_timestamp.to_rfc3339(),
+            features
+        )
+    }
+}
@@ -0,0 +1,248 @@
+//! Read-through cache decorator for FlowerRepository
+//!
+//! Point lookups are cached per flower and invalidated on update and delete.
+//! The first `CACHE_LIST_PAGES` pages of the unfiltered listing (and its
+//! count and facets) are cached under a per-tenant generation number; every write bumps
+//! the generation, so stale pages are simply never read again and expire on
+//! their own. Cache failures are logged and fall back to the database.
+//!
+//! Lookups are counted in `cache_requests_total{cache="flowers",result}`,
+//! with `hit`, `miss` or `error` as result.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+
+use crate::application::ports::{Cache, FlowerFacets, FlowerRepository};
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerAttributes, FlowerColor, FlowerMetadata};
+use crate::domain::shared::{Entity, Pagination, TenantId};
+
+/// Caching decorator around another FlowerRepository
+pub struct CachedFlowerRepository<R: FlowerRepository + ?Sized> {
+    inner: Arc<R>,
+    cache: Arc<dyn Cache>,
+    ttl: Duration,
+    list_pages: i64,
+}
+
+impl<R: FlowerRepository + ?Sized> CachedFlowerRepository<R> {
+    pub fn new(inner: Arc<R>, cache: Arc<dyn Cache>, ttl: Duration, list_pages: i64) -> Self {
+        Self {
+            inner,
+            cache,
+            ttl,
+            list_pages,
+        }
+    }
+
+    /// Current list generation of a tenant, `None` if the cache is unavailable
+    async fn generation(&self, tenant: &TenantId) -> Option<i64> {
+        match self.cache.get(&generation_key(tenant)).await {
+            Ok(value) => Some(value.and_then(|v| v.parse().ok()).unwrap_or(0)),
+            Err(e) => {
+                tracing::warn!("Flower cache unavailable: {}", e);
+                None
+            }
+        }
+    }
+
+    async fn read<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let (value, result) = match self.cache.get(key).await {
+            Ok(value) => match value.and_then(|json| serde_json::from_str(&json).ok()) {
+                Some(value) => (Some(value), "hit"),
+                None => (None, "miss"),
+            },
+            Err(e) => {
+                tracing::warn!("Flower cache read failed for {}: {}", key, e);
+                (None, "error")
+            }
+        };
+
+        metrics::counter!("cache_requests_total", "cache" => "flowers", "result" => result)
+            .increment(1);
+        value
+    }
+
+    async fn write<T: Serialize>(&self, key: &str, value: &T) {
+        let Ok(json) = serde_json::to_string(value) else {
+            return;
+        };
+        if let Err(e) = self.cache.set(key, &json, self.ttl).await {
+            tracing::warn!("Flower cache write failed for {}: {}", key, e);
+        }
+    }
+
+    async fn invalidate(&self, tenant: &TenantId, id: Option<Uuid>) {
+        invalidate(self.cache.as_ref(), tenant, id).await
+    }
+}
+
+pub(crate) fn flower_key(tenant: &TenantId, id: Uuid) -> String {
+    format!("flowers:{}:{}", tenant, id)
+}
+
+pub(crate) fn generation_key(tenant: &TenantId) -> String {
+    format!("flowers:{}:generation", tenant)
+}
+
+/// Drop a flower's cached copy and every cached list page of its tenant
+pub(crate) async fn invalidate(cache: &dyn Cache, tenant: &TenantId, id: Option<Uuid>) {
+    if let Some(id) = id
+        && let Err(e) = cache.delete(&flower_key(tenant, id)).await
+    {
+        tracing::warn!("Flower cache invalidation failed for {}: {}", id, e);
+    }
+    if let Err(e) = cache.increment(&generation_key(tenant)).await {
+        tracing::warn!("Flower cache invalidation failed for {}: {}", tenant, e);
+    }
+}
+
+#[async_trait]
+impl<R: FlowerRepository + ?Sized> FlowerRepository for CachedFlowerRepository<R> {
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        let key = flower_key(tenant, id);
+        if let Some(flower) = self.read::<Flower>(&key).await {
+            return Ok(Some(flower));
+        }
+
+        let flower = self.inner.find_by_id(tenant, id).await?;
+        if let Some(flower) = &flower {
+            self.write(&key, flower).await;
+        }
+        Ok(flower)
+    }
+
+    async fn find_by_sku(&self, tenant: &TenantId, sku: &str) -> DomainResult<Option<Flower>> {
+        self.inner.find_by_sku(tenant, sku).await
+    }
+
+    async fn find_all(
+        &self,
+        tenant: &TenantId,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>> {
+        let generation = match self.generation(tenant).await {
+            Some(generation) if pagination.page <= self.list_pages => generation,
+            _ => return self.inner.find_all(tenant, pagination).await,
+        };
+
+        let key = format!(
+            "flowers:{}:list:{}:{}:{}",
+            tenant, generation, pagination.page, pagination.per_page
+        );
+        if let Some(flowers) = self.read::<Vec<Flower>>(&key).await {
+            return Ok(flowers);
+        }
+
+        let flowers = self.inner.find_all(tenant, pagination).await?;
+        self.write(&key, &flowers).await;
+        Ok(flowers)
+    }
+
+    async fn count(&self, tenant: &TenantId) -> DomainResult<i64> {
+        let Some(generation) = self.generation(tenant).await else {
+            return self.inner.count(tenant).await;
+        };
+
+        let key = format!("flowers:{}:count:{}", tenant, generation);
+        if let Some(count) = self.read::<i64>(&key).await {
+            return Ok(count);
+        }
+
+        let count = self.inner.count(tenant).await?;
+        self.write(&key, &count).await;
+        Ok(count)
+    }
+
+    async fn search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>> {
+        self.inner
+            .search(tenant, query, color, attributes, metadata, pagination)
+            .await
+    }
+
+    async fn count_search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<i64> {
+        self.inner
+            .count_search(tenant, query, color, attributes, metadata)
+            .await
+    }
+
+    async fn estimate_count(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<Option<i64>> {
+        self.inner
+            .estimate_count(tenant, query, color, attributes, metadata)
+            .await
+    }
+
+    async fn facets(&self, tenant: &TenantId) -> DomainResult<FlowerFacets> {
+        let Some(generation) = self.generation(tenant).await else {
+            return self.inner.facets(tenant).await;
+        };
+
+        let key = format!("flowers:{}:facets:{}", tenant, generation);
+        if let Some(facets) = self.read::<FlowerFacets>(&key).await {
+            return Ok(facets);
+        }
+
+        let facets = self.inner.facets(tenant).await?;
+        self.write(&key, &facets).await;
+        Ok(facets)
+    }
+
+    async fn create(&self, flower: &Flower) -> DomainResult<Flower> {
+        let created = self.inner.create(flower).await?;
+        self.invalidate(created.tenant_id(), None).await;
+        Ok(created)
+    }
+
+    async fn update(&self, flower: &Flower) -> DomainResult<Flower> {
+        let updated = self.inner.update(flower).await?;
+        self.invalidate(updated.tenant_id(), Some(updated.id()))
+            .await;
+        Ok(updated)
+    }
+
+    async fn find_low_stock(&self, threshold: i32) -> DomainResult<Vec<Flower>> {
+        self.inner.find_low_stock(threshold).await
+    }
+
+    async fn find_created_since(
+        &self,
+        tenant: &TenantId,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<Flower>> {
+        self.inner.find_created_since(tenant, since).await
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
+        self.inner.delete(tenant, id).await?;
+        self.invalidate(tenant, Some(id)).await;
+        Ok(())
+    }
+}
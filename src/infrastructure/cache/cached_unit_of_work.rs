@@ -0,0 +1,114 @@
+//! Cache invalidation for UnitOfWork
+//!
+//! Flowers changed inside a transaction bypass `CachedFlowerRepository`, so
+//! this decorator remembers them and invalidates their cache entries once
+//! the transaction commits. Rolled back changes leave the cache untouched.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::{Cache, Transaction, UnitOfWork};
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerChange, FlowerColor};
+use crate::domain::inventory::StockMovement;
+use crate::domain::order::{Order, OrderEvent};
+use crate::domain::shared::{Entity, TenantId};
+use crate::domain::task::Task;
+use crate::infrastructure::cache::cached_flower_repo;
+
+/// UnitOfWork decorator keeping the flower cache consistent
+pub struct CachedUnitOfWork {
+    inner: Arc<dyn UnitOfWork>,
+    cache: Arc<dyn Cache>,
+}
+
+impl CachedUnitOfWork {
+    pub fn new(inner: Arc<dyn UnitOfWork>, cache: Arc<dyn Cache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for CachedUnitOfWork {
+    async fn begin(&self) -> DomainResult<Box<dyn Transaction>> {
+        Ok(Box::new(CachedTransaction {
+            inner: self.inner.begin().await?,
+            cache: self.cache.clone(),
+            changed: Vec::new(),
+        }))
+    }
+}
+
+struct CachedTransaction {
+    inner: Box<dyn Transaction>,
+    cache: Arc<dyn Cache>,
+    changed: Vec<(TenantId, Uuid)>,
+}
+
+#[async_trait]
+impl Transaction for CachedTransaction {
+    async fn lock_flower(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        self.inner.lock_flower(tenant, id).await
+    }
+
+    async fn lock_flowers(
+        &mut self,
+        tenant: &TenantId,
+        color: Option<FlowerColor>,
+        ids: Option<&[Uuid]>,
+    ) -> DomainResult<Vec<Flower>> {
+        self.inner.lock_flowers(tenant, color, ids).await
+    }
+
+    async fn insert_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        let created = self.inner.insert_flower(flower).await?;
+        self.changed
+            .push((created.tenant_id().clone(), created.id()));
+        Ok(created)
+    }
+
+    async fn update_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        let updated = self.inner.update_flower(flower).await?;
+        self.changed
+            .push((updated.tenant_id().clone(), updated.id()));
+        Ok(updated)
+    }
+
+    async fn record_stock_movement(&mut self, movement: &StockMovement) -> DomainResult<()> {
+        self.inner.record_stock_movement(movement).await
+    }
+
+    async fn record_flower_changes(&mut self, changes: &[FlowerChange]) -> DomainResult<()> {
+        self.inner.record_flower_changes(changes).await
+    }
+
+    async fn insert_order(&mut self, order: &Order) -> DomainResult<()> {
+        self.inner.insert_order(order).await
+    }
+
+    async fn lock_order(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Order>> {
+        self.inner.lock_order(tenant, id).await
+    }
+
+    async fn update_order(&mut self, order: &Order) -> DomainResult<()> {
+        self.inner.update_order(order).await
+    }
+
+    async fn record_order_events(&mut self, events: &[OrderEvent]) -> DomainResult<()> {
+        self.inner.record_order_events(events).await
+    }
+
+    async fn enqueue_task(&mut self, task: &Task) -> DomainResult<()> {
+        self.inner.enqueue_task(task).await
+    }
+
+    async fn commit(self: Box<Self>) -> DomainResult<()> {
+        self.inner.commit().await?;
+        for (tenant, id) in &self.changed {
+            cached_flower_repo::invalidate(self.cache.as_ref(), tenant, Some(*id)).await;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+//! Cache Health Indicator
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::ports::{Cache, Health, HealthIndicator};
+
+/// Whether the cache answers lookups; flowers are still served from the
+/// database while it does not, so it is not critical
+pub struct CacheHealth(pub Arc<dyn Cache>);
+
+#[async_trait]
+impl HealthIndicator for CacheHealth {
+    fn name(&self) -> &'static str {
+        "cache"
+    }
+
+    fn critical(&self) -> bool {
+        false
+    }
+
+    async fn check(&self) -> Health {
+        match self.0.get("health:probe").await {
+            Ok(_) => Health::up(),
+            Err(e) => Health::down(e),
+        }
+    }
+}
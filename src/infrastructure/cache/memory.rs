@@ -0,0 +1,151 @@
+//! In-process Cache
+
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use moka::Expiry;
+use moka::future::Cache as MokaCache;
+use moka::ops::compute::Op;
+
+use crate::application::ports::Cache;
+use crate::domain::errors::{AppError, DomainResult};
+
+/// Cached value with its own lifetime; counters have none
+#[derive(Debug, Clone)]
+struct CachedValue {
+    value: String,
+    ttl: Option<Duration>,
+}
+
+/// Expires each entry after the lifetime it was stored with
+struct PerEntryTtl;
+
+impl Expiry<String, CachedValue> for PerEntryTtl {
+    fn expire_after_create(
+        &self,
+        _key: &String,
+        value: &CachedValue,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        value.ttl
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &String,
+        value: &CachedValue,
+        _updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        value.ttl
+    }
+}
+
+/// Bounded cache held in the memory of this instance
+///
+/// Only suited to single-instance deployments: writes on one replica do not
+/// invalidate the entries cached by another.
+pub struct MemoryCache {
+    entries: MokaCache<String, CachedValue>,
+}
+
+impl MemoryCache {
+    pub fn new(max_entries: u64) -> Self {
+        Self {
+            entries: MokaCache::builder()
+                .max_capacity(max_entries)
+                .expire_after(PerEntryTtl)
+                .build(),
+        }
+    }
+}
+
+#[async_trait]
+impl Cache for MemoryCache {
+    async fn get(&self, key: &str) -> DomainResult<Option<String>> {
+        Ok(self.entries.get(key).await.map(|entry| entry.value))
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl: Duration) -> DomainResult<()> {
+        let value = CachedValue {
+            value: value.to_string(),
+            ttl: Some(ttl),
+        };
+        self.entries.insert(key.to_string(), value).await;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> DomainResult<()> {
+        self.entries.invalidate(key).await;
+        Ok(())
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> DomainResult<u64> {
+        let keys: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, _)| key)
+            .collect();
+        for key in &keys {
+            self.entries.invalidate(key.as_str()).await;
+        }
+        Ok(keys.len() as u64)
+    }
+
+    async fn increment(&self, key: &str) -> DomainResult<i64> {
+        let result = self
+            .entries
+            .entry_by_ref(key)
+            .and_compute_with(|current| {
+                let count = current
+                    .and_then(|entry| entry.into_value().value.parse::<i64>().ok())
+                    .unwrap_or(0);
+                std::future::ready(Op::Put(CachedValue {
+                    value: (count + 1).to_string(),
+                    ttl: None,
+                }))
+            })
+            .await;
+
+        result
+            .into_entry()
+            .and_then(|entry| entry.into_value().value.parse().ok())
+            .ok_or_else(|| AppError::internal(format!("Cache counter {} is not a number", key)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn counters_survive_entry_expiry() {
+        let cache = MemoryCache::new(100);
+        cache
+            .set("page", "[]", Duration::from_millis(10))
+            .await
+            .unwrap();
+        assert_eq!(cache.increment("generation").await.unwrap(), 1);
+        assert_eq!(cache.increment("generation").await.unwrap(), 2);
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(cache.get("page").await.unwrap(), None);
+        assert_eq!(cache.get("generation").await.unwrap().as_deref(), Some("2"));
+    }
+
+    #[tokio::test]
+    async fn deletes_by_prefix() {
+        let cache = MemoryCache::new(100);
+        let minute = Duration::from_secs(60);
+        for key in ["flowers:a:1", "flowers:a:2", "flowers:b:1"] {
+            cache.set(key, "{}", minute).await.unwrap();
+        }
+        cache.increment("flowers:a:generation").await.unwrap();
+
+        assert_eq!(cache.delete_prefix("flowers:a:").await.unwrap(), 3);
+        assert_eq!(cache.get("flowers:a:1").await.unwrap(), None);
+        assert!(cache.get("flowers:b:1").await.unwrap().is_some());
+        assert_eq!(cache.delete_prefix("").await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,48 @@
+//! Caching
+//!
+//! `CACHE_BACKEND` selects the cache placed in front of flower lookups:
+//! in-process memory for single-instance deployments, or Redis shared by all
+//! replicas (requires the `redis` feature). The admin may purge it through
+//! `CacheStorePurger`.
+
+pub mod cached_flower_repo;
+pub mod cached_unit_of_work;
+pub mod health;
+pub mod memory;
+pub mod purger;
+#[cfg(feature = "redis")]
+mod redis;
+
+use std::sync::Arc;
+
+use crate::application::ports::Cache;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::config::{AppConfig, CacheBackend};
+
+pub use cached_flower_repo::CachedFlowerRepository;
+pub use cached_unit_of_work::CachedUnitOfWork;
+pub use health::CacheHealth;
+pub use memory::MemoryCache;
+pub use purger::CacheStorePurger;
+#[cfg(feature = "redis")]
+pub use redis::RedisCache;
+
+/// Connect to the cache selected by configuration, if any
+pub async fn store(config: &AppConfig) -> DomainResult<Option<Arc<dyn Cache>>> {
+    match config.cache_backend {
+        CacheBackend::None => Ok(None),
+        CacheBackend::Memory => Ok(Some(Arc::new(MemoryCache::new(config.cache_max_entries)))),
+        #[cfg(feature = "redis")]
+        CacheBackend::Redis => {
+            let url = config
+                .redis_url
+                .as_deref()
+                .ok_or_else(|| AppError::internal("CACHE_BACKEND=redis requires REDIS_URL"))?;
+            Ok(Some(Arc::new(RedisCache::connect(url).await?)))
+        }
+        #[cfg(not(feature = "redis"))]
+        CacheBackend::Redis => Err(AppError::internal(
+            "CACHE_BACKEND=redis requires building with the `redis` feature",
+        )),
+    }
+}
@@ -0,0 +1,82 @@
+//! Cache purges requested by the admin
+//!
+//! Purging a flower also bumps its tenant's list generation, so every cached
+//! page that may show the flower is dropped along with it. A purge by prefix
+//! that removes a generation counter restarts it from 0; pages cached under
+//! the old numbers are only left if the prefix spared them.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::ports::{Cache, CachePurge, CachePurger};
+use crate::domain::errors::DomainResult;
+use crate::infrastructure::cache::cached_flower_repo::{flower_key, generation_key};
+
+/// Purges entries from the configured cache
+pub struct CacheStorePurger {
+    cache: Arc<dyn Cache>,
+}
+
+impl CacheStorePurger {
+    pub fn new(cache: Arc<dyn Cache>) -> Self {
+        Self { cache }
+    }
+}
+
+#[async_trait]
+impl CachePurger for CacheStorePurger {
+    async fn purge(&self, target: &CachePurge) -> DomainResult<u64> {
+        let removed = match target {
+            CachePurge::Flower { tenant, id } => {
+                let removed = self.cache.delete_prefix(&flower_key(tenant, *id)).await?;
+                self.cache.increment(&generation_key(tenant)).await?;
+                removed
+            }
+            CachePurge::Prefix(prefix) => self.cache.delete_prefix(prefix).await?,
+            CachePurge::All => self.cache.delete_prefix("").await?,
+        };
+
+        tracing::info!(?target, removed, "Purged cache");
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::shared::TenantId;
+    use crate::infrastructure::cache::MemoryCache;
+
+    #[tokio::test]
+    async fn purging_a_flower_retires_its_tenant_listings() {
+        let cache = Arc::new(MemoryCache::new(100));
+        let purger = CacheStorePurger::new(cache.clone());
+        let tenant = TenantId::default();
+        let id = Uuid::new_v4();
+        cache
+            .set(&flower_key(&tenant, id), "{}", Duration::from_secs(60))
+            .await
+            .unwrap();
+
+        let flower = CachePurge::Flower {
+            tenant: tenant.clone(),
+            id,
+        };
+        assert_eq!(purger.purge(&flower).await.unwrap(), 1);
+        assert_eq!(cache.get(&flower_key(&tenant, id)).await.unwrap(), None);
+        assert_eq!(
+            cache
+                .get(&generation_key(&tenant))
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("1")
+        );
+        assert_eq!(purger.purge(&CachePurge::All).await.unwrap(), 1);
+    }
+}
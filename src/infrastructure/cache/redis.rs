@@ -0,0 +1,104 @@
+//! Redis Cache
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+
+use crate::application::ports::Cache;
+use crate::domain::errors::{AppError, DomainResult};
+
+/// Cache shared by all replicas through Redis
+///
+/// The connection manager reconnects on its own after Redis restarts.
+pub struct RedisCache {
+    connection: ConnectionManager,
+}
+
+impl RedisCache {
+    pub async fn connect(url: &str) -> DomainResult<Self> {
+        let client = redis::Client::open(url).map_err(redis_error)?;
+        let connection = ConnectionManager::new(client)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to connect to Redis: {}", e)))?;
+
+        Ok(Self { connection })
+    }
+}
+
+#[async_trait]
+impl Cache for RedisCache {
+    async fn get(&self, key: &str) -> DomainResult<Option<String>> {
+        self.connection.clone().get(key).await.map_err(redis_error)
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl: Duration) -> DomainResult<()> {
+        self.connection
+            .clone()
+            .set_ex(key, value, ttl.as_secs().max(1))
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn delete(&self, key: &str) -> DomainResult<()> {
+        self.connection.clone().del(key).await.map_err(redis_error)
+    }
+
+    /// Keys are found with `SCAN`, so Redis keeps serving while a large
+    /// cache is walked; keys written meanwhile may survive
+    async fn delete_prefix(&self, prefix: &str) -> DomainResult<u64> {
+        let pattern = format!("{}*", escape_pattern(prefix));
+        let keys: Vec<String> = {
+            let mut connection = self.connection.clone();
+            let mut iter = connection
+                .scan_match::<_, String>(pattern)
+                .await
+                .map_err(redis_error)?;
+            let mut keys = Vec::new();
+            while let Some(key) = iter.next_item().await {
+                keys.push(key);
+            }
+            keys
+        };
+
+        let mut removed = 0;
+        for batch in keys.chunks(DELETE_BATCH) {
+            let deleted: u64 = self
+                .connection
+                .clone()
+                .del(batch)
+                .await
+                .map_err(redis_error)?;
+            removed += deleted;
+        }
+        Ok(removed)
+    }
+
+    async fn increment(&self, key: &str) -> DomainResult<i64> {
+        self.connection
+            .clone()
+            .incr(key, 1)
+            .await
+            .map_err(redis_error)
+    }
+}
+
+/// Keys removed per `DEL`
+const DELETE_BATCH: usize = 500;
+
+/// `prefix` matched literally in a `SCAN MATCH` glob
+fn escape_pattern(prefix: &str) -> String {
+    let mut pattern = String::with_capacity(prefix.len());
+    for c in prefix.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern
+}
+
+fn redis_error(error: redis::RedisError) -> AppError {
+    AppError::internal(format!("Redis error: {}", error))
+}
@@ -0,0 +1,258 @@
+//! Startup Diagnostics
+//!
+//! The effective configuration, logged once on boot so an operator can see
+//! what the server actually runs with. Credentials never appear: passwords
+//! in URLs are masked and tokens and keys are only reported as set or not.
+
+use std::fmt::Write;
+
+use crate::infrastructure::config::{AppConfig, CacheBackend, EmailTransport, SecretsSource};
+use crate::infrastructure::storage::MEMORY_SCHEME;
+
+/// Replaces the password of URLs
+const MASK: &str = "****";
+
+/// Summary of the effective configuration, one setting per line
+pub fn banner(config: &AppConfig) -> String {
+    let mut lines: Vec<(&str, String)> = vec![
+        ("profile", config.profile.to_string()),
+        ("listening on", listen_address(config)),
+        ("secrets", secrets_source(&config.secrets).to_string()),
+        ("database", redact_url(&config.database_url)),
+    ];
+    if !config.database_url.starts_with(MEMORY_SCHEME) {
+        lines.push((
+            "database pool",
+            format!(
+                "{}-{} connections, acquire timeout {}s",
+                config.db_pool.min_connections,
+                config.db_pool.max_connections,
+                config.db_pool.acquire_timeout.as_secs()
+            ),
+        ));
+    }
+    if !config.database_read_urls.is_empty() {
+        let replicas: Vec<_> = config
+            .database_read_urls
+            .iter()
+            .map(|url| redact_url(url))
+            .collect();
+        lines.push(("read replicas", replicas.join(", ")));
+    }
+    lines.extend([
+        (
+            "cache",
+            match config.cache_backend {
+                CacheBackend::None => "none".to_string(),
+                CacheBackend::Memory => format!(
+                    "memory, {} entries, ttl {}s",
+                    config.cache_max_entries,
+                    config.cache_ttl.as_secs()
+                ),
+                CacheBackend::Redis => format!(
+                    "redis at {}, ttl {}s",
+                    config
+                        .redis_url
+                        .as_deref()
+                        .map_or("?".to_string(), redact_url),
+                    config.cache_ttl.as_secs()
+                ),
+            },
+        ),
+        (
+            "email",
+            match config.email_transport {
+                EmailTransport::Console => "console".to_string(),
+                EmailTransport::Smtp => format!(
+                    "smtp at {}",
+                    config
+                        .smtp_url
+                        .as_deref()
+                        .map_or("?".to_string(), redact_url)
+                ),
+            },
+        ),
+        (
+            "object store",
+            config.object_store_path.as_ref().map_or_else(
+                || "none (backups and catalog exports disabled)".to_string(),
+                |path| path.display().to_string(),
+            ),
+        ),
+        (
+            "background work",
+            format!(
+                "shared jobs {}, {} task workers",
+                on_off(config.jobs_enabled),
+                config.task_workers
+            ),
+        ),
+        (
+            "limits",
+            format!(
+                "{} concurrent requests, {}s timeout, {} byte bodies",
+                config.max_concurrent_requests,
+                config.request_timeout.as_secs(),
+                config.max_body_size
+            ),
+        ),
+        (
+            "admin token",
+            if config.admin_token.is_some() {
+                "set"
+            } else {
+                "unset"
+            }
+            .to_string(),
+        ),
+        (
+            "tenants",
+            format!(
+                "default {}, {} API keys, anonymous writes {}",
+                config.default_tenant,
+                config.tenant_api_keys.len(),
+                on_off(config.anonymous_writes)
+            ),
+        ),
+        (
+            "suppliers",
+            if config.suppliers.is_empty() {
+                "none".to_string()
+            } else {
+                config
+                    .suppliers
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
+        ),
+        (
+            "shipping courier",
+            config
+                .shipping_courier
+                .as_ref()
+                .map_or("none".to_string(), |courier| {
+                    format!("{} at {}", courier.name, redact_url(&courier.url))
+                }),
+        ),
+        (
+            "payment webhooks",
+            match config.payment_webhook_secrets.len() {
+                0 => "off".to_string(),
+                secrets => format!(
+                    "{} secret(s), tolerance {}s",
+                    secrets,
+                    config.webhook_tolerance.as_secs()
+                ),
+            },
+        ),
+        (
+            "tenant webhooks",
+            format!(
+                "{}, previous secrets kept {}s",
+                if cfg!(feature = "webhooks") {
+                    "sent"
+                } else {
+                    "logged"
+                },
+                config.webhook_rotation_window.as_secs()
+            ),
+        ),
+        (
+            "error reporting",
+            on_off(config.sentry_dsn.is_some()).to_string(),
+        ),
+    ]);
+
+    let width = lines
+        .iter()
+        .map(|(label, _)| label.len())
+        .max()
+        .unwrap_or(0);
+    let mut banner = String::from("Effective configuration:");
+    for (label, value) in lines {
+        let _ = write!(banner, "\n  {:width$}  {}", label, value, width = width);
+    }
+    banner
+}
+
+fn listen_address(config: &AppConfig) -> String {
+    let scheme = if config.tls_cert_path.is_some() {
+        "https"
+    } else {
+        "http"
+    };
+    format!("{}://{}", scheme, config.server_addr())
+}
+
+fn secrets_source(secrets: &SecretsSource) -> &'static str {
+    match secrets {
+        SecretsSource::Env => "environment",
+        SecretsSource::Vault { .. } => "vault",
+        SecretsSource::AwsSecretsManager { .. } => "aws secrets manager",
+    }
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled { "on" } else { "off" }
+}
+
+/// `url` with the password of its user info masked
+pub fn redact_url(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+    let Some((user_info, host)) = authority.rsplit_once('@') else {
+        return url.to_string();
+    };
+    let user_info = match user_info.split_once(':') {
+        Some((user, _)) => format!("{}:{}", user, MASK),
+        None => user_info.to_string(),
+    };
+    format!("{}://{}@{}{}", scheme, user_info, host, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passwords_never_leave_urls() {
+        assert_eq!(
+            redact_url("postgres://app:s3cret@db:5432/flowers?sslmode=require"),
+            "postgres://app:****@db:5432/flowers?sslmode=require"
+        );
+        assert_eq!(
+            redact_url("redis://:s3cret@cache:6379"),
+            "redis://:****@cache:6379"
+        );
+        assert_eq!(
+            redact_url("postgres://localhost/flowers"),
+            "postgres://localhost/flowers"
+        );
+        assert_eq!(redact_url("memory://"), "memory://");
+    }
+
+    #[test]
+    fn the_banner_shows_no_credentials() {
+        let mut config = AppConfig::from_settings(
+            crate::infrastructure::config::Profile::Development,
+            [
+                ("DATABASE_URL", "postgres://app:s3cret@db:5432/flowers"),
+                ("ADMIN_TOKEN", "admin-s3cret"),
+                ("TENANT_API_KEYS", "key-s3cret=kiosk"),
+            ],
+        )
+        .unwrap();
+        config.sentry_dsn = Some("https://s3cret@sentry.example/1".to_string());
+
+        let banner = banner(&config);
+
+        assert!(!banner.contains("s3cret"), "{}", banner);
+        assert!(banner.contains("postgres://app:****@db:5432/flowers"));
+        assert!(banner.contains("1 API keys"));
+    }
+}
@@ -0,0 +1,22 @@
+//! Console Email Sender
+
+use async_trait::async_trait;
+
+use crate::application::ports::{EmailMessage, EmailSender};
+use crate::domain::errors::DomainResult;
+
+/// Logs emails instead of sending them, for development
+pub struct ConsoleEmailSender;
+
+#[async_trait]
+impl EmailSender for ConsoleEmailSender {
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()> {
+        tracing::info!(
+            to = %message.to.join(", "),
+            subject = %message.subject,
+            "Email not sent (console transport):\n{}",
+            message.text
+        );
+        Ok(())
+    }
+}
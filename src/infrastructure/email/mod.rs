@@ -0,0 +1,38 @@
+//! Email Delivery
+//!
+//! `EMAIL_TRANSPORT` selects how queued emails leave the process: written to
+//! the log for development, or sent through the SMTP server at `SMTP_URL`
+//! (requires the `smtp` feature).
+
+pub mod console;
+#[cfg(feature = "smtp")]
+mod smtp;
+
+use std::sync::Arc;
+
+use crate::application::ports::EmailSender;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::config::{AppConfig, EmailTransport};
+
+pub use console::ConsoleEmailSender;
+#[cfg(feature = "smtp")]
+pub use smtp::SmtpEmailSender;
+
+/// Email sender selected by configuration
+pub fn sender(config: &AppConfig) -> DomainResult<Arc<dyn EmailSender>> {
+    match config.email_transport {
+        EmailTransport::Console => Ok(Arc::new(ConsoleEmailSender)),
+        #[cfg(feature = "smtp")]
+        EmailTransport::Smtp => {
+            let url = config
+                .smtp_url
+                .as_deref()
+                .ok_or_else(|| AppError::internal("EMAIL_TRANSPORT=smtp requires SMTP_URL"))?;
+            Ok(Arc::new(SmtpEmailSender::new(url, &config.email_from)?))
+        }
+        #[cfg(not(feature = "smtp"))]
+        EmailTransport::Smtp => Err(AppError::internal(
+            "EMAIL_TRANSPORT=smtp requires building with the `smtp` feature",
+        )),
+    }
+}
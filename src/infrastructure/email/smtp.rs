@@ -0,0 +1,61 @@
+//! SMTP Email Sender
+
+use async_trait::async_trait;
+use lettre::message::{Mailbox, MultiPart, header::ContentType};
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use crate::application::ports::{EmailMessage, EmailSender};
+use crate::domain::errors::{AppError, DomainResult};
+
+/// Sends email through an SMTP relay, reusing pooled connections
+pub struct SmtpEmailSender {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpEmailSender {
+    /// `url` is an `smtp://` or `smtps://` URL with optional credentials;
+    /// `from` a mailbox such as `Shop <noreply@example.com>`
+    pub fn new(url: &str, from: &str) -> DomainResult<Self> {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::from_url(url)
+            .map_err(|e| AppError::internal(format!("Invalid SMTP_URL: {}", e)))?
+            .build();
+        let from = from
+            .parse()
+            .map_err(|e| AppError::internal(format!("Invalid EMAIL_FROM: {}", e)))?;
+
+        Ok(Self { transport, from })
+    }
+}
+
+#[async_trait]
+impl EmailSender for SmtpEmailSender {
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()> {
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(&message.subject);
+        for to in &message.to {
+            let mailbox = to
+                .parse::<Mailbox>()
+                .map_err(|e| AppError::internal(format!("Invalid recipient '{}': {}", to, e)))?;
+            builder = builder.to(mailbox);
+        }
+
+        let email = match &message.html {
+            Some(html) => builder.multipart(MultiPart::alternative_plain_html(
+                message.text.clone(),
+                html.clone(),
+            )),
+            None => builder
+                .header(ContentType::TEXT_PLAIN)
+                .body(message.text.clone()),
+        }
+        .map_err(|e| AppError::internal(format!("Failed to build email: {}", e)))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to send email: {}", e)))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+//! Error Reporting
+//!
+//! Sends server-side failures and panics to Sentry when the crate is built
+//! with the `sentry` feature and `SENTRY_DSN` is set. Without either, every
+//! function here is a no-op.
+
+use crate::domain::errors::AppError;
+use crate::infrastructure::config::AppConfig;
+
+/// Keeps the reporting client alive; flushes pending events when dropped
+pub struct ErrorReportingGuard {
+    #[cfg(feature = "sentry")]
+    _guard: Option<sentry::ClientInitGuard>,
+}
+
+/// Initialize error reporting from configuration
+pub fn init(config: &AppConfig) -> ErrorReportingGuard {
+    #[cfg(feature = "sentry")]
+    {
+        let guard = config.sentry_dsn.as_deref().map(|dsn| {
+            sentry::init((
+                dsn,
+                sentry::ClientOptions {
+                    release: sentry::release_name!(),
+                    environment: Some(config.sentry_environment.clone().into()),
+                    ..Default::default()
+                },
+            ))
+        });
+        if guard.is_some() {
+            tracing::info!("Sentry error reporting enabled");
+        }
+        ErrorReportingGuard { _guard: guard }
+    }
+
+    #[cfg(not(feature = "sentry"))]
+    {
+        if config.sentry_dsn.is_some() {
+            tracing::warn!(
+                "SENTRY_DSN is set but the binary was built without the `sentry` feature"
+            );
+        }
+        ErrorReportingGuard {}
+    }
+}
+
+/// Report server-side errors; client errors are expected and not reported
+pub fn capture(error: &AppError) {
+    #[cfg(feature = "sentry")]
+    if matches!(error, AppError::Database(_) | AppError::Internal(_)) {
+        sentry::capture_error(error);
+    }
+
+    #[cfg(not(feature = "sentry"))]
+    let _ = error;
+}
+
+/// Tag events reported during the current request with its request ID
+pub fn set_request_id(request_id: &str) {
+    #[cfg(feature = "sentry")]
+    sentry::configure_scope(|scope| scope.set_tag("request_id", request_id));
+
+    #[cfg(not(feature = "sentry"))]
+    let _ = request_id;
+}
+
+/// Report events during the current request as concerning `user`, such as
+/// `admin` or `tenant:rose-shop`
+pub fn set_user(user: &str) {
+    #[cfg(feature = "sentry")]
+    sentry::configure_scope(|scope| {
+        scope.set_user(Some(sentry::User {
+            id: Some(user.to_string()),
+            ..Default::default()
+        }))
+    });
+
+    #[cfg(not(feature = "sentry"))]
+    let _ = user;
+}
+
+/// Tag events reported during the current request with its tenant
+pub fn set_tenant(tenant: &str) {
+    #[cfg(feature = "sentry")]
+    sentry::configure_scope(|scope| scope.set_tag("tenant", tenant));
+
+    #[cfg(not(feature = "sentry"))]
+    let _ = tenant;
+}
@@ -0,0 +1,103 @@
+//! Circuit Breaker
+//!
+//! Tracks consecutive failures per host. Once a host reaches the threshold
+//! its circuit opens and calls fail fast for the cooldown; the first call
+//! after that is let through, and either closes the circuit by succeeding or
+//! reopens it by failing.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default)]
+struct Circuit {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+/// Per-host circuit breaker shared by all calls of a client
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    circuits: Mutex<HashMap<String, Circuit>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            circuits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether `host` may be called; `Err` carries how long its circuit
+    /// stays open
+    pub fn check(&self, host: &str) -> Result<(), Duration> {
+        self.check_at(host, Instant::now())
+    }
+
+    /// Record a call that reached the host and got a usable answer
+    pub fn record_success(&self, host: &str) {
+        self.circuits().remove(host);
+    }
+
+    /// Record a call that failed for reasons on the host's side
+    pub fn record_failure(&self, host: &str) {
+        self.record_failure_at(host, Instant::now());
+    }
+
+    fn check_at(&self, host: &str, now: Instant) -> Result<(), Duration> {
+        match self.circuits().get(host).and_then(|c| c.open_until) {
+            Some(open_until) if open_until > now => Err(open_until - now),
+            _ => Ok(()),
+        }
+    }
+
+    fn record_failure_at(&self, host: &str, now: Instant) {
+        let mut circuits = self.circuits();
+        let circuit = circuits.entry(host.to_string()).or_default();
+        circuit.failures = circuit.failures.saturating_add(1);
+        if circuit.failures >= self.threshold {
+            if circuit.open_until.is_none_or(|until| until <= now) {
+                tracing::warn!(
+                    host,
+                    "Circuit opened after {} consecutive failures",
+                    circuit.failures
+                );
+            }
+            circuit.open_until = Some(now + self.cooldown);
+        }
+    }
+
+    fn circuits(&self) -> std::sync::MutexGuard<'_, HashMap<String, Circuit>> {
+        self.circuits.lock().expect("circuit breaker lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_at_the_threshold_and_closes_on_success() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(30));
+        let start = Instant::now();
+
+        breaker.record_failure_at("a", start);
+        assert!(breaker.check_at("a", start).is_ok());
+        breaker.record_failure_at("a", start);
+        assert_eq!(breaker.check_at("a", start), Err(Duration::from_secs(30)));
+        assert!(breaker.check_at("b", start).is_ok());
+
+        // After the cooldown one trial call goes through; failing reopens
+        let later = start + Duration::from_secs(31);
+        assert!(breaker.check_at("a", later).is_ok());
+        breaker.record_failure_at("a", later);
+        assert!(breaker.check_at("a", later).is_err());
+
+        breaker.record_success("a");
+        assert!(breaker.check_at("a", later).is_ok());
+    }
+}
@@ -0,0 +1,172 @@
+//! HTTP Client
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::header::HeaderValue;
+use reqwest::{ClientBuilder, IntoUrl, Method, Request, RequestBuilder, Response, StatusCode};
+use thiserror::Error;
+use tracing::Instrument;
+
+use super::{CircuitBreaker, HttpClientSettings};
+use crate::application::context::current_request_id;
+use crate::domain::errors::{AppError, DomainResult};
+
+/// Header passing the ID of the request being handled on to the services we
+/// call, as our own clients send it to us
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Why an outbound call failed
+#[derive(Debug, Error)]
+pub enum HttpError {
+    #[error("{host} is failing, calls are suspended for another {retry_after:?}")]
+    CircuitOpen { host: String, retry_after: Duration },
+
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+}
+
+/// Outbound HTTP client shared by the adapters; cheap to clone
+#[derive(Clone)]
+pub struct HttpClient {
+    inner: reqwest::Client,
+    settings: HttpClientSettings,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl HttpClient {
+    pub fn new(settings: HttpClientSettings) -> DomainResult<Self> {
+        Self::with_builder(settings, |builder| builder)
+    }
+
+    /// Client whose underlying `reqwest` client is set up further by
+    /// `configure`, such as to resolve names differently
+    pub fn with_builder(
+        settings: HttpClientSettings,
+        configure: impl FnOnce(ClientBuilder) -> ClientBuilder,
+    ) -> DomainResult<Self> {
+        let inner = configure(reqwest::Client::builder().timeout(settings.timeout))
+            .build()
+            .map_err(|e| AppError::internal(format!("Failed to build HTTP client: {}", e)))?;
+
+        Ok(Self {
+            inner,
+            settings,
+            breaker: Arc::new(CircuitBreaker::new(
+                settings.breaker_threshold,
+                settings.breaker_cooldown,
+            )),
+        })
+    }
+
+    pub fn get(&self, url: impl IntoUrl) -> RequestBuilder {
+        self.inner.get(url)
+    }
+
+    pub fn post(&self, url: impl IntoUrl) -> RequestBuilder {
+        self.inner.post(url)
+    }
+
+    /// Send a request, retrying transient failures if its method is idempotent
+    ///
+    /// Transient failures are timeouts, connection errors, 429 and 5xx
+    /// responses. Once retries run out the last response is returned as is,
+    /// so callers still check its status.
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, HttpError> {
+        self.execute(request.build()?, false).await
+    }
+
+    /// Send a request that is safe to repeat although its method is not
+    /// idempotent, such as a read-only RPC over POST
+    pub async fn send_idempotent(&self, request: RequestBuilder) -> Result<Response, HttpError> {
+        self.execute(request.build()?, true).await
+    }
+
+    async fn execute(&self, mut request: Request, repeatable: bool) -> Result<Response, HttpError> {
+        if let Some(request_id) =
+            current_request_id().and_then(|id| HeaderValue::from_str(&id).ok())
+        {
+            request
+                .headers_mut()
+                .entry(REQUEST_ID_HEADER)
+                .or_insert(request_id);
+        }
+
+        let host = request.url().host_str().unwrap_or_default().to_string();
+        let attempts = if repeatable || is_idempotent(request.method()) {
+            self.settings.retries + 1
+        } else {
+            1
+        };
+        let span = tracing::debug_span!("http_client", method = %request.method(), host = %host);
+
+        async move {
+            let mut attempt = 1;
+            loop {
+                if let Err(retry_after) = self.breaker.check(&host) {
+                    record(&host, "circuit_open");
+                    return Err(HttpError::CircuitOpen { host, retry_after });
+                }
+
+                let spare = (attempt < attempts).then(|| request.try_clone()).flatten();
+                let started = Instant::now();
+                let result = self.inner.execute(request).await;
+                let transient = match &result {
+                    Ok(response) => is_transient(response.status()),
+                    Err(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+                };
+                tracing::debug!(
+                    attempt,
+                    status = result.as_ref().ok().map(|r| r.status().as_u16()),
+                    "Outbound call finished in {:?}",
+                    started.elapsed()
+                );
+
+                if !transient {
+                    self.breaker.record_success(&host);
+                    record(&host, "success");
+                    return Ok(result?);
+                }
+                self.breaker.record_failure(&host);
+
+                let Some(next) = spare else {
+                    record(&host, "failure");
+                    return Ok(result?);
+                };
+                record(&host, "retried");
+                let delay = self
+                    .settings
+                    .retry_backoff
+                    .saturating_mul(2u32.saturating_pow(attempt - 1));
+                tracing::warn!(attempt, "Transient failure, retrying in {:?}", delay);
+                tokio::time::sleep(delay).await;
+                request = next;
+                attempt += 1;
+            }
+        }
+        .instrument(span)
+        .await
+    }
+}
+
+/// Methods that can be repeated without changing the outcome
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+/// Statuses worth trying again, which also count against the circuit
+fn is_transient(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+fn record(host: &str, outcome: &'static str) {
+    metrics::counter!(
+        "http_client_requests_total",
+        "host" => host.to_string(),
+        "outcome" => outcome
+    )
+    .increment(1);
+}
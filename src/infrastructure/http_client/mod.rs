@@ -0,0 +1,45 @@
+//! Outbound HTTP
+//!
+//! Adapters calling other services share `HttpClient` (requires the
+//! `http-client` feature, enabled by the features that need it), so every
+//! outbound call gets the same timeouts, retries of idempotent requests,
+//! per-host circuit breaking, `X-Request-Id` propagation and metrics.
+//! `HTTP_CLIENT_*` settings tune it for all adapters at once.
+
+pub mod circuit_breaker;
+#[cfg(feature = "http-client")]
+mod client;
+
+use std::time::Duration;
+
+pub use circuit_breaker::CircuitBreaker;
+#[cfg(feature = "http-client")]
+pub use client::{HttpClient, HttpError};
+
+/// Behaviour of outbound HTTP calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HttpClientSettings {
+    /// Upper bound on one attempt, from connecting to reading the body
+    pub timeout: Duration,
+    /// Further attempts after a transient failure; only idempotent requests
+    /// are retried
+    pub retries: u32,
+    /// Delay before the first retry, doubled for every further one
+    pub retry_backoff: Duration,
+    /// Consecutive failures after which calls to a host fail fast
+    pub breaker_threshold: u32,
+    /// How long calls to a failing host fail fast
+    pub breaker_cooldown: Duration,
+}
+
+impl Default for HttpClientSettings {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            retries: 2,
+            retry_backoff: Duration::from_millis(200),
+            breaker_threshold: 5,
+            breaker_cooldown: Duration::from_secs(30),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Code 128 Barcodes
+//!
+//! Only code set B is used: it covers all printable ASCII, which is all a
+//! SKU may contain. Each symbol is three bars and three spaces, eleven
+//! modules wide in total; the stop symbol adds a final two-module bar.
+
+/// Bar and space widths of symbol values 0 to 106, in modules
+const PATTERNS: [&[u8]; 107] = [
+    b"212222", b"222122", b"222221", b"121223", b"121322", b"131222", b"122213", b"122312",
+    b"132212", b"221213", b"221312", b"231212", b"112232", b"122132", b"122231", b"113222",
+    b"123122", b"123221", b"223211", b"221132", b"221231", b"213212", b"223112", b"312131",
+    b"311222", b"321122", b"321221", b"312212", b"322112", b"322211", b"212123", b"212321",
+    b"232121", b"111323", b"131123", b"131321", b"112313", b"132113", b"132311", b"211313",
+    b"231113", b"231311", b"112133", b"112331", b"132131", b"113123", b"113321", b"133121",
+    b"313121", b"211331", b"231131", b"213113", b"213311", b"213131", b"311123", b"311321",
+    b"331121", b"312113", b"312311", b"332111", b"314111", b"221411", b"431111", b"111224",
+    b"111422", b"121124", b"121421", b"141122", b"141221", b"112214", b"112412", b"122114",
+    b"122411", b"142112", b"142211", b"241211", b"221114", b"413111", b"241112", b"134111",
+    b"111242", b"121142", b"121241", b"114212", b"124112", b"124211", b"411212", b"421112",
+    b"421211", b"212141", b"214121", b"412121", b"111143", b"111341", b"131141", b"114113",
+    b"114311", b"411113", b"411311", b"113141", b"114131", b"311141", b"411131", b"211412",
+    b"211214", b"211232", b"2331112",
+];
+
+const START_B: usize = 104;
+const STOP: usize = 106;
+
+/// Modules of the barcode for `data`, `true` for a bar; `None` when `data`
+/// is not printable ASCII
+pub fn encode(data: &str) -> Option<Vec<bool>> {
+    let values = data
+        .bytes()
+        .map(|byte| {
+            (b' '..=b'~')
+                .contains(&byte)
+                .then(|| usize::from(byte - b' '))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // The check symbol weighs each value by its position, the start symbol by one
+    let checksum = values
+        .iter()
+        .enumerate()
+        .fold(START_B, |sum, (i, value)| sum + (i + 1) * value)
+        % 103;
+
+    let mut modules = Vec::with_capacity(11 * (values.len() + 3) + 2);
+    for symbol in std::iter::once(START_B)
+        .chain(values)
+        .chain([checksum, STOP])
+    {
+        for (i, width) in PATTERNS[symbol].iter().enumerate() {
+            let bar = i % 2 == 0;
+            modules.extend(std::iter::repeat_n(bar, usize::from(width - b'0')));
+        }
+    }
+    Some(modules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbols_have_eleven_modules() {
+        for pattern in &PATTERNS[..STOP] {
+            let modules: u32 = pattern.iter().map(|w| u32::from(w - b'0')).sum();
+            assert_eq!(modules, 11);
+        }
+    }
+
+    #[test]
+    fn encodes_start_data_check_and_stop() {
+        let modules = encode("RO-12").unwrap();
+        assert_eq!(modules.len(), 11 * (5 + 3) + 2);
+        // Start B is 211214, the stop symbol ends on a double bar
+        assert_eq!(&modules[..6], &[true, true, false, true, false, false]);
+        assert_eq!(&modules[modules.len() - 3..], &[false, true, true]);
+
+        assert!(encode("RÖ-12").is_none());
+    }
+}
@@ -0,0 +1,54 @@
+//! In-memory implementation of DeliveryZoneRepository
+
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::DeliveryZoneRepository;
+use crate::domain::delivery::DeliveryZone;
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Delivery zones held in process memory
+#[derive(Default)]
+pub struct InMemoryDeliveryZoneRepository {
+    zones: RwLock<Vec<DeliveryZone>>,
+}
+
+impl InMemoryDeliveryZoneRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DeliveryZoneRepository for InMemoryDeliveryZoneRepository {
+    async fn create(&self, zone: &DeliveryZone) -> DomainResult<DeliveryZone> {
+        self.zones
+            .write()
+            .expect("delivery zone lock poisoned")
+            .push(zone.clone());
+        Ok(zone.clone())
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<DeliveryZone>> {
+        let mut zones: Vec<DeliveryZone> = self
+            .zones
+            .read()
+            .expect("delivery zone lock poisoned")
+            .iter()
+            .filter(|zone| zone.tenant_id() == tenant)
+            .cloned()
+            .collect();
+        zones.sort_by(|a, b| a.name().cmp(b.name()).then(a.id().cmp(&b.id())));
+        Ok(zones)
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let mut zones = self.zones.write().expect("delivery zone lock poisoned");
+        let before = zones.len();
+        zones.retain(|zone| !(zone.tenant_id() == tenant && zone.id() == id));
+        Ok(zones.len() < before)
+    }
+}
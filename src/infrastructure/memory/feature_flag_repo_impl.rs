@@ -0,0 +1,52 @@
+//! In-memory implementation of FeatureFlagRepository
+
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::application::ports::FeatureFlagRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::feature_flag::FeatureFlag;
+
+/// FeatureFlagRepository backed by a `BTreeMap` keyed by flag key
+#[derive(Default)]
+pub struct InMemoryFeatureFlagRepository {
+    flags: RwLock<BTreeMap<String, FeatureFlag>>,
+}
+
+impl InMemoryFeatureFlagRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl FeatureFlagRepository for InMemoryFeatureFlagRepository {
+    async fn find_all(&self) -> DomainResult<Vec<FeatureFlag>> {
+        let flags = self.flags.read().expect("feature flag store lock poisoned");
+        Ok(flags.values().cloned().collect())
+    }
+
+    async fn upsert(&self, flag: &FeatureFlag) -> DomainResult<FeatureFlag> {
+        let mut flags = self
+            .flags
+            .write()
+            .expect("feature flag store lock poisoned");
+        // Like the Postgres upsert, keep the previous description when none is given
+        let description = flag.description().map(String::from).or_else(|| {
+            flags
+                .get(flag.key())
+                .and_then(|previous| previous.description().map(String::from))
+        });
+        let stored = FeatureFlag::from_persistence(
+            flag.key().to_string(),
+            flag.enabled(),
+            description,
+            flag.updated_at(),
+        );
+
+        flags.insert(stored.key().to_string(), stored.clone());
+        Ok(stored)
+    }
+}
@@ -0,0 +1,69 @@
+//! In-memory flower history
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::FlowerHistory;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::FlowerChange;
+use crate::domain::shared::{Pagination, TenantId};
+
+/// Flower changes held in process memory, in the order they were recorded
+#[derive(Default)]
+pub struct InMemoryFlowerHistory {
+    changes: Mutex<Vec<FlowerChange>>,
+}
+
+impl InMemoryFlowerHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, changes: &[FlowerChange]) {
+        self.changes
+            .lock()
+            .expect("flower history lock poisoned")
+            .extend_from_slice(changes);
+    }
+
+    fn of_flower(&self, tenant: &TenantId, flower_id: Uuid) -> Vec<FlowerChange> {
+        self.changes
+            .lock()
+            .expect("flower history lock poisoned")
+            .iter()
+            .filter(|change| change.tenant_id() == tenant && change.flower_id() == flower_id)
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl FlowerHistory for InMemoryFlowerHistory {
+    async fn find(
+        &self,
+        tenant: &TenantId,
+        flower_id: Uuid,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<FlowerChange>> {
+        let mut changes = self.of_flower(tenant, flower_id);
+        changes.sort_by_key(|change| (change.changed_at(), change.id()));
+        Ok(changes
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .collect())
+    }
+
+    async fn count(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<i64> {
+        Ok(self.of_flower(tenant, flower_id).len() as i64)
+    }
+
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64> {
+        let mut changes = self.changes.lock().expect("flower history lock poisoned");
+        let before = changes.len();
+        changes.retain(|change| !(change.tenant_id() == tenant && change.flower_id() == flower_id));
+        Ok((before - changes.len()) as u64)
+    }
+}
@@ -0,0 +1,298 @@
+//! In-memory implementation of FlowerRepository
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::{FlowerFacets, FlowerRepository};
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerAttributes, FlowerColor, FlowerError, FlowerMetadata};
+use crate::domain::shared::{Entity, Pagination, TenantId};
+
+/// FlowerRepository backed by a `HashMap`, mirroring the Postgres semantics:
+/// tenant scoping, case-insensitive unique names, unique SKUs and
+/// newest-first listings
+#[derive(Default)]
+pub struct InMemoryFlowerRepository {
+    flowers: RwLock<HashMap<Uuid, Flower>>,
+}
+
+impl InMemoryFlowerRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flowers of a tenant matching a filter, newest first
+    pub(crate) fn filtered(
+        &self,
+        tenant: &TenantId,
+        filter: impl Fn(&Flower) -> bool,
+    ) -> Vec<Flower> {
+        let flowers = self.flowers.read().expect("flower store lock poisoned");
+        let mut matching: Vec<Flower> = flowers
+            .values()
+            .filter(|flower| flower.tenant_id() == tenant && filter(flower))
+            .cloned()
+            .collect();
+        matching.sort_by_key(|flower| std::cmp::Reverse((flower.created_at(), flower.id())));
+        matching
+    }
+
+    fn page(flowers: Vec<Flower>, pagination: &Pagination) -> Vec<Flower> {
+        flowers
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .collect()
+    }
+
+    fn search_filter<'a>(
+        query: Option<&'a str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&'a FlowerAttributes>,
+        metadata: Option<&'a FlowerMetadata>,
+    ) -> impl Fn(&Flower) -> bool + 'a {
+        let query = query.map(str::to_lowercase);
+        move |flower| {
+            query
+                .as_deref()
+                .is_none_or(|q| flower.name().to_lowercase().contains(q))
+                && color.is_none_or(|c| flower.color() == c)
+                && attributes.is_none_or(|filter| flower.attributes().contains(filter))
+                && metadata.is_none_or(|filter| flower.metadata().contains(filter))
+        }
+    }
+
+    /// Insert or replace a flower, enforcing unique names and SKUs within its
+    /// tenant
+    fn store(&self, flower: &Flower) -> DomainResult<Flower> {
+        let mut flowers = self.flowers.write().expect("flower store lock poisoned");
+        let name = flower.name().to_lowercase();
+        let mut others = flowers
+            .values()
+            .filter(|other| other.id() != flower.id() && other.tenant_id() == flower.tenant_id());
+        if let Some(existing) = others
+            .clone()
+            .find(|other| other.name().to_lowercase() == name)
+        {
+            return Err(FlowerError::name_taken(flower.name(), Some(existing.id())));
+        }
+        if let Some(sku) = flower.sku()
+            && let Some(existing) = others.find(|other| other.sku() == Some(sku))
+        {
+            return Err(FlowerError::sku_taken(sku, Some(existing.id())));
+        }
+
+        flowers.insert(flower.id(), flower.clone());
+        Ok(flower.clone())
+    }
+}
+
+#[async_trait]
+impl FlowerRepository for InMemoryFlowerRepository {
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        let flowers = self.flowers.read().expect("flower store lock poisoned");
+        Ok(flowers
+            .get(&id)
+            .filter(|flower| flower.tenant_id() == tenant)
+            .cloned())
+    }
+
+    async fn find_by_sku(&self, tenant: &TenantId, sku: &str) -> DomainResult<Option<Flower>> {
+        Ok(self
+            .filtered(tenant, |flower| flower.sku() == Some(sku))
+            .into_iter()
+            .next())
+    }
+
+    async fn find_all(
+        &self,
+        tenant: &TenantId,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>> {
+        Ok(Self::page(self.filtered(tenant, |_| true), pagination))
+    }
+
+    async fn count(&self, tenant: &TenantId) -> DomainResult<i64> {
+        Ok(self.filtered(tenant, |_| true).len() as i64)
+    }
+
+    async fn search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>> {
+        let matching = self.filtered(
+            tenant,
+            Self::search_filter(query, color, attributes, metadata),
+        );
+        Ok(Self::page(matching, pagination))
+    }
+
+    async fn count_search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<i64> {
+        Ok(self
+            .filtered(
+                tenant,
+                Self::search_filter(query, color, attributes, metadata),
+            )
+            .len() as i64)
+    }
+
+    async fn facets(&self, tenant: &TenantId) -> DomainResult<FlowerFacets> {
+        let flowers = self.filtered(tenant, |_| true);
+        let mut colors: Vec<FlowerColor> = flowers.iter().map(|flower| flower.color()).collect();
+        colors.sort_by_key(|color| color.as_str());
+        colors.dedup();
+        let prices = flowers.iter().map(|flower| flower.price());
+
+        Ok(FlowerFacets {
+            colors,
+            min_price: prices.clone().reduce(f64::min),
+            max_price: prices.reduce(f64::max),
+        })
+    }
+
+    async fn create(&self, flower: &Flower) -> DomainResult<Flower> {
+        self.store(flower)
+    }
+
+    async fn update(&self, flower: &Flower) -> DomainResult<Flower> {
+        if self
+            .find_by_id(flower.tenant_id(), flower.id())
+            .await?
+            .is_none()
+        {
+            return Err(FlowerError::not_found(flower.id()));
+        }
+        self.store(flower)
+    }
+
+    async fn find_low_stock(&self, threshold: i32) -> DomainResult<Vec<Flower>> {
+        let flowers = self.flowers.read().expect("flower store lock poisoned");
+        let mut low: Vec<Flower> = flowers
+            .values()
+            .filter(|flower| flower.stock() <= threshold)
+            .cloned()
+            .collect();
+        low.sort_by(|a, b| {
+            (a.tenant_id(), a.stock(), a.name()).cmp(&(b.tenant_id(), b.stock(), b.name()))
+        });
+        Ok(low)
+    }
+
+    async fn find_created_since(
+        &self,
+        tenant: &TenantId,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<Flower>> {
+        let mut created = self.filtered(tenant, |flower| flower.created_at() > since);
+        created.sort_by_key(|flower| (flower.created_at(), flower.id()));
+        Ok(created)
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
+        let mut flowers = self.flowers.write().expect("flower store lock poisoned");
+        if flowers
+            .get(&id)
+            .is_some_and(|flower| flower.tenant_id() == tenant)
+        {
+            flowers.remove(&id);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::flower::Sku;
+    use crate::test_support::FlowerBuilder;
+
+    fn flower(tenant: &str, name: &str) -> Flower {
+        FlowerBuilder::new()
+            .with_tenant(tenant)
+            .with_name(name)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn flowers_are_isolated_per_tenant() {
+        let repository = InMemoryFlowerRepository::new();
+        let rose = repository.create(&flower("shop-a", "Rose")).await.unwrap();
+        let shop_b: TenantId = "shop-b".parse().unwrap();
+
+        assert!(
+            repository
+                .find_by_id(&shop_b, rose.id())
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(repository.count(&shop_b).await.unwrap(), 0);
+        repository.create(&flower("shop-b", "Rose")).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn names_are_unique_ignoring_case() {
+        let repository = InMemoryFlowerRepository::new();
+        repository.create(&flower("shop-a", "Rose")).await.unwrap();
+
+        assert!(repository.create(&flower("shop-a", "ROSE")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn skus_are_unique_per_tenant() {
+        let sku = || Sku::new("RO-12").unwrap();
+        let mut rose = flower("shop-a", "Rose");
+        rose.update_sku(sku());
+
+        let repository = InMemoryFlowerRepository::new();
+        repository.create(&rose).await.unwrap();
+        let mut tulip = flower("shop-a", "Tulip");
+        tulip.update_sku(sku());
+        assert!(repository.create(&tulip).await.is_err());
+
+        let mut other = flower("shop-b", "Tulip");
+        other.update_sku(sku());
+        repository.create(&other).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn search_matches_name_substrings() {
+        let repository = InMemoryFlowerRepository::new();
+        repository
+            .create(&flower("shop-a", "Red Rose"))
+            .await
+            .unwrap();
+        repository.create(&flower("shop-a", "Tulip")).await.unwrap();
+        let tenant: TenantId = "shop-a".parse().unwrap();
+
+        let found = repository
+            .search(
+                &tenant,
+                Some("rose"),
+                None,
+                None,
+                None,
+                &Pagination::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name(), "Red Rose");
+    }
+}
@@ -0,0 +1,110 @@
+//! In-memory implementation of FlowerViewStore
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::{FlowerViewStore, RecentView, ViewCount};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Flowers each viewer of a tenant viewed last, newest first
+type Recent = HashMap<(TenantId, String), Vec<(Uuid, DateTime<Utc>)>>;
+
+/// Daily view totals and recent views held in process memory
+///
+/// Unlike the database stores it does not know which flowers still exist, so
+/// deleted flowers keep their place until callers skip them.
+#[derive(Default)]
+pub struct InMemoryFlowerViewStore {
+    views: Mutex<HashMap<(TenantId, Uuid, NaiveDate), i64>>,
+    recent: Mutex<Recent>,
+}
+
+impl InMemoryFlowerViewStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl FlowerViewStore for InMemoryFlowerViewStore {
+    async fn add(&self, counts: &[ViewCount]) -> DomainResult<()> {
+        let mut views = self.views.lock().expect("view store lock poisoned");
+        for count in counts {
+            *views
+                .entry((count.tenant.clone(), count.flower_id, count.day))
+                .or_default() += count.views;
+        }
+        Ok(())
+    }
+
+    async fn most_viewed(
+        &self,
+        tenant: &TenantId,
+        since: NaiveDate,
+        limit: i64,
+    ) -> DomainResult<Vec<(Uuid, i64)>> {
+        let views = self.views.lock().expect("view store lock poisoned");
+        let mut totals: HashMap<Uuid, i64> = HashMap::new();
+        for ((view_tenant, flower_id, day), count) in views.iter() {
+            if view_tenant == tenant && *day >= since {
+                *totals.entry(*flower_id).or_default() += count;
+            }
+        }
+
+        let mut totals: Vec<(Uuid, i64)> = totals.into_iter().collect();
+        totals.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        totals.truncate(limit.max(0) as usize);
+        Ok(totals)
+    }
+
+    async fn add_recent(&self, views: &[RecentView], keep: i64) -> DomainResult<()> {
+        let mut recent = self.recent.lock().expect("view store lock poisoned");
+        for view in views {
+            let viewed = recent
+                .entry((view.tenant.clone(), view.viewer.clone()))
+                .or_default();
+            match viewed.iter_mut().find(|(id, _)| *id == view.flower_id) {
+                Some((_, at)) => *at = (*at).max(view.viewed_at),
+                None => viewed.push((view.flower_id, view.viewed_at)),
+            }
+            viewed.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            viewed.truncate(keep.max(0) as usize);
+        }
+        Ok(())
+    }
+
+    async fn recently_viewed(
+        &self,
+        tenant: &TenantId,
+        viewer: &str,
+        limit: i64,
+    ) -> DomainResult<Vec<(Uuid, DateTime<Utc>)>> {
+        let recent = self.recent.lock().expect("view store lock poisoned");
+        let mut viewed = recent
+            .get(&(tenant.clone(), viewer.to_string()))
+            .cloned()
+            .unwrap_or_default();
+        viewed.truncate(limit.max(0) as usize);
+        Ok(viewed)
+    }
+
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64> {
+        let mut recent = self.recent.lock().expect("view store lock poisoned");
+        for ((view_tenant, _), viewed) in recent.iter_mut() {
+            if view_tenant == tenant {
+                viewed.retain(|(id, _)| *id != flower_id);
+            }
+        }
+        drop(recent);
+
+        let mut views = self.views.lock().expect("view store lock poisoned");
+        let before = views.len();
+        views.retain(|(view_tenant, id, _), _| !(view_tenant == tenant && *id == flower_id));
+        Ok((before - views.len()) as u64)
+    }
+}
@@ -0,0 +1,38 @@
+//! In-memory Adapters
+//!
+//! Implementations of the persistence ports that keep everything in process
+//! memory, selected with `DATABASE_URL=memory://`. They let the API and its
+//! tests run without Postgres; data is lost on restart, so they are refused
+//! in production.
+
+pub mod delivery_zone_repo_impl;
+pub mod feature_flag_repo_impl;
+pub mod flower_history_impl;
+pub mod flower_repo_impl;
+pub mod flower_view_store_impl;
+pub mod nonce_store_impl;
+pub mod order_repo_impl;
+pub mod pricing_rule_repo_impl;
+pub mod saved_search_repo_impl;
+pub mod stock_ledger_impl;
+pub mod store_repo_impl;
+pub mod task_queue_impl;
+pub mod unit_of_work_impl;
+pub mod usage_store_impl;
+pub mod webhook_endpoint_repo_impl;
+
+pub use delivery_zone_repo_impl::InMemoryDeliveryZoneRepository;
+pub use feature_flag_repo_impl::InMemoryFeatureFlagRepository;
+pub use flower_history_impl::InMemoryFlowerHistory;
+pub use flower_repo_impl::InMemoryFlowerRepository;
+pub use flower_view_store_impl::InMemoryFlowerViewStore;
+pub use nonce_store_impl::InMemoryNonceStore;
+pub use order_repo_impl::InMemoryOrderRepository;
+pub use pricing_rule_repo_impl::InMemoryPricingRuleRepository;
+pub use saved_search_repo_impl::InMemorySavedSearchRepository;
+pub use stock_ledger_impl::InMemoryStockLedger;
+pub use store_repo_impl::InMemoryStoreRepository;
+pub use task_queue_impl::InMemoryTaskQueue;
+pub use unit_of_work_impl::InMemoryUnitOfWork;
+pub use usage_store_impl::InMemoryUsageStore;
+pub use webhook_endpoint_repo_impl::InMemoryWebhookEndpointRepository;
@@ -0,0 +1,47 @@
+//! In-memory implementation of NonceStore
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::application::ports::NonceStore;
+use crate::domain::errors::DomainResult;
+
+/// Webhook nonces held in process memory
+#[derive(Default)]
+pub struct InMemoryNonceStore {
+    nonces: Mutex<HashMap<(String, String), DateTime<Utc>>>,
+}
+
+impl InMemoryNonceStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl NonceStore for InMemoryNonceStore {
+    async fn claim(
+        &self,
+        scope: &str,
+        nonce: &str,
+        expires_at: DateTime<Utc>,
+    ) -> DomainResult<bool> {
+        let mut nonces = self.nonces.lock().expect("nonce store lock poisoned");
+        let key = (scope.to_string(), nonce.to_string());
+        if nonces.get(&key).is_some_and(|expiry| *expiry > Utc::now()) {
+            return Ok(false);
+        }
+        nonces.insert(key, expires_at);
+        Ok(true)
+    }
+
+    async fn purge_expired(&self, now: DateTime<Utc>) -> DomainResult<u64> {
+        let mut nonces = self.nonces.lock().expect("nonce store lock poisoned");
+        let before = nonces.len();
+        nonces.retain(|_, expires_at| *expires_at > now);
+        Ok((before - nonces.len()) as u64)
+    }
+}
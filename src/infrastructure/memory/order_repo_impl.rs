@@ -0,0 +1,65 @@
+//! In-memory implementation of OrderRepository
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::OrderRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::order::{Order, OrderEvent};
+use crate::domain::shared::{Entity, TenantId};
+
+/// Orders and their events held in process memory; written by
+/// `InMemoryUnitOfWork` on commit
+#[derive(Default)]
+pub struct InMemoryOrderRepository {
+    orders: RwLock<HashMap<Uuid, Order>>,
+    events: RwLock<Vec<OrderEvent>>,
+}
+
+impl InMemoryOrderRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert or replace an order
+    pub fn save(&self, order: &Order) {
+        self.orders
+            .write()
+            .expect("order store lock poisoned")
+            .insert(order.id(), order.clone());
+    }
+
+    pub fn record(&self, events: &[OrderEvent]) {
+        self.events
+            .write()
+            .expect("order store lock poisoned")
+            .extend_from_slice(events);
+    }
+}
+
+#[async_trait]
+impl OrderRepository for InMemoryOrderRepository {
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Order>> {
+        Ok(self
+            .orders
+            .read()
+            .expect("order store lock poisoned")
+            .get(&id)
+            .filter(|order| order.tenant_id() == tenant)
+            .cloned())
+    }
+
+    async fn events(&self, tenant: &TenantId, order_id: Uuid) -> DomainResult<Vec<OrderEvent>> {
+        Ok(self
+            .events
+            .read()
+            .expect("order store lock poisoned")
+            .iter()
+            .filter(|event| event.order_id() == order_id && event.tenant_id() == tenant)
+            .cloned()
+            .collect())
+    }
+}
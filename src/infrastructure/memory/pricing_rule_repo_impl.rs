@@ -0,0 +1,78 @@
+//! In-memory implementation of PricingRuleRepository
+
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::PricingRuleRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::pricing::PricingRule;
+use crate::domain::shared::{Entity, TenantId};
+
+/// Pricing rules held in process memory
+#[derive(Default)]
+pub struct InMemoryPricingRuleRepository {
+    rules: RwLock<Vec<PricingRule>>,
+}
+
+impl InMemoryPricingRuleRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PricingRuleRepository for InMemoryPricingRuleRepository {
+    async fn create(&self, rule: &PricingRule) -> DomainResult<PricingRule> {
+        self.rules
+            .write()
+            .expect("pricing rule lock poisoned")
+            .push(rule.clone());
+        Ok(rule.clone())
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<PricingRule>> {
+        let mut rules: Vec<PricingRule> = self
+            .rules
+            .read()
+            .expect("pricing rule lock poisoned")
+            .iter()
+            .filter(|rule| rule.tenant_id() == tenant)
+            .cloned()
+            .collect();
+        rules.sort_by(|a, b| a.name().cmp(b.name()).then(a.id().cmp(&b.id())));
+        Ok(rules)
+    }
+
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<PricingRule>> {
+        Ok(self
+            .rules
+            .read()
+            .expect("pricing rule lock poisoned")
+            .iter()
+            .find(|rule| rule.tenant_id() == tenant && rule.id() == id)
+            .cloned())
+    }
+
+    async fn update(&self, rule: &PricingRule) -> DomainResult<bool> {
+        let mut rules = self.rules.write().expect("pricing rule lock poisoned");
+        match rules
+            .iter_mut()
+            .find(|stored| stored.tenant_id() == rule.tenant_id() && stored.id() == rule.id())
+        {
+            Some(stored) => {
+                *stored = rule.clone();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let mut rules = self.rules.write().expect("pricing rule lock poisoned");
+        let before = rules.len();
+        rules.retain(|rule| !(rule.tenant_id() == tenant && rule.id() == id));
+        Ok(rules.len() < before)
+    }
+}
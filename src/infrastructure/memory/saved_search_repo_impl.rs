@@ -0,0 +1,82 @@
+//! In-memory implementation of SavedSearchRepository
+
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::SavedSearchRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::saved_search::SavedSearch;
+use crate::domain::shared::TenantId;
+
+/// Saved searches held in process memory, in the order they were saved
+#[derive(Default)]
+pub struct InMemorySavedSearchRepository {
+    searches: RwLock<Vec<SavedSearch>>,
+}
+
+impl InMemorySavedSearchRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn filtered(&self, filter: impl Fn(&SavedSearch) -> bool) -> Vec<SavedSearch> {
+        self.searches
+            .read()
+            .expect("saved search store lock poisoned")
+            .iter()
+            .filter(|search| filter(search))
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl SavedSearchRepository for InMemorySavedSearchRepository {
+    async fn create(&self, search: &SavedSearch) -> DomainResult<SavedSearch> {
+        self.searches
+            .write()
+            .expect("saved search store lock poisoned")
+            .push(search.clone());
+        Ok(search.clone())
+    }
+
+    async fn find_by_owner(
+        &self,
+        tenant: &TenantId,
+        owner: &str,
+    ) -> DomainResult<Vec<SavedSearch>> {
+        Ok(self.filtered(|search| search.tenant_id() == tenant && search.owner() == owner))
+    }
+
+    async fn find_alerting(&self) -> DomainResult<Vec<SavedSearch>> {
+        let mut alerting = self.filtered(|search| search.alert_email().is_some());
+        alerting.sort_by(|a, b| a.tenant_id().cmp(b.tenant_id()));
+        Ok(alerting)
+    }
+
+    async fn mark_checked(&self, id: Uuid, at: DateTime<Utc>) -> DomainResult<()> {
+        let mut searches = self
+            .searches
+            .write()
+            .expect("saved search store lock poisoned");
+        if let Some(search) = searches.iter_mut().find(|search| search.id() == id) {
+            search.mark_checked(at);
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, tenant: &TenantId, owner: &str, id: Uuid) -> DomainResult<bool> {
+        let mut searches = self
+            .searches
+            .write()
+            .expect("saved search store lock poisoned");
+        let before = searches.len();
+        searches.retain(|search| {
+            !(search.tenant_id() == tenant && search.owner() == owner && search.id() == id)
+        });
+        Ok(searches.len() < before)
+    }
+}
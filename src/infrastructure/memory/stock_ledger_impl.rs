@@ -0,0 +1,71 @@
+//! In-memory inventory ledger
+
+use std::cmp::Reverse;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::{LedgerQuery, StockLedger};
+use crate::domain::errors::DomainResult;
+use crate::domain::inventory::StockMovement;
+use crate::domain::shared::{Pagination, TenantId};
+
+/// Stock movements held in process memory, in the order they were recorded
+#[derive(Default)]
+pub struct InMemoryStockLedger {
+    movements: Mutex<Vec<StockMovement>>,
+}
+
+impl InMemoryStockLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, movement: &StockMovement) {
+        self.movements
+            .lock()
+            .expect("stock ledger lock poisoned")
+            .push(movement.clone());
+    }
+
+    fn matching(&self, query: &LedgerQuery) -> Vec<StockMovement> {
+        self.movements
+            .lock()
+            .expect("stock ledger lock poisoned")
+            .iter()
+            .filter(|movement| query.matches(movement))
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl StockLedger for InMemoryStockLedger {
+    async fn find(
+        &self,
+        query: &LedgerQuery,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<StockMovement>> {
+        let mut movements = self.matching(query);
+        movements.sort_by_key(|movement| Reverse((movement.created_at(), movement.id())));
+        Ok(movements
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .collect())
+    }
+
+    async fn count(&self, query: &LedgerQuery) -> DomainResult<i64> {
+        Ok(self.matching(query).len() as i64)
+    }
+
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64> {
+        let mut movements = self.movements.lock().expect("stock ledger lock poisoned");
+        let before = movements.len();
+        movements.retain(|movement| {
+            !(movement.tenant_id() == tenant && movement.flower_id() == flower_id)
+        });
+        Ok((before - movements.len()) as u64)
+    }
+}
@@ -0,0 +1,107 @@
+//! In-memory implementation of StoreRepository
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::StoreRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::domain::store::Store;
+
+/// Stores and their stock held in process memory
+#[derive(Default)]
+pub struct InMemoryStoreRepository {
+    stores: RwLock<Vec<Store>>,
+    /// Quantity held per store and flower
+    stock: RwLock<HashMap<(Uuid, Uuid), i32>>,
+}
+
+impl InMemoryStoreRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn of_tenant(&self, tenant: &TenantId) -> Vec<Store> {
+        let mut stores: Vec<Store> = self
+            .stores
+            .read()
+            .expect("store lock poisoned")
+            .iter()
+            .filter(|store| store.tenant_id() == tenant)
+            .cloned()
+            .collect();
+        stores.sort_by(|a, b| a.name().cmp(b.name()).then(a.id().cmp(&b.id())));
+        stores
+    }
+}
+
+#[async_trait]
+impl StoreRepository for InMemoryStoreRepository {
+    async fn create(&self, store: &Store) -> DomainResult<Store> {
+        self.stores
+            .write()
+            .expect("store lock poisoned")
+            .push(store.clone());
+        Ok(store.clone())
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<Store>> {
+        Ok(self.of_tenant(tenant))
+    }
+
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Store>> {
+        Ok(self
+            .of_tenant(tenant)
+            .into_iter()
+            .find(|store| store.id() == id))
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let mut stores = self.stores.write().expect("store lock poisoned");
+        let before = stores.len();
+        stores.retain(|store| !(store.tenant_id() == tenant && store.id() == id));
+        let deleted = stores.len() < before;
+        if deleted {
+            self.stock
+                .write()
+                .expect("store lock poisoned")
+                .retain(|(store_id, _), _| *store_id != id);
+        }
+        Ok(deleted)
+    }
+
+    async fn set_stock(
+        &self,
+        _tenant: &TenantId,
+        store_id: Uuid,
+        flower_id: Uuid,
+        quantity: i32,
+    ) -> DomainResult<()> {
+        let mut stock = self.stock.write().expect("store lock poisoned");
+        if quantity == 0 {
+            stock.remove(&(store_id, flower_id));
+        } else {
+            stock.insert((store_id, flower_id), quantity);
+        }
+        Ok(())
+    }
+
+    async fn stock_of(
+        &self,
+        tenant: &TenantId,
+        flower_id: Uuid,
+    ) -> DomainResult<Vec<(Store, i32)>> {
+        let stock = self.stock.read().expect("store lock poisoned");
+        Ok(self
+            .of_tenant(tenant)
+            .into_iter()
+            .filter_map(|store| {
+                let quantity = stock.get(&(store.id(), flower_id)).copied()?;
+                Some((store, quantity))
+            })
+            .collect())
+    }
+}
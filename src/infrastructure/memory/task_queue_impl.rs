@@ -0,0 +1,144 @@
+//! In-memory implementation of TaskQueue
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::TaskQueue;
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::Pagination;
+use crate::domain::task::{FailedTask, Task};
+
+#[derive(Default)]
+struct Queue {
+    /// Pending tasks with the end of their lease, if claimed
+    pending: HashMap<Uuid, (Task, Option<DateTime<Utc>>)>,
+    /// Dead-lettered tasks in the order they failed
+    failed: Vec<FailedTask>,
+    /// Dead-lettered tasks moved out by retention
+    archived: Vec<FailedTask>,
+}
+
+/// TaskQueue held in process memory; pending tasks are lost on restart
+#[derive(Default)]
+pub struct InMemoryTaskQueue {
+    queue: Mutex<Queue>,
+}
+
+impl InMemoryTaskQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Queue> {
+        self.queue.lock().expect("task queue lock poisoned")
+    }
+}
+
+#[async_trait]
+impl TaskQueue for InMemoryTaskQueue {
+    async fn enqueue(&self, task: &Task) -> DomainResult<()> {
+        self.lock().pending.insert(task.id(), (task.clone(), None));
+        Ok(())
+    }
+
+    async fn claim(&self, lease: Duration) -> DomainResult<Option<Task>> {
+        let now = Utc::now();
+        let mut queue = self.lock();
+        let due = queue
+            .pending
+            .values_mut()
+            .filter(|(task, locked_until)| {
+                task.run_at() <= now && locked_until.is_none_or(|until| until < now)
+            })
+            .min_by_key(|(task, _)| task.run_at());
+
+        let Some((task, locked_until)) = due else {
+            return Ok(None);
+        };
+        *task = Task::from_persistence(
+            task.id(),
+            task.kind().to_string(),
+            task.payload().clone(),
+            task.attempts() + 1,
+            task.max_attempts(),
+            task.run_at(),
+            task.last_error().map(String::from),
+            task.created_at(),
+        );
+        *locked_until = Some(now + chrono::Duration::from_std(lease).unwrap_or_default());
+        Ok(Some(task.clone()))
+    }
+
+    async fn complete(&self, id: Uuid) -> DomainResult<()> {
+        self.lock().pending.remove(&id);
+        Ok(())
+    }
+
+    async fn retry(&self, id: Uuid, error: &str, run_at: DateTime<Utc>) -> DomainResult<()> {
+        if let Some((task, locked_until)) = self.lock().pending.get_mut(&id) {
+            *task = Task::from_persistence(
+                task.id(),
+                task.kind().to_string(),
+                task.payload().clone(),
+                task.attempts(),
+                task.max_attempts(),
+                run_at,
+                Some(error.to_string()),
+                task.created_at(),
+            );
+            *locked_until = None;
+        }
+        Ok(())
+    }
+
+    async fn dead_letter(&self, id: Uuid, error: &str) -> DomainResult<()> {
+        let mut queue = self.lock();
+        if let Some((task, _)) = queue.pending.remove(&id) {
+            queue.failed.push(FailedTask::from_persistence(
+                task.id(),
+                task.kind().to_string(),
+                task.payload().clone(),
+                task.attempts(),
+                error.to_string(),
+                task.created_at(),
+                Utc::now(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn find_failed(&self, pagination: &Pagination) -> DomainResult<Vec<FailedTask>> {
+        Ok(self
+            .lock()
+            .failed
+            .iter()
+            .rev()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn count_failed(&self) -> DomainResult<i64> {
+        Ok(self.lock().failed.len() as i64)
+    }
+
+    async fn purge_failed(&self, failed_before: DateTime<Utc>, archive: bool) -> DomainResult<u64> {
+        let mut queue = self.lock();
+        let (expired, kept) = std::mem::take(&mut queue.failed)
+            .into_iter()
+            .partition::<Vec<_>, _>(|task| task.failed_at() < failed_before);
+        queue.failed = kept;
+
+        let purged = expired.len() as u64;
+        if archive {
+            queue.archived.extend(expired);
+        }
+        Ok(purged)
+    }
+}
@@ -0,0 +1,271 @@
+//! In-memory implementation of UnitOfWork
+//!
+//! Transactions run one at a time and stage their writes, applying them to
+//! the in-memory repositories on commit. Writes made outside a transaction
+//! are not isolated from it, and a commit failing halfway (e.g. on a name
+//! conflict) keeps the writes applied before it; good enough for development.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use uuid::Uuid;
+
+use crate::application::ports::{
+    FlowerRepository, OrderRepository, TaskQueue, Transaction, UnitOfWork,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerChange, FlowerColor, FlowerError};
+use crate::domain::inventory::StockMovement;
+use crate::domain::order::{Order, OrderError, OrderEvent};
+use crate::domain::shared::{Entity, TenantId};
+use crate::domain::task::Task;
+use crate::infrastructure::memory::{
+    InMemoryFlowerHistory, InMemoryFlowerRepository, InMemoryOrderRepository, InMemoryStockLedger,
+    InMemoryTaskQueue,
+};
+
+/// UnitOfWork over the in-memory repositories
+pub struct InMemoryUnitOfWork {
+    flowers: Arc<InMemoryFlowerRepository>,
+    ledger: Arc<InMemoryStockLedger>,
+    history: Arc<InMemoryFlowerHistory>,
+    tasks: Arc<InMemoryTaskQueue>,
+    orders: Arc<InMemoryOrderRepository>,
+    serial: Arc<Mutex<()>>,
+}
+
+impl InMemoryUnitOfWork {
+    pub fn new(
+        flowers: Arc<InMemoryFlowerRepository>,
+        ledger: Arc<InMemoryStockLedger>,
+        history: Arc<InMemoryFlowerHistory>,
+        tasks: Arc<InMemoryTaskQueue>,
+        orders: Arc<InMemoryOrderRepository>,
+    ) -> Self {
+        Self {
+            flowers,
+            ledger,
+            history,
+            tasks,
+            orders,
+            serial: Arc::new(Mutex::new(())),
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    async fn begin(&self) -> DomainResult<Box<dyn Transaction>> {
+        Ok(Box::new(InMemoryTransaction {
+            _serial: self.serial.clone().lock_owned().await,
+            flowers: self.flowers.clone(),
+            ledger: self.ledger.clone(),
+            history: self.history.clone(),
+            tasks: self.tasks.clone(),
+            orders: self.orders.clone(),
+            staged_inserts: Vec::new(),
+            staged_flowers: Vec::new(),
+            staged_movements: Vec::new(),
+            staged_changes: Vec::new(),
+            staged_tasks: Vec::new(),
+            staged_orders: Vec::new(),
+            staged_events: Vec::new(),
+        }))
+    }
+}
+
+/// Writes staged until commit; dropping the transaction discards them
+struct InMemoryTransaction {
+    _serial: OwnedMutexGuard<()>,
+    flowers: Arc<InMemoryFlowerRepository>,
+    ledger: Arc<InMemoryStockLedger>,
+    history: Arc<InMemoryFlowerHistory>,
+    tasks: Arc<InMemoryTaskQueue>,
+    orders: Arc<InMemoryOrderRepository>,
+    staged_inserts: Vec<Flower>,
+    staged_flowers: Vec<Flower>,
+    staged_movements: Vec<StockMovement>,
+    staged_changes: Vec<FlowerChange>,
+    staged_tasks: Vec<Task>,
+    staged_orders: Vec<Order>,
+    staged_events: Vec<OrderEvent>,
+}
+
+#[async_trait]
+impl Transaction for InMemoryTransaction {
+    async fn lock_flower(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        let staged = self
+            .staged_flowers
+            .iter()
+            .rev()
+            .chain(&self.staged_inserts)
+            .find(|flower| flower.id() == id && flower.tenant_id() == tenant);
+        match staged {
+            Some(flower) => Ok(Some(flower.clone())),
+            None => self.flowers.find_by_id(tenant, id).await,
+        }
+    }
+
+    async fn lock_flowers(
+        &mut self,
+        tenant: &TenantId,
+        color: Option<FlowerColor>,
+        ids: Option<&[Uuid]>,
+    ) -> DomainResult<Vec<Flower>> {
+        let mut flowers = self.flowers.filtered(tenant, |_| true);
+        flowers.extend(
+            self.staged_inserts
+                .iter()
+                .filter(|flower| flower.tenant_id() == tenant)
+                .cloned(),
+        );
+        for flower in &mut flowers {
+            let staged = self
+                .staged_flowers
+                .iter()
+                .rev()
+                .find(|staged| staged.id() == flower.id());
+            if let Some(staged) = staged {
+                *flower = staged.clone();
+            }
+        }
+        flowers.retain(|flower| {
+            color.is_none_or(|color| flower.color() == color)
+                && ids.is_none_or(|ids| ids.contains(&flower.id()))
+        });
+        Ok(flowers)
+    }
+
+    async fn insert_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        self.staged_inserts.push(flower.clone());
+        Ok(flower.clone())
+    }
+
+    async fn update_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        if self
+            .lock_flower(flower.tenant_id(), flower.id())
+            .await?
+            .is_none()
+        {
+            return Err(FlowerError::not_found(flower.id()));
+        }
+        self.staged_flowers.push(flower.clone());
+        Ok(flower.clone())
+    }
+
+    async fn record_stock_movement(&mut self, movement: &StockMovement) -> DomainResult<()> {
+        self.staged_movements.push(movement.clone());
+        Ok(())
+    }
+
+    async fn record_flower_changes(&mut self, changes: &[FlowerChange]) -> DomainResult<()> {
+        self.staged_changes.extend_from_slice(changes);
+        Ok(())
+    }
+
+    async fn insert_order(&mut self, order: &Order) -> DomainResult<()> {
+        self.staged_orders.push(order.clone());
+        Ok(())
+    }
+
+    async fn lock_order(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Order>> {
+        let staged = self
+            .staged_orders
+            .iter()
+            .rev()
+            .find(|order| order.id() == id && order.tenant_id() == tenant);
+        match staged {
+            Some(order) => Ok(Some(order.clone())),
+            None => self.orders.find_by_id(tenant, id).await,
+        }
+    }
+
+    async fn update_order(&mut self, order: &Order) -> DomainResult<()> {
+        if self
+            .lock_order(order.tenant_id(), order.id())
+            .await?
+            .is_none()
+        {
+            return Err(OrderError::not_found(order.id()));
+        }
+        self.staged_orders.push(order.clone());
+        Ok(())
+    }
+
+    async fn record_order_events(&mut self, events: &[OrderEvent]) -> DomainResult<()> {
+        self.staged_events.extend_from_slice(events);
+        Ok(())
+    }
+
+    async fn enqueue_task(&mut self, task: &Task) -> DomainResult<()> {
+        self.staged_tasks.push(task.clone());
+        Ok(())
+    }
+
+    async fn commit(self: Box<Self>) -> DomainResult<()> {
+        for flower in &self.staged_inserts {
+            self.flowers.create(flower).await?;
+        }
+        for flower in &self.staged_flowers {
+            self.flowers.update(flower).await?;
+        }
+        for movement in &self.staged_movements {
+            self.ledger.record(movement);
+        }
+        self.history.record(&self.staged_changes);
+        for order in &self.staged_orders {
+            self.orders.save(order);
+        }
+        self.orders.record(&self.staged_events);
+        for task in &self.staged_tasks {
+            self.tasks.enqueue(task).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::flower::StockQuantity;
+    use crate::test_support::FlowerBuilder;
+
+    #[tokio::test]
+    async fn writes_apply_only_on_commit() {
+        let flowers = Arc::new(InMemoryFlowerRepository::new());
+        let unit_of_work = InMemoryUnitOfWork::new(
+            flowers.clone(),
+            Arc::new(InMemoryStockLedger::new()),
+            Arc::new(InMemoryFlowerHistory::new()),
+            Arc::new(InMemoryTaskQueue::new()),
+            Arc::new(InMemoryOrderRepository::new()),
+        );
+        let rose = FlowerBuilder::new()
+            .with_tenant("shop-a")
+            .with_stock(5)
+            .persisted(flowers.as_ref())
+            .await;
+
+        let mut changed = rose.clone();
+        changed.update_stock(StockQuantity::new(1).unwrap());
+
+        let mut tx = unit_of_work.begin().await.unwrap();
+        tx.update_flower(&changed).await.unwrap();
+        drop(tx);
+        let stored = flowers
+            .find_by_id(rose.tenant_id(), rose.id())
+            .await
+            .unwrap();
+        assert_eq!(stored.unwrap().stock(), 5);
+
+        let mut tx = unit_of_work.begin().await.unwrap();
+        tx.update_flower(&changed).await.unwrap();
+        tx.commit().await.unwrap();
+        let stored = flowers
+            .find_by_id(rose.tenant_id(), rose.id())
+            .await
+            .unwrap();
+        assert_eq!(stored.unwrap().stock(), 1);
+    }
+}
@@ -0,0 +1,102 @@
+//! In-memory implementation of UsageStore
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::application::ports::{ApiQuota, UsageRecord, UsageStore};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+type Key = (TenantId, Option<String>, DateTime<Utc>);
+
+/// Hourly usage totals and quotas held in process memory
+#[derive(Default)]
+pub struct InMemoryUsageStore {
+    usage: Mutex<HashMap<Key, UsageRecord>>,
+    quotas: Mutex<HashMap<(TenantId, String), ApiQuota>>,
+}
+
+impl InMemoryUsageStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl UsageStore for InMemoryUsageStore {
+    async fn add(&self, records: &[UsageRecord]) -> DomainResult<()> {
+        let mut usage = self.usage.lock().expect("usage store lock poisoned");
+        for record in records {
+            usage
+                .entry((record.tenant.clone(), record.key_id.clone(), record.hour))
+                .and_modify(|total| total.add(record))
+                .or_insert_with(|| record.clone());
+        }
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        tenant: Option<&TenantId>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<UsageRecord>> {
+        let usage = self.usage.lock().expect("usage store lock poisoned");
+        let mut records: Vec<UsageRecord> = usage
+            .values()
+            .filter(|record| tenant.is_none_or(|tenant| &record.tenant == tenant))
+            .filter(|record| record.hour >= from && record.hour < to)
+            .cloned()
+            .collect();
+        records.sort_by(|a, b| {
+            (a.hour, a.tenant.as_str(), &a.key_id).cmp(&(b.hour, b.tenant.as_str(), &b.key_id))
+        });
+        Ok(records)
+    }
+
+    async fn requests_by_key_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<(TenantId, String, i64)>> {
+        let usage = self.usage.lock().expect("usage store lock poisoned");
+        let mut totals: HashMap<(TenantId, String), i64> = HashMap::new();
+        for record in usage.values().filter(|record| record.hour >= since) {
+            if let Some(key_id) = &record.key_id {
+                *totals
+                    .entry((record.tenant.clone(), key_id.clone()))
+                    .or_default() += record.requests;
+            }
+        }
+        Ok(totals
+            .into_iter()
+            .map(|((tenant, key_id), requests)| (tenant, key_id, requests))
+            .collect())
+    }
+
+    async fn quotas(&self) -> DomainResult<Vec<ApiQuota>> {
+        let quotas = self.quotas.lock().expect("usage store lock poisoned");
+        let mut quotas: Vec<ApiQuota> = quotas.values().cloned().collect();
+        quotas.sort_by(|a, b| (a.tenant.as_str(), &a.key_id).cmp(&(b.tenant.as_str(), &b.key_id)));
+        Ok(quotas)
+    }
+
+    async fn set_quota(&self, quota: &ApiQuota) -> DomainResult<()> {
+        self.quotas
+            .lock()
+            .expect("usage store lock poisoned")
+            .insert((quota.tenant.clone(), quota.key_id.clone()), quota.clone());
+        Ok(())
+    }
+
+    async fn remove_quota(&self, tenant: &TenantId, key_id: &str) -> DomainResult<bool> {
+        Ok(self
+            .quotas
+            .lock()
+            .expect("usage store lock poisoned")
+            .remove(&(tenant.clone(), key_id.to_string()))
+            .is_some())
+    }
+}
@@ -0,0 +1,93 @@
+//! In-memory implementation of WebhookEndpointRepository
+
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::{WebhookEndpoint, WebhookEndpointRepository};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+
+/// Webhook endpoints held in process memory
+#[derive(Default)]
+pub struct InMemoryWebhookEndpointRepository {
+    endpoints: RwLock<Vec<WebhookEndpoint>>,
+}
+
+impl InMemoryWebhookEndpointRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WebhookEndpointRepository for InMemoryWebhookEndpointRepository {
+    async fn create(&self, endpoint: &WebhookEndpoint) -> DomainResult<()> {
+        self.endpoints
+            .write()
+            .expect("webhook endpoint lock poisoned")
+            .push(endpoint.clone());
+        Ok(())
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<WebhookEndpoint>> {
+        let mut endpoints: Vec<WebhookEndpoint> = self
+            .endpoints
+            .read()
+            .expect("webhook endpoint lock poisoned")
+            .iter()
+            .filter(|endpoint| &endpoint.tenant == tenant)
+            .cloned()
+            .collect();
+        endpoints.sort_by_key(|endpoint| (endpoint.created_at, endpoint.id));
+        Ok(endpoints)
+    }
+
+    async fn find_by_id(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<Option<WebhookEndpoint>> {
+        Ok(self
+            .endpoints
+            .read()
+            .expect("webhook endpoint lock poisoned")
+            .iter()
+            .find(|endpoint| &endpoint.tenant == tenant && endpoint.id == id)
+            .cloned())
+    }
+
+    async fn rotate_secret(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+        secret: &str,
+        previous_expires_at: DateTime<Utc>,
+    ) -> DomainResult<Option<WebhookEndpoint>> {
+        let mut endpoints = self
+            .endpoints
+            .write()
+            .expect("webhook endpoint lock poisoned");
+        Ok(endpoints
+            .iter_mut()
+            .find(|endpoint| &endpoint.tenant == tenant && endpoint.id == id)
+            .map(|endpoint| {
+                let previous = std::mem::replace(&mut endpoint.secret, secret.to_string());
+                endpoint.previous_secret = Some(previous);
+                endpoint.previous_secret_expires_at = Some(previous_expires_at);
+                endpoint.clone()
+            }))
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let mut endpoints = self
+            .endpoints
+            .write()
+            .expect("webhook endpoint lock poisoned");
+        let before = endpoints.len();
+        endpoints.retain(|endpoint| !(&endpoint.tenant == tenant && endpoint.id == id));
+        Ok(endpoints.len() < before)
+    }
+}
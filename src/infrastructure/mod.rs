@@ -1,2 +1,21 @@
+pub mod build_info;
+pub mod cache;
 pub mod config;
+pub mod diagnostics;
+pub mod email;
+pub mod error_reporting;
+pub mod http_client;
+pub mod labels;
+pub mod memory;
+pub mod metrics;
+pub mod object_store;
+pub mod payments;
 pub mod persistance;
+pub mod scheduler;
+pub mod secrets;
+pub mod shipping;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod storage;
+pub mod suppliers;
+pub mod webhooks;
@@ -0,0 +1,87 @@
+//! Local Directory Object Store
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use tokio::fs;
+
+use crate::application::ports::ObjectStore;
+use crate::domain::errors::{AppError, DomainResult};
+
+/// Stores each object as a file under a root directory, keys mapping to paths
+pub struct FileSystemObjectStore {
+    root: PathBuf,
+}
+
+impl FileSystemObjectStore {
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Path of `key`, rejecting keys that would escape the root directory
+    fn path(&self, key: &str) -> DomainResult<PathBuf> {
+        let valid = !key.is_empty()
+            && key
+                .split('/')
+                .all(|segment| !segment.is_empty() && segment != "." && segment != "..");
+        if !valid {
+            return Err(AppError::internal(format!("Invalid object key '{}'", key)));
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl ObjectStore for FileSystemObjectStore {
+    async fn put(&self, key: &str, body: Vec<u8>) -> DomainResult<()> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await.map_err(io_error)?;
+        }
+
+        // Write aside and rename so readers never see a partial object
+        let partial = path.with_extension("partial");
+        fs::write(&partial, body).await.map_err(io_error)?;
+        fs::rename(&partial, &path).await.map_err(io_error)
+    }
+
+    async fn get(&self, key: &str) -> DomainResult<Option<Vec<u8>>> {
+        match fs::read(self.path(key)?).await {
+            Ok(body) => Ok(Some(body)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(io_error(e)),
+        }
+    }
+}
+
+fn io_error(error: std::io::Error) -> AppError {
+    AppError::internal(format!("Object store error: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn stores_objects_and_rejects_escaping_keys() {
+        let root = std::env::temp_dir().join(format!("objects-{}", uuid::Uuid::new_v4()));
+        let store = FileSystemObjectStore::new(&root);
+
+        store
+            .put("backups/1/flowers.ndjson", b"{}".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            store.get("backups/1/flowers.ndjson").await.unwrap(),
+            Some(b"{}".to_vec())
+        );
+        assert_eq!(store.get("backups/2/flowers.ndjson").await.unwrap(), None);
+        assert!(store.put("../outside", Vec::new()).await.is_err());
+        assert!(store.get("/etc/passwd").await.is_err());
+
+        fs::remove_dir_all(root).await.unwrap();
+    }
+}
@@ -0,0 +1,32 @@
+//! Object Store Health Indicator
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::ports::{Health, HealthIndicator, ObjectStore};
+
+/// Key written by every check
+const PROBE_KEY: &str = "health/probe";
+
+/// Whether objects can be written; only backups and catalog exports need
+/// that, so it is not critical
+pub struct ObjectStoreHealth(pub Arc<dyn ObjectStore>);
+
+#[async_trait]
+impl HealthIndicator for ObjectStoreHealth {
+    fn name(&self) -> &'static str {
+        "object_store"
+    }
+
+    fn critical(&self) -> bool {
+        false
+    }
+
+    async fn check(&self) -> Health {
+        match self.0.put(PROBE_KEY, b"ok".to_vec()).await {
+            Ok(()) => Health::up(),
+            Err(e) => Health::down(e),
+        }
+    }
+}
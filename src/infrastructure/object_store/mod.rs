@@ -0,0 +1,23 @@
+//! Object Storage
+//!
+//! Where backups and catalog exports are written. `OBJECT_STORE_PATH` selects a local directory,
+//! which suits single-host installs; mount a volume or sync it off-host.
+
+pub mod filesystem;
+pub mod health;
+
+use std::sync::Arc;
+
+use crate::application::ports::ObjectStore;
+use crate::infrastructure::config::AppConfig;
+
+pub use filesystem::FileSystemObjectStore;
+pub use health::ObjectStoreHealth;
+
+/// Object store selected by configuration, if any
+pub fn store(config: &AppConfig) -> Option<Arc<dyn ObjectStore>> {
+    config
+        .object_store_path
+        .as_ref()
+        .map(|path| Arc::new(FileSystemObjectStore::new(path)) as Arc<dyn ObjectStore>)
+}
@@ -0,0 +1,29 @@
+//! Console Payment Gateway
+
+use async_trait::async_trait;
+
+use crate::application::ports::{PaymentGateway, Refund};
+use crate::domain::errors::DomainResult;
+
+/// Logs refunds instead of moving money, for staff to settle manually
+pub struct ConsolePaymentGateway;
+
+#[async_trait]
+impl PaymentGateway for ConsolePaymentGateway {
+    fn name(&self) -> &str {
+        "console"
+    }
+
+    async fn refund(&self, refund: &Refund) -> DomainResult<String> {
+        tracing::info!(
+            tenant = %refund.tenant,
+            order_id = %refund.order_id,
+            amount = refund.amount,
+            idempotency_key = %refund.idempotency_key,
+            "Refund not sent (console gateway), settle it manually"
+        );
+        // Refunds asked for again under the same key get the same reference,
+        // so staff settle each once
+        Ok(format!("manual-{}", refund.idempotency_key))
+    }
+}
@@ -0,0 +1,8 @@
+//! Payment Gateways
+//!
+//! No payment processor is integrated yet: refunds are logged for staff to
+//! settle by hand.
+
+pub mod console;
+
+pub use console::ConsolePaymentGateway;
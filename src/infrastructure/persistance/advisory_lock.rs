@@ -0,0 +1,64 @@
+//! PostgreSQL implementation of DistributedLock
+//!
+//! Uses transaction-scoped advisory locks: the guard owns an open transaction
+//! and the lock is released when it ends, so a guard that is dropped (or a
+//! replica that dies) can never leave the lock held.
+
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use crate::application::ports::{DistributedLock, LockGuard};
+use crate::domain::errors::DomainResult;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// First key of every advisory lock taken by this service, keeping lock names
+/// from colliding with advisory locks of other applications on the database
+const LOCK_CLASS: i32 = 0x666c_7772;
+
+/// Advisory lock backed DistributedLock
+pub struct PostgresAdvisoryLock {
+    db: DatabasePool,
+}
+
+impl PostgresAdvisoryLock {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DistributedLock for PostgresAdvisoryLock {
+    async fn try_acquire(&self, name: &str) -> DomainResult<Option<Box<dyn LockGuard>>> {
+        let mut transaction = self.db.pool().begin().await?;
+        let statement = sqlx::query_scalar!(
+            r#"SELECT pg_try_advisory_xact_lock($1, hashtext($2)) AS "acquired!""#,
+            LOCK_CLASS,
+            name
+        )
+        .fetch_one(&mut *transaction);
+        let acquired = self
+            .db
+            .timed("advisory_lock.try_acquire", statement)
+            .await?;
+
+        if acquired {
+            Ok(Some(Box::new(AdvisoryLockGuard { transaction })))
+        } else {
+            transaction.rollback().await?;
+            Ok(None)
+        }
+    }
+}
+
+/// Advisory lock held until its transaction ends
+struct AdvisoryLockGuard {
+    transaction: Transaction<'static, Postgres>,
+}
+
+#[async_trait]
+impl LockGuard for AdvisoryLockGuard {
+    async fn release(self: Box<Self>) -> DomainResult<()> {
+        self.transaction.rollback().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+//! PostgreSQL implementation of DatabaseDump
+//!
+//! Rows travel as JSON (`to_jsonb` out, `jsonb_populate_recordset` back in),
+//! so a dump restores into any schema version with the same columns.
+
+use async_trait::async_trait;
+use serde_json::Value;
+use sqlx::types::Json;
+
+use crate::application::ports::{DatabaseDump, TableDump};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Tables included in a dump, in restore order: tables referenced by
+/// foreign keys come before the tables referencing them
+const TABLES: &[&str] = &[
+    "flowers",
+    "feature_flags",
+    "tasks",
+    "dead_letter_tasks",
+    "dead_letter_tasks_archive",
+    "stock_movements",
+    "flower_views",
+    "flower_changes",
+    "saved_searches",
+    "recent_views",
+    "stores",
+    "store_stock",
+    "delivery_zones",
+    "orders",
+    "order_events",
+    "pricing_rules",
+    "api_usage",
+    "api_quotas",
+    "webhook_nonces",
+    "webhook_endpoints",
+    "job_ticks",
+];
+
+/// Bookkeeping of sqlx, restored by running the migrations
+const MIGRATIONS_TABLE: &str = "_sqlx_migrations";
+
+/// PostgreSQL implementation of DatabaseDump
+pub struct PostgresDatabaseDump {
+    db: DatabasePool,
+}
+
+impl PostgresDatabaseDump {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DatabaseDump for PostgresDatabaseDump {
+    async fn export(&self) -> DomainResult<Vec<TableDump>> {
+        let mut tx = self.db.pool().begin().await?;
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(&mut *tx)
+            .await?;
+
+        // A backup silently missing a table is worse than none
+        let statement = sqlx::query_scalar::<_, String>(
+            "SELECT table_name::text FROM information_schema.tables \
+             WHERE table_schema = current_schema() AND table_type = 'BASE TABLE' \
+               AND table_name <> $1 AND NOT (table_name = ANY($2)) \
+             ORDER BY table_name",
+        )
+        .bind(MIGRATIONS_TABLE)
+        .bind(TABLES)
+        .fetch_all(&mut *tx);
+        let unlisted = self.db.timed("backup.tables", statement).await?;
+        if !unlisted.is_empty() {
+            return Err(AppError::internal(format!(
+                "Tables missing from the backup: {}",
+                unlisted.join(", ")
+            )));
+        }
+
+        let mut tables = Vec::with_capacity(TABLES.len());
+        for table in TABLES {
+            let query = format!("SELECT to_jsonb(t) FROM {} t", table);
+            let statement = sqlx::query_scalar::<_, Value>(&query).fetch_all(&mut *tx);
+            let rows = self.db.timed("backup.export", statement).await?;
+            tables.push(TableDump {
+                table: table.to_string(),
+                rows,
+            });
+        }
+        tx.commit().await?;
+
+        Ok(tables)
+    }
+
+    async fn restore(&self, tables: &[TableDump]) -> DomainResult<()> {
+        if let Some(unknown) = tables
+            .iter()
+            .find(|dump| !TABLES.contains(&dump.table.as_str()))
+        {
+            return Err(AppError::bad_request(format!(
+                "Unknown table '{}' in dump",
+                unknown.table
+            )));
+        }
+        if tables.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self.db.pool().begin().await?;
+        let names: Vec<&str> = tables.iter().map(|dump| dump.table.as_str()).collect();
+        sqlx::query(&format!("TRUNCATE {}", names.join(", ")))
+            .execute(&mut *tx)
+            .await?;
+
+        for dump in tables {
+            let query = format!(
+                "INSERT INTO {0} SELECT * FROM jsonb_populate_recordset(NULL::{0}, $1)",
+                dump.table
+            );
+            let statement = sqlx::query(&query).bind(Json(&dump.rows)).execute(&mut *tx);
+            self.db.timed("backup.restore", statement).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -1,40 +1,742 @@
 //! Database Configuration
+//!
+//! The pool talks to PostgreSQL or, when built with the `sqlite` feature and
+//! given a `sqlite:` URL, to an embedded SQLite file. Each backend has its own
+//! set of migrations; repositories are written against one backend and ask
+//! for its pool directly.
 
-use sqlx::PgPool;
-use sqlx::postgres::PgPoolOptions;
+use std::fmt;
+use std::future::Future;
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use sqlx::migrate::{Migrate, MigrateDatabase, Migrator};
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+#[cfg(feature = "sqlite")]
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions};
+use sqlx::{Acquire, PgPool, Postgres};
 
 use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::persistance::pool_monitor::{AcquireLatency, PoolMonitor};
+use crate::infrastructure::persistance::query_timing;
+use crate::infrastructure::persistance::read_replicas::ReadReplicas;
+
+/// Migrations embedded at compile time
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// SQLite flavour of the same schema
+#[cfg(feature = "sqlite")]
+static SQLITE_MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+/// URL scheme selecting the SQLite backend
+pub const SQLITE_SCHEME: &str = "sqlite:";
+
+#[cfg(not(feature = "sqlite"))]
+const SQLITE_DISABLED: &str = "SQLite databases require building with the `sqlite` feature";
+
+/// Snapshot of the connection pool
+#[derive(Debug, Clone, Copy)]
+pub struct PoolStats {
+    pub size: u32,
+    pub idle: usize,
+    pub max_connections: u32,
+    /// Queries that found every connection busy, since startup
+    pub waits: u64,
+    /// Over the latest probes; `None` before the first one
+    pub acquire_latency: Option<AcquireLatency>,
+}
+
+impl PoolStats {
+    /// Connections in use
+    pub fn active(&self) -> u32 {
+        self.size.saturating_sub(self.idle as u32)
+    }
+}
+
+/// Whether an embedded migration has been applied
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub applied: bool,
+}
+
+/// How the database schema differs from the migrations embedded in this build
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDrift {
+    /// Embedded migrations not applied yet: the database is behind
+    pub pending: Vec<i64>,
+    /// Applied migrations this build does not have: the database is ahead,
+    /// migrated by a newer release
+    pub unknown: Vec<i64>,
+    /// Applied migrations whose embedded file has been edited since
+    pub modified: Vec<i64>,
+    /// Migration that failed partway, leaving the schema half changed
+    pub failed: Option<i64>,
+}
+
+impl SchemaDrift {
+    /// Whether the schema matches the embedded migrations exactly
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+            && self.unknown.is_empty()
+            && self.modified.is_empty()
+            && self.failed.is_none()
+    }
+
+    /// Whether the database is merely behind, so migrating fixes it
+    pub fn is_behind(&self) -> bool {
+        !self.pending.is_empty()
+            && self.unknown.is_empty()
+            && self.modified.is_empty()
+            && self.failed.is_none()
+    }
+}
+
+impl fmt::Display for SchemaDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let versions = |versions: &[i64]| {
+            versions
+                .iter()
+                .map(i64::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut problems = Vec::new();
+        if let Some(version) = self.failed {
+            problems.push(format!(
+                "migration {} failed partway; repair the schema by hand and remove its row from _sqlx_migrations",
+                version
+            ));
+        }
+        if !self.unknown.is_empty() {
+            problems.push(format!(
+                "the database is ahead of this build, applied migrations {} are unknown; deploy the release that added them or revert them with `rust-api migrate down`",
+                versions(&self.unknown)
+            ));
+        }
+        if !self.modified.is_empty() {
+            problems.push(format!(
+                "migrations {} were edited after they were applied; restore the original files",
+                versions(&self.modified)
+            ));
+        }
+        if !self.pending.is_empty() {
+            problems.push(format!(
+                "the database is behind this build, migrations {} are pending; apply them with `rust-api migrate up` or set AUTO_MIGRATE=true",
+                versions(&self.pending)
+            ));
+        }
+
+        if problems.is_empty() {
+            return f.write_str("schema is up to date");
+        }
+        f.write_str(&problems.join("; "))
+    }
+}
+
+/// Connection pool tuning, applied to the primary and every read replica
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolSettings {
+    pub max_connections: u32,
+    /// Connections kept open even when idle
+    pub min_connections: u32,
+    /// How long a query waits for a free connection before failing
+    pub acquire_timeout: Duration,
+    /// Close connections idle for longer than this; `None` keeps them
+    pub idle_timeout: Option<Duration>,
+    /// Recycle connections older than this; `None` keeps them
+    pub max_lifetime: Option<Duration>,
+    /// Server-side limit on a single statement (PostgreSQL only); `None` disables it
+    pub statement_timeout: Option<Duration>,
+}
+
+impl Default for PoolSettings {
+    fn default() -> Self {
+        Self {
+            max_connections: 10,
+            min_connections: 0,
+            acquire_timeout: Duration::from_secs(30),
+            idle_timeout: Some(Duration::from_secs(600)),
+            max_lifetime: Some(Duration::from_secs(1800)),
+            statement_timeout: None,
+        }
+    }
+}
+
+impl PoolSettings {
+    pub(crate) fn pg_pool_options(&self) -> PgPoolOptions {
+        PgPoolOptions::new()
+            .max_connections(self.max_connections)
+            .min_connections(self.min_connections)
+            .acquire_timeout(self.acquire_timeout)
+            .idle_timeout(self.idle_timeout)
+            .max_lifetime(self.max_lifetime)
+    }
+
+    pub(crate) fn pg_connect_options(&self, url: &str) -> Result<PgConnectOptions, sqlx::Error> {
+        let options = PgConnectOptions::from_str(url)?;
+        Ok(match self.statement_timeout {
+            Some(timeout) => {
+                options.options([("statement_timeout", timeout.as_millis().to_string())])
+            }
+            None => options,
+        })
+    }
+}
+
+impl fmt::Display for PoolSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = |value: Option<Duration>| match value {
+            Some(duration) => format!("{:?}", duration),
+            None => "off".to_string(),
+        };
+        write!(
+            f,
+            "max {} connections, min {}, acquire timeout {:?}, idle timeout {}, max lifetime {}, statement timeout {}",
+            self.max_connections,
+            self.min_connections,
+            self.acquire_timeout,
+            limit(self.idle_timeout),
+            limit(self.max_lifetime),
+            limit(self.statement_timeout),
+        )
+    }
+}
+
+/// Versions recorded in the migrations table
+async fn applied_versions<'a, A>(connection: A) -> Result<Vec<i64>, sqlx::migrate::MigrateError>
+where
+    A: Acquire<'a>,
+    <A::Connection as Deref>::Target: Migrate,
+{
+    let mut connection = connection.acquire().await?;
+    connection.ensure_migrations_table().await?;
+    let applied = connection.list_applied_migrations().await?;
+
+    Ok(applied
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect())
+}
+
+/// How the migrations recorded in the database differ from `migrator`
+async fn schema_drift<'a, A>(
+    connection: A,
+    migrator: &Migrator,
+) -> Result<SchemaDrift, sqlx::migrate::MigrateError>
+where
+    A: Acquire<'a>,
+    <A::Connection as Deref>::Target: Migrate,
+{
+    let mut connection = connection.acquire().await?;
+    connection.ensure_migrations_table().await?;
+    let failed = connection.dirty_version().await?;
+    let applied = connection.list_applied_migrations().await?;
+
+    let embedded: Vec<_> = migrator
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .collect();
+    let applied_checksum = |version: i64| {
+        applied
+            .iter()
+            .find(|migration| migration.version == version && Some(version) != failed)
+            .map(|migration| &migration.checksum)
+    };
+
+    Ok(SchemaDrift {
+        pending: embedded
+            .iter()
+            .filter(|migration| applied_checksum(migration.version).is_none())
+            .filter(|migration| Some(migration.version) != failed)
+            .map(|migration| migration.version)
+            .collect(),
+        unknown: applied
+            .iter()
+            .map(|migration| migration.version)
+            .filter(|version| {
+                !embedded
+                    .iter()
+                    .any(|migration| migration.version == *version)
+            })
+            .collect(),
+        modified: embedded
+            .iter()
+            .filter(|migration| {
+                applied_checksum(migration.version)
+                    .is_some_and(|checksum| *checksum != migration.checksum)
+            })
+            .map(|migration| migration.version)
+            .collect(),
+        failed,
+    })
+}
+
+/// Open `count` connections of `pool` at once, then return them to it idle
+async fn warm_up<DB: sqlx::Database>(pool: &sqlx::Pool<DB>, count: u32) -> Result<(), sqlx::Error> {
+    let mut connections = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        connections.push(pool.acquire().await?);
+    }
+    Ok(())
+}
+
+/// Create a database of backend `DB` unless it exists already
+async fn create_database<DB: MigrateDatabase>(database_url: &str) -> Result<bool, sqlx::Error> {
+    if DB::database_exists(database_url).await? {
+        return Ok(false);
+    }
+    DB::create_database(database_url).await?;
+    Ok(true)
+}
+
+/// Connection pool of the selected backend
+#[derive(Clone)]
+enum Backend {
+    Postgres(PgPool),
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqlitePool),
+}
 
 /// Database pool wrapper
 #[derive(Clone)]
 pub struct DatabasePool {
-    pool: PgPool,
+    backend: Backend,
+    settings: PoolSettings,
+    readers: Option<Arc<ReadReplicas>>,
+    slow_query_threshold: Duration,
+    monitor: Arc<PoolMonitor>,
 }
 
 impl DatabasePool {
-    /// Create a new database pool
-    pub async fn new(database_url: &str) -> DomainResult<Self> {
-        let pool = PgPoolOptions::new()
-            .max_connections(10)
-            .connect(database_url)
+    /// Create a new database pool, picking the backend from the URL scheme
+    pub async fn new(database_url: &str, settings: PoolSettings) -> DomainResult<Self> {
+        let backend = if database_url.starts_with(SQLITE_SCHEME) {
+            Self::connect_sqlite(database_url, &settings).await?
+        } else {
+            let options = settings
+                .pg_connect_options(database_url)
+                .map_err(connect_error)?;
+            let pool = settings
+                .pg_pool_options()
+                .connect_with(options)
+                .await
+                .map_err(connect_error)?;
+            Backend::Postgres(pool)
+        };
+        tracing::info!("Database pool: {}", settings);
+
+        Ok(Self {
+            backend,
+            settings,
+            readers: None,
+            slow_query_threshold: Duration::from_millis(200),
+            monitor: Arc::default(),
+        })
+    }
+
+    /// Create the database named by `database_url` unless it exists already
+    ///
+    /// Returns whether the database was created.
+    pub async fn create_database(database_url: &str) -> DomainResult<bool> {
+        if database_url.starts_with(SQLITE_SCHEME) {
+            #[cfg(feature = "sqlite")]
+            return create_database::<sqlx::Sqlite>(database_url)
+                .await
+                .map_err(connect_error);
+            #[cfg(not(feature = "sqlite"))]
+            return Err(AppError::internal(SQLITE_DISABLED));
+        }
+
+        create_database::<Postgres>(database_url)
             .await
-            .map_err(|e| AppError::internal(format!("Failed to connect to database: {}", e)))?;
+            .map_err(connect_error)
+    }
+
+    #[cfg(feature = "sqlite")]
+    async fn connect_sqlite(database_url: &str, settings: &PoolSettings) -> DomainResult<Backend> {
+        if settings.statement_timeout.is_some() {
+            tracing::warn!("Ignoring DB_STATEMENT_TIMEOUT_MS: not supported by SQLite");
+        }
 
-        Ok(Self { pool })
+        let options = SqliteConnectOptions::from_str(database_url)
+            .map_err(connect_error)?
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .foreign_keys(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(settings.max_connections)
+            .min_connections(settings.min_connections)
+            .acquire_timeout(settings.acquire_timeout)
+            .idle_timeout(settings.idle_timeout)
+            .max_lifetime(settings.max_lifetime)
+            .connect_with(options)
+            .await
+            .map_err(connect_error)?;
+
+        Ok(Backend::Sqlite(pool))
     }
 
-    /// Get a reference to the pool
+    #[cfg(not(feature = "sqlite"))]
+    async fn connect_sqlite(
+        _database_url: &str,
+        _settings: &PoolSettings,
+    ) -> DomainResult<Backend> {
+        Err(AppError::internal(SQLITE_DISABLED))
+    }
+
+    /// Whether this pool talks to SQLite
+    pub fn is_sqlite(&self) -> bool {
+        match self.backend {
+            Backend::Postgres(_) => false,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(_) => true,
+        }
+    }
+
+    /// Log queries slower than `threshold` as warnings
+    pub fn with_slow_query_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_query_threshold = threshold;
+        self
+    }
+
+    /// Route reads to the given replicas; only supported on PostgreSQL
+    pub fn with_read_replicas(mut self, urls: &[String]) -> DomainResult<Self> {
+        if urls.is_empty() {
+            return Ok(self);
+        }
+        if self.is_sqlite() {
+            tracing::warn!("Ignoring DATABASE_READ_URLS: read replicas need PostgreSQL");
+            return Ok(self);
+        }
+
+        let replicas = ReadReplicas::new(urls, &self.settings)?;
+        tracing::info!("Routing reads to {} replica(s)", replicas.len());
+        self.readers = Some(Arc::new(replicas));
+        Ok(self)
+    }
+
+    /// Time a named query, see [`query_timing::timed`], counting it as a
+    /// wait when every connection of the primary is busy
+    pub async fn timed<T>(&self, query: &'static str, future: impl Future<Output = T>) -> T {
+        let stats = self.pool_stats();
+        if stats.idle == 0 && stats.size >= stats.max_connections {
+            self.monitor.record_wait();
+        }
+        query_timing::timed(query, self.slow_query_threshold, future).await
+    }
+
+    /// Run and time a named read-only query on a replica, or on the primary
+    /// when there are no replicas or none of them is reachable
+    ///
+    /// `run` may be called twice, so it builds the statement on each call.
+    /// Reads that must see the caller's own writes belong on [`Self::pool`].
+    pub async fn read<'a, T, F, Fut>(
+        &'a self,
+        query: &'static str,
+        run: F,
+    ) -> Result<T, sqlx::Error>
+    where
+        F: Fn(&'a PgPool) -> Fut,
+        Fut: Future<Output = Result<T, sqlx::Error>>,
+    {
+        let primary = self.pool();
+        match &self.readers {
+            Some(readers) => self.timed(query, readers.run(primary, run)).await,
+            None => self.timed(query, run(primary)).await,
+        }
+    }
+
+    /// Get a reference to the primary PostgreSQL pool
+    ///
+    /// Panics on a SQLite pool; PostgreSQL adapters are only built for
+    /// PostgreSQL URLs.
     pub fn pool(&self) -> &PgPool {
-        &self.pool
+        match &self.backend {
+            Backend::Postgres(pool) => pool,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(_) => panic!("PostgreSQL pool requested from a SQLite database"),
+        }
+    }
+
+    /// Get a reference to the SQLite pool
+    ///
+    /// Panics on a PostgreSQL pool; SQLite adapters are only built for
+    /// SQLite URLs.
+    #[cfg(feature = "sqlite")]
+    pub fn sqlite_pool(&self) -> &SqlitePool {
+        match &self.backend {
+            Backend::Sqlite(pool) => pool,
+            Backend::Postgres(_) => panic!("SQLite pool requested from a PostgreSQL database"),
+        }
+    }
+
+    fn migrator(&self) -> &'static Migrator {
+        match self.backend {
+            Backend::Postgres(_) => &MIGRATOR,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(_) => &SQLITE_MIGRATOR,
+        }
     }
 
     /// Run migrations
     pub async fn run_migrations(&self) -> DomainResult<()> {
-        sqlx::migrate!("./migrations")
-            .run(&self.pool)
-            .await
-            .map_err(|e| AppError::internal(format!("Failed to run migrations: {}", e)))?;
+        let result = match &self.backend {
+            Backend::Postgres(pool) => self.migrator().run(pool).await,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(pool) => self.migrator().run(pool).await,
+        };
+        result.map_err(|e| AppError::internal(format!("Failed to run migrations: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Revert the last `steps` applied migrations, newest first
+    ///
+    /// Returns the reverted versions.
+    pub async fn undo_migrations(&self, steps: usize) -> DomainResult<Vec<i64>> {
+        let applied: Vec<i64> = self
+            .migration_status()
+            .await?
+            .into_iter()
+            .filter(|migration| migration.applied)
+            .map(|migration| migration.version)
+            .collect();
+        let keep = applied.len().saturating_sub(steps);
+        let target = keep.checked_sub(1).map_or(0, |last| applied[last]);
+
+        let result = match &self.backend {
+            Backend::Postgres(pool) => self.migrator().undo(pool, target).await,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(pool) => self.migrator().undo(pool, target).await,
+        };
+        result.map_err(|e| AppError::internal(format!("Failed to revert migrations: {}", e)))?;
+
+        Ok(applied[keep..].iter().rev().copied().collect())
+    }
+
+    /// Every embedded migration, oldest first, with whether it has been applied
+    ///
+    /// Creates the migrations table on a database that has never been migrated.
+    pub async fn migration_status(&self) -> DomainResult<Vec<MigrationStatus>> {
+        let applied = match &self.backend {
+            Backend::Postgres(pool) => applied_versions(pool).await,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(pool) => applied_versions(pool).await,
+        }
+        .map_err(|e| AppError::internal(format!("Failed to read applied migrations: {}", e)))?;
+
+        Ok(self
+            .migrator()
+            .iter()
+            .filter(|migration| !migration.migration_type.is_down_migration())
+            .map(|migration| MigrationStatus {
+                version: migration.version,
+                description: migration.description.to_string(),
+                applied: applied.contains(&migration.version),
+            })
+            .collect())
+    }
+
+    /// How the schema differs from the embedded migrations
+    ///
+    /// Creates the migrations table on a database that has never been migrated.
+    pub async fn schema_drift(&self) -> DomainResult<SchemaDrift> {
+        match &self.backend {
+            Backend::Postgres(pool) => schema_drift(pool, self.migrator()).await,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(pool) => schema_drift(pool, self.migrator()).await,
+        }
+        .map_err(|e| AppError::internal(format!("Failed to read applied migrations: {}", e)))
+    }
+
+    /// Open the pool's minimum number of connections, at least one, so the
+    /// first requests do not wait for connections and an unreachable
+    /// database shows up at startup
+    ///
+    /// Returns the number of connections opened.
+    pub async fn warm_up(&self) -> DomainResult<u32> {
+        let count = self.settings.min_connections.max(1);
+        match &self.backend {
+            Backend::Postgres(pool) => warm_up(pool, count).await,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(pool) => warm_up(pool, count).await,
+        }
+        .map_err(connect_error)?;
+        Ok(count)
+    }
 
+    /// Check that the database answers queries
+    pub async fn ping(&self) -> DomainResult<()> {
+        match &self.backend {
+            Backend::Postgres(pool) => sqlx::query("SELECT 1").execute(pool).await.map(drop)?,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(pool) => sqlx::query("SELECT 1").execute(pool).await.map(drop)?,
+        }
         Ok(())
     }
+
+    /// Versions of embedded migrations that have not been applied yet
+    pub async fn pending_migrations(&self) -> DomainResult<Vec<i64>> {
+        const APPLIED: &str = "SELECT version FROM _sqlx_migrations WHERE success ORDER BY version";
+        let applied: Vec<i64> = match &self.backend {
+            Backend::Postgres(pool) => sqlx::query_scalar(APPLIED).fetch_all(pool).await?,
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(pool) => sqlx::query_scalar(APPLIED).fetch_all(pool).await?,
+        };
+
+        Ok(self
+            .migrator()
+            .iter()
+            .filter(|migration| !migration.migration_type.is_down_migration())
+            .map(|migration| migration.version)
+            .filter(|version| !applied.contains(version))
+            .collect())
+    }
+
+    /// Current pool usage, with the waits and acquire times seen so far
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            waits: self.monitor.waits(),
+            acquire_latency: self.monitor.acquire_latency(),
+            ..self.pool_stats()
+        }
+    }
+
+    fn pool_stats(&self) -> PoolStats {
+        let (size, idle, max_connections) = match &self.backend {
+            Backend::Postgres(pool) => (
+                pool.size(),
+                pool.num_idle(),
+                pool.options().get_max_connections(),
+            ),
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(pool) => (
+                pool.size(),
+                pool.num_idle(),
+                pool.options().get_max_connections(),
+            ),
+        };
+        PoolStats {
+            size,
+            idle,
+            max_connections,
+            waits: 0,
+            acquire_latency: None,
+        }
+    }
+
+    /// Acquire and release a connection as a query would, recording how
+    /// long it took and publishing the pool gauges
+    pub async fn probe(&self) -> DomainResult<Duration> {
+        let started = Instant::now();
+        let acquired = match &self.backend {
+            Backend::Postgres(pool) => pool.acquire().await.map(drop),
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(pool) => pool.acquire().await.map(drop),
+        };
+        let elapsed = started.elapsed();
+        // Timeouts count too: that is how long queries waited before failing
+        self.monitor.record_acquire(elapsed);
+        self.publish_metrics();
+
+        acquired
+            .map(|()| elapsed)
+            .map_err(|e| AppError::internal(format!("Failed to acquire a connection: {}", e)))
+    }
+
+    fn publish_metrics(&self) {
+        let stats = self.stats();
+        metrics::gauge!("db_pool_connections", "state" => "active").set(stats.active() as f64);
+        metrics::gauge!("db_pool_connections", "state" => "idle").set(stats.idle as f64);
+        metrics::gauge!("db_pool_max_connections").set(stats.max_connections as f64);
+        if let Some(latency) = stats.acquire_latency {
+            for (quantile, value) in [
+                ("0.5", latency.p50),
+                ("0.95", latency.p95),
+                ("0.99", latency.p99),
+            ] {
+                metrics::gauge!("db_pool_acquire_seconds", "quantile" => quantile)
+                    .set(value.as_secs_f64());
+            }
+        }
+    }
+}
+
+fn connect_error(error: sqlx::Error) -> AppError {
+    AppError::internal(format!("Failed to connect to database: {}", error))
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+
+    async fn sqlite() -> DatabasePool {
+        let path = std::env::temp_dir().join(format!("schema-{}.db", uuid::Uuid::new_v4()));
+        DatabasePool::new(&format!("sqlite://{}", path.display()), Default::default())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn detects_schemas_behind_ahead_and_edited() {
+        let db = sqlite().await;
+        assert_eq!(db.warm_up().await.unwrap(), 1);
+
+        let drift = db.schema_drift().await.unwrap();
+        assert!(drift.is_behind());
+        assert_eq!(drift.pending.len(), SQLITE_MIGRATOR.iter().count() / 2);
+
+        db.run_migrations().await.unwrap();
+        assert!(db.schema_drift().await.unwrap().is_empty());
+
+        let pool = db.sqlite_pool();
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+             SELECT 29991231000001, 'from the future', TRUE, checksum, 0 FROM _sqlx_migrations LIMIT 1",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query("UPDATE _sqlx_migrations SET checksum = x'00' WHERE version = 20241218000001")
+            .execute(pool)
+            .await
+            .unwrap();
+
+        let drift = db.schema_drift().await.unwrap();
+        assert!(!drift.is_behind());
+        assert_eq!(drift.unknown, [29991231000001]);
+        assert_eq!(drift.modified, [20241218000001]);
+        let diagnostic = drift.to_string();
+        assert!(diagnostic.contains("ahead of this build"), "{}", diagnostic);
+        assert!(
+            diagnostic.contains("20241218000001 were edited"),
+            "{}",
+            diagnostic
+        );
+    }
+
+    #[tokio::test]
+    async fn counts_waits_for_an_exhausted_pool() {
+        let settings = PoolSettings {
+            max_connections: 1,
+            ..Default::default()
+        };
+        let path = std::env::temp_dir().join(format!("pool-{}.db", uuid::Uuid::new_v4()));
+        let db = DatabasePool::new(&format!("sqlite://{}", path.display()), settings)
+            .await
+            .unwrap();
+        db.probe().await.unwrap();
+        assert_eq!(db.stats().acquire_latency.unwrap().samples, 1);
+
+        let held = db.sqlite_pool().acquire().await.unwrap();
+        assert_eq!(db.stats().active(), 1);
+        db.timed("wait", async {}).await;
+        drop(held);
+        assert_eq!(db.stats().waits, 1);
+    }
 }
@@ -0,0 +1,125 @@
+//! PostgreSQL implementation of DeliveryZoneRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::application::ports::DeliveryZoneRepository;
+use crate::domain::delivery::DeliveryZone;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::TenantId;
+use crate::domain::store::GeoPoint;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for DeliveryZone
+struct DeliveryZoneRow {
+    id: Uuid,
+    tenant_id: String,
+    name: String,
+    boundary: Value,
+    fee: f64,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<DeliveryZoneRow> for DeliveryZone {
+    type Error = AppError;
+
+    fn try_from(row: DeliveryZoneRow) -> Result<Self, Self::Error> {
+        let corners: Vec<(f64, f64)> = serde_json::from_value(row.boundary).map_err(|e| {
+            AppError::internal(format!(
+                "Invalid boundary of delivery zone {}: {}",
+                row.id, e
+            ))
+        })?;
+        let boundary = corners
+            .into_iter()
+            .map(|(latitude, longitude)| GeoPoint::new(latitude, longitude))
+            .collect::<DomainResult<_>>()?;
+
+        Ok(DeliveryZone::from_persistence(
+            row.id,
+            TenantId::new(row.tenant_id)?,
+            row.name,
+            boundary,
+            row.fee,
+            row.created_at,
+        ))
+    }
+}
+
+/// Boundary as stored: an array of `[latitude, longitude]` corners
+fn boundary_json(zone: &DeliveryZone) -> Value {
+    zone.boundary()
+        .iter()
+        .map(|point| serde_json::json!([point.latitude(), point.longitude()]))
+        .collect()
+}
+
+/// PostgreSQL implementation of DeliveryZoneRepository
+pub struct PostgresDeliveryZoneRepository {
+    db: DatabasePool,
+}
+
+impl PostgresDeliveryZoneRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DeliveryZoneRepository for PostgresDeliveryZoneRepository {
+    async fn create(&self, zone: &DeliveryZone) -> DomainResult<DeliveryZone> {
+        let statement = sqlx::query_as!(
+            DeliveryZoneRow,
+            r#"
+            INSERT INTO delivery_zones (id, tenant_id, name, boundary, fee, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, tenant_id, name, boundary, fee, created_at
+            "#,
+            zone.id(),
+            zone.tenant_id().as_str(),
+            zone.name(),
+            boundary_json(zone),
+            zone.fee(),
+            zone.created_at()
+        )
+        .fetch_one(self.db.pool());
+        let row = self.db.timed("delivery_zones.create", statement).await?;
+
+        row.try_into()
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<DeliveryZone>> {
+        let rows = self
+            .db
+            .read("delivery_zones.find_all", |pool| {
+                sqlx::query_as!(
+                    DeliveryZoneRow,
+                    r#"
+                    SELECT id, tenant_id, name, boundary, fee, created_at
+                    FROM delivery_zones
+                    WHERE tenant_id = $1
+                    ORDER BY name, id
+                    "#,
+                    tenant.as_str()
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter().map(DeliveryZone::try_from).collect()
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let statement = sqlx::query!(
+            "DELETE FROM delivery_zones WHERE tenant_id = $1 AND id = $2",
+            tenant.as_str(),
+            id
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("delivery_zones.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,76 @@
+//! PostgreSQL implementation of FeatureFlagRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::application::ports::FeatureFlagRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::feature_flag::FeatureFlag;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for FeatureFlag
+#[derive(Debug)]
+struct FeatureFlagRow {
+    key: String,
+    enabled: bool,
+    description: Option<String>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<FeatureFlagRow> for FeatureFlag {
+    fn from(row: FeatureFlagRow) -> Self {
+        FeatureFlag::from_persistence(row.key, row.enabled, row.description, row.updated_at)
+    }
+}
+
+/// PostgreSQL implementation of FeatureFlagRepository
+pub struct PostgresFeatureFlagRepository {
+    db: DatabasePool,
+}
+
+impl PostgresFeatureFlagRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl FeatureFlagRepository for PostgresFeatureFlagRepository {
+    async fn find_all(&self) -> DomainResult<Vec<FeatureFlag>> {
+        let rows = self
+            .db
+            .read("feature_flags.find_all", |pool| {
+                sqlx::query_as!(
+                    FeatureFlagRow,
+                    "SELECT key, enabled, description, updated_at FROM feature_flags ORDER BY key"
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        Ok(rows.into_iter().map(FeatureFlag::from).collect())
+    }
+
+    async fn upsert(&self, flag: &FeatureFlag) -> DomainResult<FeatureFlag> {
+        let statement = sqlx::query_as!(
+            FeatureFlagRow,
+            r#"
+            INSERT INTO feature_flags (key, enabled, description, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (key) DO UPDATE
+            SET enabled = EXCLUDED.enabled,
+                description = COALESCE(EXCLUDED.description, feature_flags.description),
+                updated_at = EXCLUDED.updated_at
+            RETURNING key, enabled, description, updated_at
+            "#,
+            flag.key(),
+            flag.enabled(),
+            flag.description(),
+            flag.updated_at()
+        )
+        .fetch_one(self.db.pool());
+        let row = self.db.timed("feature_flags.upsert", statement).await?;
+
+        Ok(row.into())
+    }
+}
@@ -0,0 +1,145 @@
+//! PostgreSQL flower history
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::application::ports::FlowerHistory;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::FlowerChange;
+use crate::domain::shared::{Pagination, TenantId};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for FlowerChange
+struct FlowerChangeRow {
+    id: Uuid,
+    tenant_id: String,
+    flower_id: Uuid,
+    field: String,
+    old_value: Value,
+    new_value: Value,
+    actor: String,
+    changed_at: DateTime<Utc>,
+}
+
+impl TryFrom<FlowerChangeRow> for FlowerChange {
+    type Error = AppError;
+
+    fn try_from(row: FlowerChangeRow) -> Result<Self, Self::Error> {
+        Ok(FlowerChange::from_persistence(
+            row.id,
+            TenantId::new(row.tenant_id)?,
+            row.flower_id,
+            row.field,
+            row.old_value,
+            row.new_value,
+            row.actor,
+            row.changed_at,
+        ))
+    }
+}
+
+/// Appends flower changes to the `flower_changes` table and reads them back
+pub struct PostgresFlowerHistory {
+    db: DatabasePool,
+}
+
+impl PostgresFlowerHistory {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+
+    /// Record changes on a connection, such as an open transaction
+    pub(crate) async fn record_in(
+        &self,
+        connection: &mut PgConnection,
+        changes: &[FlowerChange],
+    ) -> DomainResult<()> {
+        for change in changes {
+            let statement = sqlx::query!(
+                r#"
+                INSERT INTO flower_changes (id, tenant_id, flower_id, field, old_value, new_value, actor, changed_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+                change.id(),
+                change.tenant_id().as_str(),
+                change.flower_id(),
+                change.field(),
+                change.old_value(),
+                change.new_value(),
+                change.actor(),
+                change.changed_at()
+            )
+            .execute(&mut *connection);
+            self.db.timed("flower_changes.record", statement).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FlowerHistory for PostgresFlowerHistory {
+    async fn find(
+        &self,
+        tenant: &TenantId,
+        flower_id: Uuid,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<FlowerChange>> {
+        let rows = self
+            .db
+            .read("flower_changes.find", |pool| {
+                sqlx::query_as!(
+                    FlowerChangeRow,
+                    r#"
+                    SELECT id, tenant_id, flower_id, field, old_value, new_value, actor, changed_at
+                    FROM flower_changes
+                    WHERE tenant_id = $1 AND flower_id = $2
+                    ORDER BY changed_at, id
+                    LIMIT $3 OFFSET $4
+                    "#,
+                    tenant.as_str(),
+                    flower_id,
+                    pagination.limit(),
+                    pagination.offset()
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter().map(FlowerChange::try_from).collect()
+    }
+
+    async fn count(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<i64> {
+        let count = self
+            .db
+            .read("flower_changes.count", |pool| {
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT COUNT(*) AS "count!" FROM flower_changes
+                    WHERE tenant_id = $1 AND flower_id = $2
+                    "#,
+                    tenant.as_str(),
+                    flower_id
+                )
+                .fetch_one(pool)
+            })
+            .await?;
+
+        Ok(count)
+    }
+
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64> {
+        let statement = sqlx::query!(
+            "DELETE FROM flower_changes WHERE tenant_id = $1 AND flower_id = $2",
+            tenant.as_str(),
+            flower_id
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("flower_changes.delete", statement).await?;
+
+        Ok(result.rows_affected())
+    }
+}
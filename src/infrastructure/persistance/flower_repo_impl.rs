@@ -2,24 +2,41 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::FromRow;
+use sqlx::postgres::PgArguments;
+use sqlx::types::Json;
+use sqlx::{Arguments, Encode, FromRow, PgExecutor, Postgres, Type};
 use uuid::Uuid;
 
-use crate::application::ports::FlowerRepository;
+use crate::application::ports::{FlowerFacets, FlowerRepository};
 use crate::domain::errors::{AppError, DomainResult};
-use crate::domain::flower::Flower;
-use crate::domain::shared::Pagination;
+use crate::domain::flower::{
+    Flower, FlowerAttributes, FlowerColor, FlowerDescription, FlowerError, FlowerMetadata,
+    FlowerName, Price, Sku, StockQuantity, UnitOfMeasure,
+};
+use crate::domain::shared::{Entity, Pagination, TenantId};
 use crate::infrastructure::persistance::DatabasePool;
 
+/// Unique index enforcing case-insensitive flower names within a tenant
+const NAME_UNIQUE_CONSTRAINT: &str = "flowers_tenant_name_lower_unique";
+
+/// Unique index enforcing SKUs within a tenant
+const SKU_UNIQUE_CONSTRAINT: &str = "flowers_tenant_sku_unique";
+
 /// Database row representation for Flower
 #[derive(Debug, FromRow)]
 struct FlowerRow {
     id: Uuid,
+    tenant_id: String,
     name: String,
     color: String,
     description: Option<String>,
     price: f64,
     stock: i32,
+    sku: Option<String>,
+    attributes: Json<FlowerAttributes>,
+    metadata: Json<FlowerMetadata>,
+    unit: String,
+    stems_per_unit: i32,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -30,17 +47,88 @@ impl TryFrom<FlowerRow> for Flower {
     fn try_from(row: FlowerRow) -> Result<Self, Self::Error> {
         Flower::from_persistence(
             row.id,
-            row.name,
-            row.color,
-            row.description,
-            row.price,
-            row.stock,
+            TenantId::new(row.tenant_id)?,
+            FlowerName::new(row.name)?,
+            row.color.parse()?,
+            row.description
+                .and_then(FlowerDescription::from_persistence),
+            Price::new(row.price)?,
+            StockQuantity::new(row.stock)?,
+            UnitOfMeasure::new(&row.unit, Some(row.stems_per_unit))?,
+            row.sku.map(Sku::new).transpose()?.flatten(),
+            row.attributes.0,
+            row.metadata.0,
             row.created_at,
             row.updated_at,
         )
     }
 }
 
+/// `WHERE` clause of a flower search with only the criteria that are set,
+/// and the arguments it binds
+///
+/// Optional criteria are left out of the SQL rather than written as
+/// `$2 IS NULL OR ...`, which keeps the planner from using the trigram and
+/// color indexes once it settles on a generic plan for the statement.
+struct SearchFilter {
+    clause: String,
+    arguments: PgArguments,
+    parameters: usize,
+}
+
+impl SearchFilter {
+    fn new(
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+    ) -> Self {
+        let mut filter = Self {
+            clause: String::new(),
+            arguments: PgArguments::default(),
+            parameters: 0,
+        };
+        filter.push("tenant_id =", tenant.as_str().to_string());
+        if let Some(query) = query {
+            filter.push("LOWER(name) LIKE", format!("%{}%", query.to_lowercase()));
+        }
+        if let Some(color) = color {
+            filter.push("color =", color.as_str().to_string());
+        }
+        if let Some(attributes) = attributes {
+            filter.push("attributes @>", Json(attributes.clone()));
+        }
+        if let Some(metadata) = metadata {
+            filter.push("metadata @>", Json(metadata.clone()));
+        }
+        filter
+    }
+
+    fn push<T>(&mut self, condition: &str, value: T)
+    where
+        T: for<'q> Encode<'q, Postgres> + Type<Postgres> + Send,
+    {
+        bind(&mut self.arguments, value);
+        self.parameters += 1;
+        let keyword = if self.parameters == 1 {
+            "WHERE"
+        } else {
+            " AND"
+        };
+        self.clause += &format!("{} {} ${}", keyword, condition, self.parameters);
+    }
+}
+
+fn bind<T>(arguments: &mut PgArguments, value: T)
+where
+    T: for<'q> Encode<'q, Postgres> + Type<Postgres> + Send,
+{
+    arguments
+        .add(value)
+        .expect("text, integer and JSON arguments always encode");
+}
+
 /// PostgreSQL implementation of FlowerRepository
 pub struct PostgresFlowerRepository {
     db: DatabasePool,
@@ -50,156 +138,486 @@ impl PostgresFlowerRepository {
     pub fn new(db: DatabasePool) -> Self {
         Self { db }
     }
-}
 
-#[async_trait]
-impl FlowerRepository for PostgresFlowerRepository {
-    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Flower>> {
-        let result = sqlx::query_as::<_, FlowerRow>(
+    /// Translate a unique name or SKU violation into a conflict carrying the
+    /// existing ID
+    async fn map_write_error(&self, error: sqlx::Error, flower: &Flower) -> AppError {
+        let constraint = match &error {
+            sqlx::Error::Database(db_error) if db_error.is_unique_violation() => {
+                db_error.constraint()
+            }
+            _ => None,
+        };
+
+        match (constraint, flower.sku()) {
+            (Some(NAME_UNIQUE_CONSTRAINT), _) => {
+                let existing_id = sqlx::query_scalar!(
+                    "SELECT id FROM flowers WHERE tenant_id = $1 AND LOWER(name) = LOWER($2)",
+                    flower.tenant_id().as_str(),
+                    flower.name()
+                )
+                .fetch_optional(self.db.pool())
+                .await
+                .ok()
+                .flatten();
+
+                FlowerError::name_taken(flower.name(), existing_id)
+            }
+            (Some(SKU_UNIQUE_CONSTRAINT), Some(sku)) => {
+                let existing_id = sqlx::query_scalar!(
+                    "SELECT id FROM flowers WHERE tenant_id = $1 AND sku = $2",
+                    flower.tenant_id().as_str(),
+                    sku
+                )
+                .fetch_optional(self.db.pool())
+                .await
+                .ok()
+                .flatten();
+
+                FlowerError::sku_taken(sku, existing_id)
+            }
+            _ => error.into(),
+        }
+    }
+
+    /// Load a flower and lock its row until the surrounding transaction ends
+    pub(crate) async fn lock_in<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<Option<Flower>> {
+        let statement = sqlx::query_as!(
+            FlowerRow,
             r#"
-            SELECT id, name, color, description, price, stock, created_at, updated_at
+            SELECT id, tenant_id, name, color, description, price, stock, sku,
+                   attributes AS "attributes: Json<FlowerAttributes>",
+                   metadata AS "metadata: Json<FlowerMetadata>", unit, stems_per_unit,
+                   created_at, updated_at
             FROM flowers
-            WHERE id = $1
+            WHERE tenant_id = $1 AND id = $2
+            FOR UPDATE
             "#,
+            tenant.as_str(),
+            id
         )
-        .bind(id)
-        .fetch_optional(self.db.pool())
-        .await?;
+        .fetch_optional(executor);
+        let result = self.db.timed("flowers.lock", statement).await?;
 
-        match result {
-            Some(row) => Ok(Some(row.try_into()?)),
-            None => Ok(None),
-        }
+        result.map(Flower::try_from).transpose()
     }
 
-    async fn find_all(&self, pagination: &Pagination) -> DomainResult<Vec<Flower>> {
-        let rows = sqlx::query_as::<_, FlowerRow>(
+    /// Lock the flowers of a tenant matching every given criterion, in ID
+    /// order so concurrent batches cannot deadlock
+    pub(crate) async fn lock_all_in<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        tenant: &TenantId,
+        color: Option<FlowerColor>,
+        ids: Option<&[Uuid]>,
+    ) -> DomainResult<Vec<Flower>> {
+        let statement = sqlx::query_as!(
+            FlowerRow,
             r#"
-            SELECT id, name, color, description, price, stock, created_at, updated_at
+            SELECT id, tenant_id, name, color, description, price, stock, sku,
+                   attributes AS "attributes: Json<FlowerAttributes>",
+                   metadata AS "metadata: Json<FlowerMetadata>", unit, stems_per_unit,
+                   created_at, updated_at
             FROM flowers
-            ORDER BY created_at DESC
-            LIMIT $1 OFFSET $2
+            WHERE tenant_id = $1
+              AND ($2::text IS NULL OR color = $2)
+              AND ($3::uuid[] IS NULL OR id = ANY($3))
+            ORDER BY id
+            FOR UPDATE
             "#,
+            tenant.as_str(),
+            color.map(|c| c.as_str()),
+            ids
         )
-        .bind(pagination.limit())
-        .bind(pagination.offset())
-        .fetch_all(self.db.pool())
-        .await?;
+        .fetch_all(executor);
+        let rows = self.db.timed("flowers.lock_all", statement).await?;
+
+        rows.into_iter().map(Flower::try_from).collect()
+    }
+
+    /// Insert a flower through any executor, such as an open transaction
+    pub(crate) async fn create_in<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        flower: &Flower,
+    ) -> DomainResult<Flower> {
+        let statement = sqlx::query_as!(
+            FlowerRow,
+            r#"
+            INSERT INTO flowers (id, tenant_id, name, color, description, price, stock, sku,
+                                 attributes, metadata, unit, stems_per_unit, created_at,
+                                 updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            RETURNING id, tenant_id, name, color, description, price, stock, sku,
+                   attributes AS "attributes: Json<FlowerAttributes>",
+                   metadata AS "metadata: Json<FlowerMetadata>", unit, stems_per_unit,
+                   created_at, updated_at
+            "#,
+            flower.id(),
+            flower.tenant_id().as_str(),
+            flower.name(),
+            flower.color().as_str(),
+            flower.description(),
+            flower.price(),
+            flower.stock(),
+            flower.sku(),
+            Json(flower.attributes()) as _,
+            Json(flower.metadata()) as _,
+            flower.unit().as_str(),
+            flower.unit().stems(),
+            flower.created_at(),
+            flower.updated_at()
+        )
+        .fetch_one(executor);
+        let row = self.db.timed("flowers.create", statement).await;
+
+        match row {
+            Ok(row) => row.try_into(),
+            Err(e) => Err(self.map_write_error(e, flower).await),
+        }
+    }
+
+    /// Update a flower through any executor, such as an open transaction
+    pub(crate) async fn update_in<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        flower: &Flower,
+    ) -> DomainResult<Flower> {
+        let statement = sqlx::query_as!(
+            FlowerRow,
+            r#"
+            UPDATE flowers
+            SET name = $2, color = $3, description = $4, price = $5, stock = $6, updated_at = $7,
+                sku = $9, attributes = $10, metadata = $11, unit = $12, stems_per_unit = $13
+            WHERE id = $1 AND tenant_id = $8
+            RETURNING id, tenant_id, name, color, description, price, stock, sku,
+                   attributes AS "attributes: Json<FlowerAttributes>",
+                   metadata AS "metadata: Json<FlowerMetadata>", unit, stems_per_unit,
+                   created_at, updated_at
+            "#,
+            flower.id(),
+            flower.name(),
+            flower.color().as_str(),
+            flower.description(),
+            flower.price(),
+            flower.stock(),
+            flower.updated_at(),
+            flower.tenant_id().as_str(),
+            flower.sku(),
+            Json(flower.attributes()) as _,
+            Json(flower.metadata()) as _,
+            flower.unit().as_str(),
+            flower.unit().stems()
+        )
+        .fetch_one(executor);
+        let row = self.db.timed("flowers.update", statement).await;
+
+        match row {
+            Ok(row) => row.try_into(),
+            Err(e) => Err(self.map_write_error(e, flower).await),
+        }
+    }
+}
+
+#[async_trait]
+impl FlowerRepository for PostgresFlowerRepository {
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        let result = self
+            .db
+            .read("flowers.find_by_id", |pool| {
+                sqlx::query_as!(
+                    FlowerRow,
+                    r#"
+                    SELECT id, tenant_id, name, color, description, price, stock, sku,
+                   attributes AS "attributes: Json<FlowerAttributes>",
+                   metadata AS "metadata: Json<FlowerMetadata>", unit, stems_per_unit,
+                   created_at, updated_at
+                    FROM flowers
+                    WHERE tenant_id = $1 AND id = $2
+                    "#,
+                    tenant.as_str(),
+                    id
+                )
+                .fetch_optional(pool)
+            })
+            .await?;
+
+        match result {
+            Some(row) => Ok(Some(row.try_into()?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_sku(&self, tenant: &TenantId, sku: &str) -> DomainResult<Option<Flower>> {
+        let result = self
+            .db
+            .read("flowers.find_by_sku", |pool| {
+                sqlx::query_as!(
+                    FlowerRow,
+                    r#"
+                    SELECT id, tenant_id, name, color, description, price, stock, sku,
+                   attributes AS "attributes: Json<FlowerAttributes>",
+                   metadata AS "metadata: Json<FlowerMetadata>", unit, stems_per_unit,
+                   created_at, updated_at
+                    FROM flowers
+                    WHERE tenant_id = $1 AND sku = $2
+                    "#,
+                    tenant.as_str(),
+                    sku
+                )
+                .fetch_optional(pool)
+            })
+            .await?;
+
+        match result {
+            Some(row) => Ok(Some(row.try_into()?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(
+        &self,
+        tenant: &TenantId,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>> {
+        let rows = self
+            .db
+            .read("flowers.find_all", |pool| {
+                sqlx::query_as!(
+                    FlowerRow,
+                    r#"
+                    SELECT id, tenant_id, name, color, description, price, stock, sku,
+                   attributes AS "attributes: Json<FlowerAttributes>",
+                   metadata AS "metadata: Json<FlowerMetadata>", unit, stems_per_unit,
+                   created_at, updated_at
+                    FROM flowers
+                    WHERE tenant_id = $1
+                    ORDER BY created_at DESC, id DESC
+                    LIMIT $2 OFFSET $3
+                    "#,
+                    tenant.as_str(),
+                    pagination.limit(),
+                    pagination.offset()
+                )
+                .fetch_all(pool)
+            })
+            .await?;
 
         rows.into_iter().map(|row| row.try_into()).collect()
     }
 
-    async fn count(&self) -> DomainResult<i64> {
-        let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM flowers")
-            .fetch_one(self.db.pool())
+    async fn count(&self, tenant: &TenantId) -> DomainResult<i64> {
+        let count = self
+            .db
+            .read("flowers.count", |pool| {
+                sqlx::query_scalar!(
+                    r#"SELECT COUNT(*) AS "count!" FROM flowers WHERE tenant_id = $1"#,
+                    tenant.as_str()
+                )
+                .fetch_one(pool)
+            })
             .await?;
 
-        Ok(result.0)
+        Ok(count)
     }
 
     async fn search(
         &self,
+        tenant: &TenantId,
         query: Option<&str>,
-        color: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
         pagination: &Pagination,
     ) -> DomainResult<Vec<Flower>> {
-        let search_pattern = query.map(|q| format!("%{}%", q.to_lowercase()));
-        let color_pattern = color.map(|c| c.to_lowercase());
-
-        let rows = sqlx::query_as::<_, FlowerRow>(
+        let filter = SearchFilter::new(tenant, query, color, attributes, metadata);
+        let mut arguments = filter.arguments.clone();
+        bind(&mut arguments, pagination.limit());
+        bind(&mut arguments, pagination.offset());
+        let sql = format!(
             r#"
-            SELECT id, name, color, description, price, stock, created_at, updated_at
+            SELECT id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
             FROM flowers
-            WHERE ($1::text IS NULL OR LOWER(name) LIKE $1)
-              AND ($2::text IS NULL OR LOWER(color) = $2)
-            ORDER BY created_at DESC
-            LIMIT $3 OFFSET $4
+            {}
+            ORDER BY created_at DESC, id DESC
+            LIMIT ${} OFFSET ${}
             "#,
-        )
-        .bind(&search_pattern)
-        .bind(&color_pattern)
-        .bind(pagination.limit())
-        .bind(pagination.offset())
-        .fetch_all(self.db.pool())
-        .await?;
+            filter.clause,
+            filter.parameters + 1,
+            filter.parameters + 2
+        );
+
+        let rows = self
+            .db
+            .read("flowers.search", |pool| {
+                sqlx::query_as_with::<_, FlowerRow, _>(&sql, arguments.clone()).fetch_all(pool)
+            })
+            .await?;
 
         rows.into_iter().map(|row| row.try_into()).collect()
     }
 
-    async fn count_search(&self, query: Option<&str>, color: Option<&str>) -> DomainResult<i64> {
-        let search_pattern = query.map(|q| format!("%{}%", q.to_lowercase()));
-        let color_pattern = color.map(|c| c.to_lowercase());
+    async fn count_search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<i64> {
+        let filter = SearchFilter::new(tenant, query, color, attributes, metadata);
+        let sql = format!("SELECT COUNT(*) FROM flowers {}", filter.clause);
 
-        let result: (i64,) = sqlx::query_as(
-            r#"
-            SELECT COUNT(*)
-            FROM flowers
-            WHERE ($1::text IS NULL OR LOWER(name) LIKE $1)
-              AND ($2::text IS NULL OR LOWER(color) = $2)
-            "#,
-        )
-        .bind(&search_pattern)
-        .bind(&color_pattern)
-        .fetch_one(self.db.pool())
-        .await?;
+        let count = self
+            .db
+            .read("flowers.count_search", |pool| {
+                sqlx::query_scalar_with(&sql, filter.arguments.clone()).fetch_one(pool)
+            })
+            .await?;
 
-        Ok(result.0)
+        Ok(count)
     }
 
-    async fn create(&self, flower: &Flower) -> DomainResult<Flower> {
-        use crate::domain::shared::Entity;
+    async fn estimate_count(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<Option<i64>> {
+        // The planner's row estimate for the same filter as `count_search`,
+        // derived from `pg_class.reltuples` and the column statistics
+        let filter = SearchFilter::new(tenant, query, color, attributes, metadata);
+        let sql = format!(
+            "EXPLAIN (FORMAT JSON) SELECT 1 FROM flowers {}",
+            filter.clause
+        );
 
-        let row = sqlx::query_as::<_, FlowerRow>(
-            r#"
-            INSERT INTO flowers (id, name, color, description, price, stock, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-            RETURNING id, name, color, description, price, stock, created_at, updated_at
-            "#,
-        )
-        .bind(flower.id())
-        .bind(flower.name())
-        .bind(flower.color())
-        .bind(flower.description())
-        .bind(flower.price())
-        .bind(flower.stock())
-        .bind(flower.created_at())
-        .bind(flower.updated_at())
-        .fetch_one(self.db.pool())
-        .await?;
+        let plan: Json<serde_json::Value> = self
+            .db
+            .read("flowers.estimate_count", |pool| {
+                sqlx::query_scalar_with(&sql, filter.arguments.clone()).fetch_one(pool)
+            })
+            .await?;
 
-        row.try_into()
+        Ok(plan.0[0]["Plan"]["Plan Rows"]
+            .as_f64()
+            .map(|rows| rows as i64))
+    }
+
+    async fn facets(&self, tenant: &TenantId) -> DomainResult<FlowerFacets> {
+        let colors = self
+            .db
+            .read("flowers.facets.colors", |pool| {
+                sqlx::query_scalar!(
+                    "SELECT DISTINCT color FROM flowers WHERE tenant_id = $1",
+                    tenant.as_str()
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+        let prices = self
+            .db
+            .read("flowers.facets.prices", |pool| {
+                sqlx::query!(
+                    r#"
+                    SELECT MIN(price) AS min_price, MAX(price) AS max_price
+                    FROM flowers WHERE tenant_id = $1
+                    "#,
+                    tenant.as_str()
+                )
+                .fetch_one(pool)
+            })
+            .await?;
+
+        Ok(FlowerFacets {
+            colors: colors
+                .iter()
+                .map(|color| color.parse())
+                .collect::<Result<_, _>>()?,
+            min_price: prices.min_price,
+            max_price: prices.max_price,
+        })
+    }
+
+    async fn create(&self, flower: &Flower) -> DomainResult<Flower> {
+        self.create_in(self.db.pool(), flower).await
     }
 
     async fn update(&self, flower: &Flower) -> DomainResult<Flower> {
-        use crate::domain::shared::Entity;
+        self.update_in(self.db.pool(), flower).await
+    }
 
-        let row = sqlx::query_as::<_, FlowerRow>(
+    async fn find_low_stock(&self, threshold: i32) -> DomainResult<Vec<Flower>> {
+        let rows = self
+            .db
+            .read("flowers.find_low_stock", |pool| {
+                sqlx::query_as!(
+                    FlowerRow,
+                    r#"
+                    SELECT id, tenant_id, name, color, description, price, stock, sku,
+                   attributes AS "attributes: Json<FlowerAttributes>",
+                   metadata AS "metadata: Json<FlowerMetadata>", unit, stems_per_unit,
+                   created_at, updated_at
+                    FROM flowers
+                    WHERE stock <= $1
+                    ORDER BY tenant_id, stock, name
+                    "#,
+                    threshold
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter().map(|row| row.try_into()).collect()
+    }
+
+    async fn find_created_since(
+        &self,
+        tenant: &TenantId,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<Flower>> {
+        // From the primary: flowers a lagging replica has yet to see would
+        // fall behind a `since` that moved past them
+        let statement = sqlx::query_as!(
+            FlowerRow,
             r#"
-            UPDATE flowers
-            SET name = $2, color = $3, description = $4, price = $5, stock = $6, updated_at = $7
-            WHERE id = $1
-            RETURNING id, name, color, description, price, stock, created_at, updated_at
+            SELECT id, tenant_id, name, color, description, price, stock, sku,
+                   attributes AS "attributes: Json<FlowerAttributes>",
+                   metadata AS "metadata: Json<FlowerMetadata>", unit, stems_per_unit,
+                   created_at, updated_at
+            FROM flowers
+            WHERE tenant_id = $1 AND created_at > $2
+            ORDER BY created_at, id
             "#,
+            tenant.as_str(),
+            since
         )
-        .bind(flower.id())
-        .bind(flower.name())
-        .bind(flower.color())
-        .bind(flower.description())
-        .bind(flower.price())
-        .bind(flower.stock())
-        .bind(flower.updated_at())
-        .fetch_one(self.db.pool())
-        .await?;
-
-        row.try_into()
-    }
-
-    async fn delete(&self, id: Uuid) -> DomainResult<()> {
-        sqlx::query("DELETE FROM flowers WHERE id = $1")
-            .bind(id)
-            .execute(self.db.pool())
+        .fetch_all(self.db.pool());
+        let rows = self
+            .db
+            .timed("flowers.find_created_since", statement)
             .await?;
 
+        rows.into_iter().map(|row| row.try_into()).collect()
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
+        let statement = sqlx::query!(
+            "DELETE FROM flowers WHERE tenant_id = $1 AND id = $2",
+            tenant.as_str(),
+            id
+        )
+        .execute(self.db.pool());
+        self.db.timed("flowers.delete", statement).await?;
+
         Ok(())
     }
 }
@@ -0,0 +1,191 @@
+//! PostgreSQL implementation of FlowerViewStore
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::{FlowerViewStore, RecentView, ViewCount};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// PostgreSQL implementation of FlowerViewStore
+pub struct PostgresFlowerViewStore {
+    db: DatabasePool,
+}
+
+impl PostgresFlowerViewStore {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl FlowerViewStore for PostgresFlowerViewStore {
+    async fn add(&self, counts: &[ViewCount]) -> DomainResult<()> {
+        let tenants: Vec<String> = counts
+            .iter()
+            .map(|c| c.tenant.as_str().to_string())
+            .collect();
+        let flower_ids: Vec<Uuid> = counts.iter().map(|c| c.flower_id).collect();
+        let days: Vec<NaiveDate> = counts.iter().map(|c| c.day).collect();
+        let views: Vec<i64> = counts.iter().map(|c| c.views).collect();
+
+        // One statement for the whole batch; each key appears once per flush
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO flower_views (tenant_id, flower_id, day, views)
+            SELECT * FROM UNNEST($1::text[], $2::uuid[], $3::date[], $4::bigint[])
+            ON CONFLICT (tenant_id, flower_id, day)
+            DO UPDATE SET views = flower_views.views + EXCLUDED.views
+            "#,
+            &tenants,
+            &flower_ids,
+            &days,
+            &views
+        )
+        .execute(self.db.pool());
+        self.db.timed("flower_views.add", statement).await?;
+
+        Ok(())
+    }
+
+    async fn most_viewed(
+        &self,
+        tenant: &TenantId,
+        since: NaiveDate,
+        limit: i64,
+    ) -> DomainResult<Vec<(Uuid, i64)>> {
+        let rows = self
+            .db
+            .read("flower_views.most_viewed", |pool| {
+                sqlx::query!(
+                    r#"
+                    SELECT v.flower_id, SUM(v.views)::bigint AS "views!"
+                    FROM flower_views v
+                    JOIN flowers f ON f.id = v.flower_id AND f.tenant_id = v.tenant_id
+                    WHERE v.tenant_id = $1 AND v.day >= $2
+                    GROUP BY v.flower_id
+                    ORDER BY 2 DESC, v.flower_id
+                    LIMIT $3
+                    "#,
+                    tenant.as_str(),
+                    since,
+                    limit
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.flower_id, row.views))
+            .collect())
+    }
+
+    async fn add_recent(&self, views: &[RecentView], keep: i64) -> DomainResult<()> {
+        let tenants: Vec<String> = views
+            .iter()
+            .map(|v| v.tenant.as_str().to_string())
+            .collect();
+        let viewers: Vec<String> = views.iter().map(|v| v.viewer.clone()).collect();
+        let flower_ids: Vec<Uuid> = views.iter().map(|v| v.flower_id).collect();
+        let viewed_at: Vec<DateTime<Utc>> = views.iter().map(|v| v.viewed_at).collect();
+
+        let mut transaction = self.db.pool().begin().await?;
+        // Another instance may have flushed a later view of the same flower
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO recent_views (tenant_id, viewer, flower_id, viewed_at)
+            SELECT * FROM UNNEST($1::text[], $2::text[], $3::uuid[], $4::timestamptz[])
+            ON CONFLICT (tenant_id, viewer, flower_id)
+            DO UPDATE SET viewed_at = GREATEST(recent_views.viewed_at, EXCLUDED.viewed_at)
+            "#,
+            &tenants,
+            &viewers,
+            &flower_ids,
+            &viewed_at
+        )
+        .execute(&mut *transaction);
+        self.db.timed("recent_views.add", statement).await?;
+
+        let statement = sqlx::query!(
+            r#"
+            DELETE FROM recent_views r
+            USING (
+                SELECT tenant_id, viewer, flower_id,
+                       ROW_NUMBER() OVER (
+                           PARTITION BY tenant_id, viewer
+                           ORDER BY viewed_at DESC, flower_id
+                       ) AS position
+                FROM recent_views
+                WHERE (tenant_id, viewer) IN (SELECT * FROM UNNEST($1::text[], $2::text[]))
+            ) ranked
+            WHERE r.tenant_id = ranked.tenant_id
+              AND r.viewer = ranked.viewer
+              AND r.flower_id = ranked.flower_id
+              AND ranked.position > $3
+            "#,
+            &tenants,
+            &viewers,
+            keep
+        )
+        .execute(&mut *transaction);
+        self.db.timed("recent_views.trim", statement).await?;
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn recently_viewed(
+        &self,
+        tenant: &TenantId,
+        viewer: &str,
+        limit: i64,
+    ) -> DomainResult<Vec<(Uuid, DateTime<Utc>)>> {
+        let rows = self
+            .db
+            .read("recent_views.find", |pool| {
+                sqlx::query!(
+                    r#"
+                    SELECT r.flower_id, r.viewed_at
+                    FROM recent_views r
+                    JOIN flowers f ON f.id = r.flower_id AND f.tenant_id = r.tenant_id
+                    WHERE r.tenant_id = $1 AND r.viewer = $2
+                    ORDER BY r.viewed_at DESC, r.flower_id
+                    LIMIT $3
+                    "#,
+                    tenant.as_str(),
+                    viewer,
+                    limit
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.flower_id, row.viewed_at))
+            .collect())
+    }
+
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64> {
+        let statement = sqlx::query!(
+            "DELETE FROM recent_views WHERE tenant_id = $1 AND flower_id = $2",
+            tenant.as_str(),
+            flower_id
+        )
+        .execute(self.db.pool());
+        self.db.timed("recent_views.delete", statement).await?;
+
+        let statement = sqlx::query!(
+            "DELETE FROM flower_views WHERE tenant_id = $1 AND flower_id = $2",
+            tenant.as_str(),
+            flower_id
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("flower_views.delete", statement).await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,68 @@
+//! Database Health Indicators
+
+use async_trait::async_trait;
+use serde_json::json;
+
+use super::DatabasePool;
+use crate::application::ports::{Health, HealthIndicator};
+
+/// Whether the database answers queries
+pub struct DatabaseHealth(pub DatabasePool);
+
+#[async_trait]
+impl HealthIndicator for DatabaseHealth {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    async fn check(&self) -> Health {
+        match self.0.ping().await {
+            Ok(()) => Health::up(),
+            Err(e) => Health::down(e),
+        }
+    }
+}
+
+/// Whether the schema has every embedded migration applied
+pub struct MigrationsHealth(pub DatabasePool);
+
+#[async_trait]
+impl HealthIndicator for MigrationsHealth {
+    fn name(&self) -> &'static str {
+        "migrations"
+    }
+
+    async fn check(&self) -> Health {
+        match self.0.pending_migrations().await {
+            Ok(pending) if pending.is_empty() => Health::up().with_details(json!({ "pending": 0 })),
+            Ok(pending) => Health::down(format!("migrations pending: {:?}", pending))
+                .with_details(json!({ "pending": pending.len() })),
+            Err(e) => Health::down(e),
+        }
+    }
+}
+
+/// Connection pool usage; degraded while every connection is busy
+pub struct PoolHealth(pub DatabasePool);
+
+#[async_trait]
+impl HealthIndicator for PoolHealth {
+    fn name(&self) -> &'static str {
+        "pool"
+    }
+
+    async fn check(&self) -> Health {
+        let stats = self.0.stats();
+        let exhausted = stats.size >= stats.max_connections && stats.idle == 0;
+        let health = if exhausted {
+            Health::degraded()
+        } else {
+            Health::up()
+        };
+        health.with_details(json!({
+            "size": stats.size,
+            "idle": stats.idle,
+            "max_connections": stats.max_connections,
+        }))
+    }
+}
@@ -0,0 +1,48 @@
+//! PostgreSQL implementation of JobTickStore
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::application::ports::JobTickStore;
+use crate::domain::errors::DomainResult;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// PostgreSQL implementation of JobTickStore
+pub struct PostgresJobTickStore {
+    db: DatabasePool,
+}
+
+impl PostgresJobTickStore {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl JobTickStore for PostgresJobTickStore {
+    async fn last_tick(&self, job: &str) -> DomainResult<Option<DateTime<Utc>>> {
+        let statement = sqlx::query_scalar!("SELECT tick FROM job_ticks WHERE job = $1", job)
+            .fetch_optional(self.db.pool());
+        let tick = self.db.timed("job_ticks.last_tick", statement).await?;
+
+        Ok(tick)
+    }
+
+    async fn record(&self, job: &str, tick: DateTime<Utc>) -> DomainResult<()> {
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO job_ticks (job, tick, completed_at)
+            VALUES ($1, $2, NOW())
+            ON CONFLICT (job)
+            DO UPDATE SET tick = EXCLUDED.tick, completed_at = EXCLUDED.completed_at
+            WHERE job_ticks.tick < EXCLUDED.tick
+            "#,
+            job,
+            tick
+        )
+        .execute(self.db.pool());
+        self.db.timed("job_ticks.record", statement).await?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,47 @@
+pub mod advisory_lock;
+pub mod database_dump_impl;
 pub mod db_config;
+pub mod delivery_zone_repo_impl;
+pub mod feature_flag_repo_impl;
+pub mod flower_history_impl;
 pub mod flower_repo_impl;
+pub mod flower_view_store_impl;
+pub mod health;
+pub mod job_tick_store_impl;
+pub mod nonce_store_impl;
+pub mod order_repo_impl;
+pub mod pool_monitor;
+pub mod pricing_rule_repo_impl;
+pub mod query_timing;
+pub mod read_replicas;
+pub mod saved_search_repo_impl;
+pub mod stock_ledger_impl;
+pub mod store_repo_impl;
+pub mod task_queue_impl;
+pub mod unit_of_work_impl;
+pub mod usage_store_impl;
+pub mod webhook_endpoint_repo_impl;
 
-pub use db_config::DatabasePool;
+pub use advisory_lock::PostgresAdvisoryLock;
+pub use database_dump_impl::PostgresDatabaseDump;
+pub use db_config::{
+    DatabasePool, MigrationStatus, PoolSettings, PoolStats, SQLITE_SCHEME, SchemaDrift,
+};
+pub use delivery_zone_repo_impl::PostgresDeliveryZoneRepository;
+pub use feature_flag_repo_impl::PostgresFeatureFlagRepository;
+pub use flower_history_impl::PostgresFlowerHistory;
 pub use flower_repo_impl::PostgresFlowerRepository;
+pub use flower_view_store_impl::PostgresFlowerViewStore;
+pub use health::{DatabaseHealth, MigrationsHealth, PoolHealth};
+pub use job_tick_store_impl::PostgresJobTickStore;
+pub use nonce_store_impl::PostgresNonceStore;
+pub use order_repo_impl::PostgresOrderRepository;
+pub use pool_monitor::{AcquireLatency, PoolProbe};
+pub use pricing_rule_repo_impl::PostgresPricingRuleRepository;
+pub use saved_search_repo_impl::PostgresSavedSearchRepository;
+pub use stock_ledger_impl::PostgresStockLedger;
+pub use store_repo_impl::PostgresStoreRepository;
+pub use task_queue_impl::PostgresTaskQueue;
+pub use unit_of_work_impl::PostgresUnitOfWork;
+pub use usage_store_impl::PostgresUsageStore;
+pub use webhook_endpoint_repo_impl::PostgresWebhookEndpointRepository;
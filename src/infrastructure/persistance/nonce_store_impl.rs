@@ -0,0 +1,55 @@
+//! PostgreSQL implementation of NonceStore
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::application::ports::NonceStore;
+use crate::domain::errors::DomainResult;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// PostgreSQL implementation of NonceStore
+pub struct PostgresNonceStore {
+    db: DatabasePool,
+}
+
+impl PostgresNonceStore {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl NonceStore for PostgresNonceStore {
+    async fn claim(
+        &self,
+        scope: &str,
+        nonce: &str,
+        expires_at: DateTime<Utc>,
+    ) -> DomainResult<bool> {
+        // An expired nonce the purge has not reached yet may be claimed again
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO webhook_nonces (scope, nonce, expires_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (scope, nonce)
+            DO UPDATE SET expires_at = EXCLUDED.expires_at
+            WHERE webhook_nonces.expires_at <= NOW()
+            "#,
+            scope,
+            nonce,
+            expires_at
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("webhook_nonces.claim", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn purge_expired(&self, now: DateTime<Utc>) -> DomainResult<u64> {
+        let statement = sqlx::query!("DELETE FROM webhook_nonces WHERE expires_at <= $1", now)
+            .execute(self.db.pool());
+        let result = self.db.timed("webhook_nonces.purge", statement).await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,299 @@
+//! PostgreSQL implementation of OrderRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{PgConnection, PgExecutor};
+use uuid::Uuid;
+
+use crate::application::ports::OrderRepository;
+use crate::domain::delivery::ShippingRate;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::order::{Order, OrderError, OrderEvent, OrderLine, OrderRefund, OrderStatus};
+use crate::domain::shared::{Entity, TenantId};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for Order
+struct OrderRow {
+    id: Uuid,
+    tenant_id: String,
+    lines: Value,
+    shipping_carrier: Option<String>,
+    shipping_service: Option<String>,
+    shipping_fee: Option<f64>,
+    shipping_estimated_days: Option<i32>,
+    status: String,
+    placed_at: DateTime<Utc>,
+    paid_at: Option<DateTime<Utc>>,
+    packed_at: Option<DateTime<Utc>>,
+    shipped_at: Option<DateTime<Utc>>,
+    delivered_at: Option<DateTime<Utc>>,
+    cancelled_at: Option<DateTime<Utc>>,
+    refund_amount: Option<f64>,
+    restocking_fee: Option<f64>,
+    refund_reference: Option<String>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<OrderRow> for Order {
+    type Error = AppError;
+
+    fn try_from(row: OrderRow) -> Result<Self, Self::Error> {
+        let lines: Vec<OrderLine> = serde_json::from_value(row.lines)
+            .map_err(|e| AppError::internal(format!("Invalid lines of order {}: {}", row.id, e)))?;
+        let shipping = match (row.shipping_carrier, row.shipping_service, row.shipping_fee) {
+            (Some(carrier), Some(service), Some(fee)) => Some(ShippingRate::new(
+                carrier,
+                service,
+                fee,
+                row.shipping_estimated_days
+                    .and_then(|days| u32::try_from(days).ok()),
+            )?),
+            _ => None,
+        };
+        let refund = match (row.refund_amount, row.refund_reference) {
+            (Some(amount), Some(reference)) => Some(OrderRefund::new(
+                amount,
+                row.restocking_fee.unwrap_or(0.0),
+                reference,
+            )),
+            _ => None,
+        };
+
+        Ok(Order::from_persistence(
+            row.id,
+            TenantId::new(row.tenant_id)?,
+            lines,
+            shipping,
+            row.status.parse()?,
+            row.placed_at,
+            row.paid_at,
+            row.packed_at,
+            row.shipped_at,
+            row.delivered_at,
+            row.cancelled_at,
+            refund,
+            row.updated_at,
+        ))
+    }
+}
+
+/// Database row representation for OrderEvent
+struct OrderEventRow {
+    id: Uuid,
+    order_id: Uuid,
+    tenant_id: String,
+    from_status: Option<String>,
+    to_status: String,
+    actor: String,
+    occurred_at: DateTime<Utc>,
+    refunded: Option<f64>,
+}
+
+impl TryFrom<OrderEventRow> for OrderEvent {
+    type Error = AppError;
+
+    fn try_from(row: OrderEventRow) -> Result<Self, Self::Error> {
+        Ok(OrderEvent::from_persistence(
+            row.id,
+            row.order_id,
+            TenantId::new(row.tenant_id)?,
+            row.from_status
+                .map(|status| status.parse::<OrderStatus>())
+                .transpose()?,
+            row.to_status.parse()?,
+            row.actor,
+            row.occurred_at,
+            row.refunded,
+        ))
+    }
+}
+
+/// Lines as stored: a JSON array of `{flower_id, name, unit_price, quantity}`
+fn lines_json(order: &Order) -> DomainResult<Value> {
+    serde_json::to_value(order.lines())
+        .map_err(|e| AppError::internal(format!("Cannot encode order lines: {}", e)))
+}
+
+/// PostgreSQL implementation of OrderRepository
+pub struct PostgresOrderRepository {
+    db: DatabasePool,
+}
+
+impl PostgresOrderRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+
+    /// Insert a new order through any executor, such as an open transaction
+    pub(crate) async fn insert_in<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        order: &Order,
+    ) -> DomainResult<()> {
+        let shipping = order.shipping();
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO orders (id, tenant_id, lines, shipping_carrier, shipping_service, shipping_fee,
+                                shipping_estimated_days, status, placed_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            order.id(),
+            order.tenant_id().as_str(),
+            lines_json(order)?,
+            shipping.map(ShippingRate::carrier),
+            shipping.map(ShippingRate::service),
+            shipping.map(ShippingRate::fee),
+            shipping
+                .and_then(ShippingRate::estimated_days)
+                .map(|days| days as i32),
+            order.status().as_str(),
+            order.placed_at(),
+            order.updated_at()
+        )
+        .execute(executor);
+        self.db.timed("orders.insert", statement).await?;
+
+        Ok(())
+    }
+
+    /// Load an order and lock it until the surrounding transaction ends
+    pub(crate) async fn lock_in<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<Option<Order>> {
+        let statement = sqlx::query_as!(
+            OrderRow,
+            r#"
+            SELECT id, tenant_id, lines, shipping_carrier, shipping_service, shipping_fee,
+                   shipping_estimated_days, status, placed_at, paid_at, packed_at, shipped_at,
+                   delivered_at, cancelled_at, refund_amount, restocking_fee, refund_reference,
+                   updated_at
+            FROM orders
+            WHERE tenant_id = $1 AND id = $2
+            FOR UPDATE
+            "#,
+            tenant.as_str(),
+            id
+        )
+        .fetch_optional(executor);
+        let result = self.db.timed("orders.lock", statement).await?;
+
+        result.map(Order::try_from).transpose()
+    }
+
+    /// Save the status of an order, when it was reached and any refund
+    pub(crate) async fn update_in<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        order: &Order,
+    ) -> DomainResult<()> {
+        let statement = sqlx::query!(
+            r#"
+            UPDATE orders
+            SET status = $3, paid_at = $4, packed_at = $5, shipped_at = $6, delivered_at = $7,
+                cancelled_at = $8, refund_amount = $9, restocking_fee = $10, refund_reference = $11,
+                updated_at = $12
+            WHERE id = $1 AND tenant_id = $2
+            "#,
+            order.id(),
+            order.tenant_id().as_str(),
+            order.status().as_str(),
+            order.paid_at(),
+            order.packed_at(),
+            order.shipped_at(),
+            order.delivered_at(),
+            order.cancelled_at(),
+            order.refund().map(OrderRefund::amount),
+            order.refund().map(OrderRefund::restocking_fee),
+            order.refund().map(OrderRefund::reference),
+            order.updated_at()
+        )
+        .execute(executor);
+        let result = self.db.timed("orders.update", statement).await?;
+
+        if result.rows_affected() == 0 {
+            return Err(OrderError::not_found(order.id()));
+        }
+        Ok(())
+    }
+
+    /// Append events through an open connection, such as a transaction's
+    pub(crate) async fn record_events_in(
+        &self,
+        connection: &mut PgConnection,
+        events: &[OrderEvent],
+    ) -> DomainResult<()> {
+        for event in events {
+            let statement = sqlx::query!(
+                r#"
+                INSERT INTO order_events (id, order_id, tenant_id, from_status, to_status, actor, occurred_at, refunded)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+                event.id(),
+                event.order_id(),
+                event.tenant_id().as_str(),
+                event.from().map(OrderStatus::as_str),
+                event.to().as_str(),
+                event.actor(),
+                event.occurred_at(),
+                event.refunded()
+            )
+            .execute(&mut *connection);
+            self.db.timed("order_events.record", statement).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrderRepository for PostgresOrderRepository {
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Order>> {
+        let row = self
+            .db
+            .read("orders.find_by_id", |pool| {
+                sqlx::query_as!(
+                    OrderRow,
+                    r#"
+                    SELECT id, tenant_id, lines, shipping_carrier, shipping_service, shipping_fee,
+                           shipping_estimated_days, status, placed_at, paid_at, packed_at, shipped_at,
+                           delivered_at, cancelled_at, refund_amount, restocking_fee, refund_reference,
+                           updated_at
+                    FROM orders
+                    WHERE tenant_id = $1 AND id = $2
+                    "#,
+                    tenant.as_str(),
+                    id
+                )
+                .fetch_optional(pool)
+            })
+            .await?;
+
+        row.map(Order::try_from).transpose()
+    }
+
+    async fn events(&self, tenant: &TenantId, order_id: Uuid) -> DomainResult<Vec<OrderEvent>> {
+        let rows = self
+            .db
+            .read("order_events.find", |pool| {
+                sqlx::query_as!(
+                    OrderEventRow,
+                    r#"
+                    SELECT id, order_id, tenant_id, from_status, to_status, actor, occurred_at, refunded
+                    FROM order_events
+                    WHERE tenant_id = $1 AND order_id = $2
+                    ORDER BY occurred_at, id
+                    "#,
+                    tenant.as_str(),
+                    order_id
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter().map(OrderEvent::try_from).collect()
+    }
+}
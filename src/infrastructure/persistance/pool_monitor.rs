@@ -0,0 +1,126 @@
+//! Connection Pool Monitoring
+//!
+//! sqlx does not report how long callers wait for a connection, so the pool
+//! is watched from two sides: queries that start while every connection is
+//! busy are counted as waits, and a probe periodically acquires a connection
+//! the way a query would, keeping the latest acquire times for percentiles.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use tokio::task::JoinSet;
+
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Counter of queries that found no free connection
+pub const POOL_WAITS_METRIC: &str = "db_pool_waits_total";
+
+/// Acquire times kept for percentiles
+const SAMPLES: usize = 256;
+
+/// Waits and acquire times of one pool, shared by its clones
+#[derive(Debug, Default)]
+pub(crate) struct PoolMonitor {
+    waits: AtomicU64,
+    acquires: Mutex<VecDeque<Duration>>,
+}
+
+impl PoolMonitor {
+    pub(crate) fn record_wait(&self) {
+        self.waits.fetch_add(1, Ordering::Relaxed);
+        metrics::counter!(POOL_WAITS_METRIC).increment(1);
+    }
+
+    pub(crate) fn record_acquire(&self, elapsed: Duration) {
+        let mut acquires = self.acquires.lock().expect("pool monitor poisoned");
+        if acquires.len() == SAMPLES {
+            acquires.pop_front();
+        }
+        acquires.push_back(elapsed);
+    }
+
+    pub(crate) fn waits(&self) -> u64 {
+        self.waits.load(Ordering::Relaxed)
+    }
+
+    /// Percentiles of the latest acquire times; `None` before the first probe
+    pub(crate) fn acquire_latency(&self) -> Option<AcquireLatency> {
+        let mut samples: Vec<Duration> = self
+            .acquires
+            .lock()
+            .expect("pool monitor poisoned")
+            .iter()
+            .copied()
+            .collect();
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+
+        // Nearest rank
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Some(AcquireLatency {
+            p50: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+            max: samples[samples.len() - 1],
+            samples: samples.len(),
+        })
+    }
+}
+
+/// How long acquiring a connection took, over the latest probes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcquireLatency {
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+    /// Probes the percentiles are drawn from
+    pub samples: usize,
+}
+
+/// Keeps the pool probe running for as long as it is alive
+pub struct PoolProbe {
+    _task: JoinSet<()>,
+}
+
+impl PoolProbe {
+    /// Acquire a connection from `db` every `interval`
+    pub fn start(db: DatabasePool, interval: Duration) -> Self {
+        let mut task = JoinSet::new();
+        task.spawn(async move {
+            let mut ticks = tokio::time::interval(interval);
+            loop {
+                ticks.tick().await;
+                if let Err(e) = db.probe().await {
+                    tracing::warn!("Connection pool probe failed: {}", e);
+                }
+            }
+        });
+        Self { _task: task }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles_cover_the_latest_samples() {
+        let monitor = PoolMonitor::default();
+        assert_eq!(monitor.acquire_latency(), None);
+
+        for millis in 1..=SAMPLES as u64 + 100 {
+            monitor.record_acquire(Duration::from_millis(millis));
+        }
+        let latency = monitor.acquire_latency().unwrap();
+        assert_eq!(latency.samples, SAMPLES);
+        // The first 100 samples were dropped
+        assert_eq!(latency.p50, Duration::from_millis(228));
+        assert_eq!(latency.p99, Duration::from_millis(354));
+        assert_eq!(latency.max, Duration::from_millis(356));
+    }
+}
@@ -0,0 +1,169 @@
+//! PostgreSQL implementation of PricingRuleRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::PricingRuleRepository;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::pricing::{PricingConditions, PricingRule};
+use crate::domain::shared::{Entity, TenantId};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for PricingRule
+struct PricingRuleRow {
+    id: Uuid,
+    tenant_id: String,
+    name: String,
+    adjustment_percent: f64,
+    max_stock: Option<i32>,
+    starts_at: Option<DateTime<Utc>>,
+    ends_at: Option<DateTime<Utc>>,
+    enabled: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<PricingRuleRow> for PricingRule {
+    type Error = AppError;
+
+    fn try_from(row: PricingRuleRow) -> Result<Self, Self::Error> {
+        Ok(PricingRule::from_persistence(
+            row.id,
+            TenantId::new(row.tenant_id)?,
+            row.name,
+            row.adjustment_percent,
+            PricingConditions {
+                max_stock: row.max_stock,
+                starts_at: row.starts_at,
+                ends_at: row.ends_at,
+            },
+            row.enabled,
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+/// PostgreSQL implementation of PricingRuleRepository
+pub struct PostgresPricingRuleRepository {
+    db: DatabasePool,
+}
+
+impl PostgresPricingRuleRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PricingRuleRepository for PostgresPricingRuleRepository {
+    async fn create(&self, rule: &PricingRule) -> DomainResult<PricingRule> {
+        let conditions = rule.conditions();
+        let statement = sqlx::query_as!(
+            PricingRuleRow,
+            r#"
+            INSERT INTO pricing_rules (id, tenant_id, name, adjustment_percent, max_stock, starts_at,
+                                       ends_at, enabled, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id, tenant_id, name, adjustment_percent, max_stock, starts_at, ends_at,
+                      enabled, created_at, updated_at
+            "#,
+            rule.id(),
+            rule.tenant_id().as_str(),
+            rule.name(),
+            rule.adjustment_percent(),
+            conditions.max_stock,
+            conditions.starts_at,
+            conditions.ends_at,
+            rule.enabled(),
+            rule.created_at(),
+            rule.updated_at()
+        )
+        .fetch_one(self.db.pool());
+        let row = self.db.timed("pricing_rules.create", statement).await?;
+
+        row.try_into()
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<PricingRule>> {
+        let rows = self
+            .db
+            .read("pricing_rules.find_all", |pool| {
+                sqlx::query_as!(
+                    PricingRuleRow,
+                    r#"
+                    SELECT id, tenant_id, name, adjustment_percent, max_stock, starts_at, ends_at,
+                           enabled, created_at, updated_at
+                    FROM pricing_rules
+                    WHERE tenant_id = $1
+                    ORDER BY name, id
+                    "#,
+                    tenant.as_str()
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter().map(PricingRule::try_from).collect()
+    }
+
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<PricingRule>> {
+        let row = self
+            .db
+            .read("pricing_rules.find_by_id", |pool| {
+                sqlx::query_as!(
+                    PricingRuleRow,
+                    r#"
+                    SELECT id, tenant_id, name, adjustment_percent, max_stock, starts_at, ends_at,
+                           enabled, created_at, updated_at
+                    FROM pricing_rules
+                    WHERE tenant_id = $1 AND id = $2
+                    "#,
+                    tenant.as_str(),
+                    id
+                )
+                .fetch_optional(pool)
+            })
+            .await?;
+
+        row.map(PricingRule::try_from).transpose()
+    }
+
+    async fn update(&self, rule: &PricingRule) -> DomainResult<bool> {
+        let conditions = rule.conditions();
+        let statement = sqlx::query!(
+            r#"
+            UPDATE pricing_rules
+            SET name = $3, adjustment_percent = $4, max_stock = $5, starts_at = $6, ends_at = $7,
+                enabled = $8, updated_at = $9
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+            rule.tenant_id().as_str(),
+            rule.id(),
+            rule.name(),
+            rule.adjustment_percent(),
+            conditions.max_stock,
+            conditions.starts_at,
+            conditions.ends_at,
+            rule.enabled(),
+            rule.updated_at()
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("pricing_rules.update", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let statement = sqlx::query!(
+            "DELETE FROM pricing_rules WHERE tenant_id = $1 AND id = $2",
+            tenant.as_str(),
+            id
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("pricing_rules.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,40 @@
+//! Query Timing and Slow Query Logging
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// Histogram of query durations, labelled by query name
+pub const QUERY_DURATION_METRIC: &str = "db_query_duration_seconds";
+
+/// Counter of queries slower than the configured threshold
+pub const SLOW_QUERY_METRIC: &str = "db_slow_queries_total";
+
+/// Run a query future, recording its duration and warning when it is slow
+pub async fn timed<T>(
+    query: &'static str,
+    slow_threshold: Duration,
+    future: impl Future<Output = T>,
+) -> T {
+    let started = Instant::now();
+    let output = future.await;
+    let elapsed = started.elapsed();
+
+    metrics::histogram!(QUERY_DURATION_METRIC, "query" => query).record(elapsed.as_secs_f64());
+    if elapsed >= slow_threshold {
+        metrics::counter!(SLOW_QUERY_METRIC, "query" => query).increment(1);
+        tracing::warn!(
+            query,
+            elapsed_ms = elapsed.as_millis() as u64,
+            threshold_ms = slow_threshold.as_millis() as u64,
+            "Slow query"
+        );
+    } else {
+        tracing::trace!(
+            query,
+            elapsed_ms = elapsed.as_millis() as u64,
+            "Query finished"
+        );
+    }
+
+    output
+}
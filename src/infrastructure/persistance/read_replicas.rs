@@ -0,0 +1,132 @@
+//! Read Replica Routing
+//!
+//! Reads are spread round-robin over the replicas from `DATABASE_READ_URLS`.
+//! A replica that fails with a connection-level error is taken out of
+//! rotation for a cooldown period and the query is retried on the primary,
+//! so an unavailable replica costs at most one failed attempt per cooldown.
+//! Replicas connect lazily and never block startup.
+
+use std::future::Future;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use sqlx::PgPool;
+
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::persistance::PoolSettings;
+
+/// Upper bound on waiting for a replica connection before falling back; kept
+/// short because sqlx keeps retrying refused connections until it runs out
+const REPLICA_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How long a failed replica is skipped before it is tried again
+const REPLICA_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Counter of reads sent to the primary because a replica failed
+pub const REPLICA_FALLBACK_METRIC: &str = "db_replica_fallbacks_total";
+
+struct Replica {
+    pool: PgPool,
+    down_until: Mutex<Option<Instant>>,
+}
+
+impl Replica {
+    fn is_available(&self) -> bool {
+        let down_until = self.down_until.lock().expect("replica state poisoned");
+        down_until.is_none_or(|until| Instant::now() >= until)
+    }
+
+    fn mark_down(&self) {
+        *self.down_until.lock().expect("replica state poisoned") =
+            Some(Instant::now() + REPLICA_COOLDOWN);
+    }
+}
+
+/// Reader pools in front of the primary
+pub struct ReadReplicas {
+    replicas: Vec<Replica>,
+    next: AtomicUsize,
+}
+
+impl ReadReplicas {
+    /// Create lazily connecting pools for the given replica URLs
+    pub fn new(urls: &[String], settings: &PoolSettings) -> DomainResult<Self> {
+        let replicas = urls
+            .iter()
+            .map(|url| {
+                let options = settings
+                    .pg_connect_options(url)
+                    .map_err(|e| AppError::internal(format!("Invalid read replica URL: {}", e)))?;
+                let pool = settings
+                    .pg_pool_options()
+                    .acquire_timeout(settings.acquire_timeout.min(REPLICA_ACQUIRE_TIMEOUT))
+                    .connect_lazy_with(options);
+                Ok(Replica {
+                    pool,
+                    down_until: Mutex::new(None),
+                })
+            })
+            .collect::<DomainResult<_>>()?;
+
+        Ok(Self {
+            replicas,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.replicas.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.replicas.is_empty()
+    }
+
+    /// Next replica in rotation that is not cooling down
+    fn pick(&self) -> Option<&Replica> {
+        let count = self.replicas.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        (0..count)
+            .map(|offset| &self.replicas[(start + offset) % count])
+            .find(|replica| replica.is_available())
+    }
+
+    /// Run a read on a replica, falling back to `primary` when none can serve it
+    pub async fn run<'a, T, F, Fut>(&'a self, primary: &'a PgPool, run: F) -> Result<T, sqlx::Error>
+    where
+        F: Fn(&'a PgPool) -> Fut,
+        Fut: Future<Output = Result<T, sqlx::Error>>,
+    {
+        let Some(replica) = self.pick() else {
+            return run(primary).await;
+        };
+
+        match run(&replica.pool).await {
+            Err(e) if is_connection_error(&e) => {
+                tracing::warn!(
+                    "Read replica unavailable, using primary for {:?}: {}",
+                    REPLICA_COOLDOWN,
+                    e
+                );
+                replica.mark_down();
+                metrics::counter!(REPLICA_FALLBACK_METRIC).increment(1);
+                run(primary).await
+            }
+            result => result,
+        }
+    }
+}
+
+/// Errors meaning the server could not be reached, as opposed to query errors
+fn is_connection_error(error: &sqlx::Error) -> bool {
+    matches!(
+        error,
+        sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::Protocol(_)
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::WorkerCrashed
+    )
+}
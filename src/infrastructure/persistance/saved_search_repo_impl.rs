@@ -0,0 +1,164 @@
+//! PostgreSQL implementation of SavedSearchRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::SavedSearchRepository;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::Price;
+use crate::domain::saved_search::{SavedSearch, SearchCriteria};
+use crate::domain::shared::TenantId;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for SavedSearch
+struct SavedSearchRow {
+    id: Uuid,
+    tenant_id: String,
+    owner: String,
+    name: String,
+    query: Option<String>,
+    color: Option<String>,
+    min_price: Option<f64>,
+    max_price: Option<f64>,
+    alert_email: Option<String>,
+    checked_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<SavedSearchRow> for SavedSearch {
+    type Error = AppError;
+
+    fn try_from(row: SavedSearchRow) -> Result<Self, Self::Error> {
+        let criteria = SearchCriteria {
+            query: row.query,
+            color: row.color.map(|color| color.parse()).transpose()?,
+            min_price: row.min_price.map(Price::new).transpose()?,
+            max_price: row.max_price.map(Price::new).transpose()?,
+        };
+        Ok(SavedSearch::from_persistence(
+            row.id,
+            TenantId::new(row.tenant_id)?,
+            row.owner,
+            row.name,
+            criteria,
+            row.alert_email,
+            row.checked_at,
+            row.created_at,
+        ))
+    }
+}
+
+/// PostgreSQL implementation of SavedSearchRepository
+pub struct PostgresSavedSearchRepository {
+    db: DatabasePool,
+}
+
+impl PostgresSavedSearchRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl SavedSearchRepository for PostgresSavedSearchRepository {
+    async fn create(&self, search: &SavedSearch) -> DomainResult<SavedSearch> {
+        let criteria = search.criteria();
+        let statement = sqlx::query_as!(
+            SavedSearchRow,
+            r#"
+            INSERT INTO saved_searches (id, tenant_id, owner, name, query, color, min_price, max_price, alert_email, checked_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING id, tenant_id, owner, name, query, color, min_price, max_price, alert_email, checked_at, created_at
+            "#,
+            search.id(),
+            search.tenant_id().as_str(),
+            search.owner(),
+            search.name(),
+            criteria.query.as_deref(),
+            criteria.color.map(|color| color.as_str()),
+            criteria.min_price.map(|price| price.value()),
+            criteria.max_price.map(|price| price.value()),
+            search.alert_email(),
+            search.checked_at(),
+            search.created_at()
+        )
+        .fetch_one(self.db.pool());
+        let row = self.db.timed("saved_searches.create", statement).await?;
+
+        row.try_into()
+    }
+
+    async fn find_by_owner(
+        &self,
+        tenant: &TenantId,
+        owner: &str,
+    ) -> DomainResult<Vec<SavedSearch>> {
+        let rows = self
+            .db
+            .read("saved_searches.find_by_owner", |pool| {
+                sqlx::query_as!(
+                    SavedSearchRow,
+                    r#"
+                    SELECT id, tenant_id, owner, name, query, color, min_price, max_price, alert_email, checked_at, created_at
+                    FROM saved_searches
+                    WHERE tenant_id = $1 AND owner = $2
+                    ORDER BY created_at, id
+                    "#,
+                    tenant.as_str(),
+                    owner
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter().map(SavedSearch::try_from).collect()
+    }
+
+    async fn find_alerting(&self) -> DomainResult<Vec<SavedSearch>> {
+        // From the primary: a replica lagging behind would alert twice
+        let statement = sqlx::query_as!(
+            SavedSearchRow,
+            r#"
+            SELECT id, tenant_id, owner, name, query, color, min_price, max_price, alert_email, checked_at, created_at
+            FROM saved_searches
+            WHERE alert_email IS NOT NULL
+            ORDER BY tenant_id, created_at, id
+            "#
+        )
+        .fetch_all(self.db.pool());
+        let rows = self
+            .db
+            .timed("saved_searches.find_alerting", statement)
+            .await?;
+
+        rows.into_iter().map(SavedSearch::try_from).collect()
+    }
+
+    async fn mark_checked(&self, id: Uuid, at: DateTime<Utc>) -> DomainResult<()> {
+        let statement = sqlx::query!(
+            "UPDATE saved_searches SET checked_at = $2 WHERE id = $1",
+            id,
+            at
+        )
+        .execute(self.db.pool());
+        self.db
+            .timed("saved_searches.mark_checked", statement)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, tenant: &TenantId, owner: &str, id: Uuid) -> DomainResult<bool> {
+        let statement = sqlx::query!(
+            "DELETE FROM saved_searches WHERE tenant_id = $1 AND owner = $2 AND id = $3",
+            tenant.as_str(),
+            owner,
+            id
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("saved_searches.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
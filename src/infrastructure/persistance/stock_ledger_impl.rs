@@ -0,0 +1,142 @@
+//! PostgreSQL inventory ledger
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::application::ports::{LedgerQuery, StockLedger};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::inventory::StockMovement;
+use crate::domain::shared::{Pagination, TenantId};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for StockMovement
+struct StockMovementRow {
+    id: Uuid,
+    tenant_id: String,
+    flower_id: Uuid,
+    delta: i32,
+    reason: String,
+    stock_after: i32,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<StockMovementRow> for StockMovement {
+    type Error = AppError;
+
+    fn try_from(row: StockMovementRow) -> Result<Self, Self::Error> {
+        Ok(StockMovement::from_persistence(
+            row.id,
+            TenantId::new(row.tenant_id)?,
+            row.flower_id,
+            row.delta,
+            row.reason,
+            row.stock_after,
+            row.created_at,
+        ))
+    }
+}
+
+/// Appends stock movements to the `stock_movements` table and reads them back
+pub struct PostgresStockLedger {
+    db: DatabasePool,
+}
+
+impl PostgresStockLedger {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+
+    /// Record a movement through any executor, such as an open transaction
+    pub(crate) async fn record_in<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        movement: &StockMovement,
+    ) -> DomainResult<()> {
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO stock_movements (id, tenant_id, flower_id, delta, reason, stock_after, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            movement.id(),
+            movement.tenant_id().as_str(),
+            movement.flower_id(),
+            movement.delta(),
+            movement.reason(),
+            movement.stock_after(),
+            movement.created_at()
+        )
+        .execute(executor);
+        self.db.timed("stock_movements.record", statement).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StockLedger for PostgresStockLedger {
+    async fn find(
+        &self,
+        query: &LedgerQuery,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<StockMovement>> {
+        let tenant = query.tenant.as_ref().map(TenantId::as_str);
+        let rows = self
+            .db
+            .read("stock_movements.find", |pool| {
+                sqlx::query_as!(
+                    StockMovementRow,
+                    r#"
+                    SELECT id, tenant_id, flower_id, delta, reason, stock_after, created_at
+                    FROM stock_movements
+                    WHERE ($1::text IS NULL OR tenant_id = $1)
+                      AND ($2::uuid IS NULL OR flower_id = $2)
+                    ORDER BY created_at DESC, id DESC
+                    LIMIT $3 OFFSET $4
+                    "#,
+                    tenant,
+                    query.flower_id,
+                    pagination.limit(),
+                    pagination.offset()
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter().map(StockMovement::try_from).collect()
+    }
+
+    async fn count(&self, query: &LedgerQuery) -> DomainResult<i64> {
+        let tenant = query.tenant.as_ref().map(TenantId::as_str);
+        let count = self
+            .db
+            .read("stock_movements.count", |pool| {
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT COUNT(*) AS "count!" FROM stock_movements
+                    WHERE ($1::text IS NULL OR tenant_id = $1)
+                      AND ($2::uuid IS NULL OR flower_id = $2)
+                    "#,
+                    tenant,
+                    query.flower_id
+                )
+                .fetch_one(pool)
+            })
+            .await?;
+
+        Ok(count)
+    }
+
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64> {
+        let statement = sqlx::query!(
+            "DELETE FROM stock_movements WHERE tenant_id = $1 AND flower_id = $2",
+            tenant.as_str(),
+            flower_id
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("stock_movements.delete", statement).await?;
+
+        Ok(result.rows_affected())
+    }
+}
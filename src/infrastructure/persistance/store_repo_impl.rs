@@ -0,0 +1,202 @@
+//! PostgreSQL implementation of StoreRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::StoreRepository;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::TenantId;
+use crate::domain::store::{GeoPoint, Store};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for Store
+struct StoreRow {
+    id: Uuid,
+    tenant_id: String,
+    name: String,
+    address: String,
+    latitude: f64,
+    longitude: f64,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<StoreRow> for Store {
+    type Error = AppError;
+
+    fn try_from(row: StoreRow) -> Result<Self, Self::Error> {
+        Ok(Store::from_persistence(
+            row.id,
+            TenantId::new(row.tenant_id)?,
+            row.name,
+            row.address,
+            GeoPoint::new(row.latitude, row.longitude)?,
+            row.created_at,
+        ))
+    }
+}
+
+/// PostgreSQL implementation of StoreRepository
+pub struct PostgresStoreRepository {
+    db: DatabasePool,
+}
+
+impl PostgresStoreRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl StoreRepository for PostgresStoreRepository {
+    async fn create(&self, store: &Store) -> DomainResult<Store> {
+        let statement = sqlx::query_as!(
+            StoreRow,
+            r#"
+            INSERT INTO stores (id, tenant_id, name, address, latitude, longitude, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, tenant_id, name, address, latitude, longitude, created_at
+            "#,
+            store.id(),
+            store.tenant_id().as_str(),
+            store.name(),
+            store.address(),
+            store.location().latitude(),
+            store.location().longitude(),
+            store.created_at()
+        )
+        .fetch_one(self.db.pool());
+        let row = self.db.timed("stores.create", statement).await?;
+
+        row.try_into()
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<Store>> {
+        let rows = self
+            .db
+            .read("stores.find_all", |pool| {
+                sqlx::query_as!(
+                    StoreRow,
+                    r#"
+                    SELECT id, tenant_id, name, address, latitude, longitude, created_at
+                    FROM stores
+                    WHERE tenant_id = $1
+                    ORDER BY name, id
+                    "#,
+                    tenant.as_str()
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter().map(Store::try_from).collect()
+    }
+
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Store>> {
+        let row = self
+            .db
+            .read("stores.find_by_id", |pool| {
+                sqlx::query_as!(
+                    StoreRow,
+                    r#"
+                    SELECT id, tenant_id, name, address, latitude, longitude, created_at
+                    FROM stores
+                    WHERE tenant_id = $1 AND id = $2
+                    "#,
+                    tenant.as_str(),
+                    id
+                )
+                .fetch_optional(pool)
+            })
+            .await?;
+
+        row.map(Store::try_from).transpose()
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let statement = sqlx::query!(
+            "DELETE FROM stores WHERE tenant_id = $1 AND id = $2",
+            tenant.as_str(),
+            id
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("stores.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn set_stock(
+        &self,
+        tenant: &TenantId,
+        store_id: Uuid,
+        flower_id: Uuid,
+        quantity: i32,
+    ) -> DomainResult<()> {
+        if quantity == 0 {
+            let statement = sqlx::query!(
+                "DELETE FROM store_stock WHERE tenant_id = $1 AND store_id = $2 AND flower_id = $3",
+                tenant.as_str(),
+                store_id,
+                flower_id
+            )
+            .execute(self.db.pool());
+            self.db.timed("store_stock.delete", statement).await?;
+            return Ok(());
+        }
+
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO store_stock (store_id, tenant_id, flower_id, quantity)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (store_id, flower_id) DO UPDATE SET quantity = EXCLUDED.quantity
+            "#,
+            store_id,
+            tenant.as_str(),
+            flower_id,
+            quantity
+        )
+        .execute(self.db.pool());
+        self.db.timed("store_stock.set", statement).await?;
+
+        Ok(())
+    }
+
+    async fn stock_of(
+        &self,
+        tenant: &TenantId,
+        flower_id: Uuid,
+    ) -> DomainResult<Vec<(Store, i32)>> {
+        let rows = self
+            .db
+            .read("store_stock.find_by_flower", |pool| {
+                sqlx::query!(
+                    r#"
+                    SELECT s.id, s.tenant_id, s.name, s.address, s.latitude, s.longitude, s.created_at, k.quantity
+                    FROM store_stock k
+                    JOIN stores s ON s.id = k.store_id
+                    WHERE k.tenant_id = $1 AND k.flower_id = $2
+                    ORDER BY s.name, s.id
+                    "#,
+                    tenant.as_str(),
+                    flower_id
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let store = StoreRow {
+                    id: row.id,
+                    tenant_id: row.tenant_id,
+                    name: row.name,
+                    address: row.address,
+                    latitude: row.latitude,
+                    longitude: row.longitude,
+                    created_at: row.created_at,
+                };
+                Ok((store.try_into()?, row.quantity))
+            })
+            .collect()
+    }
+}
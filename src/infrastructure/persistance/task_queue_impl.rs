@@ -0,0 +1,245 @@
+//! PostgreSQL implementation of TaskQueue
+//!
+//! Workers claim tasks with `FOR UPDATE SKIP LOCKED`, so any number of them
+//! (across replicas) can poll the same table without handing out a task
+//! twice. A claimed task is leased rather than deleted: if the worker dies,
+//! the task becomes due again once the lease runs out.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::application::ports::TaskQueue;
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::Pagination;
+use crate::domain::task::{FailedTask, Task};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for Task
+#[derive(Debug)]
+struct TaskRow {
+    id: Uuid,
+    kind: String,
+    payload: Value,
+    attempts: i32,
+    max_attempts: i32,
+    run_at: DateTime<Utc>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<TaskRow> for Task {
+    fn from(row: TaskRow) -> Self {
+        Task::from_persistence(
+            row.id,
+            row.kind,
+            row.payload,
+            row.attempts,
+            row.max_attempts,
+            row.run_at,
+            row.last_error,
+            row.created_at,
+        )
+    }
+}
+
+/// Database row representation for FailedTask
+#[derive(Debug)]
+struct FailedTaskRow {
+    id: Uuid,
+    kind: String,
+    payload: Value,
+    attempts: i32,
+    last_error: String,
+    created_at: DateTime<Utc>,
+    failed_at: DateTime<Utc>,
+}
+
+impl From<FailedTaskRow> for FailedTask {
+    fn from(row: FailedTaskRow) -> Self {
+        FailedTask::from_persistence(
+            row.id,
+            row.kind,
+            row.payload,
+            row.attempts,
+            row.last_error,
+            row.created_at,
+            row.failed_at,
+        )
+    }
+}
+
+/// PostgreSQL implementation of TaskQueue
+pub struct PostgresTaskQueue {
+    db: DatabasePool,
+}
+
+impl PostgresTaskQueue {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+
+    /// Enqueue a task through any executor, such as an open transaction
+    pub(crate) async fn enqueue_in<'e>(
+        &self,
+        executor: impl PgExecutor<'e>,
+        task: &Task,
+    ) -> DomainResult<()> {
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO tasks (id, kind, payload, attempts, max_attempts, run_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            task.id(),
+            task.kind(),
+            task.payload(),
+            task.attempts(),
+            task.max_attempts(),
+            task.run_at(),
+            task.created_at()
+        )
+        .execute(executor);
+        self.db.timed("tasks.enqueue", statement).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TaskQueue for PostgresTaskQueue {
+    async fn enqueue(&self, task: &Task) -> DomainResult<()> {
+        self.enqueue_in(self.db.pool(), task).await
+    }
+
+    async fn claim(&self, lease: Duration) -> DomainResult<Option<Task>> {
+        let statement = sqlx::query_as!(
+            TaskRow,
+            r#"
+            UPDATE tasks
+            SET attempts = attempts + 1,
+                locked_until = NOW() + make_interval(secs => $1)
+            WHERE id = (
+                SELECT id FROM tasks
+                WHERE run_at <= NOW() AND (locked_until IS NULL OR locked_until < NOW())
+                ORDER BY run_at
+                FOR UPDATE SKIP LOCKED
+                LIMIT 1
+            )
+            RETURNING id, kind, payload, attempts, max_attempts, run_at, last_error, created_at
+            "#,
+            lease.as_secs_f64()
+        )
+        .fetch_optional(self.db.pool());
+        let row = self.db.timed("tasks.claim", statement).await?;
+
+        Ok(row.map(Task::from))
+    }
+
+    async fn complete(&self, id: Uuid) -> DomainResult<()> {
+        let statement = sqlx::query!("DELETE FROM tasks WHERE id = $1", id).execute(self.db.pool());
+        self.db.timed("tasks.complete", statement).await?;
+
+        Ok(())
+    }
+
+    async fn retry(&self, id: Uuid, error: &str, run_at: DateTime<Utc>) -> DomainResult<()> {
+        let statement = sqlx::query!(
+            "UPDATE tasks SET run_at = $2, last_error = $3, locked_until = NULL WHERE id = $1",
+            id,
+            run_at,
+            error
+        )
+        .execute(self.db.pool());
+        self.db.timed("tasks.retry", statement).await?;
+
+        Ok(())
+    }
+
+    async fn dead_letter(&self, id: Uuid, error: &str) -> DomainResult<()> {
+        let statement = sqlx::query!(
+            r#"
+            WITH failed AS (
+                DELETE FROM tasks WHERE id = $1
+                RETURNING id, kind, payload, attempts, created_at
+            )
+            INSERT INTO dead_letter_tasks (id, kind, payload, attempts, last_error, created_at)
+            SELECT id, kind, payload, attempts, $2::text, created_at FROM failed
+            "#,
+            id,
+            error
+        )
+        .execute(self.db.pool());
+        self.db.timed("tasks.dead_letter", statement).await?;
+
+        Ok(())
+    }
+
+    async fn find_failed(&self, pagination: &Pagination) -> DomainResult<Vec<FailedTask>> {
+        let rows = self
+            .db
+            .read("dead_letter_tasks.find_all", |pool| {
+                sqlx::query_as!(
+                    FailedTaskRow,
+                    r#"
+                    SELECT id, kind, payload, attempts, last_error, created_at, failed_at
+                    FROM dead_letter_tasks
+                    ORDER BY failed_at DESC
+                    LIMIT $1 OFFSET $2
+                    "#,
+                    pagination.limit(),
+                    pagination.offset()
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        Ok(rows.into_iter().map(FailedTask::from).collect())
+    }
+
+    async fn count_failed(&self) -> DomainResult<i64> {
+        let count = self
+            .db
+            .read("dead_letter_tasks.count", |pool| {
+                sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM dead_letter_tasks"#)
+                    .fetch_one(pool)
+            })
+            .await?;
+
+        Ok(count)
+    }
+
+    async fn purge_failed(&self, failed_before: DateTime<Utc>, archive: bool) -> DomainResult<u64> {
+        let result = if archive {
+            let statement = sqlx::query!(
+                r#"
+                WITH expired AS (
+                    DELETE FROM dead_letter_tasks WHERE failed_at < $1
+                    RETURNING id, kind, payload, attempts, last_error, created_at, failed_at
+                )
+                INSERT INTO dead_letter_tasks_archive
+                    (id, kind, payload, attempts, last_error, created_at, failed_at)
+                SELECT id, kind, payload, attempts, last_error, created_at, failed_at FROM expired
+                ON CONFLICT (id) DO NOTHING
+                "#,
+                failed_before
+            )
+            .execute(self.db.pool());
+            self.db
+                .timed("dead_letter_tasks.archive", statement)
+                .await?
+        } else {
+            let statement = sqlx::query!(
+                "DELETE FROM dead_letter_tasks WHERE failed_at < $1",
+                failed_before
+            )
+            .execute(self.db.pool());
+            self.db.timed("dead_letter_tasks.purge", statement).await?
+        };
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,117 @@
+//! PostgreSQL implementation of UnitOfWork
+//!
+//! A transaction runs the regular repository statements on one
+//! `sqlx::Transaction`. Locked flowers stay locked until commit or rollback,
+//! so concurrent read-modify-write cycles on the same flower serialize
+//! instead of overwriting each other.
+
+use async_trait::async_trait;
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use crate::application::ports::{Transaction, UnitOfWork};
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerChange, FlowerColor};
+use crate::domain::inventory::StockMovement;
+use crate::domain::order::{Order, OrderEvent};
+use crate::domain::shared::TenantId;
+use crate::domain::task::Task;
+use crate::infrastructure::persistance::{
+    DatabasePool, PostgresFlowerHistory, PostgresFlowerRepository, PostgresOrderRepository,
+    PostgresStockLedger, PostgresTaskQueue,
+};
+
+/// PostgreSQL implementation of UnitOfWork
+pub struct PostgresUnitOfWork {
+    db: DatabasePool,
+}
+
+impl PostgresUnitOfWork {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    async fn begin(&self) -> DomainResult<Box<dyn Transaction>> {
+        let tx = self.db.pool().begin().await?;
+        Ok(Box::new(PostgresTransaction {
+            tx,
+            flowers: PostgresFlowerRepository::new(self.db.clone()),
+            ledger: PostgresStockLedger::new(self.db.clone()),
+            history: PostgresFlowerHistory::new(self.db.clone()),
+            tasks: PostgresTaskQueue::new(self.db.clone()),
+            orders: PostgresOrderRepository::new(self.db.clone()),
+        }))
+    }
+}
+
+/// An open PostgreSQL transaction, rolled back when dropped uncommitted
+struct PostgresTransaction {
+    tx: sqlx::Transaction<'static, Postgres>,
+    flowers: PostgresFlowerRepository,
+    ledger: PostgresStockLedger,
+    history: PostgresFlowerHistory,
+    tasks: PostgresTaskQueue,
+    orders: PostgresOrderRepository,
+}
+
+#[async_trait]
+impl Transaction for PostgresTransaction {
+    async fn lock_flower(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        self.flowers.lock_in(&mut *self.tx, tenant, id).await
+    }
+
+    async fn lock_flowers(
+        &mut self,
+        tenant: &TenantId,
+        color: Option<FlowerColor>,
+        ids: Option<&[Uuid]>,
+    ) -> DomainResult<Vec<Flower>> {
+        self.flowers
+            .lock_all_in(&mut *self.tx, tenant, color, ids)
+            .await
+    }
+
+    async fn insert_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        self.flowers.create_in(&mut *self.tx, flower).await
+    }
+
+    async fn update_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        self.flowers.update_in(&mut *self.tx, flower).await
+    }
+
+    async fn record_stock_movement(&mut self, movement: &StockMovement) -> DomainResult<()> {
+        self.ledger.record_in(&mut *self.tx, movement).await
+    }
+
+    async fn record_flower_changes(&mut self, changes: &[FlowerChange]) -> DomainResult<()> {
+        self.history.record_in(&mut self.tx, changes).await
+    }
+
+    async fn insert_order(&mut self, order: &Order) -> DomainResult<()> {
+        self.orders.insert_in(&mut *self.tx, order).await
+    }
+
+    async fn lock_order(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Order>> {
+        self.orders.lock_in(&mut *self.tx, tenant, id).await
+    }
+
+    async fn update_order(&mut self, order: &Order) -> DomainResult<()> {
+        self.orders.update_in(&mut *self.tx, order).await
+    }
+
+    async fn record_order_events(&mut self, events: &[OrderEvent]) -> DomainResult<()> {
+        self.orders.record_events_in(&mut self.tx, events).await
+    }
+
+    async fn enqueue_task(&mut self, task: &Task) -> DomainResult<()> {
+        self.tasks.enqueue_in(&mut *self.tx, task).await
+    }
+
+    async fn commit(self: Box<Self>) -> DomainResult<()> {
+        self.tx.commit().await?;
+        Ok(())
+    }
+}
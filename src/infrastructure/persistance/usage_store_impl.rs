@@ -0,0 +1,199 @@
+//! PostgreSQL implementation of UsageStore
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::application::ports::{ApiQuota, UsageRecord, UsageStore};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// PostgreSQL implementation of UsageStore
+pub struct PostgresUsageStore {
+    db: DatabasePool,
+}
+
+impl PostgresUsageStore {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl UsageStore for PostgresUsageStore {
+    async fn add(&self, records: &[UsageRecord]) -> DomainResult<()> {
+        let tenants: Vec<String> = records
+            .iter()
+            .map(|r| r.tenant.as_str().to_string())
+            .collect();
+        let key_ids: Vec<String> = records
+            .iter()
+            .map(|r| r.key_id.clone().unwrap_or_default())
+            .collect();
+        let api_keys: Vec<String> = records
+            .iter()
+            .map(|r| r.api_key.clone().unwrap_or_default())
+            .collect();
+        let hours: Vec<DateTime<Utc>> = records.iter().map(|r| r.hour).collect();
+        let requests: Vec<i64> = records.iter().map(|r| r.requests).collect();
+        let errors: Vec<i64> = records.iter().map(|r| r.errors).collect();
+        let request_bytes: Vec<i64> = records.iter().map(|r| r.request_bytes).collect();
+        let response_bytes: Vec<i64> = records.iter().map(|r| r.response_bytes).collect();
+
+        // One statement for the whole batch; each key appears once per flush
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO api_usage
+                (tenant_id, key_id, api_key, hour,
+                 requests, errors, request_bytes, response_bytes)
+            SELECT * FROM UNNEST(
+                $1::text[], $2::text[], $3::text[], $4::timestamptz[],
+                $5::bigint[], $6::bigint[], $7::bigint[], $8::bigint[]
+            )
+            ON CONFLICT (tenant_id, key_id, hour)
+            DO UPDATE SET
+                requests = api_usage.requests + EXCLUDED.requests,
+                errors = api_usage.errors + EXCLUDED.errors,
+                request_bytes = api_usage.request_bytes + EXCLUDED.request_bytes,
+                response_bytes = api_usage.response_bytes + EXCLUDED.response_bytes
+            "#,
+            &tenants,
+            &key_ids,
+            &api_keys,
+            &hours,
+            &requests,
+            &errors,
+            &request_bytes,
+            &response_bytes
+        )
+        .execute(self.db.pool());
+        self.db.timed("api_usage.add", statement).await?;
+
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        tenant: Option<&TenantId>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<UsageRecord>> {
+        let rows = self
+            .db
+            .read("api_usage.list", |pool| {
+                sqlx::query!(
+                    r#"
+                    SELECT tenant_id, key_id, api_key, hour,
+                           requests, errors, request_bytes, response_bytes
+                    FROM api_usage
+                    WHERE ($1::text IS NULL OR tenant_id = $1)
+                      AND hour >= $2 AND hour < $3
+                    ORDER BY hour, tenant_id, key_id
+                    "#,
+                    tenant.map(TenantId::as_str),
+                    from,
+                    to
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(UsageRecord {
+                    tenant: TenantId::new(row.tenant_id)?,
+                    key_id: Some(row.key_id).filter(|key_id| !key_id.is_empty()),
+                    api_key: Some(row.api_key).filter(|key| !key.is_empty()),
+                    hour: row.hour,
+                    requests: row.requests,
+                    errors: row.errors,
+                    request_bytes: row.request_bytes,
+                    response_bytes: row.response_bytes,
+                })
+            })
+            .collect()
+    }
+
+    async fn requests_by_key_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<(TenantId, String, i64)>> {
+        let rows = self
+            .db
+            .read("api_usage.by_key", |pool| {
+                sqlx::query!(
+                    r#"
+                    SELECT tenant_id, key_id, SUM(requests)::bigint AS "requests!"
+                    FROM api_usage
+                    WHERE hour >= $1 AND key_id <> ''
+                    GROUP BY tenant_id, key_id
+                    "#,
+                    since
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter()
+            .map(|row| Ok((TenantId::new(row.tenant_id)?, row.key_id, row.requests)))
+            .collect()
+    }
+
+    async fn quotas(&self) -> DomainResult<Vec<ApiQuota>> {
+        let rows = self
+            .db
+            .read("api_quotas.list", |pool| {
+                sqlx::query!(
+                    "SELECT tenant_id, key_id, api_key, monthly_requests FROM api_quotas \
+                     ORDER BY tenant_id, key_id"
+                )
+                .fetch_all(pool)
+            })
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(ApiQuota {
+                    tenant: TenantId::new(row.tenant_id)?,
+                    key_id: row.key_id,
+                    api_key: row.api_key,
+                    monthly_requests: row.monthly_requests,
+                })
+            })
+            .collect()
+    }
+
+    async fn set_quota(&self, quota: &ApiQuota) -> DomainResult<()> {
+        let statement = sqlx::query!(
+            r#"
+            INSERT INTO api_quotas (tenant_id, key_id, api_key, monthly_requests)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (tenant_id, key_id)
+            DO UPDATE SET
+                api_key = EXCLUDED.api_key,
+                monthly_requests = EXCLUDED.monthly_requests,
+                updated_at = NOW()
+            "#,
+            quota.tenant.as_str(),
+            quota.key_id,
+            quota.api_key,
+            quota.monthly_requests
+        )
+        .execute(self.db.pool());
+        self.db.timed("api_quotas.set", statement).await?;
+
+        Ok(())
+    }
+
+    async fn remove_quota(&self, tenant: &TenantId, key_id: &str) -> DomainResult<bool> {
+        let statement = sqlx::query!(
+            "DELETE FROM api_quotas WHERE tenant_id = $1 AND key_id = $2",
+            tenant.as_str(),
+            key_id
+        )
+        .execute(self.db.pool());
+        let result = self.db.timed("api_quotas.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
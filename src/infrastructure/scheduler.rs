@@ -0,0 +1,324 @@
+//! In-process Job Scheduler
+//!
+//! Every registered job gets its own task that sleeps until the next tick of
+//! its schedule (plus random jitter, so replicas don't hit the database in
+//! lockstep) and then runs the job. Ticks fall on the same wall-clock times
+//! on every replica. Runs of the same job never overlap: a slow run skips the
+//! ticks it overran.
+//!
+//! With a distributed lock configured, exclusive jobs only run on the replica
+//! that takes the job's lock. The lock is held for at least the jitter window
+//! so replicas that wake up later in the same tick find it taken and skip;
+//! with job ticks recorded too, a replica taking the lock after the tick was
+//! handled skips it all the same.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use tokio::task::JoinSet;
+use tracing::Instrument;
+
+use crate::application::jobs::{Job, JobMonitor};
+use crate::application::ports::{DistributedLock, JobTickStore, LockGuard};
+use crate::domain::errors::DomainResult;
+use crate::infrastructure::error_reporting;
+
+/// When a job runs
+#[derive(Debug, Clone)]
+pub enum JobSchedule {
+    /// Every multiple of the interval since the Unix epoch, so `@every 5m`
+    /// runs at :00, :05, :10 and so on
+    Every(Duration),
+    /// Cron expression with a leading seconds field, evaluated in UTC
+    Cron(Box<cron::Schedule>),
+}
+
+impl JobSchedule {
+    /// First tick strictly after `after`
+    fn next_tick(&self, after: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            JobSchedule::Every(interval) => {
+                let interval = interval.as_millis().max(1) as i64;
+                let ticks = after.timestamp_millis().div_euclid(interval) + 1;
+                DateTime::from_timestamp_millis(ticks.saturating_mul(interval)).unwrap_or(after)
+            }
+            JobSchedule::Cron(schedule) => schedule.after(&after).next().unwrap_or(after),
+        }
+    }
+}
+
+impl fmt::Display for JobSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JobSchedule::Every(interval) => write!(f, "@every {}s", interval.as_secs()),
+            JobSchedule::Cron(schedule) => write!(f, "{}", schedule),
+        }
+    }
+}
+
+impl FromStr for JobSchedule {
+    type Err = String;
+
+    /// Parse either `@every <n>{s,m,h}` or a cron expression
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if let Some(interval) = value.strip_prefix("@every") {
+            return match parse_interval(interval.trim()) {
+                Some(interval) if !interval.is_zero() => Ok(JobSchedule::Every(interval)),
+                _ => Err(format!("invalid interval '{}'", interval.trim())),
+            };
+        }
+
+        cron::Schedule::from_str(value)
+            .map(|schedule| JobSchedule::Cron(Box::new(schedule)))
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Parse a duration such as `30s`, `5m` or `1h`
+fn parse_interval(value: &str) -> Option<Duration> {
+    let split = value.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = value.split_at(split);
+    let amount: u64 = amount.parse().ok()?;
+    let seconds = match unit {
+        "s" => amount,
+        "m" => amount.checked_mul(60)?,
+        "h" => amount.checked_mul(3600)?,
+        _ => return None,
+    };
+    Some(Duration::from_secs(seconds))
+}
+
+/// Collects jobs and their schedules before starting them
+pub struct Scheduler {
+    jobs: Vec<(Arc<dyn Job>, JobSchedule)>,
+    max_jitter: Duration,
+    lock: Option<Arc<dyn DistributedLock>>,
+    ticks: Option<Arc<dyn JobTickStore>>,
+    monitor: JobMonitor,
+}
+
+impl Scheduler {
+    pub fn new(max_jitter: Duration) -> Self {
+        Self {
+            jobs: Vec::new(),
+            max_jitter,
+            lock: None,
+            ticks: None,
+            monitor: JobMonitor::new(),
+        }
+    }
+
+    /// Report the runs of every job to `monitor`
+    pub fn with_monitor(mut self, monitor: JobMonitor) -> Self {
+        self.monitor = monitor;
+        self
+    }
+
+    /// Coordinate exclusive jobs across replicas through `lock`
+    pub fn with_lock(mut self, lock: Arc<dyn DistributedLock>) -> Self {
+        self.lock = Some(lock);
+        self
+    }
+
+    /// Record the ticks exclusive jobs ran for in `ticks`, so replicas
+    /// holding the lock skip ticks that were handled already
+    pub fn with_ticks(mut self, ticks: Arc<dyn JobTickStore>) -> Self {
+        self.ticks = Some(ticks);
+        self
+    }
+
+    /// Register a job to run on the given schedule
+    pub fn register(mut self, job: impl Job + 'static, schedule: JobSchedule) -> Self {
+        self.jobs.push((Arc::new(job), schedule));
+        self
+    }
+
+    /// Start all registered jobs; dropping the handle stops them
+    pub fn start(self) -> SchedulerHandle {
+        let mut tasks = JoinSet::new();
+        for (job, schedule) in self.jobs {
+            tracing::info!("Scheduled job {} ({})", job.name(), schedule);
+            self.monitor
+                .register(job.name(), schedule.to_string(), job.exclusive());
+            let lock = self.lock.clone().filter(|_| job.exclusive());
+            let ticks = self.ticks.clone().filter(|_| job.exclusive());
+            tasks.spawn(run_job(
+                job,
+                schedule,
+                self.max_jitter,
+                lock,
+                ticks,
+                self.monitor.clone(),
+            ));
+        }
+        SchedulerHandle { _tasks: tasks }
+    }
+}
+
+/// Keeps scheduled jobs running for as long as it is alive
+pub struct SchedulerHandle {
+    _tasks: JoinSet<()>,
+}
+
+async fn run_job(
+    job: Arc<dyn Job>,
+    schedule: JobSchedule,
+    max_jitter: Duration,
+    lock: Option<Arc<dyn DistributedLock>>,
+    ticks: Option<Arc<dyn JobTickStore>>,
+    monitor: JobMonitor,
+) {
+    let name = job.name();
+    let mut last_tick = None;
+    loop {
+        // Never the tick just run, should the timer wake up a bit early
+        let now = Utc::now();
+        let tick = schedule.next_tick(last_tick.map_or(now, |last: DateTime<Utc>| last.max(now)));
+        last_tick = Some(tick);
+        let delay = (tick - now).to_std().unwrap_or_default() + jitter(max_jitter);
+        if let Ok(delay) = chrono::Duration::from_std(delay) {
+            monitor.scheduled(name, now + delay);
+        }
+        tokio::time::sleep(delay).await;
+
+        let span = tracing::info_span!("job", job = name);
+        let guard = match &lock {
+            Some(lock) => match lock.try_acquire(name).await {
+                Ok(Some(guard)) => match handled(ticks.as_deref(), name, tick).await {
+                    Ok(false) => Some(guard),
+                    Ok(true) => {
+                        tracing::debug!(parent: &span, "Tick was run by another instance, skipping");
+                        metrics::counter!("job_skipped_total", "job" => name).increment(1);
+                        monitor.skipped(name);
+                        release(guard, &span).await;
+                        continue;
+                    }
+                    Err(e) => {
+                        tracing::error!(parent: &span, "Failed to read the last job tick: {}", e);
+                        release(guard, &span).await;
+                        continue;
+                    }
+                },
+                Ok(None) => {
+                    tracing::debug!(parent: &span, "Job is running on another instance, skipping");
+                    metrics::counter!("job_skipped_total", "job" => name).increment(1);
+                    monitor.skipped(name);
+                    continue;
+                }
+                Err(e) => {
+                    tracing::error!(parent: &span, "Failed to acquire job lock: {}", e);
+                    continue;
+                }
+            },
+            None => None,
+        };
+
+        let started = Instant::now();
+        monitor.started(name);
+        let result = job.run().instrument(span.clone()).await;
+        let elapsed = started.elapsed();
+        monitor.finished(
+            name,
+            elapsed,
+            result.as_ref().err().map(ToString::to_string),
+        );
+
+        metrics::histogram!("job_duration_seconds", "job" => name).record(elapsed.as_secs_f64());
+        match result {
+            Ok(()) => tracing::debug!(parent: &span, "Job finished in {:?}", elapsed),
+            Err(e) => {
+                metrics::counter!("job_failures_total", "job" => name).increment(1);
+                tracing::error!(parent: &span, "Job failed after {:?}: {}", elapsed, e);
+                error_reporting::capture(&e);
+            }
+        }
+
+        if let Some(guard) = guard {
+            // Failed runs count as handled too: they are reported, and a
+            // rerun on another replica could repeat what they did get done
+            if let Some(ticks) = &ticks
+                && let Err(e) = ticks.record(name, tick).await
+            {
+                tracing::warn!(parent: &span, "Failed to record job tick: {}", e);
+            }
+            if let Some(remaining) = max_jitter.checked_sub(elapsed) {
+                tokio::time::sleep(remaining).await;
+            }
+            release(guard, &span).await;
+        }
+    }
+}
+
+/// Whether `tick` of `job` was run already, by any replica
+async fn handled(
+    ticks: Option<&dyn JobTickStore>,
+    job: &str,
+    tick: DateTime<Utc>,
+) -> DomainResult<bool> {
+    match ticks {
+        Some(ticks) => Ok(ticks.last_tick(job).await?.is_some_and(|last| last >= tick)),
+        None => Ok(false),
+    }
+}
+
+async fn release(guard: Box<dyn LockGuard>, span: &tracing::Span) {
+    if let Err(e) = guard.release().await {
+        tracing::warn!(parent: span, "Failed to release job lock: {}", e);
+    }
+}
+
+fn jitter(max: Duration) -> Duration {
+    let max_millis = max.as_millis() as u64;
+    if max_millis == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_millis(rand::random_range(0..=max_millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_intervals() {
+        let schedule: JobSchedule = "@every 5m".parse().unwrap();
+        assert!(
+            matches!(schedule, JobSchedule::Every(interval) if interval == Duration::from_secs(300))
+        );
+        assert!("@every 0s".parse::<JobSchedule>().is_err());
+        assert!("@every 5 minutes".parse::<JobSchedule>().is_err());
+    }
+
+    #[test]
+    fn ticks_fall_on_wall_clock_boundaries() {
+        let at = |time: &str| time.parse::<DateTime<Utc>>().unwrap();
+        let schedule: JobSchedule = "@every 5m".parse().unwrap();
+        assert_eq!(
+            schedule.next_tick(at("2025-01-09T10:03:17Z")),
+            at("2025-01-09T10:05:00Z")
+        );
+        // The tick itself is never next, so it runs once
+        assert_eq!(
+            schedule.next_tick(at("2025-01-09T10:05:00Z")),
+            at("2025-01-09T10:10:00Z")
+        );
+
+        let schedule: JobSchedule = "0 0 7 * * *".parse().unwrap();
+        assert_eq!(
+            schedule.next_tick(at("2025-01-09T07:00:00Z")),
+            at("2025-01-10T07:00:00Z")
+        );
+    }
+
+    #[test]
+    fn parses_cron_expressions() {
+        let schedule: JobSchedule = "0 0 7 * * *".parse().unwrap();
+        let now = Utc::now();
+        assert!(schedule.next_tick(now) - now <= chrono::Duration::hours(24));
+        assert!("every morning".parse::<JobSchedule>().is_err());
+    }
+}
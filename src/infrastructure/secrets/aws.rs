@@ -0,0 +1,175 @@
+//! AWS Secrets Manager secrets
+//!
+//! Requests are signed with Signature Version 4 using the standard
+//! `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and optional
+//! `AWS_SESSION_TOKEN` environment variables.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tokio::sync::OnceCell;
+
+use crate::application::ports::SecretsProvider;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::http_client::{HttpClient, HttpClientSettings, HttpError};
+
+const SERVICE: &str = "secretsmanager";
+const TARGET: &str = "secretsmanager.GetSecretValue";
+const CONTENT_TYPE: &str = "application/x-amz-json-1.1";
+
+#[derive(Deserialize)]
+struct GetSecretValueResponse {
+    #[serde(rename = "SecretString")]
+    secret_string: Option<String>,
+}
+
+/// Static credentials used to sign requests
+struct Credentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+}
+
+impl Credentials {
+    fn from_env() -> DomainResult<Self> {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+        match (var("AWS_ACCESS_KEY_ID"), var("AWS_SECRET_ACCESS_KEY")) {
+            (Some(access_key_id), Some(secret_access_key)) => Ok(Self {
+                access_key_id,
+                secret_access_key,
+                session_token: var("AWS_SESSION_TOKEN"),
+            }),
+            _ => Err(AppError::internal(
+                "AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY must be set",
+            )),
+        }
+    }
+}
+
+/// Reads secrets from one JSON secret, fetched once on first use
+pub struct AwsSecretsManagerProvider {
+    client: HttpClient,
+    credentials: Credentials,
+    region: String,
+    secret_id: String,
+    secrets: OnceCell<HashMap<String, String>>,
+}
+
+impl AwsSecretsManagerProvider {
+    pub fn new(region: &str, secret_id: &str, http: &HttpClientSettings) -> DomainResult<Self> {
+        Ok(Self {
+            client: HttpClient::new(*http)?,
+            credentials: Credentials::from_env()?,
+            region: region.to_string(),
+            secret_id: secret_id.to_string(),
+            secrets: OnceCell::new(),
+        })
+    }
+
+    async fn fetch(&self) -> DomainResult<HashMap<String, String>> {
+        let host = format!("{}.{}.amazonaws.com", SERVICE, self.region);
+        let payload = serde_json::json!({ "SecretId": self.secret_id }).to_string();
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+        let mut headers = vec![
+            ("content-type", CONTENT_TYPE.to_string()),
+            ("host", host.clone()),
+            ("x-amz-date", amz_date.clone()),
+        ];
+        if let Some(token) = &self.credentials.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+        headers.push(("x-amz-target", TARGET.to_string()));
+
+        let authorization = self.authorization(&headers, &payload, &amz_date);
+
+        let mut request = self
+            .client
+            .post(format!("https://{}/", host))
+            .header("authorization", authorization)
+            .body(payload);
+        for (name, value) in headers.iter().filter(|(name, _)| *name != "host") {
+            request = request.header(*name, value);
+        }
+
+        // GetSecretValue only reads, so retrying the POST is safe
+        let response = self
+            .client
+            .send_idempotent(request)
+            .await
+            .and_then(|response| response.error_for_status().map_err(HttpError::from))
+            .map_err(|e| AppError::internal(format!("Failed to read AWS secret: {}", e)))?;
+
+        let body: GetSecretValueResponse = response
+            .json()
+            .await
+            .map_err(|e| AppError::internal(format!("Invalid Secrets Manager response: {}", e)))?;
+
+        let secret_string = body
+            .secret_string
+            .ok_or_else(|| AppError::internal("AWS secret has no SecretString"))?;
+        let document = serde_json::from_str(&secret_string)
+            .map_err(|e| AppError::internal(format!("AWS secret is not valid JSON: {}", e)))?;
+
+        super::parse_document(document)
+    }
+
+    /// Signature Version 4 `Authorization` header; `headers` must be sorted by name
+    fn authorization(&self, headers: &[(&str, String)], payload: &str, amz_date: &str) -> String {
+        let date = &amz_date[..8];
+        let scope = format!("{}/{}/{}/aws4_request", date, self.region, SERVICE);
+
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+
+        let canonical_request = format!(
+            "POST\n/\n\n{}\n{}\n{}",
+            canonical_headers,
+            signed_headers,
+            hex::encode(Sha256::digest(payload))
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request))
+        );
+
+        let secret = format!("AWS4{}", self.credentials.secret_access_key);
+        let key = [date, self.region.as_str(), SERVICE, "aws4_request"]
+            .iter()
+            .fold(secret.into_bytes(), |key, part| hmac_sha256(&key, part));
+        let signature = hex::encode(hmac_sha256(&key, &string_to_sign));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.credentials.access_key_id, scope, signed_headers, signature
+        )
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[async_trait]
+impl SecretsProvider for AwsSecretsManagerProvider {
+    async fn get(&self, name: &str) -> DomainResult<Option<String>> {
+        let secrets = self.secrets.get_or_try_init(|| self.fetch()).await?;
+        Ok(secrets.get(name).cloned())
+    }
+}
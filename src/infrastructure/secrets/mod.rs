@@ -0,0 +1,82 @@
+//! Secrets Providers
+//!
+//! `SECRETS_PROVIDER` selects where credentials such as `DATABASE_URL` come
+//! from: plain environment variables (the default), a HashiCorp Vault KV v2
+//! secret, or an AWS Secrets Manager secret. The remote providers store all
+//! credentials as one JSON object keyed by setting name and require the
+//! `secrets` feature.
+
+#[cfg(feature = "secrets")]
+mod aws;
+#[cfg(feature = "secrets")]
+mod vault;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::ports::SecretsProvider;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::config::SecretsSource;
+use crate::infrastructure::http_client::HttpClientSettings;
+
+#[cfg(feature = "secrets")]
+pub use aws::AwsSecretsManagerProvider;
+#[cfg(feature = "secrets")]
+pub use vault::VaultSecretsProvider;
+
+/// Reads secrets from environment variables
+pub struct EnvSecretsProvider;
+
+#[async_trait]
+impl SecretsProvider for EnvSecretsProvider {
+    async fn get(&self, name: &str) -> DomainResult<Option<String>> {
+        Ok(std::env::var(name).ok().filter(|value| !value.is_empty()))
+    }
+}
+
+/// Build the provider selected by configuration
+#[cfg_attr(not(feature = "secrets"), allow(unused_variables))]
+pub fn provider(
+    source: &SecretsSource,
+    http: &HttpClientSettings,
+) -> DomainResult<Arc<dyn SecretsProvider>> {
+    match source {
+        SecretsSource::Env => Ok(Arc::new(EnvSecretsProvider)),
+        #[cfg(feature = "secrets")]
+        SecretsSource::Vault {
+            address,
+            token,
+            mount,
+            path,
+        } => Ok(Arc::new(VaultSecretsProvider::new(
+            address, token, mount, path, http,
+        )?)),
+        #[cfg(feature = "secrets")]
+        SecretsSource::AwsSecretsManager { region, secret_id } => Ok(Arc::new(
+            AwsSecretsManagerProvider::new(region, secret_id, http)?,
+        )),
+        #[cfg(not(feature = "secrets"))]
+        _ => Err(AppError::internal(
+            "SECRETS_PROVIDER requires building with the `secrets` feature",
+        )),
+    }
+}
+
+/// Turn a secret document (a JSON object of strings) into a lookup table
+#[cfg_attr(not(feature = "secrets"), allow(dead_code))]
+fn parse_document(document: serde_json::Value) -> DomainResult<HashMap<String, String>> {
+    let serde_json::Value::Object(entries) = document else {
+        return Err(AppError::internal("Secret must be a JSON object"));
+    };
+
+    Ok(entries
+        .into_iter()
+        .filter_map(|(key, value)| match value {
+            serde_json::Value::String(value) => Some((key, value)),
+            serde_json::Value::Null => None,
+            other => Some((key, other.to_string())),
+        })
+        .collect())
+}
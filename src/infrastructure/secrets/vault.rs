@@ -0,0 +1,79 @@
+//! HashiCorp Vault KV v2 secrets
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+use crate::application::ports::SecretsProvider;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::http_client::{HttpClient, HttpClientSettings, HttpError};
+
+#[derive(Deserialize)]
+struct KvResponse {
+    data: KvData,
+}
+
+#[derive(Deserialize)]
+struct KvData {
+    data: serde_json::Value,
+}
+
+/// Reads secrets from one KV v2 secret, fetched once on first use
+pub struct VaultSecretsProvider {
+    client: HttpClient,
+    url: String,
+    token: String,
+    secrets: OnceCell<HashMap<String, String>>,
+}
+
+impl VaultSecretsProvider {
+    pub fn new(
+        address: &str,
+        token: &str,
+        mount: &str,
+        path: &str,
+        http: &HttpClientSettings,
+    ) -> DomainResult<Self> {
+        Ok(Self {
+            client: HttpClient::new(*http)?,
+            url: format!(
+                "{}/v1/{}/data/{}",
+                address.trim_end_matches('/'),
+                mount.trim_matches('/'),
+                path.trim_matches('/')
+            ),
+            token: token.to_string(),
+            secrets: OnceCell::new(),
+        })
+    }
+
+    async fn fetch(&self) -> DomainResult<HashMap<String, String>> {
+        let request = self
+            .client
+            .get(&self.url)
+            .header("X-Vault-Token", &self.token);
+        let response = self
+            .client
+            .send(request)
+            .await
+            .and_then(|response| response.error_for_status().map_err(HttpError::from))
+            .map_err(|e| AppError::internal(format!("Failed to read Vault secret: {}", e)))?;
+
+        let body: KvResponse = response
+            .json()
+            .await
+            .map_err(|e| AppError::internal(format!("Invalid Vault response: {}", e)))?;
+
+        super::parse_document(body.data.data)
+    }
+}
+
+#[async_trait]
+impl SecretsProvider for VaultSecretsProvider {
+    async fn get(&self, name: &str) -> DomainResult<Option<String>> {
+        let secrets = self.secrets.get_or_try_init(|| self.fetch()).await?;
+        Ok(secrets.get(name).cloned())
+    }
+}
@@ -0,0 +1,155 @@
+//! Courier Rates
+//!
+//! Quotes from a courier or courier aggregator API, in the shape JNE- and
+//! SiCepat-style tariff APIs take once coordinates stand in for their area
+//! codes. The provider is sent
+//!
+//! ```json
+//! { "origin": { "latitude": -6.2, "longitude": 106.8 },
+//!   "destination": { "latitude": -6.9, "longitude": 107.6 },
+//!   "weight_grams": 1200 }
+//! ```
+//!
+//! with the API key, if any, in `X-Api-Key`, and answers
+//!
+//! ```json
+//! { "rates": [ { "service": "REG", "price": 18000, "etd_days": 2 } ] }
+//! ```
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::CourierSettings;
+use crate::application::ports::{Shipment, ShippingRateProvider};
+use crate::domain::delivery::ShippingRate;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::store::GeoPoint;
+use crate::infrastructure::http_client::{HttpClient, HttpClientSettings, HttpError};
+
+#[derive(Serialize)]
+struct Point {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl From<GeoPoint> for Point {
+    fn from(point: GeoPoint) -> Self {
+        Self {
+            latitude: point.latitude(),
+            longitude: point.longitude(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct QuoteRequest {
+    origin: Point,
+    destination: Point,
+    weight_grams: u32,
+}
+
+#[derive(Deserialize)]
+struct QuoteResponse {
+    rates: Vec<CourierRate>,
+}
+
+#[derive(Deserialize)]
+struct CourierRate {
+    service: String,
+    price: f64,
+    etd_days: Option<u32>,
+}
+
+/// Quotes rates from a courier's API
+pub struct CourierRateProvider {
+    client: HttpClient,
+    settings: CourierSettings,
+}
+
+impl CourierRateProvider {
+    pub fn new(settings: CourierSettings, http: &HttpClientSettings) -> DomainResult<Self> {
+        Ok(Self {
+            client: HttpClient::new(*http)?,
+            settings,
+        })
+    }
+}
+
+/// Rates of a courier's answer, quoted under `carrier`
+fn parse_rates(carrier: &str, body: &[u8]) -> DomainResult<Vec<ShippingRate>> {
+    let response: QuoteResponse = serde_json::from_slice(body).map_err(|e| {
+        AppError::internal(format!("Invalid rates from courier '{}': {}", carrier, e))
+    })?;
+    response
+        .rates
+        .into_iter()
+        .map(|rate| ShippingRate::new(carrier, rate.service, rate.price, rate.etd_days))
+        .collect()
+}
+
+#[async_trait]
+impl ShippingRateProvider for CourierRateProvider {
+    fn name(&self) -> &str {
+        &self.settings.name
+    }
+
+    async fn quote(&self, shipment: &Shipment) -> DomainResult<Vec<ShippingRate>> {
+        let mut request = self.client.post(&self.settings.url).json(&QuoteRequest {
+            origin: self.settings.origin.into(),
+            destination: shipment.destination.into(),
+            weight_grams: shipment.weight_grams,
+        });
+        if let Some(api_key) = &self.settings.api_key {
+            request = request.header("X-Api-Key", api_key);
+        }
+
+        // Quoting changes nothing, so it is retried like a GET
+        let response = self
+            .client
+            .send_idempotent(request)
+            .await
+            .and_then(|response| response.error_for_status().map_err(HttpError::from))
+            .map_err(|e| {
+                AppError::internal(format!(
+                    "Failed to quote courier '{}': {}",
+                    self.settings.name, e
+                ))
+            })?;
+        let body = response.bytes().await.map_err(|e| {
+            AppError::internal(format!(
+                "Failed to read rates of courier '{}': {}",
+                self.settings.name, e
+            ))
+        })?;
+
+        parse_rates(&self.settings.name, &body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rates_are_read_from_the_courier_answer() {
+        let body = br#"{ "rates": [
+            { "service": "REG", "price": 18000, "etd_days": 2 },
+            { "service": "YES", "price": 32000.0 }
+        ] }"#;
+        let rates = parse_rates("jne", body).unwrap();
+        assert_eq!(
+            rates,
+            [
+                ShippingRate::new("jne", "REG", 18_000.0, Some(2)).unwrap(),
+                ShippingRate::new("jne", "YES", 32_000.0, None).unwrap(),
+            ]
+        );
+
+        assert!(parse_rates("jne", br#"{ "price": [] }"#).is_err());
+        let negative = br#"{ "rates": [ { "service": "REG", "price": -1 } ] }"#;
+        assert_eq!(
+            parse_rates("jne", negative).unwrap_err().code(),
+            "shipping.fee.invalid"
+        );
+    }
+}
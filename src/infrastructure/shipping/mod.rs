@@ -0,0 +1,55 @@
+//! Shipping Rates
+//!
+//! Delivery is quoted from a rate table (`SHIPPING_RATE_TABLE`) and from a
+//! courier's API (`SHIPPING_COURIER_URL`, requires the `shipping` feature);
+//! either, both or neither may be configured.
+
+#[cfg(feature = "shipping")]
+pub mod courier;
+pub mod table;
+
+use std::sync::Arc;
+
+use crate::application::ports::ShippingRateProvider;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::store::GeoPoint;
+use crate::infrastructure::config::AppConfig;
+
+#[cfg(feature = "shipping")]
+pub use courier::CourierRateProvider;
+pub use table::{RateTable, TableRateProvider};
+
+/// Courier API quoting deliveries
+#[derive(Debug, Clone, PartialEq)]
+pub struct CourierSettings {
+    /// Carrier the rates are quoted under, such as `jne`
+    pub name: String,
+    pub url: String,
+    pub api_key: Option<String>,
+    /// Where parcels are sent from
+    pub origin: GeoPoint,
+}
+
+/// Rate providers of the configuration
+pub fn providers(config: &AppConfig) -> DomainResult<Vec<Arc<dyn ShippingRateProvider>>> {
+    if let (Some(courier), false) = (&config.shipping_courier, cfg!(feature = "shipping")) {
+        return Err(AppError::internal(format!(
+            "Courier '{}' is quoted over HTTP, which requires building with the `shipping` feature",
+            courier.name
+        )));
+    }
+
+    let mut providers: Vec<Arc<dyn ShippingRateProvider>> = Vec::new();
+    if let Some(table) = &config.shipping_rate_table {
+        providers.push(Arc::new(TableRateProvider::new(table.clone())));
+    }
+    #[cfg(feature = "shipping")]
+    if let Some(courier) = &config.shipping_courier {
+        providers.push(Arc::new(CourierRateProvider::new(
+            courier.clone(),
+            &config.http_client,
+        )?));
+    }
+
+    Ok(providers)
+}
@@ -0,0 +1,151 @@
+//! Table Rates
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use crate::application::ports::{Shipment, ShippingRateProvider};
+use crate::domain::delivery::ShippingRate;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::Price;
+
+/// Fees by parcel weight
+///
+/// Written as `max_grams=fee` pairs, `*` standing for any heavier parcel:
+/// `1000=15000,5000=25000,*=40000` charges 15000 up to 1 kg, 25000 up to
+/// 5 kg and 40000 above. A lone amount such as `15000` is a flat rate.
+/// Parcels heavier than every tier, without a `*` one, are not delivered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateTable {
+    /// Heaviest weight in grams and its fee, lightest first
+    tiers: Vec<(u32, f64)>,
+    /// Fee of parcels heavier than every tier
+    rest: Option<f64>,
+}
+
+impl RateTable {
+    /// Same fee whatever the weight
+    pub fn flat(fee: f64) -> Self {
+        Self {
+            tiers: Vec::new(),
+            rest: Some(fee),
+        }
+    }
+
+    /// Fee of a parcel weighing `grams`; `None` when too heavy
+    pub fn fee(&self, grams: u32) -> Option<f64> {
+        self.tiers
+            .iter()
+            .find(|(max, _)| grams <= *max)
+            .map(|(_, fee)| *fee)
+            .or(self.rest)
+    }
+}
+
+impl FromStr for RateTable {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let fee = |value: &str| match value.trim().parse::<f64>() {
+            Ok(fee) if fee.is_finite() && (0.0..=Price::MAX).contains(&fee) => Ok(fee),
+            _ => Err(format!("invalid fee '{}'", value.trim())),
+        };
+
+        let entries: Vec<&str> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .collect();
+        if let [entry] = entries[..]
+            && !entry.contains('=')
+        {
+            return Ok(Self::flat(fee(entry)?));
+        }
+
+        let mut table = Self {
+            tiers: Vec::new(),
+            rest: None,
+        };
+        for entry in entries {
+            let Some((weight, amount)) = entry.split_once('=') else {
+                return Err(format!("expected max_grams=fee, got '{}'", entry));
+            };
+            let amount = fee(amount)?;
+            match weight.trim() {
+                "*" if table.rest.is_none() => table.rest = Some(amount),
+                weight => match weight.parse::<u32>() {
+                    Ok(max) if !table.tiers.iter().any(|(other, _)| *other == max) => {
+                        table.tiers.push((max, amount))
+                    }
+                    _ => return Err(format!("invalid or repeated weight '{}'", weight)),
+                },
+            }
+        }
+        if table.tiers.is_empty() && table.rest.is_none() {
+            return Err("no rates".to_string());
+        }
+        table.tiers.sort_by_key(|(max, _)| *max);
+        Ok(table)
+    }
+}
+
+/// Quotes the shop's own deliveries from a rate table
+pub struct TableRateProvider {
+    table: RateTable,
+}
+
+impl TableRateProvider {
+    /// Carrier the rates are quoted under
+    pub const CARRIER: &'static str = "shop";
+
+    pub fn new(table: RateTable) -> Self {
+        Self { table }
+    }
+}
+
+#[async_trait]
+impl ShippingRateProvider for TableRateProvider {
+    fn name(&self) -> &str {
+        "rate_table"
+    }
+
+    async fn quote(&self, shipment: &Shipment) -> DomainResult<Vec<ShippingRate>> {
+        self.table
+            .fee(shipment.weight_grams)
+            .map(|fee| ShippingRate::new(Self::CARRIER, "standard", fee, None))
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fees_follow_the_lightest_tier_holding_the_parcel() {
+        let table: RateTable = "5000=25000, 1000=15000".parse().unwrap();
+        assert_eq!(table.fee(800), Some(15_000.0));
+        assert_eq!(table.fee(1000), Some(15_000.0));
+        assert_eq!(table.fee(1001), Some(25_000.0));
+        assert_eq!(table.fee(5001), None);
+
+        let capped: RateTable = "1000=15000,*=40000".parse().unwrap();
+        assert_eq!(capped.fee(20_000), Some(40_000.0));
+        assert_eq!(
+            "15000".parse::<RateTable>().unwrap(),
+            RateTable::flat(15_000.0)
+        );
+
+        for invalid in [
+            "",
+            "1000=",
+            "1000=-1",
+            "1000=1,1000=2",
+            "heavy=1",
+            "*=1,*=2",
+        ] {
+            assert!(invalid.parse::<RateTable>().is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! SQLite implementation of DeliveryZoneRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::DeliveryZoneRepository;
+use crate::domain::delivery::DeliveryZone;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::TenantId;
+use crate::domain::store::GeoPoint;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for DeliveryZone
+#[derive(Debug, FromRow)]
+struct DeliveryZoneRow {
+    id: Hyphenated,
+    tenant_id: String,
+    name: String,
+    boundary: Json<Value>,
+    fee: f64,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<DeliveryZoneRow> for DeliveryZone {
+    type Error = AppError;
+
+    fn try_from(row: DeliveryZoneRow) -> Result<Self, Self::Error> {
+        let id = row.id.into_uuid();
+        let corners: Vec<(f64, f64)> = serde_json::from_value(row.boundary.0).map_err(|e| {
+            AppError::internal(format!("Invalid boundary of delivery zone {}: {}", id, e))
+        })?;
+        let boundary = corners
+            .into_iter()
+            .map(|(latitude, longitude)| GeoPoint::new(latitude, longitude))
+            .collect::<DomainResult<_>>()?;
+
+        Ok(DeliveryZone::from_persistence(
+            id,
+            TenantId::new(row.tenant_id)?,
+            row.name,
+            boundary,
+            row.fee,
+            row.created_at,
+        ))
+    }
+}
+
+/// Boundary as stored: an array of `[latitude, longitude]` corners
+fn boundary_json(zone: &DeliveryZone) -> Value {
+    zone.boundary()
+        .iter()
+        .map(|point| serde_json::json!([point.latitude(), point.longitude()]))
+        .collect()
+}
+
+/// SQLite implementation of DeliveryZoneRepository
+pub struct SqliteDeliveryZoneRepository {
+    db: DatabasePool,
+}
+
+impl SqliteDeliveryZoneRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DeliveryZoneRepository for SqliteDeliveryZoneRepository {
+    async fn create(&self, zone: &DeliveryZone) -> DomainResult<DeliveryZone> {
+        let statement = sqlx::query_as::<_, DeliveryZoneRow>(
+            r#"
+            INSERT INTO delivery_zones (id, tenant_id, name, boundary, fee, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            RETURNING id, tenant_id, name, boundary, fee, created_at
+            "#,
+        )
+        .bind(zone.id().hyphenated())
+        .bind(zone.tenant_id().as_str())
+        .bind(zone.name())
+        .bind(Json(boundary_json(zone)))
+        .bind(zone.fee())
+        .bind(zone.created_at())
+        .fetch_one(self.db.sqlite_pool());
+        let row = self.db.timed("delivery_zones.create", statement).await?;
+
+        row.try_into()
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<DeliveryZone>> {
+        let statement = sqlx::query_as::<_, DeliveryZoneRow>(
+            r#"
+            SELECT id, tenant_id, name, boundary, fee, created_at
+            FROM delivery_zones
+            WHERE tenant_id = ?1
+            ORDER BY name, id
+            "#,
+        )
+        .bind(tenant.as_str())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("delivery_zones.find_all", statement).await?;
+
+        rows.into_iter().map(DeliveryZone::try_from).collect()
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let statement = sqlx::query("DELETE FROM delivery_zones WHERE tenant_id = ?1 AND id = ?2")
+            .bind(tenant.as_str())
+            .bind(id.hyphenated())
+            .execute(self.db.sqlite_pool());
+        let result = self.db.timed("delivery_zones.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,71 @@
+//! SQLite implementation of FeatureFlagRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+use crate::application::ports::FeatureFlagRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::feature_flag::FeatureFlag;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for FeatureFlag
+#[derive(Debug, FromRow)]
+struct FeatureFlagRow {
+    key: String,
+    enabled: bool,
+    description: Option<String>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<FeatureFlagRow> for FeatureFlag {
+    fn from(row: FeatureFlagRow) -> Self {
+        FeatureFlag::from_persistence(row.key, row.enabled, row.description, row.updated_at)
+    }
+}
+
+/// SQLite implementation of FeatureFlagRepository
+pub struct SqliteFeatureFlagRepository {
+    db: DatabasePool,
+}
+
+impl SqliteFeatureFlagRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl FeatureFlagRepository for SqliteFeatureFlagRepository {
+    async fn find_all(&self) -> DomainResult<Vec<FeatureFlag>> {
+        let statement = sqlx::query_as::<_, FeatureFlagRow>(
+            "SELECT key, enabled, description, updated_at FROM feature_flags ORDER BY key",
+        )
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("feature_flags.find_all", statement).await?;
+
+        Ok(rows.into_iter().map(FeatureFlag::from).collect())
+    }
+
+    async fn upsert(&self, flag: &FeatureFlag) -> DomainResult<FeatureFlag> {
+        let statement = sqlx::query_as::<_, FeatureFlagRow>(
+            r#"
+            INSERT INTO feature_flags (key, enabled, description, updated_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT (key) DO UPDATE
+            SET enabled = excluded.enabled,
+                description = COALESCE(excluded.description, feature_flags.description),
+                updated_at = excluded.updated_at
+            RETURNING key, enabled, description, updated_at
+            "#,
+        )
+        .bind(flag.key())
+        .bind(flag.enabled())
+        .bind(flag.description())
+        .bind(flag.updated_at())
+        .fetch_one(self.db.sqlite_pool());
+        let row = self.db.timed("feature_flags.upsert", statement).await?;
+
+        Ok(row.into())
+    }
+}
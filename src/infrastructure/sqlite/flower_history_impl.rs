@@ -0,0 +1,195 @@
+//! SQLite flower history
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqliteConnection};
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::FlowerHistory;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::FlowerChange;
+use crate::domain::shared::{Pagination, TenantId};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for FlowerChange, values as JSON text
+#[derive(Debug, FromRow)]
+struct FlowerChangeRow {
+    id: Hyphenated,
+    tenant_id: String,
+    flower_id: Hyphenated,
+    field: String,
+    old_value: String,
+    new_value: String,
+    actor: String,
+    changed_at: DateTime<Utc>,
+}
+
+impl TryFrom<FlowerChangeRow> for FlowerChange {
+    type Error = AppError;
+
+    fn try_from(row: FlowerChangeRow) -> Result<Self, Self::Error> {
+        let value = |text: &str| {
+            serde_json::from_str(text)
+                .map_err(|e| AppError::internal(format!("Invalid flower change value: {}", e)))
+        };
+        Ok(FlowerChange::from_persistence(
+            row.id.into_uuid(),
+            TenantId::new(row.tenant_id)?,
+            row.flower_id.into_uuid(),
+            row.field,
+            value(&row.old_value)?,
+            value(&row.new_value)?,
+            row.actor,
+            row.changed_at,
+        ))
+    }
+}
+
+/// Appends flower changes to the `flower_changes` table and reads them back
+pub struct SqliteFlowerHistory {
+    db: DatabasePool,
+}
+
+impl SqliteFlowerHistory {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+
+    /// Record changes on a connection, such as an open transaction
+    pub(crate) async fn record_in(
+        &self,
+        connection: &mut SqliteConnection,
+        changes: &[FlowerChange],
+    ) -> DomainResult<()> {
+        for change in changes {
+            let statement = sqlx::query(
+                r#"
+                INSERT INTO flower_changes (id, tenant_id, flower_id, field, old_value, new_value, actor, changed_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                "#,
+            )
+            .bind(change.id().hyphenated())
+            .bind(change.tenant_id().as_str())
+            .bind(change.flower_id().hyphenated())
+            .bind(change.field())
+            .bind(change.old_value().to_string())
+            .bind(change.new_value().to_string())
+            .bind(change.actor())
+            .bind(change.changed_at())
+            .execute(&mut *connection);
+            self.db.timed("flower_changes.record", statement).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FlowerHistory for SqliteFlowerHistory {
+    async fn find(
+        &self,
+        tenant: &TenantId,
+        flower_id: Uuid,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<FlowerChange>> {
+        let statement = sqlx::query_as::<_, FlowerChangeRow>(
+            r#"
+            SELECT id, tenant_id, flower_id, field, old_value, new_value, actor, changed_at
+            FROM flower_changes
+            WHERE tenant_id = ?1 AND flower_id = ?2
+            ORDER BY changed_at, id
+            LIMIT ?3 OFFSET ?4
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(flower_id.hyphenated())
+        .bind(pagination.limit())
+        .bind(pagination.offset())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("flower_changes.find", statement).await?;
+
+        rows.into_iter().map(FlowerChange::try_from).collect()
+    }
+
+    async fn count(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<i64> {
+        let statement = sqlx::query_as(
+            "SELECT COUNT(*) FROM flower_changes WHERE tenant_id = ?1 AND flower_id = ?2",
+        )
+        .bind(tenant.as_str())
+        .bind(flower_id.hyphenated())
+        .fetch_one(self.db.sqlite_pool());
+        let result: (i64,) = self.db.timed("flower_changes.count", statement).await?;
+
+        Ok(result.0)
+    }
+
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64> {
+        let statement =
+            sqlx::query("DELETE FROM flower_changes WHERE tenant_id = ?1 AND flower_id = ?2")
+                .bind(tenant.as_str())
+                .bind(flower_id.hyphenated())
+                .execute(self.db.sqlite_pool());
+        let result = self.db.timed("flower_changes.delete", statement).await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::application::ports::UnitOfWork;
+    use crate::domain::flower::Price;
+    use crate::domain::shared::Entity;
+    use crate::infrastructure::sqlite::SqliteUnitOfWork;
+    use crate::test_support::FlowerBuilder;
+
+    #[tokio::test]
+    async fn records_changes_with_the_update() {
+        let path = std::env::temp_dir().join(format!("history-{}.db", Uuid::new_v4()));
+        let db = DatabasePool::new(&format!("sqlite://{}", path.display()), Default::default())
+            .await
+            .unwrap();
+        db.run_migrations().await.unwrap();
+        let history = SqliteFlowerHistory::new(db.clone());
+
+        let before = FlowerBuilder::new()
+            .with_price(25_000.0)
+            .with_description("Fresh")
+            .build();
+        let mut after = before.clone();
+        after.update_price(Price::new(30_000.0).unwrap());
+        after.update_description(None);
+        let changes = FlowerChange::between(&before, &after, "admin");
+
+        let mut tx = SqliteUnitOfWork::new(db).begin().await.unwrap();
+        tx.record_flower_changes(&changes).await.unwrap();
+        tx.commit().await.unwrap();
+
+        let tenant = before.tenant_id();
+        let found = history
+            .find(tenant, before.id(), &Pagination::default())
+            .await
+            .unwrap();
+        let mut values: Vec<_> = found
+            .iter()
+            .map(|change| (change.field(), change.old_value(), change.new_value()))
+            .collect();
+        values.sort_by_key(|(field, _, _)| *field);
+        assert_eq!(
+            values,
+            [
+                ("description", &json!("Fresh"), &Value::Null),
+                ("price", &json!(25_000.0), &json!(30_000.0)),
+            ]
+        );
+        assert!(found.iter().all(|change| change.actor() == "admin"));
+
+        assert_eq!(history.delete_flower(tenant, before.id()).await.unwrap(), 2);
+        assert_eq!(history.count(tenant, before.id()).await.unwrap(), 0);
+        let _ = std::fs::remove_file(path);
+    }
+}
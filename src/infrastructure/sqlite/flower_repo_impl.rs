@@ -0,0 +1,573 @@
+//! SQLite implementation of FlowerRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{FromRow, SqliteExecutor};
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::{FlowerFacets, FlowerRepository};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::{
+    Flower, FlowerAttributes, FlowerColor, FlowerDescription, FlowerError, FlowerMetadata,
+    FlowerName, Price, Sku, StockQuantity, UnitOfMeasure,
+};
+use crate::domain::shared::{Entity, Pagination, TenantId};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for Flower
+#[derive(Debug, FromRow)]
+struct FlowerRow {
+    id: Hyphenated,
+    tenant_id: String,
+    name: String,
+    color: String,
+    description: Option<String>,
+    price: f64,
+    stock: i32,
+    sku: Option<String>,
+    attributes: Json<FlowerAttributes>,
+    metadata: Json<FlowerMetadata>,
+    unit: String,
+    stems_per_unit: i32,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<FlowerRow> for Flower {
+    type Error = AppError;
+
+    fn try_from(row: FlowerRow) -> Result<Self, Self::Error> {
+        Flower::from_persistence(
+            row.id.into_uuid(),
+            TenantId::new(row.tenant_id)?,
+            FlowerName::new(row.name)?,
+            row.color.parse()?,
+            row.description
+                .and_then(FlowerDescription::from_persistence),
+            Price::new(row.price)?,
+            StockQuantity::new(row.stock)?,
+            UnitOfMeasure::new(&row.unit, Some(row.stems_per_unit))?,
+            row.sku.map(Sku::new).transpose()?.flatten(),
+            row.attributes.0,
+            row.metadata.0,
+            row.created_at,
+            row.updated_at,
+        )
+    }
+}
+
+/// SQLite implementation of FlowerRepository
+pub struct SqliteFlowerRepository {
+    db: DatabasePool,
+}
+
+impl SqliteFlowerRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+
+    /// Translate a unique name or SKU violation into a conflict carrying the
+    /// existing ID
+    ///
+    /// SQLite does not report which index was violated, so the SKU is checked
+    /// first; the only other unique key is the primary key, and generated IDs
+    /// do not collide.
+    async fn map_write_error(&self, error: sqlx::Error, flower: &Flower) -> AppError {
+        let is_unique_violation = matches!(
+            &error,
+            sqlx::Error::Database(db_error) if db_error.is_unique_violation()
+        );
+        if !is_unique_violation {
+            return error.into();
+        }
+
+        if let Some(sku) = flower.sku() {
+            let existing_id: Option<Hyphenated> = sqlx::query_scalar(
+                "SELECT id FROM flowers WHERE tenant_id = ?1 AND sku = ?2 AND id <> ?3",
+            )
+            .bind(flower.tenant_id().as_str())
+            .bind(sku)
+            .bind(flower.id().hyphenated())
+            .fetch_optional(self.db.sqlite_pool())
+            .await
+            .ok()
+            .flatten();
+            if let Some(existing_id) = existing_id {
+                return FlowerError::sku_taken(sku, Some(existing_id.into_uuid()));
+            }
+        }
+
+        let existing_id: Option<Hyphenated> = sqlx::query_scalar(
+            "SELECT id FROM flowers WHERE tenant_id = ?1 AND LOWER(name) = LOWER(?2)",
+        )
+        .bind(flower.tenant_id().as_str())
+        .bind(flower.name())
+        .fetch_optional(self.db.sqlite_pool())
+        .await
+        .ok()
+        .flatten();
+
+        FlowerError::name_taken(flower.name(), existing_id.map(Hyphenated::into_uuid))
+    }
+
+    /// Load a flower inside a transaction; SQLite transactions started with
+    /// `BEGIN IMMEDIATE` already hold the database write lock
+    pub(crate) async fn lock_in<'e>(
+        &self,
+        executor: impl SqliteExecutor<'e>,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<Option<Flower>> {
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            SELECT id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            FROM flowers
+            WHERE tenant_id = ?1 AND id = ?2
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(id.hyphenated())
+        .fetch_optional(executor);
+        let result = self.db.timed("flowers.lock", statement).await?;
+
+        result.map(Flower::try_from).transpose()
+    }
+
+    /// Load the flowers of a tenant matching every given criterion; the
+    /// transaction already holds the database write lock
+    pub(crate) async fn lock_all_in<'e>(
+        &self,
+        executor: impl SqliteExecutor<'e>,
+        tenant: &TenantId,
+        color: Option<FlowerColor>,
+        ids: Option<&[Uuid]>,
+    ) -> DomainResult<Vec<Flower>> {
+        // IDs travel as one JSON array rather than a variable number of binds
+        let ids = ids.map(|ids| {
+            serde_json::Value::from_iter(ids.iter().map(|id| id.hyphenated().to_string()))
+                .to_string()
+        });
+
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            SELECT id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            FROM flowers
+            WHERE tenant_id = ?1
+              AND (?2 IS NULL OR color = ?2)
+              AND (?3 IS NULL OR id IN (SELECT value FROM json_each(?3)))
+            ORDER BY id
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(color.map(|c| c.as_str()))
+        .bind(ids)
+        .fetch_all(executor);
+        let rows = self.db.timed("flowers.lock_all", statement).await?;
+
+        rows.into_iter().map(Flower::try_from).collect()
+    }
+
+    /// Insert a flower through any executor, such as an open transaction
+    pub(crate) async fn create_in<'e>(
+        &self,
+        executor: impl SqliteExecutor<'e>,
+        flower: &Flower,
+    ) -> DomainResult<Flower> {
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            INSERT INTO flowers (id, tenant_id, name, color, description, price, stock, sku,
+                                 attributes, metadata, unit, stems_per_unit, created_at,
+                                 updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+            RETURNING id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            "#,
+        )
+        .bind(flower.id().hyphenated())
+        .bind(flower.tenant_id().as_str())
+        .bind(flower.name())
+        .bind(flower.color().as_str())
+        .bind(flower.description())
+        .bind(flower.price())
+        .bind(flower.stock())
+        .bind(flower.sku())
+        .bind(Json(flower.attributes()))
+        .bind(Json(flower.metadata()))
+        .bind(flower.unit().as_str())
+        .bind(flower.unit().stems())
+        .bind(flower.created_at())
+        .bind(flower.updated_at())
+        .fetch_one(executor);
+        let row = self.db.timed("flowers.create", statement).await;
+
+        match row {
+            Ok(row) => row.try_into(),
+            Err(e) => Err(self.map_write_error(e, flower).await),
+        }
+    }
+
+    /// Update a flower through any executor, such as an open transaction
+    pub(crate) async fn update_in<'e>(
+        &self,
+        executor: impl SqliteExecutor<'e>,
+        flower: &Flower,
+    ) -> DomainResult<Flower> {
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            UPDATE flowers
+            SET name = ?2, color = ?3, description = ?4, price = ?5, stock = ?6, updated_at = ?7,
+                sku = ?9, attributes = ?10, metadata = ?11, unit = ?12, stems_per_unit = ?13
+            WHERE id = ?1 AND tenant_id = ?8
+            RETURNING id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            "#,
+        )
+        .bind(flower.id().hyphenated())
+        .bind(flower.name())
+        .bind(flower.color().as_str())
+        .bind(flower.description())
+        .bind(flower.price())
+        .bind(flower.stock())
+        .bind(flower.updated_at())
+        .bind(flower.tenant_id().as_str())
+        .bind(flower.sku())
+        .bind(Json(flower.attributes()))
+        .bind(Json(flower.metadata()))
+        .bind(flower.unit().as_str())
+        .bind(flower.unit().stems())
+        .fetch_one(executor);
+        let row = self.db.timed("flowers.update", statement).await;
+
+        match row {
+            Ok(row) => row.try_into(),
+            Err(e) => Err(self.map_write_error(e, flower).await),
+        }
+    }
+}
+
+#[async_trait]
+impl FlowerRepository for SqliteFlowerRepository {
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            SELECT id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            FROM flowers
+            WHERE tenant_id = ?1 AND id = ?2
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(id.hyphenated())
+        .fetch_optional(self.db.sqlite_pool());
+        let result = self.db.timed("flowers.find_by_id", statement).await?;
+
+        result.map(Flower::try_from).transpose()
+    }
+
+    async fn find_by_sku(&self, tenant: &TenantId, sku: &str) -> DomainResult<Option<Flower>> {
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            SELECT id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            FROM flowers
+            WHERE tenant_id = ?1 AND sku = ?2
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(sku)
+        .fetch_optional(self.db.sqlite_pool());
+        let result = self.db.timed("flowers.find_by_sku", statement).await?;
+
+        result.map(Flower::try_from).transpose()
+    }
+
+    async fn find_all(
+        &self,
+        tenant: &TenantId,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>> {
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            SELECT id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            FROM flowers
+            WHERE tenant_id = ?1
+            ORDER BY created_at DESC, id DESC
+            LIMIT ?2 OFFSET ?3
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(pagination.limit())
+        .bind(pagination.offset())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("flowers.find_all", statement).await?;
+
+        rows.into_iter().map(|row| row.try_into()).collect()
+    }
+
+    async fn count(&self, tenant: &TenantId) -> DomainResult<i64> {
+        let statement = sqlx::query_as("SELECT COUNT(*) FROM flowers WHERE tenant_id = ?1")
+            .bind(tenant.as_str())
+            .fetch_one(self.db.sqlite_pool());
+        let result: (i64,) = self.db.timed("flowers.count", statement).await?;
+
+        Ok(result.0)
+    }
+
+    async fn search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<Flower>> {
+        let search_pattern = query.map(|q| format!("%{}%", q.to_lowercase()));
+        let color_pattern = color.map(|c| c.as_str());
+
+        // A flower matches when none of the wanted attributes and metadata
+        // entries differs; metadata keys have dots, so their paths are quoted
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            SELECT id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            FROM flowers
+            WHERE tenant_id = ?1
+              AND (?2 IS NULL OR LOWER(name) LIKE ?2)
+              AND (?3 IS NULL OR color = ?3)
+              AND (?4 IS NULL OR NOT EXISTS (
+                  SELECT 1 FROM json_each(?4) AS wanted
+                  WHERE json_extract(attributes, '$.' || wanted.key) IS NOT wanted.value
+              ))
+              AND (?5 IS NULL OR NOT EXISTS (
+                  SELECT 1 FROM json_each(?5) AS wanted
+                  WHERE json_extract(metadata, '$."' || wanted.key || '"') IS NOT wanted.value
+              ))
+            ORDER BY created_at DESC, id DESC
+            LIMIT ?6 OFFSET ?7
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(&search_pattern)
+        .bind(color_pattern)
+        .bind(attributes.map(Json))
+        .bind(metadata.map(Json))
+        .bind(pagination.limit())
+        .bind(pagination.offset())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("flowers.search", statement).await?;
+
+        rows.into_iter().map(|row| row.try_into()).collect()
+    }
+
+    async fn count_search(
+        &self,
+        tenant: &TenantId,
+        query: Option<&str>,
+        color: Option<FlowerColor>,
+        attributes: Option<&FlowerAttributes>,
+        metadata: Option<&FlowerMetadata>,
+    ) -> DomainResult<i64> {
+        let search_pattern = query.map(|q| format!("%{}%", q.to_lowercase()));
+        let color_pattern = color.map(|c| c.as_str());
+
+        let statement = sqlx::query_as(
+            r#"
+            SELECT COUNT(*)
+            FROM flowers
+            WHERE tenant_id = ?1
+              AND (?2 IS NULL OR LOWER(name) LIKE ?2)
+              AND (?3 IS NULL OR color = ?3)
+              AND (?4 IS NULL OR NOT EXISTS (
+                  SELECT 1 FROM json_each(?4) AS wanted
+                  WHERE json_extract(attributes, '$.' || wanted.key) IS NOT wanted.value
+              ))
+              AND (?5 IS NULL OR NOT EXISTS (
+                  SELECT 1 FROM json_each(?5) AS wanted
+                  WHERE json_extract(metadata, '$."' || wanted.key || '"') IS NOT wanted.value
+              ))
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(&search_pattern)
+        .bind(color_pattern)
+        .bind(attributes.map(Json))
+        .bind(metadata.map(Json))
+        .fetch_one(self.db.sqlite_pool());
+        let result: (i64,) = self.db.timed("flowers.count_search", statement).await?;
+
+        Ok(result.0)
+    }
+
+    async fn facets(&self, tenant: &TenantId) -> DomainResult<FlowerFacets> {
+        let statement =
+            sqlx::query_scalar("SELECT DISTINCT color FROM flowers WHERE tenant_id = ?1")
+                .bind(tenant.as_str())
+                .fetch_all(self.db.sqlite_pool());
+        let colors: Vec<String> = self.db.timed("flowers.facets.colors", statement).await?;
+
+        let statement =
+            sqlx::query_as("SELECT MIN(price), MAX(price) FROM flowers WHERE tenant_id = ?1")
+                .bind(tenant.as_str())
+                .fetch_one(self.db.sqlite_pool());
+        let (min_price, max_price): (Option<f64>, Option<f64>) =
+            self.db.timed("flowers.facets.prices", statement).await?;
+
+        Ok(FlowerFacets {
+            colors: colors
+                .iter()
+                .map(|color| color.parse())
+                .collect::<Result<_, _>>()?,
+            min_price,
+            max_price,
+        })
+    }
+
+    async fn create(&self, flower: &Flower) -> DomainResult<Flower> {
+        self.create_in(self.db.sqlite_pool(), flower).await
+    }
+
+    async fn update(&self, flower: &Flower) -> DomainResult<Flower> {
+        self.update_in(self.db.sqlite_pool(), flower).await
+    }
+
+    async fn find_low_stock(&self, threshold: i32) -> DomainResult<Vec<Flower>> {
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            SELECT id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            FROM flowers
+            WHERE stock <= ?1
+            ORDER BY tenant_id, stock, name
+            "#,
+        )
+        .bind(threshold)
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("flowers.find_low_stock", statement).await?;
+
+        rows.into_iter().map(|row| row.try_into()).collect()
+    }
+
+    async fn find_created_since(
+        &self,
+        tenant: &TenantId,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<Flower>> {
+        let statement = sqlx::query_as::<_, FlowerRow>(
+            r#"
+            SELECT id, tenant_id, name, color, description, price, stock, sku, attributes,
+                   metadata, unit, stems_per_unit, created_at, updated_at
+            FROM flowers
+            WHERE tenant_id = ?1 AND created_at > ?2
+            ORDER BY created_at, id
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(since)
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self
+            .db
+            .timed("flowers.find_created_since", statement)
+            .await?;
+
+        rows.into_iter().map(|row| row.try_into()).collect()
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<()> {
+        let statement = sqlx::query("DELETE FROM flowers WHERE tenant_id = ?1 AND id = ?2")
+            .bind(tenant.as_str())
+            .bind(id.hyphenated())
+            .execute(self.db.sqlite_pool());
+        self.db.timed("flowers.delete", statement).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::flower::Fragrance;
+    use crate::test_support::FlowerBuilder;
+
+    #[tokio::test]
+    async fn searches_by_attributes_and_metadata() {
+        let path = std::env::temp_dir().join(format!("flowers-{}.db", Uuid::new_v4()));
+        let db = DatabasePool::new(&format!("sqlite://{}", path.display()), Default::default())
+            .await
+            .unwrap();
+        db.run_migrations().await.unwrap();
+        let repository = SqliteFlowerRepository::new(db);
+
+        let lily = FlowerBuilder::new()
+            .with_attributes(FlowerAttributes {
+                fragrance: Some(Fragrance::High),
+                stem_length_cm: Some(70),
+                ..Default::default()
+            })
+            .with_metadata("erp.item_id", "ITM-1")
+            .persisted(&repository)
+            .await;
+        let tulip = FlowerBuilder::new()
+            .with_metadata("erp.item_id", "ITM-2")
+            .persisted(&repository)
+            .await;
+        let tenant = tulip.tenant_id();
+        assert_eq!(lily.attributes().stem_length_cm, Some(70));
+
+        let fragrant = FlowerAttributes {
+            fragrance: Some(Fragrance::High),
+            ..Default::default()
+        };
+        let found = repository
+            .search(
+                tenant,
+                None,
+                None,
+                Some(&fragrant),
+                None,
+                &Pagination::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            found.iter().map(Entity::id).collect::<Vec<_>>(),
+            [lily.id()]
+        );
+
+        let long = FlowerAttributes {
+            stem_length_cm: Some(70),
+            ..fragrant.clone()
+        };
+        let short = FlowerAttributes {
+            stem_length_cm: Some(40),
+            ..fragrant
+        };
+        let count = |filter| repository.count_search(tenant, None, None, Some(filter), None);
+        assert_eq!(count(&long).await.unwrap(), 1);
+        assert_eq!(count(&short).await.unwrap(), 0);
+
+        let erp_item = FlowerMetadata::parse_entry("erp.item_id:ITM-2").unwrap();
+        let found = repository
+            .search(
+                tenant,
+                None,
+                None,
+                None,
+                Some(&erp_item),
+                &Pagination::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            found.iter().map(Entity::id).collect::<Vec<_>>(),
+            [tulip.id()]
+        );
+        let _ = std::fs::remove_file(path);
+    }
+}
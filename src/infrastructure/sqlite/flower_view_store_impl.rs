@@ -0,0 +1,164 @@
+//! SQLite implementation of FlowerViewStore
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::{FlowerViewStore, RecentView, ViewCount};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// SQLite implementation of FlowerViewStore
+pub struct SqliteFlowerViewStore {
+    db: DatabasePool,
+}
+
+impl SqliteFlowerViewStore {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl FlowerViewStore for SqliteFlowerViewStore {
+    async fn add(&self, counts: &[ViewCount]) -> DomainResult<()> {
+        let mut tx = self.db.sqlite_pool().begin().await?;
+        for count in counts {
+            let statement = sqlx::query(
+                r#"
+                INSERT INTO flower_views (tenant_id, flower_id, day, views)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT (tenant_id, flower_id, day)
+                DO UPDATE SET views = views + excluded.views
+                "#,
+            )
+            .bind(count.tenant.as_str())
+            .bind(count.flower_id.hyphenated())
+            .bind(count.day)
+            .bind(count.views)
+            .execute(&mut *tx);
+            self.db.timed("flower_views.add", statement).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn most_viewed(
+        &self,
+        tenant: &TenantId,
+        since: NaiveDate,
+        limit: i64,
+    ) -> DomainResult<Vec<(Uuid, i64)>> {
+        let statement = sqlx::query_as::<_, (Hyphenated, i64)>(
+            r#"
+            SELECT v.flower_id, SUM(v.views) AS views
+            FROM flower_views v
+            JOIN flowers f ON f.id = v.flower_id AND f.tenant_id = v.tenant_id
+            WHERE v.tenant_id = ?1 AND v.day >= ?2
+            GROUP BY v.flower_id
+            ORDER BY views DESC, v.flower_id
+            LIMIT ?3
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(since)
+        .bind(limit)
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("flower_views.most_viewed", statement).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, views)| (id.into_uuid(), views))
+            .collect())
+    }
+
+    async fn add_recent(&self, views: &[RecentView], keep: i64) -> DomainResult<()> {
+        let mut tx = self.db.sqlite_pool().begin().await?;
+        for view in views {
+            // Another instance may have flushed a later view of the same flower
+            let statement = sqlx::query(
+                r#"
+                INSERT INTO recent_views (tenant_id, viewer, flower_id, viewed_at)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT (tenant_id, viewer, flower_id)
+                DO UPDATE SET viewed_at = MAX(viewed_at, excluded.viewed_at)
+                "#,
+            )
+            .bind(view.tenant.as_str())
+            .bind(&view.viewer)
+            .bind(view.flower_id.hyphenated())
+            .bind(view.viewed_at)
+            .execute(&mut *tx);
+            self.db.timed("recent_views.add", statement).await?;
+
+            let statement = sqlx::query(
+                r#"
+                DELETE FROM recent_views
+                WHERE tenant_id = ?1 AND viewer = ?2 AND flower_id NOT IN (
+                    SELECT flower_id FROM recent_views
+                    WHERE tenant_id = ?1 AND viewer = ?2
+                    ORDER BY viewed_at DESC, flower_id
+                    LIMIT ?3
+                )
+                "#,
+            )
+            .bind(view.tenant.as_str())
+            .bind(&view.viewer)
+            .bind(keep)
+            .execute(&mut *tx);
+            self.db.timed("recent_views.trim", statement).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn recently_viewed(
+        &self,
+        tenant: &TenantId,
+        viewer: &str,
+        limit: i64,
+    ) -> DomainResult<Vec<(Uuid, DateTime<Utc>)>> {
+        let statement = sqlx::query_as::<_, (Hyphenated, DateTime<Utc>)>(
+            r#"
+            SELECT r.flower_id, r.viewed_at
+            FROM recent_views r
+            JOIN flowers f ON f.id = r.flower_id AND f.tenant_id = r.tenant_id
+            WHERE r.tenant_id = ?1 AND r.viewer = ?2
+            ORDER BY r.viewed_at DESC, r.flower_id
+            LIMIT ?3
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(viewer)
+        .bind(limit)
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("recent_views.find", statement).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, viewed_at)| (id.into_uuid(), viewed_at))
+            .collect())
+    }
+
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64> {
+        let statement =
+            sqlx::query("DELETE FROM recent_views WHERE tenant_id = ?1 AND flower_id = ?2")
+                .bind(tenant.as_str())
+                .bind(flower_id.hyphenated())
+                .execute(self.db.sqlite_pool());
+        self.db.timed("recent_views.delete", statement).await?;
+
+        let statement =
+            sqlx::query("DELETE FROM flower_views WHERE tenant_id = ?1 AND flower_id = ?2")
+                .bind(tenant.as_str())
+                .bind(flower_id.hyphenated())
+                .execute(self.db.sqlite_pool());
+        let result = self.db.timed("flower_views.delete", statement).await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,38 @@
+//! SQLite Adapters
+//!
+//! Implementations of the persistence ports on an embedded SQLite file,
+//! selected with `DATABASE_URL=sqlite://<path>` when built with the `sqlite`
+//! feature. Meant for single-node deployments such as an offline kiosk: there
+//! is no cross-process locking, so scheduled jobs run on every instance.
+
+pub mod delivery_zone_repo_impl;
+pub mod feature_flag_repo_impl;
+pub mod flower_history_impl;
+pub mod flower_repo_impl;
+pub mod flower_view_store_impl;
+pub mod nonce_store_impl;
+pub mod order_repo_impl;
+pub mod pricing_rule_repo_impl;
+pub mod saved_search_repo_impl;
+pub mod stock_ledger_impl;
+pub mod store_repo_impl;
+pub mod task_queue_impl;
+pub mod unit_of_work_impl;
+pub mod usage_store_impl;
+pub mod webhook_endpoint_repo_impl;
+
+pub use delivery_zone_repo_impl::SqliteDeliveryZoneRepository;
+pub use feature_flag_repo_impl::SqliteFeatureFlagRepository;
+pub use flower_history_impl::SqliteFlowerHistory;
+pub use flower_repo_impl::SqliteFlowerRepository;
+pub use flower_view_store_impl::SqliteFlowerViewStore;
+pub use nonce_store_impl::SqliteNonceStore;
+pub use order_repo_impl::SqliteOrderRepository;
+pub use pricing_rule_repo_impl::SqlitePricingRuleRepository;
+pub use saved_search_repo_impl::SqliteSavedSearchRepository;
+pub use stock_ledger_impl::SqliteStockLedger;
+pub use store_repo_impl::SqliteStoreRepository;
+pub use task_queue_impl::SqliteTaskQueue;
+pub use unit_of_work_impl::SqliteUnitOfWork;
+pub use usage_store_impl::SqliteUsageStore;
+pub use webhook_endpoint_repo_impl::SqliteWebhookEndpointRepository;
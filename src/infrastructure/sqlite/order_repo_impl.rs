@@ -0,0 +1,369 @@
+//! SQLite implementation of OrderRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::types::Json;
+use sqlx::{FromRow, SqliteConnection, SqliteExecutor};
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::OrderRepository;
+use crate::domain::delivery::ShippingRate;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::order::{Order, OrderError, OrderEvent, OrderLine, OrderRefund, OrderStatus};
+use crate::domain::shared::{Entity, TenantId};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for Order
+#[derive(Debug, FromRow)]
+struct OrderRow {
+    id: Hyphenated,
+    tenant_id: String,
+    lines: Json<Value>,
+    shipping_carrier: Option<String>,
+    shipping_service: Option<String>,
+    shipping_fee: Option<f64>,
+    shipping_estimated_days: Option<i64>,
+    status: String,
+    placed_at: DateTime<Utc>,
+    paid_at: Option<DateTime<Utc>>,
+    packed_at: Option<DateTime<Utc>>,
+    shipped_at: Option<DateTime<Utc>>,
+    delivered_at: Option<DateTime<Utc>>,
+    cancelled_at: Option<DateTime<Utc>>,
+    refund_amount: Option<f64>,
+    restocking_fee: Option<f64>,
+    refund_reference: Option<String>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<OrderRow> for Order {
+    type Error = AppError;
+
+    fn try_from(row: OrderRow) -> Result<Self, Self::Error> {
+        let id = row.id.into_uuid();
+        let lines: Vec<OrderLine> = serde_json::from_value(row.lines.0)
+            .map_err(|e| AppError::internal(format!("Invalid lines of order {}: {}", id, e)))?;
+        let shipping = match (row.shipping_carrier, row.shipping_service, row.shipping_fee) {
+            (Some(carrier), Some(service), Some(fee)) => Some(ShippingRate::new(
+                carrier,
+                service,
+                fee,
+                row.shipping_estimated_days
+                    .and_then(|days| u32::try_from(days).ok()),
+            )?),
+            _ => None,
+        };
+        let refund = match (row.refund_amount, row.refund_reference) {
+            (Some(amount), Some(reference)) => Some(OrderRefund::new(
+                amount,
+                row.restocking_fee.unwrap_or(0.0),
+                reference,
+            )),
+            _ => None,
+        };
+
+        Ok(Order::from_persistence(
+            id,
+            TenantId::new(row.tenant_id)?,
+            lines,
+            shipping,
+            row.status.parse()?,
+            row.placed_at,
+            row.paid_at,
+            row.packed_at,
+            row.shipped_at,
+            row.delivered_at,
+            row.cancelled_at,
+            refund,
+            row.updated_at,
+        ))
+    }
+}
+
+/// Database row representation for OrderEvent
+#[derive(Debug, FromRow)]
+struct OrderEventRow {
+    id: Hyphenated,
+    order_id: Hyphenated,
+    tenant_id: String,
+    from_status: Option<String>,
+    to_status: String,
+    actor: String,
+    occurred_at: DateTime<Utc>,
+    refunded: Option<f64>,
+}
+
+impl TryFrom<OrderEventRow> for OrderEvent {
+    type Error = AppError;
+
+    fn try_from(row: OrderEventRow) -> Result<Self, Self::Error> {
+        Ok(OrderEvent::from_persistence(
+            row.id.into_uuid(),
+            row.order_id.into_uuid(),
+            TenantId::new(row.tenant_id)?,
+            row.from_status
+                .map(|status| status.parse::<OrderStatus>())
+                .transpose()?,
+            row.to_status.parse()?,
+            row.actor,
+            row.occurred_at,
+            row.refunded,
+        ))
+    }
+}
+
+/// Lines as stored: a JSON array of `{flower_id, name, unit_price, quantity}`
+fn lines_json(order: &Order) -> DomainResult<Value> {
+    serde_json::to_value(order.lines())
+        .map_err(|e| AppError::internal(format!("Cannot encode order lines: {}", e)))
+}
+
+/// SQLite implementation of OrderRepository
+pub struct SqliteOrderRepository {
+    db: DatabasePool,
+}
+
+impl SqliteOrderRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+
+    /// Insert a new order through any executor, such as an open transaction
+    pub(crate) async fn insert_in<'e>(
+        &self,
+        executor: impl SqliteExecutor<'e>,
+        order: &Order,
+    ) -> DomainResult<()> {
+        let shipping = order.shipping();
+        let statement = sqlx::query(
+            r#"
+            INSERT INTO orders (id, tenant_id, lines, shipping_carrier, shipping_service, shipping_fee,
+                                shipping_estimated_days, status, placed_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            "#,
+        )
+        .bind(order.id().hyphenated())
+        .bind(order.tenant_id().as_str())
+        .bind(Json(lines_json(order)?))
+        .bind(shipping.map(ShippingRate::carrier))
+        .bind(shipping.map(ShippingRate::service))
+        .bind(shipping.map(ShippingRate::fee))
+        .bind(shipping.and_then(ShippingRate::estimated_days).map(i64::from))
+        .bind(order.status().as_str())
+        .bind(order.placed_at())
+        .bind(order.updated_at())
+        .execute(executor);
+        self.db.timed("orders.insert", statement).await?;
+
+        Ok(())
+    }
+
+    /// Load an order inside a transaction; SQLite transactions started with
+    /// `BEGIN IMMEDIATE` already hold the database write lock
+    pub(crate) async fn lock_in<'e>(
+        &self,
+        executor: impl SqliteExecutor<'e>,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<Option<Order>> {
+        let statement = sqlx::query_as::<_, OrderRow>(
+            r#"
+            SELECT id, tenant_id, lines, shipping_carrier, shipping_service, shipping_fee,
+                   shipping_estimated_days, status, placed_at, paid_at, packed_at, shipped_at,
+                   delivered_at, cancelled_at, refund_amount, restocking_fee, refund_reference,
+                   updated_at
+            FROM orders
+            WHERE tenant_id = ?1 AND id = ?2
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(id.hyphenated())
+        .fetch_optional(executor);
+        let result = self.db.timed("orders.lock", statement).await?;
+
+        result.map(Order::try_from).transpose()
+    }
+
+    /// Save the status of an order, when it was reached and any refund
+    pub(crate) async fn update_in<'e>(
+        &self,
+        executor: impl SqliteExecutor<'e>,
+        order: &Order,
+    ) -> DomainResult<()> {
+        let statement = sqlx::query(
+            r#"
+            UPDATE orders
+            SET status = ?3, paid_at = ?4, packed_at = ?5, shipped_at = ?6, delivered_at = ?7,
+                cancelled_at = ?8, refund_amount = ?9, restocking_fee = ?10, refund_reference = ?11,
+                updated_at = ?12
+            WHERE id = ?1 AND tenant_id = ?2
+            "#,
+        )
+        .bind(order.id().hyphenated())
+        .bind(order.tenant_id().as_str())
+        .bind(order.status().as_str())
+        .bind(order.paid_at())
+        .bind(order.packed_at())
+        .bind(order.shipped_at())
+        .bind(order.delivered_at())
+        .bind(order.cancelled_at())
+        .bind(order.refund().map(OrderRefund::amount))
+        .bind(order.refund().map(OrderRefund::restocking_fee))
+        .bind(order.refund().map(OrderRefund::reference))
+        .bind(order.updated_at())
+        .execute(executor);
+        let result = self.db.timed("orders.update", statement).await?;
+
+        if result.rows_affected() == 0 {
+            return Err(OrderError::not_found(order.id()));
+        }
+        Ok(())
+    }
+
+    /// Append events on a connection, such as an open transaction
+    pub(crate) async fn record_events_in(
+        &self,
+        connection: &mut SqliteConnection,
+        events: &[OrderEvent],
+    ) -> DomainResult<()> {
+        for event in events {
+            let statement = sqlx::query(
+                r#"
+                INSERT INTO order_events (id, order_id, tenant_id, from_status, to_status, actor, occurred_at, refunded)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                "#,
+            )
+            .bind(event.id().hyphenated())
+            .bind(event.order_id().hyphenated())
+            .bind(event.tenant_id().as_str())
+            .bind(event.from().map(OrderStatus::as_str))
+            .bind(event.to().as_str())
+            .bind(event.actor())
+            .bind(event.occurred_at())
+            .bind(event.refunded())
+            .execute(&mut *connection);
+            self.db.timed("order_events.record", statement).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrderRepository for SqliteOrderRepository {
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Order>> {
+        let statement = sqlx::query_as::<_, OrderRow>(
+            r#"
+            SELECT id, tenant_id, lines, shipping_carrier, shipping_service, shipping_fee,
+                   shipping_estimated_days, status, placed_at, paid_at, packed_at, shipped_at,
+                   delivered_at, cancelled_at, refund_amount, restocking_fee, refund_reference,
+                   updated_at
+            FROM orders
+            WHERE tenant_id = ?1 AND id = ?2
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(id.hyphenated())
+        .fetch_optional(self.db.sqlite_pool());
+        let result = self.db.timed("orders.find_by_id", statement).await?;
+
+        result.map(Order::try_from).transpose()
+    }
+
+    async fn events(&self, tenant: &TenantId, order_id: Uuid) -> DomainResult<Vec<OrderEvent>> {
+        let statement = sqlx::query_as::<_, OrderEventRow>(
+            r#"
+            SELECT id, order_id, tenant_id, from_status, to_status, actor, occurred_at, refunded
+            FROM order_events
+            WHERE tenant_id = ?1 AND order_id = ?2
+            ORDER BY occurred_at, id
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(order_id.hyphenated())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("order_events.find", statement).await?;
+
+        rows.into_iter().map(OrderEvent::try_from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::UnitOfWork;
+    use crate::infrastructure::sqlite::SqliteUnitOfWork;
+    use crate::test_support::FlowerBuilder;
+
+    #[tokio::test]
+    async fn orders_round_trip_with_their_events() {
+        let path = std::env::temp_dir().join(format!("orders-{}.db", Uuid::new_v4()));
+        let db = DatabasePool::new(&format!("sqlite://{}", path.display()), Default::default())
+            .await
+            .unwrap();
+        db.run_migrations().await.unwrap();
+        let orders = SqliteOrderRepository::new(db.clone());
+        let unit_of_work = SqliteUnitOfWork::new(db);
+
+        let rose = FlowerBuilder::new().build();
+        let line = OrderLine::new(&rose, rose.price(), 2).unwrap();
+        let shipping = ShippingRate::new("jne", "REG", 18_000.0, Some(2)).unwrap();
+        let mut order = Order::place(
+            rose.tenant_id().clone(),
+            vec![line],
+            Some(shipping),
+            "admin",
+        )
+        .unwrap();
+        let mut tx = unit_of_work.begin().await.unwrap();
+        tx.insert_order(&order).await.unwrap();
+        tx.record_order_events(&order.take_events()).await.unwrap();
+        tx.commit().await.unwrap();
+
+        let mut tx = unit_of_work.begin().await.unwrap();
+        let mut locked = tx
+            .lock_order(rose.tenant_id(), order.id())
+            .await
+            .unwrap()
+            .unwrap();
+        locked.pay("admin").unwrap();
+        locked.cancel("admin").unwrap();
+        locked.refunded(OrderRefund::new(16_000.0, 2_000.0, "rf-1"), "admin");
+        tx.update_order(&locked).await.unwrap();
+        tx.record_order_events(&locked.take_events()).await.unwrap();
+        tx.commit().await.unwrap();
+
+        let found = orders
+            .find_by_id(rose.tenant_id(), order.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.status(), OrderStatus::Cancelled);
+        assert_eq!(found.lines(), order.lines());
+        assert_eq!(found.shipping(), order.shipping());
+        assert!(found.paid_at().is_some() && found.packed_at().is_none());
+        assert_eq!(found.refund(), locked.refund());
+
+        let events = orders.events(rose.tenant_id(), order.id()).await.unwrap();
+        let steps: Vec<_> = events
+            .iter()
+            .map(|event| (event.from(), event.to(), event.refunded()))
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                (None, OrderStatus::Pending, None),
+                (Some(OrderStatus::Pending), OrderStatus::Paid, None),
+                (Some(OrderStatus::Paid), OrderStatus::Cancelled, None),
+                (
+                    Some(OrderStatus::Cancelled),
+                    OrderStatus::Cancelled,
+                    Some(16_000.0)
+                )
+            ]
+        );
+        let _ = std::fs::remove_file(path);
+    }
+}
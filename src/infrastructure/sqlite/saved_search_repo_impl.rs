@@ -0,0 +1,160 @@
+//! SQLite implementation of SavedSearchRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::SavedSearchRepository;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::flower::Price;
+use crate::domain::saved_search::{SavedSearch, SearchCriteria};
+use crate::domain::shared::TenantId;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for SavedSearch
+#[derive(Debug, FromRow)]
+struct SavedSearchRow {
+    id: Hyphenated,
+    tenant_id: String,
+    owner: String,
+    name: String,
+    query: Option<String>,
+    color: Option<String>,
+    min_price: Option<f64>,
+    max_price: Option<f64>,
+    alert_email: Option<String>,
+    checked_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<SavedSearchRow> for SavedSearch {
+    type Error = AppError;
+
+    fn try_from(row: SavedSearchRow) -> Result<Self, Self::Error> {
+        let criteria = SearchCriteria {
+            query: row.query,
+            color: row.color.map(|color| color.parse()).transpose()?,
+            min_price: row.min_price.map(Price::new).transpose()?,
+            max_price: row.max_price.map(Price::new).transpose()?,
+        };
+        Ok(SavedSearch::from_persistence(
+            row.id.into_uuid(),
+            TenantId::new(row.tenant_id)?,
+            row.owner,
+            row.name,
+            criteria,
+            row.alert_email,
+            row.checked_at,
+            row.created_at,
+        ))
+    }
+}
+
+/// SQLite implementation of SavedSearchRepository
+pub struct SqliteSavedSearchRepository {
+    db: DatabasePool,
+}
+
+impl SqliteSavedSearchRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl SavedSearchRepository for SqliteSavedSearchRepository {
+    async fn create(&self, search: &SavedSearch) -> DomainResult<SavedSearch> {
+        let criteria = search.criteria();
+        let statement = sqlx::query_as::<_, SavedSearchRow>(
+            r#"
+            INSERT INTO saved_searches (id, tenant_id, owner, name, query, color, min_price, max_price, alert_email, checked_at, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            RETURNING id, tenant_id, owner, name, query, color, min_price, max_price, alert_email, checked_at, created_at
+            "#,
+        )
+        .bind(search.id().hyphenated())
+        .bind(search.tenant_id().as_str())
+        .bind(search.owner())
+        .bind(search.name())
+        .bind(criteria.query.as_deref())
+        .bind(criteria.color.map(|color| color.as_str()))
+        .bind(criteria.min_price.map(|price| price.value()))
+        .bind(criteria.max_price.map(|price| price.value()))
+        .bind(search.alert_email())
+        .bind(search.checked_at())
+        .bind(search.created_at())
+        .fetch_one(self.db.sqlite_pool());
+        let row = self.db.timed("saved_searches.create", statement).await?;
+
+        row.try_into()
+    }
+
+    async fn find_by_owner(
+        &self,
+        tenant: &TenantId,
+        owner: &str,
+    ) -> DomainResult<Vec<SavedSearch>> {
+        let statement = sqlx::query_as::<_, SavedSearchRow>(
+            r#"
+            SELECT id, tenant_id, owner, name, query, color, min_price, max_price, alert_email, checked_at, created_at
+            FROM saved_searches
+            WHERE tenant_id = ?1 AND owner = ?2
+            ORDER BY created_at, id
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(owner)
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self
+            .db
+            .timed("saved_searches.find_by_owner", statement)
+            .await?;
+
+        rows.into_iter().map(SavedSearch::try_from).collect()
+    }
+
+    async fn find_alerting(&self) -> DomainResult<Vec<SavedSearch>> {
+        let statement = sqlx::query_as::<_, SavedSearchRow>(
+            r#"
+            SELECT id, tenant_id, owner, name, query, color, min_price, max_price, alert_email, checked_at, created_at
+            FROM saved_searches
+            WHERE alert_email IS NOT NULL
+            ORDER BY tenant_id, created_at, id
+            "#,
+        )
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self
+            .db
+            .timed("saved_searches.find_alerting", statement)
+            .await?;
+
+        rows.into_iter().map(SavedSearch::try_from).collect()
+    }
+
+    async fn mark_checked(&self, id: Uuid, at: DateTime<Utc>) -> DomainResult<()> {
+        let statement = sqlx::query("UPDATE saved_searches SET checked_at = ?2 WHERE id = ?1")
+            .bind(id.hyphenated())
+            .bind(at)
+            .execute(self.db.sqlite_pool());
+        self.db
+            .timed("saved_searches.mark_checked", statement)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, tenant: &TenantId, owner: &str, id: Uuid) -> DomainResult<bool> {
+        let statement = sqlx::query(
+            "DELETE FROM saved_searches WHERE tenant_id = ?1 AND owner = ?2 AND id = ?3",
+        )
+        .bind(tenant.as_str())
+        .bind(owner)
+        .bind(id.hyphenated())
+        .execute(self.db.sqlite_pool());
+        let result = self.db.timed("saved_searches.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
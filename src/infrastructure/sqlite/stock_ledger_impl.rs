@@ -0,0 +1,130 @@
+//! SQLite inventory ledger
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqliteExecutor};
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::{LedgerQuery, StockLedger};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::inventory::StockMovement;
+use crate::domain::shared::{Pagination, TenantId};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for StockMovement
+#[derive(Debug, FromRow)]
+struct StockMovementRow {
+    id: Hyphenated,
+    tenant_id: String,
+    flower_id: Hyphenated,
+    delta: i32,
+    reason: String,
+    stock_after: i32,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<StockMovementRow> for StockMovement {
+    type Error = AppError;
+
+    fn try_from(row: StockMovementRow) -> Result<Self, Self::Error> {
+        Ok(StockMovement::from_persistence(
+            row.id.into_uuid(),
+            TenantId::new(row.tenant_id)?,
+            row.flower_id.into_uuid(),
+            row.delta,
+            row.reason,
+            row.stock_after,
+            row.created_at,
+        ))
+    }
+}
+
+/// Appends stock movements to the `stock_movements` table and reads them back
+pub struct SqliteStockLedger {
+    db: DatabasePool,
+}
+
+impl SqliteStockLedger {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+
+    /// Record a movement through any executor, such as an open transaction
+    pub(crate) async fn record_in<'e>(
+        &self,
+        executor: impl SqliteExecutor<'e>,
+        movement: &StockMovement,
+    ) -> DomainResult<()> {
+        let statement = sqlx::query(
+            r#"
+            INSERT INTO stock_movements (id, tenant_id, flower_id, delta, reason, stock_after, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )
+        .bind(movement.id().hyphenated())
+        .bind(movement.tenant_id().as_str())
+        .bind(movement.flower_id().hyphenated())
+        .bind(movement.delta())
+        .bind(movement.reason())
+        .bind(movement.stock_after())
+        .bind(movement.created_at())
+        .execute(executor);
+        self.db.timed("stock_movements.record", statement).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StockLedger for SqliteStockLedger {
+    async fn find(
+        &self,
+        query: &LedgerQuery,
+        pagination: &Pagination,
+    ) -> DomainResult<Vec<StockMovement>> {
+        let statement = sqlx::query_as::<_, StockMovementRow>(
+            r#"
+            SELECT id, tenant_id, flower_id, delta, reason, stock_after, created_at
+            FROM stock_movements
+            WHERE (?1 IS NULL OR tenant_id = ?1) AND (?2 IS NULL OR flower_id = ?2)
+            ORDER BY created_at DESC, id DESC
+            LIMIT ?3 OFFSET ?4
+            "#,
+        )
+        .bind(query.tenant.as_ref().map(TenantId::as_str))
+        .bind(query.flower_id.map(|id| id.hyphenated()))
+        .bind(pagination.limit())
+        .bind(pagination.offset())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("stock_movements.find", statement).await?;
+
+        rows.into_iter().map(StockMovement::try_from).collect()
+    }
+
+    async fn count(&self, query: &LedgerQuery) -> DomainResult<i64> {
+        let statement = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) FROM stock_movements
+            WHERE (?1 IS NULL OR tenant_id = ?1) AND (?2 IS NULL OR flower_id = ?2)
+            "#,
+        )
+        .bind(query.tenant.as_ref().map(TenantId::as_str))
+        .bind(query.flower_id.map(|id| id.hyphenated()))
+        .fetch_one(self.db.sqlite_pool());
+        let result: (i64,) = self.db.timed("stock_movements.count", statement).await?;
+
+        Ok(result.0)
+    }
+
+    async fn delete_flower(&self, tenant: &TenantId, flower_id: Uuid) -> DomainResult<u64> {
+        let statement =
+            sqlx::query("DELETE FROM stock_movements WHERE tenant_id = ?1 AND flower_id = ?2")
+                .bind(tenant.as_str())
+                .bind(flower_id.hyphenated())
+                .execute(self.db.sqlite_pool());
+        let result = self.db.timed("stock_movements.delete", statement).await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,188 @@
+//! SQLite implementation of StoreRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::StoreRepository;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::TenantId;
+use crate::domain::store::{GeoPoint, Store};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for Store
+#[derive(Debug, FromRow)]
+struct StoreRow {
+    id: Hyphenated,
+    tenant_id: String,
+    name: String,
+    address: String,
+    latitude: f64,
+    longitude: f64,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<StoreRow> for Store {
+    type Error = AppError;
+
+    fn try_from(row: StoreRow) -> Result<Self, Self::Error> {
+        Ok(Store::from_persistence(
+            row.id.into_uuid(),
+            TenantId::new(row.tenant_id)?,
+            row.name,
+            row.address,
+            GeoPoint::new(row.latitude, row.longitude)?,
+            row.created_at,
+        ))
+    }
+}
+
+/// Store row with the quantity it holds of one flower
+#[derive(Debug, FromRow)]
+struct StockRow {
+    #[sqlx(flatten)]
+    store: StoreRow,
+    quantity: i32,
+}
+
+/// SQLite implementation of StoreRepository
+pub struct SqliteStoreRepository {
+    db: DatabasePool,
+}
+
+impl SqliteStoreRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl StoreRepository for SqliteStoreRepository {
+    async fn create(&self, store: &Store) -> DomainResult<Store> {
+        let statement = sqlx::query_as::<_, StoreRow>(
+            r#"
+            INSERT INTO stores (id, tenant_id, name, address, latitude, longitude, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            RETURNING id, tenant_id, name, address, latitude, longitude, created_at
+            "#,
+        )
+        .bind(store.id().hyphenated())
+        .bind(store.tenant_id().as_str())
+        .bind(store.name())
+        .bind(store.address())
+        .bind(store.location().latitude())
+        .bind(store.location().longitude())
+        .bind(store.created_at())
+        .fetch_one(self.db.sqlite_pool());
+        let row = self.db.timed("stores.create", statement).await?;
+
+        row.try_into()
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<Store>> {
+        let statement = sqlx::query_as::<_, StoreRow>(
+            r#"
+            SELECT id, tenant_id, name, address, latitude, longitude, created_at
+            FROM stores
+            WHERE tenant_id = ?1
+            ORDER BY name, id
+            "#,
+        )
+        .bind(tenant.as_str())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("stores.find_all", statement).await?;
+
+        rows.into_iter().map(Store::try_from).collect()
+    }
+
+    async fn find_by_id(&self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Store>> {
+        let statement = sqlx::query_as::<_, StoreRow>(
+            r#"
+            SELECT id, tenant_id, name, address, latitude, longitude, created_at
+            FROM stores
+            WHERE tenant_id = ?1 AND id = ?2
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(id.hyphenated())
+        .fetch_optional(self.db.sqlite_pool());
+        let row = self.db.timed("stores.find_by_id", statement).await?;
+
+        row.map(Store::try_from).transpose()
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let statement = sqlx::query("DELETE FROM stores WHERE tenant_id = ?1 AND id = ?2")
+            .bind(tenant.as_str())
+            .bind(id.hyphenated())
+            .execute(self.db.sqlite_pool());
+        let result = self.db.timed("stores.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn set_stock(
+        &self,
+        tenant: &TenantId,
+        store_id: Uuid,
+        flower_id: Uuid,
+        quantity: i32,
+    ) -> DomainResult<()> {
+        if quantity == 0 {
+            let statement = sqlx::query(
+                "DELETE FROM store_stock WHERE tenant_id = ?1 AND store_id = ?2 AND flower_id = ?3",
+            )
+            .bind(tenant.as_str())
+            .bind(store_id.hyphenated())
+            .bind(flower_id.hyphenated())
+            .execute(self.db.sqlite_pool());
+            self.db.timed("store_stock.delete", statement).await?;
+            return Ok(());
+        }
+
+        let statement = sqlx::query(
+            r#"
+            INSERT INTO store_stock (store_id, tenant_id, flower_id, quantity)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT (store_id, flower_id) DO UPDATE SET quantity = excluded.quantity
+            "#,
+        )
+        .bind(store_id.hyphenated())
+        .bind(tenant.as_str())
+        .bind(flower_id.hyphenated())
+        .bind(quantity)
+        .execute(self.db.sqlite_pool());
+        self.db.timed("store_stock.set", statement).await?;
+
+        Ok(())
+    }
+
+    async fn stock_of(
+        &self,
+        tenant: &TenantId,
+        flower_id: Uuid,
+    ) -> DomainResult<Vec<(Store, i32)>> {
+        let statement = sqlx::query_as::<_, StockRow>(
+            r#"
+            SELECT s.id, s.tenant_id, s.name, s.address, s.latitude, s.longitude, s.created_at, k.quantity
+            FROM store_stock k
+            JOIN stores s ON s.id = k.store_id
+            WHERE k.tenant_id = ?1 AND k.flower_id = ?2
+            ORDER BY s.name, s.id
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(flower_id.hyphenated())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self
+            .db
+            .timed("store_stock.find_by_flower", statement)
+            .await?;
+
+        rows.into_iter()
+            .map(|row| Ok((row.store.try_into()?, row.quantity)))
+            .collect()
+    }
+}
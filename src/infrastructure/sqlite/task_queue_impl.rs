@@ -0,0 +1,278 @@
+//! SQLite implementation of TaskQueue
+//!
+//! SQLite serializes writers, so claiming the oldest due task with a single
+//! `UPDATE ... RETURNING` is enough to never hand it out twice. Leases work
+//! as in the PostgreSQL queue: an expired lease makes the task due again.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::types::Json;
+use sqlx::{FromRow, SqliteExecutor};
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::TaskQueue;
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::Pagination;
+use crate::domain::task::{FailedTask, Task};
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for Task
+#[derive(Debug, FromRow)]
+struct TaskRow {
+    id: Hyphenated,
+    kind: String,
+    payload: Json<Value>,
+    attempts: i32,
+    max_attempts: i32,
+    run_at: DateTime<Utc>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<TaskRow> for Task {
+    fn from(row: TaskRow) -> Self {
+        Task::from_persistence(
+            row.id.into_uuid(),
+            row.kind,
+            row.payload.0,
+            row.attempts,
+            row.max_attempts,
+            row.run_at,
+            row.last_error,
+            row.created_at,
+        )
+    }
+}
+
+/// Database row representation for FailedTask
+#[derive(Debug, FromRow)]
+struct FailedTaskRow {
+    id: Hyphenated,
+    kind: String,
+    payload: Json<Value>,
+    attempts: i32,
+    last_error: String,
+    created_at: DateTime<Utc>,
+    failed_at: DateTime<Utc>,
+}
+
+impl From<FailedTaskRow> for FailedTask {
+    fn from(row: FailedTaskRow) -> Self {
+        FailedTask::from_persistence(
+            row.id.into_uuid(),
+            row.kind,
+            row.payload.0,
+            row.attempts,
+            row.last_error,
+            row.created_at,
+            row.failed_at,
+        )
+    }
+}
+
+/// SQLite implementation of TaskQueue
+pub struct SqliteTaskQueue {
+    db: DatabasePool,
+}
+
+impl SqliteTaskQueue {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+
+    /// Enqueue a task through any executor, such as an open transaction
+    pub(crate) async fn enqueue_in<'e>(
+        &self,
+        executor: impl SqliteExecutor<'e>,
+        task: &Task,
+    ) -> DomainResult<()> {
+        let statement = sqlx::query(
+            r#"
+            INSERT INTO tasks (id, kind, payload, attempts, max_attempts, run_at, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )
+        .bind(task.id().hyphenated())
+        .bind(task.kind())
+        .bind(Json(task.payload()))
+        .bind(task.attempts())
+        .bind(task.max_attempts())
+        .bind(task.run_at())
+        .bind(task.created_at())
+        .execute(executor);
+        self.db.timed("tasks.enqueue", statement).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TaskQueue for SqliteTaskQueue {
+    async fn enqueue(&self, task: &Task) -> DomainResult<()> {
+        self.enqueue_in(self.db.sqlite_pool(), task).await
+    }
+
+    async fn claim(&self, lease: Duration) -> DomainResult<Option<Task>> {
+        let now = Utc::now();
+        let lease = chrono::Duration::from_std(lease)
+            .map_err(|e| AppError::internal(format!("Invalid task lease: {}", e)))?;
+
+        let statement = sqlx::query_as::<_, TaskRow>(
+            r#"
+            UPDATE tasks
+            SET attempts = attempts + 1,
+                locked_until = ?2
+            WHERE id = (
+                SELECT id FROM tasks
+                WHERE run_at <= ?1 AND (locked_until IS NULL OR locked_until < ?1)
+                ORDER BY run_at
+                LIMIT 1
+            )
+            RETURNING id, kind, payload, attempts, max_attempts, run_at, last_error, created_at
+            "#,
+        )
+        .bind(now)
+        .bind(now + lease)
+        .fetch_optional(self.db.sqlite_pool());
+        let row = self.db.timed("tasks.claim", statement).await?;
+
+        Ok(row.map(Task::from))
+    }
+
+    async fn complete(&self, id: Uuid) -> DomainResult<()> {
+        let statement = sqlx::query("DELETE FROM tasks WHERE id = ?1")
+            .bind(id.hyphenated())
+            .execute(self.db.sqlite_pool());
+        self.db.timed("tasks.complete", statement).await?;
+
+        Ok(())
+    }
+
+    async fn retry(&self, id: Uuid, error: &str, run_at: DateTime<Utc>) -> DomainResult<()> {
+        let statement = sqlx::query(
+            "UPDATE tasks SET run_at = ?2, last_error = ?3, locked_until = NULL WHERE id = ?1",
+        )
+        .bind(id.hyphenated())
+        .bind(run_at)
+        .bind(error)
+        .execute(self.db.sqlite_pool());
+        self.db.timed("tasks.retry", statement).await?;
+
+        Ok(())
+    }
+
+    async fn dead_letter(&self, id: Uuid, error: &str) -> DomainResult<()> {
+        let mut tx = self.db.sqlite_pool().begin().await?;
+
+        let statement = sqlx::query(
+            r#"
+            INSERT INTO dead_letter_tasks (id, kind, payload, attempts, last_error, created_at, failed_at)
+            SELECT id, kind, payload, attempts, ?2, created_at, ?3 FROM tasks WHERE id = ?1
+            "#,
+        )
+        .bind(id.hyphenated())
+        .bind(error)
+        .bind(Utc::now())
+        .execute(&mut *tx);
+        self.db.timed("tasks.dead_letter", statement).await?;
+
+        sqlx::query("DELETE FROM tasks WHERE id = ?1")
+            .bind(id.hyphenated())
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_failed(&self, pagination: &Pagination) -> DomainResult<Vec<FailedTask>> {
+        let statement = sqlx::query_as::<_, FailedTaskRow>(
+            r#"
+            SELECT id, kind, payload, attempts, last_error, created_at, failed_at
+            FROM dead_letter_tasks
+            ORDER BY failed_at DESC
+            LIMIT ?1 OFFSET ?2
+            "#,
+        )
+        .bind(pagination.limit())
+        .bind(pagination.offset())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self
+            .db
+            .timed("dead_letter_tasks.find_all", statement)
+            .await?;
+
+        Ok(rows.into_iter().map(FailedTask::from).collect())
+    }
+
+    async fn count_failed(&self) -> DomainResult<i64> {
+        let statement = sqlx::query_as("SELECT COUNT(*) FROM dead_letter_tasks")
+            .fetch_one(self.db.sqlite_pool());
+        let result: (i64,) = self.db.timed("dead_letter_tasks.count", statement).await?;
+
+        Ok(result.0)
+    }
+
+    async fn purge_failed(&self, failed_before: DateTime<Utc>, archive: bool) -> DomainResult<u64> {
+        let mut tx = self.db.sqlite_pool().begin().await?;
+
+        if archive {
+            let statement = sqlx::query(
+                r#"
+                INSERT OR IGNORE INTO dead_letter_tasks_archive
+                    (id, kind, payload, attempts, last_error, created_at, failed_at, archived_at)
+                SELECT id, kind, payload, attempts, last_error, created_at, failed_at, ?2
+                FROM dead_letter_tasks WHERE failed_at < ?1
+                "#,
+            )
+            .bind(failed_before)
+            .bind(Utc::now())
+            .execute(&mut *tx);
+            self.db
+                .timed("dead_letter_tasks.archive", statement)
+                .await?;
+        }
+
+        let statement = sqlx::query("DELETE FROM dead_letter_tasks WHERE failed_at < ?1")
+            .bind(failed_before)
+            .execute(&mut *tx);
+        let result = self.db.timed("dead_letter_tasks.purge", statement).await?;
+        tx.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn claims_each_task_once_and_dead_letters() {
+        let path = std::env::temp_dir().join(format!("tasks-{}.db", Uuid::new_v4()));
+        let db = DatabasePool::new(&format!("sqlite://{}", path.display()), Default::default())
+            .await
+            .unwrap();
+        db.run_migrations().await.unwrap();
+        let queue = SqliteTaskQueue::new(db);
+
+        let task = Task::new("email", json!({"to": "kiosk"}), Utc::now(), 1);
+        queue.enqueue(&task).await.unwrap();
+
+        let lease = Duration::from_secs(60);
+        let claimed = queue.claim(lease).await.unwrap().unwrap();
+        assert_eq!(claimed.id(), task.id());
+        assert_eq!(claimed.payload(), &json!({"to": "kiosk"}));
+        assert!(queue.claim(lease).await.unwrap().is_none());
+
+        queue.dead_letter(task.id(), "boom").await.unwrap();
+        assert_eq!(queue.count_failed().await.unwrap(), 1);
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -0,0 +1,117 @@
+//! SQLite implementation of UnitOfWork
+//!
+//! Transactions start with `BEGIN IMMEDIATE`, taking the database write lock
+//! up front: SQLite has no row locks, and a deferred transaction that reads
+//! before writing would fail with `SQLITE_BUSY` instead of waiting.
+
+use async_trait::async_trait;
+use sqlx::Sqlite;
+use uuid::Uuid;
+
+use crate::application::ports::{Transaction, UnitOfWork};
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{Flower, FlowerChange, FlowerColor};
+use crate::domain::inventory::StockMovement;
+use crate::domain::order::{Order, OrderEvent};
+use crate::domain::shared::TenantId;
+use crate::domain::task::Task;
+use crate::infrastructure::persistance::DatabasePool;
+use crate::infrastructure::sqlite::{
+    SqliteFlowerHistory, SqliteFlowerRepository, SqliteOrderRepository, SqliteStockLedger,
+    SqliteTaskQueue,
+};
+
+/// SQLite implementation of UnitOfWork
+pub struct SqliteUnitOfWork {
+    db: DatabasePool,
+}
+
+impl SqliteUnitOfWork {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for SqliteUnitOfWork {
+    async fn begin(&self) -> DomainResult<Box<dyn Transaction>> {
+        let tx = self.db.sqlite_pool().begin_with("BEGIN IMMEDIATE").await?;
+        Ok(Box::new(SqliteTransaction {
+            tx,
+            flowers: SqliteFlowerRepository::new(self.db.clone()),
+            ledger: SqliteStockLedger::new(self.db.clone()),
+            history: SqliteFlowerHistory::new(self.db.clone()),
+            tasks: SqliteTaskQueue::new(self.db.clone()),
+            orders: SqliteOrderRepository::new(self.db.clone()),
+        }))
+    }
+}
+
+/// An open SQLite transaction, rolled back when dropped uncommitted
+struct SqliteTransaction {
+    tx: sqlx::Transaction<'static, Sqlite>,
+    flowers: SqliteFlowerRepository,
+    ledger: SqliteStockLedger,
+    history: SqliteFlowerHistory,
+    tasks: SqliteTaskQueue,
+    orders: SqliteOrderRepository,
+}
+
+#[async_trait]
+impl Transaction for SqliteTransaction {
+    async fn lock_flower(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Flower>> {
+        self.flowers.lock_in(&mut *self.tx, tenant, id).await
+    }
+
+    async fn lock_flowers(
+        &mut self,
+        tenant: &TenantId,
+        color: Option<FlowerColor>,
+        ids: Option<&[Uuid]>,
+    ) -> DomainResult<Vec<Flower>> {
+        self.flowers
+            .lock_all_in(&mut *self.tx, tenant, color, ids)
+            .await
+    }
+
+    async fn insert_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        self.flowers.create_in(&mut *self.tx, flower).await
+    }
+
+    async fn update_flower(&mut self, flower: &Flower) -> DomainResult<Flower> {
+        self.flowers.update_in(&mut *self.tx, flower).await
+    }
+
+    async fn record_stock_movement(&mut self, movement: &StockMovement) -> DomainResult<()> {
+        self.ledger.record_in(&mut *self.tx, movement).await
+    }
+
+    async fn record_flower_changes(&mut self, changes: &[FlowerChange]) -> DomainResult<()> {
+        self.history.record_in(&mut self.tx, changes).await
+    }
+
+    async fn insert_order(&mut self, order: &Order) -> DomainResult<()> {
+        self.orders.insert_in(&mut *self.tx, order).await
+    }
+
+    async fn lock_order(&mut self, tenant: &TenantId, id: Uuid) -> DomainResult<Option<Order>> {
+        self.orders.lock_in(&mut *self.tx, tenant, id).await
+    }
+
+    async fn update_order(&mut self, order: &Order) -> DomainResult<()> {
+        self.orders.update_in(&mut *self.tx, order).await
+    }
+
+    async fn record_order_events(&mut self, events: &[OrderEvent]) -> DomainResult<()> {
+        self.orders.record_events_in(&mut self.tx, events).await
+    }
+
+    async fn enqueue_task(&mut self, task: &Task) -> DomainResult<()> {
+        self.tasks.enqueue_in(&mut *self.tx, task).await
+    }
+
+    async fn commit(self: Box<Self>) -> DomainResult<()> {
+        self.tx.commit().await?;
+        Ok(())
+    }
+}
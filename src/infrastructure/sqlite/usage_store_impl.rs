@@ -0,0 +1,180 @@
+//! SQLite implementation of UsageStore
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::application::ports::{ApiQuota, UsageRecord, UsageStore};
+use crate::domain::errors::DomainResult;
+use crate::domain::shared::TenantId;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Row of `api_usage`
+type UsageRow = (String, String, String, DateTime<Utc>, i64, i64, i64, i64);
+
+/// SQLite implementation of UsageStore
+pub struct SqliteUsageStore {
+    db: DatabasePool,
+}
+
+impl SqliteUsageStore {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl UsageStore for SqliteUsageStore {
+    async fn add(&self, records: &[UsageRecord]) -> DomainResult<()> {
+        let mut tx = self.db.sqlite_pool().begin().await?;
+        for record in records {
+            let statement = sqlx::query(
+                r#"
+                INSERT INTO api_usage
+                    (tenant_id, key_id, api_key, hour,
+                     requests, errors, request_bytes, response_bytes)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                ON CONFLICT (tenant_id, key_id, hour)
+                DO UPDATE SET
+                    requests = requests + excluded.requests,
+                    errors = errors + excluded.errors,
+                    request_bytes = request_bytes + excluded.request_bytes,
+                    response_bytes = response_bytes + excluded.response_bytes
+                "#,
+            )
+            .bind(record.tenant.as_str())
+            .bind(record.key_id.as_deref().unwrap_or_default())
+            .bind(record.api_key.as_deref().unwrap_or_default())
+            .bind(record.hour)
+            .bind(record.requests)
+            .bind(record.errors)
+            .bind(record.request_bytes)
+            .bind(record.response_bytes)
+            .execute(&mut *tx);
+            self.db.timed("api_usage.add", statement).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        tenant: Option<&TenantId>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<UsageRecord>> {
+        let statement = sqlx::query_as::<_, UsageRow>(
+            r#"
+            SELECT tenant_id, key_id, api_key, hour,
+                   requests, errors, request_bytes, response_bytes
+            FROM api_usage
+            WHERE (?1 IS NULL OR tenant_id = ?1) AND hour >= ?2 AND hour < ?3
+            ORDER BY hour, tenant_id, key_id
+            "#,
+        )
+        .bind(tenant.map(TenantId::as_str))
+        .bind(from)
+        .bind(to)
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("api_usage.list", statement).await?;
+
+        rows.into_iter()
+            .map(
+                |(
+                    tenant,
+                    key_id,
+                    api_key,
+                    hour,
+                    requests,
+                    errors,
+                    request_bytes,
+                    response_bytes,
+                )| {
+                    Ok(UsageRecord {
+                        tenant: TenantId::new(tenant)?,
+                        key_id: Some(key_id).filter(|key_id| !key_id.is_empty()),
+                        api_key: Some(api_key).filter(|key| !key.is_empty()),
+                        hour,
+                        requests,
+                        errors,
+                        request_bytes,
+                        response_bytes,
+                    })
+                },
+            )
+            .collect()
+    }
+
+    async fn requests_by_key_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> DomainResult<Vec<(TenantId, String, i64)>> {
+        let statement = sqlx::query_as::<_, (String, String, i64)>(
+            r#"
+            SELECT tenant_id, key_id, SUM(requests)
+            FROM api_usage
+            WHERE hour >= ?1 AND key_id <> ''
+            GROUP BY tenant_id, key_id
+            "#,
+        )
+        .bind(since)
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("api_usage.by_key", statement).await?;
+
+        rows.into_iter()
+            .map(|(tenant, key_id, requests)| Ok((TenantId::new(tenant)?, key_id, requests)))
+            .collect()
+    }
+
+    async fn quotas(&self) -> DomainResult<Vec<ApiQuota>> {
+        let statement = sqlx::query_as::<_, (String, String, String, i64)>(
+            "SELECT tenant_id, key_id, api_key, monthly_requests FROM api_quotas \
+             ORDER BY tenant_id, key_id",
+        )
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self.db.timed("api_quotas.list", statement).await?;
+
+        rows.into_iter()
+            .map(|(tenant, key_id, api_key, monthly_requests)| {
+                Ok(ApiQuota {
+                    tenant: TenantId::new(tenant)?,
+                    key_id,
+                    api_key,
+                    monthly_requests,
+                })
+            })
+            .collect()
+    }
+
+    async fn set_quota(&self, quota: &ApiQuota) -> DomainResult<()> {
+        let statement = sqlx::query(
+            r#"
+            INSERT INTO api_quotas (tenant_id, key_id, api_key, monthly_requests, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT (tenant_id, key_id)
+            DO UPDATE SET api_key = excluded.api_key,
+                          monthly_requests = excluded.monthly_requests,
+                          updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(quota.tenant.as_str())
+        .bind(&quota.key_id)
+        .bind(&quota.api_key)
+        .bind(quota.monthly_requests)
+        .bind(Utc::now())
+        .execute(self.db.sqlite_pool());
+        self.db.timed("api_quotas.set", statement).await?;
+
+        Ok(())
+    }
+
+    async fn remove_quota(&self, tenant: &TenantId, key_id: &str) -> DomainResult<bool> {
+        let statement = sqlx::query("DELETE FROM api_quotas WHERE tenant_id = ?1 AND key_id = ?2")
+            .bind(tenant.as_str())
+            .bind(key_id)
+            .execute(self.db.sqlite_pool());
+        let result = self.db.timed("api_quotas.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,159 @@
+//! SQLite implementation of WebhookEndpointRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+use uuid::fmt::Hyphenated;
+
+use crate::application::ports::{WebhookEndpoint, WebhookEndpointRepository};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::domain::shared::TenantId;
+use crate::infrastructure::persistance::DatabasePool;
+
+/// Database row representation for WebhookEndpoint
+#[derive(Debug, FromRow)]
+struct WebhookEndpointRow {
+    id: Hyphenated,
+    tenant_id: String,
+    url: String,
+    secret: String,
+    previous_secret: Option<String>,
+    previous_secret_expires_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<WebhookEndpointRow> for WebhookEndpoint {
+    type Error = AppError;
+
+    fn try_from(row: WebhookEndpointRow) -> Result<Self, Self::Error> {
+        Ok(WebhookEndpoint {
+            id: row.id.into_uuid(),
+            tenant: TenantId::new(row.tenant_id)?,
+            url: row.url,
+            secret: row.secret,
+            previous_secret: row.previous_secret,
+            previous_secret_expires_at: row.previous_secret_expires_at,
+            created_at: row.created_at,
+        })
+    }
+}
+
+/// SQLite implementation of WebhookEndpointRepository
+pub struct SqliteWebhookEndpointRepository {
+    db: DatabasePool,
+}
+
+impl SqliteWebhookEndpointRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl WebhookEndpointRepository for SqliteWebhookEndpointRepository {
+    async fn create(&self, endpoint: &WebhookEndpoint) -> DomainResult<()> {
+        let statement = sqlx::query(
+            r#"
+            INSERT INTO webhook_endpoints
+                (id, tenant_id, url, secret, previous_secret, previous_secret_expires_at, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )
+        .bind(endpoint.id.hyphenated())
+        .bind(endpoint.tenant.as_str())
+        .bind(&endpoint.url)
+        .bind(&endpoint.secret)
+        .bind(&endpoint.previous_secret)
+        .bind(endpoint.previous_secret_expires_at)
+        .bind(endpoint.created_at)
+        .execute(self.db.sqlite_pool());
+        self.db.timed("webhook_endpoints.create", statement).await?;
+
+        Ok(())
+    }
+
+    async fn find_all(&self, tenant: &TenantId) -> DomainResult<Vec<WebhookEndpoint>> {
+        let statement = sqlx::query_as::<_, WebhookEndpointRow>(
+            r#"
+            SELECT id, tenant_id, url, secret, previous_secret, previous_secret_expires_at,
+                   created_at
+            FROM webhook_endpoints
+            WHERE tenant_id = ?1
+            ORDER BY created_at, id
+            "#,
+        )
+        .bind(tenant.as_str())
+        .fetch_all(self.db.sqlite_pool());
+        let rows = self
+            .db
+            .timed("webhook_endpoints.find_all", statement)
+            .await?;
+
+        rows.into_iter().map(WebhookEndpoint::try_from).collect()
+    }
+
+    async fn find_by_id(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+    ) -> DomainResult<Option<WebhookEndpoint>> {
+        let statement = sqlx::query_as::<_, WebhookEndpointRow>(
+            r#"
+            SELECT id, tenant_id, url, secret, previous_secret, previous_secret_expires_at,
+                   created_at
+            FROM webhook_endpoints
+            WHERE tenant_id = ?1 AND id = ?2
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(id.hyphenated())
+        .fetch_optional(self.db.sqlite_pool());
+        let row = self
+            .db
+            .timed("webhook_endpoints.find_by_id", statement)
+            .await?;
+
+        row.map(WebhookEndpoint::try_from).transpose()
+    }
+
+    async fn rotate_secret(
+        &self,
+        tenant: &TenantId,
+        id: Uuid,
+        secret: &str,
+        previous_expires_at: DateTime<Utc>,
+    ) -> DomainResult<Option<WebhookEndpoint>> {
+        let statement = sqlx::query_as::<_, WebhookEndpointRow>(
+            r#"
+            UPDATE webhook_endpoints
+            SET previous_secret = secret, previous_secret_expires_at = ?3, secret = ?4
+            WHERE tenant_id = ?1 AND id = ?2
+            RETURNING id, tenant_id, url, secret, previous_secret, previous_secret_expires_at,
+                      created_at
+            "#,
+        )
+        .bind(tenant.as_str())
+        .bind(id.hyphenated())
+        .bind(previous_expires_at)
+        .bind(secret)
+        .fetch_optional(self.db.sqlite_pool());
+        let row = self
+            .db
+            .timed("webhook_endpoints.rotate_secret", statement)
+            .await?;
+
+        row.map(WebhookEndpoint::try_from).transpose()
+    }
+
+    async fn delete(&self, tenant: &TenantId, id: Uuid) -> DomainResult<bool> {
+        let statement =
+            sqlx::query("DELETE FROM webhook_endpoints WHERE tenant_id = ?1 AND id = ?2")
+                .bind(tenant.as_str())
+                .bind(id.hyphenated())
+                .execute(self.db.sqlite_pool());
+        let result = self.db.timed("webhook_endpoints.delete", statement).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
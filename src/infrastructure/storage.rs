@@ -0,0 +1,236 @@
+//! Storage Wiring
+//!
+//! Builds the persistence adapters selected by the scheme of `DATABASE_URL`:
+//! `postgres://` (migrated on startup unless `AUTO_MIGRATE` is off), `sqlite://` for single-node
+//! deployments (needs the `sqlite` feature) or `memory://` for development
+//! and tests without a database.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::application::health::HealthRegistry;
+use crate::application::ports::{
+    DatabaseDump, DeliveryZoneRepository, DistributedLock, FeatureFlagRepository, FlowerHistory,
+    FlowerRepository, FlowerViewStore, Health, HealthIndicator, JobTickStore, NonceStore,
+    OrderRepository, PricingRuleRepository, SavedSearchRepository, StockLedger, StoreRepository,
+    TaskQueue, UnitOfWork, UsageStore, WebhookEndpointRepository,
+};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::config::AppConfig;
+use crate::infrastructure::memory::{
+    InMemoryDeliveryZoneRepository, InMemoryFeatureFlagRepository, InMemoryFlowerHistory,
+    InMemoryFlowerRepository, InMemoryFlowerViewStore, InMemoryNonceStore, InMemoryOrderRepository,
+    InMemoryPricingRuleRepository, InMemorySavedSearchRepository, InMemoryStockLedger,
+    InMemoryStoreRepository, InMemoryTaskQueue, InMemoryUnitOfWork, InMemoryUsageStore,
+    InMemoryWebhookEndpointRepository,
+};
+use crate::infrastructure::persistance::{
+    DatabaseHealth, DatabasePool, MigrationsHealth, PoolHealth, PostgresAdvisoryLock,
+    PostgresDatabaseDump, PostgresDeliveryZoneRepository, PostgresFeatureFlagRepository,
+    PostgresFlowerHistory, PostgresFlowerRepository, PostgresFlowerViewStore, PostgresJobTickStore,
+    PostgresNonceStore, PostgresOrderRepository, PostgresPricingRuleRepository,
+    PostgresSavedSearchRepository, PostgresStockLedger, PostgresStoreRepository, PostgresTaskQueue,
+    PostgresUnitOfWork, PostgresUsageStore, PostgresWebhookEndpointRepository,
+};
+
+/// URL scheme selecting the in-memory adapters
+pub const MEMORY_SCHEME: &str = "memory://";
+
+/// Connect to the database of `DATABASE_URL` without touching its schema
+pub async fn connect_database(config: &AppConfig) -> DomainResult<DatabasePool> {
+    if config.database_url.starts_with(MEMORY_SCHEME) {
+        return Err(AppError::internal(
+            "In-memory storage has no database to manage",
+        ));
+    }
+
+    tracing::info!("Connecting to database...");
+    DatabasePool::new(&config.database_url, config.db_pool)
+        .await?
+        .with_slow_query_threshold(config.slow_query_threshold)
+        .with_read_replicas(&config.database_read_urls)
+}
+
+/// Persistence adapters used by the application
+pub struct Storage {
+    pub flowers: Arc<dyn FlowerRepository>,
+    pub feature_flags: Arc<dyn FeatureFlagRepository>,
+    pub tasks: Arc<dyn TaskQueue>,
+    pub views: Arc<dyn FlowerViewStore>,
+    /// Inventory ledger, for audits; movements are recorded through
+    /// `unit_of_work`
+    pub ledger: Arc<dyn StockLedger>,
+    /// Field-level changes to flowers; recorded through `unit_of_work` too
+    pub history: Arc<dyn FlowerHistory>,
+    pub saved_searches: Arc<dyn SavedSearchRepository>,
+    pub stores: Arc<dyn StoreRepository>,
+    pub delivery_zones: Arc<dyn DeliveryZoneRepository>,
+    /// Orders and their status history; written through `unit_of_work`
+    pub orders: Arc<dyn OrderRepository>,
+    pub pricing_rules: Arc<dyn PricingRuleRepository>,
+    /// Hourly API usage per tenant and API key
+    pub usage: Arc<dyn UsageStore>,
+    /// Nonces of signed inbound webhooks, against replays
+    pub nonces: Arc<dyn NonceStore>,
+    /// Receivers tenants have their events delivered to
+    pub webhook_endpoints: Arc<dyn WebhookEndpointRepository>,
+    /// Transactions spanning the repositories above
+    pub unit_of_work: Arc<dyn UnitOfWork>,
+    /// Lock coordinating replicas; `None` when storage is not shared
+    pub lock: Option<Arc<dyn DistributedLock>>,
+    /// Ticks the jobs taking `lock` ran for; `None` when storage is not
+    /// shared
+    pub job_ticks: Option<Arc<dyn JobTickStore>>,
+    /// Logical export for backups; only supported on PostgreSQL
+    pub dump: Option<Arc<dyn DatabaseDump>>,
+    /// Database pool, for health checks; `None` for in-memory storage
+    pub db: Option<DatabasePool>,
+}
+
+impl Storage {
+    /// Connect to the configured storage and bring its schema up to date
+    ///
+    /// Fails when the schema does not match the embedded migrations and
+    /// migrating cannot fix it: migrations are pending with `AUTO_MIGRATE`
+    /// off, or the database is ahead of this build, or applied migrations
+    /// were edited or failed.
+    pub async fn connect(config: &AppConfig) -> DomainResult<Self> {
+        if config.database_url.starts_with(MEMORY_SCHEME) {
+            tracing::warn!("Using in-memory storage; data is lost on restart");
+            return Ok(Self::in_memory());
+        }
+
+        let db = connect_database(config).await?;
+        let connections = db.warm_up().await?;
+        tracing::info!("Opened {} database connection(s)", connections);
+
+        let mut drift = db.schema_drift().await?;
+        if config.auto_migrate && drift.is_behind() {
+            tracing::info!("Running {} pending migration(s)...", drift.pending.len());
+            db.run_migrations().await?;
+            tracing::info!("Migrations completed successfully");
+            drift = db.schema_drift().await?;
+        }
+        if !drift.is_empty() {
+            return Err(AppError::internal(format!(
+                "Database schema does not match this build: {}",
+                drift
+            )));
+        }
+
+        #[cfg(feature = "sqlite")]
+        if db.is_sqlite() {
+            use crate::infrastructure::sqlite::{
+                SqliteDeliveryZoneRepository, SqliteFeatureFlagRepository, SqliteFlowerHistory,
+                SqliteFlowerRepository, SqliteFlowerViewStore, SqliteNonceStore,
+                SqliteOrderRepository, SqlitePricingRuleRepository, SqliteSavedSearchRepository,
+                SqliteStockLedger, SqliteStoreRepository, SqliteTaskQueue, SqliteUnitOfWork,
+                SqliteUsageStore, SqliteWebhookEndpointRepository,
+            };
+
+            return Ok(Self {
+                flowers: Arc::new(SqliteFlowerRepository::new(db.clone())),
+                feature_flags: Arc::new(SqliteFeatureFlagRepository::new(db.clone())),
+                tasks: Arc::new(SqliteTaskQueue::new(db.clone())),
+                views: Arc::new(SqliteFlowerViewStore::new(db.clone())),
+                ledger: Arc::new(SqliteStockLedger::new(db.clone())),
+                history: Arc::new(SqliteFlowerHistory::new(db.clone())),
+                saved_searches: Arc::new(SqliteSavedSearchRepository::new(db.clone())),
+                stores: Arc::new(SqliteStoreRepository::new(db.clone())),
+                delivery_zones: Arc::new(SqliteDeliveryZoneRepository::new(db.clone())),
+                orders: Arc::new(SqliteOrderRepository::new(db.clone())),
+                pricing_rules: Arc::new(SqlitePricingRuleRepository::new(db.clone())),
+                usage: Arc::new(SqliteUsageStore::new(db.clone())),
+                nonces: Arc::new(SqliteNonceStore::new(db.clone())),
+                webhook_endpoints: Arc::new(SqliteWebhookEndpointRepository::new(db.clone())),
+                unit_of_work: Arc::new(SqliteUnitOfWork::new(db.clone())),
+                lock: None,
+                job_ticks: None,
+                dump: None,
+                db: Some(db),
+            });
+        }
+
+        Ok(Self {
+            flowers: Arc::new(PostgresFlowerRepository::new(db.clone())),
+            feature_flags: Arc::new(PostgresFeatureFlagRepository::new(db.clone())),
+            tasks: Arc::new(PostgresTaskQueue::new(db.clone())),
+            views: Arc::new(PostgresFlowerViewStore::new(db.clone())),
+            ledger: Arc::new(PostgresStockLedger::new(db.clone())),
+            history: Arc::new(PostgresFlowerHistory::new(db.clone())),
+            saved_searches: Arc::new(PostgresSavedSearchRepository::new(db.clone())),
+            stores: Arc::new(PostgresStoreRepository::new(db.clone())),
+            delivery_zones: Arc::new(PostgresDeliveryZoneRepository::new(db.clone())),
+            orders: Arc::new(PostgresOrderRepository::new(db.clone())),
+            pricing_rules: Arc::new(PostgresPricingRuleRepository::new(db.clone())),
+            usage: Arc::new(PostgresUsageStore::new(db.clone())),
+            nonces: Arc::new(PostgresNonceStore::new(db.clone())),
+            webhook_endpoints: Arc::new(PostgresWebhookEndpointRepository::new(db.clone())),
+            unit_of_work: Arc::new(PostgresUnitOfWork::new(db.clone())),
+            lock: Some(Arc::new(PostgresAdvisoryLock::new(db.clone()))),
+            job_ticks: Some(Arc::new(PostgresJobTickStore::new(db.clone()))),
+            dump: Some(Arc::new(PostgresDatabaseDump::new(db.clone()))),
+            db: Some(db),
+        })
+    }
+
+    /// Fresh, empty in-memory storage
+    pub fn in_memory() -> Self {
+        let flowers = Arc::new(InMemoryFlowerRepository::new());
+        let tasks = Arc::new(InMemoryTaskQueue::new());
+        let ledger = Arc::new(InMemoryStockLedger::new());
+        let history = Arc::new(InMemoryFlowerHistory::new());
+        let orders = Arc::new(InMemoryOrderRepository::new());
+        Self {
+            flowers: flowers.clone(),
+            feature_flags: Arc::new(InMemoryFeatureFlagRepository::new()),
+            tasks: tasks.clone(),
+            views: Arc::new(InMemoryFlowerViewStore::new()),
+            ledger: ledger.clone(),
+            history: history.clone(),
+            saved_searches: Arc::new(InMemorySavedSearchRepository::new()),
+            stores: Arc::new(InMemoryStoreRepository::new()),
+            delivery_zones: Arc::new(InMemoryDeliveryZoneRepository::new()),
+            orders: orders.clone(),
+            pricing_rules: Arc::new(InMemoryPricingRuleRepository::new()),
+            usage: Arc::new(InMemoryUsageStore::new()),
+            nonces: Arc::new(InMemoryNonceStore::new()),
+            webhook_endpoints: Arc::new(InMemoryWebhookEndpointRepository::new()),
+            unit_of_work: Arc::new(InMemoryUnitOfWork::new(
+                flowers, ledger, history, tasks, orders,
+            )),
+            lock: None,
+            job_ticks: None,
+            dump: None,
+            db: None,
+        }
+    }
+
+    /// Add the indicators of this storage to `registry`: the database, its
+    /// schema and its pool, or the in-memory storage that cannot fail
+    pub fn register_health(&self, registry: HealthRegistry) -> HealthRegistry {
+        match &self.db {
+            Some(db) => registry
+                .register(DatabaseHealth(db.clone()))
+                .register(MigrationsHealth(db.clone()))
+                .register(PoolHealth(db.clone())),
+            None => registry.register(MemoryStorageHealth),
+        }
+    }
+}
+
+/// In-memory storage, always up
+struct MemoryStorageHealth;
+
+#[async_trait]
+impl HealthIndicator for MemoryStorageHealth {
+    fn name(&self) -> &'static str {
+        "storage"
+    }
+
+    async fn check(&self) -> Health {
+        Health::up().with_details(json!({ "backend": "memory" }))
+    }
+}
@@ -0,0 +1,124 @@
+//! Supplier Feed Formats
+//!
+//! CSV feeds have a header row naming the columns `sku`, `name`, `color`,
+//! `description`, `price` and `stock` in any order; JSON feeds are an array
+//! of objects with the same fields. Only `sku` is required.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::application::ports::{FeedEntry, SupplierProduct};
+
+/// Encoding of a feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Csv,
+    Json,
+}
+
+impl FeedFormat {
+    /// Format named by a response's content type, or else by the extension
+    /// of the feed's path
+    pub fn detect(url: &str, content_type: Option<&str>) -> Option<Self> {
+        let content_type = content_type.unwrap_or_default().to_ascii_lowercase();
+        if content_type.contains("json") {
+            return Some(FeedFormat::Json);
+        }
+        if content_type.contains("csv") {
+            return Some(FeedFormat::Csv);
+        }
+
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let extension = path.rsplit_once('.')?.1.to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Some(FeedFormat::Json),
+            "csv" => Some(FeedFormat::Csv),
+            _ => None,
+        }
+    }
+
+    /// Parse a feed into its entries; fails only when the feed as a whole is
+    /// malformed
+    pub fn parse(self, body: &[u8]) -> Result<Vec<FeedEntry>, String> {
+        match self {
+            FeedFormat::Csv => parse_csv(body),
+            FeedFormat::Json => parse_json(body),
+        }
+    }
+}
+
+fn parse_csv(body: &[u8]) -> Result<Vec<FeedEntry>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(body);
+    let headers = reader.headers().map_err(|e| e.to_string())?;
+    if !headers.iter().any(|header| header == "sku") {
+        return Err("the header row has no sku column".to_string());
+    }
+
+    Ok(reader
+        .deserialize::<SupplierProduct>()
+        .map(|row| row.map_err(|e| e.to_string()))
+        .collect())
+}
+
+fn parse_json(body: &[u8]) -> Result<Vec<FeedEntry>, String> {
+    let items: Vec<Value> = serde_json::from_slice(body).map_err(|e| e.to_string())?;
+
+    Ok(items
+        .into_iter()
+        .map(|item| SupplierProduct::deserialize(item).map_err(|e| e.to_string()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_format_from_content_type_then_extension() {
+        let detect = FeedFormat::detect;
+        assert_eq!(
+            detect("https://x/feed", Some("application/json; charset=utf-8")),
+            Some(FeedFormat::Json)
+        );
+        assert_eq!(
+            detect("https://x/feed.json?key=1", Some("text/plain")),
+            Some(FeedFormat::Json)
+        );
+        assert_eq!(detect("file:///srv/feed.CSV", None), Some(FeedFormat::Csv));
+        assert_eq!(detect("https://x/feed", None), None);
+    }
+
+    #[test]
+    fn csv_rows_fail_on_their_own() {
+        let feed = "sku,name,price,stock\n\
+                    RO-1, Rose ,25000,\n\
+                    TU-2,Tulip,cheap,4\n";
+        let entries = FeedFormat::Csv.parse(feed.as_bytes()).unwrap();
+
+        assert_eq!(
+            entries[0],
+            Ok(SupplierProduct {
+                sku: "RO-1".to_string(),
+                name: Some("Rose".to_string()),
+                color: None,
+                description: None,
+                price: Some(25_000.0),
+                stock: None,
+            })
+        );
+        assert!(entries[1].is_err());
+        assert!(FeedFormat::Csv.parse(b"code,name\nA,B\n").is_err());
+    }
+
+    #[test]
+    fn json_items_fail_on_their_own() {
+        let feed = r#"[{"sku": "RO-1", "price": 25000}, {"name": "No SKU"}]"#;
+        let entries = FeedFormat::Json.parse(feed.as_bytes()).unwrap();
+
+        assert_eq!(entries[0].as_ref().unwrap().price, Some(25_000.0));
+        assert!(entries[1].as_ref().unwrap_err().contains("sku"));
+        assert!(FeedFormat::Json.parse(b"{}").is_err());
+    }
+}
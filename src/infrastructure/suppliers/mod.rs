@@ -0,0 +1,106 @@
+//! Supplier Feeds
+//!
+//! Feeds are read from `file://` paths, such as a directory suppliers upload
+//! to, or over HTTP(S) (requires the `suppliers` feature). See `format` for
+//! the CSV and JSON layouts.
+
+pub mod format;
+
+use std::sync::Arc;
+#[cfg(feature = "suppliers")]
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::application::ports::{FeedEntry, SupplierFeed};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::i18n::Message;
+use crate::infrastructure::config::AppConfig;
+use crate::infrastructure::http_client::HttpClientSettings;
+#[cfg(feature = "suppliers")]
+use crate::infrastructure::http_client::{HttpClient, HttpError};
+
+pub use format::FeedFormat;
+
+/// Upper bound on downloading one feed
+#[cfg(feature = "suppliers")]
+const FETCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Feed reader for the configured suppliers
+pub fn feed(config: &AppConfig) -> DomainResult<Arc<dyn SupplierFeed>> {
+    let remote = config
+        .suppliers
+        .iter()
+        .find(|(_, supplier)| !supplier.feed_url.starts_with("file://"));
+    if let (Some((id, _)), false) = (remote, cfg!(feature = "suppliers")) {
+        return Err(AppError::internal(format!(
+            "The feed of supplier '{}' is fetched over HTTP, which requires building with the `suppliers` feature",
+            id
+        )));
+    }
+
+    Ok(Arc::new(UrlSupplierFeed::new(&config.http_client)?))
+}
+
+/// Reads feeds from `file://`, `http://` and `https://` URLs
+pub struct UrlSupplierFeed {
+    #[cfg(feature = "suppliers")]
+    client: HttpClient,
+}
+
+impl UrlSupplierFeed {
+    #[cfg_attr(not(feature = "suppliers"), allow(unused_variables))]
+    pub fn new(http: &HttpClientSettings) -> DomainResult<Self> {
+        Ok(Self {
+            #[cfg(feature = "suppliers")]
+            client: HttpClient::new(*http)?,
+        })
+    }
+
+    /// Body and content type of the feed
+    async fn download(&self, url: &str) -> Result<(Vec<u8>, Option<String>), String> {
+        if let Some(path) = url.strip_prefix("file://") {
+            let body = tokio::fs::read(path)
+                .await
+                .map_err(|e| format!("{}: {}", path, e))?;
+            return Ok((body, None));
+        }
+
+        #[cfg(feature = "suppliers")]
+        {
+            let request = self.client.get(url).timeout(FETCH_TIMEOUT);
+            let response = self
+                .client
+                .send(request)
+                .await
+                .and_then(|response| response.error_for_status().map_err(HttpError::from))
+                .map_err(|e| e.to_string())?;
+            let content_type = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+            let body = response.bytes().await.map_err(|e| e.to_string())?;
+            Ok((body.to_vec(), content_type))
+        }
+        #[cfg(not(feature = "suppliers"))]
+        Err("fetching feeds over HTTP requires the `suppliers` feature".to_string())
+    }
+}
+
+#[async_trait]
+impl SupplierFeed for UrlSupplierFeed {
+    async fn fetch(&self, url: &str) -> DomainResult<Vec<FeedEntry>> {
+        let unavailable = |reason: String| {
+            AppError::service_unavailable(
+                Message::new("supplier.feed_unavailable").arg("reason", reason),
+            )
+        };
+
+        let (body, content_type) = self.download(url).await.map_err(unavailable)?;
+        let format = FeedFormat::detect(url, content_type.as_deref()).ok_or_else(|| {
+            unavailable("the content type and file extension name neither CSV nor JSON".to_string())
+        })?;
+        format.parse(&body).map_err(unavailable)
+    }
+}
@@ -0,0 +1,28 @@
+//! Console Webhook Sender
+
+use async_trait::async_trait;
+
+use crate::application::ports::{OutboundWebhook, WebhookReceipt, WebhookSender};
+use crate::domain::errors::DomainResult;
+use crate::infrastructure::webhooks::destination::is_private_url;
+
+/// Logs webhooks instead of sending them, for builds without the `webhooks`
+/// feature
+pub struct ConsoleWebhookSender;
+
+#[async_trait]
+impl WebhookSender for ConsoleWebhookSender {
+    async fn send(&self, webhook: &OutboundWebhook) -> DomainResult<WebhookReceipt> {
+        tracing::info!(
+            url = %webhook.url,
+            id = %webhook.id,
+            "Webhook not sent (console transport):\n{}",
+            String::from_utf8_lossy(&webhook.body)
+        );
+        Ok(WebhookReceipt { status: None })
+    }
+
+    async fn is_private(&self, url: &str) -> bool {
+        is_private_url(url)
+    }
+}
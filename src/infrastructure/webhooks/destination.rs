@@ -0,0 +1,123 @@
+//! Webhook Destinations
+//!
+//! Receivers are given by tenants, so deliveries only go to the public
+//! internet. URLs are judged by the host `url::Url` parses from them, the
+//! same parser the HTTP client connects by, so `http://127.0.0.1\@evil.com`
+//! or `http://2130706433` are judged by the address they really lead to.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use url::{Host, Url};
+
+/// Whether `url`, as written, leads into our own network: to a `localhost`
+/// or `.internal` name or to an IP address that is not public; URLs that are
+/// not absolute http(s) URLs with a host count as private too
+pub fn is_private_url(url: &str) -> bool {
+    let Some(url) = parse(url) else {
+        return true;
+    };
+    match url.host() {
+        Some(Host::Ipv4(ip)) => !is_public_address(IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => !is_public_address(IpAddr::V6(ip)),
+        Some(Host::Domain(name)) => {
+            let name = name.trim_end_matches('.');
+            name == "localhost" || name.ends_with(".localhost") || name.ends_with(".internal")
+        }
+        None => true,
+    }
+}
+
+/// Name `url` leads to, to be resolved; `None` for IP addresses and URLs
+/// that do not parse
+pub fn domain(url: &str) -> Option<String> {
+    match parse(url)?.host()? {
+        Host::Domain(name) => Some(name.to_string()),
+        Host::Ipv4(_) | Host::Ipv6(_) => None,
+    }
+}
+
+fn parse(url: &str) -> Option<Url> {
+    Url::parse(url)
+        .ok()
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+}
+
+/// Whether `ip` is on the public internet: not loopback, private,
+/// link-local (where cloud metadata services answer), shared, unspecified,
+/// broadcast, multicast or reserved for documentation
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_v4(mapped),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        // "This network", shared address space (carrier-grade NAT),
+        // benchmarking and reserved
+        || a == 0
+        || (a == 100 && (64..128).contains(&b))
+        || (a == 198 && (18..20).contains(&b))
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // Unique local, link-local and documentation
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80
+        || first == 0x2001 && ip.segments()[1] == 0x0db8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn urls_are_judged_by_the_host_they_connect_to() {
+        for url in [
+            "http://localhost:8080/hooks",
+            "http://127.0.0.1/hooks",
+            "http://127.0.0.1\\@shop.example.com/hooks",
+            "http://0x7f000001/hooks",
+            "http://2130706433/hooks",
+            "http://0177.0.0.1/hooks",
+            "https://shop.example.com@10.0.0.7/hooks",
+            "http://169.254.169.254/latest/meta-data",
+            "http://metadata.google.internal/computeMetadata/v1",
+            "http://[::1]:8080",
+            "http://[::ffff:192.168.1.1]/hooks",
+            "http://100.64.0.1/hooks",
+            "ftp://shop.example.com",
+            "not a url",
+        ] {
+            assert!(is_private_url(url), "{}", url);
+        }
+        for url in [
+            "https://shop.example.com/hooks",
+            "http://93.184.216.34:8080/hooks",
+            "https://[2606:4700::1111]/hooks",
+        ] {
+            assert!(!is_private_url(url), "{}", url);
+        }
+        assert_eq!(
+            domain("https://Shop.Example.com/hooks").as_deref(),
+            Some("shop.example.com")
+        );
+        assert_eq!(domain("http://2130706433/hooks"), None);
+    }
+}
@@ -0,0 +1,113 @@
+//! HTTP Webhook Sender
+//!
+//! Deliveries are POSTed with their JSON body and the headers receivers
+//! verify them by, as the payment provider signs its callbacks to us:
+//! `X-Webhook-Id`, `X-Webhook-Timestamp` and `X-Signature`, the latter with
+//! a signature per secret, comma separated, while a secret is rotated.
+//!
+//! Receivers are given by tenants, so they are only reached on the public
+//! internet: names are resolved to public addresses only, and redirects are
+//! not followed, so neither a DNS record nor a receiver can point a
+//! delivery into our own network.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::redirect::Policy;
+
+use crate::application::ports::{
+    OutboundWebhook, SIGNATURE_HEADER, WEBHOOK_ID_HEADER, WEBHOOK_TIMESTAMP_HEADER, WebhookReceipt,
+    WebhookSender,
+};
+use crate::domain::errors::{AppError, DomainResult};
+use crate::infrastructure::http_client::{HttpClient, HttpClientSettings};
+use crate::infrastructure::webhooks::destination::{domain, is_private_url, is_public_address};
+
+/// POSTs webhooks to their receivers
+pub struct HttpWebhookSender {
+    client: HttpClient,
+}
+
+impl HttpWebhookSender {
+    pub fn new(http: &HttpClientSettings) -> DomainResult<Self> {
+        Ok(Self {
+            client: HttpClient::with_builder(*http, |builder| {
+                builder
+                    .dns_resolver(Arc::new(PublicResolver))
+                    .redirect(Policy::none())
+            })?,
+        })
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, webhook: &OutboundWebhook) -> DomainResult<WebhookReceipt> {
+        // Addresses in the URL itself are not resolved, so not checked by
+        // `PublicResolver`
+        if is_private_url(&webhook.url) {
+            return Err(AppError::internal(format!(
+                "Webhook {} not sent, {} is not a public address",
+                webhook.id, webhook.url
+            )));
+        }
+        let request = self
+            .client
+            .post(&webhook.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_ID_HEADER, &webhook.id)
+            .header(WEBHOOK_TIMESTAMP_HEADER, webhook.timestamp.to_string())
+            .header(SIGNATURE_HEADER, webhook.signatures.join(","))
+            .body(webhook.body.clone());
+
+        // Not retried here: a failed delivery is retried by its task
+        let response = self.client.send(request).await.map_err(|e| {
+            AppError::internal(format!("Failed to deliver webhook {}: {}", webhook.id, e))
+        })?;
+        Ok(WebhookReceipt {
+            status: Some(response.status().as_u16()),
+        })
+    }
+
+    async fn is_private(&self, url: &str) -> bool {
+        if is_private_url(url) {
+            return true;
+        }
+        // Names that do not resolve yet are let through; deliveries to
+        // them go through `PublicResolver` all the same
+        match domain(url) {
+            Some(name) => lookup(&name)
+                .await
+                .is_ok_and(|addrs| addrs.iter().any(|addr| !is_public_address(addr.ip()))),
+            None => false,
+        }
+    }
+}
+
+/// Resolves names like the system does, but fails for names with any
+/// address outside the public internet
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = lookup(name.as_str()).await?;
+            if let Some(addr) = addrs.iter().find(|addr| !is_public_address(addr.ip())) {
+                return Err(format!(
+                    "{} resolves to {}, not a public address",
+                    name.as_str(),
+                    addr.ip()
+                )
+                .into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+async fn lookup(host: &str) -> std::io::Result<Vec<SocketAddr>> {
+    Ok(tokio::net::lookup_host((host, 0)).await?.collect())
+}
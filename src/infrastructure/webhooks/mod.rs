@@ -0,0 +1,30 @@
+//! Webhook Delivery
+//!
+//! Deliveries to tenants' receivers are POSTed over HTTP(S) (requires the
+//! `webhooks` feature); builds without it write them to the log instead.
+
+pub mod console;
+pub mod destination;
+#[cfg(feature = "webhooks")]
+mod http;
+
+use std::sync::Arc;
+
+use crate::application::ports::WebhookSender;
+use crate::domain::errors::DomainResult;
+use crate::infrastructure::config::AppConfig;
+
+pub use console::ConsoleWebhookSender;
+pub use destination::is_private_url;
+#[cfg(feature = "webhooks")]
+pub use http::HttpWebhookSender;
+
+/// Webhook sender of this build
+#[cfg_attr(not(feature = "webhooks"), allow(unused_variables))]
+pub fn sender(config: &AppConfig) -> DomainResult<Arc<dyn WebhookSender>> {
+    #[cfg(feature = "webhooks")]
+    return Ok(Arc::new(HttpWebhookSender::new(&config.http_client)?));
+
+    #[cfg(not(feature = "webhooks"))]
+    Ok(Arc::new(ConsoleWebhookSender))
+}
@@ -0,0 +1,7 @@
+pub mod api;
+pub mod application;
+pub mod domain;
+pub mod i18n;
+pub mod infrastructure;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
@@ -1,21 +1,32 @@
-mod api;
-mod application;
-mod domain;
-mod infrastructure;
+mod cli;
 
 use std::sync::Arc;
 
-use tower_http::cors::{Any, CorsLayer};
-use tower_http::trace::TraceLayer;
+use clap::Parser;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-use crate::api::http::{AppState, create_router};
-use crate::application::usecases::FlowerUseCase;
-use crate::infrastructure::config::AppConfig;
-use crate::infrastructure::persistance::{DatabasePool, PostgresFlowerRepository};
+use rust_api::api::http::{AppState, create_router, serve};
+use rust_api::application::jobs::{
+    FlushUsageJob, FlushViewsJob, LowStockDigestJob, PurgeWebhookNoncesJob, RefreshFeatureFlagsJob,
+    RetentionJob, SavedSearchAlertsJob, SupplierSyncJob,
+};
+use rust_api::application::tasks::{
+    CatalogExportTask, DeliverWebhookTask, SendEmailTask, TaskWorker, TaskWorkerSettings,
+};
+use rust_api::application::usecases::{Emails, Seeder};
+use rust_api::infrastructure::build_info::BuildInfo;
+use rust_api::infrastructure::config::AppConfig;
+use rust_api::infrastructure::persistance::PoolProbe;
+use rust_api::infrastructure::scheduler::{JobSchedule, Scheduler};
+use rust_api::infrastructure::storage::Storage;
+use rust_api::infrastructure::{cache, diagnostics, email, error_reporting, secrets};
+
+use crate::cli::{Cli, Command};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
     // Initialize tracing
     tracing_subscriber::registry()
         .with(
@@ -25,51 +36,177 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    // The API document needs no configuration
+    let command = cli.command.unwrap_or(Command::Serve);
+    if let Command::Openapi { out, yaml } = command {
+        return cli::openapi(out.as_deref(), yaml);
+    }
+
     // Load configuration
-    let config = AppConfig::from_env();
+    let mut config = match AppConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            tracing::error!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    tracing::info!("Loaded {} configuration", config.profile);
+
+    // Resolve credentials from the secrets provider
+    let secrets_provider = secrets::provider(&config.secrets, &config.http_client)?;
+    config.apply_secrets(secrets_provider.as_ref()).await?;
+    config.id_version.set_global();
+    tracing::info!("{}", BuildInfo::current().summary());
+
+    let result = match command {
+        Command::Serve => run_server(config).await,
+        Command::Migrate(command) => cli::migrate(&config, command).await,
+        Command::Db(command) => cli::db(&config, command).await,
+        Command::Seed => cli::seed(&config).await,
+        Command::Openapi { .. } => unreachable!("handled before loading configuration"),
+    };
+    if let Err(e) = result {
+        tracing::error!("{}", e);
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Wire up storage, background work and the HTTP server, then serve until shutdown
+async fn run_server(config: AppConfig) -> Result<(), Box<dyn std::error::Error>> {
+    // Refuse settings that cannot work together before touching anything
+    for warning in config.check_consistency()? {
+        tracing::warn!("{}", warning);
+    }
+    tracing::info!("{}", diagnostics::banner(&config));
+
+    // Initialize error reporting (kept alive until shutdown)
+    let _error_reporting = error_reporting::init(&config);
     tracing::info!("Starting server on {}", config.server_addr());
 
-    // Initialize database
-    tracing::info!("Connecting to database...");
-    let db_pool = DatabasePool::new(&config.database_url).await?;
+    // Connect to storage
+    let storage = Storage::connect(&config).await?;
 
-    // Run migrations
-    tracing::info!("Running migrations...");
-    db_pool.run_migrations().await?;
-    tracing::info!("Migrations completed successfully");
+    // Wire the use cases, with the configured cache in front of flower lookups;
+    // refunds are settled by hand until a payment processor is integrated
+    let cache = cache::store(&config).await?;
+    let app_state = AppState::builder(&config, &storage)
+        .with_cache(cache)
+        .build()
+        .await?;
 
-    // Setup repositories
-    let flower_repository = Arc::new(PostgresFlowerRepository::new(db_pool));
+    let flower_usecase = app_state.flower_usecase.clone();
+    if config.seed_on_start == Some(config.profile) {
+        let report = Seeder::new(flower_usecase.clone())
+            .run(&config.default_tenant)
+            .await?;
+        tracing::info!(
+            "Seeded {} flowers ({} already present)",
+            report.created,
+            report.skipped
+        );
+    }
+    let views = app_state.views.clone();
+    let usage = app_state.usage.clone();
+    let feature_flags = app_state.feature_flags.clone();
+    let supplier_sync = app_state.suppliers.clone();
+    let tasks = app_state.tasks.clone();
 
-    // Setup use cases
-    let flower_usecase = Arc::new(FlowerUseCase::new(flower_repository));
+    // Queue email
+    let emails = Arc::new(Emails::new(tasks.clone()));
+
+    // Start background jobs (kept alive until shutdown). Jobs keeping this
+    // instance's state, such as its buffers and caches, always run; the
+    // shared ones, which one replica runs per tick, only with JOBS_ENABLED
+    let jobs = app_state.jobs.clone();
+    let scheduler = Scheduler::new(config.job_jitter).with_monitor(jobs.clone());
+    let scheduler = match storage.lock.clone() {
+        Some(lock) => scheduler.with_lock(lock),
+        None => scheduler,
+    };
+    let scheduler = match storage.job_ticks.clone() {
+        Some(ticks) => scheduler.with_ticks(ticks),
+        None => scheduler,
+    };
+    let scheduler = scheduler
+        .register(
+            RefreshFeatureFlagsJob::new(feature_flags.clone()),
+            JobSchedule::Every(config.feature_flags_refresh),
+        )
+        .register(
+            FlushViewsJob::new(views.clone()),
+            JobSchedule::Every(config.view_flush_interval),
+        )
+        .register(
+            FlushUsageJob::new(usage.clone()),
+            JobSchedule::Every(config.usage_flush_interval),
+        );
+    let scheduler = if config.jobs_enabled {
+        let scheduler = scheduler
+            .register(
+                PurgeWebhookNoncesJob::new(storage.nonces.clone()),
+                JobSchedule::Every(config.webhook_tolerance),
+            )
+            .register(
+                LowStockDigestJob::new(storage.flowers.clone(), config.low_stock_threshold)
+                    .with_emails(emails.clone(), config.low_stock_digest_recipients.clone()),
+                config.low_stock_digest_schedule.clone(),
+            )
+            .register(
+                SavedSearchAlertsJob::new(
+                    storage.saved_searches.clone(),
+                    flower_usecase.repository(),
+                    emails.clone(),
+                ),
+                config.saved_search_alerts_schedule.clone(),
+            );
+        let scheduler = if config.suppliers.is_empty() {
+            scheduler
+        } else {
+            scheduler.register(
+                SupplierSyncJob::new(supplier_sync.clone()),
+                config.supplier_sync_schedule.clone(),
+            )
+        };
+        match config.retention {
+            Some(policy) => scheduler.register(
+                RetentionJob::new(storage.tasks.clone(), policy),
+                config.retention_schedule.clone(),
+            ),
+            None => scheduler,
+        }
+    } else {
+        scheduler
+    };
+    let _scheduler = scheduler.start();
 
-    // Create application state
-    let app_state = AppState::new(flower_usecase);
+    // Start task workers (kept alive until shutdown)
+    let _task_workers = TaskWorker::new(
+        storage.tasks.clone(),
+        TaskWorkerSettings {
+            poll_interval: config.task_poll_interval,
+            lease: config.task_lease,
+            retry_backoff: config.task_retry_backoff,
+        },
+    )
+    .handle(CatalogExportTask::new(app_state.catalog_exports.clone()))
+    .handle(SendEmailTask::new(email::sender(&config)?))
+    .handle(DeliverWebhookTask::new(app_state.webhooks.clone()))
+    .start(config.task_workers);
 
-    // Setup CORS
-    let cors = CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods(Any)
-        .allow_headers(Any);
+    // Sample the database pool for metrics (kept alive until shutdown)
+    let _pool_probe = storage
+        .db
+        .clone()
+        .zip(config.db_pool_probe_interval)
+        .map(|(db, interval)| PoolProbe::start(db, interval));
 
-    // Create router
-    let app = create_router(app_state)
-        .layer(cors)
-        .layer(TraceLayer::new_for_http());
+    // Create router, wrapped in the middleware stack of the profile
+    let app = create_router(app_state, &config);
 
     // Start server
-    let listener = tokio::net::TcpListener::bind(&config.server_addr()).await?;
-    tracing::info!(
-        "🌸 Flower API is running on http://{}",
-        config.server_addr()
-    );
-    tracing::info!(
-        "📚 OpenAPI docs available at http://{}/openapi",
-        config.server_addr()
-    );
-
-    axum::serve(listener, app).await?;
+    serve(app, &config).await?;
 
     Ok(())
 }
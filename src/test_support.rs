@@ -0,0 +1,168 @@
+//! Test Support
+//!
+//! Factories for test data, shared by the unit tests and, through the
+//! `test-support` feature, the integration tests in `tests/`. Builders keep
+//! raw values until the end, so the same builder yields a domain entity or a
+//! request DTO, and invalid values can be sent to exercise validation.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::application::dtos::CreateFlowerRequest;
+use crate::application::ports::FlowerRepository;
+use crate::domain::errors::DomainResult;
+use crate::domain::flower::{
+    Flower, FlowerAttributes, FlowerDescription, FlowerMetadata, FlowerName, Price, Sku,
+    StockQuantity, UnitOfMeasure,
+};
+use crate::domain::shared::TenantId;
+
+/// Numbers default flower names, so builders can be used repeatedly
+/// without running into the unique name constraint
+static FLOWER_SEQUENCE: AtomicUsize = AtomicUsize::new(1);
+
+/// Flower of the default tenant with a unique name, red, priced at 25,000
+/// with 5 in stock unless told otherwise
+#[derive(Debug, Clone)]
+pub struct FlowerBuilder {
+    tenant: String,
+    name: String,
+    color: String,
+    description: Option<String>,
+    price: f64,
+    stock: i32,
+    unit: Option<String>,
+    stems_per_unit: Option<i32>,
+    sku: Option<String>,
+    attributes: FlowerAttributes,
+    metadata: BTreeMap<String, String>,
+}
+
+impl Default for FlowerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FlowerBuilder {
+    pub fn new() -> Self {
+        Self {
+            tenant: TenantId::DEFAULT.to_string(),
+            name: format!("Flower {}", FLOWER_SEQUENCE.fetch_add(1, Ordering::Relaxed)),
+            color: "red".to_string(),
+            description: None,
+            price: 25_000.0,
+            stock: 5,
+            unit: None,
+            stems_per_unit: None,
+            sku: None,
+            attributes: FlowerAttributes::default(),
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_tenant(mut self, tenant: impl ToString) -> Self {
+        self.tenant = tenant.to_string();
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_price(mut self, price: f64) -> Self {
+        self.price = price;
+        self
+    }
+
+    pub fn with_stock(mut self, stock: i32) -> Self {
+        self.stock = stock;
+        self
+    }
+
+    pub fn with_unit(mut self, unit: impl Into<String>, stems_per_unit: Option<i32>) -> Self {
+        self.unit = Some(unit.into());
+        self.stems_per_unit = stems_per_unit;
+        self
+    }
+
+    pub fn with_sku(mut self, sku: impl Into<String>) -> Self {
+        self.sku = Some(sku.into());
+        self
+    }
+
+    pub fn with_attributes(mut self, attributes: FlowerAttributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// The flower as an entity; panics if a value is invalid
+    pub fn build(self) -> Flower {
+        let description = self.description.map(FlowerDescription::new).transpose();
+        let sku = self.sku.map(Sku::new).transpose();
+        let unit = match &self.unit {
+            Some(unit) => UnitOfMeasure::new(unit, self.stems_per_unit),
+            None => Ok(UnitOfMeasure::default()),
+        };
+        valid(
+            "flower",
+            Flower::new(
+                valid("tenant", self.tenant.parse()),
+                valid("name", FlowerName::new(&self.name)),
+                valid("color", self.color.parse()),
+                valid("description", description).flatten(),
+                valid("price", Price::new(self.price)),
+                valid("stock", StockQuantity::new(self.stock)),
+                valid("unit", unit),
+                valid("sku", sku).flatten(),
+                self.attributes,
+                valid("metadata", FlowerMetadata::new(self.metadata)),
+            ),
+        )
+    }
+
+    /// The flower saved in `repository`, as stored there
+    pub async fn persisted(self, repository: &dyn FlowerRepository) -> Flower {
+        repository
+            .create(&self.build())
+            .await
+            .expect("FlowerBuilder could not persist the flower")
+    }
+
+    /// A request creating the flower, with values passed on unchecked; the
+    /// tenant is up to whoever sends it
+    pub fn create_request(self) -> CreateFlowerRequest {
+        CreateFlowerRequest {
+            name: self.name,
+            color: self.color,
+            description: self.description,
+            price: self.price,
+            stock: self.stock,
+            unit: self.unit,
+            stems_per_unit: self.stems_per_unit,
+            sku: self.sku,
+            attributes: (!self.attributes.is_empty()).then_some(self.attributes),
+            metadata: (!self.metadata.is_empty()).then_some(self.metadata),
+        }
+    }
+}
+
+fn valid<T>(field: &str, value: DomainResult<T>) -> T {
+    value.unwrap_or_else(|e| panic!("FlowerBuilder has an invalid {}: {}", field, e))
+}
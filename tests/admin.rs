@@ -0,0 +1,344 @@
+//! Admin endpoints end to end
+
+mod common;
+
+use axum::http::StatusCode;
+use rust_api::application::dtos::CreateFlowerRequest;
+use rust_api::domain::shared::Entity;
+use rust_api::test_support::FlowerBuilder;
+use serde_json::json;
+
+use common::TestApp;
+
+fn orchid() -> CreateFlowerRequest {
+    FlowerBuilder::new()
+        .with_name("Orchid")
+        .with_color("white")
+        .with_stock(3)
+        .create_request()
+}
+
+#[tokio::test]
+async fn admin_routes_admit_only_the_admin() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .build()
+        .await;
+
+    let anonymous = app.get("/api/admin/jobs").send().await;
+    assert_eq!(anonymous.status, StatusCode::UNAUTHORIZED);
+    let tenant = app.get("/api/admin/jobs").api_key("rose-key").send().await;
+    assert_eq!(tenant.status, StatusCode::FORBIDDEN);
+
+    // Background jobs are off in tests
+    let admin = app.get("/api/admin/jobs").admin().send().await;
+    assert_eq!(admin.status, StatusCode::OK);
+    assert_eq!(admin.data(), &json!([]));
+}
+
+#[tokio::test]
+async fn audits_and_purges_flower_history() {
+    let app = TestApp::spawn().await;
+    let created = app
+        .post("/api/flowers")
+        .for_tenant("greenhouse")
+        .json(orchid())
+        .send()
+        .await;
+    let id = created.data()["id"].as_str().unwrap().to_string();
+    let adjusted = app
+        .post(&format!("/api/flowers/{}/stock-adjustments", id))
+        .for_tenant("greenhouse")
+        .json(json!({ "delta": 4, "reason": "Delivery" }))
+        .send()
+        .await;
+    assert_eq!(adjusted.status, StatusCode::CREATED);
+
+    let audit = format!("/api/admin/audit/stock-movements?flower_id={}", id);
+    let ledger = app.get(&audit).admin().send().await;
+    assert_eq!(ledger.status, StatusCode::OK);
+    assert_eq!(ledger.headers["x-total-count"], "2");
+    assert_eq!(ledger.data()["data"][0]["tenant_id"], "greenhouse");
+    assert_eq!(ledger.data()["data"][0]["stock_after"], 7);
+    let elsewhere = app
+        .get("/api/admin/audit/stock-movements?tenant=elsewhere")
+        .admin()
+        .send()
+        .await;
+    assert_eq!(elsewhere.data()["total"], 0);
+
+    let purge = format!("/api/admin/tenants/greenhouse/flowers/{}", id);
+    let purged = app.delete(&purge).admin().send().await;
+    assert_eq!(purged.status, StatusCode::OK);
+    assert_eq!(purged.data()["flower_deleted"], true);
+    assert_eq!(purged.data()["stock_movements"], 2);
+    assert_eq!(purged.data()["flower_changes"], 1);
+
+    let gone = app
+        .get(&format!("/api/flowers/{}", id))
+        .for_tenant("greenhouse")
+        .send()
+        .await;
+    assert_eq!(gone.status, StatusCode::NOT_FOUND);
+    assert_eq!(app.get(&audit).admin().send().await.data()["total"], 0);
+    let again = app.delete(&purge).admin().send().await;
+    assert_eq!(again.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn cache_purges_need_a_scope() {
+    let app = TestApp::spawn().await;
+
+    // Tests run without a cache
+    let flushed = app
+        .post("/api/admin/cache/purge")
+        .admin()
+        .json(json!({ "scope": "all" }))
+        .send()
+        .await;
+    assert_eq!(flushed.status, StatusCode::OK);
+    assert_eq!(flushed.data()["cache_enabled"], false);
+    assert_eq!(flushed.data()["entries_removed"], 0);
+
+    let empty_prefix = app
+        .post("/api/admin/cache/purge")
+        .admin()
+        .json(json!({ "scope": "prefix", "prefix": "" }))
+        .send()
+        .await;
+    assert_eq!(empty_prefix.status, StatusCode::BAD_REQUEST);
+    assert_eq!(empty_prefix.code(), "cache.prefix.empty");
+
+    let unknown_scope = app
+        .post("/api/admin/cache/purge")
+        .admin()
+        .json(json!({ "scope": "everything" }))
+        .send()
+        .await;
+    assert_eq!(unknown_scope.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn reports_connection_pool_usage() {
+    let app = TestApp::spawn().await;
+
+    let pool = app.get("/api/admin/db/pool").admin().send().await;
+    assert_eq!(pool.status, StatusCode::OK);
+    assert!(pool.data()["max_connections"].as_u64().unwrap() > 0);
+    assert!(pool.data()["waits"].is_u64());
+    // The probe only runs in the server
+    assert!(pool.data().get("acquire_latency").is_none());
+}
+
+#[tokio::test]
+async fn reports_usage_per_tenant_and_api_key() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-shop-key-0001=rose-shop")
+        .build()
+        .await;
+
+    let created = app
+        .post("/api/flowers")
+        .api_key("rose-shop-key-0001")
+        .for_tenant("rose-shop")
+        .json(orchid())
+        .send()
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED);
+    let missing = app
+        .get(&format!("/api/flowers/{}", uuid::Uuid::new_v4()))
+        .api_key("rose-shop-key-0001")
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+    app.get("/api/flowers").for_tenant("kiosk").send().await;
+    // Admin calls belong to no tenant
+    app.get("/api/admin/jobs").admin().send().await;
+    assert_eq!(app.usage().flush().await.unwrap(), 3);
+
+    let report = app.get("/api/admin/usage").admin().send().await;
+    assert_eq!(report.status, StatusCode::OK);
+    let totals = &report.data()["totals"];
+    assert_eq!(totals.as_array().unwrap().len(), 2);
+    assert_eq!(totals[0]["tenant_id"], "kiosk");
+    assert_eq!(totals[0]["api_key"], json!(null));
+    assert_eq!(totals[1]["tenant_id"], "rose-shop");
+    assert_eq!(totals[1]["api_key"], "****0001");
+    assert_eq!(totals[1]["requests"], 2);
+    assert_eq!(totals[1]["errors"], 1);
+    assert!(totals[1]["request_bytes"].as_i64().unwrap() > 0);
+    assert!(totals[1]["response_bytes"].as_i64().unwrap() > 0);
+
+    let one_tenant = app
+        .get("/api/admin/usage?tenant=kiosk")
+        .admin()
+        .send()
+        .await;
+    assert_eq!(one_tenant.data()["totals"].as_array().unwrap().len(), 1);
+    let backwards = app
+        .get("/api/admin/usage?from=2025-01-02T00:00:00Z&to=2025-01-01T00:00:00Z")
+        .admin()
+        .send()
+        .await;
+    assert_eq!(backwards.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(backwards.code(), "usage.range.invalid");
+}
+
+#[tokio::test]
+async fn holds_api_keys_to_their_monthly_quota() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-shop-key-0001=rose-shop")
+        .setting("API_KEY_MONTHLY_QUOTA", "2")
+        .build()
+        .await;
+    let list = || {
+        app.get("/api/flowers")
+            .api_key("rose-shop-key-0001")
+            .for_tenant("rose-shop")
+            .send()
+    };
+
+    let first = list().await;
+    assert_eq!(first.status, StatusCode::OK);
+    assert_eq!(first.headers["x-ratelimit-limit"], "2");
+    assert_eq!(first.headers["x-ratelimit-remaining"], "1");
+    assert!(first.headers.contains_key("x-ratelimit-reset"));
+    assert_eq!(list().await.headers["x-ratelimit-remaining"], "0");
+    let exceeded = list().await;
+    assert_eq!(exceeded.status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(exceeded.code(), "quota.exceeded");
+    assert_eq!(exceeded.headers["x-ratelimit-remaining"], "0");
+    assert!(exceeded.headers.contains_key("retry-after"));
+    // Callers without a key are not limited
+    let anonymous = app.get("/api/flowers").for_tenant("rose-shop").send().await;
+    assert_eq!(anonymous.status, StatusCode::OK);
+
+    let quotas = app.get("/api/admin/quotas").admin().send().await;
+    assert_eq!(quotas.status, StatusCode::OK);
+    assert_eq!(quotas.data()[0]["api_key"], "****0001");
+    assert_eq!(quotas.data()[0]["used"], 2);
+    assert_eq!(quotas.data()[0]["custom"], false);
+
+    let raised = app
+        .put("/api/admin/quotas")
+        .admin()
+        .json(json!({ "api_key": "rose-shop-key-0001", "monthly_requests": 5 }))
+        .send()
+        .await;
+    assert_eq!(raised.status, StatusCode::OK);
+    assert_eq!(raised.data()["custom"], true);
+    assert_eq!(raised.data()["remaining"], 3);
+    let after = list().await;
+    assert_eq!(after.status, StatusCode::OK);
+    assert_eq!(after.headers["x-ratelimit-remaining"], "2");
+
+    let unknown = app
+        .put("/api/admin/quotas")
+        .admin()
+        .json(json!({ "api_key": "nobody", "monthly_requests": 5 }))
+        .send()
+        .await;
+    assert_eq!(unknown.status, StatusCode::NOT_FOUND);
+    let tenant_key = app
+        .put("/api/admin/quotas")
+        .api_key("rose-shop-key-0001")
+        .json(json!({ "api_key": "rose-shop-key-0001", "monthly_requests": 1000 }))
+        .send()
+        .await;
+    assert_eq!(tenant_key.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn backups_restore_every_table_including_orders() {
+    let objects = std::env::temp_dir().join(format!("rust-api-backups-{}", uuid::Uuid::new_v4()));
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .setting("SHIPPING_RATE_TABLE", "10000")
+        .setting("OBJECT_STORE_PATH", objects.to_str().unwrap())
+        .build()
+        .await;
+    let tulip = FlowerBuilder::new()
+        .with_tenant("rose-shop")
+        .with_stock(5)
+        .persisted(app.flowers())
+        .await;
+    let order = json!({
+        "lines": [{ "flower_id": tulip.id(), "quantity": 2 }],
+        "shipping": {
+            "destination": { "latitude": -6.2, "longitude": 106.83 },
+            "carrier": "shop",
+            "service": "standard"
+        }
+    });
+    let placed = app
+        .post("/api/orders")
+        .api_key("rose-key")
+        .json(order.clone())
+        .send()
+        .await;
+    assert_eq!(placed.status, StatusCode::CREATED);
+    let uri = format!("/api/orders/{}", placed.data()["id"].as_str().unwrap());
+
+    let backup = app.post("/api/admin/backup").admin().send().await;
+    assert_eq!(backup.status, StatusCode::CREATED);
+    let tables: Vec<_> = backup.data()["tables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|table| table["table"].as_str().unwrap())
+        .collect();
+    assert!(tables.contains(&"orders") && tables.contains(&"order_events"));
+
+    let paid = app
+        .put(&format!("{uri}/status"))
+        .api_key("rose-key")
+        .json(json!({ "status": "paid" }))
+        .send()
+        .await;
+    assert_eq!(paid.status, StatusCode::OK);
+    let later = app
+        .post("/api/orders")
+        .api_key("rose-key")
+        .json(order)
+        .send()
+        .await;
+    assert_eq!(later.status, StatusCode::CREATED);
+
+    let restored = app
+        .post(&format!(
+            "/api/admin/backup/{}/restore",
+            backup.data()["id"].as_str().unwrap()
+        ))
+        .admin()
+        .json(json!({ "confirm": backup.data()["restore_token"] }))
+        .send()
+        .await;
+    std::fs::remove_dir_all(&objects).unwrap();
+    assert_eq!(restored.status, StatusCode::OK);
+
+    let fetched = app.get(&uri).api_key("rose-key").send().await;
+    assert_eq!(fetched.status, StatusCode::OK);
+    assert_eq!(fetched.data()["status"], "pending");
+    let events = app
+        .get(&format!("{uri}/events"))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(events.data().as_array().unwrap().len(), 1);
+    let gone = app
+        .get(&format!(
+            "/api/orders/{}",
+            later.data()["id"].as_str().unwrap()
+        ))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(gone.status, StatusCode::NOT_FOUND);
+    let flower = app
+        .get(&format!("/api/flowers/{}", tulip.id()))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(flower.data()["stock"], 3);
+}
@@ -0,0 +1,331 @@
+//! Integration Test Harness
+//!
+//! `TestApp` runs the full router from `create_router` against its own
+//! migrated PostgreSQL database and sends requests to it in process.
+//!
+//! The database lives in a Postgres container started with testcontainers,
+//! which needs a running Docker daemon. With `TEST_DATABASE_URL` set to a
+//! server instead (e.g. a CI service container), each app creates a fresh
+//! `rust_api_test_*` database there; those are left behind for inspecting
+//! failures.
+//!
+//! Migrations seed a sample catalog into the default tenant; tests wanting
+//! an empty one send their requests `for_tenant` another.
+//!
+//! Tests that only exercise the HTTP layer can run `in_memory` instead,
+//! without any database.
+//!
+//! Tenants' webhooks are recorded rather than sent, so tests never reach
+//! the network; `webhooks_sent` lists them.
+
+#![allow(dead_code)]
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::{
+    Router,
+    body::Body,
+    http::{HeaderMap, Method, Request, StatusCode, header},
+};
+use serde::Serialize;
+use serde_json::Value;
+use testcontainers_modules::postgres::Postgres;
+use testcontainers_modules::testcontainers::{ContainerAsync, ImageExt, runners::AsyncRunner};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use rust_api::api::http::{AppState, create_router};
+use rust_api::application::ports::{
+    FlowerRepository, OutboundWebhook, WebhookReceipt, WebhookSender,
+};
+use rust_api::application::usecases::UsageMetering;
+use rust_api::domain::errors::DomainResult;
+use rust_api::infrastructure::config::{AppConfig, Profile};
+use rust_api::infrastructure::persistance::DatabasePool;
+use rust_api::infrastructure::storage::{MEMORY_SCHEME, Storage};
+use rust_api::infrastructure::webhooks::is_private_url;
+
+pub const ADMIN_TOKEN: &str = "test-admin-token";
+
+/// Matches the server in docker-compose.yml
+const POSTGRES_TAG: &str = "17-alpine";
+
+/// Settings every test app starts from; background work stays off so tests
+/// only see the effects of their own requests, and tests pick their tenant
+/// with `for_tenant`
+const BASE_SETTINGS: [(&str, &str); 6] = [
+    ("JOBS_ENABLED", "false"),
+    ("TASK_WORKERS", "0"),
+    ("CACHE_BACKEND", "none"),
+    ("TENANT_SOURCES", "api_key,subdomain,header"),
+    ("ADMIN_TOKEN", ADMIN_TOKEN),
+    ("API_KEY_FINGERPRINT_SECRET", "test-fingerprint-secret"),
+];
+
+/// The API running against a database of its own
+pub struct TestApp {
+    router: Router,
+    flowers: Arc<dyn FlowerRepository>,
+    usage: Arc<UsageMetering>,
+    webhooks: Arc<RecordingWebhookSender>,
+    _container: Option<ContainerAsync<Postgres>>,
+}
+
+impl TestApp {
+    pub fn builder() -> TestAppBuilder {
+        TestAppBuilder::default()
+    }
+
+    /// App with the default settings
+    pub async fn spawn() -> Self {
+        Self::builder().build().await
+    }
+
+    /// Repository behind the app, for arranging data without requests
+    pub fn flowers(&self) -> &dyn FlowerRepository {
+        self.flowers.as_ref()
+    }
+
+    /// Usage metered by the app; tests flush it themselves, as background
+    /// jobs are off
+    pub fn usage(&self) -> &UsageMetering {
+        self.usage.as_ref()
+    }
+
+    /// Webhooks delivered to tenants' receivers so far, oldest first
+    pub fn webhooks_sent(&self) -> Vec<OutboundWebhook> {
+        self.webhooks.sent.lock().unwrap().clone()
+    }
+
+    pub fn get(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::GET, uri)
+    }
+
+    pub fn post(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::POST, uri)
+    }
+
+    pub fn put(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::PUT, uri)
+    }
+
+    pub fn delete(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::DELETE, uri)
+    }
+
+    pub fn request(&self, method: Method, uri: &str) -> TestRequest<'_> {
+        TestRequest {
+            app: self,
+            request: Request::builder().method(method).uri(uri),
+            body: Body::empty(),
+        }
+    }
+}
+
+/// Settings of a `TestApp` on top of the defaults
+#[derive(Default)]
+pub struct TestAppBuilder {
+    settings: Vec<(String, String)>,
+    in_memory: bool,
+    profile: Option<Profile>,
+}
+
+impl TestAppBuilder {
+    /// Set a configuration value, named like its environment variable
+    pub fn setting(mut self, key: &str, value: &str) -> Self {
+        self.settings.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Use the in-memory storage rather than PostgreSQL
+    pub fn in_memory(mut self) -> Self {
+        self.in_memory = true;
+        self
+    }
+
+    /// Run with the settings and middleware of `profile` rather than
+    /// development
+    pub fn profile(mut self, profile: Profile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    pub async fn build(self) -> TestApp {
+        let (database_url, container) = if self.in_memory {
+            (MEMORY_SCHEME.to_string(), None)
+        } else {
+            database().await
+        };
+
+        let settings = BASE_SETTINGS
+            .into_iter()
+            .chain([("DATABASE_URL", database_url.as_str())])
+            .chain(self.settings.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+        let profile = self.profile.unwrap_or(Profile::Development);
+        let config = AppConfig::from_settings(profile, settings).expect("valid test settings");
+
+        let storage = Storage::connect(&config)
+            .await
+            .expect("connect and migrate test database");
+        let flowers = storage.flowers.clone();
+        let webhooks = Arc::new(RecordingWebhookSender::default());
+        let state = app_state(&config, storage, webhooks.clone()).await;
+        let usage = state.usage.clone();
+
+        TestApp {
+            router: create_router(state, &config),
+            flowers,
+            usage,
+            webhooks,
+            _container: container,
+        }
+    }
+}
+
+/// A request to a `TestApp`, built up before `send`
+pub struct TestRequest<'a> {
+    app: &'a TestApp,
+    request: axum::http::request::Builder,
+    body: Body,
+}
+
+impl TestRequest<'_> {
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.request = self.request.header(name, value);
+        self
+    }
+
+    pub fn admin(self) -> Self {
+        self.header("authorization", &format!("Bearer {}", ADMIN_TOKEN))
+    }
+
+    pub fn api_key(self, key: &str) -> Self {
+        self.header("x-api-key", key)
+    }
+
+    pub fn for_tenant(self, tenant: &str) -> Self {
+        self.header("x-tenant-id", tenant)
+    }
+
+    /// Raw body, for content the `json` helper cannot produce
+    pub fn body(mut self, body: impl Into<Body>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn json(mut self, body: impl Serialize) -> Self {
+        self.request = self
+            .request
+            .header(header::CONTENT_TYPE, "application/json");
+        self.body = Body::from(serde_json::to_vec(&body).expect("serializable test body"));
+        self
+    }
+
+    pub async fn send(self) -> TestResponse {
+        let request = self.request.body(self.body).expect("valid test request");
+        let response = self
+            .app
+            .router
+            .clone()
+            .oneshot(request)
+            .await
+            .expect("router is infallible");
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("read response body");
+        TestResponse {
+            status,
+            headers,
+            body: serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+            text: String::from_utf8_lossy(&bytes).into_owned(),
+        }
+    }
+}
+
+/// Response of a `TestApp`, with its body parsed as JSON (`Null` otherwise)
+/// and as text
+#[derive(Debug)]
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Value,
+    pub text: String,
+}
+
+impl TestResponse {
+    /// `data` of a successful envelope
+    pub fn data(&self) -> &Value {
+        &self.body["data"]
+    }
+
+    /// `code` of an error envelope
+    pub fn code(&self) -> &str {
+        self.body["code"].as_str().unwrap_or_default()
+    }
+}
+
+/// URL of an empty database, and the container serving it if one was started
+async fn database() -> (String, Option<ContainerAsync<Postgres>>) {
+    if let Ok(server_url) = std::env::var("TEST_DATABASE_URL") {
+        let (server, _) = server_url
+            .rsplit_once('/')
+            .expect("TEST_DATABASE_URL names a database");
+        let url = format!("{}/rust_api_test_{}", server, Uuid::new_v4().simple());
+        DatabasePool::create_database(&url)
+            .await
+            .expect("create test database");
+        return (url, None);
+    }
+
+    let container = Postgres::default()
+        .with_tag(POSTGRES_TAG)
+        .start()
+        .await
+        .expect("start Postgres container; is Docker running? Set TEST_DATABASE_URL to use an existing server");
+    let host = container.get_host().await.expect("container host");
+    let port = container
+        .get_host_port_ipv4(5432)
+        .await
+        .expect("container port");
+    (
+        format!("postgres://postgres:postgres@{}:{}/postgres", host, port),
+        Some(container),
+    )
+}
+
+/// Takes every webhook with a 200, judging receivers like the real sender
+/// does before any name is resolved
+#[derive(Default)]
+struct RecordingWebhookSender {
+    sent: Mutex<Vec<OutboundWebhook>>,
+}
+
+#[async_trait]
+impl WebhookSender for RecordingWebhookSender {
+    async fn send(&self, webhook: &OutboundWebhook) -> DomainResult<WebhookReceipt> {
+        self.sent.lock().unwrap().push(webhook.clone());
+        Ok(WebhookReceipt { status: Some(200) })
+    }
+
+    async fn is_private(&self, url: &str) -> bool {
+        is_private_url(url)
+    }
+}
+
+/// State wired like `run_server`, minus caching and background work, and
+/// with webhooks recorded by `webhooks`
+async fn app_state(
+    config: &AppConfig,
+    storage: Storage,
+    webhooks: Arc<RecordingWebhookSender>,
+) -> AppState {
+    AppState::builder(config, &storage)
+        .with_webhook_sender(webhooks)
+        .build()
+        .await
+        .expect("wire app state")
+}
@@ -0,0 +1,100 @@
+//! Delivery zone endpoints end to end
+
+mod common;
+
+use axum::http::StatusCode;
+use serde_json::json;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn addresses_are_checked_against_the_delivery_zones() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .setting("ALLOW_ANONYMOUS_WRITES", "false")
+        .build()
+        .await;
+    let central_jakarta = json!({
+        "name": "Central Jakarta",
+        "boundary": [
+            { "latitude": -6.15, "longitude": 106.80 },
+            { "latitude": -6.15, "longitude": 106.87 },
+            { "latitude": -6.22, "longitude": 106.87 },
+            { "latitude": -6.22, "longitude": 106.80 },
+            { "latitude": -6.15, "longitude": 106.80 }
+        ],
+        "fee": 15000.0
+    });
+
+    let anonymous = app
+        .post("/api/delivery-zones")
+        .for_tenant("rose-shop")
+        .json(central_jakarta.clone())
+        .send()
+        .await;
+    assert_eq!(anonymous.status, StatusCode::UNAUTHORIZED);
+
+    let zone = app
+        .post("/api/delivery-zones")
+        .api_key("rose-key")
+        .json(central_jakarta)
+        .send()
+        .await;
+    assert_eq!(zone.status, StatusCode::CREATED);
+    assert_eq!(zone.data()["boundary"].as_array().unwrap().len(), 4);
+    let zone_id = zone.data()["id"].as_str().unwrap().to_string();
+
+    let line = app
+        .post("/api/delivery-zones")
+        .api_key("rose-key")
+        .json(json!({
+            "name": "Line",
+            "boundary": [
+                { "latitude": -6.1, "longitude": 106.8 },
+                { "latitude": -6.2, "longitude": 106.8 }
+            ],
+            "fee": 0.0
+        }))
+        .send()
+        .await;
+    assert_eq!(line.status, StatusCode::BAD_REQUEST);
+    assert_eq!(line.code(), "delivery_zone.boundary.too_small");
+
+    let menteng = app
+        .get("/api/delivery-zones/check?lat=-6.19&lng=106.83")
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(menteng.status, StatusCode::OK);
+    assert_eq!(menteng.data()["serviceable"], true);
+    assert_eq!(menteng.data()["zone_id"], zone_id.as_str());
+    assert_eq!(menteng.data()["fee"], 15000.0);
+
+    let bogor = app
+        .get("/api/delivery-zones/check?lat=-6.5971&lng=106.806")
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(bogor.data()["serviceable"], false);
+    assert!(bogor.data()["fee"].is_null());
+
+    let nowhere = app
+        .get("/api/delivery-zones/check?lat=-6.19")
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(nowhere.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let deleted = app
+        .delete(&format!("/api/delivery-zones/{zone_id}"))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(deleted.status, StatusCode::NO_CONTENT);
+    let unserved = app
+        .get("/api/delivery-zones/check?lat=-6.19&lng=106.83")
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(unserved.data()["serviceable"], false);
+}
@@ -0,0 +1,540 @@
+//! Flower endpoints end to end
+
+mod common;
+
+use axum::http::{StatusCode, header};
+use rust_api::application::dtos::CreateFlowerRequest;
+use rust_api::domain::shared::Entity;
+use rust_api::test_support::FlowerBuilder;
+use serde_json::json;
+
+use common::TestApp;
+
+/// Not among the flowers seeded by the migrations
+fn peony() -> CreateFlowerRequest {
+    FlowerBuilder::new()
+        .with_name("Peony")
+        .with_color("pink")
+        .with_description("Lush and ruffled")
+        .with_price(40_000.0)
+        .with_stock(10)
+        .create_request()
+}
+
+#[tokio::test]
+async fn creates_reads_updates_and_deletes_a_flower() {
+    let app = TestApp::spawn().await;
+
+    let created = app.post("/api/flowers").json(peony()).send().await;
+    assert_eq!(created.status, StatusCode::CREATED);
+    let id = created.data()["id"].as_str().unwrap().to_string();
+    let uri = format!("/api/flowers/{}", id);
+
+    let fetched = app.get(&uri).send().await;
+    assert_eq!(fetched.status, StatusCode::OK);
+    assert_eq!(fetched.data()["name"], "Peony");
+    assert_eq!(fetched.data()["stock"], 10);
+
+    let updated = app
+        .put(&uri)
+        .json(json!({ "price": 30000.0, "description": null }))
+        .send()
+        .await;
+    assert_eq!(updated.status, StatusCode::OK);
+    assert_eq!(updated.data()["price"], 30000.0);
+    assert_eq!(updated.data()["name"], "Peony");
+
+    let adjusted = app
+        .post(&format!("{}/stock-adjustments", uri))
+        .json(json!({ "delta": -4, "reason": "Sold at the market" }))
+        .send()
+        .await;
+    assert_eq!(adjusted.status, StatusCode::CREATED);
+    assert_eq!(adjusted.data()["stock_after"], 6);
+
+    assert_eq!(app.delete(&uri).send().await.status, StatusCode::NO_CONTENT);
+    let gone = app.get(&uri).send().await;
+    assert_eq!(gone.status, StatusCode::NOT_FOUND);
+    assert_eq!(gone.code(), "flower.not_found");
+}
+
+#[tokio::test]
+async fn paginates_searches_and_filters() {
+    let app = TestApp::spawn().await;
+    for i in 1..=12 {
+        let color = if i % 3 == 0 { "white" } else { "red" };
+        FlowerBuilder::new()
+            .with_tenant("garden")
+            .with_name(format!("Flower {:02}", i))
+            .with_color(color)
+            .persisted(app.flowers())
+            .await;
+    }
+
+    let page = app
+        .get("/api/flowers?page=3&per_page=5")
+        .for_tenant("garden")
+        .send()
+        .await;
+    assert_eq!(page.status, StatusCode::OK);
+    assert_eq!(page.data()["total"], 12);
+    assert_eq!(page.data()["total_pages"], 3);
+    assert_eq!(page.data()["data"].as_array().unwrap().len(), 2);
+    assert_eq!(page.data()["total_estimated"], false);
+    assert_eq!(page.headers["x-total-count"], "12");
+    let links = page.headers[header::LINK].to_str().unwrap();
+    assert!(links.contains("</api/flowers?per_page=5&page=1>; rel=\"first\""));
+    assert!(links.contains("</api/flowers?per_page=5&page=2>; rel=\"prev\""));
+    assert!(links.contains("</api/flowers?per_page=5&page=3>; rel=\"last\""));
+    assert!(!links.contains("rel=\"next\""));
+
+    // Too few flowers for an estimate to be worth it
+    let estimated = app
+        .get("/api/flowers?estimate=true&color=white")
+        .for_tenant("garden")
+        .send()
+        .await;
+    assert_eq!(estimated.status, StatusCode::OK);
+    assert_eq!(estimated.data()["total"], 4);
+    assert_eq!(estimated.data()["total_estimated"], false);
+
+    let search = app
+        .get("/api/flowers?search=flower%2001")
+        .for_tenant("garden")
+        .send()
+        .await;
+    let names: Vec<_> = search.data()["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|flower| flower["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["Flower 01"]);
+    assert_eq!(
+        search.data()["data"][0]["highlight"]["name"],
+        "<em>Flower 01</em>"
+    );
+    assert!(page.data()["data"][0].get("highlight").is_none());
+
+    let white = app
+        .get("/api/flowers?color=white&per_page=100")
+        .for_tenant("garden")
+        .send()
+        .await;
+    assert_eq!(white.data()["total"], 4);
+    assert!(
+        white.data()["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|flower| flower["color"] == "white")
+    );
+}
+
+#[tokio::test]
+async fn filters_by_attributes() {
+    let app = TestApp::spawn().await;
+    let lily = app
+        .post("/api/flowers")
+        .for_tenant("garden")
+        .json(json!({
+            "name": "Stargazer Lily",
+            "color": "pink",
+            "price": 35000.0,
+            "stock": 8,
+            "attributes": { "fragrance": "high", "stem_length_cm": 70, "vase_life_days": 10 }
+        }))
+        .send()
+        .await;
+    assert_eq!(lily.status, StatusCode::CREATED);
+    assert_eq!(lily.data()["attributes"]["stem_length_cm"], 70);
+    FlowerBuilder::new()
+        .with_tenant("garden")
+        .with_name("Tulip")
+        .persisted(app.flowers())
+        .await;
+
+    let fragrant = app
+        .get("/api/flowers?attr.fragrance=high")
+        .for_tenant("garden")
+        .send()
+        .await;
+    assert_eq!(fragrant.status, StatusCode::OK);
+    assert_eq!(fragrant.data()["total"], 1);
+    assert_eq!(fragrant.data()["data"][0]["name"], "Stargazer Lily");
+
+    let short = app
+        .get("/api/flowers?attr.fragrance=high&attr.stem_length_cm=40")
+        .for_tenant("garden")
+        .send()
+        .await;
+    assert_eq!(short.data()["total"], 0);
+
+    let unknown = app
+        .get("/api/flowers?attr.fragrance=overpowering")
+        .for_tenant("garden")
+        .send()
+        .await;
+    assert_eq!(unknown.status, StatusCode::BAD_REQUEST);
+    assert_eq!(unknown.code(), "flower.attributes.invalid");
+
+    let uri = format!("/api/flowers/{}", lily.data()["id"].as_str().unwrap());
+    let cleared = app
+        .put(&uri)
+        .for_tenant("garden")
+        .json(json!({ "attributes": { "vase_life_days": 12 } }))
+        .send()
+        .await;
+    assert_eq!(cleared.status, StatusCode::OK);
+    assert_eq!(
+        cleared.data()["attributes"],
+        json!({ "vase_life_days": 12 })
+    );
+
+    let out_of_range = app
+        .put(&uri)
+        .for_tenant("garden")
+        .json(json!({ "attributes": { "vase_life_days": 365 } }))
+        .send()
+        .await;
+    assert_eq!(out_of_range.status, StatusCode::BAD_REQUEST);
+    assert_eq!(out_of_range.code(), "flower.attributes.out_of_range");
+}
+
+#[tokio::test]
+async fn keeps_integrator_metadata() {
+    let app = TestApp::spawn().await;
+    let peony = app
+        .post("/api/flowers")
+        .for_tenant("garden")
+        .json(json!({
+            "name": "Peony",
+            "color": "pink",
+            "price": 40000.0,
+            "stock": 4,
+            "metadata": { "erp.item_id": "ITM-00042", "erp.warehouse": "JKT-1" }
+        }))
+        .send()
+        .await;
+    assert_eq!(peony.status, StatusCode::CREATED);
+    assert_eq!(peony.data()["metadata"]["erp.item_id"], "ITM-00042");
+    FlowerBuilder::new()
+        .with_tenant("garden")
+        .with_metadata("erp.item_id", "ITM-00043")
+        .persisted(app.flowers())
+        .await;
+
+    let found = app
+        .get("/api/flowers?metadata=erp.item_id:ITM-00042")
+        .for_tenant("garden")
+        .send()
+        .await;
+    assert_eq!(found.status, StatusCode::OK);
+    assert_eq!(found.data()["total"], 1);
+    assert_eq!(found.data()["data"][0]["name"], "Peony");
+
+    let malformed = app
+        .get("/api/flowers?metadata=item_id")
+        .for_tenant("garden")
+        .send()
+        .await;
+    assert_eq!(malformed.status, StatusCode::BAD_REQUEST);
+    assert_eq!(malformed.code(), "flower.metadata.filter_invalid");
+
+    let unnamespaced = app
+        .put(&format!(
+            "/api/flowers/{}",
+            peony.data()["id"].as_str().unwrap()
+        ))
+        .for_tenant("garden")
+        .json(json!({ "metadata": { "item_id": "ITM-00042" } }))
+        .send()
+        .await;
+    assert_eq!(unnamespaced.status, StatusCode::BAD_REQUEST);
+    assert_eq!(unnamespaced.code(), "flower.metadata.key_invalid");
+}
+
+#[tokio::test]
+async fn formats_prices_for_the_requested_language() {
+    let app = TestApp::spawn().await;
+    let orchid = FlowerBuilder::new()
+        .with_price(1_250_000.0)
+        .persisted(app.flowers())
+        .await;
+    let uri = format!("/api/flowers/{}", orchid.id());
+
+    let english = app.get(&uri).send().await;
+    assert_eq!(
+        english.data()["price_display"],
+        json!({ "currency": "IDR", "symbol": "Rp", "decimals": 0, "formatted": "Rp1,250,000" })
+    );
+
+    let indonesian = app
+        .get(&uri)
+        .header("accept-language", "id-ID")
+        .send()
+        .await;
+    assert_eq!(
+        indonesian.data()["base_price_display"]["formatted"],
+        "Rp1.250.000"
+    );
+}
+
+#[tokio::test]
+async fn rejects_invalid_requests() {
+    let app = TestApp::spawn().await;
+    let created = app.post("/api/flowers").json(peony()).send().await;
+    let id = created.data()["id"].clone();
+
+    let duplicate = app.post("/api/flowers").json(peony()).send().await;
+    assert_eq!(duplicate.status, StatusCode::CONFLICT);
+    assert_eq!(duplicate.body["existing_id"], id);
+
+    let invalid = app
+        .post("/api/flowers")
+        .json(
+            FlowerBuilder::new()
+                .with_name("R")
+                .with_price(-1.0)
+                .create_request(),
+        )
+        .send()
+        .await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST);
+
+    let unknown_color = app.get("/api/flowers?color=green").send().await;
+    assert_eq!(unknown_color.status, StatusCode::BAD_REQUEST);
+
+    let bad_page = app.get("/api/flowers?page=0").send().await;
+    assert_eq!(bad_page.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let missing = app
+        .get("/api/flowers/01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a60")
+        .header("x-request-id", "trace-me")
+        .send()
+        .await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+    assert_eq!(missing.body["request_id"], "trace-me");
+    assert_eq!(missing.headers["x-request-id"], "trace-me");
+
+    let oversold = app
+        .post(&format!(
+            "/api/flowers/{}/stock-adjustments",
+            id.as_str().unwrap()
+        ))
+        .json(json!({ "delta": -11, "reason": "Too many" }))
+        .send()
+        .await;
+    assert_eq!(oversold.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn reads_are_cacheable_and_writes_are_not() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .setting("HTTP_CACHE_LIST_TTL_SECS", "15")
+        .build()
+        .await;
+    let cache_control = |response: &common::TestResponse| {
+        response.headers[header::CACHE_CONTROL]
+            .to_str()
+            .unwrap()
+            .to_string()
+    };
+
+    let created = app.post("/api/flowers").json(peony()).send().await;
+    assert_eq!(cache_control(&created), "no-store");
+    let uri = format!("/api/flowers/{}", created.data()["id"].as_str().unwrap());
+
+    let listed = app.get("/api/flowers").send().await;
+    assert_eq!(cache_control(&listed), "public, max-age=15");
+    let vary: Vec<_> = listed.headers.get_all(header::VARY).iter().collect();
+    assert!(vary.contains(&&header::HeaderValue::from_static("x-tenant-id")));
+    assert!(vary.contains(&&header::HeaderValue::from_static("accept-language")));
+    let own = app.get("/api/flowers").api_key("rose-key").send().await;
+    assert_eq!(cache_control(&own), "private, max-age=15");
+
+    let fetched = app.get(&uri).send().await;
+    assert_eq!(cache_control(&fetched), "public, max-age=60");
+    let etag = fetched.headers[header::ETAG].to_str().unwrap();
+    let revalidated = app.get(&uri).header("if-none-match", etag).send().await;
+    assert_eq!(revalidated.status, StatusCode::NOT_MODIFIED);
+    assert_eq!(cache_control(&revalidated), "public, max-age=60");
+    let stale = app
+        .get(&uri)
+        .header("if-none-match", "\"0000000000000000\"")
+        .send()
+        .await;
+    assert_eq!(stale.status, StatusCode::OK);
+
+    let missing = app
+        .get("/api/flowers/01939a4e-6f1c-7b5e-9a3d-2f4e8c1b7a60")
+        .send()
+        .await;
+    assert_eq!(cache_control(&missing), "no-store");
+}
+
+#[tokio::test]
+async fn offers_the_colors_and_prices_in_the_catalog() {
+    let app = TestApp::spawn().await;
+
+    let empty = app
+        .get("/api/flowers/filters")
+        .for_tenant("florist")
+        .send()
+        .await;
+    assert_eq!(empty.status, StatusCode::OK);
+    assert_eq!(
+        empty.data(),
+        &json!({ "colors": [], "min_price": null, "max_price": null })
+    );
+
+    let rose = FlowerBuilder::new()
+        .with_name("Rose")
+        .with_color("red")
+        .with_price(25_000.0)
+        .create_request();
+    for flower in [peony(), rose] {
+        app.post("/api/flowers")
+            .for_tenant("florist")
+            .json(flower)
+            .send()
+            .await;
+    }
+
+    let filters = app
+        .get("/api/flowers/filters")
+        .for_tenant("florist")
+        .send()
+        .await;
+    assert_eq!(
+        filters.data(),
+        &json!({ "colors": ["red", "pink"], "min_price": 25000.0, "max_price": 40000.0 })
+    );
+}
+
+#[tokio::test]
+async fn keeps_the_history_of_each_flower() {
+    let app = TestApp::spawn().await;
+    let created = app.post("/api/flowers").json(peony()).send().await;
+    let uri = format!("/api/flowers/{}", created.data()["id"].as_str().unwrap());
+    let history = format!("{}/history", uri);
+
+    let empty = app.get(&history).send().await;
+    assert_eq!(empty.status, StatusCode::OK);
+    assert_eq!(empty.data()["total"], 0);
+
+    app.put(&uri).json(json!({ "price": 30000.0 })).send().await;
+    app.post(&format!("{}/stock-adjustments", uri))
+        .admin()
+        .json(json!({ "delta": 5, "reason": "Delivery" }))
+        .send()
+        .await;
+    // Nothing changes, nothing is recorded
+    app.put(&uri).json(json!({ "price": 30000.0 })).send().await;
+
+    let listed = app.get(&history).send().await;
+    assert_eq!(listed.status, StatusCode::OK);
+    assert_eq!(listed.headers["x-total-count"], "2");
+    let changes = listed.data()["data"].as_array().unwrap();
+    assert_eq!(changes[0]["field"], "price");
+    assert_eq!(changes[0]["old_value"], 40000.0);
+    assert_eq!(changes[0]["new_value"], 30000.0);
+    assert_eq!(changes[0]["actor"], "anonymous");
+    assert_eq!(changes[1]["field"], "stock");
+    assert_eq!(changes[1]["old_value"], 10);
+    assert_eq!(changes[1]["new_value"], 15);
+    assert_eq!(changes[1]["actor"], "admin");
+
+    // The history outlives the flower
+    app.delete(&uri).send().await;
+    assert_eq!(app.get(&history).send().await.data()["total"], 2);
+    let unknown = app
+        .get("/api/flowers/00000000-0000-0000-0000-000000000000/history")
+        .send()
+        .await;
+    assert_eq!(unknown.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn api_keys_confine_callers_to_their_tenant() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop,tulip-key=tulip-shop")
+        .setting("ALLOW_ANONYMOUS_WRITES", "false")
+        .build()
+        .await;
+
+    let anonymous = app.post("/api/flowers").json(peony()).send().await;
+    assert_eq!(anonymous.status, StatusCode::UNAUTHORIZED);
+
+    let created = app
+        .post("/api/flowers")
+        .api_key("rose-key")
+        .json(peony())
+        .send()
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED);
+    let uri = format!("/api/flowers/{}", created.data()["id"].as_str().unwrap());
+
+    let own = app.get(&uri).api_key("rose-key").send().await;
+    assert_eq!(own.status, StatusCode::OK);
+    let other = app.get(&uri).api_key("tulip-key").send().await;
+    assert_eq!(other.status, StatusCode::NOT_FOUND);
+    let listed = app.get("/api/flowers").api_key("tulip-key").send().await;
+    assert_eq!(listed.data()["total"], 0);
+
+    let unknown = app.get("/api/flowers").api_key("nope").send().await;
+    assert_eq!(unknown.status, StatusCode::UNAUTHORIZED);
+
+    let admin_only = app
+        .get("/api/admin/feature-flags")
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(admin_only.status, StatusCode::FORBIDDEN);
+    let admin = app.get("/api/admin/feature-flags").admin().send().await;
+    assert_eq!(admin.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn refuses_tenant_headers_it_does_not_trust() {
+    let app = TestApp::builder()
+        .setting("TENANT_SOURCES", "api_key,subdomain")
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .build()
+        .await;
+    let created = app
+        .post("/api/flowers")
+        .api_key("rose-key")
+        .json(peony())
+        .send()
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED);
+    let uri = format!("/api/flowers/{}", created.data()["id"].as_str().unwrap());
+
+    let read = app.get(&uri).for_tenant("rose-shop").send().await;
+    assert_eq!(read.status, StatusCode::FORBIDDEN);
+    assert_eq!(read.code(), "tenant.header.untrusted");
+    let write = app
+        .put(&uri)
+        .for_tenant("rose-shop")
+        .json(json!({ "stock": 0 }))
+        .send()
+        .await;
+    assert_eq!(write.status, StatusCode::FORBIDDEN);
+    // Naming the tenant the caller already has is fine
+    let own = app
+        .get(&uri)
+        .api_key("rose-key")
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(own.status, StatusCode::OK);
+    let elsewhere = app
+        .get(&uri)
+        .api_key("rose-key")
+        .for_tenant("default")
+        .send()
+        .await;
+    assert_eq!(elsewhere.status, StatusCode::FORBIDDEN);
+}
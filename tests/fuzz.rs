@@ -0,0 +1,239 @@
+//! Randomized input against the request extractors
+//!
+//! Random bytes, arbitrary JSON and garbled paths and query strings are
+//! sent through the full router. Every one must be answered without a
+//! panic or a server error. Runs on the in-memory storage, so only the HTTP
+//! layer and the use cases are exercised.
+
+mod common;
+
+use std::fmt::Debug;
+
+use axum::http::{Method, StatusCode};
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestRunner};
+use serde_json::{Value, json};
+
+use common::{TestApp, TestResponse};
+use rust_api::domain::shared::Entity;
+use rust_api::test_support::FlowerBuilder;
+
+/// Send `request` for inputs drawn from `strategy`, failing on panics and
+/// server errors
+///
+/// The app holds a single flower, whose URI `request` is given along with
+/// the input, so requests also reach the use cases behind the extractors.
+fn fuzz<S>(strategy: S, request: impl AsyncFn(&TestApp, &str, S::Value) -> TestResponse)
+where
+    S: Strategy,
+    S::Value: Debug,
+{
+    let runtime = tokio::runtime::Runtime::new().expect("tokio runtime");
+    let (app, flower) = runtime.block_on(async {
+        let app = TestApp::builder().in_memory().build().await;
+        let flower = FlowerBuilder::new()
+            .with_sku("FUZZ-1")
+            .persisted(app.flowers())
+            .await;
+        (app, format!("/api/flowers/{}", flower.id()))
+    });
+    let mut runner = TestRunner::new(Config {
+        cases: 256,
+        // Failures are reported with their minimal input instead
+        failure_persistence: None,
+        ..Config::default()
+    });
+
+    runner
+        .run(&strategy, |input| {
+            let response = runtime.block_on(request(&app, &flower, input));
+            prop_assert!(
+                !response.status.is_server_error(),
+                "answered {}: {}",
+                response.status,
+                response.body
+            );
+            Ok(())
+        })
+        .unwrap();
+}
+
+/// Endpoints taking a JSON body, as method, path below the flower routes
+/// and whether the path is relative to the flower
+const JSON_ENDPOINTS: [(&str, &str, bool); 4] = [
+    ("POST", "", false),
+    ("PUT", "", true),
+    ("POST", "/price-adjustments", false),
+    ("POST", "/stock-adjustments", true),
+];
+
+fn json_endpoint() -> impl Strategy<Value = (Method, &'static str, bool)> {
+    prop::sample::select(JSON_ENDPOINTS.to_vec())
+        .prop_map(|(method, path, of_flower)| (method.parse().unwrap(), path, of_flower))
+}
+
+fn endpoint_uri(flower: &str, path: &str, of_flower: bool) -> String {
+    let base = if of_flower { flower } else { "/api/flowers" };
+    format!("{}{}", base, path)
+}
+
+fn any_json() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::from),
+        any::<i64>().prop_map(Value::from),
+        any::<f64>().prop_map(Value::from),
+        ".{0,40}".prop_map(Value::from),
+    ];
+    leaf.prop_recursive(4, 32, 6, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..6).prop_map(Value::from),
+            prop::collection::btree_map(
+                prop_oneof![
+                    prop::sample::select(vec![
+                        "name",
+                        "color",
+                        "description",
+                        "price",
+                        "stock",
+                        "sku",
+                        "delta",
+                        "reason",
+                        "filter",
+                        "ids",
+                        "adjustment",
+                        "dry_run",
+                    ])
+                    .prop_map(str::to_string),
+                    ".{0,10}",
+                ],
+                inner,
+                0..8,
+            )
+            .prop_map(|fields| Value::Object(fields.into_iter().collect())),
+        ]
+    })
+}
+
+/// Characters allowed in a URI, `%` included to garble percent-encoding
+fn uri_text(max: usize) -> impl Strategy<Value = String> {
+    proptest::string::string_regex(&format!("[A-Za-z0-9%&=+,;:@._~!$'()*-]{{0,{}}}", max)).unwrap()
+}
+
+#[test]
+fn random_bytes_as_json_bodies() {
+    fuzz(
+        (json_endpoint(), prop::collection::vec(any::<u8>(), 0..512)),
+        async |app, flower, ((method, path, of_flower), bytes)| {
+            app.request(method, &endpoint_uri(flower, path, of_flower))
+                .header("content-type", "application/json")
+                .body(bytes)
+                .send()
+                .await
+        },
+    );
+}
+
+#[test]
+fn arbitrary_json_bodies() {
+    fuzz(
+        (json_endpoint(), any_json()),
+        async |app, flower, ((method, path, of_flower), body)| {
+            app.request(method, &endpoint_uri(flower, path, of_flower))
+                .json(body)
+                .send()
+                .await
+        },
+    );
+}
+
+#[test]
+fn near_valid_flowers() {
+    let fields = (
+        any::<bool>(),
+        prop_oneof![".{0,120}", any::<String>()],
+        prop_oneof![
+            prop::sample::select(vec!["red", " Pink ", "WHITE", "yellow"]).prop_map(str::to_string),
+            ".{0,12}",
+        ],
+        prop::option::of(prop_oneof![
+            ".{0,600}",
+            "(<[a-z]{1,6}>|[a-z ]{0,8}|</[a-z]{1,6}>){0,20}"
+        ]),
+        prop_oneof![0.0..2e9, any::<f64>()],
+        prop_oneof![-10..2_000_000i64, any::<i64>()],
+        prop::option::of(prop_oneof!["[A-Za-z0-9/._-]{0,40}", ".{0,40}"]),
+    );
+    fuzz(
+        fields,
+        async |app, flower, (update, name, color, description, price, stock, sku)| {
+            let body = json!({
+                "name": name,
+                "color": color,
+                "description": description,
+                "price": price,
+                "stock": stock,
+                "sku": sku,
+            });
+            if update {
+                app.put(flower).json(body).send().await
+            } else {
+                app.post("/api/flowers").json(body).send().await
+            }
+        },
+    );
+}
+
+#[test]
+fn garbled_query_strings() {
+    let paths = prop::sample::select(vec!["/api/flowers", "/api/flowers/trending"]);
+    let key = prop_oneof![
+        prop::sample::select(vec![
+            "page", "per_page", "search", "color", "window", "limit",
+        ])
+        .prop_map(str::to_string),
+        uri_text(8),
+    ];
+    let pairs = prop::collection::vec((key, uri_text(24)), 0..5);
+    fuzz((paths, pairs), async |app, _, (path, pairs)| {
+        let query: Vec<_> = pairs
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        app.get(&format!("{}?{}", path, query.join("&")))
+            .send()
+            .await
+    });
+}
+
+#[test]
+fn garbled_path_parameters() {
+    let suffixes = prop::sample::select(vec!["", "/stock-adjustments", "/qr.png", "/barcode.png"]);
+    fuzz((uri_text(48), suffixes), async |app, _, (id, suffix)| {
+        let method = if suffix == "/stock-adjustments" {
+            Method::POST
+        } else {
+            Method::GET
+        };
+        app.request(method, &format!("/api/flowers/{}{}", id, suffix))
+            .json(json!({ "delta": 1, "reason": "Fuzzing" }))
+            .send()
+            .await
+    });
+}
+
+#[tokio::test]
+async fn deeply_nested_json_is_rejected() {
+    let app = TestApp::builder().in_memory().build().await;
+    let depth = 100_000;
+    let body = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+    let response = app
+        .post("/api/flowers")
+        .header("content-type", "application/json")
+        .body(body)
+        .send()
+        .await;
+    assert!(response.status.is_client_error(), "{}", response.status);
+    assert_ne!(response.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,76 @@
+//! Readiness of the service and the components it depends on
+
+mod common;
+
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+
+use common::TestApp;
+
+fn statuses(components: &Value) -> Vec<(&str, &str)> {
+    components
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|component| {
+            (
+                component["name"].as_str().unwrap(),
+                component["status"].as_str().unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn readiness_lists_every_registered_component() {
+    let app = TestApp::spawn().await;
+
+    let ready = app.get("/health/ready").send().await;
+
+    assert_eq!(ready.status, StatusCode::OK);
+    assert_eq!(ready.body["status"], "ready");
+    assert_eq!(
+        statuses(&ready.body["components"]),
+        [("database", "up"), ("migrations", "up"), ("pool", "up")]
+    );
+    let components = &ready.body["components"];
+    for component in components.as_array().unwrap() {
+        assert!(component["latency_ms"].is_u64());
+    }
+    assert_eq!(components[1]["details"], json!({ "pending": 0 }));
+    let pool = &components[2]["details"];
+    assert!(pool["size"].is_u64());
+    assert!(pool["idle"].is_u64());
+    assert!(pool["max_connections"].is_u64());
+}
+
+#[tokio::test]
+async fn a_broken_optional_component_only_degrades_the_service() {
+    // A file where the object store expects a directory cannot be written to
+    let blocked = std::env::temp_dir().join(format!("rust-api-health-{}", std::process::id()));
+    std::fs::write(&blocked, b"not a directory").unwrap();
+    let app = TestApp::builder()
+        .in_memory()
+        .setting("OBJECT_STORE_PATH", blocked.to_str().unwrap())
+        .build()
+        .await;
+
+    let ready = app.get("/health/ready").send().await;
+    std::fs::remove_file(&blocked).unwrap();
+
+    assert_eq!(ready.status, StatusCode::OK);
+    assert_eq!(ready.body["status"], "degraded");
+    assert_eq!(
+        statuses(&ready.body["components"]),
+        [("storage", "up"), ("object_store", "down")]
+    );
+    assert_eq!(
+        ready.body["components"][0]["details"],
+        json!({ "backend": "memory" })
+    );
+    // Why it is down is only logged: the probe is public
+    let object_store = &ready.body["components"][1];
+    assert!(object_store["latency_ms"].is_u64());
+    assert!(object_store.get("details").is_none());
+    assert_eq!(object_store.as_object().unwrap().len(), 3);
+}
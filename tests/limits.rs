@@ -0,0 +1,92 @@
+//! Request body limits and payload size metrics end to end
+
+mod common;
+
+use axum::http::StatusCode;
+use rust_api::test_support::FlowerBuilder;
+use serde_json::json;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn routes_can_have_their_own_body_limit() {
+    let app = TestApp::builder()
+        .in_memory()
+        .setting("MAX_BODY_SIZE_BYTES", "4096")
+        .setting("ROUTE_BODY_SIZES_BYTES", "/api/flowers/{id}=64")
+        .build()
+        .await;
+    let description = "Lush and ruffled ".repeat(10);
+
+    let created = app
+        .post("/api/flowers")
+        .json(
+            FlowerBuilder::new()
+                .with_description(&description)
+                .create_request(),
+        )
+        .send()
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED);
+    let uri = format!("/api/flowers/{}", created.data()["id"].as_str().unwrap());
+
+    let too_large = app
+        .put(&uri)
+        .json(json!({ "description": description }))
+        .send()
+        .await;
+    assert_eq!(too_large.status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(too_large.code(), "error.payload_too_large");
+    assert!(
+        too_large.body["error"]
+            .as_str()
+            .unwrap()
+            .contains("64 bytes"),
+        "{}",
+        too_large.body
+    );
+
+    let small = app.put(&uri).json(json!({ "stock": 3 })).send().await;
+    assert_eq!(small.status, StatusCode::OK);
+
+    let default_limit = app
+        .post("/api/flowers")
+        .json(
+            FlowerBuilder::new()
+                .with_description("x".repeat(5000))
+                .create_request(),
+        )
+        .send()
+        .await;
+    assert_eq!(default_limit.status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert!(
+        default_limit.body["error"]
+            .as_str()
+            .unwrap()
+            .contains("4096")
+    );
+}
+
+#[tokio::test]
+async fn payload_sizes_are_recorded_per_route() {
+    let app = TestApp::builder().in_memory().build().await;
+    let created = app
+        .post("/api/flowers")
+        .json(FlowerBuilder::new().create_request())
+        .send()
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED);
+
+    let metrics = app.get("/metrics").send().await;
+    for series in [
+        r#"http_request_size_bytes_count{method="POST",route="/api/flowers"}"#,
+        r#"http_response_size_bytes_count{method="POST",route="/api/flowers"}"#,
+    ] {
+        assert!(
+            metrics.text.contains(series),
+            "{} missing from\n{}",
+            series,
+            metrics.text
+        );
+    }
+}
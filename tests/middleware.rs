@@ -0,0 +1,41 @@
+//! Middleware stack presets end to end
+
+mod common;
+
+use axum::http::{StatusCode, header};
+use rust_api::infrastructure::config::Profile;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn only_deployed_profiles_compress_responses() {
+    for (profile, encoding) in [
+        (Profile::Development, None),
+        (Profile::Staging, Some("gzip")),
+    ] {
+        let app = TestApp::builder()
+            .in_memory()
+            .profile(profile)
+            .build()
+            .await;
+
+        let document = app
+            .get("/openapi.json")
+            .header("accept-encoding", "gzip")
+            .header("origin", "https://shop.example")
+            .send()
+            .await;
+
+        assert_eq!(document.status, StatusCode::OK, "{}", profile);
+        assert_eq!(
+            document
+                .headers
+                .get(header::CONTENT_ENCODING)
+                .and_then(|value| value.to_str().ok()),
+            encoding,
+            "{}",
+            profile
+        );
+        assert_eq!(document.headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+    }
+}
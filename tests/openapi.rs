@@ -0,0 +1,15 @@
+//! OpenAPI contract snapshot
+//!
+//! Fails when the document clients are generated from changes. Review the
+//! difference with `cargo insta review` and commit the updated snapshot
+//! along with the change, noting any breaking change in the PR.
+
+use rust_api::api::http::openapi::ApiDoc;
+use utoipa::OpenApi;
+
+#[test]
+fn openapi_document_matches_snapshot() {
+    insta::assert_json_snapshot!("openapi", ApiDoc::openapi(), {
+        ".info.version" => "[version]",
+    });
+}
@@ -0,0 +1,243 @@
+//! Order endpoints end to end
+
+mod common;
+
+use axum::http::StatusCode;
+use rust_api::domain::shared::Entity;
+use rust_api::test_support::FlowerBuilder;
+use serde_json::json;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn orders_reserve_stock_and_move_through_fulfilment() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .setting("SHIPPING_RATE_TABLE", "10000")
+        .build()
+        .await;
+    let tulip = FlowerBuilder::new()
+        .with_tenant("rose-shop")
+        .with_name("Tulip")
+        .with_price(20_000.0)
+        .with_stock(5)
+        .persisted(app.flowers())
+        .await;
+    let order = json!({
+        "lines": [{ "flower_id": tulip.id(), "quantity": 3 }],
+        "shipping": {
+            "destination": { "latitude": -6.2, "longitude": 106.83 },
+            "carrier": "shop",
+            "service": "standard"
+        }
+    });
+
+    let anonymous = app
+        .post("/api/orders")
+        .for_tenant("rose-shop")
+        .json(order.clone())
+        .send()
+        .await;
+    assert_eq!(anonymous.status, StatusCode::UNAUTHORIZED);
+
+    let placed = app
+        .post("/api/orders")
+        .api_key("rose-key")
+        .json(order.clone())
+        .send()
+        .await;
+    assert_eq!(placed.status, StatusCode::CREATED);
+    assert_eq!(placed.data()["status"], "pending");
+    assert_eq!(placed.data()["subtotal"], 60000.0);
+    assert_eq!(placed.data()["total"], 70000.0);
+    let uri = format!("/api/orders/{}", placed.data()["id"].as_str().unwrap());
+
+    let flower = app
+        .get(&format!("/api/flowers/{}", tulip.id()))
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(flower.data()["stock"], 2);
+
+    let oversold = app
+        .post("/api/orders")
+        .api_key("rose-key")
+        .json(order)
+        .send()
+        .await;
+    assert_eq!(oversold.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(oversold.code(), "flower.stock.insufficient");
+
+    let skipped = app
+        .put(&format!("{uri}/status"))
+        .api_key("rose-key")
+        .json(json!({ "status": "shipped" }))
+        .send()
+        .await;
+    assert_eq!(skipped.status, StatusCode::CONFLICT);
+    assert_eq!(skipped.code(), "order.status.invalid_transition");
+
+    for status in ["paid", "packed", "shipped", "delivered"] {
+        let advanced = app
+            .put(&format!("{uri}/status"))
+            .api_key("rose-key")
+            .json(json!({ "status": status }))
+            .send()
+            .await;
+        assert_eq!(advanced.status, StatusCode::OK);
+        assert_eq!(advanced.data()["status"], status);
+    }
+
+    let fetched = app.get(&uri).api_key("rose-key").send().await;
+    assert_eq!(fetched.status, StatusCode::OK);
+    assert!(fetched.data()["delivered_at"].is_string());
+    assert!(fetched.data()["cancelled_at"].is_null());
+
+    let events = app
+        .get(&format!("{uri}/events"))
+        .api_key("rose-key")
+        .send()
+        .await;
+    let steps: Vec<_> = events
+        .data()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|event| event["to"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(steps, ["pending", "paid", "packed", "shipped", "delivered"]);
+    assert_eq!(events.data()[1]["actor"], "tenant:rose-shop");
+
+    let reopened = app
+        .put(&format!("{uri}/status"))
+        .api_key("rose-key")
+        .json(json!({ "status": "pending" }))
+        .send()
+        .await;
+    assert_eq!(reopened.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn cancelling_returns_stock_and_refunds_less_the_restocking_fee() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .setting("RESTOCKING_FEE_PERCENT", "10")
+        .build()
+        .await;
+    let tulip = FlowerBuilder::new()
+        .with_tenant("rose-shop")
+        .with_price(20_000.0)
+        .with_stock(5)
+        .persisted(app.flowers())
+        .await;
+    let order = json!({ "lines": [{ "flower_id": tulip.id(), "quantity": 3 }] });
+    let stock = || async {
+        let flower = app
+            .get(&format!("/api/flowers/{}", tulip.id()))
+            .for_tenant("rose-shop")
+            .send()
+            .await;
+        flower.data()["stock"].clone()
+    };
+
+    let placed = app
+        .post("/api/orders")
+        .api_key("rose-key")
+        .json(order)
+        .send()
+        .await;
+    let uri = format!("/api/orders/{}", placed.data()["id"].as_str().unwrap());
+    assert_eq!(stock().await, 2);
+
+    let paid = app
+        .put(&format!("{uri}/status"))
+        .api_key("rose-key")
+        .json(json!({ "status": "paid" }))
+        .send()
+        .await;
+    assert_eq!(paid.status, StatusCode::OK);
+
+    let cancelled = app
+        .post(&format!("{uri}/cancel"))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(cancelled.status, StatusCode::OK);
+    assert_eq!(cancelled.data()["status"], "cancelled");
+    assert_eq!(cancelled.data()["refund"]["amount"], 54000.0);
+    assert_eq!(cancelled.data()["refund"]["restocking_fee"], 6000.0);
+    assert_eq!(stock().await, 5);
+
+    let audit = format!("/api/admin/audit/stock-movements?flower_id={}", tulip.id());
+    let ledger = app.get(&audit).admin().send().await;
+    let reasons: Vec<_> = ledger.data()["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|movement| movement["reason"].as_str().unwrap().to_string())
+        .collect();
+    assert!(reasons.iter().any(|reason| reason.ends_with("cancelled")));
+
+    let fetched = app.get(&uri).api_key("rose-key").send().await;
+    assert!(fetched.data()["refund"]["reference"].is_string());
+
+    let again = app
+        .post(&format!("{uri}/cancel"))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(again.status, StatusCode::CONFLICT);
+    assert_eq!(stock().await, 5);
+}
+
+#[tokio::test]
+async fn dozens_take_their_stems_from_stock() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .build()
+        .await;
+    let roses = app
+        .post("/api/flowers")
+        .for_tenant("rose-shop")
+        .json(json!({
+            "name": "Rose",
+            "color": "red",
+            "price": 180000.0,
+            "stock": 30,
+            "unit": "dozen"
+        }))
+        .send()
+        .await;
+    assert_eq!(roses.status, StatusCode::CREATED);
+    assert_eq!(roses.data()["stems_per_unit"], 12);
+    assert_eq!(roses.data()["units_in_stock"], 2);
+    let id = roses.data()["id"].as_str().unwrap().to_string();
+
+    let placed = app
+        .post("/api/orders")
+        .api_key("rose-key")
+        .json(json!({ "lines": [{ "flower_id": id, "quantity": 2 }] }))
+        .send()
+        .await;
+    assert_eq!(placed.status, StatusCode::CREATED);
+    assert_eq!(placed.data()["subtotal"], 360000.0);
+    assert_eq!(placed.data()["lines"][0]["unit"], "dozen");
+    assert_eq!(placed.data()["lines"][0]["stems"], 24);
+
+    let flower = app
+        .get(&format!("/api/flowers/{id}"))
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(flower.data()["stock"], 6);
+    assert_eq!(flower.data()["units_in_stock"], 0);
+
+    let unsized_bundle = app
+        .put(&format!("/api/flowers/{id}"))
+        .for_tenant("rose-shop")
+        .json(json!({ "unit": "bundle" }))
+        .send()
+        .await;
+    assert_eq!(unsized_bundle.status, StatusCode::BAD_REQUEST);
+    assert_eq!(unsized_bundle.code(), "flower.unit.bundle_size");
+}
@@ -0,0 +1,167 @@
+//! Pricing rule endpoints end to end
+
+mod common;
+
+use axum::http::{StatusCode, header};
+use rust_api::domain::shared::Entity;
+use rust_api::test_support::FlowerBuilder;
+use serde_json::json;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn low_stock_markups_apply_to_served_and_ordered_prices() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .setting("SHIPPING_RATE_TABLE", "10000")
+        .build()
+        .await;
+    let tulip = FlowerBuilder::new()
+        .with_tenant("rose-shop")
+        .with_name("Tulip")
+        .with_price(20_000.0)
+        .with_stock(5)
+        .persisted(app.flowers())
+        .await;
+    let rules = "/api/admin/tenants/rose-shop/pricing-rules";
+    let scarcity = json!({ "name": "Scarcity", "adjustment_percent": 25.0, "max_stock": 5 });
+
+    let tenant = app
+        .post(rules)
+        .api_key("rose-key")
+        .json(scarcity.clone())
+        .send()
+        .await;
+    assert_eq!(tenant.status, StatusCode::FORBIDDEN);
+
+    let invalid = app
+        .post(rules)
+        .admin()
+        .json(json!({ "name": "Giveaway", "adjustment_percent": -100.0 }))
+        .send()
+        .await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST);
+    assert_eq!(invalid.code(), "pricing_rule.adjustment.invalid");
+
+    let created = app.post(rules).admin().json(scarcity).send().await;
+    assert_eq!(created.status, StatusCode::CREATED);
+    assert_eq!(created.data()["enabled"], true);
+    let rule = format!("{rules}/{}", created.data()["id"].as_str().unwrap());
+
+    let listed = app.get(rules).admin().send().await;
+    assert_eq!(listed.data().as_array().unwrap().len(), 1);
+
+    let flower = app
+        .get(&format!("/api/flowers/{}", tulip.id()))
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(flower.data()["price"], 25000.0);
+    assert_eq!(flower.data()["base_price"], 20000.0);
+    assert_eq!(flower.data()["pricing_rules"][0], created.data()["id"]);
+
+    let placed = app
+        .post("/api/orders")
+        .api_key("rose-key")
+        .json(json!({
+            "lines": [{ "flower_id": tulip.id(), "quantity": 2 }],
+            "shipping": {
+                "destination": { "latitude": -6.2, "longitude": 106.83 },
+                "carrier": "shop",
+                "service": "standard"
+            }
+        }))
+        .send()
+        .await;
+    assert_eq!(placed.status, StatusCode::CREATED);
+    assert_eq!(placed.data()["subtotal"], 50000.0);
+
+    let paused = app
+        .put(&rule)
+        .admin()
+        .json(json!({
+            "name": "Scarcity",
+            "adjustment_percent": 25.0,
+            "max_stock": 5,
+            "enabled": false
+        }))
+        .send()
+        .await;
+    assert_eq!(paused.status, StatusCode::OK);
+    let flower = app
+        .get(&format!("/api/flowers/{}", tulip.id()))
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(flower.data()["price"], 20000.0);
+    assert!(flower.data().get("pricing_rules").is_none());
+
+    let deleted = app.delete(&rule).admin().send().await;
+    assert_eq!(deleted.status, StatusCode::NO_CONTENT);
+    let gone = app.get(&rule).admin().send().await;
+    assert_eq!(gone.status, StatusCode::NOT_FOUND);
+    assert_eq!(gone.code(), "pricing_rule.not_found");
+}
+
+#[tokio::test]
+async fn new_rules_revalidate_cached_flowers() {
+    let app = TestApp::spawn().await;
+    let tulip = FlowerBuilder::new()
+        .with_tenant("rose-shop")
+        .with_name("Tulip")
+        .with_price(20_000.0)
+        .persisted(app.flowers())
+        .await;
+    let uri = format!("/api/flowers/{}", tulip.id());
+
+    let fetched = app.get(&uri).for_tenant("rose-shop").send().await;
+    let etag = fetched.headers[header::ETAG].to_str().unwrap().to_string();
+    let cached = app
+        .get(&uri)
+        .for_tenant("rose-shop")
+        .header("if-none-match", &etag)
+        .send()
+        .await;
+    assert_eq!(cached.status, StatusCode::NOT_MODIFIED);
+
+    let created = app
+        .post("/api/admin/tenants/rose-shop/pricing-rules")
+        .admin()
+        .json(json!({ "name": "Valentine", "adjustment_percent": 50.0 }))
+        .send()
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED);
+
+    let since = app
+        .get(&uri)
+        .for_tenant("rose-shop")
+        .header("if-modified-since", "Sun, 01 Jan 2099 00:00:00 GMT")
+        .send()
+        .await;
+    assert_eq!(since.status, StatusCode::OK);
+    let repriced = app
+        .get(&uri)
+        .for_tenant("rose-shop")
+        .header("if-none-match", &etag)
+        .send()
+        .await;
+    assert_eq!(repriced.status, StatusCode::OK);
+    assert_eq!(repriced.data()["price"], 30000.0);
+
+    let rule = format!(
+        "/api/admin/tenants/rose-shop/pricing-rules/{}",
+        created.data()["id"].as_str().unwrap()
+    );
+    app.delete(&rule).admin().send().await;
+    let restored = app
+        .get(&uri)
+        .for_tenant("rose-shop")
+        .header(
+            "if-none-match",
+            repriced.headers[header::ETAG].to_str().unwrap(),
+        )
+        .send()
+        .await;
+    assert_eq!(restored.status, StatusCode::OK);
+    assert_eq!(restored.data()["price"], 20000.0);
+}
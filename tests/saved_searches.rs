@@ -0,0 +1,84 @@
+//! Saved search endpoints end to end
+
+mod common;
+
+use axum::http::StatusCode;
+use serde_json::json;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn searches_belong_to_the_caller_that_saved_them() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .build()
+        .await;
+    let search = json!({
+        "name": "Affordable red roses",
+        "query": "rose",
+        "color": "red",
+        "max_price": 30000.0,
+        "alert_email": "florist@example.com"
+    });
+
+    let anonymous = app
+        .post("/api/saved-searches")
+        .json(search.clone())
+        .send()
+        .await;
+    assert_eq!(anonymous.status, StatusCode::UNAUTHORIZED);
+
+    let saved = app
+        .post("/api/saved-searches")
+        .api_key("rose-key")
+        .json(search)
+        .send()
+        .await;
+    assert_eq!(saved.status, StatusCode::CREATED);
+    assert_eq!(saved.data()["color"], "red");
+    assert_eq!(saved.data()["alert_email"], "florist@example.com");
+    let uri = format!(
+        "/api/saved-searches/{}",
+        saved.data()["id"].as_str().unwrap()
+    );
+
+    let own = app
+        .get("/api/saved-searches")
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(own.status, StatusCode::OK);
+    assert_eq!(own.data()[0]["name"], "Affordable red roses");
+    let admin = app
+        .get("/api/saved-searches")
+        .for_tenant("rose-shop")
+        .admin()
+        .send()
+        .await;
+    assert_eq!(admin.data(), &json!([]));
+    let not_theirs = app
+        .delete(&uri)
+        .for_tenant("rose-shop")
+        .admin()
+        .send()
+        .await;
+    assert_eq!(not_theirs.status, StatusCode::NOT_FOUND);
+
+    let inverted = app
+        .post("/api/saved-searches")
+        .api_key("rose-key")
+        .json(json!({ "name": "Nothing", "min_price": 50000.0, "max_price": 100.0 }))
+        .send()
+        .await;
+    assert_eq!(inverted.status, StatusCode::BAD_REQUEST);
+    assert_eq!(inverted.code(), "saved_search.price_range.inverted");
+
+    let deleted = app.delete(&uri).api_key("rose-key").send().await;
+    assert_eq!(deleted.status, StatusCode::NO_CONTENT);
+    let listed = app
+        .get("/api/saved-searches")
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(listed.data(), &json!([]));
+}
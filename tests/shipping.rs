@@ -0,0 +1,40 @@
+//! Shipping endpoints end to end
+
+mod common;
+
+use axum::http::StatusCode;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn deliveries_are_quoted_by_parcel_weight() {
+    let app = TestApp::builder()
+        .setting("SHIPPING_RATE_TABLE", "1000=15000,5000=25000")
+        .build()
+        .await;
+
+    let bouquet = app
+        .get("/api/shipping/rates?lat=-6.9&lng=107.6")
+        .send()
+        .await;
+    assert_eq!(bouquet.status, StatusCode::OK);
+    assert_eq!(bouquet.data()[0]["carrier"], "shop");
+    assert_eq!(bouquet.data()[0]["fee"], 15000.0);
+
+    let hamper = app
+        .get("/api/shipping/rates?lat=-6.9&lng=107.6&weight_grams=3000")
+        .send()
+        .await;
+    assert_eq!(hamper.data()[0]["fee"], 25000.0);
+
+    let too_heavy = app
+        .get("/api/shipping/rates?lat=-6.9&lng=107.6&weight_grams=8000")
+        .send()
+        .await;
+    assert_eq!(too_heavy.status, StatusCode::OK);
+    assert!(too_heavy.data().as_array().unwrap().is_empty());
+
+    let nowhere = app.get("/api/shipping/rates?weight_grams=500").send().await;
+    assert_eq!(nowhere.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(nowhere.code(), "shipping.quote.invalid");
+}
@@ -0,0 +1,101 @@
+//! Store endpoints end to end
+
+mod common;
+
+use axum::http::StatusCode;
+use serde_json::json;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn customers_find_the_nearest_store_holding_a_flower() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop")
+        .setting("ALLOW_ANONYMOUS_WRITES", "false")
+        .build()
+        .await;
+    let flower = app
+        .post("/api/flowers")
+        .api_key("rose-key")
+        .json(json!({ "name": "Pickup Rose", "color": "red", "price": 25000.0, "stock": 10 }))
+        .send()
+        .await;
+    assert_eq!(flower.status, StatusCode::CREATED);
+    let flower_id = flower.data()["id"].as_str().unwrap().to_string();
+
+    let anonymous = app
+        .post("/api/stores")
+        .for_tenant("rose-shop")
+        .json(json!({ "name": "Bogor", "address": "Jl. Pajajaran 1", "latitude": -6.5971, "longitude": 106.806 }))
+        .send()
+        .await;
+    assert_eq!(anonymous.status, StatusCode::UNAUTHORIZED);
+
+    let mut store_ids = Vec::new();
+    for (name, latitude, longitude, quantity) in [
+        ("Bandung", -6.9175, 107.6191, 4),
+        ("Bogor", -6.5971, 106.806, 3),
+    ] {
+        let store = app
+            .post("/api/stores")
+            .api_key("rose-key")
+            .json(json!({ "name": name, "address": format!("{name}, Jawa Barat"), "latitude": latitude, "longitude": longitude }))
+            .send()
+            .await;
+        assert_eq!(store.status, StatusCode::CREATED);
+        let store_id = store.data()["id"].as_str().unwrap().to_string();
+
+        let stocked = app
+            .put(&format!("/api/stores/{store_id}/stock/{flower_id}"))
+            .api_key("rose-key")
+            .json(json!({ "quantity": quantity }))
+            .send()
+            .await;
+        assert_eq!(stocked.status, StatusCode::OK);
+        store_ids.push(store_id);
+    }
+
+    let over = app
+        .put(&format!("/api/stores/{}/stock/{flower_id}", store_ids[0]))
+        .api_key("rose-key")
+        .json(json!({ "quantity": 8 }))
+        .send()
+        .await;
+    assert_eq!(over.status, StatusCode::BAD_REQUEST);
+    assert_eq!(over.code(), "store.stock.exceeds_flower");
+
+    let nearest = app
+        .get(&format!(
+            "/api/flowers/{flower_id}/availability?lat=-6.2&lng=106.8"
+        ))
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(nearest.status, StatusCode::OK);
+    assert_eq!(nearest.data()[0]["name"], "Bogor");
+    assert_eq!(nearest.data()[0]["quantity"], 3);
+    assert_eq!(nearest.data()[1]["name"], "Bandung");
+
+    let nowhere = app
+        .get(&format!("/api/flowers/{flower_id}/availability"))
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(nowhere.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let closed = app
+        .delete(&format!("/api/stores/{}", store_ids[1]))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(closed.status, StatusCode::NO_CONTENT);
+    let remaining = app
+        .get(&format!(
+            "/api/flowers/{flower_id}/availability?lat=-6.2&lng=106.8"
+        ))
+        .for_tenant("rose-shop")
+        .send()
+        .await;
+    assert_eq!(remaining.data()[0]["name"], "Bandung");
+    assert_eq!(remaining.data().as_array().unwrap().len(), 1);
+}
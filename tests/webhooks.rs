@@ -0,0 +1,193 @@
+//! Signed webhook callbacks end to end
+
+mod common;
+
+use axum::http::StatusCode;
+use rust_api::application::webhooks::sign;
+use rust_api::domain::shared::Entity;
+use rust_api::test_support::FlowerBuilder;
+use serde_json::{Value, json};
+
+use common::{TestApp, TestResponse};
+
+const SECRET: &str = "whsec_test";
+
+async fn deliver(
+    app: &TestApp,
+    id: &str,
+    timestamp: i64,
+    secret: &str,
+    event: &Value,
+) -> TestResponse {
+    let body = serde_json::to_vec(event).unwrap();
+    app.post("/api/webhooks/payments")
+        .header("content-type", "application/json")
+        .header("x-webhook-id", id)
+        .header("x-webhook-timestamp", &timestamp.to_string())
+        .header("x-signature", &sign(secret, id, timestamp, &body))
+        .body(body)
+        .send()
+        .await
+}
+
+#[tokio::test]
+async fn signed_payment_callbacks_mark_orders_paid_once() {
+    let app = TestApp::builder()
+        .setting("PAYMENT_WEBHOOK_SECRETS", &format!("whsec_next,{}", SECRET))
+        .build()
+        .await;
+    let tulip = FlowerBuilder::new()
+        .with_tenant("rose-shop")
+        .with_stock(5)
+        .persisted(app.flowers())
+        .await;
+    let order = app
+        .post("/api/orders")
+        .for_tenant("rose-shop")
+        .admin()
+        .json(json!({ "lines": [{ "flower_id": tulip.id(), "quantity": 1 }] }))
+        .send()
+        .await;
+    assert_eq!(order.status, StatusCode::CREATED);
+    let event = json!({
+        "type": "payment.succeeded",
+        "tenant_id": "rose-shop",
+        "order_id": order.data()["id"],
+        "reference": "pay_1"
+    });
+    let now = chrono::Utc::now().timestamp();
+
+    let forged = deliver(&app, "evt_1", now, "whsec_guess", &event).await;
+    assert_eq!(forged.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(forged.code(), "webhook.signature.invalid");
+    let stale = deliver(&app, "evt_1", now - 3600, SECRET, &event).await;
+    assert_eq!(stale.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(stale.code(), "webhook.timestamp.stale");
+    let unsigned = app
+        .post("/api/webhooks/payments")
+        .json(event.clone())
+        .send()
+        .await;
+    assert_eq!(unsigned.status, StatusCode::UNAUTHORIZED);
+
+    let paid = deliver(&app, "evt_1", now, SECRET, &event).await;
+    assert_eq!(paid.status, StatusCode::OK);
+    assert_eq!(paid.data()["status"], "paid");
+    let replayed = deliver(&app, "evt_1", now, SECRET, &event).await;
+    assert_eq!(replayed.status, StatusCode::CONFLICT);
+    assert_eq!(replayed.code(), "webhook.replayed");
+    // A retry is signed anew, and finds the order paid already
+    let retried = deliver(&app, "evt_1", now + 1, SECRET, &event).await;
+    assert_eq!(retried.status, StatusCode::OK);
+    assert_eq!(retried.data()["status"], "paid");
+}
+
+#[tokio::test]
+async fn callbacks_are_refused_without_secrets() {
+    let app = TestApp::spawn().await;
+    let event = json!({
+        "type": "payment.succeeded",
+        "tenant_id": "rose-shop",
+        "order_id": uuid::Uuid::new_v4(),
+        "reference": "pay_1"
+    });
+
+    let response = deliver(
+        &app,
+        "evt_1",
+        chrono::Utc::now().timestamp(),
+        SECRET,
+        &event,
+    )
+    .await;
+    assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.code(), "webhook.unconfigured");
+}
+
+#[tokio::test]
+async fn tenants_register_rotate_and_test_their_endpoints() {
+    let app = TestApp::builder()
+        .setting("TENANT_API_KEYS", "rose-key=rose-shop,lily-key=lily-shop")
+        .build()
+        .await;
+
+    let invalid = app
+        .post("/api/webhook-endpoints")
+        .api_key("rose-key")
+        .json(json!({ "url": "ftp://hooks.example.com" }))
+        .send()
+        .await;
+    assert_eq!(invalid.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let metadata = app
+        .post("/api/webhook-endpoints")
+        .api_key("rose-key")
+        .json(json!({ "url": "http://169.254.169.254/latest/meta-data" }))
+        .send()
+        .await;
+    assert_eq!(metadata.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let created = app
+        .post("/api/webhook-endpoints")
+        .api_key("rose-key")
+        .json(json!({ "url": "https://hooks.example.com/flowers" }))
+        .send()
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED);
+    let id = created.data()["id"].as_str().unwrap().to_string();
+    let secret = created.data()["secret"].as_str().unwrap().to_string();
+    assert!(secret.starts_with("whsec_"));
+
+    let listed = app
+        .get("/api/webhook-endpoints")
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(listed.status, StatusCode::OK);
+    assert_eq!(listed.data().as_array().unwrap().len(), 1);
+    assert!(listed.data()[0].get("secret").is_none());
+
+    let rotated = app
+        .post(&format!("/api/webhook-endpoints/{}/rotate-secret", id))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(rotated.status, StatusCode::OK);
+    assert_ne!(rotated.data()["secret"].as_str().unwrap(), secret);
+    assert!(rotated.data()["previous_secret_expires_at"].is_string());
+
+    let tested = app
+        .post(&format!("/api/webhook-endpoints/{}/test", id))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(tested.status, StatusCode::OK);
+    assert!(tested.data()["delivery_id"].is_string());
+    assert_eq!(tested.data()["delivered"], true);
+    assert!(tested.data().get("error").is_none());
+    let sent = app.webhooks_sent();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].url, "https://hooks.example.com/flowers");
+
+    // Endpoints belong to their tenant
+    let foreign = app
+        .post(&format!("/api/webhook-endpoints/{}/test", id))
+        .api_key("lily-key")
+        .send()
+        .await;
+    assert_eq!(foreign.status, StatusCode::NOT_FOUND);
+    let anonymous = app.get("/api/webhook-endpoints").send().await;
+    assert_eq!(anonymous.status, StatusCode::UNAUTHORIZED);
+
+    let deleted = app
+        .delete(&format!("/api/webhook-endpoints/{}", id))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(deleted.status, StatusCode::NO_CONTENT);
+    let gone = app
+        .delete(&format!("/api/webhook-endpoints/{}", id))
+        .api_key("rose-key")
+        .send()
+        .await;
+    assert_eq!(gone.status, StatusCode::NOT_FOUND);
+}